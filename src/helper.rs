@@ -1,14 +1,18 @@
 pub mod address;
 pub mod binary;
+pub mod calculation;
 pub mod color;
 pub mod const_str;
 pub mod coordinate;
 pub mod crypt;
 pub mod date;
 pub mod formula;
+pub mod formula_ast;
+pub mod function_catalog;
 pub mod html;
 pub mod number_format;
 pub mod range;
+pub mod reference;
 pub mod string_helper;
 pub mod time_zone;
 pub mod utils;
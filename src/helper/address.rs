@@ -21,12 +21,24 @@ pub fn join_address(sheet_name: &str, address: &str) -> String {
     format!("{sheet_name}!{address}")
 }
 
+/// Join a 3-D reference over the sheets from `first_sheet` to `last_sheet`.
+/// ex) "Sheet1:Sheet3!A1", "'Jan 1:Mar 1'!A1"
+#[must_use]
+pub fn join_3d_address(first_sheet: &str, last_sheet: &str, address: &str) -> String {
+    if sheet_name_needs_quoting(first_sheet) || sheet_name_needs_quoting(last_sheet) {
+        let escaped = format!("{first_sheet}:{last_sheet}").replace('\'', "''");
+        return format!("'{escaped}'!{address}");
+    }
+    format!("{first_sheet}:{last_sheet}!{address}")
+}
+
 /// A sheet name can appear unquoted in a formula only when it is a bare
 /// identifier: it starts with a letter or underscore and otherwise contains
 /// only letters, digits, underscores or periods. Anything else (spaces,
 /// punctuation such as `(`/`)`, a leading digit, …) must be wrapped in single
-/// quotes when re-serialized.
-fn sheet_name_needs_quoting(sheet_name: &str) -> bool {
+/// quotes when re-serialized, and so must a name that reads as a cell such
+/// as `A1` or `R1C1`.
+pub(crate) fn sheet_name_needs_quoting(sheet_name: &str) -> bool {
     let mut chars = sheet_name.chars();
     match chars.next() {
         None => false,
@@ -37,6 +49,9 @@ fn sheet_name_needs_quoting(sheet_name: &str) -> bool {
             sheet_name
                 .chars()
                 .any(|c| !(c.is_ascii_alphanumeric() || c == '_' || c == '.'))
+                || compile_regex!(r"^([A-Z]{1,3}[0-9]+|R[0-9]*C[0-9]*)$")
+                    .is_match(&sheet_name.to_uppercase())
+                    .unwrap_or(false)
         }
     }
 }
@@ -44,8 +59,10 @@ fn sheet_name_needs_quoting(sheet_name: &str) -> bool {
 /// Checks if the given input string is a valid address format.
 ///
 /// The address format is defined by the following regular expression:
-/// `^([^\:\\\?$$$$\/\*]+\!)?(\$?[A-Z]{1,3}\$?[0-9]+)(\:\$?[A-Z]{1,3}\$?[0-9]+)?
-/// $`.
+/// `^(('([^'\:\\\?\[\]\/\*]|'')+'|[^'\:\\\?\[\]\/\*!<>=+\-&,^()]+)\!)?`
+/// `(\$?[A-Z]{1,3}\$?[0-9]+)(\:\$?[A-Z]{1,3}\$?[0-9]+)?$`.
+/// A sheet name with an apostrophe must be quoted, and an unquoted one can't
+/// contain an operator, so a formula such as `$A1>Sheet1!$B$1` is no address.
 ///
 /// # Parameters
 ///
@@ -76,7 +93,7 @@ fn sheet_name_needs_quoting(sheet_name: &str) -> bool {
 /// ```
 pub fn is_address<S: AsRef<str>>(input: S) -> bool {
     let regex = compile_regex!(
-        r"^([^\:\\\?\[\]\/\*]+\!)?(\$?[A-Z]{1,3}\$?[0-9]+)(\:\$?[A-Z]{1,3}\$?[0-9]+)?$"
+        r"^(('([^'\:\\\?\[\]\/\*]|'')+'|[^'\:\\\?\[\]\/\*!<>=+\-&,^()]+)\!)?(\$?[A-Z]{1,3}\$?[0-9]+)(\:\$?[A-Z]{1,3}\$?[0-9]+)?$"
     );
     regex.is_match(input.as_ref()).unwrap()
 }
@@ -114,10 +131,13 @@ fn is_address_test() {
     assert!(is_address("Sheet1!$A$1:$B2"));
     assert!(is_address("Sheet1!$A$1:$B$2"));
     assert!(is_address("New Sheet!$H$7:$H$10"));
+    assert!(is_address("'Bob''s Data'!$A$1:$A$3"));
 
     assert!(!is_address("(Sheet1!A1:B2)"));
     assert!(!is_address("Sheet1!A1:"));
     assert!(!is_address("Sheet1!A1:B"));
     assert!(!is_address("Sheet1!A:B2"));
     assert!(!is_address("Sheet1"));
+    assert!(!is_address("$A1>Sheet1!$B$1"));
+    assert!(!is_address("$A1>'Bob''s Data'!$B$1"));
 }
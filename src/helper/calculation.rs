@@ -0,0 +1,714 @@
+//! Calculation of the formulas of cells, used by goal seek and data tables.
+//!
+//! Arithmetic, comparison and text operators, references to the cells of the
+//! workbook, defined names and a set of common functions are calculated.
+//! Other functions, structured references and references to other workbooks
+//! can not be calculated and make the calculation fail.
+
+use std::{
+    cmp::Ordering,
+    collections::{
+        HashMap,
+        HashSet,
+    },
+};
+
+use crate::{
+    XlsxError,
+    helper::{
+        coordinate::coordinate_from_index,
+        formula_ast::{
+            BinaryOperator,
+            FormulaExpr,
+            FormulaName,
+            FormulaReference,
+            ReferenceArea,
+            UnaryOperator,
+            parse_formula,
+        },
+        reference::find_defined_name,
+    },
+    structs::{
+        CellRawValue,
+        CellValue,
+        DependencyGraph,
+        DependencyNode,
+        Workbook,
+    },
+};
+
+const MAX_NAME_DEPTH: usize = 32;
+
+/// A cell of the workbook: sheet index, column and row.
+type CellKey = (usize, u32, u32);
+
+/// The value of an expression.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Value {
+    Number(f64),
+    Text(String),
+    Boolean(bool),
+    Error(String),
+    Empty,
+    /// The values of a range or of an array constant.
+    Array(Vec<Value>),
+}
+
+impl Value {
+    fn from_cell_value(cell_value: &CellValue) -> Self {
+        match cell_value.raw_value() {
+            CellRawValue::Numeric(v) => Self::Number(*v),
+            CellRawValue::Bool(v) => Self::Boolean(*v),
+            CellRawValue::Error(v) => Self::Error(v.to_string()),
+            CellRawValue::Empty => Self::Empty,
+            v => Self::Text(v.to_string()),
+        }
+    }
+
+    #[inline]
+    fn error(code: &str) -> Self {
+        Self::Error(code.to_string())
+    }
+
+    /// The first value of an array, as an array in a cell shows it.
+    pub(crate) fn scalar(self) -> Self {
+        match self {
+            Self::Array(values) => values.into_iter().next().map_or(Self::Empty, Self::scalar),
+            v => v,
+        }
+    }
+
+    pub(crate) fn number(&self) -> Result<f64, Self> {
+        match self {
+            Self::Number(v) => Ok(*v),
+            Self::Boolean(v) => Ok(f64::from(u8::from(*v))),
+            Self::Empty => Ok(0.0),
+            Self::Text(v) => v.trim().parse().map_err(|_| Self::error("#VALUE!")),
+            Self::Error(_) => Err(self.clone()),
+            Self::Array(_) => self.clone().scalar().number(),
+        }
+    }
+
+    fn boolean(&self) -> Result<bool, Self> {
+        match self {
+            Self::Boolean(v) => Ok(*v),
+            Self::Text(v) if v.eq_ignore_ascii_case("TRUE") => Ok(true),
+            Self::Text(v) if v.eq_ignore_ascii_case("FALSE") => Ok(false),
+            v => v.number().map(|v| v != 0.0),
+        }
+    }
+
+    fn text(&self) -> Result<String, Self> {
+        match self {
+            Self::Number(v) => Ok(v.to_string()),
+            Self::Text(v) => Ok(v.clone()),
+            Self::Boolean(v) => Ok(if *v { "TRUE" } else { "FALSE" }.to_string()),
+            Self::Empty => Ok(String::new()),
+            Self::Error(_) => Err(self.clone()),
+            Self::Array(_) => self.clone().scalar().text(),
+        }
+    }
+
+    /// Compare as Excel does: numbers before texts before booleans, texts
+    /// without case, and an empty value as the zero of the other side.
+    fn compare(&self, other: &Self) -> Result<Ordering, Self> {
+        let rank = |value: &Self| match value {
+            Self::Number(_) => 0,
+            Self::Text(_) => 1,
+            _ => 2,
+        };
+        match (self, other) {
+            (Self::Error(_), _) => Err(self.clone()),
+            (_, Self::Error(_)) => Err(other.clone()),
+            (Self::Empty, Self::Empty) => Ok(Ordering::Equal),
+            (Self::Empty, Self::Number(_)) => Self::Number(0.0).compare(other),
+            (Self::Empty, Self::Text(_)) => Self::Text(String::new()).compare(other),
+            (Self::Empty, Self::Boolean(_)) => Self::Boolean(false).compare(other),
+            (_, Self::Empty) => other.compare(self).map(Ordering::reverse),
+            (Self::Number(a), Self::Number(b)) => Ok(a.total_cmp(b)),
+            (Self::Text(a), Self::Text(b)) => Ok(a.to_lowercase().cmp(&b.to_lowercase())),
+            (Self::Boolean(a), Self::Boolean(b)) => Ok(a.cmp(b)),
+            (Self::Array(_), _) => self.clone().scalar().compare(other),
+            (_, Self::Array(_)) => self.compare(&other.clone().scalar()),
+            (a, b) => Ok(rank(a).cmp(&rank(b))),
+        }
+    }
+}
+
+#[inline]
+fn invalid<T>(message: String) -> Result<T, XlsxError> {
+    Err(XlsxError::InvalidArgument(message))
+}
+
+/// Calculates the formulas of the cells of a workbook, with some cells set
+/// to other numbers.
+pub(crate) struct Calculator<'a> {
+    workbook:   &'a Workbook,
+    inputs:     HashMap<CellKey, f64>,
+    cache:      HashMap<CellKey, Value>,
+    visiting:   HashSet<CellKey>,
+    name_depth: usize,
+    graph:      Option<DependencyGraph>,
+}
+
+impl<'a> Calculator<'a> {
+    pub(crate) fn new(workbook: &'a Workbook) -> Self {
+        Self {
+            workbook,
+            inputs: HashMap::new(),
+            cache: HashMap::new(),
+            visiting: HashSet::new(),
+            name_depth: 0,
+            graph: None,
+        }
+    }
+
+    /// Calculate as if the cell held `value`.
+    pub(crate) fn set_input(&mut self, sheet_index: usize, col: u32, row: u32, value: f64) {
+        self.inputs.insert((sheet_index, col, row), value);
+        self.cache.clear();
+    }
+
+    /// The value of a cell, calculating its formula.
+    ///
+    /// The cells the formula depends on are calculated first, each after its
+    /// own precedents, so that long chains of formulas do not overflow the
+    /// stack.
+    pub(crate) fn cell(&mut self, sheet_index: usize, col: u32, row: u32) -> Result<Value, XlsxError> {
+        let key = (sheet_index, col, row);
+        let has_formula = self.workbook.sheet_collection_no_check()[sheet_index]
+            .cell((col, row))
+            .is_some_and(|cell| !cell.formula().is_empty());
+        if has_formula && !self.inputs.contains_key(&key) && !self.cache.contains_key(&key) {
+            for precedent in self.precedents_in_order(key)? {
+                // A failure is reported when the formula really uses the
+                // cell, not for the branch of an `IF` that is not taken.
+                self.value(precedent.0, precedent.1, precedent.2).ok();
+            }
+        }
+        self.value(sheet_index, col, row)
+    }
+
+    /// The cells the formula of the cell at `key` depends on, directly or
+    /// through other cells and defined names, each after its precedents.
+    fn precedents_in_order(&mut self, key: CellKey) -> Result<Vec<CellKey>, XlsxError> {
+        if self.graph.is_none() {
+            self.graph = Some(DependencyGraph::new(self.workbook)?);
+        }
+        let graph = self.graph.as_ref().unwrap();
+        let sheets = self.workbook.sheet_collection_no_check();
+        let start = DependencyNode::cell(sheets[key.0].name(), (key.1, key.2));
+
+        // `true` once the node and its precedents are ordered.
+        let mut is_ordered: HashMap<&DependencyNode, bool> = HashMap::new();
+        let mut order = Vec::new();
+        // `(node, precedents of it still to visit)`
+        let mut work = vec![(&start, graph.precedents(&start))];
+        is_ordered.insert(&start, false);
+        while let Some((node, pending)) = work.last_mut() {
+            if let Some(next) = pending.pop() {
+                match is_ordered.get(next) {
+                    None => {
+                        is_ordered.insert(next, false);
+                        work.push((next, graph.precedents(next)));
+                    }
+                    Some(false) => return invalid(format!("circular reference at {next}")),
+                    Some(true) => {}
+                }
+                continue;
+            }
+            let node = *node;
+            work.pop();
+            is_ordered.insert(node, true);
+            if let DependencyNode::Cell {
+                sheet_name,
+                col,
+                row,
+            } = node
+            {
+                if let Some(sheet_index) = sheets.iter().position(|x| x.name() == sheet_name) {
+                    order.push((sheet_index, *col, *row));
+                }
+            }
+        }
+        order.pop();
+        Ok(order)
+    }
+
+    /// The value of a cell, calculating its formula and the formulas it
+    /// refers to that are not calculated yet.
+    fn value(&mut self, sheet_index: usize, col: u32, row: u32) -> Result<Value, XlsxError> {
+        let key = (sheet_index, col, row);
+        if let Some(value) = self.inputs.get(&key) {
+            return Ok(Value::Number(*value));
+        }
+        if let Some(value) = self.cache.get(&key) {
+            return Ok(value.clone());
+        }
+        let worksheet = &self.workbook.sheet_collection_no_check()[sheet_index];
+        let Some(cell) = worksheet.cell((col, row)) else {
+            return Ok(Value::Empty);
+        };
+        let value = if cell.formula().is_empty() {
+            Value::from_cell_value(cell.cell_value())
+        } else {
+            if !self.visiting.insert(key) {
+                return invalid(format!(
+                    "circular reference at {}!{}",
+                    worksheet.name(),
+                    coordinate_from_index(col, row)
+                ));
+            }
+            let value = parse_formula(cell.formula()).and_then(|expr| self.expr(&expr, sheet_index));
+            self.visiting.remove(&key);
+            value?.scalar()
+        };
+        self.cache.insert(key, value.clone());
+        Ok(value)
+    }
+
+    fn expr(&mut self, expr: &FormulaExpr, scope: usize) -> Result<Value, XlsxError> {
+        Ok(match expr {
+            FormulaExpr::Number(v) => Value::Number(*v),
+            FormulaExpr::Text(v) => Value::Text(v.clone()),
+            FormulaExpr::Boolean(v) => Value::Boolean(*v),
+            FormulaExpr::Error(v) => Value::Error(v.clone()),
+            FormulaExpr::Missing => Value::Empty,
+            FormulaExpr::Array(rows) => Value::Array(
+                rows.iter()
+                    .flatten()
+                    .map(|v| self.expr(v, scope))
+                    .collect::<Result<Vec<Value>, XlsxError>>()?,
+            ),
+            FormulaExpr::Reference(reference) => self.reference(reference, scope)?,
+            FormulaExpr::Name(name) => self.name(name, scope)?,
+            FormulaExpr::StructuredReference(v) => {
+                return invalid(format!("{v} can not be calculated"));
+            }
+            FormulaExpr::Function { name, args } => self.function(name, args, scope)?,
+            FormulaExpr::Call { .. } => {
+                return invalid(format!("{expr} can not be calculated"));
+            }
+            FormulaExpr::Unary { operator, operand } => {
+                let value = self.expr(operand, scope)?;
+                match operator {
+                    UnaryOperator::ImplicitIntersection => value.scalar(),
+                    UnaryOperator::Spill => value,
+                    UnaryOperator::Plus | UnaryOperator::Minus | UnaryOperator::Percent => {
+                        match value.number() {
+                            Ok(v) => Value::Number(match operator {
+                                UnaryOperator::Minus => -v,
+                                UnaryOperator::Percent => v / 100.0,
+                                _ => v,
+                            }),
+                            Err(e) => e,
+                        }
+                    }
+                }
+            }
+            FormulaExpr::Binary {
+                operator,
+                left,
+                right,
+            } => {
+                let left = self.expr(left, scope)?;
+                let right = self.expr(right, scope)?;
+                binary(*operator, &left, &right)
+                    .unwrap_or_else(|| invalid(format!("operator \"{}\" can not be calculated", operator.symbol())))?
+            }
+            FormulaExpr::Paren(v) => self.expr(v, scope)?,
+        })
+    }
+
+    fn sheet_index(&self, sheet_name: Option<&str>, scope: usize) -> Option<usize> {
+        match sheet_name {
+            Some(sheet_name) => self
+                .workbook
+                .sheet_collection_no_check()
+                .iter()
+                .position(|sheet| sheet.name().eq_ignore_ascii_case(sheet_name)),
+            None => Some(scope),
+        }
+    }
+
+    fn reference(&mut self, reference: &FormulaReference, scope: usize) -> Result<Value, XlsxError> {
+        if reference.is_external() {
+            return invalid(format!("{reference} refers to another workbook"));
+        }
+        let first = self.sheet_index(reference.sheet(), scope);
+        let last = match reference.last_sheet() {
+            Some(sheet_name) => self.sheet_index(Some(sheet_name), scope),
+            None => first,
+        };
+        let (Some(first), Some(last)) = (first, last) else {
+            return Ok(Value::error("#REF!"));
+        };
+        let (col_start, row_start, col_end, row_end) = match reference.area() {
+            ReferenceArea::Cell(coordinate) if first == last => {
+                return self.value(first, coordinate.col_num(), coordinate.row_num());
+            }
+            ReferenceArea::Cell(coordinate) => (
+                coordinate.col_num(),
+                coordinate.row_num(),
+                coordinate.col_num(),
+                coordinate.row_num(),
+            ),
+            ReferenceArea::Range(start, end) => {
+                (start.col_num(), start.row_num(), end.col_num(), end.row_num())
+            }
+            ReferenceArea::Columns(start, end) => (start.num(), 1, end.num(), u32::MAX),
+            ReferenceArea::Rows(start, end) => (1, start.num(), u32::MAX, end.num()),
+        };
+        let cols = col_start.min(col_end)..=col_start.max(col_end);
+        let rows = row_start.min(row_end)..=row_start.max(row_end);
+        let mut keys: Vec<CellKey> = Vec::new();
+        for sheet_index in first.min(last)..=first.max(last) {
+            let worksheet = &self.workbook.sheet_collection_no_check()[sheet_index];
+            let mut coordinates: Vec<(u32, u32)> = worksheet
+                .cells()
+                .into_iter()
+                .map(|cell| (cell.coordinate().row_num(), cell.coordinate().col_num()))
+                .filter(|(row, col)| rows.contains(row) && cols.contains(col))
+                .collect();
+            coordinates.sort_unstable();
+            keys.extend(coordinates.into_iter().map(|(row, col)| (sheet_index, col, row)));
+        }
+        let mut values = Vec::with_capacity(keys.len());
+        for (sheet_index, col, row) in keys {
+            values.push(self.value(sheet_index, col, row)?);
+        }
+        Ok(Value::Array(values))
+    }
+
+    fn name(&mut self, name: &FormulaName, scope: usize) -> Result<Value, XlsxError> {
+        if name.is_external() {
+            return invalid(format!("{name} refers to another workbook"));
+        }
+        let Some(scope) = self.sheet_index(name.sheet(), scope) else {
+            return Ok(Value::error("#REF!"));
+        };
+        let Some(defined_name) = find_defined_name(self.workbook, name.name(), Some(scope)) else {
+            return Ok(Value::error("#NAME?"));
+        };
+        if self.name_depth > MAX_NAME_DEPTH {
+            return invalid(format!("\"{name}\" refers to itself"));
+        }
+        // The formula of a sheet scoped name is seen from its own sheet.
+        let scope = if defined_name.has_local_sheet_id() {
+            let index = defined_name.local_sheet_id() as usize;
+            if index >= self.workbook.sheet_collection_no_check().len() {
+                return Ok(Value::error("#REF!"));
+            }
+            index
+        } else {
+            scope
+        };
+        let expr = parse_formula(&defined_name.address())?;
+        self.name_depth += 1;
+        let value = self.expr(&expr, scope);
+        self.name_depth -= 1;
+        value
+    }
+
+    fn function(&mut self, name: &str, args: &[FormulaExpr], scope: usize) -> Result<Value, XlsxError> {
+        let name = name.strip_prefix("_xlfn.").unwrap_or(name).to_uppercase();
+        // Only the argument used is calculated.
+        match name.as_str() {
+            "IF" if (2..=3).contains(&args.len()) => {
+                return match self.expr(&args[0], scope)?.boolean() {
+                    Ok(true) => self.expr(&args[1], scope),
+                    Ok(false) => match args.get(2) {
+                        Some(v) => self.expr(v, scope),
+                        None => Ok(Value::Boolean(false)),
+                    },
+                    Err(e) => Ok(e),
+                };
+            }
+            "IFERROR" if args.len() == 2 => {
+                return match self.expr(&args[0], scope)?.scalar() {
+                    Value::Error(_) => self.expr(&args[1], scope),
+                    v => Ok(v),
+                };
+            }
+            _ => {}
+        }
+        let values = args
+            .iter()
+            .map(|v| self.expr(v, scope))
+            .collect::<Result<Vec<Value>, XlsxError>>()?;
+        match call(&name, &values) {
+            Some(result) => Ok(result.unwrap_or_else(|e| e)),
+            None => invalid(format!("{name} can not be calculated")),
+        }
+    }
+}
+
+fn binary(operator: BinaryOperator, left: &Value, right: &Value) -> Option<Result<Value, XlsxError>> {
+    let arithmetic = |f: fn(f64, f64) -> Value| -> Value {
+        match (left.number(), right.number()) {
+            (Ok(a), Ok(b)) => f(a, b),
+            (Err(e), _) | (_, Err(e)) => e,
+        }
+    };
+    let comparison = |f: fn(Ordering) -> bool| -> Value {
+        match left.compare(right) {
+            Ok(v) => Value::Boolean(f(v)),
+            Err(e) => e,
+        }
+    };
+    let value = match operator {
+        BinaryOperator::Range | BinaryOperator::Intersection | BinaryOperator::Union => {
+            return None;
+        }
+        BinaryOperator::Power => arithmetic(|a, b| finite(a.powf(b))),
+        BinaryOperator::Multiply => arithmetic(|a, b| finite(a * b)),
+        BinaryOperator::Divide => arithmetic(|a, b| {
+            if b == 0.0 {
+                Value::error("#DIV/0!")
+            } else {
+                finite(a / b)
+            }
+        }),
+        BinaryOperator::Add => arithmetic(|a, b| finite(a + b)),
+        BinaryOperator::Subtract => arithmetic(|a, b| finite(a - b)),
+        BinaryOperator::Concat => match (left.text(), right.text()) {
+            (Ok(a), Ok(b)) => Value::Text(a + &b),
+            (Err(e), _) | (_, Err(e)) => e,
+        },
+        BinaryOperator::Equal => comparison(Ordering::is_eq),
+        BinaryOperator::NotEqual => comparison(Ordering::is_ne),
+        BinaryOperator::Less => comparison(Ordering::is_lt),
+        BinaryOperator::LessEqual => comparison(Ordering::is_le),
+        BinaryOperator::Greater => comparison(Ordering::is_gt),
+        BinaryOperator::GreaterEqual => comparison(Ordering::is_ge),
+    };
+    Some(Ok(value))
+}
+
+/// `#NUM!` for a result out of the range of numbers.
+#[inline]
+fn finite(value: f64) -> Value {
+    if value.is_finite() {
+        Value::Number(value)
+    } else {
+        Value::error("#NUM!")
+    }
+}
+
+/// The numbers of the arguments of a function such as `SUM`: the numbers
+/// of ranges, and the arguments given directly converted to numbers.
+fn numbers(args: &[Value]) -> Result<Vec<f64>, Value> {
+    let mut result = Vec::new();
+    for arg in args {
+        match arg {
+            Value::Array(values) => {
+                for value in values {
+                    match value {
+                        Value::Number(v) => result.push(*v),
+                        Value::Error(_) => return Err(value.clone()),
+                        _ => {}
+                    }
+                }
+            }
+            v => result.push(v.number()?),
+        }
+    }
+    Ok(result)
+}
+
+/// The argument at `index` as a number, `default` when it is omitted.
+fn number_arg(args: &[Value], index: usize, default: Option<f64>) -> Result<f64, Value> {
+    match (args.get(index), default) {
+        (None | Some(Value::Empty), Some(v)) => Ok(v),
+        (Some(v), _) => v.number(),
+        (None, None) => Err(Value::error("#VALUE!")),
+    }
+}
+
+fn math(args: &[Value], f: fn(f64) -> Option<f64>) -> Result<Value, Value> {
+    if args.len() != 1 {
+        return Err(Value::error("#VALUE!"));
+    }
+    f(number_arg(args, 0, None)?).map_or(Err(Value::error("#NUM!")), |v| Ok(finite(v)))
+}
+
+fn round(args: &[Value], f: fn(f64) -> f64) -> Result<Value, Value> {
+    let value = number_arg(args, 0, None)?;
+    let digits: i32 = num_traits::cast(number_arg(args, 1, None)?.trunc()).unwrap_or(0);
+    let scale = 10f64.powi(digits);
+    Ok(finite(f(value * scale) / scale))
+}
+
+fn round_up(value: f64) -> f64 {
+    value.abs().ceil().copysign(value)
+}
+
+fn logical(args: &[Value], f: fn(&[bool]) -> bool) -> Result<Value, Value> {
+    let mut values = Vec::new();
+    for arg in args {
+        match arg {
+            Value::Array(items) => {
+                for item in items {
+                    match item {
+                        Value::Number(_) | Value::Boolean(_) => values.push(item.boolean()?),
+                        Value::Error(_) => return Err(item.clone()),
+                        _ => {}
+                    }
+                }
+            }
+            v => values.push(v.boolean()?),
+        }
+    }
+    if values.is_empty() {
+        return Err(Value::error("#VALUE!"));
+    }
+    Ok(Value::Boolean(f(&values)))
+}
+
+/// Rate, number of periods, payment, present value, future value and type
+/// of the financial functions.
+fn annuity(args: &[Value], order: [usize; 3]) -> Result<(f64, f64, f64, f64, f64), Value> {
+    let rate = number_arg(args, 0, None)?;
+    let periods = number_arg(args, 1, None)?;
+    let first = number_arg(args, order[0], None)?;
+    let second = number_arg(args, order[1], Some(0.0))?;
+    let kind = if number_arg(args, order[2], Some(0.0))? == 0.0 { 0.0 } else { 1.0 };
+    Ok((rate, periods, first, second, kind))
+}
+
+/// Calculate the function `name`, `None` when it is not supported.
+fn call(name: &str, args: &[Value]) -> Option<Result<Value, Value>> {
+    let result = match name {
+        "SUM" => numbers(args).map(|v| finite(v.iter().sum())),
+        "PRODUCT" => numbers(args).map(|v| finite(v.iter().product())),
+        "AVERAGE" => numbers(args).and_then(|v| {
+            if v.is_empty() {
+                Err(Value::error("#DIV/0!"))
+            } else {
+                Ok(finite(v.iter().sum::<f64>() / num_traits::cast::<usize, f64>(v.len()).unwrap()))
+            }
+        }),
+        "MIN" => numbers(args).map(|v| Value::Number(v.into_iter().reduce(f64::min).unwrap_or(0.0))),
+        "MAX" => numbers(args).map(|v| Value::Number(v.into_iter().reduce(f64::max).unwrap_or(0.0))),
+        "COUNT" | "COUNTA" => {
+            let is_counted = |v: &Value| match v {
+                Value::Number(_) => true,
+                Value::Empty => false,
+                _ => name == "COUNTA",
+            };
+            let count = args
+                .iter()
+                .map(|arg| match arg {
+                    Value::Array(values) => values.iter().filter(|v| is_counted(v)).count(),
+                    v => usize::from(is_counted(v) || (name == "COUNT" && v.number().is_ok())),
+                })
+                .sum::<usize>();
+            Ok(Value::Number(num_traits::cast(count).unwrap()))
+        }
+        "ABS" => math(args, |v| Some(v.abs())),
+        "SQRT" => math(args, |v| (v >= 0.0).then(|| v.sqrt())),
+        "EXP" => math(args, |v| Some(v.exp())),
+        "LN" => math(args, |v| (v > 0.0).then(|| v.ln())),
+        "LOG10" => math(args, |v| (v > 0.0).then(|| v.log10())),
+        "INT" => math(args, |v| Some(v.floor())),
+        "SIGN" => math(args, |v| Some(if v == 0.0 { 0.0 } else { v.signum() })),
+        "LOG" => number_arg(args, 0, None).and_then(|value| {
+            let base = number_arg(args, 1, Some(10.0))?;
+            if value <= 0.0 || base <= 0.0 || (base - 1.0).abs() < f64::EPSILON {
+                return Err(Value::error("#NUM!"));
+            }
+            Ok(finite(value.log(base)))
+        }),
+        "POWER" => number_arg(args, 0, None)
+            .and_then(|value| Ok(finite(value.powf(number_arg(args, 1, None)?)))),
+        "MOD" => number_arg(args, 0, None).and_then(|value| {
+            let divisor = number_arg(args, 1, None)?;
+            if divisor == 0.0 {
+                return Err(Value::error("#DIV/0!"));
+            }
+            Ok(finite(value - divisor * (value / divisor).floor()))
+        }),
+        "ROUND" => round(args, f64::round),
+        "ROUNDUP" => round(args, round_up),
+        "ROUNDDOWN" => round(args, f64::trunc),
+        "PI" => Ok(Value::Number(std::f64::consts::PI)),
+        "AND" => logical(args, |v| v.iter().all(|x| *x)),
+        "OR" => logical(args, |v| v.iter().any(|x| *x)),
+        "NOT" => number_arg(args, 0, None)
+            .and_then(|_| args[0].boolean())
+            .map(|v| Value::Boolean(!v)),
+        // PMT(rate, nper, pv, [fv], [type])
+        "PMT" => annuity(args, [2, 3, 4]).map(|(rate, periods, pv, fv, kind)| {
+            if rate == 0.0 {
+                return finite(-(pv + fv) / periods);
+            }
+            let growth = (1.0 + rate).powf(periods);
+            finite(-(rate * (fv + pv * growth)) / ((1.0 + rate * kind) * (growth - 1.0)))
+        }),
+        // PV(rate, nper, pmt, [fv], [type])
+        "PV" => annuity(args, [2, 3, 4]).map(|(rate, periods, pmt, fv, kind)| {
+            if rate == 0.0 {
+                return finite(-(fv + pmt * periods));
+            }
+            let growth = (1.0 + rate).powf(periods);
+            finite(-(fv + pmt * (1.0 + rate * kind) * (growth - 1.0) / rate) / growth)
+        }),
+        // FV(rate, nper, pmt, [pv], [type])
+        "FV" => annuity(args, [2, 3, 4]).map(|(rate, periods, pmt, pv, kind)| {
+            if rate == 0.0 {
+                return finite(-(pv + pmt * periods));
+            }
+            let growth = (1.0 + rate).powf(periods);
+            finite(-(pv * growth + pmt * (1.0 + rate * kind) * (growth - 1.0) / rate))
+        }),
+        _ => return None,
+    };
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn calculate(book: &Workbook, coordinate: &str) -> Value {
+        let sheet = book.sheet(0).unwrap();
+        let cell = sheet.cell(coordinate).unwrap();
+        let coordinate = cell.coordinate();
+        Calculator::new(book)
+            .cell(0, coordinate.col_num(), coordinate.row_num())
+            .unwrap()
+    }
+
+    #[test]
+    fn calculate_formulas() {
+        let mut book = crate::new_file();
+        book.new_sheet("Rates").unwrap();
+        book.sheet_mut(1).unwrap().cell_mut("A1").set_value_number(0.5);
+        book.defined_names_manager()
+            .add("Rate", "Rates!$A$1", None)
+            .unwrap();
+        let sheet = book.sheet_mut(0).unwrap();
+        sheet.cell_mut("A1").set_value_number(2);
+        sheet.cell_mut("A2").set_value_number(3);
+        sheet.cell_mut("A3").set_value("text");
+        sheet.cell_mut("B1").set_formula("SUM(A1:A3)*Rate+A1^2");
+        sheet.cell_mut("B2").set_formula("IF(B1>5,\"big\",\"small\")&\"!\"");
+        sheet.cell_mut("B3").set_formula("IFERROR(A1/0,-1)");
+        sheet.cell_mut("B4").set_formula("ROUND(PMT(0.05/12,360,-200000),2)");
+        sheet.cell_mut("B5").set_formula("A3+1");
+        sheet.cell_mut("B6").set_formula("B6+1");
+        sheet.cell_mut("B7").set_formula("XLOOKUP(1,A1:A3,A1:A3)");
+
+        assert_eq!(calculate(&book, "B1"), Value::Number(6.5));
+        assert_eq!(calculate(&book, "B2"), Value::Text("big!".into()));
+        assert_eq!(calculate(&book, "B3"), Value::Number(-1.0));
+        assert_eq!(calculate(&book, "B4"), Value::Number(1073.64));
+        assert_eq!(calculate(&book, "B5"), Value::error("#VALUE!"));
+
+        let mut calculator = Calculator::new(&book);
+        assert!(calculator.cell(0, 2, 6).is_err());
+        assert!(calculator.cell(0, 2, 7).is_err());
+        calculator.set_input(0, 1, 1, 4.0);
+        assert_eq!(calculator.cell(0, 2, 1).unwrap(), Value::Number(19.5));
+    }
+}
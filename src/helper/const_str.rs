@@ -7,6 +7,7 @@ macro_rules! declare_const_strings {
 }
 
 declare_const_strings! {
+    CALC_CHAIN_NS        => "http://schemas.openxmlformats.org/officeDocument/2006/relationships/calcChain",
     CERTIFICATE_NS       => "http://schemas.microsoft.com/office/2006/keyEncryptor/certificate",
     CHART_NS             => "http://schemas.openxmlformats.org/officeDocument/2006/relationships/chart",
     CHART_TYPE           => "application/vnd.openxmlformats-officedocument.drawingml.chart+xml",
@@ -23,6 +24,8 @@ declare_const_strings! {
     CUSTOM_PROPS_NS      => "http://schemas.openxmlformats.org/officeDocument/2006/custom-properties",
     CUSTOM_PROPS_REL     => "http://schemas.openxmlformats.org/officeDocument/2006/relationships/custom-properties",
     CUSTOM_PROPS_TYPE    => "application/vnd.openxmlformats-officedocument.custom-properties+xml",
+    CTRL_PROP_NS         => "http://schemas.openxmlformats.org/officeDocument/2006/relationships/ctrlProp",
+    CTRL_PROP_TYPE       => "application/vnd.ms-excel.controlproperties+xml",
     CUSTOMUI_NS          => "http://schemas.microsoft.com/office/2006/relationships/ui/extensibility",
     DCMITYPE_NS          => "http://purl.org/dc/dcmitype/",
     DCORE_NS             => "http://purl.org/dc/elements/1.1/",
@@ -37,6 +40,9 @@ declare_const_strings! {
     ENCRYPTION_NS        => "http://schemas.microsoft.com/office/2006/encryption",
     EXCEL_MAIN_NS        => "http://schemas.microsoft.com/office/excel/2006/main",
     EXCEL_NS             => "urn:schemas-microsoft-com:office:excel",
+    EXTERNAL_LINK_NS     => "http://schemas.openxmlformats.org/officeDocument/2006/relationships/externalLink",
+    EXTERNAL_LINK_PATH_NS => "http://schemas.openxmlformats.org/officeDocument/2006/relationships/externalLinkPath",
+    EXTERNAL_LINK_TYPE   => "application/vnd.openxmlformats-officedocument.spreadsheetml.externalLink+xml",
     HYPERLINK_NS         => "http://schemas.openxmlformats.org/officeDocument/2006/relationships/hyperlink",
     IMAGE_NS             => "http://schemas.openxmlformats.org/officeDocument/2006/relationships/image",
     MC_NS                => "http://schemas.openxmlformats.org/markup-compatibility/2006",
@@ -46,6 +52,7 @@ declare_const_strings! {
     OLE_OBJECT_TYPE      => "application/vnd.openxmlformats-officedocument.oleObject",
     PACKAGE_NS           => "http://schemas.openxmlformats.org/officeDocument/2006/relationships/package",
     PASSWORD_NS          => "http://schemas.microsoft.com/office/2006/keyEncryptor/password",
+    PERSON_TYPE          => "application/vnd.ms-excel.person+xml",
     PIVOT_CACHE_DEF_NS   => "http://schemas.openxmlformats.org/officeDocument/2006/relationships/pivotCacheDefinition",
     PRINTER_SETTINGS_NS  => "http://schemas.openxmlformats.org/officeDocument/2006/relationships/printerSettings",
     PRNTR_SETTINGS_TYPE  => "application/vnd.openxmlformats-officedocument.spreadsheetml.printerSettings",
@@ -59,6 +66,12 @@ declare_const_strings! {
     SHEET_MS_MAIN_NS     => "http://schemas.microsoft.com/office/spreadsheetml/2009/9/main",
     SHEET_MS_REVISION_NS => "http://schemas.microsoft.com/office/spreadsheetml/2014/revision",
     SHEET_TYPE           => "application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml",
+    SHEET_METADATA_NS    => "http://schemas.openxmlformats.org/officeDocument/2006/relationships/sheetMetadata",
+    SHEET_METADATA_TYPE  => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheetMetadata+xml",
+    DYNAMIC_ARRAY_NS     => "http://schemas.microsoft.com/office/spreadsheetml/2017/dynamicarray",
+    DYNAMIC_ARRAY_EXT_URI => "{bdbb8cdc-fa1e-496e-a857-3c3f30c029c3}",
+    RICH_DATA_NS         => "http://schemas.microsoft.com/office/spreadsheetml/2017/richdata",
+    RICH_VALUE_EXT_URI   => "{3e2802c4-a4d2-4d8b-9148-e3be6c30e623}",
     SHEETML_AC_NS        => "http://schemas.microsoft.com/office/spreadsheetml/2009/9/ac",
     STYLES_NS            => "http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles",
     STYLES_TYPE          => "application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml",
@@ -67,6 +80,7 @@ declare_const_strings! {
     TABLE_TYPE           => "application/vnd.openxmlformats-officedocument.spreadsheetml.table+xml",
     PIVOT_TABLE_TYPE     => "application/vnd.openxmlformats-officedocument.spreadsheetml.pivotTable+xml",
     PIVOT_CACHE_DEF_TYPE => "application/vnd.openxmlformats-officedocument.spreadsheetml.pivotCacheDefinition+xml",
+    THREADED_COMMENTS_TYPE => "application/vnd.ms-excel.threadedcomments+xml",
     THEME_NS             => "http://schemas.openxmlformats.org/officeDocument/2006/relationships/theme",
     THEME_TYPE           => "application/vnd.openxmlformats-officedocument.theme+xml",
     VBA_PROJECT_NS       => "http://schemas.microsoft.com/office/2006/relationships/vbaProject",
@@ -85,10 +99,14 @@ declare_const_strings! {
     XPROPS_TYPE          => "application/vnd.openxmlformats-officedocument.extended-properties+xml",
     XSI_NS               => "http://www.w3.org/2001/XMLSchema-instance",
     PKG_CHARTS           => "xl/charts",
+    PKG_CTRL_PROPS       => "xl/ctrlProps",
     PKG_DRAWINGS         => "xl/drawings",
     PKG_DRAWINGS_RELS    => "xl/drawings/_rels/drawing",
     PKG_EMBEDDINGS       => "xl/embeddings",
+    PKG_EXTERNAL_LINKS   => "xl/externalLinks",
     PKG_MEDIA            => "xl/media",
+    PKG_METADATA         => "xl/metadata.xml",
+    PKG_RELS             => "_rels/.rels",
     PKG_PRNTR_SETTINGS   => "xl/printerSettings",
     PKG_SHARED_STRINGS   => "xl/sharedStrings.xml",
     PKG_SHEET            => "xl/worksheets/sheet",
@@ -8,6 +8,8 @@ use num_traits::cast;
 pub const CALENDAR_WINDOWS_1900: &str = "1900";
 pub const CALENDAR_MAC_1904: &str = "1904";
 pub const DEFAULT_TIMEZONE: &str = "UTC";
+/// The serial date of 9999-12-31, the last date of Excel.
+pub(crate) const MAX_SERIAL_DATE: f64 = 2_958_465.0;
 
 /// Converts an Excel timestamp to a [`NaiveDateTime`] object.
 ///
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use crate::{
     helper::{
         address::{
@@ -5,18 +7,30 @@ use crate::{
             split_address,
         },
         coordinate::{
+            CellCoordinates,
+            CellIndex,
             adjustment_insert_coordinate,
             adjustment_remove_coordinate,
             coordinate_from_index_with_lock,
             index_from_coordinate,
+            string_from_column_index,
+        },
+        formula_ast::{
+            FormulaExpr,
+            UnaryOperator,
+            parse_formula,
         },
+        function_catalog::function_arity,
         range::{
             get_join_range,
             get_split_range,
         },
         utils::compile_regex,
     },
-    structs::StringValue,
+    structs::{
+        StringValue,
+        XlsxError,
+    },
 };
 
 /// PARTLY BASED ON: */
@@ -129,6 +143,179 @@ pub const ERRORS: &[&str] = &[
     "#NULL!", "#DIV/0!", "#VALUE!", "#REF!", "#NAME?", "#NUM!", "#N/A",
 ];
 const COMPARATORS_MULTI: &[&str] = &[">=", "<=", "<>"];
+/// Functions newer than the file format, with the prefix Excel stores them
+/// with.
+const FUTURE_FUNCTIONS: &[(&str, &str)] = &[
+    ("ACOT", "_xlfn."),
+    ("ACOTH", "_xlfn."),
+    ("AGGREGATE", "_xlfn."),
+    ("ANCHORARRAY", "_xlfn."),
+    ("ARABIC", "_xlfn."),
+    ("ARRAYTOTEXT", "_xlfn."),
+    ("BASE", "_xlfn."),
+    ("BETA.DIST", "_xlfn."),
+    ("BETA.INV", "_xlfn."),
+    ("BINOM.DIST", "_xlfn."),
+    ("BINOM.DIST.RANGE", "_xlfn."),
+    ("BINOM.INV", "_xlfn."),
+    ("BITAND", "_xlfn."),
+    ("BITLSHIFT", "_xlfn."),
+    ("BITOR", "_xlfn."),
+    ("BITRSHIFT", "_xlfn."),
+    ("BITXOR", "_xlfn."),
+    ("BYCOL", "_xlfn."),
+    ("BYROW", "_xlfn."),
+    ("CEILING.MATH", "_xlfn."),
+    ("CEILING.PRECISE", "_xlfn."),
+    ("CHISQ.DIST", "_xlfn."),
+    ("CHISQ.DIST.RT", "_xlfn."),
+    ("CHISQ.INV", "_xlfn."),
+    ("CHISQ.INV.RT", "_xlfn."),
+    ("CHISQ.TEST", "_xlfn."),
+    ("CHOOSECOLS", "_xlfn."),
+    ("CHOOSEROWS", "_xlfn."),
+    ("COMBINA", "_xlfn."),
+    ("CONCAT", "_xlfn."),
+    ("CONFIDENCE.NORM", "_xlfn."),
+    ("CONFIDENCE.T", "_xlfn."),
+    ("COT", "_xlfn."),
+    ("COTH", "_xlfn."),
+    ("COVARIANCE.P", "_xlfn."),
+    ("COVARIANCE.S", "_xlfn."),
+    ("CSC", "_xlfn."),
+    ("CSCH", "_xlfn."),
+    ("DAYS", "_xlfn."),
+    ("DECIMAL", "_xlfn."),
+    ("DROP", "_xlfn."),
+    ("ENCODEURL", "_xlfn."),
+    ("ERF.PRECISE", "_xlfn."),
+    ("ERFC.PRECISE", "_xlfn."),
+    ("EXPAND", "_xlfn."),
+    ("EXPON.DIST", "_xlfn."),
+    ("F.DIST", "_xlfn."),
+    ("F.DIST.RT", "_xlfn."),
+    ("F.INV", "_xlfn."),
+    ("F.INV.RT", "_xlfn."),
+    ("F.TEST", "_xlfn."),
+    ("FIELDVALUE", "_xlfn."),
+    ("FILTER", "_xlfn._xlws."),
+    ("FILTERXML", "_xlfn."),
+    ("FLOOR.MATH", "_xlfn."),
+    ("FLOOR.PRECISE", "_xlfn."),
+    ("FORECAST.ETS", "_xlfn."),
+    ("FORECAST.ETS.CONFINT", "_xlfn."),
+    ("FORECAST.ETS.SEASONALITY", "_xlfn."),
+    ("FORECAST.ETS.STAT", "_xlfn."),
+    ("FORECAST.LINEAR", "_xlfn."),
+    ("FORMULATEXT", "_xlfn."),
+    ("GAMMA", "_xlfn."),
+    ("GAMMA.DIST", "_xlfn."),
+    ("GAMMA.INV", "_xlfn."),
+    ("GAMMALN.PRECISE", "_xlfn."),
+    ("GAUSS", "_xlfn."),
+    ("GROUPBY", "_xlfn."),
+    ("HSTACK", "_xlfn."),
+    ("HYPGEOM.DIST", "_xlfn."),
+    ("IFNA", "_xlfn."),
+    ("IFS", "_xlfn."),
+    ("IMAGE", "_xlfn."),
+    ("IMCOSH", "_xlfn."),
+    ("IMCOT", "_xlfn."),
+    ("IMCSC", "_xlfn."),
+    ("IMCSCH", "_xlfn."),
+    ("IMSEC", "_xlfn."),
+    ("IMSECH", "_xlfn."),
+    ("IMSINH", "_xlfn."),
+    ("IMTAN", "_xlfn."),
+    ("ISFORMULA", "_xlfn."),
+    ("ISOMITTED", "_xlfn."),
+    ("ISOWEEKNUM", "_xlfn."),
+    ("LAMBDA", "_xlfn."),
+    ("LET", "_xlfn."),
+    ("LOGNORM.DIST", "_xlfn."),
+    ("LOGNORM.INV", "_xlfn."),
+    ("MAKEARRAY", "_xlfn."),
+    ("MAP", "_xlfn."),
+    ("MAXIFS", "_xlfn."),
+    ("MINIFS", "_xlfn."),
+    ("MODE.MULT", "_xlfn."),
+    ("MODE.SNGL", "_xlfn."),
+    ("MUNIT", "_xlfn."),
+    ("NEGBINOM.DIST", "_xlfn."),
+    ("NETWORKDAYS.INTL", "_xlfn."),
+    ("NORM.DIST", "_xlfn."),
+    ("NORM.INV", "_xlfn."),
+    ("NORM.S.DIST", "_xlfn."),
+    ("NORM.S.INV", "_xlfn."),
+    ("NUMBERVALUE", "_xlfn."),
+    ("PDURATION", "_xlfn."),
+    ("PERCENTILE.EXC", "_xlfn."),
+    ("PERCENTILE.INC", "_xlfn."),
+    ("PERCENTOF", "_xlfn."),
+    ("PERCENTRANK.EXC", "_xlfn."),
+    ("PERCENTRANK.INC", "_xlfn."),
+    ("PERMUTATIONA", "_xlfn."),
+    ("PHI", "_xlfn."),
+    ("PIVOTBY", "_xlfn."),
+    ("POISSON.DIST", "_xlfn."),
+    ("QUARTILE.EXC", "_xlfn."),
+    ("QUARTILE.INC", "_xlfn."),
+    ("RANDARRAY", "_xlfn."),
+    ("RANK.AVG", "_xlfn."),
+    ("RANK.EQ", "_xlfn."),
+    ("REDUCE", "_xlfn."),
+    ("REGEXEXTRACT", "_xlfn."),
+    ("REGEXREPLACE", "_xlfn."),
+    ("REGEXTEST", "_xlfn."),
+    ("RRI", "_xlfn."),
+    ("SCAN", "_xlfn."),
+    ("SEC", "_xlfn."),
+    ("SECH", "_xlfn."),
+    ("SEQUENCE", "_xlfn."),
+    ("SHEET", "_xlfn."),
+    ("SHEETS", "_xlfn."),
+    ("SINGLE", "_xlfn."),
+    ("SKEW.P", "_xlfn."),
+    ("SORT", "_xlfn._xlws."),
+    ("SORTBY", "_xlfn."),
+    ("STDEV.P", "_xlfn."),
+    ("STDEV.S", "_xlfn."),
+    ("STOCKHISTORY", "_xlfn."),
+    ("SWITCH", "_xlfn."),
+    ("T.DIST", "_xlfn."),
+    ("T.DIST.2T", "_xlfn."),
+    ("T.DIST.RT", "_xlfn."),
+    ("T.INV", "_xlfn."),
+    ("T.INV.2T", "_xlfn."),
+    ("T.TEST", "_xlfn."),
+    ("TAKE", "_xlfn."),
+    ("TEXTAFTER", "_xlfn."),
+    ("TEXTBEFORE", "_xlfn."),
+    ("TEXTJOIN", "_xlfn."),
+    ("TEXTSPLIT", "_xlfn."),
+    ("TOCOL", "_xlfn."),
+    ("TOROW", "_xlfn."),
+    ("TRIMRANGE", "_xlfn."),
+    ("UNICHAR", "_xlfn."),
+    ("UNICODE", "_xlfn."),
+    ("UNIQUE", "_xlfn."),
+    ("VALUETOTEXT", "_xlfn."),
+    ("VAR.P", "_xlfn."),
+    ("VAR.S", "_xlfn."),
+    ("VSTACK", "_xlfn."),
+    ("WEBSERVICE", "_xlfn."),
+    ("WEIBULL.DIST", "_xlfn."),
+    ("WORKDAY.INTL", "_xlfn."),
+    ("WRAPCOLS", "_xlfn."),
+    ("WRAPROWS", "_xlfn."),
+    ("XLOOKUP", "_xlfn."),
+    ("XMATCH", "_xlfn."),
+    ("XOR", "_xlfn."),
+    ("Z.TEST", "_xlfn."),
+];
+
+const MAX_COLUMN: u32 = 16_384;
+const MAX_ROW: u32 = 1_048_576;
 
 macro_rules! token {
     ($value:expr, $typ:expr, $sub:expr) => {{
@@ -873,6 +1060,586 @@ pub(crate) fn render(formula_token_list: &[FormulaToken]) -> String {
     result
 }
 
+/// Replace the range operands of a formula written without the leading `=`.
+/// The formula is returned as is when `replacer` changes nothing.
+pub(crate) fn replace_range_operands<F>(formula: &str, mut replacer: F) -> String
+where
+    F: FnMut(&str) -> Option<String>,
+{
+    let mut tokens = parse_to_tokens(format!("={formula}"));
+    let mut is_changed = false;
+    for token in &mut tokens {
+        if token.get_token_type() == &FormulaTokenTypes::Operand
+            && token.get_token_sub_type() == &FormulaTokenSubTypes::Range
+        {
+            if let Some(value) = replacer(token.get_value()) {
+                token.set_value(value);
+                is_changed = true;
+            }
+        }
+    }
+    if is_changed {
+        render(&tokens)
+    } else {
+        formula.to_string()
+    }
+}
+
+/// (This method is crate only.)
+/// Write a formula the way Excel stores it.
+/// * The functions newer than the file format get their prefix.
+///   ex) "SORT(A1:A3)" becomes "`_xlfn._xlws.SORT(A1:A3)`".
+/// * The parameters of `LET` and `LAMBDA` get the `_xlpm.` prefix.
+///   ex) "LET(x,1,x+1)" becomes "`_xlfn.LET(_xlpm.x,1,_xlpm.x+1)`".
+/// * `@` and the `#` of a spilled array become functions.
+///   ex) "SUM(A1#)" becomes "`SUM(_xlfn.ANCHORARRAY(A1))`", "@A1:A3" becomes
+///   "`_xlfn.SINGLE(A1:A3)`".
+///
+/// The formula is written without the leading `=` and returned as is when
+/// nothing changes. Otherwise it is printed from its parsed tree, without
+/// its whitespace.
+pub(crate) fn add_future_function_prefixes(formula: &str) -> String {
+    match parse_formula(formula) {
+        Ok(mut expr) => stored_formula(formula, &mut expr),
+        // A formula the parser does not understand only gets the prefixes.
+        Err(_) => add_function_prefixes(formula),
+    }
+}
+
+fn stored_formula(formula: &str, expr: &mut FormulaExpr) -> String {
+    if to_stored_form(expr, &mut Vec::new()) {
+        expr.to_string()
+    } else {
+        formula.to_string()
+    }
+}
+
+/// Rewrite a parsed formula as `add_future_function_prefixes` describes.
+/// `params` holds the parameters of `LET` and `LAMBDA` in scope.
+/// Returns whether anything changed.
+fn to_stored_form(expr: &mut FormulaExpr, params: &mut Vec<String>) -> bool {
+    let is_param = |params: &[String], name: &str| params.iter().any(|v| v.eq_ignore_ascii_case(name));
+    let mut is_changed = false;
+    match expr {
+        FormulaExpr::Function { name, args } => {
+            let base_name = function_base_name(name);
+            let upper_name = name.to_uppercase();
+            if is_param(params, name) {
+                *name = format!("_xlpm.{name}");
+                is_changed = true;
+            } else if let Some((_, prefix)) = FUTURE_FUNCTIONS.iter().find(|(x, _)| *x == upper_name) {
+                *name = format!("{prefix}{upper_name}");
+                is_changed = true;
+            }
+            // `LET(name1,value1,...,calculation)`, `LAMBDA(param1,...,calculation)`
+            let scope = params.len();
+            let count = args.len();
+            for (index, arg) in args.iter_mut().enumerate() {
+                let is_param_position = index + 1 < count
+                    && match base_name.as_str() {
+                        "LET" => index % 2 == 0,
+                        "LAMBDA" => true,
+                        _ => false,
+                    };
+                match arg {
+                    FormulaExpr::Name(name)
+                        if is_param_position && name.sheet().is_none() && !name.is_external() =>
+                    {
+                        let param = name.name().to_string();
+                        if param.get(..6).is_some_and(|v| v.eq_ignore_ascii_case("_xlpm.")) {
+                            params.push(param[6..].to_string());
+                        } else {
+                            name.set_name(format!("_xlpm.{param}"));
+                            params.push(param);
+                            is_changed = true;
+                        }
+                    }
+                    _ => is_changed |= to_stored_form(arg, params),
+                }
+            }
+            params.truncate(scope);
+        }
+        FormulaExpr::Name(name)
+            if name.sheet().is_none() && !name.is_external() && is_param(params, name.name()) =>
+        {
+            let value = format!("_xlpm.{}", name.name());
+            name.set_name(value);
+            is_changed = true;
+        }
+        FormulaExpr::Call { function, args } => {
+            is_changed |= to_stored_form(function, params);
+            for arg in args {
+                is_changed |= to_stored_form(arg, params);
+            }
+        }
+        FormulaExpr::Unary { operator, operand } => {
+            is_changed |= to_stored_form(operand, params);
+            let name = match operator {
+                UnaryOperator::ImplicitIntersection => "_xlfn.SINGLE",
+                UnaryOperator::Spill => "_xlfn.ANCHORARRAY",
+                _ => return is_changed,
+            };
+            let operand = std::mem::replace(&mut **operand, FormulaExpr::Missing);
+            *expr = FormulaExpr::Function {
+                name: name.to_string(),
+                args: vec![operand],
+            };
+            is_changed = true;
+        }
+        FormulaExpr::Binary { left, right, .. } => {
+            is_changed |= to_stored_form(left, params);
+            is_changed |= to_stored_form(right, params);
+        }
+        FormulaExpr::Paren(inner) => is_changed |= to_stored_form(inner, params),
+        _ => {}
+    }
+    is_changed
+}
+
+/// Prefix the functions newer than the file format in the tokens of a
+/// formula.
+fn add_function_prefixes(formula: &str) -> String {
+    let mut tokens = parse_to_tokens(format!("={formula}"));
+    let mut is_changed = false;
+    for token in &mut tokens {
+        if token.get_token_type() != &FormulaTokenTypes::Function
+            || token.get_token_sub_type() != &FormulaTokenSubTypes::Start
+        {
+            continue;
+        }
+        let name = token.get_value().to_uppercase();
+        if let Some((_, prefix)) = FUTURE_FUNCTIONS.iter().find(|(x, _)| *x == name) {
+            token.set_value(format!("{prefix}{name}"));
+            is_changed = true;
+        }
+    }
+    if is_changed {
+        render(&tokens)
+    } else {
+        formula.to_string()
+    }
+}
+
+/// (This method is crate only.)
+/// Check that a formula is well formed before it is set to a cell, and
+/// write it the way Excel stores it, as `add_future_function_prefixes` does.
+///
+/// Parentheses, braces, quotes and operators must be balanced, functions
+/// must exist and get a number of arguments they accept, and references
+/// must point at existing sheets and cells or at defined names.
+/// # Arguments
+/// * `formula` - ex) "SUM(A1:A3)", "=XLOOKUP(1,A:A,B:B)"
+/// * `is_sheet` - Whether a sheet of this name exists.
+/// * `is_name` - Whether a defined name exists, with the sheet it is
+///   qualified with.
+/// # Return value
+/// * `Result<String, XlsxError>` - The formula without the leading `=`.
+pub(crate) fn check_formula<F, G>(
+    formula: &str,
+    is_sheet: F,
+    is_name: G,
+) -> Result<String, XlsxError>
+where
+    F: Fn(&str) -> bool,
+    G: Fn(Option<&str>, &str) -> bool,
+{
+    let formula = formula.strip_prefix('=').unwrap_or(formula);
+    if formula.trim().is_empty() {
+        return Err(XlsxError::InvalidArgument("the formula is empty".to_string()));
+    }
+    check_formula_brackets(formula)?;
+    let mut expr = parse_formula(formula)?;
+
+    // Parameters of LET and LAMBDA are names and functions of their own,
+    // so whatever is inside those calls is not looked up.
+    let mut in_lambda: HashSet<*const FormulaExpr> = HashSet::new();
+    expr.walk(&mut |node| {
+        if let FormulaExpr::Function { name, args } = node {
+            if matches!(function_base_name(name).as_str(), "LET" | "LAMBDA") {
+                for arg in args {
+                    arg.walk(&mut |inner| {
+                        in_lambda.insert(std::ptr::from_ref(inner));
+                    });
+                }
+            }
+        }
+    });
+
+    let mut result = Ok(());
+    expr.walk(&mut |node| {
+        if result.is_ok() {
+            let is_in_lambda = in_lambda.contains(&std::ptr::from_ref(node));
+            result = check_formula_node(node, is_in_lambda, &is_sheet, &is_name);
+        }
+    });
+    result?;
+    Ok(stored_formula(formula, &mut expr))
+}
+
+/// Check that the parentheses, braces, brackets and quotes of a formula are
+/// balanced.
+fn check_formula_brackets(formula: &str) -> Result<(), XlsxError> {
+    let mut opened: Vec<char> = Vec::new();
+    let mut in_string = false;
+    let mut in_sheet_name = false;
+    let mut bracket_depth = 0usize;
+    for c in formula.chars() {
+        if in_string {
+            in_string = c != QUOTE_DOUBLE;
+            continue;
+        }
+        if in_sheet_name {
+            in_sheet_name = c != QUOTE_SINGLE;
+            continue;
+        }
+        if bracket_depth > 0 {
+            match c {
+                BRACKET_OPEN => bracket_depth += 1,
+                BRACKET_CLOSE => bracket_depth -= 1,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            QUOTE_DOUBLE => in_string = true,
+            QUOTE_SINGLE => in_sheet_name = true,
+            BRACKET_OPEN => bracket_depth = 1,
+            BRACKET_CLOSE => {
+                return Err(XlsxError::InvalidArgument(
+                    "\"]\" has no matching \"[\"".to_string(),
+                ));
+            }
+            PAREN_OPEN | BRACE_OPEN => opened.push(c),
+            PAREN_CLOSE | BRACE_CLOSE => {
+                let expected = if c == PAREN_CLOSE { PAREN_OPEN } else { BRACE_OPEN };
+                if opened.pop() != Some(expected) {
+                    return Err(XlsxError::InvalidArgument(format!(
+                        "\"{c}\" has no matching \"{expected}\""
+                    )));
+                }
+            }
+            _ => {}
+        }
+    }
+    if in_string {
+        return Err(XlsxError::InvalidArgument(
+            "a text is not closed with '\"'".to_string(),
+        ));
+    }
+    if in_sheet_name {
+        return Err(XlsxError::InvalidArgument(
+            "a sheet name is not closed with \"'\"".to_string(),
+        ));
+    }
+    if bracket_depth > 0 {
+        return Err(XlsxError::InvalidArgument("\"[\" is not closed".to_string()));
+    }
+    if let Some(c) = opened.pop() {
+        return Err(XlsxError::InvalidArgument(format!("\"{c}\" is not closed")));
+    }
+    Ok(())
+}
+
+/// The name of a function without the prefixes of the newer functions.
+/// ex) "_xlfn._xlws.SORT" becomes "SORT".
+fn function_base_name(name: &str) -> String {
+    let mut name = name.to_uppercase();
+    while let Some(v) = name
+        .strip_prefix("_XLFN.")
+        .or_else(|| name.strip_prefix("_XLWS."))
+    {
+        name = v.to_string();
+    }
+    name
+}
+
+/// Check one node of a parsed formula, without the nodes below it.
+fn check_formula_node<F, G>(
+    node: &FormulaExpr,
+    in_lambda: bool,
+    is_sheet: &F,
+    is_name: &G,
+) -> Result<(), XlsxError>
+where
+    F: Fn(&str) -> bool,
+    G: Fn(Option<&str>, &str) -> bool,
+{
+    match node {
+        FormulaExpr::Function { name, args } => {
+            check_function(name, args.len(), in_lambda, is_name)
+        }
+        FormulaExpr::Reference(reference) if !reference.is_external() => {
+            // "Sheet1:Sheet3!A1" refers to the sheets from Sheet1 to Sheet3.
+            for name in [reference.sheet(), reference.last_sheet()].into_iter().flatten() {
+                check_sheet(name, is_sheet)?;
+            }
+            Ok(())
+        }
+        FormulaExpr::Name(name) if !name.is_external() => {
+            if let Some(sheet_name) = name.sheet() {
+                check_sheet(sheet_name, is_sheet)?;
+            } else if in_lambda {
+                return Ok(());
+            }
+            if !is_name(name.sheet(), name.name()) {
+                return Err(XlsxError::InvalidArgument(format!(
+                    "\"{name}\" is not a valid reference or defined name"
+                )));
+            }
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Check that a called function exists and gets a number of arguments it
+/// accepts.
+/// Add-in functions and defined names called as `LAMBDA` functions are
+/// accepted with any number of arguments.
+fn check_function<G>(
+    name: &str,
+    count: usize,
+    in_lambda: bool,
+    is_name: &G,
+) -> Result<(), XlsxError>
+where
+    G: Fn(Option<&str>, &str) -> bool,
+{
+    let name = function_base_name(name);
+    let Some((min, max)) = function_arity(&name) else {
+        let is_known = matches!(name.as_str(), "ARRAY" | "ARRAYROW")
+            || name.starts_with("_XLL.")
+            || in_lambda
+            || is_name(None, &name);
+        if !is_known {
+            return Err(XlsxError::InvalidArgument(format!(
+                "\"{name}\" is not a function"
+            )));
+        }
+        return Ok(());
+    };
+    if count < min || count > max {
+        let expected = match (min, max) {
+            (min, max) if min == max => format!("{min}"),
+            (min, crate::helper::function_catalog::MAX_ARGUMENTS) => format!("at least {min}"),
+            (min, max) => format!("{min} to {max}"),
+        };
+        return Err(XlsxError::InvalidArgument(format!(
+            "{name} takes {expected} arguments, not {count}"
+        )));
+    }
+    Ok(())
+}
+
+fn check_sheet<F>(name: &str, is_sheet: &F) -> Result<(), XlsxError>
+where
+    F: Fn(&str) -> bool,
+{
+    if !is_sheet(name) {
+        return Err(XlsxError::InvalidArgument(format!(
+            "the sheet \"{name}\" does not exist"
+        )));
+    }
+    Ok(())
+}
+
+/// Convert an A1 style formula to R1C1 style, relative to `base_cell`.
+/// References without `$` become offsets from `base_cell`, locked ones stay
+/// absolute.
+/// # Arguments
+/// * `formula` - ex) "SUM(A1:A3)*$B$1"
+/// * `base_cell` - Cell the formula is in. ex) "B4", (2, 4)
+/// # Examples
+/// ```
+/// use umya_spreadsheet::helper::formula::to_r1c1;
+/// assert_eq!(to_r1c1("SUM(A1:A3)*$B$1", "B4"), "SUM(R[-3]C[-1]:R[-1]C[-1])*R1C2");
+/// assert_eq!(to_r1c1("Sheet2!B:B", "B4"), "Sheet2!C:C");
+/// ```
+pub fn to_r1c1<T>(formula: &str, base_cell: T) -> String
+where
+    T: Into<CellCoordinates>,
+{
+    let base = base_cell.into();
+    replace_formula_operands(formula, |range| {
+        let parts: Vec<&str> = range.split(':').collect();
+        let indexes: Vec<CellIndex> = parts
+            .iter()
+            .map(|part| index_from_coordinate(part.to_uppercase()))
+            .collect();
+        let is_cell = |index: &CellIndex| index.0.is_some() && index.1.is_some();
+        let is_column = |index: &CellIndex| index.0.is_some() && index.1.is_none();
+        let is_row = |index: &CellIndex| index.0.is_none() && index.1.is_some();
+        let is_pair = parts.len() == 2
+            && (indexes.iter().all(is_column) || indexes.iter().all(is_row));
+        if !(indexes.iter().all(is_cell) || is_pair) {
+            return None;
+        }
+        let converted: Vec<String> = indexes
+            .iter()
+            .map(|(col, row, is_lock_col, is_lock_row)| {
+                let mut result = String::new();
+                if let Some(row) = row {
+                    result.push_str(&r1c1_part(
+                        'R',
+                        *row,
+                        base.row,
+                        is_lock_row.unwrap_or(false),
+                    ));
+                }
+                if let Some(col) = col {
+                    result.push_str(&r1c1_part(
+                        'C',
+                        *col,
+                        base.col,
+                        is_lock_col.unwrap_or(false),
+                    ));
+                }
+                result
+            })
+            .collect();
+        Some(converted.join(":"))
+    })
+}
+
+/// Convert an R1C1 style formula to A1 style, relative to `base_cell`.
+/// A reference that falls outside the sheet becomes `#REF!`.
+/// # Arguments
+/// * `formula` - ex) "SUM(R[-3]C:R[-1]C)*R1C2"
+/// * `base_cell` - Cell the formula is in. ex) "B4", (2, 4)
+/// # Examples
+/// ```
+/// use umya_spreadsheet::helper::formula::from_r1c1;
+/// assert_eq!(from_r1c1("SUM(R[-3]C:R[-1]C)*R1C2", "B4"), "SUM(B1:B3)*$B$1");
+/// assert_eq!(from_r1c1("'My Sheet'!R[1]C[-1]+R2", "B4"), "'My Sheet'!A5+$2:$2");
+/// ```
+pub fn from_r1c1<T>(formula: &str, base_cell: T) -> String
+where
+    T: Into<CellCoordinates>,
+{
+    let base = base_cell.into();
+    replace_formula_operands(formula, |range| {
+        let parts = range
+            .split(':')
+            .map(parse_r1c1_part)
+            .collect::<Option<Vec<_>>>()?;
+        let is_cells = parts.iter().all(|(row, col)| row.is_some() && col.is_some());
+        let is_rows = parts.iter().all(|(row, col)| row.is_some() && col.is_none());
+        let is_columns = parts.iter().all(|(row, col)| row.is_none() && col.is_some());
+        if !(is_cells || ((is_rows || is_columns) && parts.len() <= 2)) {
+            return None;
+        }
+        let lock = |is_lock: bool| if is_lock { "$" } else { "" };
+        let mut converted = Vec::new();
+        for (row, col) in &parts {
+            let row = row.as_ref().map(|index| index.resolve(base.row, MAX_ROW));
+            let col = col.as_ref().map(|index| index.resolve(base.col, MAX_COLUMN));
+            converted.push(match (row, col) {
+                (Some(Some((row, is_lock_row))), Some(Some((col, is_lock_col)))) => {
+                    coordinate_from_index_with_lock(col, row, is_lock_col, is_lock_row)
+                }
+                (Some(Some((row, is_lock))), None) => format!("{}{row}", lock(is_lock)),
+                (None, Some(Some((col, is_lock)))) => {
+                    format!("{}{}", lock(is_lock), string_from_column_index(col))
+                }
+                _ => return Some("#REF!".to_string()),
+            });
+        }
+        if converted.len() == 1 && !is_cells {
+            // a whole row or column is written as a range in A1 style
+            converted.push(converted[0].clone());
+        }
+        Some(converted.join(":"))
+    })
+}
+
+/// Row or column of an R1C1 reference.
+enum R1C1Index {
+    /// ex) `R2`
+    Absolute(u32),
+    /// ex) `R[-1]`, `R`
+    Relative(i64),
+}
+
+impl R1C1Index {
+    /// The index and whether it is absolute, or `None` outside of the sheet.
+    fn resolve(&self, base: u32, max: u32) -> Option<(u32, bool)> {
+        match self {
+            Self::Absolute(index) => (1..=max).contains(index).then_some((*index, true)),
+            Self::Relative(offset) => {
+                let index = i64::from(base) + offset;
+                (1..=i64::from(max))
+                    .contains(&index)
+                    .then(|| (num_traits::cast(index).unwrap(), false))
+            }
+        }
+    }
+}
+
+/// ex) "R[-1]C2" -> (row, column), "R3" -> (row, None), "C" -> (None, column)
+fn parse_r1c1_part(part: &str) -> Option<(Option<R1C1Index>, Option<R1C1Index>)> {
+    let (row, rest) = match part.strip_prefix('R') {
+        Some(rest) => {
+            let (index, rest) = parse_r1c1_index(rest)?;
+            (Some(index), rest)
+        }
+        None => (None, part),
+    };
+    let (col, rest) = match rest.strip_prefix('C') {
+        Some(rest) => {
+            let (index, rest) = parse_r1c1_index(rest)?;
+            (Some(index), rest)
+        }
+        None => (None, rest),
+    };
+    (rest.is_empty() && (row.is_some() || col.is_some())).then_some((row, col))
+}
+
+fn parse_r1c1_index(text: &str) -> Option<(R1C1Index, &str)> {
+    if let Some(rest) = text.strip_prefix('[') {
+        let (offset, rest) = rest.split_once(']')?;
+        return Some((R1C1Index::Relative(offset.parse().ok()?), rest));
+    }
+    let end = text
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(text.len());
+    if end == 0 {
+        return Some((R1C1Index::Relative(0), text));
+    }
+    Some((R1C1Index::Absolute(text[..end].parse().ok()?), &text[end..]))
+}
+
+/// ex) ('R', 3, 4, false) -> "R[-1]"
+fn r1c1_part(marker: char, index: u32, base: u32, is_lock: bool) -> String {
+    if is_lock {
+        return format!("{marker}{index}");
+    }
+    match i64::from(index) - i64::from(base) {
+        0 => marker.to_string(),
+        offset => format!("{marker}[{offset}]"),
+    }
+}
+
+/// Replace the range part of the range operands, keeping the sheet name and a
+/// leading `=`.
+fn replace_formula_operands<F>(formula: &str, mut replacer: F) -> String
+where
+    F: FnMut(&str) -> Option<String>,
+{
+    let (equal, body) = formula
+        .strip_prefix('=')
+        .map_or(("", formula), |rest| ("=", rest));
+    let body = replace_range_operands(body, |operand| {
+        let (sheet, range) = operand.rsplit_once('!').unwrap_or(("", operand));
+        let range = replacer(range)?;
+        Some(if sheet.is_empty() {
+            range
+        } else {
+            format!("{sheet}!{range}")
+        })
+    });
+    format!("{equal}{body}")
+}
+
 pub fn adjustment_formula_coordinate(
     token_list: &mut [FormulaToken],
     offset_col_num: i32,
@@ -929,6 +1696,19 @@ pub fn adjustment_formula_coordinate(
     }
 }
 
+/// (This method is crate only.)
+/// Render `token_list` as it reads from the cell `(offset_col_num,
+/// offset_row_num)` away, as a shared or filled formula does.
+pub(crate) fn translate_formula(
+    token_list: &[FormulaToken],
+    offset_col_num: i32,
+    offset_row_num: i32,
+) -> String {
+    let mut token_list = token_list.to_vec();
+    adjustment_formula_coordinate(&mut token_list, offset_col_num, offset_row_num);
+    render(&token_list)
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn adjustment_insert_formula_coordinate(
     token_list: &mut [FormulaToken],
@@ -1082,6 +1862,32 @@ mod tests {
     }
 }
 
+#[cfg(test)]
+mod r1c1_tests {
+    use super::*;
+
+    #[test]
+    fn to_r1c1_test() {
+        assert_eq!(to_r1c1("=A1+$B$2", "C3"), "=R[-2]C[-2]+R2C2");
+        assert_eq!(to_r1c1("SUM($A1:A$3)", "B2"), "SUM(R[-1]C1:R3C[-1])");
+        assert_eq!(to_r1c1("SUM(C:$D)+SUM(2:$4)", "B2"), "SUM(C[1]:C4)+SUM(R:R4)");
+        assert_eq!(to_r1c1("'My Sheet'!B2*Rate", "B2"), "'My Sheet'!RC*Rate");
+        assert_eq!(to_r1c1("\"A1\"&TRUE", "B2"), "\"A1\"&TRUE");
+    }
+
+    #[test]
+    fn from_r1c1_test() {
+        assert_eq!(from_r1c1("=R[-2]C[-2]+R2C2", "C3"), "=A1+$B$2");
+        assert_eq!(from_r1c1("SUM(R[-1]C1:R3C[-1])", "B2"), "SUM($A1:A$3)");
+        assert_eq!(from_r1c1("SUM(C[1]:C4)+SUM(R:R4)", "B2"), "SUM(C:$D)+SUM(2:$4)");
+        assert_eq!(from_r1c1("SUM(C)+R[1]", "B2"), "SUM(B:B)+3:3");
+        assert_eq!(from_r1c1("Sheet2!R[-5]C*Rate", "B2"), "Sheet2!#REF!*Rate");
+        for formula in ["IF(A1>$B$1,Sheet2!C3:D4,\"R1C1\")", "SUM($A:B)*2:$3"] {
+            assert_eq!(from_r1c1(&to_r1c1(formula, "D10"), "D10"), formula);
+        }
+    }
+}
+
 #[cfg(test)]
 mod shared_formula_quoted_sheet_tests {
     use super::*;
@@ -1106,3 +1912,98 @@ mod shared_formula_quoted_sheet_tests {
         );
     }
 }
+
+#[cfg(test)]
+mod check_formula_tests {
+    use super::*;
+
+    fn check(formula: &str) -> Result<String, XlsxError> {
+        check_formula(
+            formula,
+            |sheet| sheet == "Sheet1" || sheet == "My Sheet",
+            |_, name| name.eq_ignore_ascii_case("Rate"),
+        )
+    }
+
+    #[test]
+    fn valid_formulas() {
+        assert_eq!(check("=SUM(A1:A3)*Rate").unwrap(), "SUM(A1:A3)*Rate");
+        assert_eq!(check("XLOOKUP(1,A:A,B:B)").unwrap(), "_xlfn.XLOOKUP(1,A:A,B:B)");
+        assert_eq!(
+            check("CONCAT(\"a(\",'My Sheet'!B2)").unwrap(),
+            "_xlfn.CONCAT(\"a(\",'My Sheet'!B2)"
+        );
+        assert_eq!(check("_xlfn.IFS(A1>0,1,TRUE,0)").unwrap(), "_xlfn.IFS(A1>0,1,TRUE,0)");
+        for formula in [
+            "IF(A1,,)",
+            "-A1%+(1)",
+            "NOW()",
+            "SUM({1,2;3,4})",
+            "Sheet1!$A$1:$B$2",
+            "SUM(Sheet1:Sheet1!A1)",
+            "SUM(2:3)",
+            "Table1[Col]",
+            "[1]Sheet9!A1",
+            "LET(x,1,x+1)",
+            "A1:INDEX(B:B,2)",
+            "A1:A3 A2:B2",
+            "SUM(A1:A3 A2:B2)",
+            "(A1:A3,C1:C3)",
+            "SUM((A1:A3,C1:C3),1)",
+            "ROUND((A1,B1) A1,0)",
+        ] {
+            assert!(check(formula).is_ok(), "{formula}");
+        }
+    }
+
+    #[test]
+    fn stored_form() {
+        for (formula, expected) in [
+            ("LET(x,1,x+1)", "_xlfn.LET(_xlpm.x,1,_xlpm.x+1)"),
+            ("LET(x,Rate,y,x*2,y*Rate)", "_xlfn.LET(_xlpm.x,Rate,_xlpm.y,_xlpm.x*2,_xlpm.y*Rate)"),
+            ("LET(x,1,LAMBDA(y,x+y)(2))", "_xlfn.LET(_xlpm.x,1,_xlfn.LAMBDA(_xlpm.y,_xlpm.x+_xlpm.y)(2))"),
+            ("LAMBDA(x,x+1)(3)", "_xlfn.LAMBDA(_xlpm.x,_xlpm.x+1)(3)"),
+            ("_xlfn.LET(_xlpm.x,1,_xlpm.x+1)", "_xlfn.LET(_xlpm.x,1,_xlpm.x+1)"),
+            ("SUM(A1#)", "SUM(_xlfn.ANCHORARRAY(A1))"),
+            ("Sheet1!A1#*2", "_xlfn.ANCHORARRAY(Sheet1!A1)*2"),
+            ("@A1:A3", "_xlfn.SINGLE(A1:A3)"),
+            ("-@A1:A3+1", "-_xlfn.SINGLE(A1:A3)+1"),
+            ("SUM( A1:A3 )", "SUM( A1:A3 )"),
+        ] {
+            assert_eq!(check(formula).unwrap(), expected, "{formula}");
+        }
+        assert!(check("LAMBDA(x,x+1)(3").is_err());
+        assert!(check("A1#A2").is_err());
+    }
+
+    #[test]
+    fn invalid_formulas() {
+        for formula in [
+            "",
+            "=",
+            "SUM(A1",
+            "SUM(A1))",
+            "\"abc",
+            "{1,2",
+            "1+",
+            "*2",
+            "1+*2",
+            "SUM(1,)+",
+            "SUMM(A1)",
+            "ROUND(A1)",
+            "PI(1)",
+            "IF()",
+            "Sheet9!A1",
+            "'My Sheet:Sheet9'!A1",
+            "XFE1",
+            "A0",
+            "A1:B",
+            "Unknown",
+            "A1:A3 Sheet9!A2",
+            "SUM((A1,Unknown))",
+            "ROUND((A1,B1))",
+        ] {
+            assert!(check(formula).is_err(), "{formula}");
+        }
+    }
+}
@@ -0,0 +1,1515 @@
+//! Formula parser building a syntax tree, and the printer back to text.
+
+use std::fmt;
+
+use crate::{
+    XlsxError,
+    helper::{
+        address::sheet_name_needs_quoting,
+        coordinate::column_index_from_string,
+    },
+    structs::{
+        ColumnReference,
+        Coordinate,
+        RowReference,
+    },
+};
+
+const MAX_COLUMN: u32 = 16_384;
+const MAX_ROW: u32 = 1_048_576;
+// Nesting levels of a formula Excel accepts.
+const MAX_DEPTH: usize = 64;
+
+const ERROR_LITERALS: &[&str] = &[
+    "#NULL!",
+    "#DIV/0!",
+    "#VALUE!",
+    "#REF!",
+    "#NAME?",
+    "#NUM!",
+    "#N/A",
+    "#GETTING_DATA",
+    "#SPILL!",
+    "#CALC!",
+    "#FIELD!",
+    "#BLOCKED!",
+    "#CONNECT!",
+    "#BUSY!",
+    "#UNKNOWN!",
+];
+
+/// A node of a parsed formula.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FormulaExpr {
+    /// ex) `1.5`
+    Number(f64),
+    /// ex) `"text"`, held without the quotes.
+    Text(String),
+    /// `TRUE` or `FALSE`
+    Boolean(bool),
+    /// ex) `#N/A`
+    Error(String),
+    /// An omitted function argument. ex) the second one of `IF(A1,,1)`
+    Missing,
+    /// ex) `{1,2;3,4}`, row by row.
+    Array(Vec<Vec<FormulaExpr>>),
+    /// ex) `Sheet1!$A$1:B2`
+    Reference(FormulaReference),
+    /// A defined name. ex) `Sales`, `Sheet1!Sales`
+    Name(FormulaName),
+    /// ex) `Table1[[#This Row],[Price]]`
+    StructuredReference(StructuredReference),
+    /// ex) `SUM(A1:A3,2)`
+    Function {
+        name: String,
+        args: Vec<FormulaExpr>,
+    },
+    /// A function given by a formula, called with the arguments after it.
+    /// ex) `LAMBDA(x,x+1)(3)`
+    Call {
+        function: Box<FormulaExpr>,
+        args:     Vec<FormulaExpr>,
+    },
+    /// ex) `-A1`, `A1%`
+    Unary {
+        operator: UnaryOperator,
+        operand:  Box<FormulaExpr>,
+    },
+    /// ex) `A1+1`, `A1:B2 B1:C3`
+    Binary {
+        operator: BinaryOperator,
+        left:     Box<FormulaExpr>,
+        right:    Box<FormulaExpr>,
+    },
+    /// ex) `(A1+1)`
+    Paren(Box<FormulaExpr>),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnaryOperator {
+    /// `+`
+    Plus,
+    /// `-`
+    Minus,
+    /// `%`, written after the operand.
+    Percent,
+    /// `@`, the implicit intersection. ex) `@A1:A3`
+    ImplicitIntersection,
+    /// `#`, written after the operand: the whole array spilled from a cell.
+    /// ex) `A1#`
+    Spill,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BinaryOperator {
+    /// `:`
+    Range,
+    /// ` `
+    Intersection,
+    /// `,`
+    Union,
+    /// `^`
+    Power,
+    /// `*`
+    Multiply,
+    /// `/`
+    Divide,
+    /// `+`
+    Add,
+    /// `-`
+    Subtract,
+    /// `&`
+    Concat,
+    /// `=`
+    Equal,
+    /// `<>`
+    NotEqual,
+    /// `<`
+    Less,
+    /// `<=`
+    LessEqual,
+    /// `>`
+    Greater,
+    /// `>=`
+    GreaterEqual,
+}
+
+impl BinaryOperator {
+    #[must_use]
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::Range => ":",
+            Self::Intersection => " ",
+            Self::Union => ",",
+            Self::Power => "^",
+            Self::Multiply => "*",
+            Self::Divide => "/",
+            Self::Add => "+",
+            Self::Subtract => "-",
+            Self::Concat => "&",
+            Self::Equal => "=",
+            Self::NotEqual => "<>",
+            Self::Less => "<",
+            Self::LessEqual => "<=",
+            Self::Greater => ">",
+            Self::GreaterEqual => ">=",
+        }
+    }
+
+    fn precedence(self) -> u8 {
+        match self {
+            Self::Range => 10,
+            Self::Intersection => 9,
+            Self::Union => 8,
+            Self::Power => 5,
+            Self::Multiply | Self::Divide => 4,
+            Self::Add | Self::Subtract => 3,
+            Self::Concat => 2,
+            Self::Equal
+            | Self::NotEqual
+            | Self::Less
+            | Self::LessEqual
+            | Self::Greater
+            | Self::GreaterEqual => 1,
+        }
+    }
+}
+
+/// The cells a reference points to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReferenceArea {
+    /// ex) `$A$1`
+    Cell(Coordinate),
+    /// ex) `A1:B2`
+    Range(Coordinate, Coordinate),
+    /// ex) `A:$C`
+    Columns(ColumnReference, ColumnReference),
+    /// ex) `1:3`
+    Rows(RowReference, RowReference),
+}
+
+impl fmt::Display for ReferenceArea {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cell(cell) => write!(f, "{cell}"),
+            Self::Range(first, last) => write!(f, "{first}:{last}"),
+            Self::Columns(first, last) => {
+                write!(f, "{}:{}", first.coordinate(), last.coordinate())
+            }
+            Self::Rows(first, last) => write!(f, "{}:{}", first.coordinate(), last.coordinate()),
+        }
+    }
+}
+
+/// A cell reference, with the workbook and the sheets it is qualified by.
+/// ex) `[1]Sheet1!A1`, `'Jan:Mar'!$B$2:$C$3`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FormulaReference {
+    workbook:   Option<String>,
+    sheet:      Option<String>,
+    last_sheet: Option<String>,
+    area:       ReferenceArea,
+}
+
+impl FormulaReference {
+    #[inline]
+    #[must_use]
+    pub fn new(area: ReferenceArea) -> Self {
+        Self {
+            workbook: None,
+            sheet: None,
+            last_sheet: None,
+            area,
+        }
+    }
+
+    /// External workbook, as an index such as `1` or a file name with the
+    /// path.
+    #[inline]
+    #[must_use]
+    pub fn workbook(&self) -> Option<&str> {
+        self.workbook.as_deref()
+    }
+
+    #[inline]
+    pub fn set_workbook<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.workbook = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn remove_workbook(&mut self) -> &mut Self {
+        self.workbook = None;
+        self
+    }
+
+    /// Sheet, or the first sheet of a 3-D reference.
+    #[inline]
+    #[must_use]
+    pub fn sheet(&self) -> Option<&str> {
+        self.sheet.as_deref()
+    }
+
+    #[inline]
+    pub fn set_sheet<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.sheet = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn remove_sheet(&mut self) -> &mut Self {
+        self.sheet = None;
+        self.last_sheet = None;
+        self
+    }
+
+    /// Last sheet of a 3-D reference.
+    #[inline]
+    #[must_use]
+    pub fn last_sheet(&self) -> Option<&str> {
+        self.last_sheet.as_deref()
+    }
+
+    #[inline]
+    pub fn set_last_sheet<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.last_sheet = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn remove_last_sheet(&mut self) -> &mut Self {
+        self.last_sheet = None;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn area(&self) -> &ReferenceArea {
+        &self.area
+    }
+
+    #[inline]
+    pub fn area_mut(&mut self) -> &mut ReferenceArea {
+        &mut self.area
+    }
+
+    #[inline]
+    pub fn set_area(&mut self, value: ReferenceArea) -> &mut Self {
+        self.area = value;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_external(&self) -> bool {
+        self.workbook.is_some()
+    }
+}
+
+impl fmt::Display for FormulaReference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_prefix(
+            f,
+            self.workbook(),
+            self.sheet(),
+            self.last_sheet(),
+        )?;
+        write!(f, "{}", self.area)
+    }
+}
+
+/// A defined name, with the workbook or the sheet it is qualified by.
+/// ex) `Sales`, `Sheet1!Sales`, `[1]!Sales`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FormulaName {
+    workbook: Option<String>,
+    sheet:    Option<String>,
+    name:     String,
+}
+
+impl FormulaName {
+    #[inline]
+    #[must_use]
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        Self {
+            workbook: None,
+            sheet:    None,
+            name:     name.into(),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn workbook(&self) -> Option<&str> {
+        self.workbook.as_deref()
+    }
+
+    #[inline]
+    pub fn set_workbook<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.workbook = Some(value.into());
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn sheet(&self) -> Option<&str> {
+        self.sheet.as_deref()
+    }
+
+    #[inline]
+    pub fn set_sheet<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.sheet = Some(value.into());
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[inline]
+    pub fn set_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.name = value.into();
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_external(&self) -> bool {
+        self.workbook.is_some()
+    }
+}
+
+impl fmt::Display for FormulaName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_prefix(f, self.workbook(), self.sheet(), None)?;
+        write!(f, "{}", self.name)
+    }
+}
+
+/// A reference to a table. ex) `Table1[Price]`, `[@Price]`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StructuredReference {
+    table:     Option<String>,
+    specifier: String,
+}
+
+impl StructuredReference {
+    #[inline]
+    #[must_use]
+    pub fn new<S: Into<String>>(specifier: S) -> Self {
+        Self {
+            table:     None,
+            specifier: specifier.into(),
+        }
+    }
+
+    /// Table name. `None` inside the table itself. ex) `[@Price]`
+    #[inline]
+    #[must_use]
+    pub fn table(&self) -> Option<&str> {
+        self.table.as_deref()
+    }
+
+    #[inline]
+    pub fn set_table<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.table = Some(value.into());
+        self
+    }
+
+    #[inline]
+    pub fn remove_table(&mut self) -> &mut Self {
+        self.table = None;
+        self
+    }
+
+    /// The text between the outer brackets. ex) `[#This Row],[Price]`
+    #[inline]
+    #[must_use]
+    pub fn specifier(&self) -> &str {
+        &self.specifier
+    }
+
+    #[inline]
+    pub fn set_specifier<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.specifier = value.into();
+        self
+    }
+}
+
+impl fmt::Display for StructuredReference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}[{}]",
+            self.table().unwrap_or_default(),
+            self.specifier
+        )
+    }
+}
+
+impl FormulaExpr {
+    /// Call `visitor` with this node and then with every node below it.
+    pub fn walk<'a, F>(&'a self, visitor: &mut F)
+    where
+        F: FnMut(&'a FormulaExpr),
+    {
+        visitor(self);
+        match self {
+            Self::Array(rows) => {
+                for value in rows.iter().flatten() {
+                    value.walk(visitor);
+                }
+            }
+            Self::Function { args, .. } => {
+                for arg in args {
+                    arg.walk(visitor);
+                }
+            }
+            Self::Call { function, args } => {
+                function.walk(visitor);
+                for arg in args {
+                    arg.walk(visitor);
+                }
+            }
+            Self::Unary { operand, .. } | Self::Paren(operand) => operand.walk(visitor),
+            Self::Binary { left, right, .. } => {
+                left.walk(visitor);
+                right.walk(visitor);
+            }
+            _ => {}
+        }
+    }
+
+    /// Names of the functions called, in order of appearance.
+    #[must_use]
+    pub fn function_names(&self) -> Vec<&str> {
+        let mut result: Vec<&str> = Vec::new();
+        self.walk(&mut |expr| {
+            if let Self::Function { name, .. } = expr {
+                if !result.contains(&name.as_str()) {
+                    result.push(name);
+                }
+            }
+        });
+        result
+    }
+
+    /// Cell references, in order of appearance.
+    #[must_use]
+    pub fn references(&self) -> Vec<&FormulaReference> {
+        let mut result = Vec::new();
+        self.walk(&mut |expr| {
+            if let Self::Reference(reference) = expr {
+                result.push(reference);
+            }
+        });
+        result
+    }
+
+    /// Whether a reference or a name points into another workbook.
+    #[must_use]
+    pub fn has_external_reference(&self) -> bool {
+        let mut result = false;
+        self.walk(&mut |expr| match expr {
+            Self::Reference(reference) => result |= reference.is_external(),
+            Self::Name(name) => result |= name.is_external(),
+            _ => {}
+        });
+        result
+    }
+
+    fn precedence(&self) -> u8 {
+        match self {
+            Self::Binary { operator, .. } => operator.precedence(),
+            Self::Unary {
+                operator: UnaryOperator::ImplicitIntersection | UnaryOperator::Spill,
+                ..
+            } => 11,
+            Self::Unary {
+                operator: UnaryOperator::Percent,
+                ..
+            } => 6,
+            Self::Unary { .. } => 7,
+            _ => u8::MAX,
+        }
+    }
+
+    fn write_operand(
+        f: &mut fmt::Formatter<'_>,
+        operand: &FormulaExpr,
+        is_wrapped: bool,
+    ) -> fmt::Result {
+        if is_wrapped {
+            write!(f, "({operand})")
+        } else {
+            write!(f, "{operand}")
+        }
+    }
+
+    fn write_args(f: &mut fmt::Formatter<'_>, args: &[FormulaExpr]) -> fmt::Result {
+        write!(f, "(")?;
+        for (index, arg) in args.iter().enumerate() {
+            if index > 0 {
+                write!(f, ",")?;
+            }
+            let is_union = matches!(
+                arg,
+                Self::Binary {
+                    operator: BinaryOperator::Union,
+                    ..
+                }
+            );
+            Self::write_operand(f, arg, is_union)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Prints the formula without the leading `=`.
+/// Parentheses are added where the tree needs them, and the whitespace of the
+/// parsed text is not kept.
+impl fmt::Display for FormulaExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(value) => write!(f, "{value}"),
+            Self::Text(value) => write!(f, "\"{}\"", value.replace('"', "\"\"")),
+            Self::Boolean(value) => write!(f, "{}", if *value { "TRUE" } else { "FALSE" }),
+            Self::Error(value) => write!(f, "{value}"),
+            Self::Missing => Ok(()),
+            Self::Array(rows) => {
+                let rows: Vec<String> = rows
+                    .iter()
+                    .map(|row| {
+                        row.iter()
+                            .map(ToString::to_string)
+                            .collect::<Vec<_>>()
+                            .join(",")
+                    })
+                    .collect();
+                write!(f, "{{{}}}", rows.join(";"))
+            }
+            Self::Reference(reference) => write!(f, "{reference}"),
+            Self::Name(name) => write!(f, "{name}"),
+            Self::StructuredReference(reference) => write!(f, "{reference}"),
+            Self::Function { name, args } => {
+                write!(f, "{name}")?;
+                Self::write_args(f, args)
+            }
+            Self::Call { function, args } => {
+                write!(f, "{function}")?;
+                Self::write_args(f, args)
+            }
+            Self::Unary { operator, operand } => {
+                let is_wrapped = operand.precedence() < self.precedence();
+                match operator {
+                    UnaryOperator::Plus => write!(f, "+")?,
+                    UnaryOperator::Minus => write!(f, "-")?,
+                    UnaryOperator::ImplicitIntersection => write!(f, "@")?,
+                    UnaryOperator::Percent => {
+                        Self::write_operand(f, operand, is_wrapped)?;
+                        return write!(f, "%");
+                    }
+                    UnaryOperator::Spill => {
+                        Self::write_operand(f, operand, is_wrapped)?;
+                        return write!(f, "#");
+                    }
+                }
+                Self::write_operand(f, operand, is_wrapped)
+            }
+            Self::Binary {
+                operator,
+                left,
+                right,
+            } => {
+                let precedence = operator.precedence();
+                Self::write_operand(f, left, left.precedence() < precedence)?;
+                write!(f, "{}", operator.symbol())?;
+                Self::write_operand(f, right, right.precedence() <= precedence)
+            }
+            Self::Paren(expr) => write!(f, "({expr})"),
+        }
+    }
+}
+
+fn write_prefix(
+    f: &mut fmt::Formatter<'_>,
+    workbook: Option<&str>,
+    sheet: Option<&str>,
+    last_sheet: Option<&str>,
+) -> fmt::Result {
+    if workbook.is_none() && sheet.is_none() {
+        return Ok(());
+    }
+    let mut text = String::new();
+    let mut is_quoted = false;
+    if let Some(workbook) = workbook {
+        let (dir, file) = workbook
+            .rfind(['\\', '/'])
+            .map_or(("", workbook), |index| workbook.split_at(index + 1));
+        is_quoted |= !dir.is_empty()
+            || file
+                .chars()
+                .any(|c| !(c.is_ascii_alphanumeric() || c == '_' || c == '.'));
+        text = format!("{dir}[{file}]");
+    }
+    let sheets: Vec<&str> = [sheet, last_sheet].into_iter().flatten().collect();
+    is_quoted |= sheets.iter().any(|name| sheet_name_needs_quoting(name));
+    text.push_str(&sheets.join(":"));
+    if is_quoted {
+        write!(f, "'{}'!", text.replace('\'', "''"))
+    } else {
+        write!(f, "{text}!")
+    }
+}
+
+/// Parse a formula into its syntax tree. The leading `=` is optional.
+/// # Arguments
+/// * `formula` - ex) "=SUM(Sheet1!A1:A3)*2"
+/// # Errors
+/// * `InvalidArgument` - the formula can't be parsed, or nests more than 64
+///   levels of parentheses, functions, unary operators and arrays.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::helper::formula_ast::{
+///     FormulaExpr,
+///     parse_formula,
+/// };
+/// let expr = parse_formula("=IF([1]Rates!$B$2>0,SUM(A1:A3),#N/A)").unwrap();
+/// assert_eq!(expr.function_names(), ["IF", "SUM"]);
+/// assert!(expr.has_external_reference());
+/// assert_eq!(expr.to_string(), "IF([1]Rates!$B$2>0,SUM(A1:A3),#N/A)");
+/// ```
+pub fn parse_formula(formula: &str) -> Result<FormulaExpr, XlsxError> {
+    let text = formula.strip_prefix('=').unwrap_or(formula);
+    let mut parser = Parser {
+        chars:       text.chars().collect(),
+        pos:         0,
+        depth:       0,
+        allow_union: true,
+        formula,
+    };
+    let expr = parser.parse_expr()?;
+    parser.skip_whitespace();
+    if let Some(c) = parser.peek() {
+        return Err(parser.error(&format!("unexpected '{c}'")));
+    }
+    Ok(expr)
+}
+
+/// Workbook and sheets before the `!` of a reference.
+#[derive(Clone, Default)]
+struct Prefix {
+    workbook:   Option<String>,
+    sheet:      Option<String>,
+    last_sheet: Option<String>,
+}
+
+impl Prefix {
+    /// ex) "Sheet1", "Jan:Mar", "[1]Sheet1", "C:\dir\[Book1.xlsx]Sheet 1"
+    fn from_text(text: &str) -> Self {
+        let mut prefix = Self::default();
+        let mut sheets = text;
+        if let Some(open) = text.find('[') {
+            if let Some(close) = text[open..].find(']') {
+                let close = open + close;
+                prefix.workbook = Some(format!("{}{}", &text[..open], &text[open + 1..close]));
+                sheets = &text[close + 1..];
+            }
+        }
+        if !sheets.is_empty() {
+            match sheets.split_once(':') {
+                Some((first, last)) => {
+                    prefix.sheet = Some(first.to_string());
+                    prefix.last_sheet = Some(last.to_string());
+                }
+                None => prefix.sheet = Some(sheets.to_string()),
+            }
+        }
+        prefix
+    }
+
+    fn reference(self, area: ReferenceArea) -> FormulaReference {
+        FormulaReference {
+            workbook: self.workbook,
+            sheet: self.sheet,
+            last_sheet: self.last_sheet,
+            area,
+        }
+    }
+}
+
+struct Parser<'a> {
+    chars:       Vec<char>,
+    pos:         usize,
+    depth:       usize,
+    allow_union: bool,
+    formula:     &'a str,
+}
+
+impl Parser<'_> {
+    fn error(&self, reason: &str) -> XlsxError {
+        XlsxError::InvalidArgument(format!(
+            "{reason} at {} of formula: {}",
+            self.pos, self.formula
+        ))
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            return true;
+        }
+        false
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), XlsxError> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{c}'")))
+        }
+    }
+
+    // Enter a parenthesis, a function call, a unary operator or an array.
+    fn enter(&mut self) -> Result<(), XlsxError> {
+        if self.depth >= MAX_DEPTH {
+            return Err(self.error(&format!("more than {MAX_DEPTH} levels of nesting")));
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    fn leave(&mut self) {
+        self.depth -= 1;
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn parse_expr(&mut self) -> Result<FormulaExpr, XlsxError> {
+        self.parse_comparison()
+    }
+
+    fn parse_comparison(&mut self) -> Result<FormulaExpr, XlsxError> {
+        let mut left = self.parse_concat()?;
+        loop {
+            self.skip_whitespace();
+            let operator = match (self.peek(), self.peek_at(1)) {
+                (Some('<'), Some('>')) => BinaryOperator::NotEqual,
+                (Some('<'), Some('=')) => BinaryOperator::LessEqual,
+                (Some('>'), Some('=')) => BinaryOperator::GreaterEqual,
+                (Some('<'), _) => BinaryOperator::Less,
+                (Some('>'), _) => BinaryOperator::Greater,
+                (Some('='), _) => BinaryOperator::Equal,
+                _ => return Ok(left),
+            };
+            self.pos += operator.symbol().len();
+            let right = self.parse_concat()?;
+            left = binary(operator, left, right);
+        }
+    }
+
+    fn parse_concat(&mut self) -> Result<FormulaExpr, XlsxError> {
+        let mut left = self.parse_additive()?;
+        loop {
+            self.skip_whitespace();
+            if !self.eat('&') {
+                return Ok(left);
+            }
+            let right = self.parse_additive()?;
+            left = binary(BinaryOperator::Concat, left, right);
+        }
+    }
+
+    fn parse_additive(&mut self) -> Result<FormulaExpr, XlsxError> {
+        let mut left = self.parse_multiplicative()?;
+        loop {
+            self.skip_whitespace();
+            let operator = match self.peek() {
+                Some('+') => BinaryOperator::Add,
+                Some('-') => BinaryOperator::Subtract,
+                _ => return Ok(left),
+            };
+            self.pos += 1;
+            let right = self.parse_multiplicative()?;
+            left = binary(operator, left, right);
+        }
+    }
+
+    fn parse_multiplicative(&mut self) -> Result<FormulaExpr, XlsxError> {
+        let mut left = self.parse_power()?;
+        loop {
+            self.skip_whitespace();
+            let operator = match self.peek() {
+                Some('*') => BinaryOperator::Multiply,
+                Some('/') => BinaryOperator::Divide,
+                _ => return Ok(left),
+            };
+            self.pos += 1;
+            let right = self.parse_power()?;
+            left = binary(operator, left, right);
+        }
+    }
+
+    fn parse_power(&mut self) -> Result<FormulaExpr, XlsxError> {
+        let mut left = self.parse_percent()?;
+        loop {
+            self.skip_whitespace();
+            if !self.eat('^') {
+                return Ok(left);
+            }
+            let right = self.parse_percent()?;
+            left = binary(BinaryOperator::Power, left, right);
+        }
+    }
+
+    fn parse_percent(&mut self) -> Result<FormulaExpr, XlsxError> {
+        let mut operand = self.parse_unary()?;
+        loop {
+            let save = self.pos;
+            self.skip_whitespace();
+            if !self.eat('%') {
+                self.pos = save;
+                return Ok(operand);
+            }
+            operand = FormulaExpr::Unary {
+                operator: UnaryOperator::Percent,
+                operand:  Box::new(operand),
+            };
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<FormulaExpr, XlsxError> {
+        self.skip_whitespace();
+        let operator = match self.peek() {
+            Some('+') => UnaryOperator::Plus,
+            Some('-') => UnaryOperator::Minus,
+            _ => return self.parse_union(),
+        };
+        self.pos += 1;
+        self.enter()?;
+        let operand = self.parse_unary()?;
+        self.leave();
+        Ok(FormulaExpr::Unary {
+            operator,
+            operand: Box::new(operand),
+        })
+    }
+
+    fn parse_union(&mut self) -> Result<FormulaExpr, XlsxError> {
+        let mut left = self.parse_intersection()?;
+        while self.allow_union {
+            let save = self.pos;
+            self.skip_whitespace();
+            if !self.eat(',') {
+                self.pos = save;
+                break;
+            }
+            let right = self.parse_intersection()?;
+            left = binary(BinaryOperator::Union, left, right);
+        }
+        Ok(left)
+    }
+
+    fn parse_intersection(&mut self) -> Result<FormulaExpr, XlsxError> {
+        let mut left = self.parse_range()?;
+        loop {
+            let save = self.pos;
+            self.skip_whitespace();
+            let is_operand = self.pos > save
+                && self.peek().is_some_and(|c| {
+                    is_word_start(c) || c.is_ascii_digit() || matches!(c, '$' | '\'' | '[' | '(' | '@')
+                });
+            if !is_operand {
+                self.pos = save;
+                return Ok(left);
+            }
+            let right = self.parse_range()?;
+            left = binary(BinaryOperator::Intersection, left, right);
+        }
+    }
+
+    fn parse_range(&mut self) -> Result<FormulaExpr, XlsxError> {
+        let mut left = self.parse_operand()?;
+        loop {
+            let save = self.pos;
+            self.skip_whitespace();
+            if !self.eat(':') {
+                self.pos = save;
+                return Ok(left);
+            }
+            let right = self.parse_operand()?;
+            left = binary(BinaryOperator::Range, left, right);
+        }
+    }
+
+    /// An operand with its `@` and `#` operators.
+    fn parse_operand(&mut self) -> Result<FormulaExpr, XlsxError> {
+        self.skip_whitespace();
+        if self.eat('@') {
+            self.enter()?;
+            let operand = self.parse_operand()?;
+            self.leave();
+            return Ok(FormulaExpr::Unary {
+                operator: UnaryOperator::ImplicitIntersection,
+                operand:  Box::new(operand),
+            });
+        }
+        let operand = self.parse_primary()?;
+        if matches!(operand, FormulaExpr::Reference(_) | FormulaExpr::Name(_)) && self.eat('#') {
+            return Ok(FormulaExpr::Unary {
+                operator: UnaryOperator::Spill,
+                operand:  Box::new(operand),
+            });
+        }
+        Ok(operand)
+    }
+
+    fn parse_primary(&mut self) -> Result<FormulaExpr, XlsxError> {
+        self.skip_whitespace();
+        let Some(c) = self.peek() else {
+            return Err(self.error("unexpected end"));
+        };
+        match c {
+            '(' => {
+                self.pos += 1;
+                self.enter()?;
+                let allow_union = std::mem::replace(&mut self.allow_union, true);
+                let expr = self.parse_expr()?;
+                self.allow_union = allow_union;
+                self.skip_whitespace();
+                self.expect(')')?;
+                self.leave();
+                Ok(FormulaExpr::Paren(Box::new(expr)))
+            }
+            '"' => Ok(FormulaExpr::Text(self.parse_text()?)),
+            '{' => self.parse_array(),
+            '#' => self.parse_error(),
+            '\'' => {
+                let prefix = self.parse_quoted_prefix()?;
+                self.parse_target(prefix)
+            }
+            '[' => self.parse_bracket(),
+            c if c.is_ascii_digit() || c == '.' => {
+                if let Some(expr) = self.try_parse_pair(&Prefix::default()) {
+                    return Ok(expr);
+                }
+                self.parse_number().map(FormulaExpr::Number)
+            }
+            c if c == '$' || is_word_start(c) => self.parse_word(),
+            c => Err(self.error(&format!("unexpected '{c}'"))),
+        }
+    }
+
+    fn parse_text(&mut self) -> Result<String, XlsxError> {
+        self.expect('"')?;
+        let mut value = String::new();
+        loop {
+            match self.peek() {
+                None => return Err(self.error("unterminated text")),
+                Some('"') => {
+                    self.pos += 1;
+                    if !self.eat('"') {
+                        return Ok(value);
+                    }
+                    value.push('"');
+                }
+                Some(c) => {
+                    self.pos += 1;
+                    value.push(c);
+                }
+            }
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<f64, XlsxError> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit() || c == '.') {
+            self.pos += 1;
+        }
+        if matches!(self.peek(), Some('e' | 'E')) {
+            let save = self.pos;
+            self.pos += 1;
+            if matches!(self.peek(), Some('+' | '-')) {
+                self.pos += 1;
+            }
+            if self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                    self.pos += 1;
+                }
+            } else {
+                self.pos = save;
+            }
+        }
+        let text: String = self.chars[start..self.pos].iter().collect();
+        text.parse::<f64>()
+            .map_err(|_| self.error(&format!("invalid number '{text}'")))
+    }
+
+    fn parse_error(&mut self) -> Result<FormulaExpr, XlsxError> {
+        let rest: String = self.chars[self.pos..].iter().collect::<String>().to_uppercase();
+        let Some(value) = ERROR_LITERALS
+            .iter()
+            .find(|value| rest.starts_with(**value))
+        else {
+            return Err(self.error("unknown error value"));
+        };
+        self.pos += value.len();
+        Ok(FormulaExpr::Error((*value).to_string()))
+    }
+
+    fn parse_array(&mut self) -> Result<FormulaExpr, XlsxError> {
+        self.expect('{')?;
+        self.enter()?;
+        let mut rows = Vec::new();
+        let mut row = Vec::new();
+        loop {
+            self.skip_whitespace();
+            row.push(self.parse_array_value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => {}
+                Some(';') => rows.push(std::mem::take(&mut row)),
+                Some('}') => {
+                    self.pos += 1;
+                    self.leave();
+                    rows.push(row);
+                    return Ok(FormulaExpr::Array(rows));
+                }
+                _ => return Err(self.error("expected ',', ';' or '}'")),
+            }
+            self.pos += 1;
+        }
+    }
+
+    fn parse_array_value(&mut self) -> Result<FormulaExpr, XlsxError> {
+        match self.peek() {
+            Some('"') => Ok(FormulaExpr::Text(self.parse_text()?)),
+            Some('#') => self.parse_error(),
+            Some('-') => {
+                self.pos += 1;
+                Ok(FormulaExpr::Number(-self.parse_number()?))
+            }
+            Some('+') => {
+                self.pos += 1;
+                Ok(FormulaExpr::Number(self.parse_number()?))
+            }
+            Some(c) if c.is_ascii_digit() || c == '.' => {
+                Ok(FormulaExpr::Number(self.parse_number()?))
+            }
+            _ => {
+                let word = self.read_word();
+                match word.to_uppercase().as_str() {
+                    "TRUE" => Ok(FormulaExpr::Boolean(true)),
+                    "FALSE" => Ok(FormulaExpr::Boolean(false)),
+                    _ => Err(self.error("invalid array constant")),
+                }
+            }
+        }
+    }
+
+    fn read_word(&mut self) -> String {
+        let start = self.pos;
+        while self.peek().is_some_and(is_word_char) {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    /// `'...'!`, with `''` for an apostrophe.
+    fn parse_quoted_prefix(&mut self) -> Result<Prefix, XlsxError> {
+        self.expect('\'')?;
+        let mut text = String::new();
+        loop {
+            match self.peek() {
+                None => return Err(self.error("unterminated sheet name")),
+                Some('\'') => {
+                    self.pos += 1;
+                    if !self.eat('\'') {
+                        break;
+                    }
+                    text.push('\'');
+                }
+                Some(c) => {
+                    self.pos += 1;
+                    text.push(c);
+                }
+            }
+        }
+        self.expect('!')?;
+        Ok(Prefix::from_text(&text))
+    }
+
+    /// `[1]Sheet1!A1`, `[1]!Name` or a structured reference without table.
+    fn parse_bracket(&mut self) -> Result<FormulaExpr, XlsxError> {
+        let start = self.pos;
+        if let Some(close) = self.chars[start..].iter().position(|c| *c == ']') {
+            let inner = &self.chars[start + 1..start + close];
+            if !inner.contains(&'[') {
+                self.pos = start + close + 1;
+                let mut text: String = self.chars[start..self.pos].iter().collect();
+                text.push_str(&self.read_word());
+                if self.peek() == Some(':') {
+                    self.pos += 1;
+                    text.push(':');
+                    text.push_str(&self.read_word());
+                }
+                if self.eat('!') {
+                    return self.parse_target(Prefix::from_text(&text));
+                }
+                self.pos = start;
+            }
+        }
+        let specifier = self.parse_specifier()?;
+        Ok(FormulaExpr::StructuredReference(StructuredReference {
+            table: None,
+            specifier,
+        }))
+    }
+
+    /// The text between the outer brackets of a structured reference.
+    fn parse_specifier(&mut self) -> Result<String, XlsxError> {
+        self.expect('[')?;
+        let mut depth = 1;
+        let mut text = String::new();
+        loop {
+            let Some(c) = self.peek() else {
+                return Err(self.error("unterminated structured reference"));
+            };
+            self.pos += 1;
+            match c {
+                '\'' => {
+                    text.push(c);
+                    if let Some(escaped) = self.peek() {
+                        self.pos += 1;
+                        text.push(escaped);
+                    }
+                    continue;
+                }
+                '[' => depth += 1,
+                ']' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Ok(text);
+                    }
+                }
+                _ => {}
+            }
+            text.push(c);
+        }
+    }
+
+    fn parse_word(&mut self) -> Result<FormulaExpr, XlsxError> {
+        let start = self.pos;
+        let word = self.read_word();
+        match self.peek() {
+            Some('(') if !word.contains('$') => return self.parse_function(word),
+            Some('[') if !word.contains('$') => {
+                let specifier = self.parse_specifier()?;
+                return Ok(FormulaExpr::StructuredReference(StructuredReference {
+                    table: Some(word),
+                    specifier,
+                }));
+            }
+            Some('!') => {
+                self.pos += 1;
+                return self.parse_target(Prefix::from_text(&word));
+            }
+            Some(':') => {
+                let save = self.pos;
+                self.pos += 1;
+                let last = self.read_word();
+                if self.eat('!') {
+                    return self.parse_target(Prefix::from_text(&format!("{word}:{last}")));
+                }
+                self.pos = start;
+                if let Some(expr) = self.try_parse_pair(&Prefix::default()) {
+                    return Ok(expr);
+                }
+                self.pos = save;
+            }
+            _ => {}
+        }
+        match word.to_uppercase().as_str() {
+            "TRUE" => return Ok(FormulaExpr::Boolean(true)),
+            "FALSE" => return Ok(FormulaExpr::Boolean(false)),
+            _ => {}
+        }
+        self.single_target(Prefix::default(), &word)
+    }
+
+    fn parse_function(&mut self, name: String) -> Result<FormulaExpr, XlsxError> {
+        let args = self.parse_args()?;
+        let mut expr = FormulaExpr::Function { name, args };
+        // `LAMBDA(x,x+1)(3)` calls the function the first call returns.
+        while self.peek() == Some('(') {
+            let args = self.parse_args()?;
+            expr = FormulaExpr::Call {
+                function: Box::new(expr),
+                args,
+            };
+        }
+        Ok(expr)
+    }
+
+    /// The arguments of a call, in parentheses.
+    fn parse_args(&mut self) -> Result<Vec<FormulaExpr>, XlsxError> {
+        self.expect('(')?;
+        self.enter()?;
+        let allow_union = std::mem::replace(&mut self.allow_union, false);
+        let mut args = Vec::new();
+        self.skip_whitespace();
+        if !self.eat(')') {
+            loop {
+                self.skip_whitespace();
+                if matches!(self.peek(), Some(',' | ')')) {
+                    args.push(FormulaExpr::Missing);
+                } else {
+                    args.push(self.parse_expr()?);
+                }
+                self.skip_whitespace();
+                if self.eat(')') {
+                    break;
+                }
+                self.expect(',')?;
+            }
+        }
+        self.allow_union = allow_union;
+        self.leave();
+        Ok(args)
+    }
+
+    /// The cells or the name after the `!` of a prefix.
+    fn parse_target(&mut self, prefix: Prefix) -> Result<FormulaExpr, XlsxError> {
+        if self.peek() == Some('#') {
+            return self.parse_error();
+        }
+        let start = self.pos;
+        let word = self.read_word();
+        if self.peek() == Some(':') {
+            let end = self.pos;
+            self.pos = start;
+            if let Some(expr) = self.try_parse_pair(&prefix) {
+                return Ok(expr);
+            }
+            self.pos = end;
+        }
+        self.single_target(prefix, &word)
+    }
+
+    /// `A1:B2`, `A:C` or `1:3` at the current position, as one reference.
+    fn try_parse_pair(&mut self, prefix: &Prefix) -> Option<FormulaExpr> {
+        let start = self.pos;
+        let first = self.read_word();
+        if !self.eat(':') {
+            self.pos = start;
+            return None;
+        }
+        let last = self.read_word();
+        let area = if let (Some(first), Some(last)) = (parse_cell(&first), parse_cell(&last)) {
+            ReferenceArea::Range(first, last)
+        } else if let (Some(first), Some(last)) = (parse_column(&first), parse_column(&last)) {
+            ReferenceArea::Columns(first, last)
+        } else if let (Some(first), Some(last)) = (parse_row(&first), parse_row(&last)) {
+            ReferenceArea::Rows(first, last)
+        } else {
+            self.pos = start;
+            return None;
+        };
+        if self.peek().is_some_and(|c| c == '(' || c == '[' || c == '!') {
+            self.pos = start;
+            return None;
+        }
+        Some(FormulaExpr::Reference(prefix.clone().reference(area)))
+    }
+
+    fn single_target(&self, prefix: Prefix, word: &str) -> Result<FormulaExpr, XlsxError> {
+        if let Some(cell) = parse_cell(word) {
+            return Ok(FormulaExpr::Reference(
+                prefix.reference(ReferenceArea::Cell(cell)),
+            ));
+        }
+        if word.is_empty()
+            || word.contains('$')
+            || !word.starts_with(is_word_start)
+            || prefix.last_sheet.is_some()
+        {
+            return Err(self.error(&format!("invalid reference '{word}'")));
+        }
+        Ok(FormulaExpr::Name(FormulaName {
+            workbook: prefix.workbook,
+            sheet:    prefix.sheet,
+            name:     word.to_string(),
+        }))
+    }
+}
+
+fn binary(operator: BinaryOperator, left: FormulaExpr, right: FormulaExpr) -> FormulaExpr {
+    FormulaExpr::Binary {
+        operator,
+        left: Box::new(left),
+        right: Box::new(right),
+    }
+}
+
+fn is_word_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_' || c == '\\'
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '.' | '$' | '\\' | '?')
+}
+
+/// ex) "A1", "$A$1"
+fn parse_cell(word: &str) -> Option<Coordinate> {
+    let index = word.rfind(|c: char| c.is_ascii_alphabetic())? + 1;
+    let (column, row) = word.split_at(index);
+    let (column, row) = (parse_column(column)?, parse_row(row)?);
+    let mut coordinate = Coordinate::default();
+    coordinate
+        .set_col_num(column.num())
+        .set_row_num(row.num())
+        .set_is_lock_col(column.is_lock())
+        .set_is_lock_row(row.is_lock());
+    Some(coordinate)
+}
+
+/// ex) "A", "$XFD"
+fn parse_column(word: &str) -> Option<ColumnReference> {
+    let (is_lock, letters) = word
+        .strip_prefix('$')
+        .map_or((false, word), |rest| (true, rest));
+    if letters.is_empty() || letters.len() > 3 || !letters.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let num = column_index_from_string(letters.to_uppercase());
+    if num > MAX_COLUMN {
+        return None;
+    }
+    let mut column = ColumnReference::default();
+    column.set_num(num).set_is_lock(is_lock);
+    Some(column)
+}
+
+/// ex) "1", "$1048576"
+fn parse_row(word: &str) -> Option<RowReference> {
+    let (is_lock, digits) = word
+        .strip_prefix('$')
+        .map_or((false, word), |rest| (true, rest));
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let num = digits.parse::<u32>().ok()?;
+    if num == 0 || num > MAX_ROW {
+        return None;
+    }
+    let mut row = RowReference::default();
+    row.set_num(num).set_is_lock(is_lock);
+    Some(row)
+}
+
+#[test]
+fn parse_formula_test() {
+    let expr = parse_formula("=SUM(Sheet1!$A$1:B2, 3) * -2%").unwrap();
+    let FormulaExpr::Binary {
+        operator: BinaryOperator::Multiply,
+        left,
+        right,
+    } = &expr
+    else {
+        panic!("{expr:?}");
+    };
+    assert!(matches!(&**left, FormulaExpr::Function { name, args } if name == "SUM" && args.len() == 2));
+    assert!(matches!(
+        &**right,
+        FormulaExpr::Unary {
+            operator: UnaryOperator::Percent,
+            ..
+        }
+    ));
+    let reference = expr.references()[0];
+    assert_eq!(reference.sheet(), Some("Sheet1"));
+    let ReferenceArea::Range(first, last) = reference.area() else {
+        panic!("{reference:?}");
+    };
+    assert!(first.is_lock_col() && first.is_lock_row());
+    assert_eq!((last.col_num(), last.row_num()), (2, 2));
+
+    // precedence: unary minus binds before ^, ^ is left associative
+    let expr = parse_formula("-2^3^2").unwrap();
+    assert_eq!(expr.to_string(), "-2^3^2");
+    let FormulaExpr::Binary { left, .. } = &expr else {
+        panic!("{expr:?}");
+    };
+    assert!(matches!(&**left, FormulaExpr::Binary { .. }));
+
+    assert!(parse_formula("SUM(A1").is_err());
+    assert!(parse_formula("A1+").is_err());
+    assert!(parse_formula("\"abc").is_err());
+
+    // nesting is limited as in Excel, instead of overflowing the stack
+    let nested = |depth: usize| format!("{}1{}", "(".repeat(depth), ")".repeat(depth));
+    assert!(parse_formula(&nested(64)).is_ok());
+    assert!(parse_formula(&nested(65)).is_err());
+    assert!(parse_formula(&nested(100_000)).is_err());
+    assert!(parse_formula(&format!("{}1", "-".repeat(100_000))).is_err());
+    assert!(parse_formula(&format!("{}1{}", "ABS(".repeat(100_000), ")".repeat(100_000))).is_err());
+}
+
+#[test]
+fn print_formula_test() {
+    for formula in [
+        "IF(A1>=10,\"a\"\"b\",FALSE)",
+        "SUM((A1:A3,C1:C3))",
+        "SUM(A:A 1:1)",
+        "'Bob''s Data'!A1&Jan:Mar!$B$2",
+        "'C:\\dir\\[Book 1.xlsx]Sheet1'!A1+[1]!Rate",
+        "Table1[[#This Row],[Price]]*[@Qty]",
+        "{1,-2.5;\"x\",TRUE}",
+        "INDEX(A1:C3,2,)",
+        "A1:INDEX(B1:B3,2)",
+        "Sheet1!#REF!+#N/A",
+        "_xlfn.XLOOKUP(D2,A2:A9,B2:B9)",
+        "-(1+2)*3%",
+        "Sales/'A1'!Total",
+        "SUM(A1#)+@A1:A3*-@(A1 B1)",
+        "LAMBDA(x,x+1)(3)",
+    ] {
+        let expr = parse_formula(formula).unwrap();
+        let text = expr.to_string();
+        assert_eq!(parse_formula(&text).unwrap(), expr, "{formula} -> {text}");
+    }
+    assert_eq!(
+        parse_formula("= SUM( A1 , B1 )").unwrap().to_string(),
+        "SUM(A1,B1)"
+    );
+    assert_eq!(
+        parse_formula("'Bob''s Data'!A1").unwrap().to_string(),
+        "'Bob''s Data'!A1"
+    );
+
+    // parentheses are added where the tree needs them
+    let expr = FormulaExpr::Binary {
+        operator: BinaryOperator::Multiply,
+        left:     Box::new(FormulaExpr::Binary {
+            operator: BinaryOperator::Add,
+            left:     Box::new(FormulaExpr::Number(1.0)),
+            right:    Box::new(FormulaExpr::Number(2.0)),
+        }),
+        right:    Box::new(FormulaExpr::Number(3.0)),
+    };
+    assert_eq!(expr.to_string(), "(1+2)*3");
+}
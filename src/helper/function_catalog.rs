@@ -0,0 +1,545 @@
+//! Catalog of the worksheet functions of Excel, with their number of
+//! arguments.
+
+/// The most arguments a function takes.
+pub(crate) const MAX_ARGUMENTS: usize = 255;
+
+/// Name, minimum and maximum number of arguments, sorted by name.
+const FUNCTIONS: &[(&str, usize, usize)] = &[
+    ("ABS", 1, 1),
+    ("ACCRINT", 6, 8),
+    ("ACCRINTM", 4, 5),
+    ("ACOS", 1, 1),
+    ("ACOSH", 1, 1),
+    ("ACOT", 1, 1),
+    ("ACOTH", 1, 1),
+    ("ADDRESS", 2, 5),
+    ("AGGREGATE", 3, MAX_ARGUMENTS),
+    ("AMORDEGRC", 6, 7),
+    ("AMORLINC", 6, 7),
+    ("ANCHORARRAY", 1, 1),
+    ("AND", 1, MAX_ARGUMENTS),
+    ("ARABIC", 1, 1),
+    ("AREAS", 1, 1),
+    ("ARRAYTOTEXT", 1, 2),
+    ("ASC", 1, 1),
+    ("ASIN", 1, 1),
+    ("ASINH", 1, 1),
+    ("ATAN", 1, 1),
+    ("ATAN2", 2, 2),
+    ("ATANH", 1, 1),
+    ("AVEDEV", 1, MAX_ARGUMENTS),
+    ("AVERAGE", 1, MAX_ARGUMENTS),
+    ("AVERAGEA", 1, MAX_ARGUMENTS),
+    ("AVERAGEIF", 2, 3),
+    ("AVERAGEIFS", 3, MAX_ARGUMENTS),
+    ("BAHTTEXT", 1, 1),
+    ("BASE", 2, 3),
+    ("BESSELI", 2, 2),
+    ("BESSELJ", 2, 2),
+    ("BESSELK", 2, 2),
+    ("BESSELY", 2, 2),
+    ("BETA.DIST", 4, 6),
+    ("BETA.INV", 3, 5),
+    ("BETADIST", 3, 5),
+    ("BETAINV", 3, 5),
+    ("BIN2DEC", 1, 1),
+    ("BIN2HEX", 1, 2),
+    ("BIN2OCT", 1, 2),
+    ("BINOM.DIST", 4, 4),
+    ("BINOM.DIST.RANGE", 3, 4),
+    ("BINOM.INV", 3, 3),
+    ("BINOMDIST", 4, 4),
+    ("BITAND", 2, 2),
+    ("BITLSHIFT", 2, 2),
+    ("BITOR", 2, 2),
+    ("BITRSHIFT", 2, 2),
+    ("BITXOR", 2, 2),
+    ("BYCOL", 2, 2),
+    ("BYROW", 2, 2),
+    ("CALL", 1, MAX_ARGUMENTS),
+    ("CEILING", 2, 2),
+    ("CEILING.MATH", 1, 3),
+    ("CEILING.PRECISE", 1, 2),
+    ("CELL", 1, 2),
+    ("CHAR", 1, 1),
+    ("CHIDIST", 2, 2),
+    ("CHIINV", 2, 2),
+    ("CHISQ.DIST", 3, 3),
+    ("CHISQ.DIST.RT", 2, 2),
+    ("CHISQ.INV", 2, 2),
+    ("CHISQ.INV.RT", 2, 2),
+    ("CHISQ.TEST", 2, 2),
+    ("CHITEST", 2, 2),
+    ("CHOOSE", 2, MAX_ARGUMENTS),
+    ("CHOOSECOLS", 2, MAX_ARGUMENTS),
+    ("CHOOSEROWS", 2, MAX_ARGUMENTS),
+    ("CLEAN", 1, 1),
+    ("CODE", 1, 1),
+    ("COLUMN", 0, 1),
+    ("COLUMNS", 1, 1),
+    ("COMBIN", 2, 2),
+    ("COMBINA", 2, 2),
+    ("COMPLEX", 2, 3),
+    ("CONCAT", 1, MAX_ARGUMENTS),
+    ("CONCATENATE", 1, MAX_ARGUMENTS),
+    ("CONFIDENCE", 3, 3),
+    ("CONFIDENCE.NORM", 3, 3),
+    ("CONFIDENCE.T", 3, 3),
+    ("CONVERT", 3, 3),
+    ("CORREL", 2, 2),
+    ("COS", 1, 1),
+    ("COSH", 1, 1),
+    ("COT", 1, 1),
+    ("COTH", 1, 1),
+    ("COUNT", 1, MAX_ARGUMENTS),
+    ("COUNTA", 1, MAX_ARGUMENTS),
+    ("COUNTBLANK", 1, 1),
+    ("COUNTIF", 2, 2),
+    ("COUNTIFS", 2, MAX_ARGUMENTS),
+    ("COUPDAYBS", 3, 4),
+    ("COUPDAYS", 3, 4),
+    ("COUPDAYSNC", 3, 4),
+    ("COUPNCD", 3, 4),
+    ("COUPNUM", 3, 4),
+    ("COUPPCD", 3, 4),
+    ("COVAR", 2, 2),
+    ("COVARIANCE.P", 2, 2),
+    ("COVARIANCE.S", 2, 2),
+    ("CRITBINOM", 3, 3),
+    ("CSC", 1, 1),
+    ("CSCH", 1, 1),
+    ("CUBEKPIMEMBER", 3, 4),
+    ("CUBEMEMBER", 2, 3),
+    ("CUBEMEMBERPROPERTY", 3, 3),
+    ("CUBERANKEDMEMBER", 3, 4),
+    ("CUBESET", 2, 5),
+    ("CUBESETCOUNT", 1, 1),
+    ("CUBEVALUE", 1, MAX_ARGUMENTS),
+    ("CUMIPMT", 6, 6),
+    ("CUMPRINC", 6, 6),
+    ("DATE", 3, 3),
+    ("DATEDIF", 3, 3),
+    ("DATEVALUE", 1, 1),
+    ("DAVERAGE", 3, 3),
+    ("DAY", 1, 1),
+    ("DAYS", 2, 2),
+    ("DAYS360", 2, 3),
+    ("DB", 4, 5),
+    ("DBCS", 1, 1),
+    ("DCOUNT", 3, 3),
+    ("DCOUNTA", 3, 3),
+    ("DDB", 4, 5),
+    ("DEC2BIN", 1, 2),
+    ("DEC2HEX", 1, 2),
+    ("DEC2OCT", 1, 2),
+    ("DECIMAL", 2, 2),
+    ("DEGREES", 1, 1),
+    ("DELTA", 1, 2),
+    ("DEVSQ", 1, MAX_ARGUMENTS),
+    ("DGET", 3, 3),
+    ("DISC", 4, 5),
+    ("DMAX", 3, 3),
+    ("DMIN", 3, 3),
+    ("DOLLAR", 1, 2),
+    ("DOLLARDE", 2, 2),
+    ("DOLLARFR", 2, 2),
+    ("DPRODUCT", 3, 3),
+    ("DROP", 2, 3),
+    ("DSTDEV", 3, 3),
+    ("DSTDEVP", 3, 3),
+    ("DSUM", 3, 3),
+    ("DURATION", 5, 6),
+    ("DVAR", 3, 3),
+    ("DVARP", 3, 3),
+    ("ECMA.CEILING", 2, 2),
+    ("EDATE", 2, 2),
+    ("EFFECT", 2, 2),
+    ("ENCODEURL", 1, 1),
+    ("EOMONTH", 2, 2),
+    ("ERF", 1, 2),
+    ("ERF.PRECISE", 1, 1),
+    ("ERFC", 1, 1),
+    ("ERFC.PRECISE", 1, 1),
+    ("ERROR.TYPE", 1, 1),
+    ("EUROCONVERT", 3, 5),
+    ("EVEN", 1, 1),
+    ("EXACT", 2, 2),
+    ("EXP", 1, 1),
+    ("EXPAND", 2, 4),
+    ("EXPON.DIST", 3, 3),
+    ("EXPONDIST", 3, 3),
+    ("F.DIST", 4, 4),
+    ("F.DIST.RT", 3, 3),
+    ("F.INV", 3, 3),
+    ("F.INV.RT", 3, 3),
+    ("F.TEST", 2, 2),
+    ("FACT", 1, 1),
+    ("FACTDOUBLE", 1, 1),
+    ("FALSE", 0, 0),
+    ("FDIST", 3, 3),
+    ("FIELDVALUE", 2, 2),
+    ("FILTER", 2, 3),
+    ("FILTERXML", 2, 2),
+    ("FIND", 2, 3),
+    ("FINDB", 2, 3),
+    ("FINV", 3, 3),
+    ("FISHER", 1, 1),
+    ("FISHERINV", 1, 1),
+    ("FIXED", 1, 3),
+    ("FLOOR", 2, 2),
+    ("FLOOR.MATH", 1, 3),
+    ("FLOOR.PRECISE", 1, 2),
+    ("FORECAST", 3, 3),
+    ("FORECAST.ETS", 3, 6),
+    ("FORECAST.ETS.CONFINT", 3, 7),
+    ("FORECAST.ETS.SEASONALITY", 2, 4),
+    ("FORECAST.ETS.STAT", 3, 6),
+    ("FORECAST.LINEAR", 3, 3),
+    ("FORMULATEXT", 1, 1),
+    ("FREQUENCY", 2, 2),
+    ("FTEST", 2, 2),
+    ("FV", 3, 5),
+    ("FVSCHEDULE", 2, 2),
+    ("GAMMA", 1, 1),
+    ("GAMMA.DIST", 4, 4),
+    ("GAMMA.INV", 3, 3),
+    ("GAMMADIST", 4, 4),
+    ("GAMMAINV", 3, 3),
+    ("GAMMALN", 1, 1),
+    ("GAMMALN.PRECISE", 1, 1),
+    ("GAUSS", 1, 1),
+    ("GCD", 1, MAX_ARGUMENTS),
+    ("GEOMEAN", 1, MAX_ARGUMENTS),
+    ("GESTEP", 1, 2),
+    ("GETPIVOTDATA", 2, MAX_ARGUMENTS),
+    ("GROUPBY", 3, 8),
+    ("GROWTH", 1, 4),
+    ("HARMEAN", 1, MAX_ARGUMENTS),
+    ("HEX2BIN", 1, 2),
+    ("HEX2DEC", 1, 1),
+    ("HEX2OCT", 1, 2),
+    ("HLOOKUP", 3, 4),
+    ("HOUR", 1, 1),
+    ("HSTACK", 1, MAX_ARGUMENTS),
+    ("HYPERLINK", 1, 2),
+    ("HYPGEOM.DIST", 5, 5),
+    ("HYPGEOMDIST", 4, 4),
+    ("IF", 2, 3),
+    ("IFERROR", 2, 2),
+    ("IFNA", 2, 2),
+    ("IFS", 2, 254),
+    ("IMABS", 1, 1),
+    ("IMAGE", 1, 5),
+    ("IMAGINARY", 1, 1),
+    ("IMARGUMENT", 1, 1),
+    ("IMCONJUGATE", 1, 1),
+    ("IMCOS", 1, 1),
+    ("IMCOSH", 1, 1),
+    ("IMCOT", 1, 1),
+    ("IMCSC", 1, 1),
+    ("IMCSCH", 1, 1),
+    ("IMDIV", 2, 2),
+    ("IMEXP", 1, 1),
+    ("IMLN", 1, 1),
+    ("IMLOG10", 1, 1),
+    ("IMLOG2", 1, 1),
+    ("IMPOWER", 2, 2),
+    ("IMPRODUCT", 1, MAX_ARGUMENTS),
+    ("IMREAL", 1, 1),
+    ("IMSEC", 1, 1),
+    ("IMSECH", 1, 1),
+    ("IMSIN", 1, 1),
+    ("IMSINH", 1, 1),
+    ("IMSQRT", 1, 1),
+    ("IMSUB", 2, 2),
+    ("IMSUM", 1, MAX_ARGUMENTS),
+    ("IMTAN", 1, 1),
+    ("INDEX", 2, 4),
+    ("INDIRECT", 1, 2),
+    ("INFO", 1, 1),
+    ("INT", 1, 1),
+    ("INTERCEPT", 2, 2),
+    ("INTRATE", 4, 5),
+    ("IPMT", 4, 6),
+    ("IRR", 1, 2),
+    ("ISBLANK", 1, 1),
+    ("ISERR", 1, 1),
+    ("ISERROR", 1, 1),
+    ("ISEVEN", 1, 1),
+    ("ISFORMULA", 1, 1),
+    ("ISLOGICAL", 1, 1),
+    ("ISNA", 1, 1),
+    ("ISNONTEXT", 1, 1),
+    ("ISNUMBER", 1, 1),
+    ("ISO.CEILING", 1, 2),
+    ("ISODD", 1, 1),
+    ("ISOMITTED", 1, 1),
+    ("ISOWEEKNUM", 1, 1),
+    ("ISPMT", 4, 4),
+    ("ISREF", 1, 1),
+    ("ISTEXT", 1, 1),
+    ("JIS", 1, 1),
+    ("KURT", 1, MAX_ARGUMENTS),
+    ("LAMBDA", 1, MAX_ARGUMENTS),
+    ("LARGE", 2, 2),
+    ("LCM", 1, MAX_ARGUMENTS),
+    ("LEFT", 1, 2),
+    ("LEFTB", 1, 2),
+    ("LEN", 1, 1),
+    ("LENB", 1, 1),
+    ("LET", 3, MAX_ARGUMENTS),
+    ("LINEST", 1, 4),
+    ("LN", 1, 1),
+    ("LOG", 1, 2),
+    ("LOG10", 1, 1),
+    ("LOGEST", 1, 4),
+    ("LOGINV", 3, 3),
+    ("LOGNORM.DIST", 4, 4),
+    ("LOGNORM.INV", 3, 3),
+    ("LOGNORMDIST", 3, 3),
+    ("LOOKUP", 2, 3),
+    ("LOWER", 1, 1),
+    ("MAKEARRAY", 3, 3),
+    ("MAP", 2, MAX_ARGUMENTS),
+    ("MATCH", 2, 3),
+    ("MAX", 1, MAX_ARGUMENTS),
+    ("MAXA", 1, MAX_ARGUMENTS),
+    ("MAXIFS", 3, MAX_ARGUMENTS),
+    ("MDETERM", 1, 1),
+    ("MDURATION", 5, 6),
+    ("MEDIAN", 1, MAX_ARGUMENTS),
+    ("MID", 3, 3),
+    ("MIDB", 3, 3),
+    ("MIN", 1, MAX_ARGUMENTS),
+    ("MINA", 1, MAX_ARGUMENTS),
+    ("MINIFS", 3, MAX_ARGUMENTS),
+    ("MINUTE", 1, 1),
+    ("MINVERSE", 1, 1),
+    ("MIRR", 3, 3),
+    ("MMULT", 2, 2),
+    ("MOD", 2, 2),
+    ("MODE", 1, MAX_ARGUMENTS),
+    ("MODE.MULT", 1, MAX_ARGUMENTS),
+    ("MODE.SNGL", 1, MAX_ARGUMENTS),
+    ("MONTH", 1, 1),
+    ("MROUND", 2, 2),
+    ("MULTINOMIAL", 1, MAX_ARGUMENTS),
+    ("MUNIT", 1, 1),
+    ("N", 1, 1),
+    ("NA", 0, 0),
+    ("NEGBINOM.DIST", 4, 4),
+    ("NEGBINOMDIST", 3, 3),
+    ("NETWORKDAYS", 2, 3),
+    ("NETWORKDAYS.INTL", 2, 4),
+    ("NOMINAL", 2, 2),
+    ("NORM.DIST", 4, 4),
+    ("NORM.INV", 3, 3),
+    ("NORM.S.DIST", 2, 2),
+    ("NORM.S.INV", 1, 1),
+    ("NORMDIST", 4, 4),
+    ("NORMINV", 3, 3),
+    ("NORMSDIST", 1, 1),
+    ("NORMSINV", 1, 1),
+    ("NOT", 1, 1),
+    ("NOW", 0, 0),
+    ("NPER", 3, 5),
+    ("NPV", 2, MAX_ARGUMENTS),
+    ("NUMBERVALUE", 1, 3),
+    ("OCT2BIN", 1, 2),
+    ("OCT2DEC", 1, 1),
+    ("OCT2HEX", 1, 2),
+    ("ODD", 1, 1),
+    ("ODDFPRICE", 8, 9),
+    ("ODDFYIELD", 8, 9),
+    ("ODDLPRICE", 7, 8),
+    ("ODDLYIELD", 7, 8),
+    ("OFFSET", 3, 5),
+    ("OR", 1, MAX_ARGUMENTS),
+    ("PDURATION", 3, 3),
+    ("PEARSON", 2, 2),
+    ("PERCENTILE", 2, 2),
+    ("PERCENTILE.EXC", 2, 2),
+    ("PERCENTILE.INC", 2, 2),
+    ("PERCENTOF", 1, 2),
+    ("PERCENTRANK", 2, 3),
+    ("PERCENTRANK.EXC", 2, 3),
+    ("PERCENTRANK.INC", 2, 3),
+    ("PERMUT", 2, 2),
+    ("PERMUTATIONA", 2, 2),
+    ("PHI", 1, 1),
+    ("PHONETIC", 1, 1),
+    ("PI", 0, 0),
+    ("PIVOTBY", 4, 11),
+    ("PMT", 3, 5),
+    ("POISSON", 3, 3),
+    ("POISSON.DIST", 3, 3),
+    ("POWER", 2, 2),
+    ("PPMT", 4, 6),
+    ("PRICE", 6, 7),
+    ("PRICEDISC", 4, 5),
+    ("PRICEMAT", 5, 6),
+    ("PROB", 3, 4),
+    ("PRODUCT", 1, MAX_ARGUMENTS),
+    ("PROPER", 1, 1),
+    ("PV", 3, 5),
+    ("QUARTILE", 2, 2),
+    ("QUARTILE.EXC", 2, 2),
+    ("QUARTILE.INC", 2, 2),
+    ("QUOTIENT", 2, 2),
+    ("RADIANS", 1, 1),
+    ("RAND", 0, 0),
+    ("RANDARRAY", 0, 5),
+    ("RANDBETWEEN", 2, 2),
+    ("RANK", 2, 3),
+    ("RANK.AVG", 2, 3),
+    ("RANK.EQ", 2, 3),
+    ("RATE", 3, 6),
+    ("RECEIVED", 4, 5),
+    ("REDUCE", 3, 3),
+    ("REGEXEXTRACT", 2, 4),
+    ("REGEXREPLACE", 3, 6),
+    ("REGEXTEST", 2, 3),
+    ("REGISTER.ID", 2, 3),
+    ("REPLACE", 4, 4),
+    ("REPLACEB", 4, 4),
+    ("REPT", 2, 2),
+    ("RIGHT", 1, 2),
+    ("RIGHTB", 1, 2),
+    ("ROMAN", 1, 2),
+    ("ROUND", 2, 2),
+    ("ROUNDDOWN", 2, 2),
+    ("ROUNDUP", 2, 2),
+    ("ROW", 0, 1),
+    ("ROWS", 1, 1),
+    ("RRI", 3, 3),
+    ("RSQ", 2, 2),
+    ("RTD", 3, MAX_ARGUMENTS),
+    ("SCAN", 3, 3),
+    ("SEARCH", 2, 3),
+    ("SEARCHB", 2, 3),
+    ("SEC", 1, 1),
+    ("SECH", 1, 1),
+    ("SECOND", 1, 1),
+    ("SEQUENCE", 1, 4),
+    ("SERIESSUM", 4, 4),
+    ("SHEET", 0, 1),
+    ("SHEETS", 0, 1),
+    ("SIGN", 1, 1),
+    ("SIN", 1, 1),
+    ("SINGLE", 1, 1),
+    ("SINH", 1, 1),
+    ("SKEW", 1, MAX_ARGUMENTS),
+    ("SKEW.P", 1, MAX_ARGUMENTS),
+    ("SLN", 3, 3),
+    ("SLOPE", 2, 2),
+    ("SMALL", 2, 2),
+    ("SORT", 1, 4),
+    ("SORTBY", 2, MAX_ARGUMENTS),
+    ("SQRT", 1, 1),
+    ("SQRTPI", 1, 1),
+    ("STANDARDIZE", 3, 3),
+    ("STDEV", 1, MAX_ARGUMENTS),
+    ("STDEV.P", 1, MAX_ARGUMENTS),
+    ("STDEV.S", 1, MAX_ARGUMENTS),
+    ("STDEVA", 1, MAX_ARGUMENTS),
+    ("STDEVP", 1, MAX_ARGUMENTS),
+    ("STDEVPA", 1, MAX_ARGUMENTS),
+    ("STEYX", 2, 2),
+    ("STOCKHISTORY", 2, MAX_ARGUMENTS),
+    ("SUBSTITUTE", 3, 4),
+    ("SUBTOTAL", 2, MAX_ARGUMENTS),
+    ("SUM", 1, MAX_ARGUMENTS),
+    ("SUMIF", 2, 3),
+    ("SUMIFS", 3, MAX_ARGUMENTS),
+    ("SUMPRODUCT", 1, MAX_ARGUMENTS),
+    ("SUMSQ", 1, MAX_ARGUMENTS),
+    ("SUMX2MY2", 2, 2),
+    ("SUMX2PY2", 2, 2),
+    ("SUMXMY2", 2, 2),
+    ("SWITCH", 3, 254),
+    ("SYD", 4, 4),
+    ("T", 1, 1),
+    ("T.DIST", 3, 3),
+    ("T.DIST.2T", 2, 2),
+    ("T.DIST.RT", 2, 2),
+    ("T.INV", 2, 2),
+    ("T.INV.2T", 2, 2),
+    ("T.TEST", 4, 4),
+    ("TAKE", 2, 3),
+    ("TAN", 1, 1),
+    ("TANH", 1, 1),
+    ("TBILLEQ", 3, 3),
+    ("TBILLPRICE", 3, 3),
+    ("TBILLYIELD", 3, 3),
+    ("TDIST", 3, 3),
+    ("TEXT", 2, 2),
+    ("TEXTAFTER", 2, 6),
+    ("TEXTBEFORE", 2, 6),
+    ("TEXTJOIN", 3, MAX_ARGUMENTS),
+    ("TEXTSPLIT", 2, 6),
+    ("TIME", 3, 3),
+    ("TIMEVALUE", 1, 1),
+    ("TINV", 2, 2),
+    ("TOCOL", 1, 3),
+    ("TODAY", 0, 0),
+    ("TOROW", 1, 3),
+    ("TRANSPOSE", 1, 1),
+    ("TREND", 1, 4),
+    ("TRIM", 1, 1),
+    ("TRIMMEAN", 2, 2),
+    ("TRIMRANGE", 1, 3),
+    ("TRUE", 0, 0),
+    ("TRUNC", 1, 2),
+    ("TTEST", 4, 4),
+    ("TYPE", 1, 1),
+    ("UNICHAR", 1, 1),
+    ("UNICODE", 1, 1),
+    ("UNIQUE", 1, 3),
+    ("UPPER", 1, 1),
+    ("USDOLLAR", 1, 2),
+    ("VALUE", 1, 1),
+    ("VALUETOTEXT", 1, 2),
+    ("VAR", 1, MAX_ARGUMENTS),
+    ("VAR.P", 1, MAX_ARGUMENTS),
+    ("VAR.S", 1, MAX_ARGUMENTS),
+    ("VARA", 1, MAX_ARGUMENTS),
+    ("VARP", 1, MAX_ARGUMENTS),
+    ("VARPA", 1, MAX_ARGUMENTS),
+    ("VDB", 5, 7),
+    ("VLOOKUP", 3, 4),
+    ("VSTACK", 1, MAX_ARGUMENTS),
+    ("WEBSERVICE", 1, 1),
+    ("WEEKDAY", 1, 2),
+    ("WEEKNUM", 1, 2),
+    ("WEIBULL", 4, 4),
+    ("WEIBULL.DIST", 4, 4),
+    ("WORKDAY", 2, 3),
+    ("WORKDAY.INTL", 2, 4),
+    ("WRAPCOLS", 2, 3),
+    ("WRAPROWS", 2, 3),
+    ("XIRR", 2, 3),
+    ("XLOOKUP", 3, 6),
+    ("XMATCH", 2, 4),
+    ("XNPV", 3, 3),
+    ("XOR", 1, MAX_ARGUMENTS),
+    ("YEAR", 1, 1),
+    ("YEARFRAC", 2, 3),
+    ("YIELD", 6, 7),
+    ("YIELDDISC", 4, 5),
+    ("YIELDMAT", 5, 6),
+    ("Z.TEST", 2, 3),
+    ("ZTEST", 2, 3),
+];
+
+/// Get the minimum and maximum number of arguments of a function, `None`
+/// when Excel has no function of this name.
+/// # Arguments
+/// * `name` - Function name without the `_xlfn.` prefix. ex) "SUM"
+pub(crate) fn function_arity(name: &str) -> Option<(usize, usize)> {
+    let name = name.to_uppercase();
+    FUNCTIONS
+        .binary_search_by(|(x, ..)| (*x).cmp(name.as_str()))
+        .ok()
+        .map(|index| (FUNCTIONS[index].1, FUNCTIONS[index].2))
+}
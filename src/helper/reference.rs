@@ -0,0 +1,607 @@
+//! Evaluation of defined names to the cells they refer to.
+
+use crate::{
+    XlsxError,
+    helper::{
+        coordinate::index_from_coordinate,
+        formula::{
+            FormulaToken,
+            FormulaTokenSubTypes,
+            FormulaTokenTypes,
+            parse_to_tokens,
+        },
+    },
+    structs::{
+        CellValue,
+        DefinedName,
+        Workbook,
+        Worksheet,
+    },
+};
+
+const MAX_COLUMN: u32 = 16_384;
+const MAX_ROW: u32 = 1_048_576;
+const MAX_NAME_DEPTH: usize = 32;
+/// Cells a name may list, as many as a whole column has.
+pub(crate) const MAX_AREA_CELLS: u64 = MAX_ROW as u64;
+
+/// A rectangular block of cells of a worksheet.
+/// Whole columns and whole rows reach up to `MAX_ROW` and `MAX_COLUMN`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Area {
+    pub(crate) sheet_name: String,
+    pub(crate) col_start:  u32,
+    pub(crate) row_start:  u32,
+    pub(crate) col_end:    u32,
+    pub(crate) row_end:    u32,
+}
+
+impl Area {
+    /// Parse a range without sheet name. ex) "$A$1", "A1:C3", "A:B", "1:2"
+    fn parse(sheet_name: &str, range: &str) -> Option<Self> {
+        let mut points = range.split(':');
+        let start = index_from_coordinate(points.next()?.to_uppercase());
+        let end = match points.next() {
+            Some(v) => index_from_coordinate(v.to_uppercase()),
+            None => start,
+        };
+        if points.next().is_some() {
+            return None;
+        }
+        let (col_start, row_start, col_end, row_end) = match (start.0, start.1, end.0, end.1) {
+            (Some(c1), Some(r1), Some(c2), Some(r2)) => (c1, r1, c2, r2),
+            (Some(c1), None, Some(c2), None) if range.contains(':') => (c1, 1, c2, MAX_ROW),
+            (None, Some(r1), None, Some(r2)) if range.contains(':') => (1, r1, MAX_COLUMN, r2),
+            _ => return None,
+        };
+        if col_start == 0 || row_start == 0 || col_end > MAX_COLUMN || row_end > MAX_ROW {
+            return None;
+        }
+        Some(Self {
+            sheet_name: sheet_name.to_string(),
+            col_start: col_start.min(col_end),
+            row_start: row_start.min(row_end),
+            col_end: col_start.max(col_end),
+            row_end: row_start.max(row_end),
+        })
+    }
+
+    #[inline]
+    fn contains(&self, col: u32, row: u32) -> bool {
+        (self.col_start..=self.col_end).contains(&col)
+            && (self.row_start..=self.row_end).contains(&row)
+    }
+
+    #[inline]
+    fn rows(&self) -> u32 {
+        self.row_end - self.row_start + 1
+    }
+
+    #[inline]
+    fn columns(&self) -> u32 {
+        self.col_end - self.col_start + 1
+    }
+
+    /// The last column and row of the cells.
+    /// Whole columns and whole rows stop at the last used row and column.
+    fn used_end(&self, worksheet: &Worksheet) -> (u32, u32) {
+        let (highest_col, highest_row) = worksheet.highest_column_and_row();
+        let col_end = if self.col_end == MAX_COLUMN {
+            highest_col.min(self.col_end)
+        } else {
+            self.col_end
+        };
+        let row_end = if self.row_end == MAX_ROW {
+            highest_row.min(self.row_end)
+        } else {
+            self.row_end
+        };
+        (col_end, row_end)
+    }
+
+    /// Number of the cells `coordinates` returns.
+    pub(crate) fn cell_count(&self, worksheet: &Worksheet) -> u64 {
+        let (col_end, row_end) = self.used_end(worksheet);
+        u64::from((col_end + 1).saturating_sub(self.col_start))
+            * u64::from((row_end + 1).saturating_sub(self.row_start))
+    }
+
+    /// Coordinates `(col, row)` of the cells, row by row.
+    /// Whole columns and whole rows stop at the last used row and column.
+    pub(crate) fn coordinates(&self, worksheet: &Worksheet) -> Vec<(u32, u32)> {
+        let (col_end, row_end) = self.used_end(worksheet);
+        (self.row_start..=row_end)
+            .flat_map(|row| (self.col_start..=col_end).map(move |col| (col, row)))
+            .collect()
+    }
+}
+
+/// The result of evaluating a defined name.
+#[derive(Clone, Debug)]
+pub(crate) enum NameValue {
+    Areas(Vec<Area>),
+    Number(f64),
+    Text(String),
+}
+
+impl NameValue {
+    /// The constant of a name that does not refer to cells.
+    pub(crate) fn constant(&self) -> Option<CellValue> {
+        let mut obj = CellValue::default();
+        match self {
+            Self::Areas(_) => return None,
+            Self::Number(v) => obj.set_value_number(*v),
+            Self::Text(v) => obj.set_value_string(v),
+        };
+        Some(obj)
+    }
+}
+
+/// Evaluate the defined name `name` seen from the sheet at `scope`.
+/// `name` may be qualified with a sheet name. ex) "Rate", "'My Sheet'!Rate"
+pub(crate) fn resolve_defined_name(
+    workbook: &Workbook,
+    name: &str,
+    scope: Option<usize>,
+) -> Result<NameValue, XlsxError> {
+    let (sheet_name, local) = split_reference(name);
+    let scope = match sheet_name {
+        Some(v) => Some(workbook.find_sheet_index_by_name(&v)?),
+        None => scope,
+    };
+    Evaluator { workbook }.name(local, scope, 0)
+}
+
+/// Find a defined name. A name of the sheet at `scope` hides a workbook
+/// name of the same name.
+pub(crate) fn find_defined_name<'a>(
+    workbook: &'a Workbook,
+    name: &str,
+    scope: Option<usize>,
+) -> Option<&'a DefinedName> {
+    let lists = || {
+        std::iter::once(workbook.defined_names()).chain(
+            workbook
+                .sheet_collection_no_check()
+                .iter()
+                .map(Worksheet::defined_names),
+        )
+    };
+    let find = |scope: Option<usize>| {
+        lists().flatten().find(|x| {
+            x.name().eq_ignore_ascii_case(name)
+                && match scope {
+                    Some(index) => x.has_local_sheet_id() && x.local_sheet_id() as usize == index,
+                    None => !x.has_local_sheet_id(),
+                }
+        })
+    };
+    scope.and_then(|index| find(Some(index))).or_else(|| find(None))
+}
+
+/// Split `'Sheet''s'!A1` into `Some("Sheet's")` and `A1`.
+pub(crate) fn split_reference(reference: &str) -> (Option<String>, &str) {
+    match reference.rsplit_once('!') {
+        Some((sheet_name, local)) => {
+            let sheet_name = match sheet_name.strip_prefix('\'') {
+                Some(v) => v.strip_suffix('\'').unwrap_or(v).replace("''", "'"),
+                None => sheet_name.to_string(),
+            };
+            (Some(sheet_name), local)
+        }
+        None => (None, reference),
+    }
+}
+
+/// Split `'[1]Sheet 1'!A1` into the number of the external workbook, `1`,
+/// and the reference without it, `'Sheet 1'!A1`.
+pub(crate) fn split_external_book(reference: &str) -> Option<(usize, String)> {
+    let (quote, rest) = match reference.strip_prefix('\'') {
+        Some(v) => ("'", v),
+        None => ("", reference),
+    };
+    let (number, rest) = rest.strip_prefix('[')?.split_once(']')?;
+    let number = number.parse::<usize>().ok()?;
+    Some((number, format!("{quote}{rest}")))
+}
+
+/// Put the number of an external workbook back in front of a reference
+/// split by `split_external_book`.
+pub(crate) fn join_external_book(number: usize, reference: &str) -> String {
+    match reference.strip_prefix('\'') {
+        Some(v) => format!("'[{number}]{v}"),
+        None => format!("[{number}]{reference}"),
+    }
+}
+
+/// Split a reference list on the commas outside quoted sheet names.
+fn split_areas(value: &str) -> Vec<&str> {
+    let mut result = Vec::new();
+    let mut in_quote = false;
+    let mut start = 0;
+    for (index, c) in value.char_indices() {
+        match c {
+            '\'' => in_quote = !in_quote,
+            ',' if !in_quote => {
+                result.push(&value[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    result.push(&value[start..]);
+    result.into_iter().filter(|x| !x.is_empty()).collect()
+}
+
+#[inline]
+fn invalid<T>(message: String) -> Result<T, XlsxError> {
+    Err(XlsxError::InvalidArgument(message))
+}
+
+struct Evaluator<'a> {
+    workbook: &'a Workbook,
+}
+
+impl Evaluator<'_> {
+    fn name(&self, name: &str, scope: Option<usize>, depth: usize) -> Result<NameValue, XlsxError> {
+        if depth > MAX_NAME_DEPTH {
+            return invalid(format!("\"{name}\" refers to itself"));
+        }
+        let defined_name =
+            find_defined_name(self.workbook, name, scope).ok_or(XlsxError::NotFound())?;
+        // The formula of a sheet scoped name is seen from its own sheet.
+        let scope = if defined_name.has_local_sheet_id() {
+            Some(defined_name.local_sheet_id() as usize)
+        } else {
+            scope
+        };
+        match defined_name.string_value() {
+            Some(formula) => self.formula(&formula, scope, depth),
+            None => Ok(NameValue::Areas(
+                defined_name
+                    .address_obj()
+                    .iter()
+                    .map(|address| {
+                        Area::parse(address.sheet_name(), &address.range().range()).ok_or_else(
+                            || XlsxError::InvalidArgument(format!("\"{name}\" is not a valid range")),
+                        )
+                    })
+                    .collect::<Result<Vec<Area>, XlsxError>>()?,
+            )),
+        }
+    }
+
+    fn formula(&self, formula: &str, scope: Option<usize>, depth: usize) -> Result<NameValue, XlsxError> {
+        let formula = formula.strip_prefix('=').unwrap_or(formula);
+        let tokens: Vec<FormulaToken> = parse_to_tokens(format!("={formula}"))
+            .into_iter()
+            .filter(|x| x.get_token_type() != &FormulaTokenTypes::Whitespace)
+            .collect();
+        let mut parser = Parser {
+            evaluator: self,
+            tokens: &tokens,
+            position: 0,
+            scope,
+            depth,
+        };
+        let value = parser.union()?;
+        if parser.position < tokens.len() {
+            return invalid(format!("\"{formula}\" can not be evaluated"));
+        }
+        Ok(value)
+    }
+
+    fn reference(&self, value: &str, scope: Option<usize>, depth: usize) -> Result<NameValue, XlsxError> {
+        let mut areas = Vec::new();
+        for reference in split_areas(value) {
+            let (sheet_name, local) = split_reference(reference);
+            let default_sheet_name = || {
+                scope
+                    .and_then(|index| self.workbook.sheet_collection_no_check().get(index))
+                    .map(|sheet| sheet.name().to_string())
+            };
+            let area = sheet_name
+                .clone()
+                .or_else(default_sheet_name)
+                .and_then(|sheet_name| Area::parse(&sheet_name, local));
+            if let Some(area) = area {
+                areas.push(area);
+                continue;
+            }
+            let scope = match sheet_name {
+                Some(v) => Some(self.workbook.find_sheet_index_by_name(&v)?),
+                None => scope,
+            };
+            match self.name(local, scope, depth + 1)? {
+                NameValue::Areas(v) => areas.extend(v),
+                v if areas.is_empty() && split_areas(value).len() == 1 => return Ok(v),
+                _ => return invalid(format!("\"{value}\" is not a reference")),
+            }
+        }
+        Ok(NameValue::Areas(areas))
+    }
+
+    fn sheet(&self, area: &Area) -> Result<&Worksheet, XlsxError> {
+        self.workbook.sheet_by_name(&area.sheet_name)
+    }
+
+    fn number(&self, value: &NameValue) -> Result<f64, XlsxError> {
+        match value {
+            NameValue::Number(v) => Ok(*v),
+            NameValue::Text(v) => v
+                .parse()
+                .or_else(|_| invalid(format!("\"{v}\" is not a number"))),
+            NameValue::Areas(areas) => match areas.as_slice() {
+                [area] if area.rows() == 1 && area.columns() == 1 => Ok(self
+                    .sheet(area)?
+                    .cell_value((area.col_start, area.row_start))
+                    .value_number()
+                    .unwrap_or_default()),
+                _ => invalid("a range is not a number".to_string()),
+            },
+        }
+    }
+
+    /// Count the cells of `value` matching `is_counted`.
+    fn count<F>(&self, value: &NameValue, is_counted: F) -> Result<f64, XlsxError>
+    where
+        F: Fn(&CellValue) -> bool,
+    {
+        let NameValue::Areas(areas) = value else {
+            return Ok(1.0);
+        };
+        let mut count = 0usize;
+        for area in areas {
+            count += self
+                .sheet(area)?
+                .cells()
+                .into_iter()
+                .filter(|cell| {
+                    let coordinate = cell.coordinate();
+                    area.contains(coordinate.col_num(), coordinate.row_num())
+                        && is_counted(cell.cell_value())
+                })
+                .count();
+        }
+        Ok(num_traits::cast(count).unwrap())
+    }
+
+    fn function(&self, name: &str, args: &[NameValue]) -> Result<NameValue, XlsxError> {
+        let single_area = |value: &NameValue| match value {
+            NameValue::Areas(areas) if areas.len() == 1 => Ok(areas[0].clone()),
+            _ => invalid(format!("{name} needs a single range")),
+        };
+        let offset = |value: u32, offset: f64| -> Result<u32, XlsxError> {
+            let result = f64::from(value) + offset.trunc();
+            if result < 1.0 {
+                return invalid(format!("{name} goes outside the sheet"));
+            }
+            Ok(num_traits::cast(result).unwrap_or(u32::MAX))
+        };
+        let number = match name.to_uppercase().as_str() {
+            "OFFSET" => {
+                if !(3..=5).contains(&args.len()) {
+                    return invalid("OFFSET needs 3 to 5 arguments".to_string());
+                }
+                let area = single_area(&args[0])?;
+                let height = match args.get(3) {
+                    Some(v) => self.number(v)?,
+                    None => f64::from(area.rows()),
+                };
+                let width = match args.get(4) {
+                    Some(v) => self.number(v)?,
+                    None => f64::from(area.columns()),
+                };
+                if height < 1.0 || width < 1.0 {
+                    return invalid("OFFSET needs a positive height and width".to_string());
+                }
+                let row_start = offset(area.row_start, self.number(&args[1])?)?;
+                let col_start = offset(area.col_start, self.number(&args[2])?)?;
+                let row_end = offset(row_start, height - 1.0)?;
+                let col_end = offset(col_start, width - 1.0)?;
+                if row_end > MAX_ROW || col_end > MAX_COLUMN {
+                    return invalid("OFFSET goes outside the sheet".to_string());
+                }
+                return Ok(NameValue::Areas(vec![Area {
+                    sheet_name: area.sheet_name,
+                    col_start,
+                    row_start,
+                    col_end,
+                    row_end,
+                }]));
+            }
+            "INDEX" => {
+                if !(2..=3).contains(&args.len()) {
+                    return invalid("INDEX needs 2 or 3 arguments".to_string());
+                }
+                let mut area = single_area(&args[0])?;
+                let (row, col) = match args.get(2) {
+                    Some(v) => (self.number(&args[1])?, self.number(v)?),
+                    None if area.rows() == 1 => (1.0, self.number(&args[1])?),
+                    None => (self.number(&args[1])?, 1.0),
+                };
+                if row >= 1.0 {
+                    area.row_start = offset(area.row_start, row - 1.0)?;
+                    area.row_end = area.row_start;
+                }
+                if col >= 1.0 {
+                    area.col_start = offset(area.col_start, col - 1.0)?;
+                    area.col_end = area.col_start;
+                }
+                return Ok(NameValue::Areas(vec![area]));
+            }
+            "COUNTA" => args.iter().try_fold(0.0, |sum, x| {
+                Ok::<f64, XlsxError>(sum + self.count(x, |v| !v.is_empty())?)
+            })?,
+            "COUNT" => args.iter().try_fold(0.0, |sum, x| {
+                Ok::<f64, XlsxError>(sum + self.count(x, |v| v.value_number().is_some())?)
+            })?,
+            "ROWS" => f64::from(single_area(args.first().unwrap_or(&NameValue::Number(0.0)))?.rows()),
+            "COLUMNS" => {
+                f64::from(single_area(args.first().unwrap_or(&NameValue::Number(0.0)))?.columns())
+            }
+            "SUM" => {
+                let mut sum = 0.0;
+                for arg in args {
+                    match arg {
+                        NameValue::Areas(areas) => {
+                            for area in areas {
+                                sum += self
+                                    .sheet(area)?
+                                    .cells()
+                                    .into_iter()
+                                    .filter(|cell| {
+                                        let coordinate = cell.coordinate();
+                                        area.contains(coordinate.col_num(), coordinate.row_num())
+                                    })
+                                    .filter_map(|cell| cell.cell_value().value_number())
+                                    .sum::<f64>();
+                            }
+                        }
+                        v => sum += self.number(v)?,
+                    }
+                }
+                sum
+            }
+            _ => return invalid(format!("{name} is not supported in a defined name")),
+        };
+        Ok(NameValue::Number(number))
+    }
+}
+
+/// Recursive descent over the tokens of a formula.
+struct Parser<'a, 'b> {
+    evaluator: &'a Evaluator<'b>,
+    tokens:    &'a [FormulaToken],
+    position:  usize,
+    scope:     Option<usize>,
+    depth:     usize,
+}
+
+impl Parser<'_, '_> {
+    #[inline]
+    fn peek(&self) -> Option<&FormulaToken> {
+        self.tokens.get(self.position)
+    }
+
+    fn is_next(&self, token_type: &FormulaTokenTypes, sub_type: &FormulaTokenSubTypes) -> bool {
+        self.peek().is_some_and(|x| {
+            x.get_token_type() == token_type && x.get_token_sub_type() == sub_type
+        })
+    }
+
+    fn is_next_math(&self, operators: &[&str]) -> bool {
+        self.is_next(&FormulaTokenTypes::OperatorInfix, &FormulaTokenSubTypes::Math)
+            && operators.contains(&self.peek().unwrap().get_value())
+    }
+
+    fn is_next_separator(&self) -> bool {
+        self.is_next(&FormulaTokenTypes::OperatorInfix, &FormulaTokenSubTypes::Union)
+            || self.is_next(&FormulaTokenTypes::Argument, &FormulaTokenSubTypes::Nothing)
+    }
+
+    /// References separated by commas.
+    fn union(&mut self) -> Result<NameValue, XlsxError> {
+        let value = self.additive()?;
+        if !self.is_next_separator() {
+            return Ok(value);
+        }
+        let NameValue::Areas(mut areas) = value else {
+            return invalid("a union needs references".to_string());
+        };
+        while self.is_next_separator() {
+            self.position += 1;
+            match self.additive()? {
+                NameValue::Areas(v) => areas.extend(v),
+                _ => return invalid("a union needs references".to_string()),
+            }
+        }
+        Ok(NameValue::Areas(areas))
+    }
+
+    fn additive(&mut self) -> Result<NameValue, XlsxError> {
+        let mut value = self.multiplicative()?;
+        while self.is_next_math(&["+", "-"]) {
+            let is_add = self.peek().unwrap().get_value() == "+";
+            self.position += 1;
+            let left = self.evaluator.number(&value)?;
+            let right = self.evaluator.number(&self.multiplicative()?)?;
+            value = NameValue::Number(if is_add { left + right } else { left - right });
+        }
+        Ok(value)
+    }
+
+    fn multiplicative(&mut self) -> Result<NameValue, XlsxError> {
+        let mut value = self.unary()?;
+        while self.is_next_math(&["*", "/"]) {
+            let is_multiply = self.peek().unwrap().get_value() == "*";
+            self.position += 1;
+            let left = self.evaluator.number(&value)?;
+            let right = self.evaluator.number(&self.unary()?)?;
+            if !is_multiply && right == 0.0 {
+                return invalid("division by zero".to_string());
+            }
+            value = NameValue::Number(if is_multiply { left * right } else { left / right });
+        }
+        Ok(value)
+    }
+
+    fn unary(&mut self) -> Result<NameValue, XlsxError> {
+        if let Some(token) = self.peek() {
+            if token.get_token_type() == &FormulaTokenTypes::OperatorPrefix {
+                let is_negative = token.get_value() == "-";
+                self.position += 1;
+                let value = self.evaluator.number(&self.unary()?)?;
+                return Ok(NameValue::Number(if is_negative { -value } else { value }));
+            }
+        }
+        self.primary()
+    }
+
+    fn primary(&mut self) -> Result<NameValue, XlsxError> {
+        let Some(token) = self.peek().cloned() else {
+            return invalid("unexpected end of formula".to_string());
+        };
+        self.position += 1;
+        match (token.get_token_type(), token.get_token_sub_type()) {
+            (FormulaTokenTypes::Operand, FormulaTokenSubTypes::Number) => token
+                .get_value()
+                .parse()
+                .map(NameValue::Number)
+                .or_else(|_| invalid(format!("\"{}\" is not a number", token.get_value()))),
+            (FormulaTokenTypes::Operand, FormulaTokenSubTypes::Text) => {
+                Ok(NameValue::Text(token.get_value().to_string()))
+            }
+            (FormulaTokenTypes::Operand, FormulaTokenSubTypes::Range) => {
+                self.evaluator
+                    .reference(token.get_value(), self.scope, self.depth)
+            }
+            (FormulaTokenTypes::Subexpression, FormulaTokenSubTypes::Start) => {
+                let value = self.union()?;
+                self.expect_stop(&FormulaTokenTypes::Subexpression)?;
+                Ok(value)
+            }
+            (FormulaTokenTypes::Function, FormulaTokenSubTypes::Start) => {
+                let mut args = Vec::new();
+                if !self.is_next(&FormulaTokenTypes::Function, &FormulaTokenSubTypes::Stop) {
+                    args.push(self.additive()?);
+                    while self.is_next_separator() {
+                        self.position += 1;
+                        args.push(self.additive()?);
+                    }
+                }
+                self.expect_stop(&FormulaTokenTypes::Function)?;
+                self.evaluator.function(token.get_value(), &args)
+            }
+            _ => invalid(format!("\"{}\" can not be evaluated", token.get_value())),
+        }
+    }
+
+    fn expect_stop(&mut self, token_type: &FormulaTokenTypes) -> Result<(), XlsxError> {
+        if !self.is_next(token_type, &FormulaTokenSubTypes::Stop) {
+            return invalid("missing closing parenthesis".to_string());
+        }
+        self.position += 1;
+        Ok(())
+    }
+}
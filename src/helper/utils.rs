@@ -14,6 +14,42 @@ pub(crate) fn md5_hash(input: impl AsRef<[u8]>) -> String {
     output
 }
 
+/// Generate a random GUID. ex) "{0E6F1C2A-7B0D-4E5A-9C3F-2D8B6A1E4F70}"
+pub(crate) fn new_guid() -> String {
+    let mut bytes = [0u8; 16];
+    rand::fill(&mut bytes[..]);
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    format_guid(&bytes)
+}
+
+/// Generate a GUID derived from the given input.
+/// The same input always gives the same GUID.
+pub(crate) fn guid_from_hash(input: impl AsRef<[u8]>) -> String {
+    let mut bytes: [u8; 16] = md5::Md5::digest(input).into();
+    bytes[6] = (bytes[6] & 0x0f) | 0x30;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    format_guid(&bytes)
+}
+
+fn format_guid(bytes: &[u8; 16]) -> String {
+    const HEX: &[u8; 16] = b"0123456789ABCDEF";
+
+    let mut hex = String::with_capacity(32);
+    for byte in bytes.iter().copied() {
+        hex.push(HEX[(byte >> 4) as usize] as char);
+        hex.push(HEX[(byte & 0x0f) as usize] as char);
+    }
+    format!(
+        "{{{}-{}-{}-{}-{}}}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
 pub(crate) fn unescape_xml_text(e: &quick_xml::events::BytesText<'_>) -> String {
     let decoded = e.decode().unwrap();
     quick_xml::escape::unescape(decoded.as_ref())
@@ -21,6 +57,17 @@ pub(crate) fn unescape_xml_text(e: &quick_xml::events::BytesText<'_>) -> String
         .into_owned()
 }
 
+/// The text of an entity reference such as `&gt;` or `&#39;`, which the
+/// reader reports apart from the surrounding text.
+pub(crate) fn unescape_xml_general_ref(e: &quick_xml::events::BytesRef<'_>) -> String {
+    if let Ok(Some(ch)) = e.resolve_char_ref() {
+        return ch.to_string();
+    }
+    let name = e.decode().unwrap();
+    quick_xml::escape::resolve_predefined_entity(&name)
+        .map_or_else(|| format!("&{name};"), str::to_string)
+}
+
 /// A macro that implements the `From` trait for converting from one error type
 /// to another.
 ///
@@ -5,10 +5,26 @@ use std::{
         Path,
         PathBuf,
     },
+    str::FromStr,
     string::FromUtf8Error,
 };
 
-use quick_xml::events::attributes::Attribute;
+use quick_xml::{
+    Reader,
+    events::{
+        BytesStart,
+        Event,
+        attributes::Attribute,
+    },
+};
+
+use crate::{
+    XlsxError,
+    structs::{
+        ReadLimit,
+        ReadLimits,
+    },
+};
 
 #[macro_export]
 macro_rules! xml_read_loop {
@@ -17,7 +33,9 @@ macro_rules! xml_read_loop {
         loop {
             let ev = match $reader.read_event_into(&mut buf) {
                 Ok(v) => v,
-                Err(e) => panic!("Error at position {}: {e:?}", $reader.buffer_position()),
+                Err(e) => {
+                    return Err($crate::XlsxError::parse($reader.buffer_position(), e.to_string()))
+                }
             };
 
             match ev {
@@ -43,6 +61,22 @@ macro_rules! set_string_from_xml {
 
 pub(crate) use crate::set_string_from_xml;
 
+/// `set_string_from_xml!` for the values that refuse a text they can not
+/// parse, with the parse error at the position of `reader`.
+#[macro_export]
+macro_rules! try_set_string_from_xml {
+    ($self:ident, $reader:ident, $e:ident, $attr:ident, $xml_attr:expr) => {{
+        if let Some(v) = get_attribute($e, $xml_attr.as_bytes()) {
+            $self
+                .$attr
+                .set_value_string(v)
+                .map_err(|error| error.at($reader.buffer_position()))?;
+        }
+    }};
+}
+
+pub(crate) use crate::try_set_string_from_xml;
+
 pub(crate) fn normalize_path(path: &str) -> PathBuf {
     let path = Path::new(path);
     let mut components = path.components().peekable();
@@ -131,15 +165,13 @@ pub(crate) fn zip_by_name<'a, R: io::Read + io::Seek>(
 /// This handles XLSX files that use non-standard namespace prefixes
 /// (e.g. `d3p1:id` instead of `r:id`).
 #[inline]
-pub(crate) fn get_attribute(e: &quick_xml::events::BytesStart<'_>, key: &[u8]) -> Option<String> {
+pub(crate) fn get_attribute(e: &BytesStart<'_>, key: &[u8]) -> Option<String> {
     // 1. Exact match (fast path)
     let result = e
         .attributes()
         .with_checks(false)
         .find_map(|attr| match attr {
-            Ok(ref attr) if attr.key.into_inner() == key => {
-                Some(get_attribute_value(attr).unwrap())
-            }
+            Ok(ref attr) if attr.key.into_inner() == key => get_attribute_value(attr).ok(),
             _ => None,
         });
     if result.is_some() {
@@ -164,7 +196,7 @@ pub(crate) fn get_attribute(e: &quick_xml::events::BytesStart<'_>, key: &[u8]) -
                     None => attr_key,
                 };
                 if attr_local == local_name {
-                    Some(get_attribute_value(attr).unwrap())
+                    get_attribute_value(attr).ok()
                 } else {
                     None
                 }
@@ -173,6 +205,169 @@ pub(crate) fn get_attribute(e: &quick_xml::events::BytesStart<'_>, key: &[u8]) -
         })
 }
 
+/// Look up an attribute that the element must have.
+/// # Errors
+/// Returns a parse error when the attribute is missing.
+#[inline]
+pub(crate) fn get_required_attribute<R>(
+    reader: &Reader<R>,
+    e: &BytesStart<'_>,
+    key: &[u8],
+) -> Result<String, XlsxError> {
+    get_attribute(e, key).ok_or_else(|| {
+        XlsxError::missing_attribute(e.name().into_inner(), key, reader.buffer_position())
+    })
+}
+
+/// Parse an attribute or a text value.
+/// # Errors
+/// Returns a parse error when the value is not valid for `T`.
+#[inline]
+pub(crate) fn parse_value<T: FromStr, R>(reader: &Reader<R>, value: &str) -> Result<T, XlsxError> {
+    value.parse::<T>().map_err(|_| {
+        XlsxError::parse(
+            reader.buffer_position(),
+            format!("invalid value \"{value}\""),
+        )
+    })
+}
+
+/// Fill in the part name and the element path of a parse error.
+/// The path is found by reading `data` up to the offset of the error.
+pub(crate) fn parse_error_context(error: XlsxError, part: &str, data: &[u8]) -> XlsxError {
+    let XlsxError::Parse(mut detail) = error else {
+        return error;
+    };
+    if detail.has_part() {
+        return XlsxError::Parse(detail);
+    }
+    let mut reader = Reader::from_reader(data);
+    reader.config_mut().check_end_names = false;
+    let mut stack: Vec<String> = Vec::new();
+    let mut buf = Vec::new();
+    while reader.buffer_position() < detail.offset() {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(ref e)) => {
+                stack.push(String::from_utf8_lossy(e.name().into_inner()).into_owned());
+            }
+            Ok(Event::End(_)) if reader.buffer_position() < detail.offset() => {
+                stack.pop();
+            }
+            Ok(Event::Eof) | Err(_) => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+    detail.set_part(part.to_string(), stack.join("/"));
+    XlsxError::Parse(detail)
+}
+
+/// Fill in the part name and the element path of a parse error raised while
+/// reading a part of the archive.
+pub(crate) fn zip_parse_error_context<R: io::Read + io::Seek>(
+    error: XlsxError,
+    arv: &mut zip::ZipArchive<R>,
+    part: &str,
+) -> XlsxError {
+    if !matches!(error, XlsxError::Parse(_)) {
+        return error;
+    }
+    let mut data = Vec::new();
+    if let Ok(mut file) = zip_by_name(arv, part) {
+        if io::Read::read_to_end(&mut file, &mut data).is_err() {
+            data.clear();
+        }
+    }
+    parse_error_context(error, part, &data)
+}
+
+/// Check the parts of the archive against the size and depth limits.
+/// Every part is decompressed once, reading no more than the part size
+/// limit, so the declared sizes in the archive are not trusted.
+pub(crate) fn check_read_limits<R: io::Read + io::Seek>(
+    arv: &mut zip::ZipArchive<R>,
+    limits: &ReadLimits,
+) -> Result<(), XlsxError> {
+    if limits.max_part_size().is_none()
+        && limits.max_total_size().is_none()
+        && limits.max_xml_depth().is_none()
+    {
+        return Ok(());
+    }
+    let max_part_size = limits.max_part_size().unwrap_or(u64::MAX);
+    let max_total_size = limits.max_total_size().unwrap_or(u64::MAX);
+    let mut total_size: u64 = 0;
+    for i in 0..arv.len() {
+        let file = arv.by_index(i)?;
+        if file.is_dir() {
+            continue;
+        }
+        let name = file.name().to_string();
+        if file.size() > max_part_size {
+            return Err(XlsxError::LimitExceeded(ReadLimit::PartSize, name));
+        }
+        if total_size.saturating_add(file.size()) > max_total_size {
+            return Err(XlsxError::LimitExceeded(ReadLimit::TotalSize, name));
+        }
+        let is_xml = [".xml", ".rels", ".vml"]
+            .iter()
+            .any(|v| name.to_ascii_lowercase().ends_with(v));
+        let mut source = io::Read::take(file, max_part_size.saturating_add(1));
+        let size = match limits.max_xml_depth() {
+            Some(max_depth) if is_xml => xml_size_within_depth(&mut source, max_depth, &name)?,
+            _ => io::copy(&mut source, &mut io::sink())?,
+        };
+        if size > max_part_size {
+            return Err(XlsxError::LimitExceeded(ReadLimit::PartSize, name));
+        }
+        total_size = total_size.saturating_add(size);
+        if total_size > max_total_size {
+            return Err(XlsxError::LimitExceeded(ReadLimit::TotalSize, name));
+        }
+    }
+    Ok(())
+}
+
+/// Read an XML part to the end, checking the nesting depth of its elements.
+/// Returns the size of the part in bytes.
+/// Malformed XML is left to the reader of the part to report.
+fn xml_size_within_depth<R: io::Read>(
+    source: &mut R,
+    max_depth: usize,
+    name: &str,
+) -> Result<u64, XlsxError> {
+    let mut reader = Reader::from_reader(io::BufReader::new(source));
+    reader.config_mut().check_end_names = false;
+    let mut depth: usize = 0;
+    let mut buf = Vec::new();
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(_)) => {
+                depth += 1;
+                if depth > max_depth {
+                    return Err(XlsxError::LimitExceeded(
+                        ReadLimit::XmlDepth,
+                        name.to_string(),
+                    ));
+                }
+            }
+            Ok(Event::Empty(_)) if depth + 1 > max_depth => {
+                return Err(XlsxError::LimitExceeded(
+                    ReadLimit::XmlDepth,
+                    name.to_string(),
+                ));
+            }
+            Ok(Event::End(_)) => depth = depth.saturating_sub(1),
+            Ok(Event::Eof) | Err(_) => break,
+            Ok(_) => {}
+        }
+        buf.clear();
+    }
+    let size = reader.buffer_position();
+    let rest = io::copy(&mut reader.into_inner(), &mut io::sink())?;
+    Ok(size + rest)
+}
+
 #[inline]
 pub(crate) fn get_attribute_value(attr: &Attribute) -> Result<String, FromUtf8Error> {
     String::from_utf8(attr.value.to_vec())
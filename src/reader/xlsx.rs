@@ -5,20 +5,56 @@ use std::{
     sync::RwLock,
 };
 
-use super::driver;
+use super::driver::{
+    self,
+    zip_parse_error_context,
+};
 use crate::{
     XlsxError,
     helper::const_str::{
+        ARC_APP,
+        ARC_CORE,
+        ARC_CUSTOM,
+        CALC_CHAIN_NS,
         COMMENTS_NS,
+        CONTENT_TYPES,
+        COREPROPS_REL,
+        CTRL_PROP_NS,
+        CUSTOM_PROPS_REL,
+        CUSTOMUI_NS,
         DRAWINGS_NS,
+        EXTERNAL_LINK_NS,
+        HYPERLINK_NS,
+        IMAGE_NS,
+        JSA_PROJECT_NS,
+        OFCDOC_NS,
+        OLE_OBJECT_NS,
+        PACKAGE_NS,
+        PERSION_NS,
+        PIVOT_CACHE_DEF_NS,
         PIVOT_TABLE_NS,
+        PKG_RELS,
+        PKG_SHARED_STRINGS,
+        PKG_STYLES,
+        PKG_WORKBOOK,
+        PKG_WORKBOOK_RELS,
+        PRINTER_SETTINGS_NS,
+        SHARED_STRINGS_NS,
+        SHEET_METADATA_NS,
+        STYLES_NS,
         TABLE_NS,
         THEME_NS,
         THREADED_COMMENT_NS,
+        VBA_PROJECT_NS,
         VML_DRAWING_NS,
+        WORKSHEET_NS,
+        XPROPS_REL,
     },
     structs::{
         Cell,
+        Metadata,
+        ReadLimit,
+        ReadLimits,
         SharedStringTable,
         Stylesheet,
         Workbook,
@@ -31,11 +67,15 @@ use crate::{
 pub(crate) mod chart;
 pub(crate) mod comment;
 mod content_types;
+mod ctrl_prop;
 mod doc_props_app;
 mod doc_props_core;
 mod doc_props_custom;
 pub(crate) mod drawing;
+mod external_link;
 mod jsa_project_bin;
+mod metadata;
+mod person;
 mod pivot_cache;
 mod pivot_table;
 mod rels;
@@ -50,6 +90,47 @@ mod workbook;
 mod workbook_rels;
 pub(crate) mod worksheet;
 
+/// Relationship types of the root relationships read by the crate.
+const PACKAGE_PART_TYPES: &[&str] = &[
+    OFCDOC_NS,
+    COREPROPS_REL,
+    XPROPS_REL,
+    CUSTOM_PROPS_REL,
+    CUSTOMUI_NS,
+];
+
+/// Relationship types of the workbook relationships read by the crate.
+/// The calculation chain is dropped since it goes stale once cells change.
+const WORKBOOK_PART_TYPES: &[&str] = &[
+    WORKSHEET_NS,
+    STYLES_NS,
+    THEME_NS,
+    SHARED_STRINGS_NS,
+    VBA_PROJECT_NS,
+    JSA_PROJECT_NS,
+    PERSION_NS,
+    PIVOT_CACHE_DEF_NS,
+    CALC_CHAIN_NS,
+    SHEET_METADATA_NS,
+    EXTERNAL_LINK_NS,
+];
+
+/// Relationship types of the worksheet relationships read by the crate.
+const WORKSHEET_PART_TYPES: &[&str] = &[
+    DRAWINGS_NS,
+    COMMENTS_NS,
+    THREADED_COMMENT_NS,
+    TABLE_NS,
+    PIVOT_TABLE_NS,
+    VML_DRAWING_NS,
+    CTRL_PROP_NS,
+    HYPERLINK_NS,
+    PRINTER_SETTINGS_NS,
+    OLE_OBJECT_NS,
+    PACKAGE_NS,
+    IMAGE_NS,
+];
+
 /// read spreadsheet from arbitrary reader.
 /// # Arguments
 /// * `reader` - reader to read from.
@@ -59,52 +140,130 @@ pub fn read_reader<R: io::Read + io::Seek>(
     reader: R,
     with_sheet_read: bool,
 ) -> Result<Workbook, XlsxError> {
-    read_reader_with_source(reader, with_sheet_read, None)
+    read_reader_with_source(reader, with_sheet_read, None, &ReadLimits::default())
+}
+
+/// read spreadsheet from arbitrary reader, refusing files that go over
+/// `limits`. Use this for files from untrusted sources.
+/// Worksheets deserialized after reading are held to the same limits.
+/// # Arguments
+/// * `reader` - reader to read from.
+/// * `limits` - limits applied while reading.
+/// # Return value
+/// * `Result` - OK is `Workbook`. Err is `XlsxError::LimitExceeded` when a
+///   limit is hit.
+pub fn read_reader_with_limits<R: io::Read + io::Seek>(
+    reader: R,
+    with_sheet_read: bool,
+    limits: &ReadLimits,
+) -> Result<Workbook, XlsxError> {
+    read_reader_with_source(reader, with_sheet_read, None, limits)
 }
 
 fn read_reader_with_source<R: io::Read + io::Seek>(
     reader: R,
     with_sheet_read: bool,
     source_file: Option<&Path>,
+    limits: &ReadLimits,
 ) -> Result<Workbook, XlsxError> {
     let mut arv = zip::read::ZipArchive::new(reader)?;
+    driver::check_read_limits(&mut arv, limits)?;
 
-    let mut book = workbook::read(&mut arv)?;
-    doc_props_app::read(&mut arv, &mut book)?;
-    doc_props_core::read(&mut arv, &mut book)?;
-    doc_props_custom::read(&mut arv, &mut book)?;
+    let mut book = workbook::read(&mut arv)
+        .map_err(|e| zip_parse_error_context(e, &mut arv, PKG_WORKBOOK))?;
+    if limits
+        .max_sheets()
+        .is_some_and(|max| book.sheet_count() > max)
+    {
+        return Err(XlsxError::LimitExceeded(
+            ReadLimit::Sheets,
+            PKG_WORKBOOK.to_string(),
+        ));
+    }
+    book.set_read_limits(limits.clone());
+    doc_props_app::read(&mut arv, &mut book)
+        .map_err(|e| zip_parse_error_context(e, &mut arv, ARC_APP))?;
+    doc_props_core::read(&mut arv, &mut book)
+        .map_err(|e| zip_parse_error_context(e, &mut arv, ARC_CORE))?;
+    doc_props_custom::read(&mut arv, &mut book)
+        .map_err(|e| zip_parse_error_context(e, &mut arv, ARC_CUSTOM))?;
     vba_project_bin::read(&mut arv, &mut book)?;
     jsa_project_bin::read(&mut arv, &mut book)?;
-    content_types::read(&mut arv, &mut book)?;
-    let workbook_rel = workbook_rels::read(&mut arv, &mut book)?;
+    content_types::read(&mut arv, &mut book)
+        .map_err(|e| zip_parse_error_context(e, &mut arv, CONTENT_TYPES))?;
+    let workbook_rel = workbook_rels::read(&mut arv, &mut book)
+        .map_err(|e| zip_parse_error_context(e, &mut arv, PKG_WORKBOOK_RELS))?;
+
+    let sheet_r_ids: Vec<String> = book
+        .sheet_collection_no_check()
+        .iter()
+        .map(|sheet| sheet.r_id().to_string())
+        .collect();
+    let sheet_r_ids: Vec<&str> = sheet_r_ids.iter().map(String::as_str).collect();
+    book.raw_unknown_mut().read_parts_from_archive(
+        &mut arv,
+        PKG_WORKBOOK_RELS,
+        "xl",
+        WORKBOOK_PART_TYPES,
+        &sheet_r_ids,
+    )?;
+    book.raw_package_unknown_mut().read_parts_from_archive(
+        &mut arv,
+        PKG_RELS,
+        ".",
+        PACKAGE_PART_TYPES,
+        &[],
+    )?;
 
     book.set_theme(Theme::default_value());
     for (_, type_value, rel_target) in &workbook_rel {
         if type_value == THEME_NS {
-            let theme = theme::read(&mut arv, rel_target)?;
+            let theme = theme::read(&mut arv, rel_target)
+                .map_err(|e| zip_parse_error_context(e, &mut arv, &format!("xl/{rel_target}")))?;
             book.set_theme(theme);
         }
+        if type_value == PERSION_NS {
+            person::read(&mut arv, rel_target, &mut book)
+                .map_err(|e| zip_parse_error_context(e, &mut arv, &format!("xl/{rel_target}")))?;
+        }
+        if type_value == SHEET_METADATA_NS {
+            metadata::read(&mut arv, rel_target, &mut book)
+                .map_err(|e| zip_parse_error_context(e, &mut arv, &format!("xl/{rel_target}")))?;
+        }
+    }
+
+    for external_link in book.external_links_mut() {
+        let Some((.., rel_target)) = workbook_rel
+            .iter()
+            .find(|(rel_id, ..)| rel_id == external_link.r_id())
+        else {
+            continue;
+        };
+        external_link::read(&mut arv, rel_target, external_link)
+            .map_err(|e| zip_parse_error_context(e, &mut arv, &format!("xl/{rel_target}")))?;
     }
 
-    shared_strings::read(&mut arv, &mut book)?;
-    styles::read(&mut arv, &mut book)?;
+    shared_strings::read(&mut arv, &mut book)
+        .map_err(|e| zip_parse_error_context(e, &mut arv, PKG_SHARED_STRINGS))?;
+    styles::read(&mut arv, &mut book)
+        .map_err(|e| zip_parse_error_context(e, &mut arv, PKG_STYLES))?;
 
-    for sheet in book.sheet_collection_mut() {
+    for sheet in book.sheet_collection_no_check_mut() {
         for (rel_id, _, rel_target) in &workbook_rel {
             if sheet.r_id() != rel_id {
                 continue;
             }
             let mut raw_worksheet = RawWorksheet::default();
             match source_file {
-                Some(source_file) => raw_worksheet.read_lazy(&mut arv, rel_target, source_file),
-                None => raw_worksheet.read(&mut arv, rel_target),
+                Some(source_file) => raw_worksheet.read_lazy(&mut arv, rel_target, source_file)?,
+                None => raw_worksheet.read(&mut arv, rel_target)?,
             }
             sheet.set_raw_data_of_worksheet(raw_worksheet);
         }
     }
 
     if with_sheet_read {
-        book.read_sheet_collection();
+        book.read_sheet_collection()?;
     }
 
     Ok(book)
@@ -126,6 +285,32 @@ pub fn read<P: AsRef<Path>>(path: P) -> Result<Workbook, XlsxError> {
     read_reader(file, true)
 }
 
+/// read spreadsheet file, refusing files that go over `limits`.
+/// # Arguments
+/// * `path` - file path to read.
+/// * `limits` - limits applied while reading.
+/// # Return value
+/// * `Result` - OK is Workbook. Err is error message.
+/// # Examples
+/// ```
+/// let mut limits = umya_spreadsheet::ReadLimits::default();
+/// limits.set_max_sheets(1);
+/// let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
+/// let result = umya_spreadsheet::reader::xlsx::read_with_limits(path, &limits);
+/// assert!(matches!(
+///     result,
+///     Err(umya_spreadsheet::XlsxError::LimitExceeded(
+///         umya_spreadsheet::ReadLimit::Sheets,
+///         _
+///     ))
+/// ));
+/// ```
+#[inline]
+pub fn read_with_limits<P: AsRef<Path>>(path: P, limits: &ReadLimits) -> Result<Workbook, XlsxError> {
+    let file = File::open(path)?;
+    read_reader_with_limits(file, true, limits)
+}
+
 /// lazy read spreadsheet file.
 /// Delays the loading of the worksheet until it is needed.
 /// When loading a file with a large amount of data, response improvement can be
@@ -141,7 +326,7 @@ pub fn read<P: AsRef<Path>>(path: P) -> Result<Workbook, XlsxError> {
 #[inline]
 pub fn lazy_read(path: &Path) -> Result<Workbook, XlsxError> {
     let file = File::open(path)?;
-    read_reader_with_source(file, false, Some(path))
+    read_reader_with_source(file, false, Some(path), &ReadLimits::default())
 }
 
 /// Stream cells from a worksheet without deserializing the worksheet into
@@ -179,71 +364,134 @@ where
     )
 }
 
+/// Deserialize a worksheet from its raw data.
+/// On error the worksheet is left as it was before the call.
 pub(crate) fn raw_to_deserialize_by_worksheet(
     worksheet: &mut Worksheet,
     shared_string_table: &RwLock<SharedStringTable>,
     stylesheet: &Stylesheet,
-) {
+    metadata: &Metadata,
+    read_limits: &ReadLimits,
+) -> Result<(), XlsxError> {
     if worksheet.is_deserialized() {
-        return;
+        return Ok(());
     }
 
     let mut raw_data_of_worksheet = worksheet.take_raw_data_of_worksheet();
+    let backup = worksheet.clone();
     let shared_string_table = &*shared_string_table.read().unwrap();
+    let result = deserialize_worksheet(
+        worksheet,
+        &mut raw_data_of_worksheet,
+        shared_string_table,
+        stylesheet,
+        metadata,
+        read_limits,
+    );
+    if result.is_err() {
+        *worksheet = backup;
+        worksheet.set_raw_data_of_worksheet(raw_data_of_worksheet);
+    }
+    result
+}
+
+fn deserialize_worksheet(
+    worksheet: &mut Worksheet,
+    raw_data_of_worksheet: &mut RawWorksheet,
+    shared_string_table: &SharedStringTable,
+    stylesheet: &Stylesheet,
+    metadata: &Metadata,
+    read_limits: &ReadLimits,
+) -> Result<(), XlsxError> {
     worksheet::read(
         worksheet,
-        &raw_data_of_worksheet,
+        raw_data_of_worksheet,
         shared_string_table,
         stylesheet,
-    )
-    .unwrap();
-    raw_data_of_worksheet
-        .load_relationship_file_data_from_source()
-        .unwrap();
+        read_limits.max_cells(),
+    )?;
+    // Mark the formulas the cell metadata flags as dynamic arrays.
+    for cell in worksheet.cells_mut() {
+        if metadata.is_dynamic_array(cell.cell_meta_index()) {
+            if let Some(formula) = &mut cell.cell_value_mut().formula {
+                formula.set_dynamic_array(true);
+            }
+        }
+    }
+    worksheet::read_unknown_elements(worksheet, raw_data_of_worksheet)?;
+    raw_data_of_worksheet.load_relationship_file_data_from_source()?;
 
     if let Some(v) = raw_data_of_worksheet.worksheet_relationships() {
+        worksheet.raw_unknown_mut().read_parts(
+            v,
+            raw_data_of_worksheet.relationships_list(),
+            WORKSHEET_PART_TYPES,
+        );
         for relationship in v.relationship_list() {
+            let raw_file = relationship.raw_file();
+            if worksheet.raw_unknown().has_part(raw_file.file_target()) {
+                continue;
+            }
             match relationship.get_type() {
                 // drawing, chart
                 DRAWINGS_NS => {
                     drawing::read(
                         worksheet,
-                        relationship.raw_file(),
+                        raw_file,
                         raw_data_of_worksheet.drawing_relationships(),
-                    );
+                    )
+                    .map_err(|e| raw_file.parse_error_context(e))?;
                 }
                 // comment
                 COMMENTS_NS => {
-                    comment::read(worksheet, relationship.raw_file());
+                    comment::read(worksheet, raw_file)
+                        .map_err(|e| raw_file.parse_error_context(e))?;
                 }
                 // threaded_comment
                 THREADED_COMMENT_NS => {
-                    threaded_comment::read(worksheet, relationship.raw_file());
+                    threaded_comment::read(worksheet, raw_file)
+                        .map_err(|e| raw_file.parse_error_context(e))?;
                 }
                 // table
                 TABLE_NS => {
-                    table::read(worksheet, relationship.raw_file()).unwrap();
+                    table::read(worksheet, raw_file, stylesheet)
+                        .map_err(|e| raw_file.parse_error_context(e))?;
                 }
                 // pivot table, pivot cache
                 PIVOT_TABLE_NS => {
                     pivot_table::read(
                         worksheet,
-                        relationship.raw_file(),
+                        raw_file,
                         raw_data_of_worksheet.pivot_table_relationships(),
-                    );
+                    )
+                    .map_err(|e| raw_file.parse_error_context(e))?;
                 }
                 _ => {}
             }
         }
         for relationship in v.relationship_list() {
             // vmlDrawing
-            if relationship.get_type() == VML_DRAWING_NS {
+            if relationship.get_type() == VML_DRAWING_NS
+                && !worksheet
+                    .raw_unknown()
+                    .has_part(relationship.raw_file().file_target())
+            {
+                let raw_file = relationship.raw_file();
                 vml_drawing::read(
                     worksheet,
-                    relationship.raw_file(),
+                    raw_file,
                     raw_data_of_worksheet.vml_drawing_relationships(),
-                );
+                )
+                .map_err(|e| raw_file.parse_error_context(e))?;
+            }
+        }
+        for relationship in v.relationship_list() {
+            // ctrlProp
+            if relationship.get_type() == CTRL_PROP_NS {
+                ctrl_prop::read(worksheet, relationship)
+                    .map_err(|e| relationship.raw_file().parse_error_context(e))?;
             }
         }
     }
+    Ok(())
 }
@@ -4,6 +4,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     structs::{
         Comment,
         Worksheet,
@@ -12,7 +13,7 @@ use crate::{
     xml_read_loop,
 };
 
-pub(crate) fn read(worksheet: &mut Worksheet, drawing_file: &RawFile) {
+pub(crate) fn read(worksheet: &mut Worksheet, drawing_file: &RawFile) -> Result<(), XlsxError> {
     let data = std::io::Cursor::new(drawing_file.file_data());
     let mut reader = Reader::from_reader(data);
     reader.config_mut().trim_text(false);
@@ -29,7 +30,7 @@ pub(crate) fn read(worksheet: &mut Worksheet, drawing_file: &RawFile) {
         Event::Start(ref e) => {
             if e.name().into_inner() ==  b"comment" {
                 let mut obj = Comment::default();
-                obj.set_attributes(&mut reader, e, &authors);
+                obj.set_attributes(&mut reader, e, &authors)?;
                 worksheet.add_comments(obj);
             }
         },
@@ -43,4 +44,6 @@ pub(crate) fn read(worksheet: &mut Worksheet, drawing_file: &RawFile) {
         },
         Event::Eof => break,
     );
+
+    Ok(())
 }
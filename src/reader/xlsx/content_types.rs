@@ -8,7 +8,7 @@ use quick_xml::{
 use super::{
     XlsxError,
     driver::{
-        get_attribute,
+        get_required_attribute,
         xml_read_loop,
     },
 };
@@ -25,19 +25,29 @@ pub(crate) fn read<R: io::Read + io::Seek>(
     let mut reader = Reader::from_reader(r);
     reader.config_mut().trim_text(true);
     let mut list: Vec<(String, String)> = Vec::new();
+    let mut default_list: Vec<(String, String)> = Vec::new();
 
     xml_read_loop!(
         reader,
         Event::Empty(ref e) => {
-            if e.name().into_inner() == b"Override" {
-                let part_name = get_attribute(e, b"PartName").unwrap();
-                let content_type = get_attribute(e, b"ContentType").unwrap();
-                list.push((part_name, content_type));
+            match e.name().into_inner() {
+                b"Override" => {
+                    let part_name = get_required_attribute(&reader, e, b"PartName")?;
+                    let content_type = get_required_attribute(&reader, e, b"ContentType")?;
+                    list.push((part_name, content_type));
+                }
+                b"Default" => {
+                    let extension = get_required_attribute(&reader, e, b"Extension")?;
+                    let content_type = get_required_attribute(&reader, e, b"ContentType")?;
+                    default_list.push((extension, content_type));
+                }
+                _ => (),
             }
         },
         Event::Eof => break,
     );
 
     wb.set_backup_context_types(list);
+    wb.set_backup_default_types(default_list);
     Ok(())
 }
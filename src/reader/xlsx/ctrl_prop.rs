@@ -0,0 +1,44 @@
+use quick_xml::{
+    Reader,
+    events::Event,
+};
+
+use crate::{
+    XlsxError,
+    structs::{
+        Worksheet,
+        raw::RawRelationship,
+    },
+    xml_read_loop,
+};
+
+pub(crate) fn read(worksheet: &mut Worksheet, relationship: &RawRelationship) -> Result<(), XlsxError> {
+    let Some(form_control) = worksheet
+        .form_controls_mut()
+        .iter_mut()
+        .find(|v| v.r_id() == relationship.id())
+    else {
+        return Ok(());
+    };
+
+    let data = std::io::Cursor::new(relationship.raw_file().file_data());
+    let mut reader = Reader::from_reader(data);
+    reader.config_mut().trim_text(true);
+
+    xml_read_loop!(
+        reader,
+        Event::Empty(ref e) => {
+            if e.name().into_inner() == b"formControlPr" {
+                form_control.set_ctrl_prop_attributes(e);
+            }
+        },
+        Event::Start(ref e) => {
+            if e.name().into_inner() == b"formControlPr" {
+                form_control.set_ctrl_prop_attributes(e);
+            }
+        },
+        Event::Eof => break,
+    );
+
+    Ok(())
+}
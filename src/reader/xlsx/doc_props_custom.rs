@@ -31,11 +31,11 @@ pub(crate) fn read<R: io::Read + io::Seek>(
         match reader.read_event_into(&mut buf) {
             Ok(Event::Start(ref e)) => {
                 if e.name().into_inner() == b"Properties" {
-                    wb.properties_mut().set_attributes_custom(&mut reader, e);
+                    wb.properties_mut().set_attributes_custom(&mut reader, e)?;
                 }
             }
             Ok(Event::Eof) => break,
-            Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+            Err(e) => return Err(XlsxError::parse(reader.buffer_position(), e.to_string())),
             _ => (),
         }
         buf.clear();
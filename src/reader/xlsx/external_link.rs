@@ -0,0 +1,84 @@
+use std::{
+    io,
+    io::Read,
+};
+
+use quick_xml::{
+    Reader,
+    events::Event,
+};
+
+use super::{
+    XlsxError,
+    driver::{
+        get_attribute,
+        join_paths,
+        zip_by_name,
+    },
+};
+use crate::{
+    helper::const_str::PKG_EXTERNAL_LINKS,
+    structs::{
+        ExternalLink,
+        EXTERNAL_LINK_ELEMENTS,
+        raw::RawRelationships,
+    },
+    xml_read_loop,
+};
+
+pub(crate) fn read<R: Read + io::Seek>(
+    arv: &mut zip::ZipArchive<R>,
+    target: &str,
+    external_link: &mut ExternalLink,
+) -> Result<(), XlsxError> {
+    let path = join_paths("xl", target);
+    let mut data = Vec::new();
+    io::BufReader::new(zip_by_name(arv, &path)?).read_to_end(&mut data)?;
+    let (base_path, file_name) = path.rsplit_once('/').unwrap_or((PKG_EXTERNAL_LINKS, &path));
+    let rels_target = format!("_rels/{file_name}.rels");
+    let mut relationships = RawRelationships::default();
+    relationships.set_attributes(arv, base_path, &rels_target, None)?;
+
+    let mut reader = Reader::from_reader(data.as_slice());
+    reader.config_mut().trim_text(true);
+    let mut book_r_id = String::new();
+    xml_read_loop!(
+        reader,
+        Event::Start(ref e) => {
+            if e.name().into_inner() == b"externalBook" {
+                book_r_id = get_attribute(e, b"r:id").unwrap_or_default();
+                if let Some(relationship) = relationships.relationship_by_rid(&book_r_id) {
+                    external_link.set_target(relationship.target());
+                    external_link.set_relationship_type(relationship.get_type());
+                }
+                external_link.set_attributes(&mut reader, e)?;
+            }
+        },
+        Event::Empty(ref e) => {
+            if e.name().into_inner() == b"externalBook" {
+                book_r_id = get_attribute(e, b"r:id").unwrap_or_default();
+                if let Some(relationship) = relationships.relationship_by_rid(&book_r_id) {
+                    external_link.set_target(relationship.target());
+                    external_link.set_relationship_type(relationship.get_type());
+                }
+            }
+        },
+        Event::Eof => break,
+    );
+
+    external_link.raw_unknown_mut().read_elements(
+        data.as_slice(),
+        EXTERNAL_LINK_ELEMENTS,
+        &["externalBook"],
+        &[],
+    )?;
+    external_link.raw_unknown_mut().read_parts_from_archive(
+        arv,
+        &join_paths(base_path, &rels_target),
+        base_path,
+        &[],
+        &[book_r_id.as_str()],
+    )?;
+
+    Ok(())
+}
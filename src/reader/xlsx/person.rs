@@ -0,0 +1,39 @@
+use std::io;
+
+use quick_xml::{
+    Reader,
+    events::Event,
+};
+
+use super::XlsxError;
+use crate::{
+    structs::{
+        Workbook,
+        office2019::person::Person,
+    },
+    xml_read_loop,
+};
+
+pub(crate) fn read<R: io::Read + io::Seek>(
+    arv: &mut zip::ZipArchive<R>,
+    target: &str,
+    wb: &mut Workbook,
+) -> Result<(), XlsxError> {
+    let r = io::BufReader::new(super::driver::zip_by_name(arv, &format!("xl/{target}"))?);
+    let mut reader = Reader::from_reader(r);
+    reader.config_mut().trim_text(true);
+
+    xml_read_loop!(
+        reader,
+        Event::Empty(ref e) | Event::Start(ref e) => {
+            if e.name().into_inner() == b"person" {
+                let mut obj = Person::default();
+                obj.set_attributes(e);
+                wb.add_person(obj);
+            }
+        },
+        Event::Eof => break,
+    );
+
+    Ok(())
+}
@@ -5,13 +5,14 @@ use quick_xml::{
 
 use crate::{
     PivotTable,
+    XlsxError,
     structs::{
         PivotCacheDefinition,
         raw::RawFile,
     },
 };
 
-pub(crate) fn read(raw_file: &RawFile, pivot_table: &mut PivotTable) {
+pub(crate) fn read(raw_file: &RawFile, pivot_table: &mut PivotTable) -> Result<(), XlsxError> {
     let data = std::io::Cursor::new(raw_file.file_data());
     let mut reader = Reader::from_reader(data);
     reader.config_mut().trim_text(false);
@@ -22,7 +23,7 @@ pub(crate) fn read(raw_file: &RawFile, pivot_table: &mut PivotTable) {
         match reader.read_event_into(&mut buf) {
             Ok(Event::Start(ref e)) => {
                 if e.name().into_inner() == b"pivotCacheDefinition" {
-                    pivot_cache_def.set_attributes(&mut reader, e);
+                    pivot_cache_def.set_attributes(&mut reader, e)?;
                 }
             }
             Ok(Event::End(ref e)) => {
@@ -31,11 +32,13 @@ pub(crate) fn read(raw_file: &RawFile, pivot_table: &mut PivotTable) {
                 }
             }
             Ok(Event::Eof) => break,
-            Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+            Err(e) => return Err(XlsxError::parse(reader.buffer_position(), e.to_string())),
             _ => (),
         }
         buf.clear();
     }
 
     pivot_table.set_pivot_cache_definition(pivot_cache_def);
+
+    Ok(())
 }
@@ -4,6 +4,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     helper::const_str::PIVOT_CACHE_DEF_NS,
     raw::RawRelationships,
     reader::xlsx::pivot_cache,
@@ -19,7 +20,7 @@ pub(crate) fn read(
     worksheet: &mut Worksheet,
     pivot_table_file: &RawFile,
     pivot_table_relationships: Option<&RawRelationships>,
-) {
+) -> Result<(), XlsxError> {
     let data = std::io::Cursor::new(pivot_table_file.file_data());
     let mut reader = Reader::from_reader(data);
     reader.config_mut().trim_text(false);
@@ -30,7 +31,7 @@ pub(crate) fn read(
             Ok(Event::Start(ref e)) => {
                 if e.name().into_inner() == b"pivotTableDefinition" {
                     let mut obj = PivotTableDefinition::default();
-                    obj.set_attributes(&mut reader, e);
+                    obj.set_attributes(&mut reader, e)?;
                     pivot_table.set_pivot_table_definition(obj);
                 }
             }
@@ -40,18 +41,21 @@ pub(crate) fn read(
                 }
             }
             Ok(Event::Eof) => break,
-            Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+            Err(e) => return Err(XlsxError::parse(reader.buffer_position(), e.to_string())),
             _ => (),
         }
         buf.clear();
     }
 
-    if let Some(rrs_list) = pivot_table_relationships {
-        pivot_cache::read(
-            rrs_list.relationship_by_type(PIVOT_CACHE_DEF_NS).raw_file(),
-            &mut pivot_table,
-        );
+    if let Some(relationship) =
+        pivot_table_relationships.and_then(|v| v.relationship_by_type(PIVOT_CACHE_DEF_NS))
+    {
+        let raw_file = relationship.raw_file();
+        pivot_cache::read(raw_file, &mut pivot_table)
+            .map_err(|e| raw_file.parse_error_context(e))?;
     }
 
     worksheet.add_pivot_table(pivot_table);
+
+    Ok(())
 }
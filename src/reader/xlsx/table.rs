@@ -8,8 +8,11 @@ use super::{
     driver::get_attribute_value,
 };
 use crate::structs::{
+    AutoFilter,
     ShowColumn,
     ShowStripes,
+    SortState,
+    Stylesheet,
     Table,
     TableColumn,
     TableStyleInfo,
@@ -17,7 +20,11 @@ use crate::structs::{
     raw::RawFile,
 };
 
-pub(crate) fn read(worksheet: &mut Worksheet, table_file: &RawFile) -> Result<(), XlsxError> {
+pub(crate) fn read(
+    worksheet: &mut Worksheet,
+    table_file: &RawFile,
+    stylesheet: &Stylesheet,
+) -> Result<(), XlsxError> {
     let data = std::io::Cursor::new(table_file.file_data());
     let mut reader = Reader::from_reader(data);
     reader.config_mut().trim_text(false);
@@ -28,6 +35,11 @@ pub(crate) fn read(worksheet: &mut Worksheet, table_file: &RawFile) -> Result<()
     loop {
         match reader.read_event_into(&mut buf) {
             Ok(Event::Empty(ref e)) => match e.name().into_inner() {
+                b"sortState" => {
+                    let mut obj = SortState::default();
+                    obj.set_attributes(&mut reader, e, stylesheet.differential_formats(), true)?;
+                    table.set_sort_state(obj);
+                }
                 b"tableColumn" => {
                     table_column = TableColumn::default();
                     for attr in e.attributes().with_checks(false).flatten() {
@@ -110,6 +122,21 @@ pub(crate) fn read(worksheet: &mut Worksheet, table_file: &RawFile) -> Result<()
             },
             Ok(Event::Text(e)) => string_value = crate::helper::utils::unescape_xml_text(&e),
             Ok(Event::Start(ref e)) => match e.name().into_inner() {
+                b"autoFilter" => {
+                    let mut obj = AutoFilter::default();
+                    obj.set_attributes(&mut reader, e, stylesheet.differential_formats(), false)?;
+                    for filter_column in obj.filter_column_list() {
+                        table.add_filter_column(filter_column.clone());
+                    }
+                    if let Some(v) = obj.sort_state() {
+                        table.set_sort_state(v.clone());
+                    }
+                }
+                b"sortState" => {
+                    let mut obj = SortState::default();
+                    obj.set_attributes(&mut reader, e, stylesheet.differential_formats(), false)?;
+                    table.set_sort_state(obj);
+                }
                 b"table" => {
                     for attr in e.attributes().with_checks(false).flatten() {
                         let attr_val = get_attribute_value(&attr)?;
@@ -173,7 +200,7 @@ pub(crate) fn read(worksheet: &mut Worksheet, table_file: &RawFile) -> Result<()
                 _ => (),
             },
             Ok(Event::Eof) => break,
-            Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+            Err(e) => return Err(XlsxError::parse(reader.buffer_position(), e.to_string())),
             _ => (),
         }
         buf.clear();
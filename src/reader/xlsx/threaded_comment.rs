@@ -4,6 +4,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     office2019::threaded_comment::ThreadedComment,
     structs::{
         Worksheet,
@@ -12,7 +13,7 @@ use crate::{
     xml_read_loop,
 };
 
-pub(crate) fn read(worksheet: &mut Worksheet, drawing_file: &RawFile) {
+pub(crate) fn read(worksheet: &mut Worksheet, drawing_file: &RawFile) -> Result<(), XlsxError> {
     let data = std::io::Cursor::new(drawing_file.file_data());
     let mut reader = Reader::from_reader(data);
     reader.config_mut().trim_text(false);
@@ -22,10 +23,12 @@ pub(crate) fn read(worksheet: &mut Worksheet, drawing_file: &RawFile) {
         Event::Start(ref e) => {
             if e.name().into_inner() ==  b"threadedComment" {
                 let mut obj = ThreadedComment::default();
-                obj.set_attributes(&mut reader, e);
+                obj.set_attributes(&mut reader, e)?;
                 worksheet.add_threaded_comments(obj);
             }
         },
         Event::Eof => break,
     );
+
+    Ok(())
 }
@@ -4,7 +4,9 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     structs::{
+        FormControl,
         Worksheet,
         raw::{
             RawFile,
@@ -19,26 +21,38 @@ pub(crate) fn read(
     worksheet: &mut Worksheet,
     drawing_file: &RawFile,
     drawing_relationships: Option<&RawRelationships>,
-) {
+) -> Result<(), XlsxError> {
     let data = std::io::Cursor::new(drawing_file.file_data());
     let mut reader = Reader::from_reader(data);
     reader.config_mut().trim_text(true);
 
     let mut ole_index = 0;
     let mut comment_index = 0;
+    let mut form_control_index = 0;
 
     xml_read_loop!(
         reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"v:shape" {
                     let mut obj = Shape::default();
-                    obj.set_attributes(&mut reader, e, drawing_relationships);
+                    obj.set_attributes(&mut reader, e, drawing_relationships)?;
                     if obj.client_data().comment_column_target().is_some() {
                         worksheet
                             .comments_mut()
                             .get_mut(comment_index)
                             .map(|comment| comment.set_shape(obj));
                         comment_index += 1;
+                    } else if FormControl::is_form_control_type(obj.client_data().object_type()) {
+                        if let Some(form_control) =
+                            worksheet.form_controls_mut().get_mut(form_control_index)
+                        {
+                            form_control.set_shape(obj);
+                        } else {
+                            let mut form_control = FormControl::default();
+                            form_control.set_shape(obj);
+                            worksheet.form_controls_mut().push(form_control);
+                        }
+                        form_control_index += 1;
                     } else {
                         worksheet
                             .ole_objects_mut()
@@ -51,6 +65,8 @@ pub(crate) fn read(
             },
             Event::Eof => break,
     );
+
+    Ok(())
 }
 
 // fn set_style(comment:&mut Comment, style_string:&str) {
@@ -8,20 +8,38 @@ use quick_xml::{
 
 use super::{
     XlsxError,
-    driver::get_attribute,
+    driver::{
+        get_attribute,
+        get_required_attribute,
+    },
 };
 use crate::{
     helper::const_str::PKG_WORKBOOK,
     structs::{
         DefinedName,
+        ExternalLink,
         Workbook,
         WorkbookProtection,
         WorkbookView,
         Worksheet,
+        raw::WORKBOOK_ELEMENTS,
     },
     xml_read_loop,
 };
 
+/// Children of `workbook` that are read or written by the crate.
+const KNOWN_ELEMENTS: &[&str] = &[
+    "fileVersion",
+    "workbookPr",
+    "workbookProtection",
+    "bookViews",
+    "sheets",
+    "externalReferences",
+    "definedNames",
+    "calcPr",
+    "pivotCaches",
+];
+
 pub(crate) fn read<R: io::Read + io::Seek>(
     arv: &mut zip::read::ZipArchive<R>,
 ) -> Result<Workbook, XlsxError> {
@@ -47,22 +65,32 @@ pub(crate) fn read<R: io::Read + io::Seek>(
                     wb.set_workbook_protection(obj);
                 }
                 b"sheet" => {
-                    let name_value = get_attribute(e, b"name").unwrap();
-                    let sheet_id_value = get_attribute(e, b"sheetId").unwrap();
-                    let r_id_value = get_attribute(e, b"r:id").unwrap();
+                    let name_value = get_required_attribute(&reader, e, b"name")?;
+                    let sheet_id_value = get_required_attribute(&reader, e, b"sheetId")?;
+                    let r_id_value = get_required_attribute(&reader, e, b"r:id")?;
                     let state = get_attribute(e, b"state");
+                    let name_value = escape::unescape(&name_value)
+                        .map_err(|e| XlsxError::parse(reader.buffer_position(), e.to_string()))?;
                     let mut worksheet = Worksheet::default();
-                    worksheet.set_name(escape::unescape(&name_value).unwrap());
+                    worksheet.set_name(name_value);
                     worksheet.set_sheet_id(sheet_id_value);
                     worksheet.set_r_id(r_id_value);
                     if let Some(v) = state {
                         worksheet.set_state_str(&v);
                     }
-                    wb.add_sheet(worksheet).unwrap();
+                    wb.add_sheet(worksheet).map_err(|_| {
+                        XlsxError::parse(reader.buffer_position(), "duplicate sheet name")
+                    })?;
+                }
+                b"externalReference" => {
+                    let r_id = get_required_attribute(&reader, e, b"r:id")?;
+                    let mut obj = ExternalLink::default();
+                    obj.set_r_id(r_id);
+                    wb.add_external_link(obj);
                 }
                 b"pivotCache" => {
-                    let cache_id = get_attribute(e, b"cacheId").unwrap();
-                    let r_id = get_attribute(e, b"r:id").unwrap();
+                    let cache_id = get_required_attribute(&reader, e, b"cacheId")?;
+                    let r_id = get_required_attribute(&reader, e, b"r:id")?;
                     wb.add_pivot_caches((r_id, cache_id, String::new()));
                 }
                 _ => (),
@@ -71,7 +99,7 @@ pub(crate) fn read<R: io::Read + io::Seek>(
         Event::Start(ref e) => {
             if e.name().into_inner() == b"definedName" {
                 let mut obj = DefinedName::default();
-                obj.set_attributes(&mut reader, e);
+                obj.set_attributes(&mut reader, e)?;
                 defined_names.push(obj);
             }
         },
@@ -81,9 +109,9 @@ pub(crate) fn read<R: io::Read + io::Seek>(
     for defined_name in &defined_names {
         if defined_name.has_local_sheet_id() {
             let local_sheet_id = defined_name.local_sheet_id() as usize;
-            wb.sheet_mut(local_sheet_id)
-                .unwrap()
-                .add_defined_names(defined_name.clone());
+            if let Some(worksheet) = wb.sheet_collection_no_check_mut().get_mut(local_sheet_id) {
+                worksheet.add_defined_names(defined_name.clone());
+            }
         } else {
             if let Some(v) = defined_name.address_obj().first() {
                 if let Ok(s) = wb.sheet_by_name_mut(v.sheet_name()) {
@@ -95,5 +123,10 @@ pub(crate) fn read<R: io::Read + io::Seek>(
         }
     }
 
+    drop(reader);
+    let r = io::BufReader::new(super::driver::zip_by_name(arv, PKG_WORKBOOK)?);
+    wb.raw_unknown_mut()
+        .read_elements(r, WORKBOOK_ELEMENTS, KNOWN_ELEMENTS, &[])?;
+
     Ok(wb)
 }
@@ -8,7 +8,7 @@ use quick_xml::{
 use super::{
     XlsxError,
     driver::{
-        get_attribute,
+        get_required_attribute,
         xml_read_loop,
     },
 };
@@ -34,9 +34,9 @@ pub(crate) fn read<R: io::Read + io::Seek>(
         reader,
         Event::Empty(ref e) => {
             if e.name().into_inner() == b"Relationship" {
-                let id_value = get_attribute(e, b"Id").unwrap();
-                let type_value = get_attribute(e, b"Type").unwrap();
-                let target_value = get_attribute(e, b"Target").unwrap();
+                let id_value = get_required_attribute(&reader, e, b"Id")?;
+                let type_value = get_required_attribute(&reader, e, b"Type")?;
+                let target_value = get_required_attribute(&reader, e, b"Target")?;
                 let target_value = target_value
                     .strip_prefix("/xl/")
                     .map(ToOwned::to_owned)
@@ -20,16 +20,23 @@ use super::{
     },
 };
 use crate::{
-    helper::formula::FormulaToken,
+    helper::{
+        coordinate::CellCoordinates,
+        formula::FormulaToken,
+    },
     structs::{
+        AutoFilter,
         Cell,
         Cells,
         Columns,
         ConditionalFormatting,
         DataValidations,
+        FormControl,
         Hyperlink,
         OleObjects,
+        ReadLimit,
         Row,
+        Scenarios,
         SharedStringTable,
         SheetProtection,
         Stylesheet,
@@ -38,15 +45,53 @@ use crate::{
         raw::{
             RawRelationships,
             RawWorksheet,
+            WORKSHEET_ELEMENTS,
         },
     },
 };
 
+/// Children of `worksheet` that are read or written by the crate.
+const KNOWN_ELEMENTS: &[&str] = &[
+    "sheetPr",
+    "dimension",
+    "sheetViews",
+    "sheetFormatPr",
+    "cols",
+    "sheetData",
+    "sheetProtection",
+    "scenarios",
+    "autoFilter",
+    "mergeCells",
+    "phoneticPr",
+    "conditionalFormatting",
+    "dataValidations",
+    "hyperlinks",
+    "printOptions",
+    "pageMargins",
+    "pageSetup",
+    "headerFooter",
+    "rowBreaks",
+    "colBreaks",
+    "drawing",
+    "legacyDrawing",
+    "oleObjects",
+    "controls",
+    "mc:AlternateContent",
+    "tableParts",
+];
+
+/// `ext` entries of the worksheet `extLst` that are read by the crate.
+const KNOWN_EXTENSIONS: &[&str] = &[
+    // x14:dataValidations
+    "{CCE6A557-97BC-4b89-ADB6-D9C93CAAB3DF}",
+];
+
 pub(crate) fn read(
     worksheet: &mut Worksheet,
     raw_data_of_worksheet: &RawWorksheet,
     shared_string_table: &SharedStringTable,
     stylesheet: &Stylesheet,
+    max_cells: Option<usize>,
 ) -> Result<(), XlsxError> {
     if let Some(source_file) = raw_data_of_worksheet.worksheet_file().source_file() {
         if !raw_data_of_worksheet.worksheet_file().has_file_data() {
@@ -63,7 +108,9 @@ pub(crate) fn read(
                 raw_data_of_worksheet,
                 shared_string_table,
                 stylesheet,
-            );
+                max_cells,
+            )
+            .map_err(|e| raw_data_of_worksheet.worksheet_file().parse_error_context(e));
         }
     }
 
@@ -74,7 +121,43 @@ pub(crate) fn read(
         raw_data_of_worksheet,
         shared_string_table,
         stylesheet,
+        max_cells,
     )
+    .map_err(|e| raw_data_of_worksheet.worksheet_file().parse_error_context(e))
+}
+
+/// Keep the worksheet elements that are not modeled.
+pub(crate) fn read_unknown_elements(
+    worksheet: &mut Worksheet,
+    raw_data_of_worksheet: &RawWorksheet,
+) -> Result<(), XlsxError> {
+    let worksheet_file = raw_data_of_worksheet.worksheet_file();
+    if let Some(source_file) = worksheet_file.source_file() {
+        if !worksheet_file.has_file_data() {
+            let file = File::open(source_file)?;
+            let mut archive = zip::read::ZipArchive::new(file)?;
+            let source = zip_by_name(&mut archive, worksheet_file.file_target())?;
+            return worksheet
+                .raw_unknown_mut()
+                .read_elements(
+                    io::BufReader::new(source),
+                    WORKSHEET_ELEMENTS,
+                    KNOWN_ELEMENTS,
+                    KNOWN_EXTENSIONS,
+                )
+                .map_err(|e| worksheet_file.parse_error_context(e));
+        }
+    }
+
+    worksheet
+        .raw_unknown_mut()
+        .read_elements(
+            io::Cursor::new(worksheet_file.file_data()),
+            WORKSHEET_ELEMENTS,
+            KNOWN_ELEMENTS,
+            KNOWN_EXTENSIONS,
+        )
+        .map_err(|e| worksheet_file.parse_error_context(e))
 }
 
 fn read_from_reader<R: io::BufRead>(
@@ -83,10 +166,12 @@ fn read_from_reader<R: io::BufRead>(
     raw_data_of_worksheet: &RawWorksheet,
     shared_string_table: &SharedStringTable,
     stylesheet: &Stylesheet,
+    max_cells: Option<usize>,
 ) -> Result<(), XlsxError> {
     let mut reader = Reader::from_reader(data);
     reader.config_mut().trim_text(true);
     let mut formula_shared_list: HashMap<u32, (String, Vec<FormulaToken>)> = HashMap::new();
+    let part = raw_data_of_worksheet.worksheet_file().file_target();
     xml_read_loop!(
         reader,
         Event::Start(ref e) => match e.name().into_inner() {
@@ -103,12 +188,12 @@ fn read_from_reader<R: io::BufRead>(
             b"sheetViews" => {
                 worksheet
                     .sheet_views_mut()
-                    .set_attributes(&mut reader, e);
+                    .set_attributes(&mut reader, e)?;
             }
             b"sheetFormatPr" => {
                 worksheet
                     .sheet_format_properties_mut()
-                    .set_attributes(&mut reader, e);
+                    .set_attributes(&mut reader, e)?;
             }
             b"selection" => {
                 for a in e.attributes().with_checks(false) {
@@ -129,61 +214,73 @@ fn read_from_reader<R: io::BufRead>(
                     shared_string_table,
                     stylesheet,
                     &mut formula_shared_list,
+                    max_cells.map(|max| (max, part)),
                     false,
-                );
+                )?;
                 worksheet.set_row_dimension(obj);
             }
+            b"scenarios" => {
+                let mut obj = Scenarios::default();
+                obj.set_attributes(&mut reader, e, false)?;
+                worksheet.set_scenarios(obj);
+            }
             b"autoFilter" => {
-                worksheet.set_auto_filter(get_attribute(e, b"ref").unwrap());
+                let mut obj = AutoFilter::default();
+                obj.set_attributes(&mut reader, e, stylesheet.differential_formats(), false)?;
+                worksheet.set_auto_filter_obj(obj);
             }
             b"cols" => {
                 let mut obj = Columns::default();
-                obj.set_attributes(&mut reader, e, stylesheet);
+                obj.set_attributes(&mut reader, e, stylesheet)?;
                 worksheet.set_column_dimensions_crate(obj);
             }
             b"mergeCells" => {
                 worksheet
                     .merge_cells_crate_mut()
-                    .set_attributes(&mut reader, e);
+                    .set_attributes(&mut reader, e)?;
             }
             b"conditionalFormatting" => {
                 let mut obj = ConditionalFormatting::default();
-                obj.set_attributes(&mut reader, e, stylesheet.differential_formats());
+                obj.set_attributes(&mut reader, e, stylesheet.differential_formats())?;
                 worksheet.add_conditional_formatting_collection(obj);
             }
             b"dataValidations" => {
                 let mut obj = DataValidations::default();
-                obj.set_attributes(&mut reader, e);
+                obj.set_attributes(&mut reader, e)?;
                 worksheet.set_data_validations(obj);
             }
             b"x14:dataValidations" => {
                 let mut obj = DataValidations2010::default();
-                obj.set_attributes(&mut reader, e);
+                obj.set_attributes(&mut reader, e)?;
                 worksheet.set_data_validations_2010(obj);
             }
             b"oleObjects" => {
+                let relationships = raw_data_of_worksheet.worksheet_relationships().ok_or_else(|| {
+                    XlsxError::parse(reader.buffer_position(), "oleObjects has no relationships")
+                })?;
                 let mut obj = OleObjects::default();
-                obj.set_attributes(
-                    &mut reader,
-                    e,
-                    raw_data_of_worksheet.worksheet_relationships().unwrap(),
-                );
+                obj.set_attributes(&mut reader, e, relationships)?;
                 worksheet.set_ole_objects(obj);
             }
+            b"control" => {
+                let mut obj = FormControl::default();
+                obj.set_attributes(&mut reader, e, false)?;
+                worksheet.form_controls_mut().push(obj);
+            }
             b"headerFooter" => {
                 worksheet
                     .header_footer_mut()
-                    .set_attributes(&mut reader, e);
+                    .set_attributes(&mut reader, e)?;
             }
             b"rowBreaks" => {
                 worksheet
                     .row_breaks_mut()
-                    .set_attributes(&mut reader, e);
+                    .set_attributes(&mut reader, e)?;
             }
             b"colBreaks" => {
                 worksheet
                     .column_breaks_mut()
-                    .set_attributes(&mut reader, e);
+                    .set_attributes(&mut reader, e)?;
             }
             _ => (),
         },
@@ -201,12 +298,12 @@ fn read_from_reader<R: io::BufRead>(
             b"tabColor" => {
                 worksheet
                     .tab_color_mut()
-                    .set_attributes(&mut reader, e, true);
+                    .set_attributes(&mut reader, e, true)?;
             }
             b"sheetFormatPr" => {
                 worksheet
                     .sheet_format_properties_mut()
-                    .set_attributes(&mut reader, e);
+                    .set_attributes(&mut reader, e)?;
             }
             b"selection" => {
                 for a in e.attributes().with_checks(false) {
@@ -227,24 +324,42 @@ fn read_from_reader<R: io::BufRead>(
                     shared_string_table,
                     stylesheet,
                     &mut formula_shared_list,
+                    max_cells.map(|max| (max, part)),
                     true,
-                );
+                )?;
                 worksheet.set_row_dimension(obj);
             }
+            b"scenarios" => {
+                let mut obj = Scenarios::default();
+                obj.set_attributes(&mut reader, e, true)?;
+                worksheet.set_scenarios(obj);
+            }
             b"autoFilter" => {
-                worksheet.set_auto_filter(get_attribute(e, b"ref").unwrap());
+                let mut obj = AutoFilter::default();
+                obj.set_attributes(&mut reader, e, stylesheet.differential_formats(), true)?;
+                worksheet.set_auto_filter_obj(obj);
             }
             b"pageMargins" => {
                 worksheet
                     .page_margins_mut()
-                    .set_attributes(&mut reader, e);
+                    .set_attributes(&mut reader, e)?;
+            }
+            b"control" => {
+                let mut obj = FormControl::default();
+                obj.set_attributes(&mut reader, e, true)?;
+                worksheet.form_controls_mut().push(obj);
             }
             b"hyperlink" => {
                 let (coor, hyperlink) = get_hyperlink(
+                    &reader,
                     e,
                     raw_data_of_worksheet.worksheet_relationships()
-                );
-                worksheet.cell_mut(coor).set_hyperlink(hyperlink);
+                )?;
+                let CellCoordinates { col, row } = coor.into();
+                if max_cells.is_some_and(|max| worksheet.cells_crate().exceeds(col, row, max)) {
+                    return Err(XlsxError::LimitExceeded(ReadLimit::Cells, part.to_string()));
+                }
+                worksheet.cell_mut((col, row)).set_hyperlink(hyperlink);
             }
             b"printOptions" => {
                 worksheet
@@ -256,7 +371,7 @@ fn read_from_reader<R: io::BufRead>(
                     &mut reader,
                     e,
                     raw_data_of_worksheet.worksheet_relationships(),
-                );
+                )?;
             }
             b"sheetProtection" => {
                 let mut obj = SheetProtection::default();
@@ -278,30 +393,31 @@ pub(crate) fn read_lite(
     raw_data_of_worksheet: &RawWorksheet,
     shared_string_table: &SharedStringTable,
     stylesheet: &Stylesheet,
-) -> Cells {
+) -> Result<Cells, XlsxError> {
     if let Some(source_file) = raw_data_of_worksheet.worksheet_file().source_file() {
         if !raw_data_of_worksheet.worksheet_file().has_file_data() {
-            let file = File::open(source_file).unwrap();
-            let mut archive = zip::read::ZipArchive::new(file).unwrap();
+            let file = File::open(source_file)?;
+            let mut archive = zip::read::ZipArchive::new(file)?;
             let source = zip_by_name(
                 &mut archive,
                 raw_data_of_worksheet.worksheet_file().file_target(),
-            )
-            .unwrap();
+            )?;
             let reader = io::BufReader::new(source);
-            return read_lite_from_reader(reader, shared_string_table, stylesheet);
+            return read_lite_from_reader(reader, shared_string_table, stylesheet)
+                .map_err(|e| raw_data_of_worksheet.worksheet_file().parse_error_context(e));
         }
     }
 
     let data = io::Cursor::new(raw_data_of_worksheet.worksheet_file().file_data());
     read_lite_from_reader(data, shared_string_table, stylesheet)
+        .map_err(|e| raw_data_of_worksheet.worksheet_file().parse_error_context(e))
 }
 
 fn read_lite_from_reader<R: io::BufRead>(
     data: R,
     shared_string_table: &SharedStringTable,
     stylesheet: &Stylesheet,
-) -> Cells {
+) -> Result<Cells, XlsxError> {
     let mut reader = Reader::from_reader(data);
     reader.config_mut().trim_text(true);
 
@@ -319,8 +435,9 @@ fn read_lite_from_reader<R: io::BufRead>(
                     shared_string_table,
                     stylesheet,
                     &mut formula_shared_list,
+                    None,
                     false,
-                );
+                )?;
             }
         },
         Event::Empty(ref e) => {
@@ -333,14 +450,15 @@ fn read_lite_from_reader<R: io::BufRead>(
                     shared_string_table,
                     stylesheet,
                     &mut formula_shared_list,
+                    None,
                     true,
-                );
+                )?;
             }
         },
         Event::Eof => break,
     );
 
-    cells
+    Ok(cells)
 }
 
 pub(crate) fn read_cells_stream<F>(
@@ -361,14 +479,19 @@ where
                 raw_data_of_worksheet.worksheet_file().file_target(),
             )?;
             let reader = io::BufReader::new(source);
-            read_cells_stream_from_reader(reader, shared_string_table, stylesheet, callback);
-            return Ok(());
+            return read_cells_stream_from_reader(
+                reader,
+                shared_string_table,
+                stylesheet,
+                callback,
+            )
+            .map_err(|e| raw_data_of_worksheet.worksheet_file().parse_error_context(e));
         }
     }
 
     let data = io::Cursor::new(raw_data_of_worksheet.worksheet_file().file_data());
-    read_cells_stream_from_reader(data, shared_string_table, stylesheet, callback);
-    Ok(())
+    read_cells_stream_from_reader(data, shared_string_table, stylesheet, callback)
+        .map_err(|e| raw_data_of_worksheet.worksheet_file().parse_error_context(e))
 }
 
 fn read_cells_stream_from_reader<R, F>(
@@ -376,7 +499,8 @@ fn read_cells_stream_from_reader<R, F>(
     shared_string_table: &SharedStringTable,
     stylesheet: &Stylesheet,
     mut callback: F,
-) where
+) -> Result<(), XlsxError>
+where
     R: io::BufRead,
     F: FnMut(&Cell),
 {
@@ -394,11 +518,13 @@ fn read_cells_stream_from_reader<R, F>(
                     stylesheet,
                     &mut formula_shared_list,
                     &mut callback,
-                );
+                )?;
             }
         },
         Event::Eof => break,
     );
+
+    Ok(())
 }
 
 fn read_row_cells_stream<R, F>(
@@ -407,7 +533,8 @@ fn read_row_cells_stream<R, F>(
     stylesheet: &Stylesheet,
     formula_shared_list: &mut HashMap<u32, (String, Vec<FormulaToken>)>,
     callback: &mut F,
-) where
+) -> Result<(), XlsxError>
+where
     R: io::BufRead,
     F: FnMut(&Cell),
 {
@@ -423,7 +550,7 @@ fn read_row_cells_stream<R, F>(
                     stylesheet,
                     true,
                     formula_shared_list,
-                );
+                )?;
                 callback(&obj);
             }
         },
@@ -437,36 +564,44 @@ fn read_row_cells_stream<R, F>(
                     stylesheet,
                     false,
                     formula_shared_list,
-                );
+                )?;
                 callback(&obj);
             }
         },
         Event::End(ref e) => {
             if e.name().into_inner() == b"row" {
-                return
+                return Ok(())
             }
         },
-        Event::Eof => panic!("Error: Could not find {} end element", "row")
+        Event::Eof => return Err(XlsxError::missing_end_element("row", reader.buffer_position()))
     );
 }
 
-fn get_hyperlink(
+fn get_hyperlink<R>(
+    reader: &Reader<R>,
     e: &quick_xml::events::BytesStart<'_>,
     raw_relationships: Option<&RawRelationships>,
-) -> (String, Hyperlink) {
+) -> Result<(String, Hyperlink), XlsxError> {
     let mut hyperlink = Hyperlink::default();
 
     let coordition = get_attribute(e, b"ref").unwrap_or_default();
+    let unescape = |v: &str| {
+        escape::unescape(v)
+            .map(|v| v.to_string())
+            .map_err(|e| XlsxError::parse(reader.buffer_position(), e.to_string()))
+    };
     if let Some(v) = get_attribute(e, b"location") {
-        hyperlink.set_url(escape::unescape(&v).unwrap().to_string());
+        hyperlink.set_url(unescape(&v)?);
         hyperlink.set_location(true);
     }
     if let Some(v) = get_attribute(e, b"tooltip") {
-        hyperlink.set_tooltip(escape::unescape(&v).unwrap().to_string());
+        hyperlink.set_tooltip(unescape(&v)?);
     }
     if let Some(v) = get_attribute(e, b"r:id") {
-        let relationship = raw_relationships.unwrap().relationship_by_rid(&v);
-        hyperlink.set_url(escape::unescape(relationship.target()).unwrap().to_string());
+        let relationship = raw_relationships
+            .and_then(|relationships| relationships.relationship_by_rid(&v))
+            .ok_or_else(|| XlsxError::missing_relationship(&v, reader.buffer_position()))?;
+        hyperlink.set_url(unescape(relationship.target())?);
     }
-    (coordition, hyperlink)
+    Ok((coordition, hyperlink))
 }
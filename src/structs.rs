@@ -65,8 +65,10 @@ pub_mod_use![
     pub cell,
     pub cells,
     pub chart_type,
+    pub checked_values,
     pub chart,
     pub color_scale,
+    pub color_filter,
     pub color,
     pub column_breaks,
     pub column_fields,
@@ -84,22 +86,38 @@ pub_mod_use![
     pub coordinate,
     pub csv_encode_values,
     pub csv_writer_option,
+    pub custom_filter,
+    pub custom_filters,
     pub data_bar,
     pub data_field,
     pub data_fields,
     pub date_time_value,
+    pub date_group_item,
+    pub date_time_grouping_values,
     pub data_validation_operator_values,
     pub data_validation_values,
     pub data_validation,
     pub data_validations,
     pub defined_name,
+    pub defined_names,
+    pub dependency_graph,
     pub double_value,
+    pub dynamic_array_properties,
+    pub dynamic_filter_values,
+    pub dynamic_filter,
     pub embedded_object_properties,
     pub enum_trait,
     pub enum_value,
     pub error,
+    pub external_cell,
+    pub external_defined_name,
+    pub external_link,
+    pub external_sheet_data,
     pub field,
     pub fill,
+    pub filter_column,
+    pub filter_operator_values,
+    pub filters,
     pub font_char_set,
     pub font_family_numbering,
     pub font_name,
@@ -107,8 +125,11 @@ pub_mod_use![
     pub font_scheme,
     pub font_size,
     pub font,
+    pub form_control,
     pub formula,
     pub from_marker,
+    pub future_metadata,
+    pub future_metadata_block,
     pub gradient_fill,
     pub gradient_stop,
     pub header_footer,
@@ -116,6 +137,7 @@ pub_mod_use![
     pub hyperlink,
     pub icon_set,
     pub image,
+    pub input_cells,
     pub int16_value,
     pub int32_value,
     pub int64_value,
@@ -125,6 +147,10 @@ pub_mod_use![
     pub item_values,
     pub location,
     pub member_property_index,
+    pub metadata,
+    pub metadata_block,
+    pub metadata_record,
+    pub metadata_type,
     pub numbering_format,
     pub object_anchor,
     pub odd_footer,
@@ -151,6 +177,7 @@ pub_mod_use![
     pub protection,
     pub r#break,
     pub range,
+    pub read_limits,
     pub rich_text,
     pub row_breaks,
     pub row_fields,
@@ -159,6 +186,8 @@ pub_mod_use![
     pub row_reference,
     pub row,
     pub s_byte_value,
+    pub scenario,
+    pub scenarios,
     pub selection,
     pub sequence_of_references,
     pub shared_items,
@@ -168,6 +197,11 @@ pub_mod_use![
     pub sheet_view_values,
     pub sheet_view,
     pub sheet_views,
+    pub sort_by_values,
+    pub sort_condition,
+    pub sort_data_type_values,
+    pub sort_key,
+    pub sort_state,
     pub source_values,
     pub strike,
     pub string_value,
@@ -176,6 +210,7 @@ pub_mod_use![
     pub text_element,
     pub time_period_values,
     pub to_marker,
+    pub top10,
     pub totals_row_function_values,
     pub true_false_blank_value,
     pub true_false_value,
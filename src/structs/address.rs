@@ -72,7 +72,23 @@ impl Address {
         let (sheet_name, range) = split_address(&org_value);
         self.range.set_range(range);
         if !sheet_name.is_empty() {
-            self.sheet_name = sheet_name.into();
+            self.sheet_name = if org_value.starts_with('\'') {
+                sheet_name.replace("''", "'").into_boxed_str()
+            } else {
+                sheet_name.into()
+            };
+        }
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Replace the address with the operand `replacer` returns for it.
+    pub(crate) fn replace_operand<F>(&mut self, replacer: F) -> &mut Self
+    where
+        F: FnOnce(&str) -> Option<String>,
+    {
+        if let Some(value) = replacer(&self.address_ptn2()) {
+            self.set_address(value);
         }
         self
     }
@@ -111,14 +127,14 @@ impl Address {
         if sheet_name.contains(char::is_whitespace) {
             with_space_char = "'";
         }
+        if sheet_name.contains('\'') {
+            with_space_char = "'";
+            sheet_name = sheet_name.replace('\'', "''").into_boxed_str();
+        }
         if is_ptn2 {
             if sheet_name.contains('!') {
                 with_space_char = "'";
             }
-            if sheet_name.contains('\'') {
-                with_space_char = "'";
-                sheet_name = sheet_name.replace('\'', "''").into_boxed_str();
-            }
             if sheet_name.contains('"') {
                 with_space_char = "'";
             }
@@ -1,9 +1,41 @@
-use super::Range;
-use crate::traits::AdjustmentCoordinate;
+// autoFilter
+use std::io::Cursor;
+
+use chrono::NaiveDate;
+use quick_xml::{
+    Reader,
+    Writer,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use super::{
+    Cells,
+    DifferentialFormats,
+    FilterColumn,
+    Range,
+    SortState,
+};
+use crate::{
+    XlsxError,
+    reader::driver::{
+        get_attribute,
+        xml_read_loop,
+    },
+    traits::AdjustmentCoordinate,
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+    },
+};
 
 #[derive(Clone, Default, Debug)]
 pub struct AutoFilter {
-    range: Range,
+    range:              Range,
+    filter_column_list: Vec<FilterColumn>,
+    sort_state:         Option<SortState>,
 }
 
 impl AutoFilter {
@@ -37,9 +69,180 @@ impl AutoFilter {
         range.set_range(value.into());
         self.range = range;
     }
+
+    #[inline]
+    #[must_use]
+    pub fn filter_column_list(&self) -> &[FilterColumn] {
+        &self.filter_column_list
+    }
+
+    #[inline]
+    pub fn filter_column_list_mut(&mut self) -> &mut Vec<FilterColumn> {
+        &mut self.filter_column_list
+    }
+
+    /// Get the filter of the column at `column_id`,
+    /// counted from 0 at the first column of the range.
+    #[inline]
+    #[must_use]
+    pub fn filter_column(&self, column_id: u32) -> Option<&FilterColumn> {
+        self.filter_column_list
+            .iter()
+            .find(|x| x.column_id() == column_id)
+    }
+
+    #[inline]
+    pub fn filter_column_mut(&mut self, column_id: u32) -> Option<&mut FilterColumn> {
+        self.filter_column_list
+            .iter_mut()
+            .find(|x| x.column_id() == column_id)
+    }
+
+    /// Add a filter column, replacing the filter of the same column.
+    #[inline]
+    pub fn add_filter_column(&mut self, value: FilterColumn) -> &mut Self {
+        self.filter_column_list
+            .retain(|x| x.column_id() != value.column_id());
+        self.filter_column_list.push(value);
+        self.filter_column_list.sort_by_key(FilterColumn::column_id);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn sort_state(&self) -> Option<&SortState> {
+        self.sort_state.as_ref()
+    }
+
+    #[inline]
+    pub fn sort_state_mut(&mut self) -> Option<&mut SortState> {
+        self.sort_state.as_mut()
+    }
+
+    #[inline]
+    pub fn set_sort_state(&mut self, value: SortState) -> &mut Self {
+        self.sort_state = Some(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_sort_state(&mut self) -> &mut Self {
+        self.sort_state = None;
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        differential_formats: &DifferentialFormats,
+        empty_flg: bool,
+    ) -> Result<(), XlsxError> {
+        if let Some(v) = get_attribute(e, b"ref") {
+            self.set_range(v);
+        }
+
+        if empty_flg {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                match e.name().into_inner() {
+                    b"filterColumn" => {
+                        let mut obj = FilterColumn::default();
+                        obj.set_attributes(reader, e, differential_formats, true)?;
+                        self.filter_column_list.push(obj);
+                    }
+                    b"sortState" => {
+                        let mut obj = SortState::default();
+                        obj.set_attributes(reader, e, differential_formats, true)?;
+                        self.sort_state = Some(obj);
+                    }
+                    _ => (),
+                }
+            },
+            Event::Start(ref e) => {
+                match e.name().into_inner() {
+                    b"filterColumn" => {
+                        let mut obj = FilterColumn::default();
+                        obj.set_attributes(reader, e, differential_formats, false)?;
+                        self.filter_column_list.push(obj);
+                    }
+                    b"sortState" => {
+                        let mut obj = SortState::default();
+                        obj.set_attributes(reader, e, differential_formats, false)?;
+                        self.sort_state = Some(obj);
+                    }
+                    _ => (),
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"autoFilter" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(XlsxError::missing_end_element("autoFilter", reader.buffer_position()))
+        );
+    }
+
+    pub(crate) fn write_to(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        differential_formats: &mut DifferentialFormats,
+    ) {
+        // autoFilter
+        let is_empty = self.filter_column_list.is_empty() && self.sort_state.is_none();
+        write_start_tag(
+            writer,
+            "autoFilter",
+            vec![("ref", &self.range.range()).into()],
+            is_empty,
+        );
+        if is_empty {
+            return;
+        }
+
+        for filter_column in &self.filter_column_list {
+            filter_column.write_to(writer, differential_formats);
+        }
+
+        if let Some(v) = &self.sort_state {
+            v.write_to(writer, differential_formats);
+        }
+
+        write_end_tag(writer, "autoFilter");
+    }
+}
+
+/// Evaluate filter columns over the data rows of a filtered range.
+/// # Return value
+/// * `Vec<(u32, bool)>` - Row number and whether the row is shown.
+pub(crate) fn evaluate_filter_columns(
+    filter_column_list: &mut [FilterColumn],
+    cells: &Cells,
+    col_beg: u32,
+    row_beg: u32,
+    row_end: u32,
+    today: NaiveDate,
+) -> Vec<(u32, bool)> {
+    let mut result: Vec<(u32, bool)> = (row_beg..=row_end).map(|x| (x, true)).collect();
+    for filter_column in filter_column_list {
+        let col_num = col_beg + filter_column.column_id();
+        let column: Vec<_> = (row_beg..=row_end)
+            .map(|row_num| cells.get((col_num, row_num)))
+            .collect();
+        for (row, is_match) in result
+            .iter_mut()
+            .zip(filter_column.evaluate(&column, today))
+        {
+            row.1 &= is_match;
+        }
+    }
+    result
 }
 impl AdjustmentCoordinate for AutoFilter {
-    #[inline]
     fn adjustment_insert_coordinate(
         &mut self,
         root_col_num: u32,
@@ -53,9 +256,16 @@ impl AdjustmentCoordinate for AutoFilter {
             root_row_num,
             offset_row_num,
         );
+        if let Some(v) = &mut self.sort_state {
+            v.adjustment_insert_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
     }
 
-    #[inline]
     fn adjustment_remove_coordinate(
         &mut self,
         root_col_num: u32,
@@ -69,5 +279,13 @@ impl AdjustmentCoordinate for AutoFilter {
             root_row_num,
             offset_row_num,
         );
+        if let Some(v) = &mut self.sort_state {
+            v.adjustment_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
     }
 }
@@ -16,6 +16,7 @@ use super::{
     EnumValue,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -130,18 +131,18 @@ impl Border {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, style, "style");
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"color" {
-                    self.color.clone().unwrap_or_default().set_attributes(reader, e, true);
+                    self.color.clone().unwrap_or_default().set_attributes(reader, e, true)?;
                 }
             },
             Event::End(ref e) => {
@@ -152,14 +153,11 @@ impl Border {
                     b"bottom"   |
                     b"diagonal" |
                     b"vertical" |
-                    b"horizontal" => return,
+                    b"horizontal" => return Ok(()),
                     _ => (),
                 }
             },
-            Event::Eof => panic!(
-                "Error: Could not find {} end element",
-                "left,right,top,bottom,diagonal,vertical,horizontal"
-            )
+            Event::Eof => return Err(XlsxError::missing_end_element("left,right,top,bottom,diagonal,vertical,horizontal", reader.buffer_position()))
         );
     }
 
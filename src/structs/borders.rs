@@ -15,6 +15,7 @@ use super::{
     Border,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -536,7 +537,7 @@ impl Borders {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, diagonal_up, "diagonalUp");
         set_string_from_xml!(self, e, diagonal_down, "diagonalDown");
 
@@ -545,25 +546,25 @@ impl Borders {
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                     b"left" => {
-                        self.data[BordersIndex::Left as usize].set_attributes(reader, e, true);
+                        self.data[BordersIndex::Left as usize].set_attributes(reader, e, true)?;
                     }
                     b"right" => {
-                        self.data[BordersIndex::Right as usize].set_attributes(reader, e, true);
+                        self.data[BordersIndex::Right as usize].set_attributes(reader, e, true)?;
                     }
                     b"top" => {
-                        self.data[BordersIndex::Top as usize].set_attributes(reader, e, true);
+                        self.data[BordersIndex::Top as usize].set_attributes(reader, e, true)?;
                     }
                     b"bottom" => {
-                        self.data[BordersIndex::Bottom as usize].set_attributes(reader, e, true);
+                        self.data[BordersIndex::Bottom as usize].set_attributes(reader, e, true)?;
                     }
                     b"diagonal" => {
-                        self.data[BordersIndex::Diagonal as usize].set_attributes(reader, e, true);
+                        self.data[BordersIndex::Diagonal as usize].set_attributes(reader, e, true)?;
                     }
                     b"vertical" => {
-                        self.data[BordersIndex::Vertical as usize].set_attributes(reader, e, true);
+                        self.data[BordersIndex::Vertical as usize].set_attributes(reader, e, true)?;
                     }
                     b"horizontal" => {
-                        self.data[BordersIndex::Horizontal as usize].set_attributes(reader, e, true);
+                        self.data[BordersIndex::Horizontal as usize].set_attributes(reader, e, true)?;
                     }
                     _ => (),
                 }
@@ -571,35 +572,35 @@ impl Borders {
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"left" => {
-                        self.data[BordersIndex::Left as usize].set_attributes(reader, e, false);
+                        self.data[BordersIndex::Left as usize].set_attributes(reader, e, false)?;
                     }
                     b"right" => {
-                        self.data[BordersIndex::Right as usize].set_attributes(reader, e, false);
+                        self.data[BordersIndex::Right as usize].set_attributes(reader, e, false)?;
                     }
                     b"top" => {
-                        self.data[BordersIndex::Top as usize].set_attributes(reader, e, false);
+                        self.data[BordersIndex::Top as usize].set_attributes(reader, e, false)?;
                     }
                     b"bottom" => {
-                        self.data[BordersIndex::Bottom as usize].set_attributes(reader, e, false);
+                        self.data[BordersIndex::Bottom as usize].set_attributes(reader, e, false)?;
                     }
                     b"diagonal" => {
-                        self.data[BordersIndex::Diagonal as usize].set_attributes(reader, e, false);
+                        self.data[BordersIndex::Diagonal as usize].set_attributes(reader, e, false)?;
                     }
                     b"vertical" => {
-                        self.data[BordersIndex::Vertical as usize].set_attributes(reader, e, false);
+                        self.data[BordersIndex::Vertical as usize].set_attributes(reader, e, false)?;
                     }
                     b"horizontal" => {
-                        self.data[BordersIndex::Horizontal as usize].set_attributes(reader, e, false);
+                        self.data[BordersIndex::Horizontal as usize].set_attributes(reader, e, false)?;
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"border" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "border")
+            Event::Eof => return Err(XlsxError::missing_end_element("border", reader.buffer_position()))
         );
     }
 
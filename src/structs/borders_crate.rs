@@ -15,6 +15,7 @@ use super::{
     Style,
 };
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -80,7 +81,7 @@ impl BordersCrate {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
@@ -92,16 +93,16 @@ impl BordersCrate {
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"border" {
                     let mut obj = Borders::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_borders(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"borders" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "borders")
+            Event::Eof => return Err(XlsxError::missing_end_element("borders", reader.buffer_position()))
         );
     }
 
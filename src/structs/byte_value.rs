@@ -33,7 +33,10 @@ impl ByteValue {
 
     #[inline]
     pub(crate) fn set_value_string<S: Into<String>>(&mut self, value: S) -> &mut ByteValue {
-        self.set_value(value.into().parse::<u8>().unwrap())
+        if let Ok(v) = value.into().parse::<u8>() {
+            self.set_value(v);
+        }
+        self
     }
 
     #[inline]
@@ -11,6 +11,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -117,7 +118,7 @@ impl CacheField {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, name, "name");
         set_string_from_xml!(self, e, number_format_id, "numFmtId");
 
@@ -126,23 +127,23 @@ impl CacheField {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"sharedItems" {
                     let mut obj = SharedItems::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_shared_items(obj);
                 }
             },
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"sharedItems" {
                     let mut obj = SharedItems::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.set_shared_items(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"cacheField" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "cacheField")
+            Event::Eof => return Err(XlsxError::missing_end_element("cacheField", reader.buffer_position()))
         );
     }
 
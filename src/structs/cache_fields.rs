@@ -10,6 +10,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     structs::CacheField,
     writer::driver::{
@@ -63,22 +64,22 @@ impl CacheFields {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"cacheField" {
                     let mut obj = CacheField::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_list_mut(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"cacheFields" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "cacheFields")
+            Event::Eof => return Err(XlsxError::missing_end_element("cacheFields", reader.buffer_position()))
         );
     }
 
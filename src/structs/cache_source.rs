@@ -11,6 +11,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -107,11 +108,11 @@ impl CacheSource {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, r#type, "type");
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -125,10 +126,10 @@ impl CacheSource {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"cacheSource" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "cacheSource")
+            Event::Eof => return Err(XlsxError::missing_end_element("cacheSource", reader.buffer_position()))
         );
     }
 
@@ -15,6 +15,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     helper::{
         coordinate::CellCoordinates,
         formula::{
@@ -36,8 +37,10 @@ use crate::{
         CellValue,
         Coordinate,
         Hyperlink,
+        Metadata,
         NumberingFormat,
         RichText,
+        SharedFormulaGroup,
         SharedStringItem,
         SharedStringTable,
         Style,
@@ -568,7 +571,7 @@ impl Cell {
         stylesheet: &Stylesheet,
         empty_flag: bool,
         formula_shared_list: &mut HashMap<u32, (String, Vec<FormulaToken>)>,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut type_value: String = String::new();
         let mut cell_reference: String = String::new();
 
@@ -591,7 +594,7 @@ impl Cell {
         set_string_from_xml!(self, e, cell_meta_index, "cm");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         let mut string_value: String = String::new();
@@ -602,7 +605,7 @@ impl Cell {
                 Ok(Event::Start(ref e)) => match e.name().into_inner() {
                     b"f" => {
                         let mut obj = CellFormula::default();
-                        obj.set_attributes(reader, e, false, &cell_reference, formula_shared_list);
+                        obj.set_attributes(reader, e, false, &cell_reference, formula_shared_list)?;
                         self.cell_value.set_formula_obj(obj);
                     }
                     b"t" => {
@@ -619,7 +622,7 @@ impl Cell {
                 Ok(Event::Empty(ref e)) => {
                     if e.name().into_inner() == b"f" {
                         let mut obj = CellFormula::default();
-                        obj.set_attributes(reader, e, true, &cell_reference, formula_shared_list);
+                        obj.set_attributes(reader, e, true, &cell_reference, formula_shared_list)?;
                         self.cell_value.set_formula_obj(obj);
                     }
                 }
@@ -654,14 +657,14 @@ impl Cell {
                             self.set_value_crate(&string_value);
                         }
                     }
-                    b"c" => return,
+                    b"c" => return Ok(()),
                     b"t" => {
                         reader.config_mut().trim_text(true);
                     }
                     _ => (),
                 },
-                Ok(Event::Eof) => panic!("Error: Could not find {} end element", "c"),
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(XlsxError::missing_end_element("c", reader.buffer_position())),
+                Err(e) => return Err(XlsxError::parse(reader.buffer_position(), e.to_string())),
                 _ => (),
             }
             buf.clear();
@@ -673,7 +676,8 @@ impl Cell {
         writer: &mut Writer<Cursor<Vec<u8>>>,
         shared_string_table: &RwLock<SharedStringTable>,
         stylesheet: &mut Stylesheet,
-        formula_shared_list: &HashMap<u32, (String, Option<String>)>,
+        formula_shared_list: &HashMap<u32, SharedFormulaGroup>,
+        metadata: &Metadata,
     ) {
         let empty_flag_value = self.cell_value.is_empty();
         let empty_flag_style = self.style.is_empty();
@@ -699,11 +703,25 @@ impl Cell {
             attributes.push(("s", &xf_index_str).into());
         }
 
-        // NOT SUPPORTED
-        // let cell_meta_index_str = self.cell_meta_index.get_value_string();
-        // if self.cell_meta_index.has_value() {
-        //    attributes.push(("cm", &cell_meta_index_str).into());
-        // }
+        let is_dynamic_array = self
+            .cell_value
+            .formula
+            .as_deref()
+            .is_some_and(CellFormula::dynamic_array);
+        let cell_meta_index = if is_dynamic_array {
+            metadata.dynamic_array_cell_meta_index()
+        } else if self.cell_meta_index.has_value()
+            && self.cell_meta_index.value() > 0
+            && !metadata.is_dynamic_array(self.cell_meta_index.value())
+        {
+            Some(self.cell_meta_index.value())
+        } else {
+            None
+        };
+        let cell_meta_index_str = cell_meta_index.map(|v| v.to_string());
+        if let Some(v) = &cell_meta_index_str {
+            attributes.push(("cm", v).into());
+        }
 
         if empty_flag_value {
             write_start_tag(writer, "c", attributes, true);
@@ -17,6 +17,7 @@ use super::{
     UInt32Value,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -325,7 +326,7 @@ impl CellFormat {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, number_format_id, "numFmtId");
         set_string_from_xml!(self, e, font_id, "fontId");
         set_string_from_xml!(self, e, fill_id, "fillId");
@@ -338,7 +339,7 @@ impl CellFormat {
         set_string_from_xml!(self, e, apply_protection, "applyProtection");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -360,10 +361,10 @@ impl CellFormat {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xf" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xf")
+            Event::Eof => return Err(XlsxError::missing_end_element("xf", reader.buffer_position()))
         );
     }
 
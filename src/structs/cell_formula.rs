@@ -1,5 +1,8 @@
 use std::{
-    collections::HashMap,
+    collections::{
+        HashMap,
+        HashSet,
+    },
     io::Cursor,
 };
 
@@ -13,15 +16,15 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     helper::{
         coordinate::index_from_coordinate,
         formula::{
             FormulaToken,
-            adjustment_formula_coordinate,
             adjustment_insert_formula_coordinate,
             adjustment_remove_formula_coordinate,
             parse_to_tokens,
-            render,
+            translate_formula,
         },
     },
     reader::driver::{
@@ -44,6 +47,17 @@ use crate::{
     },
 };
 
+/// (This struct is crate only.)
+/// A shared formula group as it is written: the master cell, the `ref`
+/// covering the group and the dependents that still read as the master
+/// formula translated to their position.
+#[derive(Clone, Default, Debug)]
+pub(crate) struct SharedFormulaGroup {
+    pub(crate) master:     String,
+    pub(crate) reference:  String,
+    pub(crate) dependents: HashSet<String>,
+}
+
 #[derive(Clone, Default, Debug, PartialEq, PartialOrd)]
 pub struct CellFormula {
     bx:             BooleanValue,
@@ -58,6 +72,7 @@ pub struct CellFormula {
     shared_index:   UInt32Value,
     text:           StringValue,
     text_view:      StringValue,
+    dynamic_array:  bool,
 }
 impl CellFormula {
     #[inline]
@@ -277,6 +292,41 @@ impl CellFormula {
         self
     }
 
+    /// Whether this is a dynamic array formula, which spills its result over
+    /// `reference`. It is written with the cell metadata that marks it.
+    #[inline]
+    #[must_use]
+    pub fn dynamic_array(&self) -> bool {
+        self.dynamic_array
+    }
+
+    #[inline]
+    pub fn set_dynamic_array(&mut self, value: bool) -> &mut Self {
+        self.dynamic_array = value;
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Replace the formula returned by `text()`.
+    #[inline]
+    pub(crate) fn replace_text<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        if self.text_view.has_value() {
+            self.text_view.set_value(value);
+        } else {
+            self.text.set_value(value);
+        }
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Whether this is the master of a shared formula group, the member
+    /// holding the formula text.
+    #[inline]
+    pub(crate) fn is_shared_master(&self) -> bool {
+        self.formula_type.value() == &CellFormulaValues::Shared
+            && !self.text.value_str().is_empty()
+    }
+
     #[inline]
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
@@ -285,7 +335,7 @@ impl CellFormula {
         is_empty: bool,
         cell_reference_str: &str,
         formula_shared_list: &mut HashMap<u32, (String, Vec<FormulaToken>)>,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, bx, "bx");
         set_string_from_xml!(self, e, data_table_2d, "dt2D");
         set_string_from_xml!(self, e, data_table_row, "dtr");
@@ -298,18 +348,25 @@ impl CellFormula {
         set_string_from_xml!(self, e, shared_index, "si");
 
         if !is_empty {
+            let mut value = String::new();
             xml_read_loop!(
                 reader,
                 Event::Text(e) => {
-                    self.text.set_value(crate::helper::utils::unescape_xml_text(&e));
+                    value.push_str(&crate::helper::utils::unescape_xml_text(&e));
+                },
+                Event::GeneralRef(e) => {
+                    value.push_str(&crate::helper::utils::unescape_xml_general_ref(&e));
                 },
                 Event::End(ref e) => {
                     if e.name().into_inner() == b"f" {
                         break;
                     }
                 },
-                Event::Eof => panic!("Error: Could not find {} end element", "f")
+                Event::Eof => return Err(XlsxError::missing_end_element("f", reader.buffer_position()))
             );
+            if !value.is_empty() {
+                self.text.set_value(value);
+            }
         }
 
         // Shared
@@ -319,10 +376,10 @@ impl CellFormula {
                     let parent_cell = index_from_coordinate(parent_cell_reference_str);
                     let self_cell = index_from_coordinate(cell_reference_str);
                     let (Some(parent_col_num), Some(parent_row_num), ..) = parent_cell else {
-                        return;
+                        return Ok(());
                     };
                     let (Some(self_col_num), Some(self_row_num), ..) = self_cell else {
-                        return;
+                        return Ok(());
                     };
 
                     // Shared-formula sibling rebasing: translate every
@@ -341,10 +398,11 @@ impl CellFormula {
                     let offset_row_num: i32 = num_traits::cast::<_, i32>(self_row_num).unwrap()
                         - num_traits::cast::<_, i32>(parent_row_num).unwrap();
 
-                    let mut token_new = token.clone();
-                    adjustment_formula_coordinate(&mut token_new, offset_col_num, offset_row_num);
-                    let value = render(token_new.as_ref());
-                    self.text_view.set_value(value);
+                    self.text_view.set_value(translate_formula(
+                        token,
+                        offset_col_num,
+                        offset_row_num,
+                    ));
                 }
                 None => {
                     formula_shared_list.insert(
@@ -357,6 +415,7 @@ impl CellFormula {
                 }
             }
         }
+        Ok(())
     }
 
     #[inline]
@@ -364,8 +423,18 @@ impl CellFormula {
         &self,
         writer: &mut Writer<Cursor<Vec<u8>>>,
         coordinate: &str,
-        formula_shared_list: &HashMap<u32, (String, Option<String>)>,
+        formula_shared_list: &HashMap<u32, SharedFormulaGroup>,
     ) {
+        // A shared member the group no longer covers is written on its own.
+        let shared_group = formula_shared_list
+            .get(&self.shared_index.value())
+            .filter(|group| {
+                self.formula_type.value() == &CellFormulaValues::Shared
+                    && (group.master == coordinate || group.dependents.contains(coordinate))
+            });
+        let is_standalone =
+            self.formula_type.value() == &CellFormulaValues::Shared && shared_group.is_none();
+
         // f
         let mut attributes: crate::structs::AttrCollection = Vec::new();
         let bx_str = self.bx.value_string();
@@ -384,11 +453,8 @@ impl CellFormula {
         }
 
         let formula_type_str = self.formula_type.value_string();
-        if self.formula_type.has_value() {
-            // Not SUPPORT Array
-            if self.formula_type.value() != &CellFormulaValues::Array {
-                attributes.push(("t", formula_type_str).into());
-            }
+        if self.formula_type.has_value() && !is_standalone {
+            attributes.push(("t", formula_type_str).into());
         }
 
         let input_1deleted_str = self.input_1deleted.value_string();
@@ -409,28 +475,26 @@ impl CellFormula {
             attributes.push(("r2", self.r2.value_str()).into());
         }
 
-        #[allow(unused_assignments)]
-        let mut reference_str = String::new();
-        if let Some((start_col, end_col)) = formula_shared_list.get(&self.shared_index.value())
-        {
-            if coordinate == start_col {
-                reference_str = match end_col {
-                    Some(v) => {
-                        format!("{start_col}:{v}")
-                    }
-                    None => start_col.clone(),
-                };
-                attributes.push(("ref", &reference_str).into());
+        let mut text = self.text();
+        if let Some(group) = shared_group {
+            if group.master == coordinate {
+                attributes.push(("ref", &group.reference).into());
+            } else {
+                text = "";
             }
+        } else if self.reference.has_value()
+            && self.formula_type.value() != &CellFormulaValues::Shared
+        {
+            attributes.push(("ref", self.reference.value_str()).into());
         }
 
         let shared_index_str = self.shared_index.value_string();
-        if self.shared_index.has_value() {
+        if self.shared_index.has_value() && !is_standalone {
             attributes.push(("si", &shared_index_str).into());
         }
 
         write_start_tag(writer, "f", attributes, false);
-        write_text_node_conversion(writer, self.text.value_str());
+        write_text_node_conversion(writer, text);
         write_end_tag(writer, "f");
     }
 }
@@ -471,6 +535,19 @@ impl AdjustmentCoordinateWith2Sheet for CellFormula {
             );
             self.text_view.set_value(formula);
         }
+        if let Some(v) = self.reference.value() {
+            let reference = adjustment_insert_formula_coordinate(
+                &mut parse_to_tokens(format!("={v}")),
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+                sheet_name,
+                self_sheet_name,
+                false,
+            );
+            self.reference.set_value(reference);
+        }
     }
 
     #[inline]
@@ -509,5 +586,18 @@ impl AdjustmentCoordinateWith2Sheet for CellFormula {
             );
             self.text_view.set_value(formula);
         }
+        if let Some(v) = self.reference.value() {
+            let reference = adjustment_remove_formula_coordinate(
+                &mut parse_to_tokens(format!("={v}")),
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+                sheet_name,
+                self_sheet_name,
+                false,
+            );
+            self.reference.set_value(reference);
+        }
     }
 }
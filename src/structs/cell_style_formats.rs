@@ -12,6 +12,7 @@ use quick_xml::{
 
 use super::CellFormat;
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -58,29 +59,29 @@ impl CellStyleFormats {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"xf" {
                     let mut obj = CellFormat::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_cell_format(obj);
                 }
             },
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"xf" {
                     let mut obj = CellFormat::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.set_cell_format(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"cellStyleXfs" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "cellStyleXfs")
+            Event::Eof => return Err(XlsxError::missing_end_element("cellStyleXfs", reader.buffer_position()))
         );
     }
 
@@ -12,6 +12,7 @@ use quick_xml::{
 
 use super::CellStyle;
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -57,7 +58,7 @@ impl CellStyles {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
@@ -69,10 +70,10 @@ impl CellStyles {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"cellStyles" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "cellStyles")
+            Event::Eof => return Err(XlsxError::missing_end_element("cellStyles", reader.buffer_position()))
         );
     }
 
@@ -10,8 +10,16 @@ use super::{
 };
 use crate::{
     CellErrorType,
+    helper::{
+        coordinate::CellCoordinates,
+        formula::{
+            add_future_function_prefixes,
+            from_r1c1,
+        },
+    },
     structs::{
         CellFormula,
+        CellFormulaValues,
         CellRawValue,
     },
     traits::AdjustmentCoordinateWith2Sheet,
@@ -256,6 +264,62 @@ impl CellValue {
         self
     }
 
+    /// Set a formula written in R1C1 style.
+    /// # Arguments
+    /// * `value` - ex) "SUM(R[-3]C:R[-1]C)"
+    /// * `at` - Cell the formula is in, which the relative references are
+    ///   based on. ex) "B4"
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let sheet = book.sheet_mut(0).unwrap();
+    /// sheet
+    ///     .cell_mut("B4")
+    ///     .cell_value_mut()
+    ///     .set_formula_r1c1("SUM(R[-3]C:R[-1]C)", "B4");
+    /// assert_eq!(sheet.cell("B4").unwrap().formula(), "SUM(B1:B3)");
+    /// ```
+    #[inline]
+    pub fn set_formula_r1c1<S, T>(&mut self, value: S, at: T) -> &mut Self
+    where
+        S: AsRef<str>,
+        T: Into<CellCoordinates>,
+    {
+        self.set_formula(from_r1c1(value.as_ref(), at))
+    }
+
+    /// Set a dynamic array formula, which spills its result over
+    /// `spill_ref`.
+    /// Functions that came with dynamic arrays, such as `SORT` or `FILTER`,
+    /// are given the prefix Excel stores them with.
+    /// # Arguments
+    /// * `value` - ex) "SORT(A1:A3)"
+    /// * `spill_ref` - Range the result spills over, starting at the cell
+    ///   the formula is in. ex) "B1:B3"
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let sheet = book.sheet_mut(0).unwrap();
+    /// sheet
+    ///     .cell_mut("B1")
+    ///     .cell_value_mut()
+    ///     .set_dynamic_array_formula("SORT(A1:A3)", "B1:B3");
+    /// assert_eq!(sheet.cell("B1").unwrap().formula(), "_xlfn._xlws.SORT(A1:A3)");
+    /// ```
+    pub fn set_dynamic_array_formula<S, T>(&mut self, value: S, spill_ref: T) -> &mut Self
+    where
+        S: AsRef<str>,
+        T: Into<String>,
+    {
+        let mut obj = CellFormula::default();
+        obj.set_formula_type(CellFormulaValues::Array);
+        obj.set_reference(spill_ref);
+        obj.set_text(add_future_function_prefixes(value.as_ref()));
+        obj.set_dynamic_array(true);
+        self.formula = Some(Box::new(obj));
+        self
+    }
+
     #[inline]
     pub fn set_formula_obj(&mut self, value: CellFormula) -> &mut Self {
         self.formula = Some(Box::new(value));
@@ -423,6 +423,13 @@ impl Cells {
         self.column_row_index.insert((col_num, row_num));
     }
 
+    /// Whether adding a cell at `(col_num, row_num)` makes more than `max`
+    /// cells.
+    #[inline]
+    pub(crate) fn exceeds(&self, col_num: u32, row_num: u32, max: usize) -> bool {
+        self.map.len() >= max && !self.map.contains_key(&(row_num, col_num))
+    }
+
     #[inline]
     pub(crate) fn remove(&mut self, col_num: u32, row_num: u32) -> bool {
         let k = (row_num, col_num);
@@ -0,0 +1,38 @@
+use std::str::FromStr;
+
+use super::EnumTrait;
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CheckedValues {
+    Checked,
+    Mixed,
+    Unchecked,
+}
+impl Default for CheckedValues {
+    #[inline]
+    fn default() -> Self {
+        Self::Unchecked
+    }
+}
+impl EnumTrait for CheckedValues {
+    #[inline]
+    fn value_string(&self) -> &str {
+        match &self {
+            Self::Checked => "Checked",
+            Self::Mixed => "Mixed",
+            Self::Unchecked => "Unchecked",
+        }
+    }
+}
+impl FromStr for CheckedValues {
+    type Err = ();
+
+    #[inline]
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "Checked" => Ok(Self::Checked),
+            "Mixed" => Ok(Self::Mixed),
+            "Unchecked" => Ok(Self::Unchecked),
+            _ => Err(()),
+        }
+    }
+}
@@ -16,6 +16,7 @@ use quick_xml::{
 use rgb::Argb;
 
 use crate::{
+    XlsxError,
     helper::color::calc_tint,
     reader::driver::get_attribute_value,
     structs::drawing::Theme,
@@ -373,6 +374,34 @@ impl Color {
         self.argb_with_theme(theme)
     }
 
+    /// Resolve the color to a concrete ARGB value.
+    /// Theme colors are looked up in the color scheme of `theme`, and the
+    /// tint is applied to every kind of color.
+    #[must_use]
+    pub(crate) fn resolve(&self, theme: &Theme) -> ARGB8 {
+        let mut argb = self.argb();
+        if self.indexed.is_none() {
+            if let Some(key) = self.theme_index {
+                if let Some(v) = theme
+                    .theme_elements()
+                    .color_scheme()
+                    .color_map()
+                    .get(key as usize)
+                    .and_then(|v| Self::hex_to_argb8(v))
+                {
+                    argb = v;
+                }
+            }
+        }
+        match self.tint {
+            Some(tint) if tint != 0.0 => {
+                let rgb = format!("{:02X}{:02X}{:02X}", argb.r, argb.g, argb.b);
+                Self::hex_to_argb8(&calc_tint(&rgb, tint)).map_or(argb, |v| ARGB8 { a: argb.a, ..v })
+            }
+            _ => argb,
+        }
+    }
+
     pub fn set_argb<S: Into<ARGB8>>(&mut self, value: S) -> &mut Self {
         let argb = value.into();
         let indexed = COLOR_STR_TO_INDEX.get(Self::argb8_to_hex(argb).as_ref());
@@ -500,7 +529,7 @@ impl Color {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         for attr in e.attributes().with_checks(false).flatten() {
             match attr.key.0 {
                 b"indexed" => {
@@ -534,21 +563,18 @@ impl Color {
         }
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         let mut buf = Vec::new();
         loop {
             match reader.read_event_into(&mut buf) {
                 Ok(Event::End(ref e)) => match e.name().into_inner() {
-                    b"color" | b"fgColor" | b"bgColor" | b"tabColor" => return,
+                    b"color" | b"fgColor" | b"bgColor" | b"tabColor" => return Ok(()),
                     _ => (),
                 },
-                Ok(Event::Eof) => panic!(
-                    "Error: Could not find {} end element",
-                    "color,fgColor,bgColor,tabColor"
-                ),
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(XlsxError::missing_end_element("color,fgColor,bgColor,tabColor", reader.buffer_position())),
+                Err(e) => return Err(XlsxError::parse(reader.buffer_position(), e.to_string())),
                 _ => (),
             }
             buf.clear();
@@ -0,0 +1,115 @@
+// colorFilter
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::BytesStart,
+};
+
+use super::{
+    BooleanValue,
+    DifferentialFormats,
+    Style,
+};
+use crate::{
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+    },
+    writer::driver::write_start_tag,
+};
+
+#[derive(Clone, Default, Debug)]
+pub struct ColorFilter {
+    style:      Option<Box<Style>>,
+    cell_color: BooleanValue,
+}
+
+impl ColorFilter {
+    /// Get the format holding the filtered color.
+    #[inline]
+    #[must_use]
+    pub fn style(&self) -> Option<&Style> {
+        self.style.as_deref()
+    }
+
+    #[inline]
+    pub fn set_style(&mut self, value: Style) -> &mut Self {
+        self.style = Some(Box::new(value));
+        self
+    }
+
+    /// Whether the fill color is filtered. `false` filters the font color.
+    #[inline]
+    #[must_use]
+    pub fn cell_color(&self) -> bool {
+        !self.cell_color.has_value() || self.cell_color.value()
+    }
+
+    #[inline]
+    pub fn set_cell_color(&mut self, value: bool) -> &mut Self {
+        self.cell_color.set_value(value);
+        self
+    }
+
+    pub(crate) fn is_match(&self, style: Option<&Style>) -> bool {
+        let Some(criteria) = self.style() else {
+            return false;
+        };
+        if self.cell_color() {
+            let Some(pattern_fill) = criteria.fill().and_then(|x| x.pattern_fill()) else {
+                return false;
+            };
+            let expected = pattern_fill
+                .foreground_color()
+                .or_else(|| pattern_fill.background_color())
+                .map(super::Color::argb_str);
+            let actual = style
+                .and_then(Style::background_color)
+                .map(super::Color::argb_str);
+            expected.is_some() && expected == actual
+        } else {
+            let Some(font) = criteria.font() else {
+                return false;
+            };
+            let actual = style
+                .and_then(Style::font)
+                .map(|x| x.color().argb_str());
+            actual.is_some_and(|x| x == font.color().argb_str())
+        }
+    }
+
+    #[inline]
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+        differential_formats: &DifferentialFormats,
+    ) {
+        if let Some(v) = get_attribute(e, b"dxfId") {
+            if let Ok(dxf_id) = v.parse::<usize>() {
+                self.set_style(differential_formats.style(dxf_id));
+            }
+        }
+        set_string_from_xml!(self, e, cell_color, "cellColor");
+    }
+
+    pub(crate) fn write_to(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        differential_formats: &mut DifferentialFormats,
+    ) {
+        // colorFilter
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        let dxf_id_str: String;
+        if let Some(v) = &self.style {
+            dxf_id_str = differential_formats.set_style(v).to_string();
+            attributes.push(("dxfId", &dxf_id_str).into());
+        }
+        if self.cell_color.has_value() {
+            attributes.push(("cellColor", self.cell_color.value_string()).into());
+        }
+        write_start_tag(writer, "colorFilter", attributes, true);
+    }
+}
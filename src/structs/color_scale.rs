@@ -14,6 +14,7 @@ use super::{
     ConditionalFormatValueObject,
 };
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -82,19 +83,19 @@ impl ColorScale {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                     b"cfvo" => {
                         let mut obj = ConditionalFormatValueObject::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.cfvo_collection.push(obj);
                     }
                     b"color" => {
                         let mut obj = Color::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.color_collection.push(obj);
                     }
                     _ => (),
@@ -104,12 +105,12 @@ impl ColorScale {
                 match e.name().into_inner() {
                     b"cfvo" => {
                         let mut obj = ConditionalFormatValueObject::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.cfvo_collection.push(obj);
                     }
                     b"color" => {
                         let mut obj = Color::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.color_collection.push(obj);
                     }
                     _ => (),
@@ -117,10 +118,10 @@ impl ColorScale {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"colorScale" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "colorScale")
+            Event::Eof => return Err(XlsxError::missing_end_element("colorScale", reader.buffer_position()))
         );
     }
 
@@ -12,6 +12,7 @@ use quick_xml::{
 
 use super::MruColors;
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -57,20 +58,20 @@ impl Colors {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"mruColors" {
-                    self.mru_colors.set_attributes(reader, e);
+                    self.mru_colors.set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"colors" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "colors")
+            Event::Eof => return Err(XlsxError::missing_end_element("colors", reader.buffer_position()))
         );
     }
 
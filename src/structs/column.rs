@@ -11,9 +11,12 @@ use super::{
     UInt32Value,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        try_set_string_from_xml,
+        parse_value,
     },
     structs::Cells,
     traits::AdjustmentValue,
@@ -239,18 +242,19 @@ impl Column {
 
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
-        _reader: &mut Reader<R>,
+        reader: &mut Reader<R>,
         e: &BytesStart,
         stylesheet: &Stylesheet,
-    ) {
-        set_string_from_xml!(self, e, width, "width");
+    ) -> Result<(), XlsxError> {
+        try_set_string_from_xml!(self, reader, e, width, "width");
         set_string_from_xml!(self, e, hidden, "hidden");
         set_string_from_xml!(self, e, best_fit, "bestFit");
 
         if let Some(v) = get_attribute(e, b"style") {
-            let style = stylesheet.style(v.parse::<usize>().unwrap());
+            let style = stylesheet.style(parse_value(reader, &v)?);
             self.set_style(style);
         }
+        Ok(())
     }
 }
 impl AdjustmentValue for Column {
@@ -11,6 +11,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     structs::Break,
     writer::driver::{
@@ -64,7 +65,7 @@ impl ColumnBreaks {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
@@ -76,10 +77,10 @@ impl ColumnBreaks {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"colBreaks" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "colBreaks")
+            Event::Eof => return Err(XlsxError::missing_end_element("colBreaks", reader.buffer_position()))
         );
     }
 
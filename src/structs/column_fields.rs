@@ -11,6 +11,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     structs::Field,
     writer::driver::{
@@ -59,7 +60,7 @@ impl ColumnFields {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
@@ -71,10 +72,10 @@ impl ColumnFields {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"colFields" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "colFields")
+            Event::Eof => return Err(XlsxError::missing_end_element("colFields", reader.buffer_position()))
         );
     }
 
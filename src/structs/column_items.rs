@@ -11,6 +11,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     structs::RowItem,
     writer::driver::{
@@ -60,29 +61,29 @@ impl ColumnItems {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"i" {
                     let mut obj = RowItem::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.add_list_mut(obj);
                 }
             },
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"i" {
                     let mut obj = RowItem::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.add_list_mut(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"colItems" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "colItems")
+            Event::Eof => return Err(XlsxError::missing_end_element("colItems", reader.buffer_position()))
         );
     }
 
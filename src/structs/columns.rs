@@ -11,9 +11,11 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::{
-        get_attribute,
+        get_required_attribute,
         xml_read_loop,
+        parse_value,
     },
     structs::{
         Cells,
@@ -116,15 +118,15 @@ impl Columns {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         stylesheet: &Stylesheet,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"col" {
                     let mut obj = Column::default();
-                    obj.set_attributes(reader, e, stylesheet);
-                    let min = get_attribute(e, b"min").unwrap().parse::<u32>().unwrap();
-                    let max = get_attribute(e, b"max").unwrap().parse::<u32>().unwrap();
+                    obj.set_attributes(reader, e, stylesheet)?;
+                    let min = parse_value(reader, &get_required_attribute(reader, e, b"min")?)?;
+                    let max = parse_value(reader, &get_required_attribute(reader, e, b"max")?)?;
                     for i in min..=max {
                         obj.set_col_num(i);
                         self.set_column(obj.clone());
@@ -133,10 +135,10 @@ impl Columns {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"cols" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "cols")
+            Event::Eof => return Err(XlsxError::missing_end_element("cols", reader.buffer_position()))
         );
     }
 
@@ -28,8 +28,11 @@ use super::{
     },
 };
 use crate::{
+    XlsxError,
     helper::coordinate::CellCoordinates, reader::driver::{
         get_attribute,
+        get_required_attribute,
+        parse_value,
         set_string_from_xml,
     }, structs::vml::Shape, traits::AdjustmentCoordinate, writer::driver::{
         write_end_tag,
@@ -280,15 +283,17 @@ impl Comment {
         reader: &mut Reader<R>,
         e: &BytesStart,
         authors: &[String],
-    ) {
-        let coordinate = get_attribute(e, b"ref").unwrap();
+    ) -> Result<(), XlsxError> {
+        let coordinate = get_required_attribute(reader, e, b"ref")?;
         self.coordinate_mut().set_coordinate(coordinate);
 
-        let author_id = get_attribute(e, b"authorId")
-            .unwrap()
-            .parse::<usize>()
-            .unwrap();
-        let author = authors.get(author_id).unwrap();
+        let author_id: usize = parse_value(reader, &get_required_attribute(reader, e, b"authorId")?)?;
+        let author = authors.get(author_id).ok_or_else(|| {
+            XlsxError::parse(
+                reader.buffer_position(),
+                format!("author {author_id} not found"),
+            )
+        })?;
         self.set_author(author);
 
         set_string_from_xml!(self, e, id, "id");
@@ -297,15 +302,15 @@ impl Comment {
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"text" {
-                    self.text_mut().set_attributes(reader, e);
+                    self.text_mut().set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"comment" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "comment")
+            Event::Eof => return Err(XlsxError::missing_end_element("comment", reader.buffer_position()))
         );
     }
 
@@ -4,7 +4,10 @@ use super::RichText;
 use super::Text;
 use super::TextElement;
 use crate::writer::driver::{write_end_tag, write_start_tag};
-use crate::xml_read_loop;
+use crate::{
+    XlsxError,
+    xml_read_loop,
+};
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 use quick_xml::Writer;
@@ -107,7 +110,7 @@ impl CommentText {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut vec_text_element: Vec<TextElement> = Vec::new();
 
         xml_read_loop!(
@@ -116,16 +119,16 @@ impl CommentText {
                 match e.name().into_inner() {
                     b"t" => {
                         let mut obj = Text::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         let _ = self.set_text(obj);
                     }
                     b"r" => {
                         let mut obj = TextElement::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         vec_text_element.push(obj);
                     }
                     b"rPh" => {
-                        PhoneticRun::set_attributes(reader, e);
+                        PhoneticRun::set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
@@ -137,10 +140,10 @@ impl CommentText {
                         obj.set_rich_text_elements(vec_text_element);
                         self.set_rich_text(obj);
                     }
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "text")
+            Event::Eof => return Err(XlsxError::missing_end_element("text", reader.buffer_position()))
         );
     }
 
@@ -15,6 +15,7 @@ use super::{
     StringValue,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -66,22 +67,22 @@ impl ConditionalFormatValueObject {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, r#type, "type");
         set_string_from_xml!(self, e, val, "val");
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::End(ref e) => {
                 if e.name().into_inner() == b"cfvo" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "cfvo")
+            Event::Eof => return Err(XlsxError::missing_end_element("cfvo", reader.buffer_position()))
         );
     }
 
@@ -26,6 +26,7 @@ use super::{
     UInt32Value,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -411,7 +412,7 @@ impl ConditionalFormattingRule {
         e: &BytesStart,
         differential_formats: &DifferentialFormats,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, r#type, "type");
         set_string_from_xml!(self, e, operator, "operator");
 
@@ -433,7 +434,7 @@ impl ConditionalFormattingRule {
         set_string_from_xml!(self, e, equal_average, "equalAverage");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -442,22 +443,22 @@ impl ConditionalFormattingRule {
                 match e.name().into_inner() {
                     b"colorScale" => {
                         let mut obj = ColorScale::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.color_scale = Some(obj);
                     }
                     b"dataBar" => {
                         let mut obj = DataBar::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.data_bar = Some(obj);
                     }
                     b"iconSet" => {
                         let mut obj = IconSet::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.icon_set = Some(obj);
                     }
                     b"formula" => {
                         let mut obj = Formula::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.formula = Some(Box::new(obj));
                     }
                     _ => (),
@@ -465,10 +466,10 @@ impl ConditionalFormattingRule {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"cfRule" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => return
+            Event::Eof => return Ok(())
         );
     }
 
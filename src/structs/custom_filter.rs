@@ -0,0 +1,173 @@
+// customFilter
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use super::{
+    EnumValue,
+    FilterOperatorValues,
+    StringValue,
+};
+use crate::{
+    XlsxError,
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+        xml_read_loop,
+    },
+    writer::driver::write_start_tag,
+};
+
+#[derive(Clone, Default, Debug)]
+pub struct CustomFilter {
+    operator: EnumValue<FilterOperatorValues>,
+    val:      StringValue,
+}
+
+impl CustomFilter {
+    #[inline]
+    #[must_use]
+    pub fn operator(&self) -> &FilterOperatorValues {
+        self.operator.value()
+    }
+
+    #[inline]
+    pub fn set_operator(&mut self, value: FilterOperatorValues) -> &mut Self {
+        self.operator.set_value(value);
+        self
+    }
+
+    /// Get the criteria value.
+    /// Text criteria may contain the `*` and `?` wildcards (`~` escapes them).
+    #[inline]
+    #[must_use]
+    pub fn val(&self) -> &str {
+        self.val.value_str()
+    }
+
+    #[inline]
+    pub fn set_val<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.val.set_value(value);
+        self
+    }
+
+    pub(crate) fn is_match(&self, number: Option<f64>, text: &str) -> bool {
+        let val = self.val();
+        let operator = self.operator();
+
+        // Excel stores "non blanks" as `notEqual " "`.
+        if val == " " {
+            return match operator {
+                FilterOperatorValues::Equal => text.is_empty(),
+                FilterOperatorValues::NotEqual => !text.is_empty(),
+                _ => false,
+            };
+        }
+
+        if let (Some(number), Ok(criteria)) = (number, val.parse::<f64>()) {
+            return match operator {
+                FilterOperatorValues::Equal => (number - criteria).abs() < f64::EPSILON,
+                FilterOperatorValues::NotEqual => (number - criteria).abs() >= f64::EPSILON,
+                FilterOperatorValues::GreaterThan => number > criteria,
+                FilterOperatorValues::GreaterThanOrEqual => number >= criteria,
+                FilterOperatorValues::LessThan => number < criteria,
+                FilterOperatorValues::LessThanOrEqual => number <= criteria,
+            };
+        }
+
+        let text = text.to_lowercase();
+        let criteria = val.to_lowercase();
+        match operator {
+            FilterOperatorValues::Equal => wildcard_match(&criteria, &text),
+            FilterOperatorValues::NotEqual => !wildcard_match(&criteria, &text),
+            FilterOperatorValues::GreaterThan => text > criteria,
+            FilterOperatorValues::GreaterThanOrEqual => text >= criteria,
+            FilterOperatorValues::LessThan => text < criteria,
+            FilterOperatorValues::LessThanOrEqual => text <= criteria,
+        }
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flg: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, operator, "operator");
+        set_string_from_xml!(self, e, val, "val");
+
+        if empty_flg {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"customFilter" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(XlsxError::missing_end_element("customFilter", reader.buffer_position()))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // customFilter
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        if self.operator.has_value() {
+            attributes.push(("operator", self.operator.value_string()).into());
+        }
+        if self.val.has_value() {
+            attributes.push(("val", self.val.value_str()).into());
+        }
+        write_start_tag(writer, "customFilter", attributes, true);
+    }
+}
+
+/// Match `text` against a pattern using the Excel `*`, `?` and `~` wildcards.
+pub(crate) fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    // tokens: Some(c) literal, None for `?`, and `*` handled separately
+    let mut tokens: Vec<(bool, Option<char>)> = Vec::new();
+    let mut i = 0;
+    while i < pattern.len() {
+        match pattern[i] {
+            '~' if i + 1 < pattern.len() => {
+                tokens.push((false, Some(pattern[i + 1])));
+                i += 1;
+            }
+            '*' => tokens.push((true, None)),
+            '?' => tokens.push((false, None)),
+            c => tokens.push((false, Some(c))),
+        }
+        i += 1;
+    }
+
+    let mut matched = vec![false; text.len() + 1];
+    matched[0] = true;
+    for (is_star, c) in tokens {
+        let mut next = vec![false; text.len() + 1];
+        if is_star {
+            let mut any = false;
+            for j in 0..=text.len() {
+                any |= matched[j];
+                next[j] = any;
+            }
+        } else {
+            for j in 1..=text.len() {
+                next[j] = matched[j - 1] && c.is_none_or(|c| c == text[j - 1]);
+            }
+        }
+        matched = next;
+    }
+    matched[text.len()]
+}
@@ -0,0 +1,129 @@
+// customFilters
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use super::{
+    BooleanValue,
+    CustomFilter,
+};
+use crate::{
+    XlsxError,
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+        xml_read_loop,
+    },
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+    },
+};
+
+#[derive(Clone, Default, Debug)]
+pub struct CustomFilters {
+    and:                BooleanValue,
+    custom_filter_list: Vec<CustomFilter>,
+}
+
+impl CustomFilters {
+    /// Whether both conditions must be met. The default is either of them.
+    #[inline]
+    #[must_use]
+    pub fn and(&self) -> bool {
+        self.and.value()
+    }
+
+    #[inline]
+    pub fn set_and(&mut self, value: bool) -> &mut Self {
+        self.and.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn custom_filter_list(&self) -> &[CustomFilter] {
+        &self.custom_filter_list
+    }
+
+    #[inline]
+    pub fn custom_filter_list_mut(&mut self) -> &mut Vec<CustomFilter> {
+        &mut self.custom_filter_list
+    }
+
+    /// Add a condition. Excel supports up to two.
+    #[inline]
+    pub fn add_custom_filter(&mut self, value: CustomFilter) -> &mut Self {
+        self.custom_filter_list.push(value);
+        self
+    }
+
+    pub(crate) fn is_match(&self, number: Option<f64>, text: &str) -> bool {
+        let mut results = self
+            .custom_filter_list
+            .iter()
+            .map(|x| x.is_match(number, text));
+        if self.and() {
+            results.all(|x| x)
+        } else {
+            results.any(|x| x)
+        }
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flg: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, and, "and");
+
+        if empty_flg {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"customFilter" {
+                    let mut obj = CustomFilter::default();
+                    obj.set_attributes(reader, e, true)?;
+                    self.custom_filter_list.push(obj);
+                }
+            },
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"customFilter" {
+                    let mut obj = CustomFilter::default();
+                    obj.set_attributes(reader, e, false)?;
+                    self.custom_filter_list.push(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"customFilters" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(XlsxError::missing_end_element("customFilters", reader.buffer_position()))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // customFilters
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        if self.and.has_value() {
+            attributes.push(("and", self.and.value_string()).into());
+        }
+        write_start_tag(writer, "customFilters", attributes, false);
+        for custom_filter in &self.custom_filter_list {
+            custom_filter.write_to(writer);
+        }
+        write_end_tag(writer, "customFilters");
+    }
+}
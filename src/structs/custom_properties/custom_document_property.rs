@@ -13,10 +13,12 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
         xml_read_loop,
+        parse_value,
     },
     structs::{
         StringValue,
@@ -156,12 +158,12 @@ impl CustomDocumentProperty {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, name, "name");
         set_string_from_xml!(self, e, link_target, "linkTarget");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         let mut value: String = String::new();
@@ -174,13 +176,13 @@ impl CustomDocumentProperty {
                 match e.name().into_inner(){
                     b"vt:lpwstr" =>{self.set_value_string(&value);}
                     b"vt:filetime" =>{self.set_value_date_manual(&value);}
-                    b"vt:i4"=> {self.set_value_number(value.parse::<i32>().unwrap());}
+                    b"vt:i4"=> {self.set_value_number(parse_value::<i32, _>(reader, &value)?);}
                     b"vt:bool"=> {self.set_value_bool(matches!(value.as_str(), "true" | "1"));}
-                    b"property"=> {return}
+                    b"property"=> {return Ok(())}
                     _=>{}
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "property")
+            Event::Eof => return Err(XlsxError::missing_end_element("property", reader.buffer_position()))
         );
     }
 
@@ -10,6 +10,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     helper::const_str::{
         CUSTOM_PROPS_NS,
         VTYPES_NS,
@@ -80,29 +81,29 @@ impl Properties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"property" {
                     let mut obj = CustomDocumentProperty::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.add_custom_document_property_list(obj);
                 }
             },
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"property" {
                     let mut obj = CustomDocumentProperty::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.add_custom_document_property_list(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"Properties" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "Properties")
+            Event::Eof => return Err(XlsxError::missing_end_element("Properties", reader.buffer_position()))
         );
     }
 
@@ -11,6 +11,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     structs::DataField,
     writer::driver::{
@@ -60,7 +61,7 @@ impl DataFields {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
@@ -72,10 +73,10 @@ impl DataFields {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"dataFields" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "dataFields")
+            Event::Eof => return Err(XlsxError::missing_end_element("dataFields", reader.buffer_position()))
         );
     }
 
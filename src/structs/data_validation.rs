@@ -22,11 +22,12 @@ use super::{
     StringValue,
 };
 use crate::{
+    XlsxError,
     reader::driver::get_attribute,
     writer::driver::{
         write_end_tag,
         write_start_tag,
-        write_text_node,
+        write_text_node_conversion,
     },
 };
 
@@ -283,7 +284,7 @@ impl DataValidation {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(v) = get_attribute(e, b"type") {
             self.r#type.set_value_string(v);
         }
@@ -325,15 +326,19 @@ impl DataValidation {
         }
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         let mut value: String = String::new();
         let mut buf = Vec::new();
         loop {
             match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(_)) => value.clear(),
                 Ok(Event::Text(e)) => {
-                    value = crate::helper::utils::unescape_xml_text(&e);
+                    value.push_str(&crate::helper::utils::unescape_xml_text(&e));
+                }
+                Ok(Event::GeneralRef(e)) => {
+                    value.push_str(&crate::helper::utils::unescape_xml_general_ref(&e));
                 }
                 Ok(Event::End(ref e)) => match e.name().into_inner() {
                     b"formula1" => {
@@ -342,13 +347,13 @@ impl DataValidation {
                     b"formula2" => {
                         self.formula2.set_value_string(std::mem::take(&mut value));
                     }
-                    b"dataValidation" => return,
+                    b"dataValidation" => return Ok(()),
                     _ => {}
                 },
                 Ok(Event::Eof) => {
-                    panic!("Error: Could not find {} end element", "dataValidation")
+                    return Err(XlsxError::missing_end_element("dataValidation", reader.buffer_position()))
                 }
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Err(e) => return Err(XlsxError::parse(reader.buffer_position(), e.to_string())),
                 _ => {}
             }
             buf.clear();
@@ -418,12 +423,12 @@ impl DataValidation {
         if is_inner {
             if self.formula1.has_value() {
                 write_start_tag(writer, "formula1", vec![], false);
-                write_text_node(writer, self.formula1.value_str());
+                write_text_node_conversion(writer, self.formula1.value_str());
                 write_end_tag(writer, "formula1");
             }
             if self.formula2.has_value() {
                 write_start_tag(writer, "formula2", vec![], false);
-                write_text_node(writer, self.formula2.value_str());
+                write_text_node_conversion(writer, self.formula2.value_str());
                 write_end_tag(writer, "formula2");
             }
             write_end_tag(writer, "dataValidation");
@@ -12,6 +12,7 @@ use quick_xml::{
 
 use super::DataValidation;
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -65,29 +66,29 @@ impl DataValidations {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"dataValidation" {
                     let mut obj = DataValidation::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.add_data_validation_list(obj);
                 }
             },
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"dataValidation" {
                     let mut obj = DataValidation::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.add_data_validation_list(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"dataValidations" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "dataValidations")
+            Event::Eof => return Err(XlsxError::missing_end_element("dataValidations", reader.buffer_position()))
         );
     }
 
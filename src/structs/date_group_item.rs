@@ -0,0 +1,195 @@
+// dateGroupItem
+use std::io::Cursor;
+
+use chrono::{
+    Datelike,
+    NaiveDateTime,
+    Timelike,
+};
+use quick_xml::{
+    Reader,
+    Writer,
+    events::BytesStart,
+};
+
+use super::{
+    DateTimeGroupingValues,
+    EnumValue,
+    UInt16Value,
+};
+use crate::{
+    XlsxError,
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+        try_set_string_from_xml,
+    },
+    writer::driver::write_start_tag,
+};
+
+#[derive(Clone, Default, Debug)]
+pub struct DateGroupItem {
+    year:               UInt16Value,
+    month:              UInt16Value,
+    day:                UInt16Value,
+    hour:               UInt16Value,
+    minute:             UInt16Value,
+    second:             UInt16Value,
+    date_time_grouping: EnumValue<DateTimeGroupingValues>,
+}
+
+impl DateGroupItem {
+    #[inline]
+    #[must_use]
+    pub fn year(&self) -> u16 {
+        self.year.value()
+    }
+
+    #[inline]
+    pub fn set_year(&mut self, value: u16) -> &mut Self {
+        self.year.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn month(&self) -> u16 {
+        self.month.value()
+    }
+
+    #[inline]
+    pub fn set_month(&mut self, value: u16) -> &mut Self {
+        self.month.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn day(&self) -> u16 {
+        self.day.value()
+    }
+
+    #[inline]
+    pub fn set_day(&mut self, value: u16) -> &mut Self {
+        self.day.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn hour(&self) -> u16 {
+        self.hour.value()
+    }
+
+    #[inline]
+    pub fn set_hour(&mut self, value: u16) -> &mut Self {
+        self.hour.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn minute(&self) -> u16 {
+        self.minute.value()
+    }
+
+    #[inline]
+    pub fn set_minute(&mut self, value: u16) -> &mut Self {
+        self.minute.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn second(&self) -> u16 {
+        self.second.value()
+    }
+
+    #[inline]
+    pub fn set_second(&mut self, value: u16) -> &mut Self {
+        self.second.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn date_time_grouping(&self) -> &DateTimeGroupingValues {
+        self.date_time_grouping.value()
+    }
+
+    #[inline]
+    pub fn set_date_time_grouping(&mut self, value: DateTimeGroupingValues) -> &mut Self {
+        self.date_time_grouping.set_value(value);
+        self
+    }
+
+    /// Whether the date falls into this group.
+    pub(crate) fn is_match(&self, value: &NaiveDateTime) -> bool {
+        let parts = [
+            (
+                DateTimeGroupingValues::Year,
+                u32::try_from(value.year()).unwrap_or_default(),
+                self.year(),
+            ),
+            (DateTimeGroupingValues::Month, value.month(), self.month()),
+            (DateTimeGroupingValues::Day, value.day(), self.day()),
+            (DateTimeGroupingValues::Hour, value.hour(), self.hour()),
+            (DateTimeGroupingValues::Minute, value.minute(), self.minute()),
+            (DateTimeGroupingValues::Second, value.second(), self.second()),
+        ];
+        for (grouping, actual, expected) in parts {
+            if actual != u32::from(expected) {
+                return false;
+            }
+            if &grouping == self.date_time_grouping() {
+                break;
+            }
+        }
+        true
+    }
+
+    #[inline]
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        try_set_string_from_xml!(self, reader, e, year, "year");
+        try_set_string_from_xml!(self, reader, e, month, "month");
+        try_set_string_from_xml!(self, reader, e, day, "day");
+        try_set_string_from_xml!(self, reader, e, hour, "hour");
+        try_set_string_from_xml!(self, reader, e, minute, "minute");
+        try_set_string_from_xml!(self, reader, e, second, "second");
+        set_string_from_xml!(self, e, date_time_grouping, "dateTimeGrouping");
+        Ok(())
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // dateGroupItem
+        let year = self.year.value_string();
+        let month = self.month.value_string();
+        let day = self.day.value_string();
+        let hour = self.hour.value_string();
+        let minute = self.minute.value_string();
+        let second = self.second.value_string();
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        for (key, value, has_value) in [
+            ("year", &year, self.year.has_value()),
+            ("month", &month, self.month.has_value()),
+            ("day", &day, self.day.has_value()),
+            ("hour", &hour, self.hour.has_value()),
+            ("minute", &minute, self.minute.has_value()),
+            ("second", &second, self.second.has_value()),
+        ] {
+            if has_value {
+                attributes.push((key, value).into());
+            }
+        }
+        attributes.push((
+            "dateTimeGrouping",
+            self.date_time_grouping.value_string(),
+        )
+            .into());
+        write_start_tag(writer, "dateGroupItem", attributes, true);
+    }
+}
@@ -16,7 +16,13 @@ use super::{
     UInt32Value,
 };
 use crate::{
-    helper::address::is_address,
+    XlsxError,
+    helper::{
+        address::is_address,
+        coordinate::column_index_from_string,
+        formula::replace_range_operands,
+        utils::compile_regex,
+    },
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -30,6 +36,20 @@ use crate::{
     },
 };
 
+/// Built-in names, written with the `_xlnm.` prefix.
+const BUILT_IN_NAMES: &[&str] = &[
+    "Print_Area",
+    "Print_Titles",
+    "_FilterDatabase",
+    "Criteria",
+    "Extract",
+    "Consolidate_Area",
+    "Database",
+    "Sheet_Title",
+    "Auto_Open",
+    "Auto_Close",
+];
+
 #[derive(Clone, Default, Debug)]
 pub struct DefinedName {
     name:           StringValue,
@@ -77,20 +97,21 @@ impl DefinedName {
     }
 
     pub fn set_address<S: Into<String>>(&mut self, value: S) -> &mut Self {
-        let list = Self::split_str(value);
-        for v in &list {
-            if is_address(v) {
+        let value = value.into();
+        let list = Self::split_str(value.as_str());
+        if list.iter().all(is_address) {
+            for v in &list {
                 self.add_address(v);
-            } else {
-                self.set_string_value(v);
             }
+        } else {
+            self.set_string_value(value);
         }
         self
     }
 
     pub fn add_address<S: Into<String>>(&mut self, value: S) -> &mut Self {
         let mut obj = Address::default();
-        obj.set_address(value.into().replace("''", "'"));
+        obj.set_address(value);
         self.address.push(obj);
         self
     }
@@ -106,6 +127,15 @@ impl DefinedName {
         self.address_obj()
     }
 
+    /// (This method is crate only.)
+    /// Formula of a name that does not refer to plain ranges.
+    #[inline]
+    pub(crate) fn string_value(&self) -> Option<String> {
+        self.string_value
+            .has_value()
+            .then(|| self.string_value.value_str().to_string())
+    }
+
     #[inline]
     pub(crate) fn set_string_value<S: Into<String>>(&mut self, value: S) -> &mut Self {
         self.address.clear();
@@ -155,6 +185,58 @@ impl DefinedName {
         self.hidden.set_value(value);
     }
 
+    /// Check that a name can be used as a defined name.
+    /// Names start with a letter, an underscore or a backslash, contain
+    /// letters, digits, periods, underscores and backslashes, and must not
+    /// look like an A1 or R1C1 reference.
+    /// Built-in names such as `_xlnm.Print_Area` are accepted.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::DefinedName;
+    ///
+    /// assert!(DefinedName::is_valid_name("Tax_Rate"));
+    /// assert!(DefinedName::is_valid_name("_xlnm.Print_Area"));
+    /// assert!(!DefinedName::is_valid_name("Tax Rate"));
+    /// assert!(!DefinedName::is_valid_name("AB12"));
+    /// assert!(!DefinedName::is_valid_name("R1C1"));
+    /// assert!(!DefinedName::is_valid_name("c"));
+    /// ```
+    #[must_use]
+    pub fn is_valid_name(name: &str) -> bool {
+        if BUILT_IN_NAMES
+            .iter()
+            .any(|x| name.eq_ignore_ascii_case(&format!("_xlnm.{x}")))
+        {
+            return true;
+        }
+        let mut chars = name.chars();
+        let Some(first) = chars.next() else {
+            return false;
+        };
+        if name.chars().count() > 255
+            || !(first.is_alphabetic() || first == '_' || first == '\\')
+            || !chars.all(|c| c.is_alphanumeric() || matches!(c, '_' | '\\' | '.' | '?'))
+        {
+            return false;
+        }
+        let upper = name.to_ascii_uppercase();
+        // A1 style reference
+        let letters = upper.chars().take_while(char::is_ascii_alphabetic).count();
+        let digits = &upper[letters..];
+        if (1..=3).contains(&letters)
+            && !digits.is_empty()
+            && digits.chars().all(|c| c.is_ascii_digit())
+            && column_index_from_string(&upper[..letters]) <= 16_384
+            && digits.parse::<u32>().is_ok_and(|x| (1..=1_048_576).contains(&x))
+        {
+            return false;
+        }
+        // R1C1 style reference
+        !compile_regex!(r"^(R[0-9]*)?(C[0-9]*)?$")
+            .is_match(&upper)
+            .unwrap_or(false)
+    }
+
     fn split_str<S: Into<String>>(value: S) -> Vec<String> {
         let value = value.into();
         let char_list: Vec<char> = value.chars().collect::<Vec<char>>();
@@ -201,6 +283,24 @@ impl DefinedName {
         result
     }
 
+    /// (This method is crate only.)
+    /// Rewrite the references of the name with `replacer`, which returns the
+    /// new text of a range operand or `None` to keep it.
+    pub(crate) fn replace_operands<F>(&mut self, mut replacer: F) -> &mut Self
+    where
+        F: FnMut(&str) -> Option<String>,
+    {
+        if let Some(formula) = self.string_value() {
+            let formula = replace_range_operands(&formula, replacer);
+            self.string_value.set_value(formula);
+            return self;
+        }
+        for address in &mut self.address {
+            address.replace_operand(&mut replacer);
+        }
+        self
+    }
+
     pub(crate) fn set_sheet_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
         let value = value.into();
         for address in &mut self.address {
@@ -213,7 +313,7 @@ impl DefinedName {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, name, "name");
         set_string_from_xml!(self, e, local_sheet_id, "localSheetId");
         set_string_from_xml!(self, e, hidden, "hidden");
@@ -227,10 +327,10 @@ impl DefinedName {
                 Event::End(ref e) => {
                     if e.name().into_inner() == b"definedName" {
                         self.set_address(value);
-                        return
+                        return Ok(())
                     }
                 },
-                Event::Eof => panic!("Error: Could not find {} end element", "definedName")
+                Event::Eof => return Err(XlsxError::missing_end_element("definedName", reader.buffer_position()))
         );
     }
 
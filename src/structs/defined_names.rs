@@ -0,0 +1,343 @@
+use crate::{
+    XlsxError,
+    helper::{
+        address::join_address,
+        coordinate::{
+            index_from_coordinate,
+            string_from_column_index,
+        },
+        formula::replace_range_operands,
+    },
+    structs::{
+        DefinedName,
+        Workbook,
+    },
+};
+
+const PRINT_AREA: &str = "_xlnm.Print_Area";
+const PRINT_TITLES: &str = "_xlnm.Print_Titles";
+
+/// Manages the defined names of a workbook.
+/// Returned by `Workbook::defined_names_manager`.
+///
+/// A scope of `None` is the workbook, and `Some(sheet_name)` is a worksheet.
+/// Names are compared case-insensitively, as in Excel.
+/// # Examples
+/// ```
+/// let mut book = umya_spreadsheet::new_file();
+/// let mut names = book.defined_names_manager();
+/// names.add("Rate", "Sheet1!$B$1", None).unwrap();
+/// names.set_print_area("Sheet1", "A1:D20").unwrap();
+/// assert_eq!(names.get("rate", None).unwrap().address(), "Sheet1!$B$1");
+/// assert_eq!(names.print_area("Sheet1").unwrap(), "Sheet1!$A$1:$D$20");
+/// ```
+#[derive(Debug)]
+pub struct DefinedNames<'a> {
+    workbook: &'a mut Workbook,
+}
+
+impl<'a> DefinedNames<'a> {
+    #[inline]
+    pub(crate) fn new(workbook: &'a mut Workbook) -> Self {
+        Self { workbook }
+    }
+
+    /// Get a defined name.
+    /// # Arguments
+    /// * `name` - Name. ex) "Rate"
+    /// * `scope` - Sheet name of a sheet scoped name, `None` for the workbook.
+    #[must_use]
+    pub fn get(&self, name: &str, scope: Option<&str>) -> Option<&DefinedName> {
+        let (owner, index) = self.position(name, scope).ok()??;
+        match owner {
+            Some(sheet_index) => {
+                self.workbook.sheet_collection_no_check()[sheet_index]
+                    .defined_names()
+                    .get(index)
+            }
+            None => self.workbook.defined_names().get(index),
+        }
+    }
+
+    /// Add a defined name.
+    /// # Arguments
+    /// * `name` - Name. ex) "Rate"
+    /// * `address` - Reference or formula. ex) "Sheet1!$B$1"
+    /// * `scope` - Sheet name of a sheet scoped name, `None` for the workbook.
+    /// # Errors
+    /// * `InvalidArgument` - the name is not a valid name.
+    /// * `NameDuplicate` - the name is already defined in the scope.
+    /// * `NotFound` - the scope sheet does not exist.
+    pub fn add(
+        &mut self,
+        name: &str,
+        address: &str,
+        scope: Option<&str>,
+    ) -> Result<&mut DefinedName, XlsxError> {
+        if !DefinedName::is_valid_name(name) {
+            return Err(XlsxError::InvalidArgument(format!(
+                "\"{name}\" is not a valid defined name"
+            )));
+        }
+        if self.position(name, scope)?.is_some() {
+            return Err(XlsxError::NameDuplicate());
+        }
+        let mut obj = DefinedName::default();
+        obj.set_name(name);
+        obj.set_address(address);
+        self.push(obj, scope)
+    }
+
+    /// Rename a defined name and the formulas that use it.
+    /// Formulas of the worksheets and formula valued names are updated.
+    /// # Arguments
+    /// * `name` - Current name. ex) "Rate"
+    /// * `new_name` - New name. ex) "`TaxRate`"
+    /// * `scope` - Sheet name of a sheet scoped name, `None` for the workbook.
+    /// # Errors
+    /// * `NotFound` - the name or the scope sheet does not exist.
+    /// * `InvalidArgument` - the new name is not a valid name.
+    /// * `NameDuplicate` - the new name is already defined in the scope.
+    pub fn rename(&mut self, name: &str, new_name: &str, scope: Option<&str>) -> Result<(), XlsxError> {
+        let (owner, index) = self.position(name, scope)?.ok_or(XlsxError::NotFound())?;
+        if !DefinedName::is_valid_name(new_name) || is_built_in(new_name) {
+            return Err(XlsxError::InvalidArgument(format!(
+                "\"{new_name}\" is not a valid defined name"
+            )));
+        }
+        if self
+            .position(new_name, scope)?
+            .is_some_and(|x| x != (owner, index))
+        {
+            return Err(XlsxError::NameDuplicate());
+        }
+        self.workbook.read_sheet_collection()?;
+        let old_name = self.defined_name_mut(owner, index).name().to_string();
+        self.defined_name_mut(owner, index).set_name(new_name);
+
+        let scope_index = self.scope_index(scope)?;
+        let shadowed: Vec<bool> = self
+            .workbook
+            .sheet_collection_no_check()
+            .iter()
+            .enumerate()
+            .map(|(sheet_index, sheet)| {
+                scope_index.is_none()
+                    && sheet.defined_names().iter().any(|x| {
+                        x.has_local_sheet_id()
+                            && x.local_sheet_id() as usize == sheet_index
+                            && x.name().eq_ignore_ascii_case(&old_name)
+                    })
+            })
+            .collect();
+        let scope_name = scope.unwrap_or_default();
+        let rename = |operand: &str, is_in_scope: bool| -> Option<String> {
+            match operand.rsplit_once('!') {
+                Some((sheet_name, local))
+                    if local.eq_ignore_ascii_case(&old_name)
+                        && sheet_name.trim_matches('\'').replace("''", "'") == scope_name
+                        && scope.is_some() =>
+                {
+                    Some(format!("{sheet_name}!{new_name}"))
+                }
+                None if is_in_scope && operand.eq_ignore_ascii_case(&old_name) => {
+                    Some(new_name.to_string())
+                }
+                _ => None,
+            }
+        };
+
+        let rename_defined_names = |defined_names: &mut Vec<DefinedName>, is_in_scope: bool| {
+            for defined_name in defined_names {
+                if let Some(formula) = defined_name.string_value() {
+                    let formula = replace_range_operands(&formula, |x| rename(x, is_in_scope));
+                    defined_name.set_string_value(formula);
+                }
+            }
+        };
+        rename_defined_names(self.workbook.defined_names_mut(), scope_index.is_none());
+        for (sheet_index, sheet) in self
+            .workbook
+            .sheet_collection_mut()?
+            .iter_mut()
+            .enumerate()
+        {
+            let is_in_scope = scope_index.map_or(!shadowed[sheet_index], |x| x == sheet_index);
+            rename_defined_names(sheet.defined_names_mut(), is_in_scope);
+            sheet.replace_formulas(|formula| {
+                replace_range_operands(formula, |x| rename(x, is_in_scope))
+            });
+        }
+        Ok(())
+    }
+
+    /// Remove a defined name.
+    /// # Arguments
+    /// * `name` - Name. ex) "Rate"
+    /// * `scope` - Sheet name of a sheet scoped name, `None` for the workbook.
+    /// # Return value
+    /// * `Option<DefinedName>` - The removed name.
+    pub fn remove(&mut self, name: &str, scope: Option<&str>) -> Option<DefinedName> {
+        let (owner, index) = self.position(name, scope).ok()??;
+        Some(match owner {
+            Some(sheet_index) => self.workbook.sheet_collection_no_check_mut()[sheet_index]
+                .defined_names_mut()
+                .remove(index),
+            None => self.workbook.defined_names_mut().remove(index),
+        })
+    }
+
+    /// Get the print area of a worksheet.
+    /// # Arguments
+    /// * `sheet_name` - Sheet name. ex) "Sheet1"
+    /// # Return value
+    /// * `Option<String>` - ex) "Sheet1!$A$1:$D$20"
+    #[must_use]
+    pub fn print_area(&self, sheet_name: &str) -> Option<String> {
+        self.get(PRINT_AREA, Some(sheet_name))
+            .map(DefinedName::address)
+    }
+
+    /// Set the print area of a worksheet.
+    /// # Arguments
+    /// * `sheet_name` - Sheet name. ex) "Sheet1"
+    /// * `area` - Ranges separated by commas. ex) "A1:D20", "A1:B5,D1:E5"
+    /// # Errors
+    /// * `NotFound` - the sheet does not exist.
+    /// * `InvalidArgument` - the area is not a valid range.
+    pub fn set_print_area(&mut self, sheet_name: &str, area: &str) -> Result<(), XlsxError> {
+        let value = area
+            .split(',')
+            .map(|x| absolute_range(x.trim()).map(|x| join_address(sheet_name, &x)))
+            .collect::<Option<Vec<String>>>()
+            .ok_or_else(|| XlsxError::InvalidArgument(format!("\"{area}\" is not a valid range")))?;
+        self.set_built_in(PRINT_AREA, sheet_name, &value.join(","))
+    }
+
+    /// Get the rows and the columns repeated on every printed page.
+    /// # Arguments
+    /// * `sheet_name` - Sheet name. ex) "Sheet1"
+    /// # Return value
+    /// * `Option<String>` - ex) "Sheet1!$1:$2,Sheet1!$A:$A"
+    #[must_use]
+    pub fn print_titles(&self, sheet_name: &str) -> Option<String> {
+        self.get(PRINT_TITLES, Some(sheet_name))
+            .map(DefinedName::address)
+    }
+
+    /// Set the rows and the columns repeated on every printed page.
+    /// # Arguments
+    /// * `sheet_name` - Sheet name. ex) "Sheet1"
+    /// * `rows` - Rows to repeat. ex) "1:2"
+    /// * `columns` - Columns to repeat. ex) "A:A"
+    /// # Errors
+    /// * `NotFound` - the sheet does not exist.
+    /// * `InvalidArgument` - a range is not a valid range.
+    pub fn set_print_titles(
+        &mut self,
+        sheet_name: &str,
+        rows: Option<&str>,
+        columns: Option<&str>,
+    ) -> Result<(), XlsxError> {
+        let mut value: Vec<String> = Vec::new();
+        for range in [rows, columns].into_iter().flatten() {
+            let absolute = absolute_range(range).ok_or_else(|| {
+                XlsxError::InvalidArgument(format!("\"{range}\" is not a valid range"))
+            })?;
+            value.push(join_address(sheet_name, &absolute));
+        }
+        if value.is_empty() {
+            self.scope_index(Some(sheet_name))?;
+            self.remove(PRINT_TITLES, Some(sheet_name));
+            return Ok(());
+        }
+        self.set_built_in(PRINT_TITLES, sheet_name, &value.join(","))
+    }
+
+    fn set_built_in(&mut self, name: &str, sheet_name: &str, value: &str) -> Result<(), XlsxError> {
+        self.remove(name, Some(sheet_name));
+        let mut obj = DefinedName::default();
+        obj.set_name(name);
+        obj.set_string_value(value);
+        self.push(obj, Some(sheet_name))?;
+        Ok(())
+    }
+
+    fn push(&mut self, mut obj: DefinedName, scope: Option<&str>) -> Result<&mut DefinedName, XlsxError> {
+        let list = match self.scope_index(scope)? {
+            Some(sheet_index) => {
+                obj.set_local_sheet_id(num_traits::cast(sheet_index).unwrap());
+                self.workbook.sheet_collection_no_check_mut()[sheet_index].defined_names_mut()
+            }
+            None => self.workbook.defined_names_mut(),
+        };
+        list.push(obj);
+        Ok(list.last_mut().unwrap())
+    }
+
+    fn scope_index(&self, scope: Option<&str>) -> Result<Option<usize>, XlsxError> {
+        scope
+            .map(|sheet_name| self.workbook.find_sheet_index_by_name(sheet_name))
+            .transpose()
+    }
+
+    /// Find where the name is stored: the sheet index of the list holding it
+    /// (`None` for the workbook list) and the index in the list.
+    fn position(
+        &self,
+        name: &str,
+        scope: Option<&str>,
+    ) -> Result<Option<(Option<usize>, usize)>, XlsxError> {
+        let scope_index = self.scope_index(scope)?;
+        let is_match = |x: &DefinedName| {
+            x.name().eq_ignore_ascii_case(name)
+                && match scope_index {
+                    Some(sheet_index) => {
+                        x.has_local_sheet_id() && x.local_sheet_id() as usize == sheet_index
+                    }
+                    None => !x.has_local_sheet_id(),
+                }
+        };
+        if let Some(index) = self.workbook.defined_names().iter().position(is_match) {
+            return Ok(Some((None, index)));
+        }
+        for (sheet_index, sheet) in self.workbook.sheet_collection_no_check().iter().enumerate() {
+            if let Some(index) = sheet.defined_names().iter().position(is_match) {
+                return Ok(Some((Some(sheet_index), index)));
+            }
+        }
+        Ok(None)
+    }
+
+    fn defined_name_mut(&mut self, owner: Option<usize>, index: usize) -> &mut DefinedName {
+        match owner {
+            Some(sheet_index) => {
+                &mut self.workbook.sheet_collection_no_check_mut()[sheet_index].defined_names_mut()
+                    [index]
+            }
+            None => &mut self.workbook.defined_names_mut()[index],
+        }
+    }
+}
+
+#[inline]
+fn is_built_in(name: &str) -> bool {
+    name.len() > 6 && name.get(..6).is_some_and(|v| v.eq_ignore_ascii_case("_xlnm."))
+}
+
+/// Make every cell, row and column of a range absolute.
+/// ex) "A1:D20" -> "$A$1:$D$20", "1:2" -> "$1:$2", "A:B" -> "$A:$B"
+fn absolute_range(range: &str) -> Option<String> {
+    let mut result: Vec<String> = Vec::new();
+    for coordinate in range.split(':') {
+        let (col, row, ..) = index_from_coordinate(coordinate.to_uppercase());
+        let value = match (col, row) {
+            (Some(col), Some(row)) => format!("${}${row}", string_from_column_index(col)),
+            (Some(col), None) => format!("${}", string_from_column_index(col)),
+            (None, Some(row)) => format!("${row}"),
+            (None, None) => return None,
+        };
+        result.push(value);
+    }
+    (result.len() <= 2).then(|| result.join(":"))
+}
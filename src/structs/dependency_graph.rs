@@ -0,0 +1,588 @@
+use std::{
+    collections::{
+        BTreeMap,
+        BTreeSet,
+        HashMap,
+        VecDeque,
+    },
+    fmt,
+};
+
+use crate::{
+    XlsxError,
+    helper::{
+        address::sheet_name_needs_quoting,
+        coordinate::{
+            CellCoordinates,
+            coordinate_from_index,
+        },
+        formula_ast::{
+            FormulaExpr,
+            FormulaReference,
+            ReferenceArea,
+            parse_formula,
+        },
+        reference::find_defined_name,
+    },
+    structs::{
+        DefinedName,
+        Workbook,
+        Worksheet,
+    },
+};
+
+const MAX_COLUMN: u32 = 16_384;
+const MAX_ROW: u32 = 1_048_576;
+const BUILT_IN_NAME_PREFIX: &str = "_xlnm.";
+
+/// A cell or a defined name of a `DependencyGraph`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DependencyNode {
+    /// A cell of a worksheet.
+    Cell {
+        sheet_name: String,
+        col:        u32,
+        row:        u32,
+    },
+    /// A defined name, with the sheet name of a sheet scoped name.
+    Name {
+        name:  String,
+        scope: Option<String>,
+    },
+}
+
+impl DependencyNode {
+    /// A cell of a worksheet.
+    /// # Arguments
+    /// * `sheet_name` - Sheet name. ex) "Sheet1"
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)`
+    pub fn cell<S, T>(sheet_name: S, coordinate: T) -> Self
+    where
+        S: Into<String>,
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        Self::Cell {
+            sheet_name: sheet_name.into(),
+            col,
+            row,
+        }
+    }
+
+    /// A defined name.
+    /// # Arguments
+    /// * `name` - Name. ex) "Rate"
+    /// * `scope` - Sheet name of a sheet scoped name, `None` for the workbook.
+    pub fn name<S: Into<String>>(name: S, scope: Option<&str>) -> Self {
+        Self::Name {
+            name:  name.into(),
+            scope: scope.map(str::to_string),
+        }
+    }
+
+    /// Sheet names and names compare case-insensitively, as in Excel.
+    fn is_same(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Self::Cell {
+                    sheet_name,
+                    col,
+                    row,
+                },
+                Self::Cell {
+                    sheet_name: other_sheet_name,
+                    col: other_col,
+                    row: other_row,
+                },
+            ) => col == other_col && row == other_row && sheet_name.eq_ignore_ascii_case(other_sheet_name),
+            (
+                Self::Name { name, scope },
+                Self::Name {
+                    name: other_name,
+                    scope: other_scope,
+                },
+            ) => {
+                name.eq_ignore_ascii_case(other_name)
+                    && match (scope, other_scope) {
+                        (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+                        (None, None) => true,
+                        _ => false,
+                    }
+            }
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for DependencyNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let write_sheet_name = |f: &mut fmt::Formatter<'_>, sheet_name: &str| {
+            if sheet_name_needs_quoting(sheet_name) {
+                write!(f, "'{}'!", sheet_name.replace('\'', "''"))
+            } else {
+                write!(f, "{sheet_name}!")
+            }
+        };
+        match self {
+            Self::Cell {
+                sheet_name,
+                col,
+                row,
+            } => {
+                write_sheet_name(f, sheet_name)?;
+                write!(f, "{}", coordinate_from_index(*col, *row))
+            }
+            Self::Name { name, scope } => {
+                if let Some(sheet_name) = scope {
+                    write_sheet_name(f, sheet_name)?;
+                }
+                write!(f, "{name}")
+            }
+        }
+    }
+}
+
+/// The cells and defined names of a workbook linked by the references of
+/// their formulas.
+/// Returned by `Workbook::dependency_graph`.
+///
+/// The precedents of a node are the nodes its formula refers to, and its
+/// dependents are the nodes whose formulas refer to it.
+/// A range only links the cells of it that exist in the worksheet, while a
+/// single cell reference always links its cell.
+/// References into other workbooks, structured references and references
+/// built at calculation time, such as with `INDIRECT` or `OFFSET`, are not
+/// followed. Built-in names such as the print area are left out.
+/// # Examples
+/// ```
+/// use umya_spreadsheet::DependencyNode;
+///
+/// let mut book = umya_spreadsheet::new_file();
+/// let sheet = book.sheet_mut(0).unwrap();
+/// sheet.cell_mut("A1").set_value_number(1);
+/// sheet.cell_mut("A2").set_formula("A1*2");
+/// sheet.cell_mut("A3").set_formula("SUM(A1:A2)");
+///
+/// let graph = book.dependency_graph().unwrap();
+/// let a1 = DependencyNode::cell("Sheet1", "A1");
+/// let a3 = DependencyNode::cell("Sheet1", "A3");
+/// assert_eq!(graph.precedents(&a3).len(), 2);
+/// assert_eq!(graph.all_dependents(&a1).len(), 2);
+/// assert_eq!(graph.topological_order().unwrap().last(), Some(&&a3));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DependencyGraph {
+    nodes:      Vec<DependencyNode>,
+    index:      HashMap<DependencyNode, usize>,
+    precedents: Vec<BTreeSet<usize>>,
+    dependents: Vec<BTreeSet<usize>>,
+}
+
+impl DependencyGraph {
+    /// (This method is crate only.)
+    /// Build the graph of the formulas of the cells and defined names of
+    /// `workbook`.
+    pub(crate) fn new(workbook: &Workbook) -> Result<Self, XlsxError> {
+        let sheets = workbook.sheet_collection_no_check();
+        if sheets.iter().any(|sheet| !sheet.is_deserialized()) {
+            return Err(XlsxError::NotDeserialized());
+        }
+        let mut builder = Builder {
+            workbook,
+            // `(col, row)` of the cells of each sheet, by column.
+            cells: sheets
+                .iter()
+                .map(|sheet| {
+                    let mut columns: BTreeMap<u32, BTreeSet<u32>> = BTreeMap::new();
+                    for cell in sheet.cells() {
+                        let coordinate = cell.coordinate();
+                        columns
+                            .entry(coordinate.col_num())
+                            .or_default()
+                            .insert(coordinate.row_num());
+                    }
+                    columns
+                })
+                .collect(),
+            graph: Self::default(),
+        };
+
+        for (sheet_index, sheet) in sheets.iter().enumerate() {
+            for cell in sheet.cells_sorted() {
+                if cell.formula().is_empty() {
+                    continue;
+                }
+                let coordinate = cell.coordinate();
+                let node = builder.graph.intern(DependencyNode::Cell {
+                    sheet_name: sheet.name().to_string(),
+                    col:        coordinate.col_num(),
+                    row:        coordinate.row_num(),
+                });
+                builder.link_formula(node, cell.formula(), Some(sheet_index));
+            }
+        }
+
+        let defined_names = workbook
+            .defined_names()
+            .iter()
+            .chain(sheets.iter().flat_map(Worksheet::defined_names));
+        for defined_name in defined_names {
+            if defined_name.name().starts_with(BUILT_IN_NAME_PREFIX) {
+                continue;
+            }
+            let scope = defined_name
+                .has_local_sheet_id()
+                .then(|| defined_name.local_sheet_id() as usize);
+            let node = builder.name_node(defined_name);
+            builder.link_formula(node, &defined_name.address(), scope);
+        }
+        Ok(builder.graph)
+    }
+
+    fn intern(&mut self, node: DependencyNode) -> usize {
+        if let Some(index) = self.index.get(&node) {
+            return *index;
+        }
+        let index = self.nodes.len();
+        self.index.insert(node.clone(), index);
+        self.nodes.push(node);
+        self.precedents.push(BTreeSet::new());
+        self.dependents.push(BTreeSet::new());
+        index
+    }
+
+    fn link(&mut self, node: usize, precedent: usize) {
+        self.precedents[node].insert(precedent);
+        self.dependents[precedent].insert(node);
+    }
+
+    fn find(&self, node: &DependencyNode) -> Option<usize> {
+        self.index
+            .get(node)
+            .copied()
+            .or_else(|| self.nodes.iter().position(|x| x.is_same(node)))
+    }
+
+    fn to_nodes<I: IntoIterator<Item = usize>>(&self, indexes: I) -> Vec<&DependencyNode> {
+        indexes.into_iter().map(|index| &self.nodes[index]).collect()
+    }
+
+    /// Get the cells and names of the graph: the cells and names with a
+    /// formula, and the nodes those formulas refer to.
+    #[inline]
+    #[must_use]
+    pub fn nodes(&self) -> &[DependencyNode] {
+        &self.nodes
+    }
+
+    /// Get the nodes the formula of `node` refers to directly.
+    #[must_use]
+    pub fn precedents(&self, node: &DependencyNode) -> Vec<&DependencyNode> {
+        self.find(node)
+            .map(|index| self.to_nodes(self.precedents[index].iter().copied()))
+            .unwrap_or_default()
+    }
+
+    /// Get the nodes whose formulas refer directly to `node`.
+    #[must_use]
+    pub fn dependents(&self, node: &DependencyNode) -> Vec<&DependencyNode> {
+        self.find(node)
+            .map(|index| self.to_nodes(self.dependents[index].iter().copied()))
+            .unwrap_or_default()
+    }
+
+    /// Get every node `node` depends on, directly or through other nodes,
+    /// nearest first.
+    #[must_use]
+    pub fn all_precedents(&self, node: &DependencyNode) -> Vec<&DependencyNode> {
+        self.find(node)
+            .map(|index| self.to_nodes(closure(&self.precedents, index)))
+            .unwrap_or_default()
+    }
+
+    /// Get every node depending on `node`, directly or through other nodes,
+    /// nearest first.
+    #[must_use]
+    pub fn all_dependents(&self, node: &DependencyNode) -> Vec<&DependencyNode> {
+        self.find(node)
+            .map(|index| self.to_nodes(closure(&self.dependents, index)))
+            .unwrap_or_default()
+    }
+
+    /// Get the circular references, one chain for each group of nodes that
+    /// depend on each other.
+    /// A chain `[A1, B1]` means that `A1` refers to `B1` and `B1` refers back
+    /// to `A1`.
+    #[must_use]
+    pub fn circular_references(&self) -> Vec<Vec<&DependencyNode>> {
+        strongly_connected_components(&self.precedents)
+            .into_iter()
+            .filter_map(|component| self.cycle(&component))
+            .map(|chain| self.to_nodes(chain))
+            .collect()
+    }
+
+    /// Get the nodes ordered so that every node comes after its precedents,
+    /// the order to calculate them in.
+    /// # Errors
+    /// * `InvalidArgument` - the graph has a circular reference.
+    pub fn topological_order(&self) -> Result<Vec<&DependencyNode>, XlsxError> {
+        let mut remaining: Vec<usize> = self.precedents.iter().map(BTreeSet::len).collect();
+        let mut queue: VecDeque<usize> = (0..self.nodes.len())
+            .filter(|index| remaining[*index] == 0)
+            .collect();
+        let mut result = Vec::with_capacity(self.nodes.len());
+        while let Some(index) = queue.pop_front() {
+            result.push(index);
+            for dependent in &self.dependents[index] {
+                remaining[*dependent] -= 1;
+                if remaining[*dependent] == 0 {
+                    queue.push_back(*dependent);
+                }
+            }
+        }
+        if result.len() < self.nodes.len() {
+            let chain = self
+                .circular_references()
+                .first()
+                .map(|chain| {
+                    chain
+                        .iter()
+                        .chain(chain.first())
+                        .map(ToString::to_string)
+                        .collect::<Vec<String>>()
+                        .join(" -> ")
+                })
+                .unwrap_or_default();
+            return Err(XlsxError::InvalidArgument(format!(
+                "circular reference: {chain}"
+            )));
+        }
+        Ok(self.to_nodes(result))
+    }
+
+    /// The shortest chain through `component` back to its first node, if
+    /// the nodes of `component` refer to each other.
+    fn cycle(&self, component: &[usize]) -> Option<Vec<usize>> {
+        let start = *component.iter().min()?;
+        if component.len() == 1 {
+            return self.precedents[start]
+                .contains(&start)
+                .then(|| vec![start]);
+        }
+        let members: BTreeSet<usize> = component.iter().copied().collect();
+        let mut parent: HashMap<usize, usize> = HashMap::new();
+        let mut queue = VecDeque::from([start]);
+        while let Some(index) = queue.pop_front() {
+            for precedent in &self.precedents[index] {
+                if *precedent == start {
+                    let mut chain = vec![index];
+                    while let Some(previous) = parent.get(chain.last().unwrap()) {
+                        chain.push(*previous);
+                    }
+                    chain.reverse();
+                    return Some(chain);
+                }
+                if members.contains(precedent) && !parent.contains_key(precedent) {
+                    parent.insert(*precedent, index);
+                    queue.push_back(*precedent);
+                }
+            }
+        }
+        None
+    }
+}
+
+struct Builder<'a> {
+    workbook: &'a Workbook,
+    cells:    Vec<BTreeMap<u32, BTreeSet<u32>>>,
+    graph:    DependencyGraph,
+}
+
+impl Builder<'_> {
+    fn name_node(&mut self, defined_name: &DefinedName) -> usize {
+        let scope = defined_name
+            .has_local_sheet_id()
+            .then(|| {
+                self.workbook
+                    .sheet_collection_no_check()
+                    .get(defined_name.local_sheet_id() as usize)
+                    .map(|sheet| sheet.name().to_string())
+            })
+            .flatten();
+        self.graph.intern(DependencyNode::Name {
+            name: defined_name.name().to_string(),
+            scope,
+        })
+    }
+
+    fn sheet_index(&self, sheet_name: &str) -> Option<usize> {
+        self.workbook
+            .sheet_collection_no_check()
+            .iter()
+            .position(|sheet| sheet.name().eq_ignore_ascii_case(sheet_name))
+    }
+
+    /// Link `node` to what `formula` refers to, with unqualified references
+    /// on the sheet at `scope`.
+    fn link_formula(&mut self, node: usize, formula: &str, scope: Option<usize>) {
+        let Ok(expr) = parse_formula(formula) else {
+            return;
+        };
+        let mut precedents = Vec::new();
+        expr.walk(&mut |expr| match expr {
+            FormulaExpr::Reference(reference) if !reference.is_external() => {
+                self.reference_nodes(reference, scope, &mut precedents);
+            }
+            FormulaExpr::Name(name) if !name.is_external() => {
+                let scope = match name.sheet() {
+                    Some(sheet_name) => self.sheet_index(sheet_name),
+                    None => scope,
+                };
+                if let Some(defined_name) = find_defined_name(self.workbook, name.name(), scope) {
+                    precedents.push(self.name_node(defined_name));
+                }
+            }
+            _ => {}
+        });
+        for precedent in precedents {
+            self.graph.link(node, precedent);
+        }
+    }
+
+    fn reference_nodes(
+        &mut self,
+        reference: &FormulaReference,
+        scope: Option<usize>,
+        result: &mut Vec<usize>,
+    ) {
+        let first = match reference.sheet() {
+            Some(sheet_name) => self.sheet_index(sheet_name),
+            None => scope,
+        };
+        let last = match reference.last_sheet() {
+            Some(sheet_name) => self.sheet_index(sheet_name),
+            None => first,
+        };
+        let (Some(first), Some(last)) = (first, last) else {
+            return;
+        };
+        let (col_start, row_start, col_end, row_end) = match reference.area() {
+            ReferenceArea::Cell(coordinate) => {
+                for sheet_index in first.min(last)..=first.max(last) {
+                    let sheet_name = self.workbook.sheet_collection_no_check()[sheet_index]
+                        .name()
+                        .to_string();
+                    result.push(self.graph.intern(DependencyNode::Cell {
+                        sheet_name,
+                        col: coordinate.col_num(),
+                        row: coordinate.row_num(),
+                    }));
+                }
+                return;
+            }
+            ReferenceArea::Range(start, end) => {
+                (start.col_num(), start.row_num(), end.col_num(), end.row_num())
+            }
+            ReferenceArea::Columns(start, end) => (start.num(), 1, end.num(), MAX_ROW),
+            ReferenceArea::Rows(start, end) => (1, start.num(), MAX_COLUMN, end.num()),
+        };
+        let cols = col_start.min(col_end)..=col_start.max(col_end);
+        let rows = row_start.min(row_end)..=row_start.max(row_end);
+        for sheet_index in first.min(last)..=first.max(last) {
+            let sheet_name = self.workbook.sheet_collection_no_check()[sheet_index].name();
+            let coordinates: Vec<(u32, u32)> = self.cells[sheet_index]
+                .range(cols.clone())
+                .flat_map(|(col, column)| column.range(rows.clone()).map(move |row| (*col, *row)))
+                .collect();
+            for (col, row) in coordinates {
+                result.push(self.graph.intern(DependencyNode::Cell {
+                    sheet_name: sheet_name.to_string(),
+                    col,
+                    row,
+                }));
+            }
+        }
+    }
+}
+
+/// The nodes reachable from `start` through `edges`, nearest first.
+fn closure(edges: &[BTreeSet<usize>], start: usize) -> Vec<usize> {
+    let mut seen = vec![false; edges.len()];
+    seen[start] = true;
+    let mut result = Vec::new();
+    let mut queue = VecDeque::from([start]);
+    while let Some(index) = queue.pop_front() {
+        for next in &edges[index] {
+            if !seen[*next] {
+                seen[*next] = true;
+                result.push(*next);
+                queue.push_back(*next);
+            }
+        }
+    }
+    result
+}
+
+/// Tarjan's strongly connected components of `edges`, without recursion so
+/// that long chains of formulas do not overflow the stack.
+fn strongly_connected_components(edges: &[BTreeSet<usize>]) -> Vec<Vec<usize>> {
+    let count = edges.len();
+    let mut order: Vec<Option<usize>> = vec![None; count];
+    let mut low_link = vec![0; count];
+    let mut on_stack = vec![false; count];
+    let mut stack = Vec::new();
+    let mut next_order = 0;
+    let mut result = Vec::new();
+
+    for root in 0..count {
+        if order[root].is_some() {
+            continue;
+        }
+        // `(node, edges of it still to visit)`
+        let mut work: Vec<(usize, Vec<usize>)> = Vec::new();
+        order[root] = Some(next_order);
+        low_link[root] = next_order;
+        next_order += 1;
+        stack.push(root);
+        on_stack[root] = true;
+        work.push((root, edges[root].iter().rev().copied().collect()));
+
+        while let Some((node, pending)) = work.last_mut() {
+            let node = *node;
+            if let Some(next) = pending.pop() {
+                match order[next] {
+                    None => {
+                        order[next] = Some(next_order);
+                        low_link[next] = next_order;
+                        next_order += 1;
+                        stack.push(next);
+                        on_stack[next] = true;
+                        work.push((next, edges[next].iter().rev().copied().collect()));
+                    }
+                    Some(visited) if on_stack[next] => {
+                        low_link[node] = low_link[node].min(visited);
+                    }
+                    Some(_) => {}
+                }
+                continue;
+            }
+            work.pop();
+            if let Some((parent, _)) = work.last() {
+                low_link[*parent] = low_link[*parent].min(low_link[node]);
+            }
+            if Some(low_link[node]) == order[node] {
+                let mut component = Vec::new();
+                while let Some(member) = stack.pop() {
+                    on_stack[member] = false;
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                result.push(component);
+            }
+        }
+    }
+    result
+}
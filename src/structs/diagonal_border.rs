@@ -8,6 +8,8 @@ use reader::driver::*;
 use std::io::Cursor;
 use writer::driver::*;
 
+use crate::XlsxError;
+
 #[derive(Default, Debug, Clone)]
 pub struct DiagonalBorder {
     color: Color,
@@ -110,7 +112,7 @@ impl DiagonalBorder {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(v) = get_attribute(e, b"style") {
             self.style.set_value_string(v);
         }
@@ -120,16 +122,16 @@ impl DiagonalBorder {
             match reader.read_event_into(&mut buf) {
                 Ok(Event::Empty(ref e)) => match e.name().into_inner() {
                     b"color" => {
-                        &mut self.color.set_attributes(reader, e);
+                        &mut self.color.set_attributes(reader, e)?;
                     }
                     _ => (),
                 },
                 Ok(Event::End(ref e)) => match e.name().into_inner() {
-                    b"diagonal" => return,
+                    b"diagonal" => return Ok(()),
                     _ => (),
                 },
-                Ok(Event::Eof) => panic!("Error: Could not find {} end element", "diagonal"),
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(XlsxError::missing_end_element("diagonal", reader.buffer_position())),
+                Err(e) => return Err(XlsxError::parse(reader.buffer_position(), e.to_string())),
                 _ => (),
             }
             buf.clear();
@@ -18,6 +18,7 @@ use super::{
     Style,
 };
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -217,24 +218,24 @@ impl DifferentialFormat {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"font" => {
                         let mut obj = Font::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_font(obj);
                     }
                     b"fill" => {
                         let mut obj = Fill::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_fill(obj);
                     }
                     b"border" => {
                         let mut obj = Borders::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_borders(obj);
                     }
                     b"alignment" => {
@@ -247,10 +248,10 @@ impl DifferentialFormat {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"dxf" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "dxf")
+            Event::Eof => return Err(XlsxError::missing_end_element("dxf", reader.buffer_position()))
         );
     }
 
@@ -15,6 +15,7 @@ use super::{
     Style,
 };
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -89,22 +90,22 @@ impl DifferentialFormats {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"dxf" {
                     let mut obj = DifferentialFormat::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_differential_format(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"dxfs" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "dxfs")
+            Event::Eof => return Err(XlsxError::missing_end_element("dxfs", reader.buffer_position()))
         );
     }
 
@@ -1,3 +1,5 @@
+use crate::XlsxError;
+
 #[derive(Clone, Default, Debug, PartialEq, PartialOrd)]
 pub struct DoubleValue {
     value: Option<f64>,
@@ -31,9 +33,18 @@ impl DoubleValue {
         self
     }
 
+    /// The text of an attribute. A text that is not a number is a parse
+    /// error, which the reader moves to its position.
     #[inline]
-    pub(crate) fn set_value_string<S: Into<String>>(&mut self, value: S) -> &mut Self {
-        self.set_value(value.into().parse::<f64>().unwrap_or_default())
+    pub(crate) fn set_value_string<S: Into<String>>(
+        &mut self,
+        value: S,
+    ) -> Result<&mut Self, XlsxError> {
+        let value = value.into();
+        match value.parse::<f64>() {
+            Ok(v) => Ok(self.set_value(v)),
+            Err(_) => Err(XlsxError::parse(0, format!("\"{value}\" is not a number"))),
+        }
     }
 
     #[inline]
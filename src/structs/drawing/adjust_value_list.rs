@@ -12,8 +12,9 @@ use quick_xml::{
 
 use super::shape_guide::ShapeGuide;
 use crate::{
+    XlsxError,
     reader::driver::{
-        get_attribute,
+        get_required_attribute,
         xml_read_loop,
     },
     writer::driver::{
@@ -66,23 +67,23 @@ impl AdjustValueList {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:gd" {
                     let mut shape_guide = ShapeGuide::default();
-                    shape_guide.set_name(get_attribute(e, b"name").unwrap());
-                    shape_guide.set_fmla(get_attribute(e, b"fmla").unwrap());
+                    shape_guide.set_name(get_required_attribute(reader, e, b"name")?);
+                    shape_guide.set_fmla(get_required_attribute(reader, e, b"fmla")?);
                     self.add_shape_guide_collection(shape_guide);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:avLst" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:avLst")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:avLst", reader.buffer_position()))
         );
     }
 
@@ -8,7 +8,8 @@ use quick_xml::{
 };
 
 use crate::{
-    reader::driver::get_attribute,
+    XlsxError,
+    reader::driver::get_required_attribute,
     writer::driver::write_start_tag,
 };
 
@@ -38,10 +39,11 @@ impl Alpha {
     #[inline]
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
-        _reader: &mut Reader<R>,
+        reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        self.set_val(get_attribute(e, b"val").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.set_val(get_required_attribute(reader, e, b"val")?);
+        Ok(())
     }
 
     #[inline]
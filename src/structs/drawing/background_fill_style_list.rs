@@ -14,6 +14,7 @@ use super::{
     SolidFill,
 };
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -107,19 +108,19 @@ impl BackgroundFillStyleList {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                 b"a:solidFill" => {
                     let mut obj = SolidFill::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.solid_fill.push(obj);
                 }
                 b"a:gradFill" => {
                     let mut obj = GradientFill::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.gradient_fill_collection.push(obj);
                 }
                 _ => (),
@@ -127,10 +128,10 @@ impl BackgroundFillStyleList {
             },
             Event::End(ref e) => {
                 if  e.name().into_inner() == b"a:bgFillStyleLst" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "bgFillStyleLst")
+            Event::Eof => return Err(XlsxError::missing_end_element("bgFillStyleLst", reader.buffer_position()))
         );
     }
 
@@ -11,6 +11,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::write_start_tag,
 };
@@ -23,19 +24,19 @@ impl Bevel {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:bevel" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:bevel")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:bevel", reader.buffer_position()))
         );
     }
 
@@ -11,12 +11,14 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     helper::const_str::{
         DRAWING_MAIN_NS,
         REL_OFC_NS,
     },
     reader::driver::{
         get_attribute,
+        get_required_attribute,
         xml_read_loop,
     },
     structs::{
@@ -91,30 +93,32 @@ impl Blip {
         e: &BytesStart,
         drawing_relationships: &RawRelationships,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(v) = get_attribute(e, b"cstate") {
             self.set_cstate(v);
         }
 
-        let picture_id = get_attribute(e, b"r:embed").unwrap();
-        let relationship = drawing_relationships.relationship_by_rid(&picture_id);
+        let picture_id = get_required_attribute(reader, e, b"r:embed")?;
+        let relationship = drawing_relationships
+            .relationship_by_rid(&picture_id)
+            .ok_or_else(|| XlsxError::missing_relationship(&picture_id, reader.buffer_position()))?;
         self.image_mut()
             .set_image_name(relationship.raw_file().file_name());
         self.image_mut()
             .set_image_data(relationship.raw_file().file_data());
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:blip" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:blip")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:blip", reader.buffer_position()))
         );
     }
 
@@ -21,6 +21,7 @@ use super::{
 };
 use crate::{
     StringValue,
+    XlsxError,
     reader::driver::{
         get_attribute_value,
         xml_read_loop,
@@ -275,10 +276,10 @@ impl BodyProperties {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         for attr in e.attributes().with_checks(false).flatten() {
             let key = attr.key.into_inner();
-            let value = get_attribute_value(&attr).unwrap();
+            let value = get_attribute_value(&attr)?;
             match key {
                 b"rot" => {
                     self.rotation.set_value_string(value);
@@ -318,7 +319,7 @@ impl BodyProperties {
         }
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -332,10 +333,10 @@ impl BodyProperties {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:bodyPr" {
-                     return
+                     return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:bodyPr")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:bodyPr", reader.buffer_position()))
         );
     }
 
@@ -16,6 +16,7 @@ use super::{
     Rotation,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -88,11 +89,11 @@ impl Camera {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, preset, "prst");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -106,10 +107,10 @@ impl Camera {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:camera" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:camera")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:camera", reader.buffer_position()))
         );
     }
 
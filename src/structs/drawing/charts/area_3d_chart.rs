@@ -19,6 +19,7 @@ use super::{
     VaryColors,
 };
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     structs::Workbook,
     writer::driver::{
@@ -171,20 +172,20 @@ impl Area3DChart {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"c:ser" => {
                         let mut obj = AreaChartSeries::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.area_chart_series_list_mut()
                             .add_area_chart_series(obj);
                         }
                     b"c:dLbls" => {
                         let mut obj = DataLabels::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_data_labels(obj);
                     }
                     _ => (),
@@ -193,14 +194,14 @@ impl Area3DChart {
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                     b"c:grouping" => {
-                        self.grouping.set_attributes(reader, e);
+                        self.grouping.set_attributes(reader, e)?;
                     }
                     b"c:varyColors" => {
-                        self.vary_colors.set_attributes(reader, e);
+                        self.vary_colors.set_attributes(reader, e)?;
                     }
                     b"c:axId" => {
                         let mut obj = AxisId::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.add_axis_id(obj);
                     }
                     _ => (),
@@ -208,10 +209,10 @@ impl Area3DChart {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:area3DChart" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:area3DChart")
+            Event::Eof => return Err(XlsxError::missing_end_element("c:area3DChart", reader.buffer_position()))
         );
     }
 
@@ -28,6 +28,7 @@ use super::{
     YValues,
 };
 use crate::{
+    XlsxError,
     drawing::charts::ChartText,
     structs::Workbook,
     writer::driver::{
@@ -464,92 +465,92 @@ impl AreaChartSeries {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().into_inner() {
                 b"c:tx" => {
                     let mut obj = ChartText::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_chart_text(obj);
                 }
                 b"c:marker" => {
                     let mut obj = Marker::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.set_marker(obj);
                 }
                 b"c:spPr" => {
                     let mut obj = ShapeProperties::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_shape_properties(obj);
                 }
                 b"c:cat" => {
                     let mut obj = CategoryAxisData::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_category_axis_data(obj);
                 }
                 b"c:val" => {
                     let mut obj = Values::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_values(obj);
                 }
                 b"c:xVal" => {
                     let mut obj = XValues::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_x_values(obj);
                 }
                 b"c:yVal" => {
                     let mut obj = YValues::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_y_values(obj);
                 }
                 b"c:bubbleSize" => {
                     let mut obj = BubbleSize::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_bubble_size(obj);
                 }
                 b"c:dLbls" => {
                     let mut obj = DataLabels::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_data_labels(obj);
                 }
                 _ => (),
             },
             Event::Empty(ref e) => match e.name().into_inner() {
                 b"c:idx" => {
-                    self.index.set_attributes(reader, e);
+                    self.index.set_attributes(reader, e)?;
                 }
                 b"c:order" => {
-                    self.order.set_attributes(reader, e);
+                    self.order.set_attributes(reader, e)?;
                 }
                 b"c:explosion" => {
                     let mut obj = Explosion::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_explosion(obj);
                 }
                 b"c:invertIfNegative" => {
                     let mut obj = InvertIfNegative::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_invert_if_negative(obj);
                 }
                 b"c:bubble3D" => {
                     let mut obj = Bubble3D::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_bubble_3d(obj);
                 }
                 b"c:smooth" => {
                     let mut obj = Smooth::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_smooth(obj);
                 }
                 _ => (),
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:ser" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:ser"),
+            Event::Eof => return Err(XlsxError::missing_end_element("c:ser", reader.buffer_position())),
         );
     }
 
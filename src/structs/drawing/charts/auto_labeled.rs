@@ -9,7 +9,8 @@ use quick_xml::{
 
 use super::super::super::BooleanValue;
 use crate::{
-    reader::driver::get_attribute,
+    XlsxError,
+    reader::driver::get_required_attribute,
     writer::driver::write_start_tag,
 };
 
@@ -36,10 +37,11 @@ impl AutoLabeled {
 
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
-        _reader: &mut Reader<R>,
+        reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        self.val.set_value_string(get_attribute(e, b"val").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.val.set_value_string(get_required_attribute(reader, e, b"val")?);
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -12,7 +12,8 @@ use super::{
     AxisPositionValues,
 };
 use crate::{
-    reader::driver::get_attribute,
+    XlsxError,
+    reader::driver::get_required_attribute,
     writer::driver::write_start_tag,
 };
 
@@ -39,10 +40,11 @@ impl AxisPosition {
 
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
-        _reader: &mut Reader<R>,
+        reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        self.val.set_value_string(get_attribute(e, b"val").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.val.set_value_string(get_required_attribute(reader, e, b"val")?);
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
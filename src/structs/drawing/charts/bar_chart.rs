@@ -22,6 +22,7 @@ use super::{
     VaryColors,
 };
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     structs::Workbook,
     writer::driver::{
@@ -252,19 +253,19 @@ impl BarChart {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"c:ser" => {
                         let mut obj = AreaChartSeries::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.area_chart_series_list_mut()
                             .add_area_chart_series(obj);
                         }
                     b"c:dLbls" => {
-                        self.data_labels.set_attributes(reader, e);
+                        self.data_labels.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
@@ -272,23 +273,23 @@ impl BarChart {
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                     b"c:barDir" => {
-                        self.bar_direction.set_attributes(reader, e);
+                        self.bar_direction.set_attributes(reader, e)?;
                     }
                     b"c:grouping" => {
-                        self.grouping.set_attributes(reader, e);
+                        self.grouping.set_attributes(reader, e)?;
                     }
                     b"c:varyColors" => {
-                        self.vary_colors.set_attributes(reader, e);
+                        self.vary_colors.set_attributes(reader, e)?;
                     }
                     b"c:gapWidth" => {
-                        self.gap_width.set_attributes(reader, e);
+                        self.gap_width.set_attributes(reader, e)?;
                     }
                     b"c:overlap" => {
-                        self.overlap.set_attributes(reader, e);
+                        self.overlap.set_attributes(reader, e)?;
                     }
                     b"c:axId" => {
                         let mut obj = AxisId::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.add_axis_id(obj);
                     }
                     _ => (),
@@ -296,10 +297,10 @@ impl BarChart {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:barChart" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:barChart")
+            Event::Eof => return Err(XlsxError::missing_end_element("c:barChart", reader.buffer_position()))
         );
     }
 
@@ -12,6 +12,7 @@ use quick_xml::{
 
 use super::NumberReference;
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     structs::Workbook,
     writer::driver::{
@@ -55,20 +56,20 @@ impl BubbleSize {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"c:numRef" {
-                    self.number_reference.set_attributes(reader, e);
+                    self.number_reference.set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:bubbleSize" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:bubbleSize")
+            Event::Eof => return Err(XlsxError::missing_end_element("c:bubbleSize", reader.buffer_position()))
         );
     }
 
@@ -15,6 +15,7 @@ use super::{
     StringReference,
 };
 use crate::{
+    XlsxError,
     drawing::charts::NumberReference,
     reader::driver::xml_read_loop,
     structs::Workbook,
@@ -126,24 +127,24 @@ impl CategoryAxisData {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"c:strRef" => {
                         let mut obj = StringReference::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_string_reference(obj);
                     }
                     b"c:strLit" => {
                         let mut obj = StringLiteral::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_string_literal(obj);
                     }
                     b"c:numRef" => {
                         let mut obj = NumberReference::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_number_reference(obj);
                     }
                     _ => (),
@@ -151,10 +152,10 @@ impl CategoryAxisData {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:cat" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:cat")
+            Event::Eof => return Err(XlsxError::missing_end_element("c:cat", reader.buffer_position()))
         );
     }
 
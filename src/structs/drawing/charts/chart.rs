@@ -24,6 +24,7 @@ use super::{
     View3D,
 };
 use crate::{
+    XlsxError,
     structs::Workbook,
     traits::AdjustmentCoordinateWithSheet,
     writer::driver::{
@@ -392,7 +393,7 @@ impl Chart {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         // Reset legend presence so "no legend in source" round-trips through
         // the writer. Default-constructed Charts have `legend_present == true`
         // for backwards compatibility; on a real read we start over and let
@@ -403,59 +404,59 @@ impl Chart {
             Event::Start(ref e) => match e.name().into_inner() {
                 b"c:title" => {
                     let mut obj = Title::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_title(obj);
                 }
                 b"c:view3D" => {
                     let mut obj = View3D::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_view_3d(obj);
                 }
                 b"c:floor" => {
                     let mut obj = Floor::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_floor(obj);
                 }
                 b"c:sideWall" => {
                     let mut obj = SideWall::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_side_wall(obj);
                 }
                 b"c:backWall" => {
                     let mut obj = BackWall::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_back_wall(obj);
                 }
                 b"c:plotArea" => {
-                    self.plot_area.set_attributes(reader, e);
+                    self.plot_area.set_attributes(reader, e)?;
                 }
                 b"c:legend" => {
-                    self.legend.set_attributes(reader, e);
+                    self.legend.set_attributes(reader, e)?;
                     self.legend_present = true;
                 }
                 _ => (),
             },
             Event::Empty(ref e) => match e.name().into_inner() {
                 b"c:autoTitleDeleted" => {
-                    self.auto_title_deleted.set_attributes(reader, e);
+                    self.auto_title_deleted.set_attributes(reader, e)?;
                 }
                 b"c:plotVisOnly" => {
-                    self.plot_visible_only.set_attributes(reader, e);
+                    self.plot_visible_only.set_attributes(reader, e)?;
                 }
                 b"c:dispBlanksAs" => {
-                    self.display_blanks_as.set_attributes(reader, e);
+                    self.display_blanks_as.set_attributes(reader, e)?;
                 }
                 b"c:showDLblsOverMax" => {
-                    self.show_data_labels_over_maximum.set_attributes(reader, e);
+                    self.show_data_labels_over_maximum.set_attributes(reader, e)?;
                 }
                 _ => (),
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:chart" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:chart"),
+            Event::Eof => return Err(XlsxError::missing_end_element("c:chart", reader.buffer_position())),
         );
     }
 
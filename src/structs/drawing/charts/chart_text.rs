@@ -13,6 +13,7 @@ use quick_xml::{
 use super::RichText;
 use crate::{
     Workbook,
+    XlsxError,
     drawing::charts::{
         NumericValue,
         StringReference,
@@ -111,24 +112,24 @@ impl ChartText {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"c:rich" => {
                         let mut obj = RichText::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_rich_text(obj);
                     }
                     b"c:strRef" => {
                         let mut obj = StringReference::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_string_reference(obj);
                     }
                     b"c:v" => {
                         let mut obj = NumericValue::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_numeric_value(obj);
                     }
                     _ => (),
@@ -136,10 +137,10 @@ impl ChartText {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:tx" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:tx"),
+            Event::Eof => return Err(XlsxError::missing_end_element("c:tx", reader.buffer_position())),
         );
     }
 
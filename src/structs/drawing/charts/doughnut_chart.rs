@@ -19,6 +19,7 @@ use super::{
     VaryColors,
 };
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     structs::Workbook,
     writer::driver::{
@@ -166,39 +167,39 @@ impl DoughnutChart {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().into_inner() {
                 b"c:ser" => {
                     let mut obj = AreaChartSeries::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.area_chart_series_list_mut()
                         .add_area_chart_series(obj);
                 }
                 b"c:dLbls" => {
-                    self.data_labels.set_attributes(reader, e);
+                    self.data_labels.set_attributes(reader, e)?;
                 }
                 _ => (),
             },
             Event::Empty(ref e) => match e.name().into_inner() {
                 b"c:varyColors" => {
-                    self.vary_colors.set_attributes(reader, e);
+                    self.vary_colors.set_attributes(reader, e)?;
                 }
                 b"c:firstSliceAng" => {
-                    self.first_slice_angle.set_attributes(reader, e);
+                    self.first_slice_angle.set_attributes(reader, e)?;
                 }
                 b"c:holeSize" => {
-                    self.hole_size.set_attributes(reader, e);
+                    self.hole_size.set_attributes(reader, e)?;
                 }
                 _ => (),
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:doughnutChart" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:doughnutChart")
+            Event::Eof => return Err(XlsxError::missing_end_element("c:doughnutChart", reader.buffer_position()))
         );
     }
 
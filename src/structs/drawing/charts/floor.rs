@@ -15,6 +15,7 @@ use super::{
     Thickness,
 };
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -83,29 +84,29 @@ impl Floor {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"c:thickness" {
                     let mut obj = Thickness::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_thickness(obj);
                 }
             },
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"c:spPr" {
                     let mut obj = ShapeProperties::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_shape_properties(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:floor" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:floor")
+            Event::Eof => return Err(XlsxError::missing_end_element("c:floor", reader.buffer_position()))
         );
     }
 
@@ -13,6 +13,7 @@ use quick_xml::{
 use super::super::super::Address;
 use super::super::super::StringValue;
 use crate::{
+    XlsxError,
     helper::address::is_address,
     traits::AdjustmentCoordinateWithSheet,
     writer::driver::{
@@ -94,7 +95,7 @@ impl Formula {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Text(e) => {
@@ -102,10 +103,10 @@ impl Formula {
             },
             Event::End(ref e) => {
                if  e.name().0 == b"c:f" {
-                   return;
+                   return Ok(());
                }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:f"),
+            Event::Eof => return Err(XlsxError::missing_end_element("c:f", reader.buffer_position())),
         );
     }
 
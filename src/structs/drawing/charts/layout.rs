@@ -12,6 +12,7 @@ use quick_xml::{
 
 use super::ManualLayout;
 use crate::{
+    XlsxError,
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -60,9 +61,9 @@ impl Layout {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -70,16 +71,16 @@ impl Layout {
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"c:manualLayout" {
                     let mut obj = ManualLayout::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_manual_layout(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:layout" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:layout"),
+            Event::Eof => return Err(XlsxError::missing_end_element("c:layout", reader.buffer_position())),
         );
     }
 
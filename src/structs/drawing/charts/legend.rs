@@ -17,6 +17,7 @@ use super::{
     TextProperties,
 };
 use crate::{
+    XlsxError,
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -163,47 +164,47 @@ impl Legend {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().into_inner() {
                 b"c:layout" => {
                     let mut obj = Layout::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.set_layout(obj);
                 }
                 b"c:spPr" => {
                     let mut obj = ShapeProperties::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_shape_properties(obj);
                 }
                 b"c:txPr" => {
                     let mut obj = TextProperties::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_text_properties(obj);
                 }
                 _ => (),
             },
             Event::Empty(ref e) => match e.name().into_inner() {
                 b"c:legendPos" => {
-                    self.legend_position.set_attributes(reader, e);
+                    self.legend_position.set_attributes(reader, e)?;
                 }
                 b"c:layout" => {
                     let mut obj = Layout::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_layout(obj);
                 }
                 b"c:overlay" => {
-                    self.overlay.set_attributes(reader, e);
+                    self.overlay.set_attributes(reader, e)?;
                 }
                 _ => (),
             },
             Event::End(ref e) => {
                 if  e.name().into_inner() == b"c:legend" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:legend"),
+            Event::Eof => return Err(XlsxError::missing_end_element("c:legend", reader.buffer_position())),
         );
     }
 
@@ -21,6 +21,7 @@ use super::{
     VaryColors,
 };
 use crate::{
+    XlsxError,
     structs::Workbook,
     writer::driver::{
         write_end_tag,
@@ -225,47 +226,47 @@ impl LineChart {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().into_inner() {
                 b"c:ser" => {
                     let mut obj = AreaChartSeries::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.area_chart_series_list_mut()
                         .add_area_chart_series(obj);
                 }
                 b"c:dLbls" => {
-                    self.data_labels.set_attributes(reader, e);
+                    self.data_labels.set_attributes(reader, e)?;
                 }
                 _ => (),
             },
             Event::Empty(ref e) => match e.name().into_inner() {
                 b"c:grouping" => {
-                    self.grouping.set_attributes(reader, e);
+                    self.grouping.set_attributes(reader, e)?;
                 }
                 b"c:varyColors" => {
-                    self.vary_colors.set_attributes(reader, e);
+                    self.vary_colors.set_attributes(reader, e)?;
                 }
                 b"c:marker" => {
-                    self.show_marker.set_attributes(reader, e);
+                    self.show_marker.set_attributes(reader, e)?;
                 }
                 b"c:smooth" => {
-                    self.smooth.set_attributes(reader, e);
+                    self.smooth.set_attributes(reader, e)?;
                 }
                 b"c:axId" => {
                     let mut obj = AxisId::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_axis_id(obj);
                 }
                 _ => (),
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:lineChart" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:lineChart"),
+            Event::Eof => return Err(XlsxError::missing_end_element("c:lineChart", reader.buffer_position())),
         );
     }
 
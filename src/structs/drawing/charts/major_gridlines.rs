@@ -12,6 +12,7 @@ use quick_xml::{
 
 use super::ShapeProperties;
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -55,9 +56,9 @@ impl MajorGridlines {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -65,16 +66,16 @@ impl MajorGridlines {
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"c:spPr" {
                     let mut obj = ShapeProperties::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_shape_properties(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"c:majorGridlines" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:majorGridlines")
+            Event::Eof => return Err(XlsxError::missing_end_element("c:majorGridlines", reader.buffer_position()))
         );
     }
 
@@ -12,6 +12,7 @@ use quick_xml::{
 
 use super::Symbol;
 use crate::{
+    XlsxError,
     drawing::charts::{
         ShapeProperties,
         Size,
@@ -111,9 +112,9 @@ impl Marker {
         reader: &mut Reader<R>,
         _: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -121,28 +122,28 @@ impl Marker {
             Event::Empty(ref e) => {
                 if e.name().0 == b"c:symbol" {
                     let mut obj = Symbol::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_symbol(obj);
                 }
                 if e.name().0 == b"c:size" {
                     let mut obj = Size::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_size(obj);
                 }
             },
             Event::Start(ref e) => {
                 if e.name().0 == b"c:spPr" {
                     let mut obj = ShapeProperties::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_shape_properties(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:marker" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:marker")
+            Event::Eof => return Err(XlsxError::missing_end_element("c:marker", reader.buffer_position()))
         );
     }
 
@@ -13,6 +13,7 @@ use quick_xml::{
 use super::Formula;
 use super::NumberingCache;
 use crate::{
+    XlsxError,
     structs::Workbook,
     writer::driver::{
         write_end_tag,
@@ -82,24 +83,24 @@ impl NumberReference {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().0 {
                 b"c:f" => {
-                    self.formula.set_attributes(reader, e);
+                    self.formula.set_attributes(reader, e)?;
                 }
                 b"c:numCache" => {
-                    self.numbering_cache.set_attributes(reader, e);
+                    self.numbering_cache.set_attributes(reader, e)?;
                 }
                 _ => (),
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:numRef" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:numRef"),
+            Event::Eof => return Err(XlsxError::missing_end_element("c:numRef", reader.buffer_position())),
         );
     }
 
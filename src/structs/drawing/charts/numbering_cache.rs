@@ -12,6 +12,7 @@ use quick_xml::{
 
 use super::FormatCode;
 use crate::{
+    XlsxError,
     structs::{
         Address,
         Workbook,
@@ -59,20 +60,20 @@ impl NumberingCache {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().0 == b"c:formatCode" {
-                    self.format_code.set_attributes(reader, e);
+                    self.format_code.set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                if e.name().0 == b"c:numCache" {
-                   return;
+                   return Ok(());
                }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:numCache"),
+            Event::Eof => return Err(XlsxError::missing_end_element("c:numCache", reader.buffer_position())),
         );
     }
 
@@ -12,7 +12,8 @@ use super::super::super::{
     StringValue,
 };
 use crate::{
-    reader::driver::get_attribute,
+    XlsxError,
+    reader::driver::get_required_attribute,
     writer::driver::write_start_tag,
 };
 
@@ -56,13 +57,14 @@ impl NumberingFormat {
 
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
-        _reader: &mut Reader<R>,
+        reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         self.format_code
-            .set_value_string(get_attribute(e, b"formatCode").unwrap());
+            .set_value_string(get_required_attribute(reader, e, b"formatCode")?);
         self.source_linked
-            .set_value_string(get_attribute(e, b"sourceLinked").unwrap());
+            .set_value_string(get_required_attribute(reader, e, b"sourceLinked")?);
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -11,6 +11,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -45,7 +46,7 @@ impl NumericValue {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Text(e) => {
@@ -53,10 +54,10 @@ impl NumericValue {
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:v" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:v"),
+            Event::Eof => return Err(XlsxError::missing_end_element("c:v", reader.buffer_position())),
         );
     }
 
@@ -21,6 +21,7 @@ use super::{
     VaryColors,
 };
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     structs::Workbook,
     writer::driver::{
@@ -220,19 +221,19 @@ impl OfPieChart {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().0 {
                     b"c:ser" => {
                         let mut obj = AreaChartSeries::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.area_chart_series_list_mut()
                             .add_area_chart_series(obj);
                         }
                     b"c:dLbls" => {
-                        self.data_labels.set_attributes(reader, e);
+                        self.data_labels.set_attributes(reader, e)?;
                     }
                     b"c:serLines" => {
                         SeriesLines::set_attributes(reader, e);
@@ -243,26 +244,26 @@ impl OfPieChart {
             Event::Empty(ref e) => {
                 match e.name().0 {
                     b"c:ofPieType" => {
-                        self.of_pie_type.set_attributes(reader, e);
+                        self.of_pie_type.set_attributes(reader, e)?;
                     }
                     b"c:varyColors" => {
-                        self.vary_colors.set_attributes(reader, e);
+                        self.vary_colors.set_attributes(reader, e)?;
                     }
                     b"c:gapWidth" => {
-                        self.gap_width.set_attributes(reader, e);
+                        self.gap_width.set_attributes(reader, e)?;
                     }
                     b"c:secondPieSize" => {
-                        self.second_pie_size.set_attributes(reader, e);
+                        self.second_pie_size.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:ofPieChart" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:ofPieChart")
+            Event::Eof => return Err(XlsxError::missing_end_element("c:ofPieChart", reader.buffer_position()))
         );
     }
 
@@ -9,7 +9,8 @@ use quick_xml::{
 
 use super::super::super::DoubleValue;
 use crate::{
-    reader::driver::get_attribute,
+    XlsxError,
+    reader::driver::get_required_attribute,
     writer::driver::write_start_tag,
 };
 
@@ -121,18 +122,28 @@ impl PageMargins {
 
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
-        _reader: &mut Reader<R>,
+        reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         self.bottom
-            .set_value_string(get_attribute(e, b"b").unwrap());
-        self.left.set_value_string(get_attribute(e, b"l").unwrap());
-        self.right.set_value_string(get_attribute(e, b"r").unwrap());
-        self.top.set_value_string(get_attribute(e, b"t").unwrap());
+            .set_value_string(get_required_attribute(reader, e, b"b")?)
+            .map_err(|error| error.at(reader.buffer_position()))?;
+        self.left
+            .set_value_string(get_required_attribute(reader, e, b"l")?)
+            .map_err(|error| error.at(reader.buffer_position()))?;
+        self.right
+            .set_value_string(get_required_attribute(reader, e, b"r")?)
+            .map_err(|error| error.at(reader.buffer_position()))?;
+        self.top
+            .set_value_string(get_required_attribute(reader, e, b"t")?)
+            .map_err(|error| error.at(reader.buffer_position()))?;
         self.header
-            .set_value_string(get_attribute(e, b"header").unwrap());
+            .set_value_string(get_required_attribute(reader, e, b"header")?)
+            .map_err(|error| error.at(reader.buffer_position()))?;
         self.footer
-            .set_value_string(get_attribute(e, b"footer").unwrap());
+            .set_value_string(get_required_attribute(reader, e, b"footer")?)
+            .map_err(|error| error.at(reader.buffer_position()))?;
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -17,6 +17,7 @@ use super::{
     VaryColors,
 };
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     structs::Workbook,
     writer::driver::{
@@ -112,34 +113,34 @@ impl Pie3DChart {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().0 {
                     b"c:ser" => {
                         let mut obj = AreaChartSeries::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.area_chart_series_list_mut()
                             .add_area_chart_series(obj);
                         }
                     b"c:dLbls" => {
-                        self.data_labels.set_attributes(reader, e);
+                        self.data_labels.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
             },
             Event::Empty(ref e) => {
                 if e.name().0 == b"c:varyColors" {
-                    self.vary_colors.set_attributes(reader, e);
+                    self.vary_colors.set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:pie3DChart" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:pie3DChart")
+            Event::Eof => return Err(XlsxError::missing_end_element("c:pie3DChart", reader.buffer_position()))
         );
     }
 
@@ -18,6 +18,7 @@ use super::{
     VaryColors,
 };
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     structs::Workbook,
     writer::driver::{
@@ -139,19 +140,19 @@ impl PieChart {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().0 {
                     b"c:ser" => {
                         let mut obj = AreaChartSeries::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.area_chart_series_list_mut()
                             .add_area_chart_series(obj);
                         }
                     b"c:dLbls" => {
-                        self.data_labels.set_attributes(reader, e);
+                        self.data_labels.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
@@ -159,20 +160,20 @@ impl PieChart {
             Event::Empty(ref e) => {
                 match e.name().0 {
                     b"c:varyColors" => {
-                        self.vary_colors.set_attributes(reader, e);
+                        self.vary_colors.set_attributes(reader, e)?;
                     }
                     b"c:firstSliceAng" => {
-                        self.first_slice_angle.set_attributes(reader, e);
+                        self.first_slice_angle.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:pieChart" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:pieChart")
+            Event::Eof => return Err(XlsxError::missing_end_element("c:pieChart", reader.buffer_position()))
         );
     }
 
@@ -34,6 +34,7 @@ use super::{
     ValueAxis,
 };
 use crate::{
+    XlsxError,
     drawing::charts::DateAxis,
     structs::Workbook,
     traits::AdjustmentCoordinateWithSheet,
@@ -760,111 +761,111 @@ impl PlotArea {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().0 {
                 b"c:layout" => {
-                    self.layout.set_attributes(reader, e, false);
+                    self.layout.set_attributes(reader, e, false)?;
                 }
                 b"c:lineChart" => {
                     let mut obj = LineChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_line_chart(obj);
                 }
                 b"c:line3DChart" => {
                     let mut obj = Line3DChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_line_3d_chart(obj);
                 }
                 b"c:pieChart" => {
                     let mut obj = PieChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_pie_chart(obj);
                 }
                 b"c:pie3DChart" => {
                     let mut obj = Pie3DChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_pie_3d_chart(obj);
                 }
                 b"c:doughnutChart" => {
                     let mut obj = DoughnutChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_doughnut_chart(obj);
                 }
                 b"c:scatterChart" => {
                     let mut obj = ScatterChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_scatter_chart(obj);
                 }
                 b"c:barChart" => {
                     let mut obj = BarChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_bar_chart(obj);
                 }
                 b"c:bar3DChart" => {
                     let mut obj = Bar3DChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_bar_3d_chart(obj);
                 }
                 b"c:radarChart" => {
                     let mut obj = RadarChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_radar_chart(obj);
                 }
                 b"c:bubbleChart" => {
                     let mut obj = BubbleChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_bubble_chart(obj);
                 }
                 b"c:areaChart" => {
                     let mut obj = AreaChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_area_chart(obj);
                 }
                 b"c:area3DChart" => {
                     let mut obj = Area3DChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_area_3d_chart(obj);
                 }
                 b"c:ofPieChart" => {
                     let mut obj = OfPieChart::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_of_pie_chart(obj);
                 }
                 b"c:catAx" => {
                     let mut obj = CategoryAxis::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_category_axis(obj);
                 }
                 b"c:dateAx" => {
                     let mut obj = DateAxis::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_date_axis(obj);
                 }
                 b"c:valAx" => {
                     let mut obj = ValueAxis::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_value_axis(obj);
                 }
                 b"c:serAx" => {
                     let mut obj = SeriesAxis::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_series_axis(obj);
                 }
                 b"c:spPr" => {
                     let mut obj = ShapeProperties::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_shape_properties(obj);
                 }
                 _ => (),
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:plotArea" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:plotArea"),
+            Event::Eof => return Err(XlsxError::missing_end_element("c:plotArea", reader.buffer_position())),
         );
     }
 
@@ -16,6 +16,7 @@ use super::{
     PageSetup,
 };
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -110,7 +111,7 @@ impl PrintSettings {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
@@ -126,15 +127,15 @@ impl PrintSettings {
             },
             Event::Empty(ref e) => {
                 if e.name().0 == b"c:pageMargins" {
-                    self.page_margins.set_attributes(reader, e);
+                    self.page_margins.set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:printSettings" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:printSettings")
+            Event::Eof => return Err(XlsxError::missing_end_element("c:printSettings", reader.buffer_position()))
         );
     }
 
@@ -16,6 +16,7 @@ use super::super::{
     Paragraph,
 };
 use crate::{
+    XlsxError,
     drawing::Run,
     writer::driver::{
         write_end_tag,
@@ -119,18 +120,18 @@ impl RichText {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().0 {
                 b"a:p" => {
                     let mut paragraph = Paragraph::default();
-                    paragraph.set_attributes(reader, e);
+                    paragraph.set_attributes(reader, e)?;
                     self.add_paragraph(paragraph);
                 }
                 b"a:bodyPr" => {
                     let mut body_properties = BodyProperties::default();
-                    body_properties.set_attributes(reader, e, false);
+                    body_properties.set_attributes(reader, e, false)?;
                     self.set_body_properties(body_properties);
                 }
                 _ => (),
@@ -138,16 +139,16 @@ impl RichText {
             Event::Empty(ref e) => {
                 if e.name().0 == b"a:bodyPr" {
                     let mut body_properties = BodyProperties::default();
-                    body_properties.set_attributes(reader, e, true);
+                    body_properties.set_attributes(reader, e, true)?;
                     self.set_body_properties(body_properties);
                 }
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:rich" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:rich"),
+            Event::Eof => return Err(XlsxError::missing_end_element("c:rich", reader.buffer_position())),
         );
     }
 
@@ -12,6 +12,7 @@ use quick_xml::{
 
 use super::Orientation;
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -54,20 +55,20 @@ impl Scaling {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().0 == b"c:orientation" {
-                    self.orientation.set_attributes(reader, e);
+                    self.orientation.set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:scaling" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:scaling")
+            Event::Eof => return Err(XlsxError::missing_end_element("c:scaling", reader.buffer_position()))
         );
     }
 
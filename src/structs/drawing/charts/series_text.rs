@@ -11,6 +11,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     structs::StringValue,
     writer::driver::{
@@ -46,7 +47,7 @@ impl SeriesText {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Text(e) => {
@@ -54,10 +55,10 @@ impl SeriesText {
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:tx" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:tx")
+            Event::Eof => return Err(XlsxError::missing_end_element("c:tx", reader.buffer_position()))
         );
     }
 
@@ -12,6 +12,7 @@ use quick_xml::{
 
 use crate::{
     CellValue,
+    XlsxError,
     drawing::charts::Formula,
     reader::driver::xml_read_loop,
     structs::Workbook,
@@ -26,15 +27,15 @@ use crate::{
 pub struct StringCache {}
 
 impl StringCache {
-    pub(crate) fn set_attributes<R: std::io::BufRead>(reader: &mut Reader<R>, _e: &BytesStart) {
+    pub(crate) fn set_attributes<R: std::io::BufRead>(reader: &mut Reader<R>, _e: &BytesStart) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::End(ref e) => {
                 if e.name().0 == b"c:strCache" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:strCache")
+            Event::Eof => return Err(XlsxError::missing_end_element("c:strCache", reader.buffer_position()))
         );
     }
 
@@ -12,6 +12,7 @@ use quick_xml::{
 
 use super::StringPoint;
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -54,22 +55,22 @@ impl StringLiteral {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().0 == b"c:pt" {
                     let mut obj = StringPoint::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_string_point_list(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:strLit" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:strLit")
+            Event::Eof => return Err(XlsxError::missing_end_element("c:strLit", reader.buffer_position()))
         );
     }
 
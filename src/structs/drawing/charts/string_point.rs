@@ -12,6 +12,7 @@ use quick_xml::{
 // c:pt
 use super::NumericValue;
 use crate::{
+    XlsxError,
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -54,20 +55,20 @@ impl StringPoint {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().0 == b"c:v" {
-                    self.numeric_value.set_attributes(reader, e);
+                    self.numeric_value.set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:pt" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:pt"),
+            Event::Eof => return Err(XlsxError::missing_end_element("c:pt", reader.buffer_position())),
         );
     }
 
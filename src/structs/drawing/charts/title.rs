@@ -17,6 +17,7 @@ use super::{
 };
 use crate::{
     Workbook,
+    XlsxError,
     drawing::charts::ShapeProperties,
     writer::driver::{
         write_end_tag,
@@ -138,44 +139,44 @@ impl Title {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().0 {
                 b"c:tx" => {
                     let mut obj = ChartText::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_chart_text(obj);
                 }
                 b"c:layout" => {
                     let mut obj = Layout::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.set_layout(obj);
                 }
                 b"c:spPr" => {
                     let mut obj = ShapeProperties::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_shape_properties(obj);
                 }
                 _ => (),
             },
             Event::Empty(ref e) => match e.name().0 {
                 b"c:overlay" => {
-                    self.overlay.set_attributes(reader, e);
+                    self.overlay.set_attributes(reader, e)?;
                 }
                 b"c:layout" => {
                     let mut obj = Layout::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_layout(obj);
                 }
                 _ => (),
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:title" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:title"),
+            Event::Eof => return Err(XlsxError::missing_end_element("c:title", reader.buffer_position())),
         );
     }
 
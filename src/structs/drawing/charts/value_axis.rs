@@ -29,6 +29,7 @@ use super::{
 };
 use crate::{
     Workbook,
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -435,79 +436,79 @@ impl ValueAxis {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => match e.name().0 {
                 b"c:scaling" => {
-                    self.scaling.set_attributes(reader, e);
+                    self.scaling.set_attributes(reader, e)?;
                 }
                 b"c:title" => {
                     let mut obj = Title::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_title(obj);
                 }
                 b"c:spPr" => {
                     let mut obj = ShapeProperties::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_shape_properties(obj);
                 }
                 b"c:txPr" => {
                     let mut obj = TextProperties::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_text_properties(obj);
                 }
                 b"c:majorGridlines" => {
                     let mut obj = MajorGridlines::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.set_major_gridlines(obj);
                 }
                 _ => (),
             },
             Event::Empty(ref e) => match e.name().0 {
                 b"c:axId" => {
-                    self.axis_id.set_attributes(reader, e);
+                    self.axis_id.set_attributes(reader, e)?;
                 }
                 b"c:delete" => {
-                    self.delete.set_attributes(reader, e);
+                    self.delete.set_attributes(reader, e)?;
                 }
                 b"c:axPos" => {
-                    self.axis_position.set_attributes(reader, e);
+                    self.axis_position.set_attributes(reader, e)?;
                 }
                 b"c:majorGridlines" => {
                     let mut obj = MajorGridlines::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_major_gridlines(obj);
                 }
                 b"c:numFmt" => {
-                    self.numbering_format.set_attributes(reader, e);
+                    self.numbering_format.set_attributes(reader, e)?;
                 }
                 b"c:majorTickMark" => {
-                    self.major_tick_mark.set_attributes(reader, e);
+                    self.major_tick_mark.set_attributes(reader, e)?;
                 }
                 b"c:minorTickMark" => {
-                    self.minor_tick_mark.set_attributes(reader, e);
+                    self.minor_tick_mark.set_attributes(reader, e)?;
                 }
                 b"c:tickLblPos" => {
-                    self.tick_label_position.set_attributes(reader, e);
+                    self.tick_label_position.set_attributes(reader, e)?;
                 }
                 b"c:crossAx" => {
-                    self.crossing_axis.set_attributes(reader, e);
+                    self.crossing_axis.set_attributes(reader, e)?;
                 }
                 b"c:crosses" => {
-                    self.crosses.set_attributes(reader, e);
+                    self.crosses.set_attributes(reader, e)?;
                 }
                 b"c:crossBetween" => {
-                    self.cross_between.set_attributes(reader, e);
+                    self.cross_between.set_attributes(reader, e)?;
                 }
                 _ => (),
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:valAx" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:valAx")
+            Event::Eof => return Err(XlsxError::missing_end_element("c:valAx", reader.buffer_position()))
         );
     }
 
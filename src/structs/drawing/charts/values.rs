@@ -12,6 +12,7 @@ use quick_xml::{
 // c:val
 use super::NumberReference;
 use crate::{
+    XlsxError,
     structs::Workbook,
     writer::driver::{
         write_end_tag,
@@ -55,20 +56,20 @@ impl Values {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().0 == b"c:numRef" {
-                    self.number_reference.set_attributes(reader, e);
+                    self.number_reference.set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:val" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:val"),
+            Event::Eof => return Err(XlsxError::missing_end_element("c:val", reader.buffer_position())),
         );
     }
 
@@ -17,6 +17,7 @@ use super::{
     RotateY,
 };
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -137,29 +138,29 @@ impl View3D {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 match e.name().0 {
                     b"c:rotX" => {
                         let mut obj = RotateX::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_rotate_x(obj);
                     }
                     b"c:rotY" => {
                         let mut obj = RotateY::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_rotate_y(obj);
                     }
                     b"c:rAngAx" => {
                         let mut obj = RightAngleAxes::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_right_angle_axes(obj);
                     }
                     b"c:perspective" => {
                         let mut obj = Perspective::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_perspective(obj);
                     }
                     _ => (),
@@ -167,10 +168,10 @@ impl View3D {
             },
             Event::End(ref e) => {
                 if e.name().0 == b"c:view3D" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "c:view3D")
+            Event::Eof => return Err(XlsxError::missing_end_element("c:view3D", reader.buffer_position()))
         );
     }
 
@@ -14,6 +14,7 @@ use super::{
     SystemColor,
 };
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -111,19 +112,19 @@ impl Color2Type {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                 b"a:srgbClr" => {
                     let mut obj = RgbColorModelHex::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.rgb_color_model_hex = Some(Box::new(obj));
                 }
                 b"a:sysClr" => {
                     let mut obj = SystemColor::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.system_color = Some(Box::new(obj));
                 }
                 _ => (),
@@ -133,12 +134,12 @@ impl Color2Type {
                 match e.name().into_inner() {
                 b"a:srgbClr" => {
                     let mut obj = RgbColorModelHex::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.rgb_color_model_hex = Some(Box::new(obj));
                 }
                 b"a:sysClr" => {
                     let mut obj = SystemColor::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.system_color = Some(Box::new(obj));
                 }
                 _ => (),
@@ -157,11 +158,11 @@ impl Color2Type {
                     b"a:folHlink" |
                     b"a:hlink"    |
                     b"a:lt1"      |
-                    b"a:lt2"      => return,
+                    b"a:lt2"      => return Ok(()),
                     _             => (),
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "Color2Type")
+            Event::Eof => return Err(XlsxError::missing_end_element("Color2Type", reader.buffer_position()))
         );
     }
 
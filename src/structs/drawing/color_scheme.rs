@@ -13,8 +13,10 @@ use quick_xml::{
 use super::{
     super::StringValue,
     Color2Type,
+    SchemeColorValues,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -449,11 +451,51 @@ impl ColorScheme {
         self.color_map()
     }
 
+    /// Get the color for a scheme color value.
+    /// Background and text colors are the light and dark colors.
+    /// Returns `None` for `PhColor`.
+    #[must_use]
+    pub fn scheme_color(&self, value: &SchemeColorValues) -> Option<&Color2Type> {
+        match value {
+            SchemeColorValues::Light1 | SchemeColorValues::Background1 => Some(&self.lt1),
+            SchemeColorValues::Dark1 | SchemeColorValues::Text1 => Some(&self.dk1),
+            SchemeColorValues::Light2 | SchemeColorValues::Background2 => Some(&self.lt2),
+            SchemeColorValues::Dark2 | SchemeColorValues::Text2 => Some(&self.dk2),
+            SchemeColorValues::Accent1 => Some(&self.accent1),
+            SchemeColorValues::Accent2 => Some(&self.accent2),
+            SchemeColorValues::Accent3 => Some(&self.accent3),
+            SchemeColorValues::Accent4 => Some(&self.accent4),
+            SchemeColorValues::Accent5 => Some(&self.accent5),
+            SchemeColorValues::Accent6 => Some(&self.accent6),
+            SchemeColorValues::Hyperlink => Some(&self.hlink),
+            SchemeColorValues::FollowedHyperlink => Some(&self.fol_hlink),
+            SchemeColorValues::PhColor => None,
+        }
+    }
+
+    pub fn scheme_color_mut(&mut self, value: &SchemeColorValues) -> Option<&mut Color2Type> {
+        match value {
+            SchemeColorValues::Light1 | SchemeColorValues::Background1 => Some(&mut self.lt1),
+            SchemeColorValues::Dark1 | SchemeColorValues::Text1 => Some(&mut self.dk1),
+            SchemeColorValues::Light2 | SchemeColorValues::Background2 => Some(&mut self.lt2),
+            SchemeColorValues::Dark2 | SchemeColorValues::Text2 => Some(&mut self.dk2),
+            SchemeColorValues::Accent1 => Some(&mut self.accent1),
+            SchemeColorValues::Accent2 => Some(&mut self.accent2),
+            SchemeColorValues::Accent3 => Some(&mut self.accent3),
+            SchemeColorValues::Accent4 => Some(&mut self.accent4),
+            SchemeColorValues::Accent5 => Some(&mut self.accent5),
+            SchemeColorValues::Accent6 => Some(&mut self.accent6),
+            SchemeColorValues::Hyperlink => Some(&mut self.hlink),
+            SchemeColorValues::FollowedHyperlink => Some(&mut self.fol_hlink),
+            SchemeColorValues::PhColor => None,
+        }
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, name, "name");
 
         xml_read_loop!(
@@ -461,50 +503,50 @@ impl ColorScheme {
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"a:accent1" => {
-                        self.accent1.set_attributes(reader, e);
+                        self.accent1.set_attributes(reader, e)?;
                     }
                     b"a:accent2" => {
-                        self.accent2.set_attributes(reader, e);
+                        self.accent2.set_attributes(reader, e)?;
                     }
                     b"a:accent3" => {
-                        self.accent3.set_attributes(reader, e);
+                        self.accent3.set_attributes(reader, e)?;
                     }
                     b"a:accent4" => {
-                        self.accent4.set_attributes(reader, e);
+                        self.accent4.set_attributes(reader, e)?;
                     }
                     b"a:accent5" => {
-                        self.accent5.set_attributes(reader, e);
+                        self.accent5.set_attributes(reader, e)?;
                     }
                     b"a:accent6" => {
-                        self.accent6.set_attributes(reader, e);
+                        self.accent6.set_attributes(reader, e)?;
                     }
                     b"a:dk1" => {
-                        self.dk1.set_attributes(reader, e);
+                        self.dk1.set_attributes(reader, e)?;
                     }
                     b"a:dk2" => {
-                        self.dk2.set_attributes(reader, e);
+                        self.dk2.set_attributes(reader, e)?;
                     }
                     b"a:folHlink" => {
-                        self.fol_hlink.set_attributes(reader, e);
+                        self.fol_hlink.set_attributes(reader, e)?;
                     }
                     b"a:hlink" => {
-                        self.hlink.set_attributes(reader, e);
+                        self.hlink.set_attributes(reader, e)?;
                     }
                     b"a:lt1" => {
-                        self.lt1.set_attributes(reader, e);
+                        self.lt1.set_attributes(reader, e)?;
                     }
                     b"a:lt2" => {
-                        self.lt2.set_attributes(reader, e);
+                        self.lt2.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:clrScheme" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:clrScheme")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:clrScheme", reader.buffer_position()))
         );
     }
 
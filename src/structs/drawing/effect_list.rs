@@ -16,6 +16,7 @@ use super::{
     SoftEdge,
 };
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -123,9 +124,9 @@ impl EffectList {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -133,7 +134,7 @@ impl EffectList {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:softEdge" {
                     let mut obj = SoftEdge::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_soft_edge(obj);
                 }
             },
@@ -141,12 +142,12 @@ impl EffectList {
                 match e.name().into_inner() {
                     b"a:glow" => {
                         let mut obj = Glow::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_glow(obj);
                     }
                     b"a:outerShdw" => {
                         let mut obj = OuterShadow::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_outer_shadow(obj);
                     }
                     _ => (),
@@ -154,10 +155,10 @@ impl EffectList {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:effectLst" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:effectLst")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:effectLst", reader.buffer_position()))
         );
     }
 
@@ -11,6 +11,7 @@ use quick_xml::{
 
 use super::EffectStyle;
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -64,22 +65,22 @@ impl EffectStyleList {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"a:effectStyle" {
                     let mut obj = EffectStyle::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.effect_style_collection.push(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:effectStyleLst" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:effectStyleLst")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:effectStyleLst", reader.buffer_position()))
         );
     }
 
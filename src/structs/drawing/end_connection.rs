@@ -9,7 +9,8 @@ use quick_xml::{
 
 use super::super::super::UInt32Value;
 use crate::{
-    reader::driver::get_attribute,
+    XlsxError,
+    reader::driver::get_required_attribute,
     writer::driver::write_start_tag,
 };
 
@@ -58,12 +59,13 @@ impl EndConnection {
     #[inline]
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
-        _reader: &mut Reader<R>,
+        reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        self.id.set_value_string(get_attribute(e, b"id").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.id.set_value_string(get_required_attribute(reader, e, b"id")?);
         self.index
-            .set_value_string(get_attribute(e, b"idx").unwrap());
+            .set_value_string(get_required_attribute(reader, e, b"idx")?);
+        Ok(())
     }
 
     #[inline]
@@ -9,7 +9,8 @@ use quick_xml::{
 
 use super::super::super::Int64Value;
 use crate::{
-    reader::driver::get_attribute,
+    XlsxError,
+    reader::driver::get_required_attribute,
     writer::driver::write_start_tag,
 };
 
@@ -60,11 +61,12 @@ impl Extents {
     #[inline]
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
-        _reader: &mut Reader<R>,
+        reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        self.cx.set_value_string(get_attribute(e, b"cx").unwrap());
-        self.cy.set_value_string(get_attribute(e, b"cy").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.cx.set_value_string(get_required_attribute(reader, e, b"cx")?);
+        self.cy.set_value_string(get_required_attribute(reader, e, b"cy")?);
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -15,6 +15,7 @@ use super::{
     FontCollectionType,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         xml_read_loop,
@@ -116,7 +117,7 @@ impl FontScheme {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(v) = get_attribute(e, b"name") {
             self.name.set_value(v);
         }
@@ -126,20 +127,20 @@ impl FontScheme {
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                 b"a:majorFont" => {
-                    self.major_font.set_attributes(reader, e);
+                    self.major_font.set_attributes(reader, e)?;
                 }
                 b"a:minorFont" => {
-                    self.minor_font.set_attributes(reader, e);
+                    self.minor_font.set_attributes(reader, e)?;
                 }
                 _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:fontScheme" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:fontScheme")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:fontScheme", reader.buffer_position()))
         );
     }
 
@@ -12,6 +12,7 @@ use quick_xml::{
 
 use super::SchemeColor;
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -59,25 +60,25 @@ impl ForegroundColor {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"a:schemeClr" {
-                    self.scheme_color.set_attributes(reader, e, false);
+                    self.scheme_color.set_attributes(reader, e, false)?;
                 }
             },
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:schemeClr" {
-                    self.scheme_color.set_attributes(reader, e, true);
+                    self.scheme_color.set_attributes(reader, e, true)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:fgClr" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:fgClr")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:fgClr", reader.buffer_position()))
         );
     }
 
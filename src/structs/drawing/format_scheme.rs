@@ -18,6 +18,7 @@ use super::{
     LineStyleList,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         xml_read_loop,
@@ -177,7 +178,7 @@ impl FormatScheme {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(v) = get_attribute(e, b"name") {
             self.name.set_value(v);
         }
@@ -188,22 +189,22 @@ impl FormatScheme {
                 match e.name().into_inner() {
                     b"a:fillStyleLst" => {
                         let mut obj = FillStyleList::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.fill_style_list = obj;
                     }
                     b"a:lnStyleLst" => {
                         let mut obj = LineStyleList::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.line_style_list = obj;
                     }
                     b"a:effectStyleLst" => {
                         let mut obj = EffectStyleList::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.effect_style_list = obj;
                     }
                     b"a:bgFillStyleLst" => {
                         let mut obj = BackgroundFillStyleList::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.background_fill_style_list = obj;
                     }
                     _ => (),
@@ -211,10 +212,10 @@ impl FormatScheme {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:fmtScheme" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:fmtScheme")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:fmtScheme", reader.buffer_position()))
         );
     }
 
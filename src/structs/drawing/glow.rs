@@ -15,8 +15,9 @@ use super::{
     SchemeColor,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
-        get_attribute,
+        get_required_attribute,
         xml_read_loop,
     },
     writer::driver::{
@@ -73,25 +74,25 @@ impl Glow {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         self.radius
-            .set_value_string(get_attribute(e, b"rad").unwrap());
+            .set_value_string(get_required_attribute(reader, e, b"rad")?);
 
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"a:schemeClr" {
                     let mut obj = SchemeColor::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.set_scheme_color(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:glow" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:glow")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:glow", reader.buffer_position()))
         );
     }
 
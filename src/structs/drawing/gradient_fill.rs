@@ -21,6 +21,7 @@ use super::{
     TileRectangle,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -174,7 +175,7 @@ impl GradientFill {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, flip, "flip");
         set_string_from_xml!(self, e, rotate_with_shape, "rotWithShape");
 
@@ -184,12 +185,12 @@ impl GradientFill {
                 match e.name().into_inner() {
                 b"a:lin" => {
                     let mut obj = LinearGradientFill::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_linear_gradient_fill(obj);
                 }
                 b"a:tileRect" => {
                     let obj = TileRectangle::default();
-                    TileRectangle::set_attributes(reader, e, true);
+                    TileRectangle::set_attributes(reader, e, true)?;
                     self.set_tile_rectangle(obj);
                 }
                 _ => (),
@@ -199,26 +200,26 @@ impl GradientFill {
                 match e.name().into_inner() {
                 b"a:lin" => {
                     let mut obj = LinearGradientFill::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.set_linear_gradient_fill(obj);
                 }
                 b"a:tileRect" => {
                     let obj = TileRectangle::default();
-                    TileRectangle::set_attributes(reader, e, false);
+                    TileRectangle::set_attributes(reader, e, false)?;
                     self.set_tile_rectangle(obj);
                 }
                 b"a:gsLst" => {
-                    self.gradient_stop_list.set_attributes(reader, e);
+                    self.gradient_stop_list.set_attributes(reader, e)?;
                 }
                 _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:gradFill" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:gradFill")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:gradFill", reader.buffer_position()))
         );
     }
 
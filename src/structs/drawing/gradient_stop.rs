@@ -15,9 +15,11 @@ use super::{
     SchemeColor,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         xml_read_loop,
+        parse_value,
     },
     writer::driver::{
         write_end_tag,
@@ -116,9 +118,9 @@ impl GradientStop {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(v) = get_attribute(e, b"pos") {
-            self.set_position(v.parse::<i32>().unwrap());
+            self.set_position(parse_value(reader, &v)?);
         }
 
         xml_read_loop!(
@@ -127,12 +129,12 @@ impl GradientStop {
                 match e.name().into_inner() {
                     b"a:schemeClr" => {
                         let mut obj = SchemeColor::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_scheme_color(obj);
                     }
                     b"a:srgbClr" => {
                         let mut obj = RgbColorModelHex::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_rgb_color_model_hex(obj);
                     }
                     _ => (),
@@ -142,12 +144,12 @@ impl GradientStop {
                 match e.name().into_inner() {
                     b"a:schemeClr" => {
                         let mut obj = SchemeColor::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_scheme_color(obj);
                     }
                     b"a:srgbClr" => {
                         let mut obj = RgbColorModelHex::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_rgb_color_model_hex(obj);
                     }
                     _ => (),
@@ -155,10 +157,10 @@ impl GradientStop {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:gs" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:gs")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:gs", reader.buffer_position()))
         );
     }
 
@@ -12,6 +12,7 @@ use quick_xml::{
 
 use super::GradientStop;
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -68,22 +69,22 @@ impl GradientStopList {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"a:gs" {
                     let mut obj = GradientStop::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.add_gradient_stop(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:gsLst" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:gsLst")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:gsLst", reader.buffer_position()))
         );
     }
 
@@ -12,6 +12,7 @@ use quick_xml::{
 
 use super::GraphicData;
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     structs::raw::RawRelationships,
     traits::AdjustmentCoordinateWithSheet,
@@ -62,21 +63,21 @@ impl Graphic {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"a:graphicData" {
                     self.graphic_data
-                        .set_attributes(reader, e, drawing_relationships);
+                        .set_attributes(reader, e, drawing_relationships)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:graphic" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:graphic")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:graphic", reader.buffer_position()))
         );
     }
 
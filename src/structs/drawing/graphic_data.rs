@@ -12,13 +12,14 @@ use quick_xml::{
 
 use super::charts::ChartSpace;
 use crate::{
+    XlsxError,
     helper::const_str::{
         DRAWINGML_CHART_NS,
         REL_OFC_NS,
     },
     reader::{
         driver::{
-            get_attribute,
+            get_required_attribute,
             xml_read_loop,
         },
         xlsx::chart,
@@ -72,24 +73,28 @@ impl GraphicData {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"c:chart" {
-                    let chart_id = get_attribute(e, b"r:id").unwrap();
+                    let chart_id = get_required_attribute(reader, e, b"r:id")?;
                     let relationship = drawing_relationships
-                        .unwrap()
-                        .relationship_by_rid(&chart_id);
-                    chart::read(relationship.raw_file(), &mut self.chart_space);
+                        .and_then(|v| v.relationship_by_rid(&chart_id))
+                        .ok_or_else(|| {
+                            XlsxError::missing_relationship(&chart_id, reader.buffer_position())
+                        })?;
+                    let raw_file = relationship.raw_file();
+                    chart::read(raw_file, &mut self.chart_space)
+                        .map_err(|e| raw_file.parse_error_context(e))?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:graphicData" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:graphicData")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:graphicData", reader.buffer_position()))
         );
     }
 
@@ -17,6 +17,7 @@ use super::{
     Rotation,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -109,12 +110,12 @@ impl LightRig {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, rig, "rig");
         set_string_from_xml!(self, e, definition, "dir");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -128,10 +129,10 @@ impl LightRig {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:lightRig" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:lightRig")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:lightRig", reader.buffer_position()))
         );
     }
 
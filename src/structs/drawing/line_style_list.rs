@@ -11,6 +11,7 @@ use quick_xml::{
 
 use super::Outline;
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -64,22 +65,22 @@ impl LineStyleList {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"a:ln" {
                     let mut obj = Outline::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.outline_collection.push(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:lnStyleLst" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "lnStyleLst")
+            Event::Eof => return Err(XlsxError::missing_end_element("lnStyleLst", reader.buffer_position()))
         );
     }
 
@@ -15,6 +15,7 @@ use super::super::super::{
     Int32Value,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -74,22 +75,22 @@ impl LinearGradientFill {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, angle, "ang");
         set_string_from_xml!(self, e, scaled, "scaled");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:lin" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:lin")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:lin", reader.buffer_position()))
         );
     }
 
@@ -12,6 +12,7 @@ use quick_xml::{
 
 use crate::{
     Int32Value,
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -51,21 +52,21 @@ impl Miter {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, limit, "lim");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:miter" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:miter")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:miter", reader.buffer_position()))
         );
     }
 
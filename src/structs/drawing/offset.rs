@@ -9,7 +9,8 @@ use quick_xml::{
 
 use super::super::super::Int64Value;
 use crate::{
-    reader::driver::get_attribute,
+    XlsxError,
+    reader::driver::get_required_attribute,
     writer::driver::write_start_tag,
 };
 
@@ -58,11 +59,12 @@ impl Offset {
     #[inline]
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
-        _reader: &mut Reader<R>,
+        reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        self.x.set_value_string(get_attribute(e, b"x").unwrap());
-        self.y.set_value_string(get_attribute(e, b"y").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.x.set_value_string(get_required_attribute(reader, e, b"x")?);
+        self.y.set_value_string(get_required_attribute(reader, e, b"y")?);
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -17,6 +17,7 @@ use super::{
 };
 use crate::{
     StringValue,
+    XlsxError,
     reader::driver::{
         get_attribute,
         xml_read_loop,
@@ -267,7 +268,7 @@ impl OuterShadow {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(v) = get_attribute(e, b"blurRad") {
             self.set_blur_radius(v);
         }
@@ -296,12 +297,12 @@ impl OuterShadow {
                 match e.name().into_inner() {
                     b"a:schemeClr" => {
                         let mut obj = SchemeColor::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_scheme_color(obj);
                     }
                     b"a:srgbClr" => {
                         let mut obj = RgbColorModelHex::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_rgb_color_model_hex(obj);
                     }
                     _ => (),
@@ -311,17 +312,17 @@ impl OuterShadow {
                 match e.name().into_inner() {
                     b"a:prstClr" => {
                         let mut obj = PresetColor::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_preset_color(obj);
                     }
                     b"a:schemeClr" => {
                         let mut obj = SchemeColor::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_scheme_color(obj);
                     }
                     b"a:srgbClr" => {
                         let mut obj = RgbColorModelHex::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_rgb_color_model_hex(obj);
                     }
                     _ => (),
@@ -329,10 +330,10 @@ impl OuterShadow {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:outerShdw" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:outerShdw")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:outerShdw", reader.buffer_position()))
         );
     }
 
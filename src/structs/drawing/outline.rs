@@ -23,11 +23,13 @@ use super::{
 };
 use crate::{
     StringValue,
+    XlsxError,
     drawing::SystemColor,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
         xml_read_loop,
+        parse_value,
     },
     structs::{
         EnumValue,
@@ -405,9 +407,9 @@ impl Outline {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(v) = get_attribute(e, b"w") {
-            self.set_width(v.parse::<u32>().unwrap());
+            self.set_width(parse_value(reader, &v)?);
         }
 
         if let Some(v) = get_attribute(e, b"cap") {
@@ -426,47 +428,47 @@ impl Outline {
                 match e.name().into_inner() {
                     b"a:solidFill" => {
                         let mut solid_fill = SolidFill::default();
-                        solid_fill.set_attributes(reader, e);
+                        solid_fill.set_attributes(reader, e)?;
                         self.set_solid_fill(solid_fill);
                     }
                     b"a:gradFill" => {
                         let mut obj = GradientFill::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_gradient_fill(obj);
                     }
                     b"a:tailEnd" => {
                         let mut obj = TailEnd::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_tail_end(obj);
                     }
                     b"a:noFill" => {
                         let obj = NoFill::default();
-                        NoFill::set_attributes(reader, e, false);
+                        NoFill::set_attributes(reader, e, false)?;
                         self.set_no_fill(obj);
                     }
                     b"a:bevel" => {
                         let obj = Bevel::default();
-                        Bevel::set_attributes(reader, e, false);
+                        Bevel::set_attributes(reader, e, false)?;
                         self.set_bevel(obj);
                     }
                     b"a:miter" => {
                         let mut obj = Miter::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_miter(obj);
                     }
                     b"a:prstDash" => {
                         let mut obj = PresetDash::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_preset_dash(obj);
                     }
                     b"a:round" => {
                         let obj = Round::default();
-                        Round::set_attributes(reader, e, false);
+                        Round::set_attributes(reader, e, false)?;
                         self.set_round(obj);
                     }
                     b"a:sysClr" => {
                         let mut obj = SystemColor::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_system_color(obj);
                     }
                     _ => (),
@@ -476,37 +478,37 @@ impl Outline {
                 match e.name().into_inner() {
                     b"a:tailEnd" => {
                         let mut obj = TailEnd::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_tail_end(obj);
                     }
                     b"a:noFill" => {
                         let obj = NoFill::default();
-                        NoFill::set_attributes(reader, e, true);
+                        NoFill::set_attributes(reader, e, true)?;
                         self.set_no_fill(obj);
                     }
                     b"a:bevel" => {
                         let obj = Bevel::default();
-                        Bevel::set_attributes(reader, e, true);
+                        Bevel::set_attributes(reader, e, true)?;
                         self.set_bevel(obj);
                     }
                     b"a:miter" => {
                         let mut obj = Miter::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_miter(obj);
                     }
                     b"a:prstDash" => {
                         let mut obj = PresetDash::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_preset_dash(obj);
                     }
                     b"a:round" => {
                         let obj = Round::default();
-                        Round::set_attributes(reader, e, true);
+                        Round::set_attributes(reader, e, true)?;
                         self.set_round(obj);
                     }
                     b"a:sysClr" => {
                         let mut obj = SystemColor::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_system_color(obj);
                     }
                     _ => (),
@@ -514,10 +516,10 @@ impl Outline {
             },
             Event::End(ref e) => {
                 if  e.name().into_inner() == b"a:ln" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:ln")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:ln", reader.buffer_position()))
         );
     }
 
@@ -18,6 +18,7 @@ use super::{
 };
 use crate::{
     StringValue,
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -141,14 +142,14 @@ impl ParagraphProperties {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(v) = get_attribute(e, b"rtl") {
             self.set_right_to_left(v);
         }
         set_string_from_xml!(self, e, alignment, "algn");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -157,12 +158,12 @@ impl ParagraphProperties {
                 match e.name().into_inner() {
                 b"a:defRPr" => {
                     let mut obj = RunProperties::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.set_default_run_properties(obj);
                 }
                 b"a:lnSpc" => {
                     let mut obj = LineSpacing::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_line_spacing(obj);
                 }
                 _ => (),
@@ -171,16 +172,16 @@ impl ParagraphProperties {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:defRPr" {
                     let mut obj = RunProperties::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_default_run_properties(obj);
                 }
             },
             Event::End(ref e) => {
                 if  e.name().into_inner() == b"a:pPr" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:pPr")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:pPr", reader.buffer_position()))
         );
     }
 
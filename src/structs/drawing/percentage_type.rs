@@ -11,6 +11,7 @@ use quick_xml::{
 
 use super::super::Int32Value;
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -50,33 +51,33 @@ impl PercentageType {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, val, "val");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:lum" {
-                    return;
+                    return Ok(());
                 }
                 if e.name().into_inner() == b"a:lumMod" {
-                    return;
+                    return Ok(());
                 }
                 if e.name().into_inner() == b"a:lumOff" {
-                    return;
+                    return Ok(());
                 }
                 if e.name().into_inner() == b"a:sat" {
-                    return;
+                    return Ok(());
                 }
                 if e.name().into_inner() == b"a:satMod" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:lum,a:lumMod,a:lumOff,a:sat,a:satMod")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:lum,a:lumMod,a:lumOff,a:sat,a:satMod", reader.buffer_position()))
         );
     }
 
@@ -12,6 +12,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -70,26 +71,23 @@ impl Point2DType {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, x, "x");
         set_string_from_xml!(self, e, y, "y");
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::End(ref e) => {
                 match e.name().into_inner() {
-                    b"a:chOff" | b"a:off" => return,
+                    b"a:chOff" | b"a:off" => return Ok(()),
                     _ => (),
                 }
             },
-            Event::Eof => panic!(
-                "Error: Could not find {} end element",
-                "a:off,a:chOff"
-            )
+            Event::Eof => return Err(XlsxError::missing_end_element("a:off,a:chOff", reader.buffer_position()))
         );
     }
 
@@ -11,6 +11,7 @@ use quick_xml::{
 
 use super::super::Int32Value;
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -50,27 +51,27 @@ impl PositiveFixedPercentageType {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, val, "val");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:shade" {
-                    return;
+                    return Ok(());
                 }
                 if e.name().into_inner() == b"a:alpha" {
-                    return;
+                    return Ok(());
                 }
                 if e.name().into_inner() == b"a:tint" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:shade,a:alpha,a:tint")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:shade,a:alpha,a:tint", reader.buffer_position()))
         );
     }
 
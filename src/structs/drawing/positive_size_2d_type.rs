@@ -12,6 +12,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -70,26 +71,23 @@ impl PositiveSize2DType {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, cx, "cx");
         set_string_from_xml!(self, e, cy, "cy");
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::End(ref e) => {
                 match e.name().into_inner() {
-                    b"a:chExt" | b"a:ext" => return,
+                    b"a:chExt" | b"a:ext" => return Ok(()),
                     _ => (),
                 }
             },
-            Event::Eof => panic!(
-                "Error: Could not find {} end element",
-                "a:ext,a:chExt"
-            )
+            Event::Eof => return Err(XlsxError::missing_end_element("a:ext,a:chExt", reader.buffer_position()))
         );
     }
 
@@ -12,8 +12,9 @@ use quick_xml::{
 
 use super::alpha::Alpha;
 use crate::{
+    XlsxError,
     reader::driver::{
-        get_attribute,
+        get_required_attribute,
         xml_read_loop,
     },
     writer::driver::{
@@ -80,24 +81,24 @@ impl PresetColor {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        self.set_val(get_attribute(e, b"val").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.set_val(get_required_attribute(reader, e, b"val")?);
 
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:alpha" {
                     let mut alpha = Alpha::default();
-                    alpha.set_attributes(reader, e);
+                    alpha.set_attributes(reader, e)?;
                     self.set_alpha(alpha);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:prstClr" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:prstClr")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:prstClr", reader.buffer_position()))
         );
     }
 
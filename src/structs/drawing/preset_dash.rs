@@ -15,8 +15,9 @@ use super::{
     PresetLineDashValues,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
-        get_attribute,
+        get_required_attribute,
         xml_read_loop,
     },
     writer::driver::write_start_tag,
@@ -52,21 +53,21 @@ impl PresetDash {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
-        self.val.set_value_string(get_attribute(e, b"val").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.val.set_value_string(get_required_attribute(reader, e, b"val")?);
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:prstDash" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:prstDash")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:prstDash", reader.buffer_position()))
         );
     }
 
@@ -12,8 +12,9 @@ use quick_xml::{
 
 use super::adjust_value_list::AdjustValueList;
 use crate::{
+    XlsxError,
     reader::driver::{
-        get_attribute,
+        get_required_attribute,
         xml_read_loop,
     },
     writer::driver::{
@@ -271,22 +272,22 @@ impl PresetGeometry {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        self.set_geometry(get_attribute(e, b"prst").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.set_geometry(get_required_attribute(reader, e, b"prst")?);
 
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"a:avLst" {
-                    self.adjust_value_list_mut().set_attributes(reader, e);
+                    self.adjust_value_list_mut().set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:prstGeom" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:prstGeom")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:prstGeom", reader.buffer_position()))
         );
     }
 
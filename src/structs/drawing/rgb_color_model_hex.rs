@@ -16,8 +16,9 @@ use super::{
     PositiveFixedPercentageType,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
-        get_attribute,
+        get_required_attribute,
         xml_read_loop,
     },
     writer::driver::{
@@ -307,11 +308,11 @@ impl RgbColorModelHex {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
-        self.val.set_value_string(get_attribute(e, b"val").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.val.set_value_string(get_required_attribute(reader, e, b"val")?);
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -320,42 +321,42 @@ impl RgbColorModelHex {
                 match e.name().into_inner() {
                     b"a:lum" => {
                         let mut obj = PercentageType::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.luminance = Some(obj);
                     }
                     b"a:lumMod" => {
                         let mut obj = PercentageType::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.luminance_modulation = Some(obj);
                     }
                     b"a:lumOff" => {
                         let mut obj = PercentageType::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.luminance_offset = Some(obj);
                     }
                     b"a:sat" => {
                         let mut obj = PercentageType::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.saturation = Some(obj);
                     }
                     b"a:satMod" => {
                         let mut obj = PercentageType::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.saturation_modulation = Some(obj);
                     }
                     b"a:shade" => {
                         let mut obj = PositiveFixedPercentageType::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.shade = Some(obj);
                     }
                     b"a:alpha" => {
                         let mut obj = PositiveFixedPercentageType::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.alpha = Some(obj);
                     }
                     b"a:tint" => {
                         let mut obj = PositiveFixedPercentageType::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.tint = Some(obj);
                     }
                     _ => (),
@@ -365,42 +366,42 @@ impl RgbColorModelHex {
                 match e.name().into_inner() {
                     b"a:lum" => {
                         let mut obj = PercentageType::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.luminance = Some(obj);
                     }
                     b"a:lumMod" => {
                         let mut obj = PercentageType::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.luminance_modulation = Some(obj);
                     }
                     b"a:lumOff" => {
                         let mut obj = PercentageType::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.luminance_offset = Some(obj);
                     }
                     b"a:sat" => {
                         let mut obj = PercentageType::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.saturation = Some(obj);
                     }
                     b"a:satMod" => {
                         let mut obj = PercentageType::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.saturation_modulation = Some(obj);
                     }
                     b"a:shade" => {
                         let mut obj = PositiveFixedPercentageType::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.shade = Some(obj);
                     }
                     b"a:alpha" => {
                         let mut obj = PositiveFixedPercentageType::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.alpha = Some(obj);
                     }
                     b"a:tint" => {
                         let mut obj = PositiveFixedPercentageType::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.tint = Some(obj);
                     }
                     _ => (),
@@ -408,10 +409,10 @@ impl RgbColorModelHex {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:srgbClr" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:srgbClr")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:srgbClr", reader.buffer_position()))
         );
     }
 
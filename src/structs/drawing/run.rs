@@ -11,6 +11,7 @@ use quick_xml::{
 
 use super::run_properties::RunProperties;
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -77,17 +78,17 @@ impl Run {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().0 == b"a:rPr" {
-                    self.run_properties.set_attributes(reader, e, false);
+                    self.run_properties.set_attributes(reader, e, false)?;
                 }
             },
             Event::Empty(ref e) => {
                 if e.name().0 == b"a:rPr" {
-                    self.run_properties.set_attributes(reader, e, true);
+                    self.run_properties.set_attributes(reader, e, true)?;
                 }
             },
             Event::Text(e) => {
@@ -95,10 +96,10 @@ impl Run {
             },
             Event::End(ref e) => {
                 if e.name().0 == b"a:r" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:r")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:r", reader.buffer_position()))
         );
     }
 
@@ -23,6 +23,7 @@ use super::{
     TextFontType,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         xml_read_loop,
@@ -492,7 +493,7 @@ impl RunProperties {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(v) = get_attribute(e, b"kumimoji") {
             self.set_kumimoji(v);
         }
@@ -522,7 +523,7 @@ impl RunProperties {
         }
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -531,22 +532,22 @@ impl RunProperties {
                 match e.name().into_inner() {
                 b"a:solidFill" => {
                     let mut obj = SolidFill::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_solid_fill(obj);
                 }
                 b"a:ln" => {
                     let mut obj = Outline::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_outline(obj);
                 }
                 b"a:gradFill" => {
                     let mut obj = GradientFill::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_gradient_fill(obj);
                 }
                 b"a:effectLst" => {
                     let mut effect_list = EffectList::default();
-                    effect_list.set_attributes(reader, e, false);
+                    effect_list.set_attributes(reader, e, false)?;
                     self.set_effect_list(effect_list);
                 }
                 _ => (),
@@ -556,27 +557,27 @@ impl RunProperties {
                 match e.name().into_inner() {
                 b"a:latin" => {
                     let mut obj = TextFontType::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_latin_font(obj);
                 }
                 b"a:ea" => {
                     let mut obj = TextFontType::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_east_asian_font(obj);
                 }
                 b"a:cs" => {
                     let mut obj = TextFontType::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_complex_script_font(obj);
                 }
                 b"a:noFill" => {
                     let obj = NoFill::default();
-                    NoFill::set_attributes(reader, e, true);
+                    NoFill::set_attributes(reader, e, true)?;
                     self.set_no_fill(obj);
                 }
                 b"a:effectLst" => {
                     let mut obj = EffectList::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_effect_list(obj);
                 }
                 _ => (),
@@ -584,14 +585,11 @@ impl RunProperties {
             },
             Event::End(ref e) => {
                 match e.name().into_inner() {
-                    b"a:rPr" | b"a:endParaRPr" | b"a:defRPr" => return,
+                    b"a:rPr" | b"a:endParaRPr" | b"a:defRPr" => return Ok(()),
                     _ => (),
                 }
             },
-            Event::Eof => panic!(
-                "Error: Could not find {} end element",
-                "a:rPr, a:endParaRPr, a:defRPr"
-            )
+            Event::Eof => return Err(XlsxError::missing_end_element("a:rPr, a:endParaRPr, a:defRPr", reader.buffer_position()))
         );
     }
 
@@ -15,6 +15,7 @@ use super::{
     LightRig,
 };
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -71,19 +72,19 @@ impl Scene3DType {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                     b"a:camera" => {
                         let mut obj = Camera::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_camera(obj);
                     }
                     b"a:lightRig" => {
                         let mut obj = LightRig::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_light_rig(obj);
                     }
                     _ => (),
@@ -93,12 +94,12 @@ impl Scene3DType {
                 match e.name().into_inner() {
                     b"a:camera" => {
                         let mut obj = Camera::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_camera(obj);
                     }
                     b"a:lightRig" => {
                         let mut obj = LightRig::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_light_rig(obj);
                     }
                     _ => (),
@@ -106,10 +107,10 @@ impl Scene3DType {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:scene3d" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:scene3d")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:scene3d", reader.buffer_position()))
         );
     }
 
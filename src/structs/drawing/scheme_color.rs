@@ -17,8 +17,9 @@ use super::{
     SchemeColorValues,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
-        get_attribute,
+        get_required_attribute,
         xml_read_loop,
     },
     writer::driver::{
@@ -309,11 +310,11 @@ impl SchemeColor {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
-        self.val.set_value_string(get_attribute(e, b"val").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.val.set_value_string(get_required_attribute(reader, e, b"val")?);
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -322,42 +323,42 @@ impl SchemeColor {
                 match e.name().into_inner() {
                     b"a:lum" => {
                         let mut obj = PercentageType::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.luminance = Some(obj);
                     }
                     b"a:lumMod" => {
                         let mut obj = PercentageType::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.luminance_modulation = Some(obj);
                     }
                     b"a:lumOff" => {
                         let mut obj = PercentageType::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.luminance_offset = Some(obj);
                     }
                     b"a:sat" => {
                         let mut obj = PercentageType::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.saturation = Some(obj);
                     }
                     b"a:satMod" => {
                         let mut obj = PercentageType::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.saturation_modulation = Some(obj);
                     }
                     b"a:shade" => {
                         let mut obj = PositiveFixedPercentageType::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.shade = Some(obj);
                     }
                     b"a:alpha" => {
                         let mut obj = PositiveFixedPercentageType::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.alpha = Some(obj);
                     }
                     b"a:tint" => {
                         let mut obj = PositiveFixedPercentageType::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.tint = Some(obj);
                     }
                     _ => (),
@@ -367,42 +368,42 @@ impl SchemeColor {
                 match e.name().into_inner() {
                     b"a:lum" => {
                         let mut obj = PercentageType::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.luminance = Some(obj);
                     }
                     b"a:lumMod" => {
                         let mut obj = PercentageType::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.luminance_modulation = Some(obj);
                     }
                     b"a:lumOff" => {
                         let mut obj = PercentageType::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.luminance_offset = Some(obj);
                     }
                     b"a:sat" => {
                         let mut obj = PercentageType::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.saturation = Some(obj);
                     }
                     b"a:satMod" => {
                         let mut obj = PercentageType::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.saturation_modulation = Some(obj);
                     }
                     b"a:shade" => {
                         let mut obj = PositiveFixedPercentageType::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.shade = Some(obj);
                     }
                     b"a:alpha" => {
                         let mut obj = PositiveFixedPercentageType::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.alpha = Some(obj);
                     }
                     b"a:tint" => {
                         let mut obj = PositiveFixedPercentageType::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.tint = Some(obj);
                     }
                     _ => (),
@@ -410,10 +411,10 @@ impl SchemeColor {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:schemeClr" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:schemeClr")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:schemeClr", reader.buffer_position()))
         );
     }
 
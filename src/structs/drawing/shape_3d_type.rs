@@ -17,6 +17,7 @@ use super::{
     PresetMaterialTypeValues,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -117,7 +118,7 @@ impl Shape3DType {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, preset_material, "prstMaterial");
 
         xml_read_loop!(
@@ -139,10 +140,10 @@ impl Shape3DType {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:sp3d" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:sp3d")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:sp3d", reader.buffer_position()))
         );
     }
 
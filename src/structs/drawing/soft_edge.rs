@@ -9,7 +9,8 @@ use quick_xml::{
 
 use super::super::super::Int64Value;
 use crate::{
-    reader::driver::get_attribute,
+    XlsxError,
+    reader::driver::get_required_attribute,
     writer::driver::write_start_tag,
 };
 
@@ -40,11 +41,12 @@ impl SoftEdge {
     #[inline]
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
-        _reader: &mut Reader<R>,
+        reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         self.radius
-            .set_value_string(get_attribute(e, b"rad").unwrap());
+            .set_value_string(get_required_attribute(reader, e, b"rad")?);
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -15,6 +15,7 @@ use super::{
     scheme_color::SchemeColor,
 };
 use crate::{
+    XlsxError,
     drawing::SystemColor,
     reader::driver::xml_read_loop,
     writer::driver::{
@@ -122,24 +123,24 @@ impl SolidFill {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                 b"a:schemeClr" => {
                     let mut scheme_color = SchemeColor::default();
-                    scheme_color.set_attributes(reader, e, false);
+                    scheme_color.set_attributes(reader, e, false)?;
                     self.set_scheme_color(scheme_color);
                 }
                 b"a:srgbClr" => {
                     let mut rgb_color_model_hex = RgbColorModelHex::default();
-                    rgb_color_model_hex.set_attributes(reader, e, false);
+                    rgb_color_model_hex.set_attributes(reader, e, false)?;
                     self.set_rgb_color_model_hex(rgb_color_model_hex);
                 }
                 b"a:sysClr" => {
                     let mut obj = SystemColor::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.set_system_color(obj);
                 }
                 _ => (),
@@ -149,17 +150,17 @@ impl SolidFill {
                 match e.name().into_inner() {
                 b"a:schemeClr" => {
                     let mut scheme_color = SchemeColor::default();
-                    scheme_color.set_attributes(reader, e, true);
+                    scheme_color.set_attributes(reader, e, true)?;
                     self.set_scheme_color(scheme_color);
                 }
                 b"a:srgbClr" => {
                     let mut rgb_color_model_hex = RgbColorModelHex::default();
-                    rgb_color_model_hex.set_attributes(reader, e, true);
+                    rgb_color_model_hex.set_attributes(reader, e, true)?;
                     self.set_rgb_color_model_hex(rgb_color_model_hex);
                 }
                 b"a:sysClr" => {
                     let mut obj = SystemColor::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_system_color(obj);
                 }
                 _ => (),
@@ -167,10 +168,10 @@ impl SolidFill {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:solidFill" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:solidFill")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:solidFill", reader.buffer_position()))
         );
     }
 
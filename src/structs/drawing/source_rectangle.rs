@@ -9,6 +9,7 @@ use quick_xml::{
 
 use crate::{
     StringValue,
+    XlsxError,
     reader::driver::get_attribute_value,
     writer::driver::write_start_tag,
 };
@@ -97,16 +98,17 @@ impl SourceRectangle {
         &mut self,
         _reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         for attr in e.attributes().with_checks(false).flatten() {
             match attr.key.0 {
-                b"t" => self.set_t(get_attribute_value(&attr).unwrap()),
-                b"l" => self.set_l(get_attribute_value(&attr).unwrap()),
-                b"r" => self.set_r(get_attribute_value(&attr).unwrap()),
-                b"b" => self.set_b(get_attribute_value(&attr).unwrap()),
+                b"t" => self.set_t(get_attribute_value(&attr)?),
+                b"l" => self.set_l(get_attribute_value(&attr)?),
+                b"r" => self.set_r(get_attribute_value(&attr)?),
+                b"b" => self.set_b(get_attribute_value(&attr)?),
                 _ => {}
             }
         }
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
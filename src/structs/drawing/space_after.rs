@@ -12,6 +12,7 @@ use quick_xml::{
 
 use super::SpacingPercent;
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -64,22 +65,22 @@ impl SpaceAfter {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:spcPct" {
                     let mut obj = SpacingPercent::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_spacing_percent(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:spcAft" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:spcAft")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:spcAft", reader.buffer_position()))
         );
     }
 
@@ -12,6 +12,7 @@ use quick_xml::{
 
 use super::SpacingPercent;
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -64,22 +65,22 @@ impl SpaceBefore {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:spcPct" {
                     let mut obj = SpacingPercent::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_spacing_percent(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:spcBef" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:spcBef")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:spcBef", reader.buffer_position()))
         );
     }
 
@@ -17,6 +17,7 @@ use super::super::{
     Stretch,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -152,7 +153,7 @@ impl BlipFill {
         reader: &mut Reader<R>,
         e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, rotate_with_shape, "rotWithShape");
 
         xml_read_loop!(
@@ -160,11 +161,13 @@ impl BlipFill {
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"a:blip" => {
-                        self.blip
-                            .set_attributes(reader, e, drawing_relationships.unwrap(), false);
+                        let relationships = drawing_relationships.ok_or_else(|| {
+                            XlsxError::parse(reader.buffer_position(), "a:blip has no relationships")
+                        })?;
+                        self.blip.set_attributes(reader, e, relationships, false)?;
                         }
                     b"a:stretch" => {
-                        self.stretch.set_attributes(reader, e);
+                        self.stretch.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
@@ -172,12 +175,14 @@ impl BlipFill {
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                     b"a:blip" => {
-                        self.blip
-                            .set_attributes(reader, e, drawing_relationships.unwrap(), true);
+                        let relationships = drawing_relationships.ok_or_else(|| {
+                            XlsxError::parse(reader.buffer_position(), "a:blip has no relationships")
+                        })?;
+                        self.blip.set_attributes(reader, e, relationships, true)?;
                         }
                     b"a:srcRect" => {
                         let mut source_rectangle = SourceRectangle::default();
-                        source_rectangle.set_attributes(reader, e);
+                        source_rectangle.set_attributes(reader, e)?;
                         self.set_source_rectangle(source_rectangle);
                     }
                     _ => (),
@@ -185,10 +190,10 @@ impl BlipFill {
             },
             Event::End(ref e) => {
                 if matches!(e.name().into_inner(), b"xdr:blipFill" | b"blipFill") {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "blipFill")
+            Event::Eof => return Err(XlsxError::missing_end_element("blipFill", reader.buffer_position()))
         );
     }
 
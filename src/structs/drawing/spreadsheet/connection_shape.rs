@@ -17,6 +17,7 @@ use super::{
     ShapeStyle,
 };
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     structs::raw::RawRelationships,
     writer::driver::{
@@ -167,30 +168,30 @@ impl ConnectionShape {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"xdr:nvCxnSpPr" => {
                         self.non_visual_connection_shape_properties
-                            .set_attributes(reader, e);
+                            .set_attributes(reader, e)?;
                         }
                     b"xdr:spPr" => {
-                        self.shape_properties.set_attributes(reader, e, drawing_relationships);
+                        self.shape_properties.set_attributes(reader, e, drawing_relationships)?;
                     }
                     b"xdr:style" => {
-                        self.shape_style.set_attributes(reader, e);
+                        self.shape_style.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:cxnSp" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:cxnSp")
+            Event::Eof => return Err(XlsxError::missing_end_element("xdr:cxnSp", reader.buffer_position()))
         );
     }
 
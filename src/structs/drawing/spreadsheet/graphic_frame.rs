@@ -19,6 +19,7 @@ use super::{
     Transform,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -165,7 +166,7 @@ impl GraphicFrame {
         reader: &mut Reader<R>,
         e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, r#macro, "macro");
 
         xml_read_loop!(
@@ -174,24 +175,24 @@ impl GraphicFrame {
                 match e.name().into_inner() {
                     b"xdr:nvGraphicFramePr" => {
                         self.non_visual_graphic_frame_properties
-                            .set_attributes(reader, e);
+                            .set_attributes(reader, e)?;
                         }
                     b"xdr:xfrm" => {
-                        self.transform.set_attributes(reader, e);
+                        self.transform.set_attributes(reader, e)?;
                     }
                     b"a:graphic" => {
                         self.graphic
-                            .set_attributes(reader, e, drawing_relationships);
+                            .set_attributes(reader, e, drawing_relationships)?;
                         }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if  e.name().into_inner() == b"xdr:graphicFrame" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:graphicFrame")
+            Event::Eof => return Err(XlsxError::missing_end_element("xdr:graphicFrame", reader.buffer_position()))
         );
     }
 
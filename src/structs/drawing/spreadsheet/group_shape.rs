@@ -17,6 +17,7 @@ use super::{
     Shape,
 };
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     structs::raw::RawRelationships,
     writer::driver::{
@@ -157,25 +158,25 @@ impl GroupShape {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"xdr:nvGrpSpPr" => {
-                        self.non_visual_group_shape_properties.set_attributes(reader, e);
+                        self.non_visual_group_shape_properties.set_attributes(reader, e)?;
                     }
                     b"xdr:grpSpPr" => {
-                        self.group_shape_properties.set_attributes(reader, e);
+                        self.group_shape_properties.set_attributes(reader, e)?;
                     }
                     b"xdr:pic" => {
                         let mut obj = Picture::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
+                        obj.set_attributes(reader, e, drawing_relationships)?;
                         self.add_picture_collection(obj);
                     }
                     b"xdr:sp" => {
                         let mut obj = Shape::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
+                        obj.set_attributes(reader, e, drawing_relationships)?;
                         self.add_shape_collection(obj);
                     }
                     _ => (),
@@ -183,10 +184,10 @@ impl GroupShape {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:grpSp" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:grpSp")
+            Event::Eof => return Err(XlsxError::missing_end_element("xdr:grpSp", reader.buffer_position()))
         );
     }
 
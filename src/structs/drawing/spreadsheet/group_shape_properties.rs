@@ -12,6 +12,7 @@ use quick_xml::{
 
 use super::super::Transform2D;
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -59,22 +60,22 @@ impl GroupShapeProperties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().0 == b"a:xfrm" {
                     let mut obj = Transform2D::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_transform2d(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().0 == b"xdr:grpSpPr" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:grpSpPr")
+            Event::Eof => return Err(XlsxError::missing_end_element("xdr:grpSpPr", reader.buffer_position()))
         );
     }
 
@@ -11,6 +11,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     helper::coordinate::{
         adjustment_insert_coordinate,
         adjustment_remove_coordinate,
@@ -18,6 +19,7 @@ use crate::{
         index_from_coordinate,
         is_remove_coordinate,
     },
+    reader::driver::parse_value,
     traits::AdjustmentCoordinate,
     writer::driver::{
         write_end_tag,
@@ -146,7 +148,7 @@ impl MarkerType {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut string_value: String = String::new();
         let mut buf = Vec::new();
         loop {
@@ -154,24 +156,24 @@ impl MarkerType {
                 Ok(Event::Text(e)) => string_value = crate::helper::utils::unescape_xml_text(&e),
                 Ok(Event::End(ref e)) => match e.name().into_inner() {
                     b"xdr:col" | b"col" => {
-                        self.col = string_value.parse::<u32>().unwrap();
+                        self.col = parse_value(reader, &string_value)?;
                     }
                     b"xdr:colOff" | b"colOff" => {
-                        self.col_off = string_value.parse::<i32>().unwrap();
+                        self.col_off = parse_value(reader, &string_value)?;
                     }
                     b"xdr:row" | b"row" => {
-                        self.row = string_value.parse::<u32>().unwrap();
+                        self.row = parse_value(reader, &string_value)?;
                     }
                     b"xdr:rowOff" | b"rowOff" => {
-                        self.row_off = string_value.parse::<i32>().unwrap();
+                        self.row_off = parse_value(reader, &string_value)?;
                     }
-                    b"xdr:from" | b"from" | b"xdr:to" | b"to" => return,
+                    b"xdr:from" | b"from" | b"xdr:to" | b"to" => return Ok(()),
                     _ => (),
                 },
                 Ok(Event::Eof) => {
-                    panic!("Error: Could not find {} end element", "xdr:from,xdr:to")
+                    return Err(XlsxError::missing_end_element("xdr:from,xdr:to", reader.buffer_position()))
                 }
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Err(e) => return Err(XlsxError::parse(reader.buffer_position(), e.to_string())),
                 _ => (),
             }
             buf.clear();
@@ -15,6 +15,7 @@ use super::{
     NonVisualDrawingProperties,
 };
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -113,27 +114,27 @@ impl NonVisualConnectionShapeProperties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"xdr:cNvCxnSpPr" {
                     self.non_visual_connector_shape_drawing_properties
-                        .set_attributes(reader, e);
+                        .set_attributes(reader, e)?;
                 }
             },
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"xdr:cNvPr" {
                     self.non_visual_drawing_properties
-                        .set_attributes(reader, e, true);
+                        .set_attributes(reader, e, true)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:nvCxnSpPr" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:nvCxnSpPr")
+            Event::Eof => return Err(XlsxError::missing_end_element("xdr:nvCxnSpPr", reader.buffer_position()))
         );
     }
 
@@ -15,6 +15,7 @@ use super::super::{
     StartConnection,
 };
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -79,19 +80,19 @@ impl NonVisualConnectorShapeDrawingProperties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                     b"a:stCxn" => {
                         let mut connection_type = StartConnection::default();
-                        connection_type.set_attributes(reader, e);
+                        connection_type.set_attributes(reader, e)?;
                         self.set_start_connection(connection_type);
                     }
                     b"a:endCxn" => {
                         let mut connection_type = EndConnection::default();
-                        connection_type.set_attributes(reader, e);
+                        connection_type.set_attributes(reader, e)?;
                         self.set_end_connection(connection_type);
                     }
                     _ => (),
@@ -99,10 +100,10 @@ impl NonVisualConnectorShapeDrawingProperties {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:cNvCxnSpPr" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:cNvCxnSpPr")
+            Event::Eof => return Err(XlsxError::missing_end_element("xdr:cNvCxnSpPr", reader.buffer_position()))
         );
     }
 
@@ -16,8 +16,10 @@ use super::super::super::{
     UInt32Value,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
+        get_required_attribute,
         set_string_from_xml,
         xml_read_loop,
     },
@@ -97,24 +99,24 @@ impl NonVisualDrawingProperties {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
-        self.id.set_value_string(get_attribute(e, b"id").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.id.set_value_string(get_required_attribute(reader, e, b"id")?);
         self.name
-            .set_value_string(get_attribute(e, b"name").unwrap());
+            .set_value_string(get_required_attribute(reader, e, b"name")?);
         set_string_from_xml!(self, e, hidden, "hidden");
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::End(ref e) => {
                 if matches!(e.name().into_inner(), b"xdr:cNvPr" | b"cNvPr") {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "cNvPr")
+            Event::Eof => return Err(XlsxError::missing_end_element("cNvPr", reader.buffer_position()))
         );
     }
 
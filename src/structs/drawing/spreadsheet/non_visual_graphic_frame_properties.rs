@@ -15,6 +15,7 @@ use super::{
     NonVisualGraphicFrameDrawingProperties,
 };
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -113,14 +114,14 @@ impl NonVisualGraphicFrameProperties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                     b"xdr:cNvPr" => {
                         self.non_visual_drawing_properties
-                            .set_attributes(reader, e, true);
+                            .set_attributes(reader, e, true)?;
                     },
                     b"xdr:cNvGraphicFramePr" => {
                         NonVisualGraphicFrameDrawingProperties::set_attributes(reader, e);
@@ -131,15 +132,15 @@ impl NonVisualGraphicFrameProperties {
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"xdr:cNvPr" {
                     self.non_visual_drawing_properties
-                        .set_attributes(reader, e, false);
+                        .set_attributes(reader, e, false)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:nvGraphicFramePr" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:nvGraphicFramePr")
+            Event::Eof => return Err(XlsxError::missing_end_element("xdr:nvGraphicFramePr", reader.buffer_position()))
         );
     }
 
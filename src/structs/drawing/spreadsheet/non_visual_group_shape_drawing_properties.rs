@@ -12,6 +12,7 @@ use quick_xml::{
 
 use super::super::GroupShapeLocks;
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -60,9 +61,9 @@ impl NonVisualGroupShapeDrawingProperties {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -76,10 +77,10 @@ impl NonVisualGroupShapeDrawingProperties {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:cNvGrpSpPr" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:cNvGrpSpPr")
+            Event::Eof => return Err(XlsxError::missing_end_element("xdr:cNvGrpSpPr", reader.buffer_position()))
         );
     }
 
@@ -12,6 +12,7 @@ use quick_xml::{
 
 use super::super::PictureLocks;
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -83,11 +84,11 @@ impl NonVisualPictureDrawingProperties {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, prefer_relative_resize, "preferRelativeResize");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -101,10 +102,10 @@ impl NonVisualPictureDrawingProperties {
             },
             Event::End(ref e) => {
                 if matches!(e.name().into_inner(), b"xdr:cNvPicPr" | b"cNvPicPr") {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "cNvPicPr")
+            Event::Eof => return Err(XlsxError::missing_end_element("cNvPicPr", reader.buffer_position()))
         );
     }
 
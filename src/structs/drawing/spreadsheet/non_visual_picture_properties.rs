@@ -15,6 +15,7 @@ use super::{
     NonVisualPictureDrawingProperties,
 };
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -101,18 +102,18 @@ impl NonVisualPictureProperties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"xdr:cNvPicPr" | b"cNvPicPr" => {
                         self.non_visual_picture_drawing_properties
-                            .set_attributes(reader, e, false);
+                            .set_attributes(reader, e, false)?;
                         }
                     b"xdr:cNvPr" | b"cNvPr" => {
                         self.non_visual_drawing_properties
-                            .set_attributes(reader, e, false);
+                            .set_attributes(reader, e, false)?;
                         }
                     _ => (),
                 }
@@ -121,21 +122,21 @@ impl NonVisualPictureProperties {
                 match e.name().into_inner() {
                     b"xdr:cNvPicPr" | b"cNvPicPr" => {
                         self.non_visual_picture_drawing_properties
-                            .set_attributes(reader, e, true);
+                            .set_attributes(reader, e, true)?;
                         }
                     b"xdr:cNvPr" | b"cNvPr" => {
                         self.non_visual_drawing_properties
-                            .set_attributes(reader, e, true);
+                            .set_attributes(reader, e, true)?;
                         }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if matches!(e.name().into_inner(), b"xdr:nvPicPr" | b"nvPicPr") {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "nvPicPr")
+            Event::Eof => return Err(XlsxError::missing_end_element("nvPicPr", reader.buffer_position()))
         );
     }
 
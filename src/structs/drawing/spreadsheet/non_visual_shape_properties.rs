@@ -12,6 +12,7 @@ use quick_xml::{
 
 use super::NonVisualDrawingProperties;
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -58,27 +59,27 @@ impl NonVisualShapeProperties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"xdr:cNvPr" {
                     self.non_visual_drawing_properties
-                        .set_attributes(reader, e, true);
+                        .set_attributes(reader, e, true)?;
                 }
             },
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"xdr:cNvPr" {
                     self.non_visual_drawing_properties
-                        .set_attributes(reader, e, false);
+                        .set_attributes(reader, e, false)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"xdr:nvSpPr" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:nvSpPr")
+            Event::Eof => return Err(XlsxError::missing_end_element("xdr:nvSpPr", reader.buffer_position()))
         );
     }
 
@@ -18,6 +18,7 @@ use super::{
     Shape,
 };
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     structs::raw::RawRelationships,
     traits::AdjustmentCoordinate,
@@ -197,27 +198,27 @@ impl OneCellAnchor {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"xdr:from" | b"from" => {
-                        self.from_marker.set_attributes(reader, e);
+                        self.from_marker.set_attributes(reader, e)?;
                     }
                     b"xdr:grpSp" | b"grpSp" => {
                         let mut obj = GroupShape::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
+                        obj.set_attributes(reader, e, drawing_relationships)?;
                         self.set_group_shape(obj);
                     }
                     b"xdr:sp" | b"sp" => {
                         let mut obj = Shape::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
+                        obj.set_attributes(reader, e, drawing_relationships)?;
                         self.set_shape(obj);
                     }
                     b"xdr:pic" | b"pic" => {
                         let mut obj = Picture::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
+                        obj.set_attributes(reader, e, drawing_relationships)?;
                         self.set_picture(obj);
                     }
                     _ => (),
@@ -230,10 +231,10 @@ impl OneCellAnchor {
             },
             Event::End(ref e) => {
                 if matches!(e.name().into_inner(), b"xdr:oneCellAnchor" | b"oneCellAnchor") {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "oneCellAnchor")
+            Event::Eof => return Err(XlsxError::missing_end_element("oneCellAnchor", reader.buffer_position()))
         );
     }
 
@@ -16,6 +16,7 @@ use super::{
     ShapeProperties,
 };
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     structs::raw::RawRelationships,
     writer::driver::{
@@ -124,30 +125,30 @@ impl Picture {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"xdr:nvPicPr" | b"nvPicPr" => {
-                        self.non_visual_picture_properties.set_attributes(reader, e);
+                        self.non_visual_picture_properties.set_attributes(reader, e)?;
                     }
                     b"xdr:blipFill" | b"blipFill" => {
                         self.blip_fill
-                            .set_attributes(reader, e, drawing_relationships);
+                            .set_attributes(reader, e, drawing_relationships)?;
                         }
                     b"xdr:spPr" | b"spPr" => {
-                        self.shape_properties.set_attributes(reader, e, drawing_relationships);
+                        self.shape_properties.set_attributes(reader, e, drawing_relationships)?;
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if matches!(e.name().into_inner(), b"xdr:pic" | b"pic") {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "pic")
+            Event::Eof => return Err(XlsxError::missing_end_element("pic", reader.buffer_position()))
         );
     }
 
@@ -18,6 +18,7 @@ use super::{
     TextBody,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -202,7 +203,7 @@ impl Shape {
         reader: &mut Reader<R>,
         e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, r#macro, "macro");
 
         xml_read_loop!(
@@ -210,19 +211,19 @@ impl Shape {
                 Event::Start(ref e) => {
                     match e.name().into_inner() {
                         b"xdr:nvSpPr" => {
-                            self.non_visual_shape_properties.set_attributes(reader, e);
+                            self.non_visual_shape_properties.set_attributes(reader, e)?;
                         }
                         b"xdr:spPr" => {
-                            self.shape_properties.set_attributes(reader, e, drawing_relationships);
+                            self.shape_properties.set_attributes(reader, e, drawing_relationships)?;
                         }
                         b"xdr:style" => {
                             let mut obj = ShapeStyle::default();
-                            obj.set_attributes(reader, e);
+                            obj.set_attributes(reader, e)?;
                             self.set_shape_style(obj);
                         }
                         b"xdr:txBody" => {
                             let mut obj = TextBody::default();
-                            obj.set_attributes(reader, e);
+                            obj.set_attributes(reader, e)?;
                             self.set_text_body(obj);
                         }
                         _ => (),
@@ -230,10 +231,10 @@ impl Shape {
                 },
                 Event::End(ref e) => {
                     if e.name().into_inner() == b"xdr:sp" {
-                        return;
+                        return Ok(());
                     }
                 },
-                Event::Eof => panic!("Error: Could not find {} end element", "xdr:sp")
+                Event::Eof => return Err(XlsxError::missing_end_element("xdr:sp", reader.buffer_position()))
         );
     }
 
@@ -22,6 +22,7 @@ use super::super::{
 };
 use crate::{
     EnumValue,
+    XlsxError,
     drawing::{
         BlackWhiteModeValues,
         GradientFill,
@@ -346,7 +347,7 @@ impl ShapeProperties {
         reader: &mut Reader<R>,
         e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, black_white_mode, "bwMode");
 
         xml_read_loop!(
@@ -355,40 +356,40 @@ impl ShapeProperties {
                 match e.name().into_inner() {
                     b"a:xfrm" => {
                         let mut obj = Transform2D::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_transform2d(obj);
                     }
                     b"a:prstGeom" => {
-                        self.preset_geometry.set_attributes(reader, e);
+                        self.preset_geometry.set_attributes(reader, e)?;
                     }
                     b"a:blipFill" => {
                         let mut obj = BlipFill::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
+                        obj.set_attributes(reader, e, drawing_relationships)?;
                         self.set_blip_fill(obj);
                     }
                     b"a:gradFill" => {
                         let mut obj = GradientFill::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_gradient_fill(obj);
                     }
                     b"a:ln" => {
                         let mut outline = Outline::default();
-                        outline.set_attributes(reader, e);
+                        outline.set_attributes(reader, e)?;
                         self.set_outline(outline);
                     }
                     b"a:solidFill" => {
                         let mut solid_fill = SolidFill::default();
-                        solid_fill.set_attributes(reader, e);
+                        solid_fill.set_attributes(reader, e)?;
                         self.set_solid_fill(solid_fill);
                     }
                     b"a:effectLst" => {
                         let mut effect_list = EffectList::default();
-                        effect_list.set_attributes(reader, e, false);
+                        effect_list.set_attributes(reader, e, false)?;
                         self.set_effect_list(effect_list);
                     }
                     b"a:extLst" => {
                         let obj = ExtensionList::default();
-                        ExtensionList::set_attributes(reader, e);
+                        ExtensionList::set_attributes(reader, e)?;
                         self.set_extension_list(obj);
                     }
                     _ => (),
@@ -397,16 +398,16 @@ impl ShapeProperties {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:noFill" {
                     let obj = NoFill::default();
-                    NoFill::set_attributes(reader, e, true);
+                    NoFill::set_attributes(reader, e, true)?;
                     self.set_no_fill(obj);
                 }
             },
             Event::End(ref e) => {
                 if matches!(e.name().into_inner(), b"xdr:spPr" | b"spPr") {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "spPr")
+            Event::Eof => return Err(XlsxError::missing_end_element("spPr", reader.buffer_position()))
         );
     }
 
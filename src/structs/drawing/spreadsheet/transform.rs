@@ -17,6 +17,7 @@ use super::super::{
 use crate::{
     BooleanValue,
     Int32Value,
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -156,7 +157,7 @@ impl Transform {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, rotation, "rot");
         set_string_from_xml!(self, e, horizontal_flip, "flipH");
         set_string_from_xml!(self, e, vertical_flip, "flipV");
@@ -166,20 +167,20 @@ impl Transform {
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                     b"a:off" => {
-                        self.offset.set_attributes(reader, e);
+                        self.offset.set_attributes(reader, e)?;
                     }
                     b"a:ext" => {
-                        self.extents.set_attributes(reader, e);
+                        self.extents.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if  e.name().into_inner() == b"xdr:xfrm" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:xfrm")
+            Event::Eof => return Err(XlsxError::missing_end_element("xdr:xfrm", reader.buffer_position()))
         );
     }
 
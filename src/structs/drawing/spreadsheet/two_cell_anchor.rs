@@ -21,6 +21,7 @@ use super::{
     Shape,
 };
 use crate::{
+    XlsxError,
     helper::const_str::{
         DRAWING_MAIN_NS,
         MC_NS,
@@ -335,7 +336,7 @@ impl TwoCellAnchor {
         reader: &mut Reader<R>,
         e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, edit_as, "editAs");
 
         xml_read_loop!(
@@ -343,34 +344,34 @@ impl TwoCellAnchor {
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                 b"xdr:from" | b"from" => {
-                    self.from_marker.set_attributes(reader, e);
+                    self.from_marker.set_attributes(reader, e)?;
                 }
                 b"xdr:to" | b"to" => {
-                    self.to_marker.set_attributes(reader, e);
+                    self.to_marker.set_attributes(reader, e)?;
                 }
                 b"xdr:grpSp" | b"grpSp" => {
                     let mut obj = GroupShape::default();
-                    obj.set_attributes(reader, e, drawing_relationships);
+                    obj.set_attributes(reader, e, drawing_relationships)?;
                     self.set_group_shape(obj);
                 }
                 b"xdr:graphicFrame" | b"graphicFrame" => {
                     let mut obj = GraphicFrame::default();
-                    obj.set_attributes(reader, e, drawing_relationships);
+                    obj.set_attributes(reader, e, drawing_relationships)?;
                     self.set_graphic_frame(obj);
                 }
                 b"xdr:sp" | b"sp" => {
                     let mut obj = Shape::default();
-                    obj.set_attributes(reader, e, drawing_relationships);
+                    obj.set_attributes(reader, e, drawing_relationships)?;
                     self.set_shape(obj);
                 }
                 b"xdr:cxnSp" | b"cxnSp" => {
                     let mut obj = ConnectionShape::default();
-                    obj.set_attributes(reader, e, drawing_relationships);
+                    obj.set_attributes(reader, e, drawing_relationships)?;
                     self.set_connection_shape(obj);
                 }
                 b"xdr:pic" | b"pic" => {
                     let mut obj = Picture::default();
-                    obj.set_attributes(reader, e, drawing_relationships);
+                    obj.set_attributes(reader, e, drawing_relationships)?;
                     self.set_picture(obj);
                 }
                 _ => (),
@@ -378,10 +379,10 @@ impl TwoCellAnchor {
             },
             Event::End(ref e) => {
                 if matches!(e.name().into_inner(), b"xdr:twoCellAnchor" | b"twoCellAnchor") {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "twoCellAnchor")
+            Event::Eof => return Err(XlsxError::missing_end_element("twoCellAnchor", reader.buffer_position()))
         );
     }
 
@@ -19,6 +19,7 @@ use super::{
     TwoCellAnchor,
 };
 use crate::{
+    XlsxError,
     helper::const_str::{
         DRAWINGML_MAIN_NS,
         SHEET_DRAWING_NS,
@@ -27,6 +28,7 @@ use crate::{
     structs::{
         Chart,
         Image,
+        drawing::charts::Formula,
         OleObjects,
         raw::RawRelationships,
     },
@@ -393,6 +395,26 @@ impl WorksheetDrawing {
             .collect()
     }
 
+    /// (This method is crate only.)
+    /// Series formulas of the charts.
+    pub(crate) fn chart_formula_mut(&mut self) -> Vec<&mut Formula> {
+        self.chart_collection
+            .iter_mut()
+            .map(Chart::two_cell_anchor_mut)
+            .chain(self.two_cell_anchor_collection.iter_mut())
+            .filter_map(TwoCellAnchor::graphic_frame_mut)
+            .flat_map(|graphic_frame| {
+                graphic_frame
+                    .graphic_mut()
+                    .graphic_data_mut()
+                    .chart_space_mut()
+                    .chart_mut()
+                    .plot_area_mut()
+                    .formula_mut()
+            })
+            .collect()
+    }
+
     #[deprecated(since = "3.0.0", note = "Use one_cell_anchor_all_list()")]
     pub fn get_one_cell_anchor_all_list(&mut self) -> Vec<&mut OneCellAnchor> {
         self.one_cell_anchor_all_list()
@@ -437,7 +459,7 @@ impl WorksheetDrawing {
         _e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
         ole_objects: &mut OleObjects,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut ole_index = 0;
         let mut is_alternate_content = false;
 
@@ -453,7 +475,7 @@ impl WorksheetDrawing {
                             continue;
                         }
                         let mut obj = OneCellAnchor::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
+                        obj.set_attributes(reader, e, drawing_relationships)?;
                         if obj.is_image() {
                             let mut image = Image::default();
                             image.set_one_cell_anchor(obj);
@@ -472,12 +494,12 @@ impl WorksheetDrawing {
                                 reader,
                                 e,
                                 drawing_relationships,
-                            );
+                            )?;
                             ole_index += 1;
                             continue;
                         }
                         let mut obj = TwoCellAnchor::default();
-                        obj.set_attributes(reader, e, drawing_relationships);
+                        obj.set_attributes(reader, e, drawing_relationships)?;
                         if obj.is_support() {
                             if obj.is_chart() {
                                 let mut chart = Chart::default();
@@ -501,12 +523,12 @@ impl WorksheetDrawing {
                     b"mc:AlternateContent" => {
                         is_alternate_content = false;
                     }
-                    b"xdr:wsDr" | b"wsDr" => return,
+                    b"xdr:wsDr" | b"wsDr" => return Ok(()),
                     _ => (),
                 }
             },
 
-            Event::Eof => panic!("Error: Could not find {} end element", "xdr:wsDr")
+            Event::Eof => return Err(XlsxError::missing_end_element("xdr:wsDr", reader.buffer_position()))
         );
     }
 
@@ -12,6 +12,7 @@ use quick_xml::{
 
 use super::fill_rectangle::FillRectangle;
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -58,7 +59,7 @@ impl Stretch {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
@@ -70,10 +71,10 @@ impl Stretch {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:stretch" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:stretch")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:stretch", reader.buffer_position()))
         );
     }
 
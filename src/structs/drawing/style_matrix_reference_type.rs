@@ -12,8 +12,9 @@ use quick_xml::{
 
 use super::SchemeColor;
 use crate::{
+    XlsxError,
     reader::driver::{
-        get_attribute,
+        get_required_attribute,
         xml_read_loop,
     },
     writer::driver::{
@@ -70,11 +71,11 @@ impl StyleMatrixReferenceType {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
-        self.set_index(get_attribute(e, b"idx").unwrap());
+    ) -> Result<(), XlsxError> {
+        self.set_index(get_required_attribute(reader, e, b"idx")?);
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -82,14 +83,14 @@ impl StyleMatrixReferenceType {
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"a:schemeClr" {
                     let mut scheme_color = SchemeColor::default();
-                    scheme_color.set_attributes(reader, e, false);
+                    scheme_color.set_attributes(reader, e, false)?;
                     self.set_scheme_color(scheme_color);
                 }
             },
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:schemeClr" {
                     let mut scheme_color = SchemeColor::default();
-                    scheme_color.set_attributes(reader, e, true);
+                    scheme_color.set_attributes(reader, e, true)?;
                     self.set_scheme_color(scheme_color);
                 }
             },
@@ -98,11 +99,11 @@ impl StyleMatrixReferenceType {
                     b"a:lnRef"     |
                     b"a:fillRef"   |
                     b"a:effectRef" |
-                    b"a:fontRef" => return,
+                    b"a:fontRef" => return Ok(()),
                     _ => (),
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:lnRef")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:lnRef", reader.buffer_position()))
         );
     }
 
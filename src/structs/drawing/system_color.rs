@@ -18,6 +18,7 @@ use super::{
     SystemColorValues,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -76,22 +77,22 @@ impl SystemColor {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, val, "val");
         set_string_from_xml!(self, e, last_color, "lastClr");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:sysClr" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:sysClr")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:sysClr", reader.buffer_position()))
         );
     }
 
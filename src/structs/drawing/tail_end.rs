@@ -11,6 +11,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         xml_read_loop,
@@ -82,7 +83,7 @@ impl TailEnd {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(v) = get_attribute(e, b"type") {
             self.set_type(v);
         }
@@ -96,17 +97,17 @@ impl TailEnd {
         }
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:tailEnd" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:tailEnd")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:tailEnd", reader.buffer_position()))
         );
     }
 
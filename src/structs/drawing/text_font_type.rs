@@ -11,6 +11,7 @@ use quick_xml::{
 
 use super::super::StringValue;
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         xml_read_loop,
@@ -108,7 +109,7 @@ impl TextFontType {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(v) = get_attribute(e, b"typeface") {
             self.set_typeface(v);
         }
@@ -123,23 +124,23 @@ impl TextFontType {
         }
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:latin" {
-                    return;
+                    return Ok(());
                 }
                 if e.name().into_inner() == b"a:cs" {
-                    return;
+                    return Ok(());
                 }
                 if e.name().into_inner() == b"a:ea" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:latin,a:cs,a:ea")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:latin,a:cs,a:ea", reader.buffer_position()))
         );
     }
 
@@ -22,6 +22,7 @@ use super::{
     TextFontAlignmentValues,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -194,7 +195,7 @@ impl TextParagraphPropertiesType {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, right_to_left, "rtl");
         set_string_from_xml!(self, e, alignment, "algn");
         set_string_from_xml!(self, e, font_alignment, "fontAlgn");
@@ -204,7 +205,7 @@ impl TextParagraphPropertiesType {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"a:defRPr" {
                     let mut obj = RunProperties::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_default_run_properties(obj);
                 }
             },
@@ -212,17 +213,17 @@ impl TextParagraphPropertiesType {
                 match e.name().into_inner() {
                 b"a:spcBef" => {
                     let mut obj = SpaceBefore::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_space_before(obj);
                 }
                 b"a:spcAft" => {
                     let mut obj = SpaceAfter::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_space_after(obj);
                 }
                 b"a:defRPr" => {
                     let mut obj = RunProperties::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.set_default_run_properties(obj);
                 }
                 _ => (),
@@ -239,11 +240,11 @@ impl TextParagraphPropertiesType {
                     b"a:lvl6pPr" |
                     b"a:lvl7pPr" |
                     b"a:lvl8pPr" |
-                    b"a:lvl9pPr" => return,
+                    b"a:lvl9pPr" => return Ok(()),
                     _ =>()
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:lvl1pPr-lvl9pPr")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:lvl1pPr-lvl9pPr", reader.buffer_position()))
         );
     }
 
@@ -11,6 +11,7 @@ use quick_xml::{
 
 use super::{
     super::StringValue,
+    Color2Type,
     EffectList,
     EffectStyle,
     GradientFill,
@@ -33,6 +34,7 @@ use super::{
     ThemeElements,
 };
 use crate::{
+    XlsxError,
     helper::const_str::DRAWINGML_MAIN_NS,
     reader::driver::{
         get_attribute,
@@ -100,6 +102,75 @@ impl Theme {
         self
     }
 
+    /// Set a color of the color scheme.
+    /// # Arguments
+    /// * `color` - Scheme color to set. `PhColor` is ignored.
+    /// * `rgb` - RGB value, 6 hex digits with an optional `#`. ex) "1F4E79",
+    ///   "#1F4E79"
+    /// # Errors
+    /// * `InvalidArgument` - `rgb` is not 6 hex digits. The theme is not
+    ///   changed.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::drawing::SchemeColorValues;
+    ///
+    /// let mut book = umya_spreadsheet::new_file();
+    /// book.theme_mut()
+    ///     .set_scheme_color(&SchemeColorValues::Accent1, "1F4E79")
+    ///     .unwrap()
+    ///     .set_scheme_color(&SchemeColorValues::Accent2, "#C00000")
+    ///     .unwrap()
+    ///     .set_major_font("Georgia")
+    ///     .set_minor_font("Verdana");
+    /// assert!(book.theme_mut().set_scheme_color(&SchemeColorValues::Accent3, "red").is_err());
+    /// ```
+    pub fn set_scheme_color<S: AsRef<str>>(
+        &mut self,
+        color: &SchemeColorValues,
+        rgb: S,
+    ) -> Result<&mut Self, XlsxError> {
+        let rgb = rgb.as_ref();
+        let value = rgb.strip_prefix('#').unwrap_or(rgb);
+        if value.len() != 6 || !value.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(XlsxError::InvalidArgument(format!(
+                "\"{rgb}\" is not an RGB value of 6 hex digits"
+            )));
+        }
+        if let Some(scheme_color) = self
+            .theme_elements
+            .color_scheme_mut()
+            .scheme_color_mut(color)
+        {
+            let mut obj = RgbColorModelHex::default();
+            obj.set_val(value.to_uppercase());
+            *scheme_color = Color2Type::default();
+            scheme_color.set_rgb_color_model_hex(obj);
+        }
+        Ok(self)
+    }
+
+    /// Set the latin typeface of the major (headings) font.
+    /// Fonts of the stylesheet keep their own names.
+    pub fn set_major_font<S: Into<String>>(&mut self, typeface: S) -> &mut Self {
+        self.theme_elements
+            .font_scheme_mut()
+            .major_font_mut()
+            .latin_font_mut()
+            .set_typeface(typeface);
+        self
+    }
+
+    /// Set the latin typeface of the minor (body) font.
+    /// Fonts of the stylesheet keep their own names.
+    pub fn set_minor_font<S: Into<String>>(&mut self, typeface: S) -> &mut Self {
+        self.theme_elements
+            .font_scheme_mut()
+            .minor_font_mut()
+            .latin_font_mut()
+            .set_typeface(typeface);
+        self
+    }
+
     pub(crate) fn default_value() -> Theme {
         let mut def = Theme::default();
         def.set_name("Office Theme");
@@ -576,7 +647,7 @@ impl Theme {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(v) = get_attribute(e, b"name") {
             self.name.set_value(v);
         }
@@ -585,15 +656,15 @@ impl Theme {
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"a:themeElements" {
-                    self.theme_elements.set_attributes(reader, e);
+                    self.theme_elements.set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:theme" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:theme")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:theme", reader.buffer_position()))
         );
     }
 
@@ -16,6 +16,7 @@ use super::{
     FormatScheme,
 };
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -125,29 +126,29 @@ impl ThemeElements {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"a:clrScheme" => {
-                        self.color_scheme.set_attributes(reader, e);
+                        self.color_scheme.set_attributes(reader, e)?;
                     }
                     b"a:fontScheme" => {
-                        self.font_scheme.set_attributes(reader, e);
+                        self.font_scheme.set_attributes(reader, e)?;
                     }
                     b"a:fmtScheme" => {
-                        self.format_scheme.set_attributes(reader, e);
+                        self.format_scheme.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:themeElements" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:themeElements")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:themeElements", reader.buffer_position()))
         );
     }
 
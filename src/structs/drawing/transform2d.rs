@@ -12,6 +12,7 @@ use quick_xml::{
 
 use crate::{
     StringValue,
+    XlsxError,
     reader::driver::{
         get_attribute,
         xml_read_loop,
@@ -212,7 +213,7 @@ impl Transform2D {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(v) = get_attribute(e, b"rot") {
             self.set_rot(v);
         }
@@ -230,19 +231,19 @@ impl Transform2D {
             Event::Empty(ref e) => {
                 match e.name().into_inner() {
                     b"a:off" => {
-                        self.offset.set_attributes(reader, e, true);
+                        self.offset.set_attributes(reader, e, true)?;
                     }
                     b"a:ext" => {
-                        self.extents.set_attributes(reader, e, true);
+                        self.extents.set_attributes(reader, e, true)?;
                     }
                     b"a:chOff" => {
                         let mut obj = Point2DType::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_child_offset(obj);
                     }
                     b"a:chExt" => {
                         let mut obj = PositiveSize2DType::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_child_extents(obj);
                     }
                     _ => (),
@@ -251,19 +252,19 @@ impl Transform2D {
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"a:off" => {
-                        self.offset.set_attributes(reader, e, false);
+                        self.offset.set_attributes(reader, e, false)?;
                     }
                     b"a:ext" => {
-                        self.extents.set_attributes(reader, e, false);
+                        self.extents.set_attributes(reader, e, false)?;
                     }
                     b"a:chOff" => {
                         let mut obj = Point2DType::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_child_offset(obj);
                     }
                     b"a:chExt" => {
                         let mut obj = PositiveSize2DType::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_child_extents(obj);
                     }
                     _ => (),
@@ -271,10 +272,10 @@ impl Transform2D {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"a:xfrm" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "a:xfrm")
+            Event::Eof => return Err(XlsxError::missing_end_element("a:xfrm", reader.buffer_position()))
         );
     }
 
@@ -0,0 +1,72 @@
+// xda:dynamicArrayProperties
+use std::io::Cursor;
+
+use quick_xml::{
+    Writer,
+    events::BytesStart,
+};
+
+use crate::{
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+    },
+    structs::BooleanValue,
+    writer::driver::write_start_tag,
+};
+
+/// Properties of a dynamic array formula, the future metadata of the
+/// `XLDAPR` metadata type.
+#[derive(Clone, Default, Debug)]
+pub struct DynamicArrayProperties {
+    dynamic:   BooleanValue,
+    collapsed: BooleanValue,
+}
+
+impl DynamicArrayProperties {
+    /// Whether the formula spills as a dynamic array.
+    #[inline]
+    #[must_use]
+    pub fn dynamic(&self) -> bool {
+        self.dynamic.value()
+    }
+
+    #[inline]
+    pub fn set_dynamic(&mut self, value: bool) -> &mut Self {
+        self.dynamic.set_value(value);
+        self
+    }
+
+    /// Whether the result is collapsed to a single value.
+    #[inline]
+    #[must_use]
+    pub fn collapsed(&self) -> bool {
+        self.collapsed.value()
+    }
+
+    #[inline]
+    pub fn set_collapsed(&mut self, value: bool) -> &mut Self {
+        self.collapsed.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn set_attributes(&mut self, e: &BytesStart) {
+        set_string_from_xml!(self, e, dynamic, "fDynamic");
+        set_string_from_xml!(self, e, collapsed, "fCollapsed");
+    }
+
+    #[inline]
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // xda:dynamicArrayProperties
+        write_start_tag(
+            writer,
+            "xda:dynamicArrayProperties",
+            vec![
+                ("fDynamic", self.dynamic.value_string()).into(),
+                ("fCollapsed", self.collapsed.value_string()).into(),
+            ],
+            true,
+        );
+    }
+}
@@ -0,0 +1,224 @@
+// dynamicFilter
+use std::io::Cursor;
+
+use chrono::{
+    Datelike,
+    Days,
+    NaiveDate,
+};
+use quick_xml::{
+    Reader,
+    Writer,
+    events::BytesStart,
+};
+
+use super::{
+    DoubleValue,
+    DynamicFilterValues,
+    EnumValue,
+};
+use crate::{
+    XlsxError,
+    helper::date::{
+        MAX_SERIAL_DATE,
+        excel_to_date_time_chrono,
+    },
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+        try_set_string_from_xml,
+    },
+    writer::driver::write_start_tag,
+};
+
+#[derive(Clone, Default, Debug)]
+pub struct DynamicFilter {
+    r#type:  EnumValue<DynamicFilterValues>,
+    val:     DoubleValue,
+    max_val: DoubleValue,
+}
+
+impl DynamicFilter {
+    #[inline]
+    #[must_use]
+    pub fn get_type(&self) -> &DynamicFilterValues {
+        self.r#type.value()
+    }
+
+    #[inline]
+    pub fn set_type(&mut self, value: DynamicFilterValues) -> &mut Self {
+        self.r#type.set_value(value);
+        self
+    }
+
+    /// Get the average, or the first serial date of the range,
+    /// computed when the filter was applied.
+    #[inline]
+    #[must_use]
+    pub fn val(&self) -> f64 {
+        self.val.value()
+    }
+
+    #[inline]
+    pub fn set_val(&mut self, value: f64) -> &mut Self {
+        self.val.set_value(value);
+        self
+    }
+
+    /// Get the serial date following the range.
+    #[inline]
+    #[must_use]
+    pub fn max_val(&self) -> f64 {
+        self.max_val.value()
+    }
+
+    #[inline]
+    pub fn set_max_val(&mut self, value: f64) -> &mut Self {
+        self.max_val.set_value(value);
+        self
+    }
+
+    /// Compute `val` and `max_val` for the given column values and date.
+    pub(crate) fn prepare(&mut self, numbers: &[f64], today: NaiveDate) {
+        match self.get_type() {
+            DynamicFilterValues::AboveAverage | DynamicFilterValues::BelowAverage => {
+                if !numbers.is_empty() {
+                    let average = numbers.iter().sum::<f64>()
+                        / num_traits::cast::<_, f64>(numbers.len()).unwrap();
+                    self.set_val(average);
+                }
+            }
+            _ => {
+                if let Some((beg, end)) = self.date_range(today) {
+                    self.set_val(date_to_excel(beg));
+                    self.set_max_val(date_to_excel(end));
+                }
+            }
+        }
+    }
+
+    pub(crate) fn is_match(&self, number: Option<f64>) -> bool {
+        let filter_type = self.get_type();
+        if filter_type == &DynamicFilterValues::Null {
+            return true;
+        }
+        let Some(number) = number else {
+            return false;
+        };
+        match filter_type {
+            DynamicFilterValues::AboveAverage => number > self.val(),
+            DynamicFilterValues::BelowAverage => number < self.val(),
+            DynamicFilterValues::Quarter1
+            | DynamicFilterValues::Quarter2
+            | DynamicFilterValues::Quarter3
+            | DynamicFilterValues::Quarter4 => {
+                month_of(number).is_some_and(|month| self.period_number() == Some((month - 1) / 3 + 1))
+            }
+            _ if self.period_number().is_some() => {
+                month_of(number).is_some_and(|month| self.period_number() == Some(month))
+            }
+            _ => number >= self.val() && number < self.max_val(),
+        }
+    }
+
+    /// The quarter or month number of `Q1`..`Q4` and `M1`..`M12`.
+    fn period_number(&self) -> Option<u32> {
+        let value = self.r#type.value_string();
+        if value.len() > 1 && (value.starts_with('Q') || value.starts_with('M')) {
+            return value[1..].parse().ok();
+        }
+        None
+    }
+
+    /// The range of dates `[begin, end)` of a relative date filter.
+    fn date_range(&self, today: NaiveDate) -> Option<(NaiveDate, NaiveDate)> {
+        let week_start = today - Days::new(u64::from(today.weekday().num_days_from_sunday()));
+        let month = today.month0().cast_signed();
+        let quarter = month / 3 * 3;
+        let range = match self.get_type() {
+            DynamicFilterValues::Today => (today, today + Days::new(1)),
+            DynamicFilterValues::Yesterday => (today - Days::new(1), today),
+            DynamicFilterValues::Tomorrow => (today + Days::new(1), today + Days::new(2)),
+            DynamicFilterValues::ThisWeek => (week_start, week_start + Days::new(7)),
+            DynamicFilterValues::LastWeek => (week_start - Days::new(7), week_start),
+            DynamicFilterValues::NextWeek => {
+                (week_start + Days::new(7), week_start + Days::new(14))
+            }
+            DynamicFilterValues::ThisMonth => {
+                (month_start(today, month)?, month_start(today, month + 1)?)
+            }
+            DynamicFilterValues::LastMonth => {
+                (month_start(today, month - 1)?, month_start(today, month)?)
+            }
+            DynamicFilterValues::NextMonth => {
+                (month_start(today, month + 1)?, month_start(today, month + 2)?)
+            }
+            DynamicFilterValues::ThisQuarter => {
+                (month_start(today, quarter)?, month_start(today, quarter + 3)?)
+            }
+            DynamicFilterValues::LastQuarter => {
+                (month_start(today, quarter - 3)?, month_start(today, quarter)?)
+            }
+            DynamicFilterValues::NextQuarter => {
+                (month_start(today, quarter + 3)?, month_start(today, quarter + 6)?)
+            }
+            DynamicFilterValues::ThisYear => (month_start(today, 0)?, month_start(today, 12)?),
+            DynamicFilterValues::LastYear => (month_start(today, -12)?, month_start(today, 0)?),
+            DynamicFilterValues::NextYear => (month_start(today, 12)?, month_start(today, 24)?),
+            DynamicFilterValues::YearToDate => (month_start(today, 0)?, today + Days::new(1)),
+            _ => return None,
+        };
+        Some(range)
+    }
+
+    #[inline]
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, r#type, "type");
+        try_set_string_from_xml!(self, reader, e, val, "val");
+        try_set_string_from_xml!(self, reader, e, max_val, "maxVal");
+        Ok(())
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // dynamicFilter
+        let val = self.val.value_string();
+        let max_val = self.max_val.value_string();
+        let mut attributes: crate::structs::AttrCollection =
+            vec![("type", self.r#type.value_string()).into()];
+        if self.val.has_value() {
+            attributes.push(("val", &val).into());
+        }
+        if self.max_val.has_value() {
+            attributes.push(("maxVal", &max_val).into());
+        }
+        write_start_tag(writer, "dynamicFilter", attributes, true);
+    }
+}
+
+/// The first day of the month `offset` months after January of the year of `date`.
+fn month_start(date: NaiveDate, offset: i32) -> Option<NaiveDate> {
+    let index = date.year() * 12 + offset;
+    NaiveDate::from_ymd_opt(
+        index.div_euclid(12),
+        u32::try_from(index.rem_euclid(12)).ok()? + 1,
+        1,
+    )
+}
+
+#[inline]
+fn date_to_excel(date: NaiveDate) -> f64 {
+    let base = NaiveDate::from_ymd_opt(1899, 12, 30).unwrap();
+    num_traits::cast::<_, f64>((date - base).num_days()).unwrap()
+}
+
+/// The month of a serial date, `None` when the number is out of the dates
+/// of Excel.
+fn month_of(number: f64) -> Option<u32> {
+    (0.0..=MAX_SERIAL_DATE)
+        .contains(&number)
+        .then(|| excel_to_date_time_chrono(number).month())
+}
@@ -0,0 +1,134 @@
+use std::str::FromStr;
+
+use super::EnumTrait;
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum DynamicFilterValues {
+    AboveAverage,
+    BelowAverage,
+    LastMonth,
+    LastQuarter,
+    LastWeek,
+    LastYear,
+    Month1,
+    Month2,
+    Month3,
+    Month4,
+    Month5,
+    Month6,
+    Month7,
+    Month8,
+    Month9,
+    Month10,
+    Month11,
+    Month12,
+    NextMonth,
+    NextQuarter,
+    NextWeek,
+    NextYear,
+    Null,
+    Quarter1,
+    Quarter2,
+    Quarter3,
+    Quarter4,
+    ThisMonth,
+    ThisQuarter,
+    ThisWeek,
+    ThisYear,
+    Today,
+    Tomorrow,
+    YearToDate,
+    Yesterday,
+}
+impl Default for DynamicFilterValues {
+    #[inline]
+    fn default() -> Self {
+        Self::Null
+    }
+}
+impl EnumTrait for DynamicFilterValues {
+    #[inline]
+    fn value_string(&self) -> &str {
+        match &self {
+            Self::AboveAverage => "aboveAverage",
+            Self::BelowAverage => "belowAverage",
+            Self::LastMonth => "lastMonth",
+            Self::LastQuarter => "lastQuarter",
+            Self::LastWeek => "lastWeek",
+            Self::LastYear => "lastYear",
+            Self::Month1 => "M1",
+            Self::Month2 => "M2",
+            Self::Month3 => "M3",
+            Self::Month4 => "M4",
+            Self::Month5 => "M5",
+            Self::Month6 => "M6",
+            Self::Month7 => "M7",
+            Self::Month8 => "M8",
+            Self::Month9 => "M9",
+            Self::Month10 => "M10",
+            Self::Month11 => "M11",
+            Self::Month12 => "M12",
+            Self::NextMonth => "nextMonth",
+            Self::NextQuarter => "nextQuarter",
+            Self::NextWeek => "nextWeek",
+            Self::NextYear => "nextYear",
+            Self::Null => "null",
+            Self::Quarter1 => "Q1",
+            Self::Quarter2 => "Q2",
+            Self::Quarter3 => "Q3",
+            Self::Quarter4 => "Q4",
+            Self::ThisMonth => "thisMonth",
+            Self::ThisQuarter => "thisQuarter",
+            Self::ThisWeek => "thisWeek",
+            Self::ThisYear => "thisYear",
+            Self::Today => "today",
+            Self::Tomorrow => "tomorrow",
+            Self::YearToDate => "yearToDate",
+            Self::Yesterday => "yesterday",
+        }
+    }
+}
+impl FromStr for DynamicFilterValues {
+    type Err = ();
+
+    #[inline]
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "aboveAverage" => Ok(Self::AboveAverage),
+            "belowAverage" => Ok(Self::BelowAverage),
+            "lastMonth" => Ok(Self::LastMonth),
+            "lastQuarter" => Ok(Self::LastQuarter),
+            "lastWeek" => Ok(Self::LastWeek),
+            "lastYear" => Ok(Self::LastYear),
+            "M1" => Ok(Self::Month1),
+            "M2" => Ok(Self::Month2),
+            "M3" => Ok(Self::Month3),
+            "M4" => Ok(Self::Month4),
+            "M5" => Ok(Self::Month5),
+            "M6" => Ok(Self::Month6),
+            "M7" => Ok(Self::Month7),
+            "M8" => Ok(Self::Month8),
+            "M9" => Ok(Self::Month9),
+            "M10" => Ok(Self::Month10),
+            "M11" => Ok(Self::Month11),
+            "M12" => Ok(Self::Month12),
+            "nextMonth" => Ok(Self::NextMonth),
+            "nextQuarter" => Ok(Self::NextQuarter),
+            "nextWeek" => Ok(Self::NextWeek),
+            "nextYear" => Ok(Self::NextYear),
+            "null" => Ok(Self::Null),
+            "Q1" => Ok(Self::Quarter1),
+            "Q2" => Ok(Self::Quarter2),
+            "Q3" => Ok(Self::Quarter3),
+            "Q4" => Ok(Self::Quarter4),
+            "thisMonth" => Ok(Self::ThisMonth),
+            "thisQuarter" => Ok(Self::ThisQuarter),
+            "thisWeek" => Ok(Self::ThisWeek),
+            "thisYear" => Ok(Self::ThisYear),
+            "today" => Ok(Self::Today),
+            "tomorrow" => Ok(Self::Tomorrow),
+            "yearToDate" => Ok(Self::YearToDate),
+            "yesterday" => Ok(Self::Yesterday),
+            _ => Err(()),
+        }
+    }
+}
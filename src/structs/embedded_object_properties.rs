@@ -16,8 +16,10 @@ use super::{
     UInt32Value,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
+        get_required_attribute,
         set_string_from_xml,
         xml_read_loop,
     },
@@ -182,9 +184,12 @@ impl EmbeddedObjectProperties {
         reader: &mut Reader<R>,
         e: &BytesStart,
         relationships: &RawRelationships,
-    ) {
-        let r_id = get_attribute(e, b"r:id").unwrap();
-        let attached_file = relationships.relationship_by_rid(&r_id).raw_file();
+    ) -> Result<(), XlsxError> {
+        let r_id = get_required_attribute(reader, e, b"r:id")?;
+        let attached_file = relationships
+            .relationship_by_rid(&r_id)
+            .ok_or_else(|| XlsxError::missing_relationship(&r_id, reader.buffer_position()))?
+            .raw_file();
 
         self.image_mut()
             .set_image_name(attached_file.file_name());
@@ -198,15 +203,15 @@ impl EmbeddedObjectProperties {
             reader,
                 Event::Start(ref e) => {
                     if e.name().into_inner() == b"anchor" {
-                        self.object_anchor.set_attributes(reader, e);
+                        self.object_anchor.set_attributes(reader, e)?;
                     }
                 },
                 Event::End(ref e) => {
                     if e.name().into_inner() == b"objectPr" {
-                        return
+                        return Ok(())
                     }
                 },
-                Event::Eof => panic!("Error: Could not find {} end element", "objectPr")
+                Event::Eof => return Err(XlsxError::missing_end_element("objectPr", reader.buffer_position()))
         );
     }
 
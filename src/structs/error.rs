@@ -3,7 +3,10 @@ use std::{
     str::FromStr,
 };
 
-use crate::from_err;
+use crate::{
+    from_err,
+    structs::ReadLimit,
+};
 
 // https://msdn.microsoft.com/en-us/library/office/ff839168.aspx
 /// An enum to represent all different errors that can appear as
@@ -80,6 +83,12 @@ pub enum XlsxError {
     NotDeserialized(),
     /// name duplicate error
     NameDuplicate(),
+    /// Invalid argument error
+    InvalidArgument(String),
+    /// Malformed part error
+    Parse(Box<ParseError>),
+    /// A limit of `ReadLimits` is exceeded by the given part
+    LimitExceeded(ReadLimit, String),
 }
 
 from_err!(std::io::Error, XlsxError, Io);
@@ -95,7 +104,10 @@ impl fmt::Display for XlsxError {
             NotFound,
             NotDeserialized,
             NameDuplicate,
+            InvalidArgument,
             Io,
+            LimitExceeded,
+            Parse,
             Uft8,
             Xml,
             Zip,
@@ -109,7 +121,119 @@ impl fmt::Display for XlsxError {
             NotFound() => write!(f, "Not Found"),
             NotDeserialized() => write!(f, "Not Deserialized: This Worksheet is Not Deserialized. Please exec to read_sheet(&mut self, index: usize)"),
             NameDuplicate() => write!(f, "Name Duplicate"),
+            InvalidArgument(s) => write!(f, "Invalid Argument: {s}"),
+            Parse(e) => write!(f, "ParseError: {e}"),
+            LimitExceeded(limit, part) => write!(f, "Limit Exceeded: {limit} of {part}"),
         }
     }
 }
 impl std::error::Error for XlsxError {}
+
+impl XlsxError {
+    /// Create a parse error at the given byte offset.
+    /// The part name and the element path are filled in by the reader of
+    /// the part.
+    #[inline]
+    pub(crate) fn parse<S: Into<String>>(offset: u64, reason: S) -> Self {
+        Self::Parse(Box::new(ParseError {
+            part: String::new(),
+            path: String::new(),
+            offset,
+            reason: reason.into(),
+        }))
+    }
+
+    /// Move a parse error to the given byte offset.
+    #[inline]
+    pub(crate) fn at(mut self, offset: u64) -> Self {
+        if let Self::Parse(detail) = &mut self {
+            detail.offset = offset;
+        }
+        self
+    }
+
+    /// Create a parse error for an element that is not closed.
+    #[inline]
+    pub(crate) fn missing_end_element(tag: &str, offset: u64) -> Self {
+        Self::parse(offset, format!("could not find {tag} end element"))
+    }
+
+    /// Create a parse error for a relationship id that is not found.
+    #[inline]
+    pub(crate) fn missing_relationship(r_id: &str, offset: u64) -> Self {
+        Self::parse(offset, format!("relationship {r_id} not found"))
+    }
+
+    /// Create a parse error for a missing required attribute.
+    #[inline]
+    pub(crate) fn missing_attribute(tag: &[u8], attribute: &[u8], offset: u64) -> Self {
+        Self::parse(
+            offset,
+            format!(
+                "{} requires the {} attribute",
+                String::from_utf8_lossy(tag),
+                String::from_utf8_lossy(attribute)
+            ),
+        )
+    }
+}
+
+/// Details of a malformed part found while reading a file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParseError {
+    part:   String,
+    path:   String,
+    offset: u64,
+    reason: String,
+}
+
+impl ParseError {
+    /// Name of the part in the package. ex) "xl/worksheets/sheet1.xml"
+    #[inline]
+    #[must_use]
+    pub fn part(&self) -> &str {
+        &self.part
+    }
+
+    /// Path of the element being read. ex) "worksheet/sheetData/row/c"
+    #[inline]
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Byte offset in the part.
+    #[inline]
+    #[must_use]
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    #[inline]
+    pub(crate) fn has_part(&self) -> bool {
+        !self.part.is_empty()
+    }
+
+    #[inline]
+    pub(crate) fn set_part<S: Into<String>>(&mut self, part: S, path: S) {
+        self.part = part.into();
+        self.path = path.into();
+    }
+}
+
+impl fmt::Display for ParseError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} in {}", self.reason, self.part)?;
+        if !self.path.is_empty() {
+            write!(f, " at {}", self.path)?;
+        }
+        write!(f, " (byte {})", self.offset)
+    }
+}
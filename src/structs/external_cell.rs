@@ -0,0 +1,143 @@
+// cell
+use std::{
+    io::Cursor,
+    str::FromStr,
+};
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use crate::{
+    CellErrorType,
+    XlsxError,
+    helper::utils::{
+        unescape_xml_general_ref,
+        unescape_xml_text,
+    },
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+        xml_read_loop,
+    },
+    structs::{
+        CellRawValue,
+        StringValue,
+        UInt32Value,
+    },
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+        write_text_node,
+    },
+};
+
+/// A cell value of another workbook, cached with the link to it.
+#[derive(Clone, Default, Debug)]
+pub struct ExternalCell {
+    coordinate:     StringValue,
+    value_metadata: UInt32Value,
+    value:          CellRawValue,
+}
+
+impl ExternalCell {
+    /// Get the coordinate of the cell. ex) "A1"
+    #[inline]
+    #[must_use]
+    pub fn coordinate(&self) -> &str {
+        self.coordinate.value_str()
+    }
+
+    #[inline]
+    pub fn set_coordinate<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.coordinate.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn value(&self) -> &CellRawValue {
+        &self.value
+    }
+
+    #[inline]
+    pub fn set_value(&mut self, value: CellRawValue) -> &mut Self {
+        self.value = value;
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, coordinate, "r");
+        set_string_from_xml!(self, e, value_metadata, "vm");
+        if empty_flag {
+            return Ok(());
+        }
+
+        let data_type = get_attribute(e, b"t").unwrap_or_default();
+        let mut text = String::new();
+        xml_read_loop!(
+            reader,
+            Event::Text(e) => {
+                text.push_str(&unescape_xml_text(&e));
+            },
+            Event::GeneralRef(e) => {
+                text.push_str(&unescape_xml_general_ref(&e));
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"cell" {
+                    break
+                }
+            },
+            Event::Eof => return Err(XlsxError::missing_end_element("cell", reader.buffer_position()))
+        );
+        self.value = match data_type.as_str() {
+            "b" => CellRawValue::Bool(text == "1"),
+            "e" => CellErrorType::from_str(&text).map_or(CellRawValue::Empty, CellRawValue::Error),
+            "s" | "str" | "inlineStr" => CellRawValue::String(text.into_boxed_str()),
+            _ => text
+                .parse::<f64>()
+                .map_or(CellRawValue::Empty, CellRawValue::Numeric),
+        };
+        Ok(())
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // cell
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        attributes.push(("r", self.coordinate.value_str()).into());
+        let (data_type, text) = match &self.value {
+            CellRawValue::String(v) => (Some("str"), Some(v.to_string())),
+            CellRawValue::RichText(v) => (Some("str"), Some(v.text().to_string())),
+            CellRawValue::Bool(v) => (Some("b"), Some(if *v { "1" } else { "0" }.to_string())),
+            CellRawValue::Error(v) => (Some("e"), Some(v.to_string())),
+            CellRawValue::Numeric(v) => (None, Some(v.to_string())),
+            CellRawValue::Lazy(_) | CellRawValue::Empty => (None, None),
+        };
+        if let Some(v) = data_type {
+            attributes.push(("t", v).into());
+        }
+        let value_metadata = self.value_metadata.value_string();
+        if self.value_metadata.has_value() {
+            attributes.push(("vm", &value_metadata).into());
+        }
+        let Some(text) = text else {
+            write_start_tag(writer, "cell", attributes, true);
+            return;
+        };
+        write_start_tag(writer, "cell", attributes, false);
+        write_start_tag(writer, "v", vec![], false);
+        write_text_node(writer, text);
+        write_end_tag(writer, "v");
+        write_end_tag(writer, "cell");
+    }
+}
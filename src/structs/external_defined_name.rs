@@ -0,0 +1,89 @@
+// definedName
+use std::io::Cursor;
+
+use quick_xml::{
+    Writer,
+    events::BytesStart,
+};
+
+use crate::{
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+    },
+    structs::{
+        StringValue,
+        UInt32Value,
+    },
+    writer::driver::write_start_tag,
+};
+
+/// A defined name of another workbook, cached with the link to it.
+#[derive(Clone, Default, Debug)]
+pub struct ExternalDefinedName {
+    name:      StringValue,
+    refers_to: StringValue,
+    sheet_id:  UInt32Value,
+}
+
+impl ExternalDefinedName {
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &str {
+        self.name.value_str()
+    }
+
+    #[inline]
+    pub fn set_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.name.set_value(value);
+        self
+    }
+
+    /// Get the formula the name refers to. ex) "=Sheet1!$A$1"
+    #[inline]
+    #[must_use]
+    pub fn refers_to(&self) -> &str {
+        self.refers_to.value_str()
+    }
+
+    #[inline]
+    pub fn set_refers_to<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.refers_to.set_value(value);
+        self
+    }
+
+    /// Get the zero-based index of the sheet the name is scoped to, in the
+    /// sheet names of the link. `None` for a workbook scoped name.
+    #[inline]
+    #[must_use]
+    pub fn sheet_id(&self) -> Option<u32> {
+        self.sheet_id.has_value().then(|| self.sheet_id.value())
+    }
+
+    #[inline]
+    pub fn set_sheet_id(&mut self, value: u32) -> &mut Self {
+        self.sheet_id.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn set_attributes(&mut self, e: &BytesStart) {
+        set_string_from_xml!(self, e, name, "name");
+        set_string_from_xml!(self, e, refers_to, "refersTo");
+        set_string_from_xml!(self, e, sheet_id, "sheetId");
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // definedName
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        attributes.push(("name", self.name.value_str()).into());
+        if self.refers_to.has_value() {
+            attributes.push(("refersTo", self.refers_to.value_str()).into());
+        }
+        let sheet_id = self.sheet_id.value_string();
+        if self.sheet_id.has_value() {
+            attributes.push(("sheetId", &sheet_id).into());
+        }
+        write_start_tag(writer, "definedName", attributes, true);
+    }
+}
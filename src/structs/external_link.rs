@@ -0,0 +1,306 @@
+// externalLink
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use super::{
+    CellRawValue,
+    ExternalDefinedName,
+    ExternalSheetData,
+    raw::RawUnknown,
+};
+use crate::{
+    XlsxError,
+    helper::const_str::{
+        EXTERNAL_LINK_PATH_NS,
+        REL_OFC_NS,
+        SHEET_MAIN_NS,
+    },
+    reader::driver::{
+        get_attribute,
+        xml_read_loop,
+    },
+    structs::StringValue,
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+    },
+};
+
+/// Children of `externalLink` in schema order.
+pub(crate) const EXTERNAL_LINK_ELEMENTS: &[&str] = &["externalBook", "ddeLink", "oleLink", "extLst"];
+
+/// A link to another workbook, with the sheet names, the cell values and the
+/// defined names of it that the formulas of this workbook use.
+/// Formulas refer to the n-th link of the workbook as `[n]`.
+/// ex) `[1]Sheet1!A1`
+#[derive(Clone, Default, Debug)]
+pub struct ExternalLink {
+    r_id:              Box<str>,
+    target:            StringValue,
+    relationship_type: StringValue,
+    sheet_names:       Vec<String>,
+    defined_names:     Vec<ExternalDefinedName>,
+    sheet_data_set:    Vec<ExternalSheetData>,
+    raw_unknown:       RawUnknown,
+}
+
+impl ExternalLink {
+    /// Create a link to the workbook at `target`.
+    /// # Arguments
+    /// * `target` - Path of the workbook. ex) "Budget.xlsx"
+    #[inline]
+    #[must_use]
+    pub fn new<S: Into<String>>(target: S) -> Self {
+        let mut obj = Self::default();
+        obj.set_target(target);
+        obj
+    }
+
+    /// Get the path of the linked workbook. ex) "Budget.xlsx"
+    #[inline]
+    #[must_use]
+    pub fn target(&self) -> &str {
+        self.target.value_str()
+    }
+
+    /// Set the path of the linked workbook, pointing the link to another
+    /// file.
+    #[inline]
+    pub fn set_target<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.target.set_value(value);
+        self.relationship_type.remove_value();
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn sheet_names(&self) -> &[String] {
+        &self.sheet_names
+    }
+
+    #[inline]
+    pub fn sheet_names_mut(&mut self) -> &mut Vec<String> {
+        &mut self.sheet_names
+    }
+
+    #[inline]
+    pub fn add_sheet_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.sheet_names.push(value.into());
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn defined_names(&self) -> &[ExternalDefinedName] {
+        &self.defined_names
+    }
+
+    #[inline]
+    pub fn defined_names_mut(&mut self) -> &mut Vec<ExternalDefinedName> {
+        &mut self.defined_names
+    }
+
+    #[inline]
+    pub fn add_defined_name(&mut self, value: ExternalDefinedName) -> &mut Self {
+        self.defined_names.push(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn sheet_data_set(&self) -> &[ExternalSheetData] {
+        &self.sheet_data_set
+    }
+
+    #[inline]
+    pub fn sheet_data_set_mut(&mut self) -> &mut Vec<ExternalSheetData> {
+        &mut self.sheet_data_set
+    }
+
+    #[inline]
+    pub fn add_sheet_data(&mut self, value: ExternalSheetData) -> &mut Self {
+        self.sheet_data_set.push(value);
+        self
+    }
+
+    /// Get the cached cell values of a sheet of the linked workbook.
+    /// # Arguments
+    /// * `sheet_name` - Sheet name, compared case-insensitively.
+    #[must_use]
+    pub fn sheet_data(&self, sheet_name: &str) -> Option<&ExternalSheetData> {
+        let sheet_name = sheet_name.to_lowercase();
+        let sheet_id = self
+            .sheet_names
+            .iter()
+            .position(|v| v.to_lowercase() == sheet_name)?;
+        self.sheet_data_set
+            .iter()
+            .find(|v| v.sheet_id() as usize == sheet_id)
+    }
+
+    /// Get the cached value of a cell of the linked workbook.
+    /// # Arguments
+    /// * `sheet_name` - Sheet name, compared case-insensitively.
+    /// * `coordinate` - ex) "A1", "$A$1"
+    #[must_use]
+    pub fn cached_value(&self, sheet_name: &str, coordinate: &str) -> Option<&CellRawValue> {
+        self.sheet_data(sheet_name)?
+            .cell(coordinate)
+            .map(super::ExternalCell::value)
+    }
+
+    /// (This method is crate only.)
+    /// Get the relationship id the workbook refers to the link with.
+    #[inline]
+    pub(crate) fn r_id(&self) -> &str {
+        &self.r_id
+    }
+
+    #[inline]
+    pub(crate) fn set_r_id<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.r_id = value.into().into_boxed_str();
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Get the type of the relationship to the linked workbook.
+    #[inline]
+    pub(crate) fn relationship_type(&self) -> &str {
+        if self.relationship_type.has_value() {
+            self.relationship_type.value_str()
+        } else {
+            EXTERNAL_LINK_PATH_NS
+        }
+    }
+
+    #[inline]
+    pub(crate) fn set_relationship_type<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.relationship_type.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn raw_unknown(&self) -> &RawUnknown {
+        &self.raw_unknown
+    }
+
+    #[inline]
+    pub(crate) fn raw_unknown_mut(&mut self) -> &mut RawUnknown {
+        &mut self.raw_unknown
+    }
+
+    /// (This method is crate only.)
+    /// Whether the link is to a workbook, rather than a DDE or OLE link.
+    #[inline]
+    pub(crate) fn has_external_book(&self) -> bool {
+        self.target.has_value() || self.raw_unknown.elements().is_empty()
+    }
+
+    /// (This method is crate only.)
+    /// Read the `externalBook` element.
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                match e.name().into_inner() {
+                    b"sheetName" => {
+                        self.add_sheet_name(get_attribute(e, b"val").unwrap_or_default());
+                    }
+                    b"definedName" => {
+                        let mut obj = ExternalDefinedName::default();
+                        obj.set_attributes(e);
+                        self.add_defined_name(obj);
+                    }
+                    b"sheetData" => {
+                        let mut obj = ExternalSheetData::default();
+                        obj.set_attributes(reader, e, true)?;
+                        self.add_sheet_data(obj);
+                    }
+                    _ => (),
+                }
+            },
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"sheetData" {
+                    let mut obj = ExternalSheetData::default();
+                    obj.set_attributes(reader, e, false)?;
+                    self.add_sheet_data(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"externalBook" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(XlsxError::missing_end_element("externalBook", reader.buffer_position()))
+        );
+    }
+
+    /// (This method is crate only.)
+    /// Write the `externalLink` element, the linked workbook being the
+    /// relationship `r_id`.
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, r_id: &str) {
+        // externalLink
+        let mut attributes: crate::structs::AttrCollection =
+            vec![("xmlns", SHEET_MAIN_NS).into()];
+        self.raw_unknown.extend_root_attributes(&mut attributes);
+        write_start_tag(writer, "externalLink", attributes, false);
+
+        // externalBook
+        if self.has_external_book() {
+            let mut attributes: crate::structs::AttrCollection =
+                vec![("xmlns:r", REL_OFC_NS).into()];
+            if self.target.has_value() {
+                attributes.push(("r:id", r_id).into());
+            }
+            write_start_tag(writer, "externalBook", attributes, false);
+
+            // sheetNames
+            if !self.sheet_names.is_empty() {
+                write_start_tag(writer, "sheetNames", vec![], false);
+                for sheet_name in &self.sheet_names {
+                    write_start_tag(writer, "sheetName", vec![("val", sheet_name).into()], true);
+                }
+                write_end_tag(writer, "sheetNames");
+            }
+
+            // definedNames
+            if !self.defined_names.is_empty() {
+                write_start_tag(writer, "definedNames", vec![], false);
+                for defined_name in &self.defined_names {
+                    defined_name.write_to(writer);
+                }
+                write_end_tag(writer, "definedNames");
+            }
+
+            // sheetDataSet
+            if !self.sheet_data_set.is_empty() {
+                write_start_tag(writer, "sheetDataSet", vec![], false);
+                for sheet_data in &self.sheet_data_set {
+                    sheet_data.write_to(writer);
+                }
+                write_end_tag(writer, "sheetDataSet");
+            }
+
+            write_end_tag(writer, "externalBook");
+        }
+        self.raw_unknown
+            .write_elements(writer, EXTERNAL_LINK_ELEMENTS, "externalBook", "extLst");
+
+        // extLst
+        self.raw_unknown.write_ext_lst(writer);
+
+        write_end_tag(writer, "externalLink");
+    }
+}
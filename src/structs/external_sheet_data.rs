@@ -0,0 +1,169 @@
+// sheetData
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use super::ExternalCell;
+use crate::{
+    XlsxError,
+    helper::coordinate::index_from_coordinate,
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+        xml_read_loop,
+    },
+    structs::{
+        BooleanValue,
+        UInt32Value,
+    },
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+    },
+};
+
+/// The cell values of a sheet of another workbook, cached with the link to
+/// it.
+#[derive(Clone, Default, Debug)]
+pub struct ExternalSheetData {
+    sheet_id:      UInt32Value,
+    refresh_error: BooleanValue,
+    cells:         Vec<ExternalCell>,
+}
+
+impl ExternalSheetData {
+    /// Get the zero-based index of the sheet in the sheet names of the link.
+    #[inline]
+    #[must_use]
+    pub fn sheet_id(&self) -> u32 {
+        self.sheet_id.value()
+    }
+
+    #[inline]
+    pub fn set_sheet_id(&mut self, value: u32) -> &mut Self {
+        self.sheet_id.set_value(value);
+        self
+    }
+
+    /// Whether the last update of the values failed.
+    #[inline]
+    #[must_use]
+    pub fn refresh_error(&self) -> bool {
+        self.refresh_error.value()
+    }
+
+    #[inline]
+    pub fn set_refresh_error(&mut self, value: bool) -> &mut Self {
+        self.refresh_error.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn cells(&self) -> &[ExternalCell] {
+        &self.cells
+    }
+
+    #[inline]
+    pub fn cells_mut(&mut self) -> &mut Vec<ExternalCell> {
+        &mut self.cells
+    }
+
+    #[inline]
+    pub fn add_cell(&mut self, value: ExternalCell) -> &mut Self {
+        self.cells.push(value);
+        self
+    }
+
+    /// Get the cached cell at a coordinate.
+    /// # Arguments
+    /// * `coordinate` - ex) "A1", "$A$1"
+    #[must_use]
+    pub fn cell(&self, coordinate: &str) -> Option<&ExternalCell> {
+        let (col, row, ..) = index_from_coordinate(coordinate.to_uppercase());
+        self.cells.iter().find(|cell| {
+            let (cell_col, cell_row, ..) = index_from_coordinate(cell.coordinate());
+            cell_col == col && cell_row == row
+        })
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, sheet_id, "sheetId");
+        set_string_from_xml!(self, e, refresh_error, "refreshError");
+        if empty_flag {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"cell" {
+                    let mut obj = ExternalCell::default();
+                    obj.set_attributes(reader, e, true)?;
+                    self.add_cell(obj);
+                }
+            },
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"cell" {
+                    let mut obj = ExternalCell::default();
+                    obj.set_attributes(reader, e, false)?;
+                    self.add_cell(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"sheetData" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(XlsxError::missing_end_element("sheetData", reader.buffer_position()))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // sheetData
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        let sheet_id = self.sheet_id.value_string();
+        attributes.push(("sheetId", &sheet_id).into());
+        if self.refresh_error.has_value() {
+            attributes.push(("refreshError", self.refresh_error.value_string()).into());
+        }
+        if self.cells.is_empty() {
+            write_start_tag(writer, "sheetData", attributes, true);
+            return;
+        }
+        write_start_tag(writer, "sheetData", attributes, false);
+
+        // row
+        let mut cells: Vec<(u32, u32, &ExternalCell)> = self
+            .cells
+            .iter()
+            .map(|cell| {
+                let (col, row, ..) = index_from_coordinate(cell.coordinate());
+                (row.unwrap_or(0), col.unwrap_or(0), cell)
+            })
+            .collect();
+        cells.sort_by_key(|(row, col, _)| (*row, *col));
+        for row_cells in cells.chunk_by(|a, b| a.0 == b.0) {
+            let row = row_cells[0].0.to_string();
+            write_start_tag(writer, "row", vec![("r", &row).into()], false);
+            for (.., cell) in row_cells {
+                cell.write_to(writer);
+            }
+            write_end_tag(writer, "row");
+        }
+
+        write_end_tag(writer, "sheetData");
+    }
+}
@@ -15,6 +15,7 @@ use super::{
     Style,
 };
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -80,22 +81,22 @@ impl Fills {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"fill" {
                     let mut obj = Fill::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_fill(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"fills" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "fills")
+            Event::Eof => return Err(XlsxError::missing_end_element("fills", reader.buffer_position()))
         );
     }
 
@@ -0,0 +1,354 @@
+// filterColumn
+use std::io::Cursor;
+
+use chrono::NaiveDate;
+use quick_xml::{
+    Reader,
+    Writer,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use super::{
+    BooleanValue,
+    Cell,
+    ColorFilter,
+    CustomFilters,
+    DifferentialFormats,
+    DynamicFilter,
+    Filters,
+    Top10,
+    UInt32Value,
+};
+use crate::{
+    XlsxError,
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+        xml_read_loop,
+    },
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+    },
+};
+
+#[derive(Clone, Default, Debug)]
+pub struct FilterColumn {
+    column_id:      UInt32Value,
+    hidden_button:  BooleanValue,
+    show_button:    BooleanValue,
+    filters:        Option<Filters>,
+    custom_filters: Option<CustomFilters>,
+    top10:          Option<Top10>,
+    dynamic_filter: Option<DynamicFilter>,
+    color_filter:   Option<ColorFilter>,
+}
+
+impl FilterColumn {
+    /// Create a filter for the column at `column_id`,
+    /// counted from 0 at the first column of the filtered range.
+    #[inline]
+    #[must_use]
+    pub fn new(column_id: u32) -> Self {
+        let mut obj = Self::default();
+        obj.set_column_id(column_id);
+        obj
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn column_id(&self) -> u32 {
+        self.column_id.value()
+    }
+
+    #[inline]
+    pub fn set_column_id(&mut self, value: u32) -> &mut Self {
+        self.column_id.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn hidden_button(&self) -> bool {
+        self.hidden_button.value()
+    }
+
+    #[inline]
+    pub fn set_hidden_button(&mut self, value: bool) -> &mut Self {
+        self.hidden_button.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn show_button(&self) -> bool {
+        !self.show_button.has_value() || self.show_button.value()
+    }
+
+    #[inline]
+    pub fn set_show_button(&mut self, value: bool) -> &mut Self {
+        self.show_button.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn filters(&self) -> Option<&Filters> {
+        self.filters.as_ref()
+    }
+
+    #[inline]
+    pub fn filters_mut(&mut self) -> Option<&mut Filters> {
+        self.filters.as_mut()
+    }
+
+    /// Set a value list criteria, replacing the current criteria.
+    #[inline]
+    pub fn set_filters(&mut self, value: Filters) -> &mut Self {
+        self.remove_criteria();
+        self.filters = Some(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn custom_filters(&self) -> Option<&CustomFilters> {
+        self.custom_filters.as_ref()
+    }
+
+    #[inline]
+    pub fn custom_filters_mut(&mut self) -> Option<&mut CustomFilters> {
+        self.custom_filters.as_mut()
+    }
+
+    /// Set a custom criteria, replacing the current criteria.
+    #[inline]
+    pub fn set_custom_filters(&mut self, value: CustomFilters) -> &mut Self {
+        self.remove_criteria();
+        self.custom_filters = Some(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn top10(&self) -> Option<&Top10> {
+        self.top10.as_ref()
+    }
+
+    #[inline]
+    pub fn top10_mut(&mut self) -> Option<&mut Top10> {
+        self.top10.as_mut()
+    }
+
+    /// Set a top 10 criteria, replacing the current criteria.
+    #[inline]
+    pub fn set_top10(&mut self, value: Top10) -> &mut Self {
+        self.remove_criteria();
+        self.top10 = Some(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn dynamic_filter(&self) -> Option<&DynamicFilter> {
+        self.dynamic_filter.as_ref()
+    }
+
+    #[inline]
+    pub fn dynamic_filter_mut(&mut self) -> Option<&mut DynamicFilter> {
+        self.dynamic_filter.as_mut()
+    }
+
+    /// Set a dynamic criteria, replacing the current criteria.
+    #[inline]
+    pub fn set_dynamic_filter(&mut self, value: DynamicFilter) -> &mut Self {
+        self.remove_criteria();
+        self.dynamic_filter = Some(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn color_filter(&self) -> Option<&ColorFilter> {
+        self.color_filter.as_ref()
+    }
+
+    #[inline]
+    pub fn color_filter_mut(&mut self) -> Option<&mut ColorFilter> {
+        self.color_filter.as_mut()
+    }
+
+    /// Set a color criteria, replacing the current criteria.
+    #[inline]
+    pub fn set_color_filter(&mut self, value: ColorFilter) -> &mut Self {
+        self.remove_criteria();
+        self.color_filter = Some(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_criteria(&mut self) -> &mut Self {
+        self.filters = None;
+        self.custom_filters = None;
+        self.top10 = None;
+        self.dynamic_filter = None;
+        self.color_filter = None;
+        self
+    }
+
+    /// Evaluate the criteria for the cells of the column.
+    /// Computed values such as the top 10 threshold are stored back.
+    pub(crate) fn evaluate(&mut self, cells: &[Option<&Cell>], today: NaiveDate) -> Vec<bool> {
+        let numbers: Vec<f64> = cells
+            .iter()
+            .filter_map(|x| x.and_then(Cell::value_number))
+            .collect();
+
+        let mut threshold = None;
+        if let Some(top10) = &mut self.top10 {
+            threshold = top10.threshold(&numbers);
+            if let Some(v) = threshold {
+                top10.set_filter_val(v);
+            }
+        }
+        if let Some(dynamic_filter) = &mut self.dynamic_filter {
+            dynamic_filter.prepare(&numbers, today);
+        }
+
+        cells
+            .iter()
+            .map(|cell| {
+                let number = cell.and_then(Cell::value_number);
+                let text = cell.map(Cell::formatted_value).unwrap_or_default();
+                if let Some(v) = &self.filters {
+                    return v.is_match(number, &text);
+                }
+                if let Some(v) = &self.custom_filters {
+                    return v.is_match(number, &text);
+                }
+                if let Some(v) = &self.top10 {
+                    return v.is_match(number, threshold);
+                }
+                if let Some(v) = &self.dynamic_filter {
+                    return v.is_match(number);
+                }
+                if let Some(v) = &self.color_filter {
+                    return v.is_match(cell.map(Cell::style));
+                }
+                true
+            })
+            .collect()
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        differential_formats: &DifferentialFormats,
+        empty_flg: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, column_id, "colId");
+        set_string_from_xml!(self, e, hidden_button, "hiddenButton");
+        set_string_from_xml!(self, e, show_button, "showButton");
+
+        if empty_flg {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                match e.name().into_inner() {
+                    b"filters" => {
+                        let mut obj = Filters::default();
+                        obj.set_attributes(reader, e, true)?;
+                        self.filters = Some(obj);
+                    }
+                    b"top10" => {
+                        let mut obj = Top10::default();
+                        obj.set_attributes(reader, e)?;
+                        self.top10 = Some(obj);
+                    }
+                    b"dynamicFilter" => {
+                        let mut obj = DynamicFilter::default();
+                        obj.set_attributes(reader, e)?;
+                        self.dynamic_filter = Some(obj);
+                    }
+                    b"colorFilter" => {
+                        let mut obj = ColorFilter::default();
+                        obj.set_attributes(reader, e, differential_formats);
+                        self.color_filter = Some(obj);
+                    }
+                    _ => (),
+                }
+            },
+            Event::Start(ref e) => {
+                match e.name().into_inner() {
+                    b"filters" => {
+                        let mut obj = Filters::default();
+                        obj.set_attributes(reader, e, false)?;
+                        self.filters = Some(obj);
+                    }
+                    b"customFilters" => {
+                        let mut obj = CustomFilters::default();
+                        obj.set_attributes(reader, e, false)?;
+                        self.custom_filters = Some(obj);
+                    }
+                    _ => (),
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"filterColumn" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(XlsxError::missing_end_element("filterColumn", reader.buffer_position()))
+        );
+    }
+
+    pub(crate) fn write_to(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        differential_formats: &mut DifferentialFormats,
+    ) {
+        // filterColumn
+        let column_id = self.column_id.value_string();
+        let mut attributes: crate::structs::AttrCollection = vec![("colId", &column_id).into()];
+        if self.hidden_button.has_value() {
+            attributes.push(("hiddenButton", self.hidden_button.value_string()).into());
+        }
+        if self.show_button.has_value() {
+            attributes.push(("showButton", self.show_button.value_string()).into());
+        }
+        let is_empty = self.filters.is_none()
+            && self.custom_filters.is_none()
+            && self.top10.is_none()
+            && self.dynamic_filter.is_none()
+            && self.color_filter.is_none();
+        write_start_tag(writer, "filterColumn", attributes, is_empty);
+        if is_empty {
+            return;
+        }
+
+        if let Some(v) = &self.filters {
+            v.write_to(writer);
+        }
+        if let Some(v) = &self.top10 {
+            v.write_to(writer);
+        }
+        if let Some(v) = &self.custom_filters {
+            v.write_to(writer);
+        }
+        if let Some(v) = &self.dynamic_filter {
+            v.write_to(writer);
+        }
+        if let Some(v) = &self.color_filter {
+            v.write_to(writer, differential_formats);
+        }
+
+        write_end_tag(writer, "filterColumn");
+    }
+}
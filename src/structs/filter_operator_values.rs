@@ -0,0 +1,47 @@
+use std::str::FromStr;
+
+use super::EnumTrait;
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum FilterOperatorValues {
+    Equal,
+    GreaterThan,
+    GreaterThanOrEqual,
+    LessThan,
+    LessThanOrEqual,
+    NotEqual,
+}
+impl Default for FilterOperatorValues {
+    #[inline]
+    fn default() -> Self {
+        Self::Equal
+    }
+}
+impl EnumTrait for FilterOperatorValues {
+    #[inline]
+    fn value_string(&self) -> &str {
+        match &self {
+            Self::Equal => "equal",
+            Self::GreaterThan => "greaterThan",
+            Self::GreaterThanOrEqual => "greaterThanOrEqual",
+            Self::LessThan => "lessThan",
+            Self::LessThanOrEqual => "lessThanOrEqual",
+            Self::NotEqual => "notEqual",
+        }
+    }
+}
+impl FromStr for FilterOperatorValues {
+    type Err = ();
+
+    #[inline]
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "equal" => Ok(Self::Equal),
+            "greaterThan" => Ok(Self::GreaterThan),
+            "greaterThanOrEqual" => Ok(Self::GreaterThanOrEqual),
+            "lessThan" => Ok(Self::LessThan),
+            "lessThanOrEqual" => Ok(Self::LessThanOrEqual),
+            "notEqual" => Ok(Self::NotEqual),
+            _ => Err(()),
+        }
+    }
+}
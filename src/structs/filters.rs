@@ -0,0 +1,171 @@
+// filters
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use super::{
+    BooleanValue,
+    DateGroupItem,
+};
+use crate::{
+    XlsxError,
+    helper::date::{
+        MAX_SERIAL_DATE,
+        excel_to_date_time_chrono,
+    },
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+        xml_read_loop,
+    },
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+    },
+};
+
+#[derive(Clone, Default, Debug)]
+pub struct Filters {
+    blank:                BooleanValue,
+    filter_list:          Vec<String>,
+    date_group_item_list: Vec<DateGroupItem>,
+}
+
+impl Filters {
+    /// Whether blank cells are shown.
+    #[inline]
+    #[must_use]
+    pub fn blank(&self) -> bool {
+        self.blank.value()
+    }
+
+    #[inline]
+    pub fn set_blank(&mut self, value: bool) -> &mut Self {
+        self.blank.set_value(value);
+        self
+    }
+
+    /// Get the shown values, as displayed in the cells.
+    #[inline]
+    #[must_use]
+    pub fn filter_list(&self) -> &[String] {
+        &self.filter_list
+    }
+
+    #[inline]
+    pub fn filter_list_mut(&mut self) -> &mut Vec<String> {
+        &mut self.filter_list
+    }
+
+    #[inline]
+    pub fn add_filter<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.filter_list.push(value.into());
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn date_group_item_list(&self) -> &[DateGroupItem] {
+        &self.date_group_item_list
+    }
+
+    #[inline]
+    pub fn date_group_item_list_mut(&mut self) -> &mut Vec<DateGroupItem> {
+        &mut self.date_group_item_list
+    }
+
+    #[inline]
+    pub fn add_date_group_item(&mut self, value: DateGroupItem) -> &mut Self {
+        self.date_group_item_list.push(value);
+        self
+    }
+
+    pub(crate) fn is_match(&self, number: Option<f64>, text: &str) -> bool {
+        if text.is_empty() {
+            return self.blank();
+        }
+        if self
+            .filter_list
+            .iter()
+            .any(|x| x.to_lowercase() == text.to_lowercase())
+        {
+            return true;
+        }
+        // Numbers out of the dates of Excel match no date group.
+        if let Some(number) = number.filter(|v| (0.0..=MAX_SERIAL_DATE).contains(v)) {
+            let date = excel_to_date_time_chrono(number);
+            return self.date_group_item_list.iter().any(|x| x.is_match(&date));
+        }
+        false
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flg: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, blank, "blank");
+
+        if empty_flg {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                match e.name().into_inner() {
+                    b"filter" => {
+                        if let Some(v) = get_attribute(e, b"val") {
+                            self.filter_list.push(v);
+                        }
+                    }
+                    b"dateGroupItem" => {
+                        let mut obj = DateGroupItem::default();
+                        obj.set_attributes(reader, e)?;
+                        self.date_group_item_list.push(obj);
+                    }
+                    _ => (),
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"filters" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(XlsxError::missing_end_element("filters", reader.buffer_position()))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // filters
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        if self.blank.has_value() {
+            attributes.push(("blank", self.blank.value_string()).into());
+        }
+        let is_empty = self.filter_list.is_empty() && self.date_group_item_list.is_empty();
+        write_start_tag(writer, "filters", attributes, is_empty);
+        if is_empty {
+            return;
+        }
+
+        // filter
+        for value in &self.filter_list {
+            write_start_tag(writer, "filter", vec![("val", value).into()], true);
+        }
+
+        // dateGroupItem
+        for date_group_item in &self.date_group_item_list {
+            date_group_item.write_to(writer);
+        }
+
+        write_end_tag(writer, "filters");
+    }
+}
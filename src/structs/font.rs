@@ -33,6 +33,8 @@ use crate::writer::driver::{
     write_start_tag,
 };
 
+use crate::XlsxError;
+
 #[derive(Clone, Default, Debug, PartialEq, PartialOrd)]
 pub struct Font {
     font_name:               FontName,
@@ -636,16 +638,16 @@ impl Font {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut buf = Vec::new();
         loop {
             match reader.read_event_into(&mut buf) {
                 Ok(Event::Empty(ref e)) => match e.name().into_inner() {
                     b"rFont" | b"name" => {
-                        self.font_name.set_attributes(reader, e);
+                        self.font_name.set_attributes(reader, e)?;
                     }
                     b"sz" => {
-                        self.font_size.set_attributes(reader, e);
+                        self.font_size.set_attributes(reader, e)?;
                     }
                     b"family" => {
                         self.font_family_numbering.set_attributes(reader, e);
@@ -663,13 +665,13 @@ impl Font {
                         self.font_strike.set_attributes(reader, e);
                     }
                     b"color" => {
-                        self.color.set_attributes(reader, e, true);
+                        self.color.set_attributes(reader, e, true)?;
                     }
                     b"charset" => {
                         self.font_char_set.set_attributes(reader, e);
                     }
                     b"scheme" => {
-                        self.font_scheme.set_attributes(reader, e);
+                        self.font_scheme.set_attributes(reader, e)?;
                     }
                     b"vertAlign" => {
                         self.vertical_text_alignment.set_attributes(reader, e);
@@ -677,11 +679,11 @@ impl Font {
                     _ => (),
                 },
                 Ok(Event::End(ref e)) => match e.name().into_inner() {
-                    b"font" | b"rPr" => return,
+                    b"font" | b"rPr" => return Ok(()),
                     _ => (),
                 },
-                Ok(Event::Eof) => panic!("Error: Could not find {} end element", "font, rPr"),
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(XlsxError::missing_end_element("font, rPr", reader.buffer_position())),
+                Err(e) => return Err(XlsxError::parse(reader.buffer_position(), e.to_string())),
                 _ => (),
             }
             buf.clear();
@@ -11,6 +11,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     structs::{
         Font,
@@ -80,7 +81,7 @@ impl Fonts {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
@@ -92,16 +93,16 @@ impl Fonts {
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"font" {
                     let mut obj = Font::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_font(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"fonts" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "fonts")
+            Event::Eof => return Err(XlsxError::missing_end_element("fonts", reader.buffer_position()))
         );
     }
 
@@ -0,0 +1,670 @@
+// control
+use std::{
+    io::Cursor,
+    str::FromStr,
+};
+
+use quick_xml::{
+    Reader,
+    Writer,
+    escape,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use super::{
+    CheckedValues,
+    EnumTrait,
+    ObjectAnchor,
+    StringValue,
+};
+use crate::{
+    XlsxError,
+    helper::{
+        const_str::{
+            MC_NS,
+            SHEET_MS_MAIN_NS,
+        },
+        coordinate::CellCoordinates,
+    },
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+        xml_read_loop,
+    },
+    structs::{
+        vml::{
+            Path,
+            Shape,
+            TextBox,
+            spreadsheet::{
+                Anchor,
+                MoveWithCells,
+                ObjectValues,
+                ResizeWithCells,
+            },
+        },
+    },
+    traits::AdjustmentCoordinate,
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+    },
+};
+
+/// A form control (check box, drop down, button, option button, spinner...).
+///
+/// The properties are kept in the VML `x:ClientData` of the shape and are
+/// mirrored into the `ctrlProps/ctrlPropN.xml` part when the file is written.
+#[derive(Clone, Default, Debug)]
+pub struct FormControl {
+    name:  StringValue,
+    r_id:  StringValue,
+    shape: Shape,
+}
+
+impl FormControl {
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &str {
+        self.name.value_str()
+    }
+
+    #[inline]
+    pub fn set_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.name.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn object_type(&self) -> &ObjectValues {
+        self.shape.client_data().object_type()
+    }
+
+    #[inline]
+    pub fn set_object_type(&mut self, value: ObjectValues) -> &mut Self {
+        self.shape.client_data_mut().set_object_type(value);
+        self
+    }
+
+    /// Get the caption of the control.
+    #[must_use]
+    pub fn text(&self) -> String {
+        let Some(text_box) = self.shape.text_box() else {
+            return String::new();
+        };
+        let mut result = String::new();
+        let mut in_tag = false;
+        for c in text_box.innder().chars() {
+            match c {
+                '<' => in_tag = true,
+                '>' => in_tag = false,
+                _ if !in_tag => result.push(c),
+                _ => (),
+            }
+        }
+        escape::unescape(&result)
+            .map(std::borrow::Cow::into_owned)
+            .unwrap_or(result)
+    }
+
+    /// Set the caption of the control.
+    pub fn set_text<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        let value = value.into();
+        let mut text_box = TextBox::default();
+        text_box.set_style("mso-direction-alt:auto").set_innder(format!(
+            "<div style='text-align:left'><font face=\"Segoe UI\" size=\"160\" \
+             color=\"auto\">{}</font></div>",
+            escape::escape(&value)
+        ));
+        self.shape.set_text_box(text_box);
+        self
+    }
+
+    /// Cell linked to the value of the control. ex) `"$A$1"`
+    #[inline]
+    #[must_use]
+    pub fn linked_cell(&self) -> &str {
+        self.shape.client_data().formula_link()
+    }
+
+    #[inline]
+    pub fn set_linked_cell<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.shape.client_data_mut().set_formula_link(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_linked_cell(&mut self) -> &mut Self {
+        self.shape.client_data_mut().remove_formula_link();
+        self
+    }
+
+    /// Range used to fill a list box or a drop down. ex) `"$D$1:$D$5"`
+    #[inline]
+    #[must_use]
+    pub fn list_fill_range(&self) -> &str {
+        self.shape.client_data().formula_range()
+    }
+
+    #[inline]
+    pub fn set_list_fill_range<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.shape.client_data_mut().set_formula_range(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_list_fill_range(&mut self) -> &mut Self {
+        self.shape.client_data_mut().remove_formula_range();
+        self
+    }
+
+    /// Macro run when the control is clicked. ex) `"[0]!Macro1"`
+    #[inline]
+    #[must_use]
+    pub fn macro_name(&self) -> &str {
+        self.shape.client_data().formula_macro()
+    }
+
+    #[inline]
+    pub fn set_macro_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.shape.client_data_mut().set_formula_macro(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_macro_name(&mut self) -> &mut Self {
+        self.shape.client_data_mut().remove_formula_macro();
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn checked(&self) -> CheckedValues {
+        match self.shape.client_data().checked() {
+            1 => CheckedValues::Checked,
+            2 => CheckedValues::Mixed,
+            _ => CheckedValues::Unchecked,
+        }
+    }
+
+    #[inline]
+    pub fn set_checked(&mut self, value: CheckedValues) -> &mut Self {
+        let value = match value {
+            CheckedValues::Unchecked => 0,
+            CheckedValues::Checked => 1,
+            CheckedValues::Mixed => 2,
+        };
+        self.shape.client_data_mut().set_checked(value);
+        self
+    }
+
+    /// Current value of a spinner or a scroll bar.
+    #[inline]
+    #[must_use]
+    pub fn current_value(&self) -> i32 {
+        self.shape.client_data().current_value()
+    }
+
+    #[inline]
+    pub fn set_current_value(&mut self, value: i32) -> &mut Self {
+        self.shape.client_data_mut().set_current_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn minimum(&self) -> i32 {
+        self.shape.client_data().minimum()
+    }
+
+    #[inline]
+    pub fn set_minimum(&mut self, value: i32) -> &mut Self {
+        self.shape.client_data_mut().set_minimum(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn maximum(&self) -> i32 {
+        self.shape.client_data().maximum()
+    }
+
+    #[inline]
+    pub fn set_maximum(&mut self, value: i32) -> &mut Self {
+        self.shape.client_data_mut().set_maximum(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn increment(&self) -> i32 {
+        self.shape.client_data().increment()
+    }
+
+    #[inline]
+    pub fn set_increment(&mut self, value: i32) -> &mut Self {
+        self.shape.client_data_mut().set_increment(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn page(&self) -> i32 {
+        self.shape.client_data().page()
+    }
+
+    #[inline]
+    pub fn set_page(&mut self, value: i32) -> &mut Self {
+        self.shape.client_data_mut().set_page(value);
+        self
+    }
+
+    /// Selected item of a list box or a drop down (1-based, 0 is none).
+    #[inline]
+    #[must_use]
+    pub fn selected(&self) -> u32 {
+        self.shape.client_data().selection()
+    }
+
+    #[inline]
+    pub fn set_selected(&mut self, value: u32) -> &mut Self {
+        self.shape.client_data_mut().set_selection(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn drop_lines(&self) -> u32 {
+        self.shape.client_data().drop_lines()
+    }
+
+    #[inline]
+    pub fn set_drop_lines(&mut self, value: u32) -> &mut Self {
+        self.shape.client_data_mut().set_drop_lines(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn anchor(&self) -> &Anchor {
+        self.shape.client_data().anchor()
+    }
+
+    #[inline]
+    pub fn anchor_mut(&mut self) -> &mut Anchor {
+        self.shape.client_data_mut().anchor_mut()
+    }
+
+    #[inline]
+    pub fn set_anchor(&mut self, value: Anchor) -> &mut Self {
+        self.shape.client_data_mut().set_anchor(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    #[inline]
+    pub fn shape_mut(&mut self) -> &mut Shape {
+        &mut self.shape
+    }
+
+    #[inline]
+    pub fn set_shape(&mut self, value: Shape) -> &mut Self {
+        self.shape = value;
+        self
+    }
+
+    #[inline]
+    pub(crate) fn r_id(&self) -> &str {
+        self.r_id.value_str()
+    }
+
+    /// Build a new form control placed over a range of cells.
+    /// # Arguments
+    /// * `object_type` - Type of the control.
+    /// * `from` - Top left cell.
+    /// * `to` - Bottom right cell.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::{structs::vml::spreadsheet::ObjectValues, *};
+    ///
+    /// let mut book = new_file();
+    /// let mut control = FormControl::default();
+    /// control
+    ///     .new_form_control(ObjectValues::Checkbox, "B2", "C2")
+    ///     .set_text("Done")
+    ///     .set_linked_cell("$A$2")
+    ///     .set_checked(CheckedValues::Checked);
+    /// book.sheet_mut(0).unwrap().add_form_control(control);
+    /// ```
+    pub fn new_form_control<F, T>(&mut self, object_type: ObjectValues, from: F, to: T) -> &mut Self
+    where
+        F: Into<CellCoordinates>,
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates {
+            col: from_col,
+            row: from_row,
+        } = from.into();
+        let CellCoordinates {
+            col: to_col,
+            row: to_row,
+        } = to.into();
+
+        let (fill_color, stroked) = match object_type {
+            ObjectValues::Button => ("buttonFace [67]", true),
+            _ => ("window [65]", false),
+        };
+        self.shape
+            .set_type("#_x0000_t201")
+            .set_style("position:absolute;z-index:1;mso-wrap-style:tight")
+            .set_filled(matches!(object_type, ObjectValues::Button))
+            .set_fill_color(fill_color)
+            .set_stroked(stroked)
+            .set_stroke_color("windowText [64]");
+
+        let mut path = Path::default();
+        path.set_connection_point_type(super::vml::office::ConnectValues::Rectangle);
+        self.shape.set_path(path);
+
+        let client_data = self.shape.client_data_mut();
+        client_data
+            .set_object_type(object_type)
+            .set_move_with_cells(MoveWithCells::default())
+            .set_resize_with_cells(ResizeWithCells::default());
+        client_data
+            .anchor_mut()
+            .set_left_column(from_col - 1)
+            .set_left_offset(0)
+            .set_top_row(from_row - 1)
+            .set_top_offset(0)
+            .set_right_column(to_col)
+            .set_right_offset(0)
+            .set_bottom_row(to_row)
+            .set_bottom_offset(0);
+        if !matches!(
+            client_data.object_type(),
+            ObjectValues::Button | ObjectValues::GroupBox | ObjectValues::Label
+        ) {
+            client_data.set_no_three_d(true);
+        }
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Whether a VML object type is handled as a form control.
+    #[inline]
+    pub(crate) fn is_form_control_type(value: &ObjectValues) -> bool {
+        matches!(
+            value,
+            ObjectValues::Button
+                | ObjectValues::Checkbox
+                | ObjectValues::Dialog
+                | ObjectValues::Drop
+                | ObjectValues::Edit
+                | ObjectValues::GroupBox
+                | ObjectValues::Label
+                | ObjectValues::List
+                | ObjectValues::Radio
+                | ObjectValues::Scroll
+                | ObjectValues::Spin
+        )
+    }
+
+    #[inline]
+    fn ctrl_prop_object_type(&self) -> &str {
+        match self.object_type() {
+            ObjectValues::Checkbox => "CheckBox",
+            ObjectValues::Edit => "EditBox",
+            v => v.value_string(),
+        }
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, name, "name");
+        set_string_from_xml!(self, e, r_id, "r:id");
+
+        if empty_flag {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"controlPr" {
+                    if let Some(v) = get_attribute(e, b"macro") {
+                        self.set_macro_name(v);
+                    }
+                }
+            },
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"controlPr" {
+                    if let Some(v) = get_attribute(e, b"macro") {
+                        self.set_macro_name(v);
+                    }
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"control" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(XlsxError::missing_end_element("control", reader.buffer_position()))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, r_id: usize, shape_id: usize) {
+        // mc:AlternateContent
+        write_start_tag(
+            writer,
+            "mc:AlternateContent",
+            vec![("xmlns:mc", MC_NS).into()],
+            false,
+        );
+
+        // mc:Choice
+        write_start_tag(writer, "mc:Choice", vec![("Requires", "x14").into()], false);
+
+        // control
+        let r_id_str = format!("rId{r_id}");
+        let shape_id_str = shape_id.to_string();
+        let mut attributes: crate::structs::AttrCollection = vec![
+            ("shapeId", &shape_id_str).into(),
+            ("r:id", &r_id_str).into(),
+        ];
+        if self.name.has_value() {
+            attributes.push(("name", self.name.value_str()).into());
+        }
+        write_start_tag(writer, "control", attributes, false);
+
+        // controlPr
+        let mut attributes: crate::structs::AttrCollection = vec![
+            ("defaultSize", "0").into(),
+            ("autoFill", "0").into(),
+            ("autoLine", "0").into(),
+            ("autoPict", "0").into(),
+        ];
+        if !self.macro_name().is_empty() {
+            attributes.push(("macro", self.macro_name()).into());
+        }
+        write_start_tag(writer, "controlPr", attributes, false);
+
+        // anchor
+        let anchor = self.anchor();
+        let mut object_anchor = ObjectAnchor::default();
+        object_anchor.set_move_with_cells(true);
+        object_anchor
+            .from_marker_mut()
+            .set_col(anchor.left_column() as usize)
+            .set_col_off(anchor.left_offset() as usize * 9525)
+            .set_row(anchor.top_row() as usize)
+            .set_row_off(anchor.top_offset() as usize * 9525);
+        object_anchor
+            .to_marker_mut()
+            .set_col(anchor.right_column() as usize)
+            .set_col_off(anchor.right_offset() as usize * 9525)
+            .set_row(anchor.bottom_row() as usize)
+            .set_row_off(anchor.bottom_offset() as usize * 9525);
+        object_anchor.write_to(writer);
+
+        write_end_tag(writer, "controlPr");
+
+        write_end_tag(writer, "control");
+
+        write_end_tag(writer, "mc:Choice");
+
+        write_end_tag(writer, "mc:AlternateContent");
+    }
+
+    pub(crate) fn set_ctrl_prop_attributes(&mut self, e: &BytesStart) {
+        if let Some(v) = get_attribute(e, b"objectType") {
+            let v = match v.as_str() {
+                "CheckBox" => "Checkbox",
+                "EditBox" => "Edit",
+                v => v,
+            };
+            if let Ok(object_type) = ObjectValues::from_str(v) {
+                self.set_object_type(object_type);
+            }
+        }
+        if let Some(v) = get_attribute(e, b"checked") {
+            self.set_checked(CheckedValues::from_str(&v).unwrap_or_default());
+        }
+        if let Some(v) = get_attribute(e, b"fmlaLink") {
+            self.set_linked_cell(v);
+        }
+        if let Some(v) = get_attribute(e, b"fmlaRange") {
+            self.set_list_fill_range(v);
+        }
+        let client_data = self.shape.client_data_mut();
+        if let Some(v) = get_attribute(e, b"val").and_then(|v| v.parse().ok()) {
+            client_data.set_current_value(v);
+        }
+        if let Some(v) = get_attribute(e, b"min").and_then(|v| v.parse().ok()) {
+            client_data.set_minimum(v);
+        }
+        if let Some(v) = get_attribute(e, b"max").and_then(|v| v.parse().ok()) {
+            client_data.set_maximum(v);
+        }
+        if let Some(v) = get_attribute(e, b"inc").and_then(|v| v.parse().ok()) {
+            client_data.set_increment(v);
+        }
+        if let Some(v) = get_attribute(e, b"page").and_then(|v| v.parse().ok()) {
+            client_data.set_page(v);
+        }
+        if let Some(v) = get_attribute(e, b"sel").and_then(|v| v.parse().ok()) {
+            client_data.set_selection(v);
+        }
+        if let Some(v) = get_attribute(e, b"dropLines").and_then(|v| v.parse().ok()) {
+            client_data.set_drop_lines(v);
+        }
+        if let Some(v) = get_attribute(e, b"dropStyle") {
+            let mut chars = v.chars();
+            let v = chars.next().map_or_else(String::new, |c| {
+                c.to_uppercase().chain(chars).collect::<String>()
+            });
+            client_data.set_drop_style(v);
+        }
+        if let Some(v) = get_attribute(e, b"noThreeD") {
+            client_data.set_no_three_d(v == "1" || v == "true");
+        }
+        if let Some(v) = get_attribute(e, b"firstButton") {
+            client_data.set_first_button(v == "1" || v == "true");
+        }
+    }
+
+    pub(crate) fn write_ctrl_prop_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // formControlPr
+        let client_data = self.shape.client_data();
+        let mut attributes: crate::structs::AttrCollection = vec![
+            ("xmlns", SHEET_MS_MAIN_NS).into(),
+            ("objectType", self.ctrl_prop_object_type()).into(),
+        ];
+        if client_data.has_checked() {
+            attributes.push(("checked", self.checked().value_string().to_string()).into());
+        }
+        if !client_data.drop_style().is_empty() {
+            attributes.push(("dropStyle", client_data.drop_style().to_lowercase()).into());
+        }
+        if client_data.has_drop_lines() {
+            attributes.push(("dropLines", client_data.drop_lines().to_string()).into());
+        }
+        if client_data.first_button() {
+            attributes.push(("firstButton", "1").into());
+        }
+        if !self.linked_cell().is_empty() {
+            attributes.push(("fmlaLink", self.linked_cell()).into());
+        }
+        if !self.list_fill_range().is_empty() {
+            attributes.push(("fmlaRange", self.list_fill_range()).into());
+        }
+        if client_data.has_increment() {
+            attributes.push(("inc", client_data.increment().to_string()).into());
+        }
+        if client_data.has_maximum() {
+            attributes.push(("max", client_data.maximum().to_string()).into());
+        }
+        if client_data.has_minimum() {
+            attributes.push(("min", client_data.minimum().to_string()).into());
+        }
+        if client_data.no_three_d() {
+            attributes.push(("noThreeD", "1").into());
+        }
+        if client_data.has_page() {
+            attributes.push(("page", client_data.page().to_string()).into());
+        }
+        if client_data.has_selection() {
+            attributes.push(("sel", client_data.selection().to_string()).into());
+        }
+        if client_data.has_current_value() {
+            attributes.push(("val", client_data.current_value().to_string()).into());
+        }
+        write_start_tag(writer, "formControlPr", attributes, true);
+    }
+}
+impl AdjustmentCoordinate for FormControl {
+    #[inline]
+    fn adjustment_insert_coordinate(
+        &mut self,
+        root_col_num: u32,
+        offset_col_num: u32,
+        root_row_num: u32,
+        offset_row_num: u32,
+    ) {
+        self.shape.adjustment_insert_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+    }
+
+    #[inline]
+    fn adjustment_remove_coordinate(
+        &mut self,
+        root_col_num: u32,
+        offset_col_num: u32,
+        root_row_num: u32,
+        offset_row_num: u32,
+    ) {
+        self.shape.adjustment_remove_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+    }
+}
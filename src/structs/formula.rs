@@ -15,12 +15,16 @@ use super::{
     StringValue,
 };
 use crate::{
-    helper::address::is_address,
+    XlsxError,
+    helper::{
+        address::is_address,
+        formula::replace_range_operands,
+    },
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
         write_start_tag,
-        write_text_node,
+        write_text_node_conversion,
     },
 };
 
@@ -96,22 +100,42 @@ impl Formula {
         self
     }
 
+    /// (This method is crate only.)
+    /// Rewrite the range operands of the formula with `replacer`.
+    pub(crate) fn replace_operands<F>(&mut self, mut replacer: F) -> &mut Self
+    where
+        F: FnMut(&str) -> Option<String>,
+    {
+        if self.string_value.has_value() {
+            let formula = replace_range_operands(self.string_value.value_str(), &mut replacer);
+            self.string_value.set_value(formula);
+            return self;
+        }
+        self.address.replace_operand(replacer);
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
+        let mut value = String::new();
         xml_read_loop!(
             reader,
             Event::Text(e) => {
-                self.set_address_str(crate::helper::utils::unescape_xml_text(&e));
+                value.push_str(&crate::helper::utils::unescape_xml_text(&e));
+            },
+            Event::GeneralRef(e) => {
+                value.push_str(&crate::helper::utils::unescape_xml_general_ref(&e));
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"formula" {
-                    return
+                    self.set_address_str(value);
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "formula")
+            Event::Eof => return Err(XlsxError::missing_end_element("formula", reader.buffer_position()))
         );
     }
 
@@ -119,7 +143,7 @@ impl Formula {
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
         // formula
         write_start_tag(writer, "formula", vec![], false);
-        write_text_node(writer, self.address_str());
+        write_text_node_conversion(writer, self.address_str());
         write_end_tag(writer, "formula");
     }
 }
@@ -11,7 +11,11 @@ use quick_xml::{
 };
 
 use crate::{
-    reader::driver::xml_read_loop,
+    XlsxError,
+    reader::driver::{
+        parse_value,
+        xml_read_loop,
+    },
     writer::driver::{
         write_end_tag,
         write_start_tag,
@@ -136,28 +140,28 @@ impl FromMarker {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut string_value: String = String::new();
         xml_read_loop!(
             reader,
             Event::Text(e) => string_value = crate::helper::utils::unescape_xml_text(&e),
             Event::End(ref e) => match e.name().into_inner() {
                 b"xdr:col" => {
-                    self.col = string_value.parse::<usize>().unwrap();
+                    self.col = parse_value(reader, &string_value)?;
                 }
                 b"xdr:colOff" => {
-                    self.col_off = string_value.parse::<usize>().unwrap();
+                    self.col_off = parse_value(reader, &string_value)?;
                 }
                 b"xdr:row" => {
-                    self.row = string_value.parse::<usize>().unwrap();
+                    self.row = parse_value(reader, &string_value)?;
                 }
                 b"xdr:rowOff" => {
-                    self.row_off = string_value.parse::<usize>().unwrap();
+                    self.row_off = parse_value(reader, &string_value)?;
                 }
-                b"from" => return,
+                b"from" => return Ok(()),
                 _ => (),
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "from")
+            Event::Eof => return Err(XlsxError::missing_end_element("from", reader.buffer_position()))
         );
     }
 
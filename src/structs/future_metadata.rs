@@ -0,0 +1,107 @@
+// futureMetadata
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use super::FutureMetadataBlock;
+use crate::{
+    XlsxError,
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+        xml_read_loop,
+    },
+    structs::StringValue,
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+    },
+};
+
+/// The values of a metadata type newer than the file format, such as the
+/// dynamic array properties of the `XLDAPR` type.
+#[derive(Clone, Default, Debug)]
+pub struct FutureMetadata {
+    name:  StringValue,
+    block: Vec<FutureMetadataBlock>,
+}
+
+impl FutureMetadata {
+    /// Get the name of the `MetadataType`. ex) "XLDAPR"
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &str {
+        self.name.value_str()
+    }
+
+    #[inline]
+    pub fn set_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.name.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn block(&self) -> &[FutureMetadataBlock] {
+        &self.block
+    }
+
+    #[inline]
+    pub fn block_mut(&mut self) -> &mut Vec<FutureMetadataBlock> {
+        &mut self.block
+    }
+
+    #[inline]
+    pub fn add_block(&mut self, value: FutureMetadataBlock) -> &mut Self {
+        self.block.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, name, "name");
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"bk" {
+                    let mut obj = FutureMetadataBlock::default();
+                    obj.set_attributes(reader, e)?;
+                    self.add_block(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"futureMetadata" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(XlsxError::missing_end_element("futureMetadata", reader.buffer_position()))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // futureMetadata
+        write_start_tag(
+            writer,
+            "futureMetadata",
+            vec![
+                ("name", self.name.value_str()).into(),
+                ("count", &self.block.len().to_string()).into(),
+            ],
+            false,
+        );
+        for block in &self.block {
+            block.write_to(writer);
+        }
+        write_end_tag(writer, "futureMetadata");
+    }
+}
@@ -0,0 +1,129 @@
+// bk
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use super::DynamicArrayProperties;
+use crate::{
+    XlsxError,
+    helper::const_str::{
+        DYNAMIC_ARRAY_EXT_URI,
+        RICH_VALUE_EXT_URI,
+    },
+    reader::driver::{
+        get_attribute,
+        xml_read_loop,
+    },
+    structs::UInt32Value,
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+    },
+};
+
+/// A value of a `FutureMetadata`: the properties of a dynamic array formula
+/// or the index of a rich value.
+#[derive(Clone, Default, Debug)]
+pub struct FutureMetadataBlock {
+    dynamic_array_properties: Option<DynamicArrayProperties>,
+    rich_value_index:         UInt32Value,
+}
+
+impl FutureMetadataBlock {
+    #[inline]
+    #[must_use]
+    pub fn dynamic_array_properties(&self) -> Option<&DynamicArrayProperties> {
+        self.dynamic_array_properties.as_ref()
+    }
+
+    #[inline]
+    pub fn dynamic_array_properties_mut(&mut self) -> Option<&mut DynamicArrayProperties> {
+        self.dynamic_array_properties.as_mut()
+    }
+
+    #[inline]
+    pub fn set_dynamic_array_properties(&mut self, value: DynamicArrayProperties) -> &mut Self {
+        self.dynamic_array_properties = Some(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_dynamic_array_properties(&mut self) -> &mut Self {
+        self.dynamic_array_properties = None;
+        self
+    }
+
+    /// Get the index of the rich value, `None` for other metadata.
+    #[inline]
+    #[must_use]
+    pub fn rich_value_index(&self) -> Option<u32> {
+        self.rich_value_index
+            .has_value()
+            .then(|| self.rich_value_index.value())
+    }
+
+    #[inline]
+    pub fn set_rich_value_index(&mut self, value: u32) -> &mut Self {
+        self.rich_value_index.set_value(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => match e.name().into_inner() {
+                b"xda:dynamicArrayProperties" => {
+                    let mut obj = DynamicArrayProperties::default();
+                    obj.set_attributes(e);
+                    self.set_dynamic_array_properties(obj);
+                }
+                b"xlrd:rvb" => {
+                    if let Some(v) = get_attribute(e, b"i") {
+                        self.rich_value_index.set_value_string(v);
+                    }
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"bk" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(XlsxError::missing_end_element("bk", reader.buffer_position()))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // bk
+        write_start_tag(writer, "bk", vec![], false);
+        write_start_tag(writer, "extLst", vec![], false);
+        if let Some(v) = &self.dynamic_array_properties {
+            write_start_tag(writer, "ext", vec![("uri", DYNAMIC_ARRAY_EXT_URI).into()], false);
+            v.write_to(writer);
+            write_end_tag(writer, "ext");
+        }
+        if self.rich_value_index.has_value() {
+            write_start_tag(writer, "ext", vec![("uri", RICH_VALUE_EXT_URI).into()], false);
+            write_start_tag(
+                writer,
+                "xlrd:rvb",
+                vec![("i", &self.rich_value_index.value_string()).into()],
+                true,
+            );
+            write_end_tag(writer, "ext");
+        }
+        write_end_tag(writer, "extLst");
+        write_end_tag(writer, "bk");
+    }
+}
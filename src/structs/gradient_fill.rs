@@ -18,9 +18,10 @@ use super::{
     GradientStop,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
-        set_string_from_xml,
+        try_set_string_from_xml,
         xml_read_loop,
     },
     writer::driver::{
@@ -102,24 +103,24 @@ impl GradientFill {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        set_string_from_xml!(self, e, degree, "degree");
+    ) -> Result<(), XlsxError> {
+        try_set_string_from_xml!(self, reader, e, degree, "degree");
 
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"stop" {
                     let mut obj = GradientStop::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_gradient_stop(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"gradientFill" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "gradientFill")
+            Event::Eof => return Err(XlsxError::missing_end_element("gradientFill", reader.buffer_position()))
         );
     }
 
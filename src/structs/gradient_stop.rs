@@ -15,9 +15,10 @@ use super::{
     DoubleValue,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
-        set_string_from_xml,
+        try_set_string_from_xml,
         xml_read_loop,
     },
     writer::driver::{
@@ -101,24 +102,24 @@ impl GradientStop {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        set_string_from_xml!(self, e, position, "position");
+    ) -> Result<(), XlsxError> {
+        try_set_string_from_xml!(self, reader, e, position, "position");
 
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"color" {
                     let mut obj = Color::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_color(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"stop" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "stop")
+            Event::Eof => return Err(XlsxError::missing_end_element("stop", reader.buffer_position()))
         );
     }
 
@@ -11,6 +11,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     structs::{
         OddFooter,
@@ -98,26 +99,26 @@ impl HeaderFooter {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                 b"oddHeader" => {
-                    self.odd_header.set_attributes(reader, e);
+                    self.odd_header.set_attributes(reader, e)?;
                 }
                 b"oddFooter" => {
-                    self.odd_footer.set_attributes(reader, e);
+                    self.odd_footer.set_attributes(reader, e)?;
                 }
                 _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"headerFooter" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "headerFooter")
+            Event::Eof => return Err(XlsxError::missing_end_element("headerFooter", reader.buffer_position()))
         );
     }
 
@@ -14,6 +14,7 @@ use super::{
     ConditionalFormatValueObject,
 };
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -85,19 +86,19 @@ impl IconSet {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
                 Event::Empty(ref e) => {
                     match e.name().into_inner() {
                         b"cfvo" => {
                             let mut obj = ConditionalFormatValueObject::default();
-                            obj.set_attributes(reader, e, true);
+                            obj.set_attributes(reader, e, true)?;
                             self.cfvo_collection.push(obj);
                         }
                         b"color" => {
                             let mut obj = Color::default();
-                            obj.set_attributes(reader, e, true);
+                            obj.set_attributes(reader, e, true)?;
                             self.color_collection.push(obj);
                         }
                         _ => (),
@@ -105,10 +106,10 @@ impl IconSet {
                 },
                 Event::End(ref e) => {
                     if e.name().into_inner() == b"iconSet" {
-                        return
+                        return Ok(())
                     }
                 },
-                Event::Eof => return
+                Event::Eof => return Ok(())
         );
     }
 
@@ -0,0 +1,123 @@
+// inputCells
+use std::io::Cursor;
+
+use quick_xml::{
+    Writer,
+    events::BytesStart,
+};
+
+use crate::{
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+    },
+    structs::{
+        BooleanValue,
+        StringValue,
+        UInt32Value,
+    },
+    writer::driver::write_start_tag,
+};
+
+/// A changing cell of a scenario and the value the scenario gives it.
+#[derive(Clone, Default, Debug)]
+pub struct InputCells {
+    reference:        StringValue,
+    deleted:          BooleanValue,
+    undone:           BooleanValue,
+    value:            StringValue,
+    number_format_id: UInt32Value,
+}
+
+impl InputCells {
+    /// Get the coordinate of the cell. ex) "B1"
+    #[inline]
+    #[must_use]
+    pub fn reference(&self) -> &str {
+        self.reference.value_str()
+    }
+
+    #[inline]
+    pub fn set_reference<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.reference.set_value(value);
+        self
+    }
+
+    /// Whether the cell has been deleted from the sheet.
+    #[inline]
+    #[must_use]
+    pub fn deleted(&self) -> bool {
+        self.deleted.value()
+    }
+
+    #[inline]
+    pub fn set_deleted(&mut self, value: bool) -> &mut Self {
+        self.deleted.set_value(value);
+        self
+    }
+
+    /// Whether the value has been undone.
+    #[inline]
+    #[must_use]
+    pub fn undone(&self) -> bool {
+        self.undone.value()
+    }
+
+    #[inline]
+    pub fn set_undone(&mut self, value: bool) -> &mut Self {
+        self.undone.set_value(value);
+        self
+    }
+
+    /// Get the value of the cell in the scenario. ex) "100"
+    #[inline]
+    #[must_use]
+    pub fn value(&self) -> &str {
+        self.value.value_str()
+    }
+
+    #[inline]
+    pub fn set_value<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.value.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn number_format_id(&self) -> u32 {
+        self.number_format_id.value()
+    }
+
+    #[inline]
+    pub fn set_number_format_id(&mut self, value: u32) -> &mut Self {
+        self.number_format_id.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn set_attributes(&mut self, e: &BytesStart) {
+        set_string_from_xml!(self, e, reference, "r");
+        set_string_from_xml!(self, e, deleted, "deleted");
+        set_string_from_xml!(self, e, undone, "undone");
+        set_string_from_xml!(self, e, value, "val");
+        set_string_from_xml!(self, e, number_format_id, "numFmtId");
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // inputCells
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        attributes.push(("r", self.reference.value_str()).into());
+        if self.deleted.has_value() {
+            attributes.push(("deleted", self.deleted.value_string()).into());
+        }
+        if self.undone.has_value() {
+            attributes.push(("undone", self.undone.value_string()).into());
+        }
+        attributes.push(("val", self.value.value_str()).into());
+        let number_format_id = self.number_format_id.value_string();
+        if self.number_format_id.has_value() {
+            attributes.push(("numFmtId", &number_format_id).into());
+        }
+        write_start_tag(writer, "inputCells", attributes, true);
+    }
+}
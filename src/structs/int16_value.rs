@@ -34,7 +34,10 @@ impl Int16Value {
 
     #[inline]
     pub(crate) fn set_value_string<S: Into<String>>(&mut self, value: S) -> &mut Int16Value {
-        self.set_value(value.into().parse::<i16>().unwrap())
+        if let Ok(v) = value.into().parse::<i16>() {
+            self.set_value(v);
+        }
+        self
     }
 
     #[inline]
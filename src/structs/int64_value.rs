@@ -33,7 +33,10 @@ impl Int64Value {
 
     #[inline]
     pub(crate) fn set_value_string<S: Into<String>>(&mut self, value: S) -> &mut Int64Value {
-        self.set_value(value.into().parse::<i64>().unwrap())
+        if let Ok(v) = value.into().parse::<i64>() {
+            self.set_value(v);
+        }
+        self
     }
 
     #[inline]
@@ -11,6 +11,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     structs::Item,
     writer::driver::{
@@ -59,7 +60,7 @@ impl Items {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
@@ -71,10 +72,10 @@ impl Items {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"items" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "items")
+            Event::Eof => return Err(XlsxError::missing_end_element("items", reader.buffer_position()))
         );
     }
 
@@ -12,8 +12,9 @@ use quick_xml::{
 
 use super::Range;
 use crate::{
+    XlsxError,
     reader::driver::{
-        get_attribute,
+        get_required_attribute,
         xml_read_loop,
     },
     writer::driver::{
@@ -94,20 +95,20 @@ impl MergeCells {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"mergeCell" {
-                    self.add_range(get_attribute(e, b"ref").unwrap());
+                    self.add_range(get_required_attribute(reader, e, b"ref")?);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"mergeCells" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "mergeCells")
+            Event::Eof => return Err(XlsxError::missing_end_element("mergeCells", reader.buffer_position()))
         );
     }
 
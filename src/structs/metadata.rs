@@ -0,0 +1,333 @@
+// metadata
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use super::{
+    DynamicArrayProperties,
+    FutureMetadata,
+    FutureMetadataBlock,
+    MetadataBlock,
+    MetadataRecord,
+    MetadataType,
+};
+use crate::{
+    XlsxError,
+    helper::const_str::{
+        DYNAMIC_ARRAY_NS,
+        RICH_DATA_NS,
+        SHEET_MAIN_NS,
+    },
+    reader::driver::xml_read_loop,
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+    },
+};
+
+const DYNAMIC_ARRAY_TYPE_NAME: &str = "XLDAPR";
+
+/// The `metadata.xml` part of a workbook: the metadata the `cm` attribute of
+/// a cell points to, such as the mark of a dynamic array formula.
+/// MDX metadata of cube functions is not kept.
+#[derive(Clone, Default, Debug)]
+pub struct Metadata {
+    metadata_types:  Vec<MetadataType>,
+    future_metadata: Vec<FutureMetadata>,
+    cell_metadata:   Vec<MetadataBlock>,
+    value_metadata:  Vec<MetadataBlock>,
+}
+
+impl Metadata {
+    #[inline]
+    #[must_use]
+    pub fn metadata_types(&self) -> &[MetadataType] {
+        &self.metadata_types
+    }
+
+    #[inline]
+    pub fn metadata_types_mut(&mut self) -> &mut Vec<MetadataType> {
+        &mut self.metadata_types
+    }
+
+    #[inline]
+    pub fn add_metadata_type(&mut self, value: MetadataType) -> &mut Self {
+        self.metadata_types.push(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn future_metadata(&self) -> &[FutureMetadata] {
+        &self.future_metadata
+    }
+
+    #[inline]
+    pub fn future_metadata_mut(&mut self) -> &mut Vec<FutureMetadata> {
+        &mut self.future_metadata
+    }
+
+    #[inline]
+    pub fn add_future_metadata(&mut self, value: FutureMetadata) -> &mut Self {
+        self.future_metadata.push(value);
+        self
+    }
+
+    /// Get the blocks of cell metadata. The `cm` attribute of a cell is the
+    /// one-based index of its block.
+    #[inline]
+    #[must_use]
+    pub fn cell_metadata(&self) -> &[MetadataBlock] {
+        &self.cell_metadata
+    }
+
+    #[inline]
+    pub fn cell_metadata_mut(&mut self) -> &mut Vec<MetadataBlock> {
+        &mut self.cell_metadata
+    }
+
+    #[inline]
+    pub fn add_cell_metadata(&mut self, value: MetadataBlock) -> &mut Self {
+        self.cell_metadata.push(value);
+        self
+    }
+
+    /// Get the blocks of value metadata. The `vm` attribute of a cell is the
+    /// one-based index of its block.
+    #[inline]
+    #[must_use]
+    pub fn value_metadata(&self) -> &[MetadataBlock] {
+        &self.value_metadata
+    }
+
+    #[inline]
+    pub fn value_metadata_mut(&mut self) -> &mut Vec<MetadataBlock> {
+        &mut self.value_metadata
+    }
+
+    #[inline]
+    pub fn add_value_metadata(&mut self, value: MetadataBlock) -> &mut Self {
+        self.value_metadata.push(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.metadata_types.is_empty()
+            && self.future_metadata.is_empty()
+            && self.cell_metadata.is_empty()
+            && self.value_metadata.is_empty()
+    }
+
+    /// Whether the cell metadata at `cell_meta_index`, the `cm` attribute of
+    /// a cell, marks a dynamic array formula.
+    #[must_use]
+    pub fn is_dynamic_array(&self, cell_meta_index: u32) -> bool {
+        let Some(type_index) = self.dynamic_array_type_index() else {
+            return false;
+        };
+        let Some(block) = cell_meta_index
+            .checked_sub(1)
+            .and_then(|index| self.cell_metadata.get(index as usize))
+        else {
+            return false;
+        };
+        let Some(future_metadata) = self
+            .future_metadata
+            .iter()
+            .find(|x| x.name() == DYNAMIC_ARRAY_TYPE_NAME)
+        else {
+            return false;
+        };
+        block.record().iter().any(|record| {
+            record.type_index() == type_index
+                && future_metadata
+                    .block()
+                    .get(record.value_index() as usize)
+                    .and_then(FutureMetadataBlock::dynamic_array_properties)
+                    .is_some_and(DynamicArrayProperties::dynamic)
+        })
+    }
+
+    /// (This method is crate only.)
+    /// Get the `cm` index marking dynamic array formulas, if there is one.
+    pub(crate) fn dynamic_array_cell_meta_index(&self) -> Option<u32> {
+        (1..=num_traits::cast::<_, u32>(self.cell_metadata.len()).unwrap())
+            .find(|index| self.is_dynamic_array(*index))
+    }
+
+    /// (This method is crate only.)
+    /// Get the `cm` index marking dynamic array formulas, adding the
+    /// metadata as Excel writes it when there is none.
+    pub(crate) fn add_dynamic_array(&mut self) -> u32 {
+        if let Some(index) = self.dynamic_array_cell_meta_index() {
+            return index;
+        }
+        let type_index = if let Some(v) = self.dynamic_array_type_index() {
+            v
+        } else {
+            let mut obj = MetadataType::default();
+            obj.set_name(DYNAMIC_ARRAY_TYPE_NAME)
+                .set_min_supported_version(120_000)
+                .set_copy(true)
+                .set_paste_all(true)
+                .set_paste_values(true)
+                .set_merge(true)
+                .set_split_first(true)
+                .set_row_col_shift(true)
+                .set_clear_formats(true)
+                .set_clear_comments(true)
+                .set_assign(true)
+                .set_coerce(true)
+                .set_cell_meta(true);
+            self.add_metadata_type(obj);
+            num_traits::cast(self.metadata_types.len()).unwrap()
+        };
+        let position = if let Some(v) = self
+            .future_metadata
+            .iter()
+            .position(|x| x.name() == DYNAMIC_ARRAY_TYPE_NAME)
+        {
+            v
+        } else {
+            let mut obj = FutureMetadata::default();
+            obj.set_name(DYNAMIC_ARRAY_TYPE_NAME);
+            self.add_future_metadata(obj);
+            self.future_metadata.len() - 1
+        };
+        let mut properties = DynamicArrayProperties::default();
+        properties.set_dynamic(true).set_collapsed(false);
+        let mut block = FutureMetadataBlock::default();
+        block.set_dynamic_array_properties(properties);
+        let future_metadata = &mut self.future_metadata[position];
+        future_metadata.add_block(block);
+
+        let mut record = MetadataRecord::default();
+        record
+            .set_type_index(type_index)
+            .set_value_index(num_traits::cast(future_metadata.block().len() - 1).unwrap());
+        let mut block = MetadataBlock::default();
+        block.add_record(record);
+        self.add_cell_metadata(block);
+        num_traits::cast(self.cell_metadata.len()).unwrap()
+    }
+
+    /// The one-based index of the `XLDAPR` metadata type.
+    fn dynamic_array_type_index(&self) -> Option<u32> {
+        self.metadata_types
+            .iter()
+            .position(|x| x.name() == DYNAMIC_ARRAY_TYPE_NAME)
+            .map(|index| num_traits::cast(index + 1).unwrap())
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        let mut in_value_metadata = false;
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"metadataType" {
+                    let mut obj = MetadataType::default();
+                    obj.set_attributes(e);
+                    self.add_metadata_type(obj);
+                }
+            },
+            Event::Start(ref e) => match e.name().into_inner() {
+                b"metadataType" => {
+                    let mut obj = MetadataType::default();
+                    obj.set_attributes(e);
+                    self.add_metadata_type(obj);
+                }
+                b"futureMetadata" => {
+                    let mut obj = FutureMetadata::default();
+                    obj.set_attributes(reader, e)?;
+                    self.add_future_metadata(obj);
+                }
+                b"cellMetadata" => in_value_metadata = false,
+                b"valueMetadata" => in_value_metadata = true,
+                b"bk" => {
+                    let mut obj = MetadataBlock::default();
+                    obj.set_attributes(reader, e)?;
+                    if in_value_metadata {
+                        self.add_value_metadata(obj);
+                    } else {
+                        self.add_cell_metadata(obj);
+                    }
+                }
+                _ => (),
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"metadata" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(XlsxError::missing_end_element("metadata", reader.buffer_position()))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // metadata
+        write_start_tag(
+            writer,
+            "metadata",
+            vec![
+                ("xmlns", SHEET_MAIN_NS).into(),
+                ("xmlns:xda", DYNAMIC_ARRAY_NS).into(),
+                ("xmlns:xlrd", RICH_DATA_NS).into(),
+            ],
+            false,
+        );
+
+        // metadataTypes
+        if !self.metadata_types.is_empty() {
+            write_start_tag(
+                writer,
+                "metadataTypes",
+                vec![("count", &self.metadata_types.len().to_string()).into()],
+                false,
+            );
+            for metadata_type in &self.metadata_types {
+                metadata_type.write_to(writer);
+            }
+            write_end_tag(writer, "metadataTypes");
+        }
+
+        // futureMetadata
+        for future_metadata in &self.future_metadata {
+            future_metadata.write_to(writer);
+        }
+
+        // cellMetadata, valueMetadata
+        for (tag_name, blocks) in [
+            ("cellMetadata", &self.cell_metadata),
+            ("valueMetadata", &self.value_metadata),
+        ] {
+            if blocks.is_empty() {
+                continue;
+            }
+            write_start_tag(
+                writer,
+                tag_name,
+                vec![("count", &blocks.len().to_string()).into()],
+                false,
+            );
+            for block in blocks {
+                block.write_to(writer);
+            }
+            write_end_tag(writer, tag_name);
+        }
+
+        write_end_tag(writer, "metadata");
+    }
+}
@@ -0,0 +1,79 @@
+// bk
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use super::MetadataRecord;
+use crate::{
+    XlsxError,
+    reader::driver::xml_read_loop,
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+    },
+};
+
+/// A block of cell or value metadata, the target of the `cm` attribute of
+/// a cell.
+#[derive(Clone, Default, Debug)]
+pub struct MetadataBlock {
+    record: Vec<MetadataRecord>,
+}
+
+impl MetadataBlock {
+    #[inline]
+    #[must_use]
+    pub fn record(&self) -> &[MetadataRecord] {
+        &self.record
+    }
+
+    #[inline]
+    pub fn record_mut(&mut self) -> &mut Vec<MetadataRecord> {
+        &mut self.record
+    }
+
+    #[inline]
+    pub fn add_record(&mut self, value: MetadataRecord) -> &mut Self {
+        self.record.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        _e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"rc" {
+                    let mut obj = MetadataRecord::default();
+                    obj.set_attributes(e);
+                    self.add_record(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"bk" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(XlsxError::missing_end_element("bk", reader.buffer_position()))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // bk
+        write_start_tag(writer, "bk", vec![], false);
+        for record in &self.record {
+            record.write_to(writer);
+        }
+        write_end_tag(writer, "bk");
+    }
+}
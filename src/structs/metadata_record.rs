@@ -0,0 +1,73 @@
+// rc
+use std::io::Cursor;
+
+use quick_xml::{
+    Writer,
+    events::BytesStart,
+};
+
+use crate::{
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+    },
+    structs::UInt32Value,
+    writer::driver::write_start_tag,
+};
+
+/// A metadata record: which `MetadataType` it is and which value of that
+/// type it points to.
+#[derive(Clone, Default, Debug)]
+pub struct MetadataRecord {
+    type_index:  UInt32Value,
+    value_index: UInt32Value,
+}
+
+impl MetadataRecord {
+    /// Get the one-based index of the `MetadataType`.
+    #[inline]
+    #[must_use]
+    pub fn type_index(&self) -> u32 {
+        self.type_index.value()
+    }
+
+    #[inline]
+    pub fn set_type_index(&mut self, value: u32) -> &mut Self {
+        self.type_index.set_value(value);
+        self
+    }
+
+    /// Get the zero-based index of the value, a `FutureMetadataBlock` for
+    /// future metadata types.
+    #[inline]
+    #[must_use]
+    pub fn value_index(&self) -> u32 {
+        self.value_index.value()
+    }
+
+    #[inline]
+    pub fn set_value_index(&mut self, value: u32) -> &mut Self {
+        self.value_index.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn set_attributes(&mut self, e: &BytesStart) {
+        set_string_from_xml!(self, e, type_index, "t");
+        set_string_from_xml!(self, e, value_index, "v");
+    }
+
+    #[inline]
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // rc
+        write_start_tag(
+            writer,
+            "rc",
+            vec![
+                ("t", &self.type_index.value_string()).into(),
+                ("v", &self.value_index.value_string()).into(),
+            ],
+            true,
+        );
+    }
+}
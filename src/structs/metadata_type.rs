@@ -0,0 +1,467 @@
+// metadataType
+use std::io::Cursor;
+
+use quick_xml::{
+    Writer,
+    events::BytesStart,
+};
+
+use crate::{
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+    },
+    structs::{
+        BooleanValue,
+        StringValue,
+        UInt32Value,
+    },
+    writer::driver::write_start_tag,
+};
+
+/// A type of metadata of `Metadata`, with how Excel treats the cells
+/// carrying it when they are edited.
+#[derive(Clone, Default, Debug)]
+pub struct MetadataType {
+    name:                  StringValue,
+    min_supported_version: UInt32Value,
+    ghost_row:             BooleanValue,
+    ghost_col:             BooleanValue,
+    edit:                  BooleanValue,
+    delete:                BooleanValue,
+    copy:                  BooleanValue,
+    paste_all:             BooleanValue,
+    paste_formulas:        BooleanValue,
+    paste_values:          BooleanValue,
+    paste_formats:         BooleanValue,
+    paste_comments:        BooleanValue,
+    paste_data_validation: BooleanValue,
+    paste_borders:         BooleanValue,
+    paste_col_widths:      BooleanValue,
+    paste_number_formats:  BooleanValue,
+    merge:                 BooleanValue,
+    split_first:           BooleanValue,
+    split_all:             BooleanValue,
+    row_col_shift:         BooleanValue,
+    clear_all:             BooleanValue,
+    clear_formats:         BooleanValue,
+    clear_contents:        BooleanValue,
+    clear_comments:        BooleanValue,
+    assign:                BooleanValue,
+    coerce:                BooleanValue,
+    adjust:                BooleanValue,
+    cell_meta:             BooleanValue,
+}
+
+impl MetadataType {
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &str {
+        self.name.value_str()
+    }
+
+    #[inline]
+    pub fn set_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.name.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn min_supported_version(&self) -> u32 {
+        self.min_supported_version.value()
+    }
+
+    #[inline]
+    pub fn set_min_supported_version(&mut self, value: u32) -> &mut Self {
+        self.min_supported_version.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ghost_row(&self) -> bool {
+        self.ghost_row.value()
+    }
+
+    #[inline]
+    pub fn set_ghost_row(&mut self, value: bool) -> &mut Self {
+        self.ghost_row.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ghost_col(&self) -> bool {
+        self.ghost_col.value()
+    }
+
+    #[inline]
+    pub fn set_ghost_col(&mut self, value: bool) -> &mut Self {
+        self.ghost_col.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn edit(&self) -> bool {
+        self.edit.value()
+    }
+
+    #[inline]
+    pub fn set_edit(&mut self, value: bool) -> &mut Self {
+        self.edit.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn delete(&self) -> bool {
+        self.delete.value()
+    }
+
+    #[inline]
+    pub fn set_delete(&mut self, value: bool) -> &mut Self {
+        self.delete.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn copy(&self) -> bool {
+        self.copy.value()
+    }
+
+    #[inline]
+    pub fn set_copy(&mut self, value: bool) -> &mut Self {
+        self.copy.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn paste_all(&self) -> bool {
+        self.paste_all.value()
+    }
+
+    #[inline]
+    pub fn set_paste_all(&mut self, value: bool) -> &mut Self {
+        self.paste_all.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn paste_formulas(&self) -> bool {
+        self.paste_formulas.value()
+    }
+
+    #[inline]
+    pub fn set_paste_formulas(&mut self, value: bool) -> &mut Self {
+        self.paste_formulas.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn paste_values(&self) -> bool {
+        self.paste_values.value()
+    }
+
+    #[inline]
+    pub fn set_paste_values(&mut self, value: bool) -> &mut Self {
+        self.paste_values.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn paste_formats(&self) -> bool {
+        self.paste_formats.value()
+    }
+
+    #[inline]
+    pub fn set_paste_formats(&mut self, value: bool) -> &mut Self {
+        self.paste_formats.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn paste_comments(&self) -> bool {
+        self.paste_comments.value()
+    }
+
+    #[inline]
+    pub fn set_paste_comments(&mut self, value: bool) -> &mut Self {
+        self.paste_comments.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn paste_data_validation(&self) -> bool {
+        self.paste_data_validation.value()
+    }
+
+    #[inline]
+    pub fn set_paste_data_validation(&mut self, value: bool) -> &mut Self {
+        self.paste_data_validation.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn paste_borders(&self) -> bool {
+        self.paste_borders.value()
+    }
+
+    #[inline]
+    pub fn set_paste_borders(&mut self, value: bool) -> &mut Self {
+        self.paste_borders.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn paste_col_widths(&self) -> bool {
+        self.paste_col_widths.value()
+    }
+
+    #[inline]
+    pub fn set_paste_col_widths(&mut self, value: bool) -> &mut Self {
+        self.paste_col_widths.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn paste_number_formats(&self) -> bool {
+        self.paste_number_formats.value()
+    }
+
+    #[inline]
+    pub fn set_paste_number_formats(&mut self, value: bool) -> &mut Self {
+        self.paste_number_formats.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn merge(&self) -> bool {
+        self.merge.value()
+    }
+
+    #[inline]
+    pub fn set_merge(&mut self, value: bool) -> &mut Self {
+        self.merge.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn split_first(&self) -> bool {
+        self.split_first.value()
+    }
+
+    #[inline]
+    pub fn set_split_first(&mut self, value: bool) -> &mut Self {
+        self.split_first.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn split_all(&self) -> bool {
+        self.split_all.value()
+    }
+
+    #[inline]
+    pub fn set_split_all(&mut self, value: bool) -> &mut Self {
+        self.split_all.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn row_col_shift(&self) -> bool {
+        self.row_col_shift.value()
+    }
+
+    #[inline]
+    pub fn set_row_col_shift(&mut self, value: bool) -> &mut Self {
+        self.row_col_shift.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn clear_all(&self) -> bool {
+        self.clear_all.value()
+    }
+
+    #[inline]
+    pub fn set_clear_all(&mut self, value: bool) -> &mut Self {
+        self.clear_all.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn clear_formats(&self) -> bool {
+        self.clear_formats.value()
+    }
+
+    #[inline]
+    pub fn set_clear_formats(&mut self, value: bool) -> &mut Self {
+        self.clear_formats.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn clear_contents(&self) -> bool {
+        self.clear_contents.value()
+    }
+
+    #[inline]
+    pub fn set_clear_contents(&mut self, value: bool) -> &mut Self {
+        self.clear_contents.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn clear_comments(&self) -> bool {
+        self.clear_comments.value()
+    }
+
+    #[inline]
+    pub fn set_clear_comments(&mut self, value: bool) -> &mut Self {
+        self.clear_comments.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn assign(&self) -> bool {
+        self.assign.value()
+    }
+
+    #[inline]
+    pub fn set_assign(&mut self, value: bool) -> &mut Self {
+        self.assign.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn coerce(&self) -> bool {
+        self.coerce.value()
+    }
+
+    #[inline]
+    pub fn set_coerce(&mut self, value: bool) -> &mut Self {
+        self.coerce.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn adjust(&self) -> bool {
+        self.adjust.value()
+    }
+
+    #[inline]
+    pub fn set_adjust(&mut self, value: bool) -> &mut Self {
+        self.adjust.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn cell_meta(&self) -> bool {
+        self.cell_meta.value()
+    }
+
+    #[inline]
+    pub fn set_cell_meta(&mut self, value: bool) -> &mut Self {
+        self.cell_meta.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn set_attributes(&mut self, e: &BytesStart) {
+        set_string_from_xml!(self, e, name, "name");
+        set_string_from_xml!(self, e, min_supported_version, "minSupportedVersion");
+        set_string_from_xml!(self, e, ghost_row, "ghostRow");
+        set_string_from_xml!(self, e, ghost_col, "ghostCol");
+        set_string_from_xml!(self, e, edit, "edit");
+        set_string_from_xml!(self, e, delete, "delete");
+        set_string_from_xml!(self, e, copy, "copy");
+        set_string_from_xml!(self, e, paste_all, "pasteAll");
+        set_string_from_xml!(self, e, paste_formulas, "pasteFormulas");
+        set_string_from_xml!(self, e, paste_values, "pasteValues");
+        set_string_from_xml!(self, e, paste_formats, "pasteFormats");
+        set_string_from_xml!(self, e, paste_comments, "pasteComments");
+        set_string_from_xml!(self, e, paste_data_validation, "pasteDataValidation");
+        set_string_from_xml!(self, e, paste_borders, "pasteBorders");
+        set_string_from_xml!(self, e, paste_col_widths, "pasteColWidths");
+        set_string_from_xml!(self, e, paste_number_formats, "pasteNumberFormats");
+        set_string_from_xml!(self, e, merge, "merge");
+        set_string_from_xml!(self, e, split_first, "splitFirst");
+        set_string_from_xml!(self, e, split_all, "splitAll");
+        set_string_from_xml!(self, e, row_col_shift, "rowColShift");
+        set_string_from_xml!(self, e, clear_all, "clearAll");
+        set_string_from_xml!(self, e, clear_formats, "clearFormats");
+        set_string_from_xml!(self, e, clear_contents, "clearContents");
+        set_string_from_xml!(self, e, clear_comments, "clearComments");
+        set_string_from_xml!(self, e, assign, "assign");
+        set_string_from_xml!(self, e, coerce, "coerce");
+        set_string_from_xml!(self, e, adjust, "adjust");
+        set_string_from_xml!(self, e, cell_meta, "cellMeta");
+    }
+
+    #[inline]
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // metadataType
+        let min_supported_version = self.min_supported_version.value_string();
+        let mut attributes: crate::structs::AttrCollection = vec![
+            ("name", self.name.value_str()).into(),
+            ("minSupportedVersion", &min_supported_version).into(),
+        ];
+        for (value, key) in [
+            (&self.ghost_row, "ghostRow"),
+            (&self.ghost_col, "ghostCol"),
+            (&self.edit, "edit"),
+            (&self.delete, "delete"),
+            (&self.copy, "copy"),
+            (&self.paste_all, "pasteAll"),
+            (&self.paste_formulas, "pasteFormulas"),
+            (&self.paste_values, "pasteValues"),
+            (&self.paste_formats, "pasteFormats"),
+            (&self.paste_comments, "pasteComments"),
+            (&self.paste_data_validation, "pasteDataValidation"),
+            (&self.paste_borders, "pasteBorders"),
+            (&self.paste_col_widths, "pasteColWidths"),
+            (&self.paste_number_formats, "pasteNumberFormats"),
+            (&self.merge, "merge"),
+            (&self.split_first, "splitFirst"),
+            (&self.split_all, "splitAll"),
+            (&self.row_col_shift, "rowColShift"),
+            (&self.clear_all, "clearAll"),
+            (&self.clear_formats, "clearFormats"),
+            (&self.clear_contents, "clearContents"),
+            (&self.clear_comments, "clearComments"),
+            (&self.assign, "assign"),
+            (&self.coerce, "coerce"),
+            (&self.adjust, "adjust"),
+            (&self.cell_meta, "cellMeta"),
+        ] {
+            if value.has_value() {
+                attributes.push((key, value.value_string()).into());
+            }
+        }
+        write_start_tag(writer, "metadataType", attributes, true);
+    }
+}
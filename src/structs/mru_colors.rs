@@ -12,6 +12,7 @@ use quick_xml::{
 
 use super::Color;
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -57,22 +58,22 @@ impl MruColors {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"color" {
                     let mut obj = Color::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_color(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"mruColors" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "mruColors")
+            Event::Eof => return Err(XlsxError::missing_end_element("mruColors", reader.buffer_position()))
         );
     }
 
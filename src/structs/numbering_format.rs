@@ -9,7 +9,11 @@ use quick_xml::{
 };
 
 use crate::{
-    reader::driver::get_attribute,
+    XlsxError,
+    reader::driver::{
+        get_required_attribute,
+        parse_value,
+    },
     writer::driver::write_start_tag,
 };
 
@@ -178,18 +182,16 @@ impl NumberingFormat {
 
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
-        _reader: &mut Reader<R>,
+        reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        self.number_format_id = get_attribute(e, b"numFmtId")
-            .unwrap()
-            .parse::<u32>()
-            .unwrap();
-        self.format_code = escape::unescape(get_attribute(e, b"formatCode").unwrap().as_str())
-            .unwrap()
+    ) -> Result<(), XlsxError> {
+        self.number_format_id = parse_value(reader, &get_required_attribute(reader, e, b"numFmtId")?)?;
+        self.format_code = escape::unescape(get_required_attribute(reader, e, b"formatCode")?.as_str())
+            .map_err(|e| XlsxError::parse(reader.buffer_position(), e.to_string()))?
             .to_string()
             .into_boxed_str();
         self.is_build_in = false;
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, number_format_id: u32) {
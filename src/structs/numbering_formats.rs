@@ -18,6 +18,7 @@ use super::{
     Style,
 };
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -112,22 +113,22 @@ impl NumberingFormats {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"numFmt" {
                     let mut obj = NumberingFormat::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_numbering_format(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"numFmts" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "numFmts")
+            Event::Eof => return Err(XlsxError::missing_end_element("numFmts", reader.buffer_position()))
         );
     }
 
@@ -15,6 +15,7 @@ use super::{
     ToMarker,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -141,7 +142,7 @@ impl ObjectAnchor {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, move_with_cells, "moveWithCells");
 
         xml_read_loop!(
@@ -149,20 +150,20 @@ impl ObjectAnchor {
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"from" | b"xdr:from" => {
-                        self.from_marker.set_attributes(reader, e);
+                        self.from_marker.set_attributes(reader, e)?;
                     }
                     b"to" | b"xdr:to" => {
-                        self.to_marker.set_attributes(reader, e);
+                        self.to_marker.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"anchor" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "anchor")
+            Event::Eof => return Err(XlsxError::missing_end_element("anchor", reader.buffer_position()))
         );
     }
 
@@ -11,6 +11,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     structs::StringValue,
     writer::driver::{
@@ -65,7 +66,7 @@ impl OddFooter {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Text(e) => {
@@ -73,10 +74,10 @@ impl OddFooter {
             },
             Event::End(ref e) => {
                 if e.name().0 == b"oddFooter" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "oddFooter")
+            Event::Eof => return Err(XlsxError::missing_end_element("oddFooter", reader.buffer_position()))
         );
     }
 
@@ -14,11 +14,12 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     structs::Address,
     writer::driver::{
         write_end_tag,
         write_start_tag,
-        write_text_node,
+        write_text_node_conversion,
     },
 };
 
@@ -61,7 +62,7 @@ impl Formula {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut value: String = String::new();
         let mut buf = Vec::new();
         loop {
@@ -74,11 +75,11 @@ impl Formula {
                         let mut obj = Address::default();
                         obj.set_address(value);
                         self.value = obj;
-                        return;
+                        return Ok(());
                     }
                 }
-                Ok(Event::Eof) => panic!("Error: Could not find {} end element", "xm:f"),
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(XlsxError::missing_end_element("xm:f", reader.buffer_position())),
+                Err(e) => return Err(XlsxError::parse(reader.buffer_position(), e.to_string())),
                 _ => (),
             }
             buf.clear();
@@ -88,7 +89,7 @@ impl Formula {
     #[inline]
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
         write_start_tag(writer, "xm:f", vec![], false);
-        write_text_node(writer, self.value.address());
+        write_text_node_conversion(writer, self.value.address());
         write_end_tag(writer, "xm:f");
     }
 }
@@ -14,6 +14,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     structs::Range,
     writer::driver::{
         write_end_tag,
@@ -99,7 +100,7 @@ impl ReferenceSequence {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut value: String = String::new();
         let mut buf = Vec::new();
         loop {
@@ -110,11 +111,11 @@ impl ReferenceSequence {
                 Ok(Event::End(ref e)) => {
                     if e.name().into_inner() == b"xm:sqref" {
                         self.set_sqref(value);
-                        return;
+                        return Ok(());
                     }
                 }
-                Ok(Event::Eof) => panic!("Error: Could not find {} end element", "xm:sqref"),
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(XlsxError::missing_end_element("xm:sqref", reader.buffer_position())),
+                Err(e) => return Err(XlsxError::parse(reader.buffer_position(), e.to_string())),
                 _ => (),
             }
             buf.clear();
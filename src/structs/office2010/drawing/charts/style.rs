@@ -12,12 +12,14 @@ use quick_xml::{
 
 use crate::{
     StringValue,
+    XlsxError,
     helper::const_str::{
         DRAWING_CHART_NS,
         MC_NS,
     },
     reader::driver::{
         get_attribute,
+        get_required_attribute,
         xml_read_loop,
     },
     set_string_from_xml,
@@ -77,7 +79,7 @@ impl Style {
         reader: &mut Reader<R>,
         e: &BytesStart,
         include_alternate_content: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         self.include_alternate_content = include_alternate_content;
 
         if include_alternate_content {
@@ -85,19 +87,20 @@ impl Style {
                 reader,
                 Event::Empty(ref e) => {
                     if e.name().into_inner() == b"c:style" {
-                        self.val.set_value_string(get_attribute(e, b"val").unwrap());
+                        self.val.set_value_string(get_required_attribute(reader, e, b"val")?);
                     }
                 },
                 Event::End(ref e) => {
                     if e.name().into_inner() == b"mc:AlternateContent" {
-                        return
+                        return Ok(())
                     }
                 },
-                Event::Eof => panic!("Error: Could not find {} end element", "mc:AlternateContent")
+                Event::Eof => return Err(XlsxError::missing_end_element("mc:AlternateContent", reader.buffer_position()))
             );
         } else {
             set_string_from_xml!(self, e, val, "val");
         }
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
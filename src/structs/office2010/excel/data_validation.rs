@@ -11,6 +11,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::get_attribute,
     structs::{
         BooleanValue,
@@ -277,7 +278,7 @@ impl DataValidation {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(v) = get_attribute(e, b"type") {
             self.r#type.set_value_string(v);
         }
@@ -307,7 +308,7 @@ impl DataValidation {
         }
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         let mut buf = Vec::new();
@@ -316,30 +317,30 @@ impl DataValidation {
                 Ok(Event::Start(ref e)) => match e.name().into_inner() {
                     b"x14:formula1" => {
                         let mut obj = DataValidationForumla1::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.formula1 = Some(Box::new(obj));
                     }
                     b"x14:formula2" => {
                         let mut obj = DataValidationForumla2::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.formula2 = Some(Box::new(obj));
                     }
                     b"xm:sqref" => {
                         let mut obj = ReferenceSequence::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.reference_sequence = obj;
                     }
                     _ => (),
                 },
                 Ok(Event::End(ref e)) => {
                     if e.name().into_inner() == b"x14:dataValidation" {
-                        return;
+                        return Ok(());
                     }
                 }
                 Ok(Event::Eof) => {
-                    panic!("Error: Could not find {} end element", "x14:dataValidation")
+                    return Err(XlsxError::missing_end_element("x14:dataValidation", reader.buffer_position()))
                 }
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Err(e) => return Err(XlsxError::parse(reader.buffer_position(), e.to_string())),
                 _ => (),
             }
             buf.clear();
@@ -14,6 +14,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     structs::office::excel::Formula,
     writer::driver::{
         write_end_tag,
@@ -60,25 +61,25 @@ impl DataValidationForumla2 {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut buf = Vec::new();
         loop {
             match reader.read_event_into(&mut buf) {
                 Ok(Event::Start(ref e)) => {
                     if e.name().into_inner() == b"xm:f" {
                         let mut obj = Formula::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.value = obj;
-                        return;
+                        return Ok(());
                     }
                 }
                 Ok(Event::End(ref e)) => {
                     if e.name().into_inner() == b"x14:formula2" {
-                        return;
+                        return Ok(());
                     }
                 }
-                Ok(Event::Eof) => panic!("Error: Could not find {} end element", "x14:formula2"),
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(XlsxError::missing_end_element("x14:formula2", reader.buffer_position())),
+                Err(e) => return Err(XlsxError::parse(reader.buffer_position(), e.to_string())),
                 _ => (),
             }
             buf.clear();
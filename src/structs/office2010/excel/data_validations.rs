@@ -11,6 +11,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     helper::const_str::{
         EXCEL_MAIN_NS,
         SHEET_MS_MAIN_NS,
@@ -69,29 +70,29 @@ impl DataValidations {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"x14:dataValidation" {
                     let mut obj = DataValidation::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.add_data_validation_list(obj);
                 }
             },
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"x14:dataValidation" {
                     let mut obj = DataValidation::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.add_data_validation_list(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"x14:dataValidations" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "x14:dataValidations")
+            Event::Eof => return Err(XlsxError::missing_end_element("x14:dataValidations", reader.buffer_position()))
         );
     }
 
@@ -1,2 +1,5 @@
+pub mod mention;
+pub mod person;
 pub mod threaded_comment;
+pub mod threaded_comment_handle;
 pub mod threaded_comment_text;
@@ -0,0 +1,100 @@
+// mention
+use std::io::Cursor;
+
+use quick_xml::{
+    Writer,
+    events::BytesStart,
+};
+
+use crate::{
+    StringValue,
+    UInt32Value,
+    reader::driver::get_attribute,
+    set_string_from_xml,
+    writer::driver::write_start_tag,
+};
+
+#[derive(Clone, Default, Debug)]
+pub struct Mention {
+    mention_person_id: StringValue,
+    mention_id:        StringValue,
+    start_index:       UInt32Value,
+    length:            UInt32Value,
+}
+
+impl Mention {
+    /// Id of the mentioned `Person`.
+    #[inline]
+    #[must_use]
+    pub fn mention_person_id(&self) -> &str {
+        self.mention_person_id.value_str()
+    }
+
+    #[inline]
+    pub fn set_mention_person_id<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.mention_person_id.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn mention_id(&self) -> &str {
+        self.mention_id.value_str()
+    }
+
+    #[inline]
+    pub fn set_mention_id<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.mention_id.set_value(value);
+        self
+    }
+
+    /// Position of the mention in the comment text, in characters.
+    #[inline]
+    #[must_use]
+    pub fn start_index(&self) -> u32 {
+        self.start_index.value()
+    }
+
+    #[inline]
+    pub fn set_start_index(&mut self, value: u32) -> &mut Self {
+        self.start_index.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn length(&self) -> u32 {
+        self.length.value()
+    }
+
+    #[inline]
+    pub fn set_length(&mut self, value: u32) -> &mut Self {
+        self.length.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn set_attributes(&mut self, e: &BytesStart) {
+        set_string_from_xml!(self, e, mention_person_id, "mentionpersonId");
+        set_string_from_xml!(self, e, mention_id, "mentionId");
+        set_string_from_xml!(self, e, start_index, "startIndex");
+        set_string_from_xml!(self, e, length, "length");
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // mention
+        let start_index = self.start_index.value_string();
+        let length = self.length.value_string();
+        write_start_tag(
+            writer,
+            "mention",
+            vec![
+                ("mentionpersonId", self.mention_person_id.value_str()).into(),
+                ("mentionId", self.mention_id.value_str()).into(),
+                ("startIndex", start_index.as_str()).into(),
+                ("length", length.as_str()).into(),
+            ],
+            true,
+        );
+    }
+}
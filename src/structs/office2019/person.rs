@@ -0,0 +1,110 @@
+// person
+use std::io::Cursor;
+
+use quick_xml::{
+    Writer,
+    events::BytesStart,
+};
+
+use crate::{
+    StringValue,
+    helper::utils::guid_from_hash,
+    reader::driver::get_attribute,
+    set_string_from_xml,
+    writer::driver::write_start_tag,
+};
+
+#[derive(Clone, Default, Debug)]
+pub struct Person {
+    display_name: StringValue,
+    id:           StringValue,
+    user_id:      StringValue,
+    provider_id:  StringValue,
+}
+
+impl Person {
+    /// Create a local person.
+    /// The id is derived from the display name, so the same name always
+    /// gives the same person.
+    /// # Arguments
+    /// * `display_name` - ex) "John Doe"
+    #[must_use]
+    pub fn new(display_name: &str) -> Self {
+        let mut obj = Self::default();
+        obj.display_name.set_value(display_name);
+        obj.id.set_value(guid_from_hash(format!("person:{display_name}")));
+        obj.user_id.set_value(display_name);
+        obj.provider_id.set_value("None");
+        obj
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn display_name(&self) -> &str {
+        self.display_name.value_str()
+    }
+
+    #[inline]
+    pub fn set_display_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.display_name.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn id(&self) -> &str {
+        self.id.value_str()
+    }
+
+    #[inline]
+    pub fn set_id<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.id.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn user_id(&self) -> &str {
+        self.user_id.value_str()
+    }
+
+    #[inline]
+    pub fn set_user_id<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.user_id.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn provider_id(&self) -> &str {
+        self.provider_id.value_str()
+    }
+
+    #[inline]
+    pub fn set_provider_id<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.provider_id.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn set_attributes(&mut self, e: &BytesStart) {
+        set_string_from_xml!(self, e, display_name, "displayName");
+        set_string_from_xml!(self, e, id, "id");
+        set_string_from_xml!(self, e, user_id, "userId");
+        set_string_from_xml!(self, e, provider_id, "providerId");
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // person
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        attributes.push(("displayName", self.display_name.value_str()).into());
+        attributes.push(("id", self.id.value_str()).into());
+        if self.user_id.has_value() {
+            attributes.push(("userId", self.user_id.value_str()).into());
+        }
+        if self.provider_id.has_value() {
+            attributes.push(("providerId", self.provider_id.value_str()).into());
+        }
+        write_start_tag(writer, "person", attributes, true);
+    }
+}
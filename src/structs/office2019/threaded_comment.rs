@@ -11,11 +11,19 @@ use quick_xml::{
 };
 
 use crate::{
+    BooleanValue,
     Coordinate,
     DateTimeValue,
     StringValue,
-    office2019::threaded_comment_text::ThreadedCommentText,
-    reader::driver::get_attribute,
+    XlsxError,
+    office2019::{
+        mention::Mention,
+        threaded_comment_text::ThreadedCommentText,
+    },
+    reader::driver::{
+        get_attribute,
+        get_required_attribute,
+    },
     set_string_from_xml,
     traits::AdjustmentCoordinate,
     writer::driver::{
@@ -31,6 +39,10 @@ pub struct ThreadedComment {
     d_t:                   DateTimeValue,
     threaded_comment_text: ThreadedCommentText,
     id:                    StringValue,
+    person_id:             StringValue,
+    parent_id:             StringValue,
+    done:                  BooleanValue,
+    mentions:              Vec<Mention>,
 }
 
 impl ThreadedComment {
@@ -96,30 +108,104 @@ impl ThreadedComment {
         self
     }
 
+    #[inline]
+    #[must_use]
+    pub fn text(&self) -> &str {
+        self.threaded_comment_text.value()
+    }
+
+    #[inline]
+    pub fn set_text<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.threaded_comment_text.set_value(value);
+        self
+    }
+
+    /// Id of the author `Person`.
+    #[inline]
+    #[must_use]
+    pub fn person_id(&self) -> &str {
+        self.person_id.value_str()
+    }
+
+    #[inline]
+    pub fn set_person_id<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.person_id.set_value(value);
+        self
+    }
+
+    /// Id of the first comment of the thread. Empty for the first comment
+    /// itself.
+    #[inline]
+    #[must_use]
+    pub fn parent_id(&self) -> &str {
+        self.parent_id.value_str()
+    }
+
+    #[inline]
+    pub fn set_parent_id<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.parent_id.set_value(value);
+        self
+    }
+
+    /// Whether the thread is resolved.
+    #[inline]
+    #[must_use]
+    pub fn done(&self) -> bool {
+        self.done.value()
+    }
+
+    #[inline]
+    pub fn set_done(&mut self, value: bool) -> &mut Self {
+        self.done.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn mentions(&self) -> &[Mention] {
+        &self.mentions
+    }
+
+    #[inline]
+    pub fn add_mention(&mut self, value: Mention) -> &mut Self {
+        self.mentions.push(value);
+        self
+    }
+
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        let coordinate = get_attribute(e, b"ref").unwrap();
+    ) -> Result<(), XlsxError> {
+        let coordinate = get_required_attribute(reader, e, b"ref")?;
         self.coordinate_mut().set_coordinate(coordinate);
 
         set_string_from_xml!(self, e, d_t, "dT");
         set_string_from_xml!(self, e, id, "id");
+        set_string_from_xml!(self, e, person_id, "personId");
+        set_string_from_xml!(self, e, parent_id, "parentId");
+        set_string_from_xml!(self, e, done, "done");
 
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"text" {
-                    self.threaded_comment_text.set_attributes(reader, e);
+                    self.threaded_comment_text.set_attributes(reader, e)?;
+                }
+            },
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"mention" {
+                    let mut obj = Mention::default();
+                    obj.set_attributes(e);
+                    self.mentions.push(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"threadedComment" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "threadedComment")
+            Event::Eof => return Err(XlsxError::missing_end_element("threadedComment", reader.buffer_position()))
         );
     }
 
@@ -132,15 +218,34 @@ impl ThreadedComment {
         if self.d_t.has_value() {
             attributes.push(("dT", self.d_t.value_str()).into());
         }
-        attributes.push(("personId", "{00000000-0000-0000-0000-000000000000}").into());
+        if self.person_id.has_value() {
+            attributes.push(("personId", self.person_id.value_str()).into());
+        } else {
+            attributes.push(("personId", "{00000000-0000-0000-0000-000000000000}").into());
+        }
         if self.id.has_value() {
             attributes.push(("id", self.id.value_str()).into());
         }
+        if self.parent_id.has_value() {
+            attributes.push(("parentId", self.parent_id.value_str()).into());
+        }
+        if self.done.has_value() {
+            attributes.push(("done", self.done.value_string()).into());
+        }
         write_start_tag(writer, "threadedComment", attributes, false);
 
         // text
         self.threaded_comment_text.write_to(writer);
 
+        // mentions
+        if !self.mentions.is_empty() {
+            write_start_tag(writer, "mentions", vec![], false);
+            for mention in &self.mentions {
+                mention.write_to(writer);
+            }
+            write_end_tag(writer, "mentions");
+        }
+
         write_end_tag(writer, "threadedComment");
     }
 }
@@ -0,0 +1,58 @@
+use crate::structs::Worksheet;
+
+/// A handle to a threaded comment conversation.
+/// Returned by `Worksheet::add_threaded_comment`.
+#[derive(Debug)]
+pub struct ThreadedCommentHandle<'a> {
+    worksheet: &'a mut Worksheet,
+    id:        String,
+}
+
+impl<'a> ThreadedCommentHandle<'a> {
+    #[inline]
+    pub(crate) fn new(worksheet: &'a mut Worksheet, id: String) -> Self {
+        Self { worksheet, id }
+    }
+
+    /// Id of the first comment of the thread.
+    #[inline]
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Add a reply to the thread.
+    /// # Arguments
+    /// * `author` - Display name of the author. ex) "John Doe"
+    /// * `text` - Reply text.
+    #[inline]
+    pub fn reply(&mut self, author: &str, text: &str) -> &mut Self {
+        self.worksheet
+            .reply_threaded_comment_crate(&self.id, author, text);
+        self
+    }
+
+    /// Mark the thread as resolved.
+    #[inline]
+    pub fn resolve(&mut self) -> &mut Self {
+        self.worksheet.resolve_threaded_comment_crate(&self.id, true);
+        self
+    }
+
+    /// Reopen a resolved thread.
+    #[inline]
+    pub fn reopen(&mut self) -> &mut Self {
+        self.worksheet.resolve_threaded_comment_crate(&self.id, false);
+        self
+    }
+
+    /// Mention a person at the end of the latest comment of the thread.
+    /// "@" followed by the display name is appended to the text.
+    /// # Arguments
+    /// * `person` - Display name of the person. ex) "Jane Doe"
+    #[inline]
+    pub fn mention(&mut self, person: &str) -> &mut Self {
+        self.worksheet.mention_threaded_comment_crate(&self.id, person);
+        self
+    }
+}
@@ -11,6 +11,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -46,7 +47,7 @@ impl ThreadedCommentText {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Text(e) => {
@@ -54,10 +55,10 @@ impl ThreadedCommentText {
             },
             Event::End(ref e) => {
                 if e.name().0 == b"text" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "text")
+            Event::Eof => return Err(XlsxError::missing_end_element("text", reader.buffer_position()))
         );
     }
 
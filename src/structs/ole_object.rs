@@ -14,9 +14,11 @@ use super::{
     StringValue,
 };
 use crate::{
+    XlsxError,
     helper::const_str::MC_NS,
     reader::driver::{
         get_attribute,
+        get_required_attribute,
         set_string_from_xml,
         xml_read_loop,
     },
@@ -234,7 +236,7 @@ impl OleObject {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         relationships: &RawRelationships,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut alternate_content: &str = "";
 
         xml_read_loop!(
@@ -251,18 +253,22 @@ impl OleObject {
                     b"oleObject" => {
                         if alternate_content == "Choice" {
                             self.prog_id
-                                .set_value_string(get_attribute(e, b"progId").unwrap());
-
-                            let r_id = get_attribute(e, b"r:id").unwrap();
-                            let attached_file =
-                                relationships.relationship_by_rid(&r_id).raw_file();
+                                .set_value_string(get_required_attribute(reader, e, b"progId")?);
+
+                            let r_id = get_required_attribute(reader, e, b"r:id")?;
+                            let attached_file = relationships
+                                .relationship_by_rid(&r_id)
+                                .ok_or_else(|| {
+                                    XlsxError::missing_relationship(&r_id, reader.buffer_position())
+                                })?
+                                .raw_file();
                             self.set_object_extension(attached_file.extension());
                             self.set_object_data(attached_file.file_data());
                         }
                     }
                     b"objectPr" => {
                         let mut obj = EmbeddedObjectProperties::default();
-                        obj.set_attributes(reader, e, relationships);
+                        obj.set_attributes(reader, e, relationships)?;
                         self.set_embedded_object_properties(obj);
                     }
                     _ => (),
@@ -270,10 +276,10 @@ impl OleObject {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"mc:AlternateContent" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "mc:AlternateContent")
+            Event::Eof => return Err(XlsxError::missing_end_element("mc:AlternateContent", reader.buffer_position()))
         );
     }
 
@@ -12,6 +12,7 @@ use quick_xml::{
 
 use super::OleObject;
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     structs::raw::RawRelationships,
     writer::driver::{
@@ -61,22 +62,22 @@ impl OleObjects {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         relationships: &RawRelationships,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"mc:AlternateContent" {
                     let mut obj = OleObject::default();
-                    obj.set_attributes(reader, e, relationships);
+                    obj.set_attributes(reader, e, relationships)?;
                     self.set_ole_object(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"oleObjects" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "oleObjects")
+            Event::Eof => return Err(XlsxError::missing_end_element("oleObjects", reader.buffer_position()))
         );
     }
 
@@ -7,7 +7,8 @@ use quick_xml::{
 };
 
 use crate::{
-    reader::driver::get_attribute,
+    XlsxError,
+    reader::driver::get_required_attribute,
     structs::DoubleValue,
     writer::driver::write_start_tag,
 };
@@ -138,20 +139,28 @@ impl PageMargins {
 
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
-        _reader: &mut Reader<R>,
+        reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         self.left
-            .set_value_string(get_attribute(e, b"left").unwrap());
+            .set_value_string(get_required_attribute(reader, e, b"left")?)
+            .map_err(|error| error.at(reader.buffer_position()))?;
         self.right
-            .set_value_string(get_attribute(e, b"right").unwrap());
-        self.top.set_value_string(get_attribute(e, b"top").unwrap());
+            .set_value_string(get_required_attribute(reader, e, b"right")?)
+            .map_err(|error| error.at(reader.buffer_position()))?;
+        self.top
+            .set_value_string(get_required_attribute(reader, e, b"top")?)
+            .map_err(|error| error.at(reader.buffer_position()))?;
         self.bottom
-            .set_value_string(get_attribute(e, b"bottom").unwrap());
+            .set_value_string(get_required_attribute(reader, e, b"bottom")?)
+            .map_err(|error| error.at(reader.buffer_position()))?;
         self.header
-            .set_value_string(get_attribute(e, b"header").unwrap());
+            .set_value_string(get_required_attribute(reader, e, b"header")?)
+            .map_err(|error| error.at(reader.buffer_position()))?;
         self.footer
-            .set_value_string(get_attribute(e, b"footer").unwrap());
+            .set_value_string(get_required_attribute(reader, e, b"footer")?)
+            .map_err(|error| error.at(reader.buffer_position()))?;
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -7,6 +7,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -210,10 +211,10 @@ impl PageSetup {
 
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
-        _reader: &mut Reader<R>,
+        reader: &mut Reader<R>,
         e: &BytesStart,
         relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, paper_size, "paperSize");
         set_string_from_xml!(self, e, orientation, "orientation");
         set_string_from_xml!(self, e, scale, "scale");
@@ -224,11 +225,12 @@ impl PageSetup {
 
         if let Some(r_id) = get_attribute(e, b"r:id") {
             let attached_file = relationships
-                .unwrap()
-                .relationship_by_rid(&r_id)
+                .and_then(|v| v.relationship_by_rid(&r_id))
+                .ok_or_else(|| XlsxError::missing_relationship(&r_id, reader.buffer_position()))?
                 .raw_file();
             self.set_object_data(attached_file.file_data());
         }
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>, r_id: &mut usize) {
@@ -14,9 +14,11 @@ use super::{
     PaneValues,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        try_set_string_from_xml,
     },
     writer::driver::write_start_tag,
 };
@@ -139,17 +141,18 @@ impl Pane {
 
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
-        _reader: &mut Reader<R>,
+        reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
-        set_string_from_xml!(self, e, horizontal_split, "xSplit");
-        set_string_from_xml!(self, e, vertical_split, "ySplit");
+    ) -> Result<(), XlsxError> {
+        try_set_string_from_xml!(self, reader, e, horizontal_split, "xSplit");
+        try_set_string_from_xml!(self, reader, e, vertical_split, "ySplit");
         set_string_from_xml!(self, e, active_pane, "activePane");
         set_string_from_xml!(self, e, state, "state");
 
         if let Some(v) = get_attribute(e, b"topLeftCell") {
             self.top_left_cell.set_coordinate(v);
         }
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
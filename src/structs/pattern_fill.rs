@@ -16,6 +16,7 @@ use super::{
     PatternValues,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -178,11 +179,11 @@ impl PatternFill {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, pattern_type, "patternType");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -191,12 +192,12 @@ impl PatternFill {
                 match e.name().into_inner() {
                     b"fgColor" => {
                         let mut obj = Color::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_foreground_color(obj);
                     }
                     b"bgColor" => {
                         let mut obj = Color::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_background_color(obj);
                     }
                     _ => (),
@@ -204,10 +205,10 @@ impl PatternFill {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"patternFill" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "patternFill")
+            Event::Eof => return Err(XlsxError::missing_end_element("patternFill", reader.buffer_position()))
         );
     }
 
@@ -10,21 +10,24 @@ use quick_xml::{
     },
 };
 
-use crate::reader::driver::xml_read_loop;
+use crate::{
+    XlsxError,
+    reader::driver::xml_read_loop,
+};
 
 #[derive(Default, Debug)]
 pub(crate) struct PhoneticRun {}
 
 impl PhoneticRun {
-    pub(crate) fn set_attributes<R: std::io::BufRead>(reader: &mut Reader<R>, _e: &BytesStart) {
+    pub(crate) fn set_attributes<R: std::io::BufRead>(reader: &mut Reader<R>, _e: &BytesStart) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::End(ref e) => {
                 if e.name().into_inner() == b"rPh" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "rPh")
+            Event::Eof => return Err(XlsxError::missing_end_element("rPh", reader.buffer_position()))
         );
     }
 
@@ -11,6 +11,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     helper::const_str::{
         MC_NS,
         REL_OFC_NS,
@@ -20,6 +21,7 @@ use crate::{
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        try_set_string_from_xml,
         xml_read_loop,
     },
     structs::{
@@ -280,10 +282,10 @@ impl PivotCacheDefinition {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, id, "r:id");
         set_string_from_xml!(self, e, refreshed_by, "refreshedBy");
-        set_string_from_xml!(self, e, refreshed_date, "refreshedDate");
+        try_set_string_from_xml!(self, reader, e, refreshed_date, "refreshedDate");
         set_string_from_xml!(self, e, created_version, "createdVersion");
         set_string_from_xml!(self, e, refreshed_version, "refreshedVersion");
         set_string_from_xml!(self, e, min_refreshable_version, "minRefreshableVersion");
@@ -294,28 +296,28 @@ impl PivotCacheDefinition {
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"cacheSource" {
                     let mut obj = CacheSource::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.set_cache_source(obj);
                 }
             },
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"cacheSource" {
                     let mut obj = CacheSource::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.set_cache_source(obj);
                 }
                 if e.name().into_inner() == b"cacheFields" {
                     let mut obj = CacheFields::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_cache_fields(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"pivotCacheDefinition" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "pivotCacheDefinition")
+            Event::Eof => return Err(XlsxError::missing_end_element("pivotCacheDefinition", reader.buffer_position()))
         );
     }
 
@@ -8,7 +8,7 @@ use quick_xml::{
 };
 
 use crate::{
-    EnumValue, PivotTableAxisValues, reader::driver::{
+    EnumValue, PivotTableAxisValues, XlsxError, reader::driver::{
         get_attribute,
         set_string_from_xml,
     }, structs::{
@@ -99,28 +99,28 @@ impl PivotField {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, data_field, "dataField");
         set_string_from_xml!(self, e, show_all, "showAll");
         set_string_from_xml!(self, e, axis, "axis");
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"items" {
-                    self.items.set_attributes(reader, e);
+                    self.items.set_attributes(reader, e)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"pivotField" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "pivotField")
+            Event::Eof => return Err(XlsxError::missing_end_element("pivotField", reader.buffer_position()))
         );
     }
 
@@ -11,6 +11,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     structs::PivotField,
     writer::driver::{
@@ -59,29 +60,29 @@ impl PivotFields {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"pivotField" {
                     let mut obj = PivotField::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.add_list_mut(obj);
                 }
             },
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"pivotField" {
                     let mut obj = PivotField::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.add_list_mut(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"pivotFields" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "pivotFields")
+            Event::Eof => return Err(XlsxError::missing_end_element("pivotFields", reader.buffer_position()))
         );
     }
 
@@ -11,7 +11,7 @@ use quick_xml::{
 };
 
 use crate::{
-    RowFields, helper::const_str::{
+    RowFields, XlsxError, helper::const_str::{
         MC_NS,
         SHEET_MAIN_NS,
         SHEET_MS_REVISION_NS,
@@ -685,7 +685,7 @@ impl PivotTableDefinition {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, name, "name");
         set_string_from_xml!(self, e, cache_id, "cacheId");
         set_string_from_xml!(self, e, apply_number_formats, "applyNumberFormats");
@@ -727,41 +727,41 @@ impl PivotTableDefinition {
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"pivotFields" {
                     let mut obj = PivotFields::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_pivot_fields(obj);
                 }
                 if e.name().into_inner() == b"rowFields" {
                     let mut obj = RowFields::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_row_fields(obj);
                 }
                 if e.name().into_inner() == b"rowItems" {
                     let mut obj = RowItems::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_row_items(obj);
                 }
                 if e.name().into_inner() == b"colFields" {
                     let mut obj = ColumnFields::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_column_fields(obj);
                 }
                 if e.name().into_inner() == b"colItems" {
                     let mut obj = ColumnItems::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_column_items(obj);
                 }
                 if e.name().into_inner() == b"dataFields" {
                     let mut obj = DataFields::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_data_fields(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"pivotTableDefinition" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "pivotTableDefinition")
+            Event::Eof => return Err(XlsxError::missing_end_element("pivotTableDefinition", reader.buffer_position()))
         );
     }
 
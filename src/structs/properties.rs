@@ -10,6 +10,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     helper::const_str::{
         COREPROPS_NS,
         DCMITYPE_NS,
@@ -356,7 +357,7 @@ impl Properties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut value: String = String::new();
         xml_read_loop!(
             reader,
@@ -379,7 +380,7 @@ impl Properties {
                 b"Company" => {self.set_company(std::mem::take(&mut value));},
                 _ => {}
             },
-            Event::Eof => return,
+            Event::Eof => return Ok(()),
         );
     }
 
@@ -387,7 +388,7 @@ impl Properties {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut value: String = String::new();
         xml_read_loop!(
             reader,
@@ -405,7 +406,7 @@ impl Properties {
                 b"Company" => {self.set_company(std::mem::take(&mut value));}
                 _ =>{}
             },
-            Event::Eof => return,
+            Event::Eof => return Ok(()),
         );
     }
 
@@ -414,10 +415,11 @@ impl Properties {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut obj = CustomProperties::default();
-        obj.set_attributes(reader, e);
+        obj.set_attributes(reader, e)?;
         self.set_custom_properties(obj);
+        Ok(())
     }
 
     pub(crate) fn write_to_core(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -12,7 +12,9 @@ use crate::{
     XlsxError,
     reader::driver::{
         join_paths,
+        parse_error_context,
         zip_by_name,
+        zip_parse_error_context,
     },
     structs::{
         StringValue,
@@ -58,12 +60,13 @@ impl RawFile {
         self.path()
     }
 
+    /// The extension of the file name in lower case, empty when it has none.
     #[inline]
     pub(crate) fn extension(&self) -> String {
         self.file_name()
             .rsplit_once('.')
             .map(|(_, ext)| ext.to_lowercase())
-            .unwrap()
+            .unwrap_or_default()
     }
 
     #[inline]
@@ -184,6 +187,22 @@ impl RawFile {
         Ok(())
     }
 
+    /// Fill in the part name and the element path of a parse error raised
+    /// while reading this file.
+    pub(crate) fn parse_error_context(&self, error: XlsxError) -> XlsxError {
+        if self.file_data.is_empty() {
+            if let Some(source_file) = self.source_file.as_ref() {
+                let archive = File::open(source_file)
+                    .ok()
+                    .and_then(|file| zip::read::ZipArchive::new(file).ok());
+                if let Some(mut archive) = archive {
+                    return zip_parse_error_context(error, &mut archive, self.file_target());
+                }
+            }
+        }
+        parse_error_context(error, self.file_target(), &self.file_data)
+    }
+
     pub(crate) fn write_to_target<W: io::Seek + io::Write>(
         &self,
         target: &str,
@@ -0,0 +1,202 @@
+use std::{
+    io,
+    io::Cursor,
+    path::Path,
+};
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::BytesStart,
+};
+
+use crate::{
+    reader::driver::join_paths,
+    structs::{
+        WriterManager,
+        XlsxError,
+        raw::{
+            RawRelationship,
+            RawRelationships,
+        },
+    },
+    writer::driver::write_start_tag,
+};
+
+/// A package part the crate does not model, kept together with the
+/// relationship that points at it and the relationship files below it.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct RawPart {
+    relationship:       RawRelationship,
+    relationships_list: Vec<RawRelationships>,
+}
+impl RawPart {
+    #[inline]
+    pub(crate) fn relationship(&self) -> &RawRelationship {
+        &self.relationship
+    }
+
+    #[inline]
+    pub(crate) fn relationships_list(&self) -> &[RawRelationships] {
+        &self.relationships_list
+    }
+
+    /// Build a part from a relationship read with a worksheet, picking the
+    /// relationship files of its sub parts out of `relationships_list`.
+    pub(crate) fn from_relationship(
+        relationship: &RawRelationship,
+        id: &str,
+        relationships_list: &[RawRelationships],
+    ) -> Self {
+        let mut obj = Self {
+            relationship:       relationship.clone(),
+            relationships_list: Vec::new(),
+        };
+        obj.relationship.set_id(id);
+        let mut pending = vec![relationship.clone()];
+        while let Some(relationship) = pending.pop() {
+            if relationship.target_mode() == "External" {
+                continue;
+            }
+            let raw_file = relationship.raw_file();
+            let rels_target = join_paths(&raw_file.path(), &raw_file.make_rel_name());
+            let Some(relationships) = relationships_list
+                .iter()
+                .find(|relationships| relationships.file_target() == rels_target)
+            else {
+                continue;
+            };
+            if obj
+                .relationships_list
+                .iter()
+                .any(|v| v.file_target() == rels_target)
+            {
+                continue;
+            }
+            pending.extend(relationships.relationship_list().iter().cloned());
+            obj.relationships_list.push(relationships.clone());
+        }
+        obj
+    }
+
+    /// Read the part a `Relationship` element points at, along with the
+    /// relationship files below it.
+    pub(crate) fn set_attributes<R: io::BufRead, A: io::Read + io::Seek>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        arv: &mut zip::read::ZipArchive<A>,
+        base_path: &str,
+        id: &str,
+    ) -> Result<(), XlsxError> {
+        self.relationship
+            .set_attributes(reader, e, arv, base_path, None);
+        self.relationship.set_id(id);
+        if self.relationship.target_mode() == "External" {
+            return Ok(());
+        }
+        let raw_file = self.relationship.raw_file();
+        let (path, rel_name) = (raw_file.path(), raw_file.make_rel_name());
+        self.read_relationships(arv, &path, &rel_name)
+    }
+
+    fn read_relationships<A: io::Read + io::Seek>(
+        &mut self,
+        arv: &mut zip::read::ZipArchive<A>,
+        base_path: &str,
+        target: &str,
+    ) -> Result<(), XlsxError> {
+        let path = join_paths(base_path, target);
+        if self
+            .relationships_list
+            .iter()
+            .any(|relationships| relationships.file_target() == path)
+        {
+            return Ok(());
+        }
+        let mut obj = RawRelationships::default();
+        if obj.set_attributes(arv, base_path, target, None::<&Path>)? {
+            let children: Vec<(String, String)> = obj
+                .relationship_list()
+                .iter()
+                .filter(|relationship| relationship.target_mode() != "External")
+                .map(|relationship| {
+                    let raw_file = relationship.raw_file();
+                    (raw_file.path(), raw_file.make_rel_name())
+                })
+                .collect();
+            self.relationships_list.push(obj);
+            for (path, rel_name) in children {
+                self.read_relationships(arv, &path, &rel_name)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Write the part and its sub parts, and its `Relationship` element to
+    /// `writer`. The part is renamed when another part already took its name.
+    pub(crate) fn write_to<W: io::Seek + io::Write>(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        writer_mng: &mut WriterManager<W>,
+    ) -> Result<(), XlsxError> {
+        let relationship = self.relationship();
+        let mut target = relationship.target().to_string();
+        if relationship.target_mode() != "External" {
+            let raw_file = relationship.raw_file();
+            let rels_target = join_paths(&raw_file.path(), &raw_file.make_rel_name());
+            let mut file_target = raw_file.file_target().to_string();
+            if writer_mng.check_file_exist(&file_target) {
+                file_target = free_file_target(&file_target, writer_mng);
+                let file_name = file_target.rsplit('/').next().unwrap_or_default();
+                target = match target.rsplit_once('/') {
+                    Some((dir, _)) => format!("{dir}/{file_name}"),
+                    None => file_name.to_string(),
+                };
+            }
+            raw_file.write_to_target(&file_target, writer_mng)?;
+
+            for relationships in self.relationships_list() {
+                if relationships.file_target() == rels_target {
+                    let (dir, file_name) = file_target.rsplit_once('/').unwrap_or(("", &file_target));
+                    let rels_target = join_paths(dir, &format!("_rels/{file_name}.rels"));
+                    relationships.write_to(writer_mng, Some(&rels_target))?;
+                } else {
+                    relationships.write_to(writer_mng, None)?;
+                }
+            }
+        }
+
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        attributes.push(("Id", relationship.id()).into());
+        attributes.push(("Type", relationship.get_type()).into());
+        attributes.push(("Target", &target).into());
+        if !relationship.target_mode().is_empty() {
+            attributes.push(("TargetMode", relationship.target_mode()).into());
+        }
+        write_start_tag(writer, "Relationship", attributes, true);
+        Ok(())
+    }
+}
+
+/// Find an unused name for `file_target` by numbering its file stem,
+/// e.g. `xl/drawings/vmlDrawing2.vml`.
+fn free_file_target<W: io::Seek + io::Write>(
+    file_target: &str,
+    writer_mng: &mut WriterManager<W>,
+) -> String {
+    let (stem, extension) = file_target
+        .rsplit_once('.')
+        .map_or((file_target, String::new()), |(stem, extension)| {
+            (stem, format!(".{extension}"))
+        });
+    let stem = stem.trim_end_matches(|c: char| c.is_ascii_digit());
+    let mut index = 1;
+    loop {
+        let target = format!("{stem}{index}{extension}");
+        if !writer_mng.check_file_exist(&target) {
+            return target;
+        }
+        index += 1;
+    }
+}
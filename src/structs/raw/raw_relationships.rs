@@ -17,6 +17,7 @@ use crate::{
     helper::const_str::REL_NS,
     reader::driver::{
         join_paths,
+        parse_error_context,
         xml_read_loop,
         zip_by_name,
     },
@@ -92,23 +93,21 @@ impl RawRelationships {
         self.relationship_list_mut()
     }
 
-    pub(crate) fn relationship_by_rid(&self, r_id: &str) -> &RawRelationship {
+    pub(crate) fn relationship_by_rid(&self, r_id: &str) -> Option<&RawRelationship> {
         self.relationship_list()
             .iter()
             .find(|relationship| relationship.id() == r_id)
-            .unwrap_or_else(|| panic!("Not found relationship with ID: {r_id}."))
     }
 
     #[deprecated(since = "3.0.0", note = "Use relationship_by_rid()")]
-    pub(crate) fn get_relationship_by_rid(&self, r_id: &str) -> &RawRelationship {
+    pub(crate) fn get_relationship_by_rid(&self, r_id: &str) -> Option<&RawRelationship> {
         self.relationship_by_rid(r_id)
     }
 
-    pub(crate) fn relationship_by_type(&self, rtype: &str) -> &RawRelationship {
+    pub(crate) fn relationship_by_type(&self, rtype: &str) -> Option<&RawRelationship> {
         self.relationship_list()
             .iter()
             .find(|relationship| relationship.get_type() == rtype)
-            .unwrap_or_else(|| panic!("Not found relationship with Type: {rtype}."))
     }
 
     #[inline]
@@ -123,18 +122,29 @@ impl RawRelationships {
         base_path: &str,
         target: &str,
         source_file: Option<&Path>,
-    ) -> bool {
-        let data = {
-            let path_str = join_paths(base_path, target);
+    ) -> Result<bool, XlsxError> {
+        let path_str = join_paths(base_path, target);
+        let mut buf = Vec::new();
+        {
             let Ok(file_path) = zip_by_name(arv, &path_str) else {
-                return false;
+                return Ok(false);
             };
-            self.set_file_target(path_str);
-            let mut r = io::BufReader::new(file_path);
-            let mut buf = Vec::new();
-            r.read_to_end(&mut buf).unwrap();
-            io::Cursor::new(buf)
-        };
+            io::BufReader::new(file_path).read_to_end(&mut buf)?;
+        }
+        self.set_file_target(path_str);
+
+        self.read_relationships(&buf, arv, base_path, source_file)
+            .map_err(|e| parse_error_context(e, self.file_target(), &buf))?;
+        Ok(true)
+    }
+
+    fn read_relationships<R: Read + io::Seek>(
+        &mut self,
+        data: &[u8],
+        arv: &mut zip::read::ZipArchive<R>,
+        base_path: &str,
+        source_file: Option<&Path>,
+    ) -> Result<(), XlsxError> {
         let mut reader = Reader::from_reader(data);
         reader.config_mut().trim_text(true);
 
@@ -150,7 +160,7 @@ impl RawRelationships {
             Event::Eof => break
         );
 
-        true
+        Ok(())
     }
 
     pub(crate) fn write_to<W: io::Seek + io::Write>(
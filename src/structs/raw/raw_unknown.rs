@@ -0,0 +1,485 @@
+use std::{
+    borrow::Cow,
+    io,
+    io::{
+        Cursor,
+        Read,
+    },
+};
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::{
+        BytesStart,
+        Event,
+        attributes::Attribute,
+    },
+};
+
+use crate::{
+    reader::driver::{
+        get_attribute,
+        get_attribute_value,
+        parse_error_context,
+        xml_read_loop,
+        zip_by_name,
+    },
+    structs::{
+        WriterManager,
+        XlsxError,
+        raw::{
+            RawPart,
+            RawRelationships,
+        },
+    },
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+        write_text_node_no_escape,
+    },
+};
+
+/// Children of `worksheet` in schema order.
+pub(crate) const WORKSHEET_ELEMENTS: &[&str] = &[
+    "sheetPr",
+    "dimension",
+    "sheetViews",
+    "sheetFormatPr",
+    "cols",
+    "sheetData",
+    "sheetCalcPr",
+    "sheetProtection",
+    "protectedRanges",
+    "scenarios",
+    "autoFilter",
+    "sortState",
+    "dataConsolidate",
+    "customSheetViews",
+    "mergeCells",
+    "phoneticPr",
+    "conditionalFormatting",
+    "dataValidations",
+    "hyperlinks",
+    "printOptions",
+    "pageMargins",
+    "pageSetup",
+    "headerFooter",
+    "rowBreaks",
+    "colBreaks",
+    "customProperties",
+    "cellWatches",
+    "ignoredErrors",
+    "smartTags",
+    "drawing",
+    "legacyDrawing",
+    "legacyDrawingHF",
+    "drawingHF",
+    "picture",
+    "oleObjects",
+    "controls",
+    "webPublishItems",
+    "tableParts",
+    "extLst",
+];
+
+/// Children of `workbook` in schema order.
+pub(crate) const WORKBOOK_ELEMENTS: &[&str] = &[
+    "fileVersion",
+    "fileSharing",
+    "workbookPr",
+    "workbookProtection",
+    "bookViews",
+    "sheets",
+    "functionGroups",
+    "externalReferences",
+    "definedNames",
+    "calcPr",
+    "oleSize",
+    "customWorkbookViews",
+    "pivotCaches",
+    "smartTagPr",
+    "smartTagTypes",
+    "webPublishing",
+    "fileRecoveryPr",
+    "webPublishObjects",
+    "extLst",
+];
+
+/// XML and package parts of a part that the crate does not model.
+///
+/// Unknown children of the root element are kept verbatim with their position
+/// in the schema, unknown `ext` entries of its `extLst` and the related parts
+/// of unknown relationship types are kept as well, so that they survive a
+/// read and write round trip.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct RawUnknown {
+    root_attributes:  Vec<(String, String)>,
+    elements:         Vec<(usize, String)>,
+    extensions:       Vec<String>,
+    relationship_ids: Vec<(String, String)>,
+    parts:            Vec<RawPart>,
+}
+impl RawUnknown {
+    #[inline]
+    pub(crate) fn elements(&self) -> &[(usize, String)] {
+        &self.elements
+    }
+
+    #[inline]
+    pub(crate) fn extensions(&self) -> &[String] {
+        &self.extensions
+    }
+
+    #[inline]
+    pub(crate) fn has_extensions(&self) -> bool {
+        !self.extensions.is_empty()
+    }
+
+    #[inline]
+    pub(crate) fn parts(&self) -> &[RawPart] {
+        &self.parts
+    }
+
+    #[inline]
+    pub(crate) fn has_parts(&self) -> bool {
+        !self.parts.is_empty()
+    }
+
+    /// Whether the part at `file_target` is kept by this object.
+    pub(crate) fn has_part(&self, file_target: &str) -> bool {
+        self.parts.iter().any(|part| {
+            part.relationship().target_mode() != "External"
+                && part.relationship().raw_file().file_target() == file_target
+        })
+    }
+
+    /// The relationship id a kept element or part uses when written.
+    fn relationship_id(&mut self, id: &str) -> String {
+        if let Some((_, new_id)) = self.relationship_ids.iter().find(|(old, _)| old == id) {
+            return new_id.clone();
+        }
+        let new_id = format!("rIdRaw{}", self.relationship_ids.len() + 1);
+        self.relationship_ids.push((id.to_string(), new_id.clone()));
+        new_id
+    }
+
+    fn is_referenced(&self, id: &str) -> bool {
+        self.relationship_ids.iter().any(|(old, _)| old == id)
+    }
+
+    /// Read the children of the root element of `data` that are not listed in
+    /// `known`, and the `ext` entries of its `extLst` whose `uri` is not listed
+    /// in `known_extensions`.
+    pub(crate) fn read_elements<R: io::BufRead>(
+        &mut self,
+        data: R,
+        schema: &[&str],
+        known: &[&str],
+        known_extensions: &[&str],
+    ) -> Result<(), XlsxError> {
+        let mut reader = Reader::from_reader(data);
+        let mut depth = 0;
+        let mut position = 0;
+        let mut in_ext_lst = false;
+        let mut skip_buf = Vec::new();
+        xml_read_loop!(
+            reader,
+            Event::Start(ref e) => {
+                depth += 1;
+                let name = String::from_utf8_lossy(e.name().into_inner()).into_owned();
+                if depth == 2 {
+                    if let Some(index) = schema.iter().position(|v| *v == name) {
+                        position = index;
+                    }
+                }
+                if depth == 1 {
+                    self.read_root_attributes(e);
+                } else if depth == 2 && name == "extLst" {
+                    in_ext_lst = true;
+                } else if depth == 2 {
+                    if known.contains(&name.as_str()) {
+                        reader.read_to_end_into(e.name(), &mut skip_buf).map_err(|err| {
+                            XlsxError::parse(reader.buffer_position(), err.to_string())
+                        })?;
+                        skip_buf.clear();
+                    } else {
+                        let xml = self.capture(&mut reader, e, false)?;
+                        self.elements.push((position, xml));
+                    }
+                    depth -= 1;
+                } else if depth == 3 && in_ext_lst {
+                    let uri = get_attribute(e, b"uri").unwrap_or_default();
+                    if known_extensions.contains(&uri.as_str()) {
+                        reader.read_to_end_into(e.name(), &mut skip_buf).map_err(|err| {
+                            XlsxError::parse(reader.buffer_position(), err.to_string())
+                        })?;
+                        skip_buf.clear();
+                    } else {
+                        let xml = self.capture(&mut reader, e, false)?;
+                        self.extensions.push(xml);
+                    }
+                    depth -= 1;
+                }
+            },
+            Event::Empty(ref e) => {
+                let name = String::from_utf8_lossy(e.name().into_inner()).into_owned();
+                if depth == 1 && name != "extLst" {
+                    if let Some(index) = schema.iter().position(|v| *v == name) {
+                        position = index;
+                    }
+                    if !known.contains(&name.as_str()) {
+                        let xml = self.capture(&mut reader, e, true)?;
+                        self.elements.push((position, xml));
+                    }
+                } else if depth == 2 && in_ext_lst {
+                    let uri = get_attribute(e, b"uri").unwrap_or_default();
+                    if !known_extensions.contains(&uri.as_str()) {
+                        let xml = self.capture(&mut reader, e, true)?;
+                        self.extensions.push(xml);
+                    }
+                }
+            },
+            Event::End(_) => {
+                if depth == 2 {
+                    in_ext_lst = false;
+                }
+                depth -= 1;
+            },
+            Event::Eof => break,
+        );
+        Ok(())
+    }
+
+    fn read_root_attributes(&mut self, e: &BytesStart) {
+        for attr in e.attributes().with_checks(false).flatten() {
+            let key = String::from_utf8_lossy(attr.key.into_inner()).into_owned();
+            if key.starts_with("xmlns:") || key == "mc:Ignorable" {
+                if let Ok(value) = get_attribute_value(&attr) {
+                    self.root_attributes.push((key, value));
+                }
+            }
+        }
+    }
+
+    /// Copy the element `start` and its content to a string, renaming the
+    /// relationship ids it refers to.
+    fn capture<R: io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        start: &BytesStart,
+        is_empty: bool,
+    ) -> Result<String, XlsxError> {
+        let mut writer = Writer::new(Cursor::new(Vec::new()));
+        let start = self.rename_relationship_ids(start);
+        if is_empty {
+            writer.write_event(Event::Empty(start))?;
+        } else {
+            writer.write_event(Event::Start(start))?;
+            let mut depth = 1;
+            let mut buf = Vec::new();
+            while depth > 0 {
+                let event = reader
+                    .read_event_into(&mut buf)
+                    .map_err(|e| XlsxError::parse(reader.buffer_position(), e.to_string()))?;
+                match event {
+                    Event::Start(ref e) => {
+                        depth += 1;
+                        writer.write_event(Event::Start(self.rename_relationship_ids(e)))?;
+                    }
+                    Event::Empty(ref e) => {
+                        writer.write_event(Event::Empty(self.rename_relationship_ids(e)))?;
+                    }
+                    Event::End(_) => {
+                        depth -= 1;
+                        writer.write_event(event)?;
+                    }
+                    Event::Eof => {
+                        return Err(XlsxError::parse(
+                            reader.buffer_position(),
+                            "unexpected end of file",
+                        ));
+                    }
+                    _ => writer.write_event(event)?,
+                }
+                buf.clear();
+            }
+        }
+        Ok(String::from_utf8_lossy(&writer.into_inner().into_inner()).into_owned())
+    }
+
+    fn rename_relationship_ids(&mut self, e: &BytesStart) -> BytesStart<'static> {
+        let mut obj = BytesStart::new(String::from_utf8_lossy(e.name().into_inner()).into_owned());
+        for attr in e.attributes().with_checks(false).flatten() {
+            if attr.key.into_inner().starts_with(b"r:") {
+                let id = String::from_utf8_lossy(&attr.value).into_owned();
+                let new_id = self.relationship_id(&id);
+                obj.push_attribute(Attribute {
+                    key:   attr.key,
+                    value: Cow::Owned(new_id.into_bytes()),
+                });
+            } else {
+                obj.push_attribute(attr);
+            }
+        }
+        obj
+    }
+
+    /// Keep the parts of `relationships` that the kept elements refer to or
+    /// whose type is not listed in `known_types`.
+    pub(crate) fn read_parts(
+        &mut self,
+        relationships: &RawRelationships,
+        relationships_list: &[RawRelationships],
+        known_types: &[&str],
+    ) {
+        for relationship in relationships.relationship_list() {
+            if self.is_referenced(relationship.id())
+                || !known_types.contains(&relationship.get_type())
+            {
+                let id = self.relationship_id(relationship.id());
+                self.parts.push(RawPart::from_relationship(
+                    relationship,
+                    &id,
+                    relationships_list,
+                ));
+            }
+        }
+    }
+
+    /// Keep the parts of the relationship file `rels_target` that the kept
+    /// elements refer to or whose type is not listed in `known_types`.
+    /// Relationships listed in `excluded_ids` are never kept.
+    pub(crate) fn read_parts_from_archive<R: Read + io::Seek>(
+        &mut self,
+        arv: &mut zip::read::ZipArchive<R>,
+        rels_target: &str,
+        base_path: &str,
+        known_types: &[&str],
+        excluded_ids: &[&str],
+    ) -> Result<(), XlsxError> {
+        let mut buf = Vec::new();
+        {
+            let Ok(file) = zip_by_name(arv, rels_target) else {
+                return Ok(());
+            };
+            io::BufReader::new(file).read_to_end(&mut buf)?;
+        }
+        self.read_relationship_file(&buf, arv, base_path, known_types, excluded_ids)
+            .map_err(|e| parse_error_context(e, rels_target, &buf))
+    }
+
+    fn read_relationship_file<R: Read + io::Seek>(
+        &mut self,
+        data: &[u8],
+        arv: &mut zip::read::ZipArchive<R>,
+        base_path: &str,
+        known_types: &[&str],
+        excluded_ids: &[&str],
+    ) -> Result<(), XlsxError> {
+        let mut reader = Reader::from_reader(data);
+        reader.config_mut().trim_text(true);
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"Relationship" {
+                    let id = get_attribute(e, b"Id").unwrap_or_default();
+                    let r_type = get_attribute(e, b"Type").unwrap_or_default();
+                    if !excluded_ids.contains(&id.as_str())
+                        && (self.is_referenced(&id) || !known_types.contains(&r_type.as_str()))
+                    {
+                        let new_id = self.relationship_id(&id);
+                        let mut obj = RawPart::default();
+                        obj.set_attributes(&mut reader, e, arv, base_path, &new_id)?;
+                        self.parts.push(obj);
+                    }
+                }
+            },
+            Event::Eof => break,
+        );
+        Ok(())
+    }
+
+    /// Add the namespace declarations and ignorable prefixes of the original
+    /// root element that `attributes` lacks.
+    pub(crate) fn extend_root_attributes<'a>(
+        &'a self,
+        attributes: &mut crate::structs::AttrCollection<'a>,
+    ) {
+        for (key, value) in &self.root_attributes {
+            if key == "mc:Ignorable" {
+                continue;
+            }
+            if !attributes.iter().any(|attr| attr.0 == key) {
+                attributes.push((key.as_str(), value.as_str()).into());
+            }
+        }
+        for (key, value) in &self.root_attributes {
+            if key != "mc:Ignorable" {
+                continue;
+            }
+            match attributes.iter_mut().find(|attr| attr.0 == key) {
+                Some(attr) => {
+                    let mut prefixes: Vec<&str> = attr.1.split_whitespace().collect();
+                    for prefix in value.split_whitespace() {
+                        if !prefixes.contains(&prefix) {
+                            prefixes.push(prefix);
+                        }
+                    }
+                    attr.1 = Cow::Owned(prefixes.join(" "));
+                }
+                None => attributes.push((key.as_str(), value.as_str()).into()),
+            }
+        }
+    }
+
+    /// Write the kept elements placed after the schema element `after` and
+    /// before the schema element `before`. An unknown `before` means the end
+    /// of the root element.
+    pub(crate) fn write_elements(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        schema: &[&str],
+        after: &str,
+        before: &str,
+    ) {
+        let start = schema.iter().position(|v| *v == after).unwrap_or(0);
+        let end = schema
+            .iter()
+            .position(|v| *v == before)
+            .unwrap_or(usize::MAX);
+        for (position, xml) in self.elements() {
+            if (start..end).contains(position) {
+                write_text_node_no_escape(writer, xml.as_str());
+            }
+        }
+    }
+
+    /// Write the kept `ext` entries.
+    pub(crate) fn write_extensions(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        for xml in self.extensions() {
+            write_text_node_no_escape(writer, xml.as_str());
+        }
+    }
+
+    /// Write `extLst` with the kept `ext` entries when there are any.
+    pub(crate) fn write_ext_lst(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        if self.has_extensions() {
+            write_start_tag(writer, "extLst", vec![], false);
+            self.write_extensions(writer);
+            write_end_tag(writer, "extLst");
+        }
+    }
+
+    /// Write the kept parts and their `Relationship` elements.
+    pub(crate) fn write_parts<W: io::Seek + io::Write>(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        writer_mng: &mut WriterManager<W>,
+    ) -> Result<(), XlsxError> {
+        for part in self.parts() {
+            part.write_to(writer, writer_mng)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,129 @@
+use std::fmt;
+
+/// Resource limits applied while reading a file.
+/// Every limit is unset by default.
+/// # Examples
+/// ```
+/// let mut limits = umya_spreadsheet::ReadLimits::default();
+/// limits
+///     .set_max_part_size(64 * 1024 * 1024)
+///     .set_max_total_size(256 * 1024 * 1024)
+///     .set_max_cells(1_000_000)
+///     .set_max_xml_depth(64);
+/// let file = std::fs::File::open("./tests/test_files/aaa.xlsx").unwrap();
+/// let book = umya_spreadsheet::reader::xlsx::read_reader_with_limits(file, true, &limits);
+/// assert!(book.is_ok());
+/// ```
+#[derive(Clone, Default, Debug, Eq, PartialEq)]
+pub struct ReadLimits {
+    max_part_size:      Option<u64>,
+    max_total_size:     Option<u64>,
+    max_cells:          Option<usize>,
+    max_shared_strings: Option<usize>,
+    max_sheets:         Option<usize>,
+    max_xml_depth:      Option<usize>,
+}
+impl ReadLimits {
+    /// Maximum decompressed size in bytes of a part of the package.
+    #[inline]
+    #[must_use]
+    pub fn max_part_size(&self) -> Option<u64> {
+        self.max_part_size
+    }
+
+    #[inline]
+    pub fn set_max_part_size(&mut self, value: u64) -> &mut Self {
+        self.max_part_size = Some(value);
+        self
+    }
+
+    /// Maximum decompressed size in bytes of all parts of the package.
+    #[inline]
+    #[must_use]
+    pub fn max_total_size(&self) -> Option<u64> {
+        self.max_total_size
+    }
+
+    #[inline]
+    pub fn set_max_total_size(&mut self, value: u64) -> &mut Self {
+        self.max_total_size = Some(value);
+        self
+    }
+
+    /// Maximum number of cells in a worksheet.
+    #[inline]
+    #[must_use]
+    pub fn max_cells(&self) -> Option<usize> {
+        self.max_cells
+    }
+
+    #[inline]
+    pub fn set_max_cells(&mut self, value: usize) -> &mut Self {
+        self.max_cells = Some(value);
+        self
+    }
+
+    /// Maximum number of items in the shared string table.
+    #[inline]
+    #[must_use]
+    pub fn max_shared_strings(&self) -> Option<usize> {
+        self.max_shared_strings
+    }
+
+    #[inline]
+    pub fn set_max_shared_strings(&mut self, value: usize) -> &mut Self {
+        self.max_shared_strings = Some(value);
+        self
+    }
+
+    /// Maximum number of sheets in the workbook.
+    #[inline]
+    #[must_use]
+    pub fn max_sheets(&self) -> Option<usize> {
+        self.max_sheets
+    }
+
+    #[inline]
+    pub fn set_max_sheets(&mut self, value: usize) -> &mut Self {
+        self.max_sheets = Some(value);
+        self
+    }
+
+    /// Maximum nesting depth of elements in an XML part.
+    #[inline]
+    #[must_use]
+    pub fn max_xml_depth(&self) -> Option<usize> {
+        self.max_xml_depth
+    }
+
+    #[inline]
+    pub fn set_max_xml_depth(&mut self, value: usize) -> &mut Self {
+        self.max_xml_depth = Some(value);
+        self
+    }
+}
+
+/// The limit of [`ReadLimits`] a file went over.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReadLimit {
+    PartSize,
+    TotalSize,
+    Cells,
+    SharedStrings,
+    Sheets,
+    XmlDepth,
+}
+
+impl fmt::Display for ReadLimit {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::PartSize => write!(f, "part size"),
+            Self::TotalSize => write!(f, "total size"),
+            Self::Cells => write!(f, "cell count"),
+            Self::SharedStrings => write!(f, "shared string count"),
+            Self::Sheets => write!(f, "sheet count"),
+            Self::XmlDepth => write!(f, "XML depth"),
+        }
+    }
+}
@@ -17,17 +17,21 @@ use super::{
     Cell,
     Cells,
     DoubleValue,
+    ReadLimit,
     SharedStringTable,
     Style,
     Stylesheet,
     UInt32Value,
 };
 use crate::{
+    XlsxError,
     helper::formula::FormulaToken,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        try_set_string_from_xml,
         xml_read_loop,
+        parse_value,
     },
     traits::AdjustmentValue,
     writer::driver::write_start_tag,
@@ -208,6 +212,9 @@ impl Row {
         *self.style != Style::default()
     }
 
+    /// Read the row and add its cells to `cells`.
+    /// `max_cells` is the most cells `cells` may hold, with the part name
+    /// reported when a cell would go over it.
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
@@ -217,27 +224,30 @@ impl Row {
         shared_string_table: &SharedStringTable,
         stylesheet: &Stylesheet,
         formula_shared_list: &mut HashMap<u32, (String, Vec<FormulaToken>)>,
+        max_cells: Option<(usize, &str)>,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, row_num, "r");
-        set_string_from_xml!(self, e, height, "ht");
+        try_set_string_from_xml!(self, reader, e, height, "ht");
         set_string_from_xml!(self, e, thick_bot, "thickBot");
         set_string_from_xml!(self, e, custom_height, "customHeight");
         set_string_from_xml!(self, e, hidden, "hidden");
 
         if let Some(v) = get_attribute(e, b"x14ac:dyDescent") {
             if !v.is_empty() {
-                self.descent.set_value_string(v);
+                self.descent
+                    .set_value_string(v)
+                    .map_err(|error| error.at(reader.buffer_position()))?;
             }
         }
 
         if let Some(v) = get_attribute(e, b"s") {
-            let style = stylesheet.style(v.parse::<usize>().unwrap());
+            let style = stylesheet.style(parse_value(reader, &v)?);
             self.set_style(style);
         }
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -252,8 +262,8 @@ impl Row {
                         stylesheet,
                         true,
                         formula_shared_list
-                    );
-                    cells.set_fast(obj);
+                    )?;
+                    add_cell(cells, obj, max_cells)?;
                 }
             },
             Event::Start(ref e) => {
@@ -266,16 +276,16 @@ impl Row {
                         stylesheet,
                         false,
                         formula_shared_list
-                    );
-                    cells.set_fast(obj);
+                    )?;
+                    add_cell(cells, obj, max_cells)?;
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"row" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "row")
+            Event::Eof => return Err(XlsxError::missing_end_element("row", reader.buffer_position()))
         );
     }
 
@@ -347,3 +357,14 @@ impl AdjustmentValue for Row {
         self.row_num.value() >= root_num && self.row_num.value() < root_num + offset_num
     }
 }
+
+fn add_cell(cells: &mut Cells, cell: Cell, max_cells: Option<(usize, &str)>) -> Result<(), XlsxError> {
+    if let Some((max, part)) = max_cells {
+        let coordinate = cell.coordinate();
+        if cells.exceeds(coordinate.col_num(), coordinate.row_num(), max) {
+            return Err(XlsxError::LimitExceeded(ReadLimit::Cells, part.to_string()));
+        }
+    }
+    cells.set_fast(cell);
+    Ok(())
+}
@@ -11,6 +11,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     structs::Break,
     writer::driver::{
@@ -64,7 +65,7 @@ impl RowBreaks {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"brk" {
@@ -75,10 +76,10 @@ impl RowBreaks {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"rowBreaks" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "rowBreaks")
+            Event::Eof => return Err(XlsxError::missing_end_element("rowBreaks", reader.buffer_position()))
         );
     }
 
@@ -11,7 +11,7 @@ use quick_xml::{
 };
 
 use crate::{
-    Field, reader::driver::xml_read_loop, writer::driver::{
+    Field, XlsxError, reader::driver::xml_read_loop, writer::driver::{
         write_end_tag,
         write_start_tag,
     }
@@ -57,7 +57,7 @@ impl RowFields {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
@@ -69,10 +69,10 @@ impl RowFields {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"rowFields" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "rowFields")
+            Event::Eof => return Err(XlsxError::missing_end_element("rowFields", reader.buffer_position()))
         );
     }
 
@@ -11,6 +11,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -129,13 +130,13 @@ impl RowItem {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, index, "i");
         set_string_from_xml!(self, e, item_type, "t");
         set_string_from_xml!(self, e, repeated_item_count, "r");
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -149,10 +150,10 @@ impl RowItem {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"i" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "i")
+            Event::Eof => return Err(XlsxError::missing_end_element("i", reader.buffer_position()))
         );
     }
 
@@ -33,7 +33,10 @@ impl SByteValue {
 
     #[inline]
     pub(crate) fn set_value_string<S: Into<String>>(&mut self, value: S) -> &mut SByteValue {
-        self.set_value(value.into().parse::<i8>().unwrap())
+        if let Ok(v) = value.into().parse::<i8>() {
+            self.set_value(v);
+        }
+        self
     }
 
     #[inline]
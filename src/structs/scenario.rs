@@ -0,0 +1,192 @@
+// scenario
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use super::InputCells;
+use crate::{
+    XlsxError,
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+        xml_read_loop,
+    },
+    structs::{
+        BooleanValue,
+        StringValue,
+    },
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+    },
+};
+
+/// A named set of values for the changing cells of a sheet, as kept by the
+/// Scenario Manager.
+#[derive(Clone, Default, Debug)]
+pub struct Scenario {
+    name:        StringValue,
+    locked:      BooleanValue,
+    hidden:      BooleanValue,
+    user:        StringValue,
+    comment:     StringValue,
+    input_cells: Vec<InputCells>,
+}
+
+impl Scenario {
+    #[inline]
+    #[must_use]
+    pub fn name(&self) -> &str {
+        self.name.value_str()
+    }
+
+    #[inline]
+    pub fn set_name<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.name.set_value(value);
+        self
+    }
+
+    /// Whether the scenario can not be changed when the sheet is protected.
+    #[inline]
+    #[must_use]
+    pub fn locked(&self) -> bool {
+        self.locked.value()
+    }
+
+    #[inline]
+    pub fn set_locked(&mut self, value: bool) -> &mut Self {
+        self.locked.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn hidden(&self) -> bool {
+        self.hidden.value()
+    }
+
+    #[inline]
+    pub fn set_hidden(&mut self, value: bool) -> &mut Self {
+        self.hidden.set_value(value);
+        self
+    }
+
+    /// Get the name of the user who last changed the scenario.
+    #[inline]
+    #[must_use]
+    pub fn user(&self) -> &str {
+        self.user.value_str()
+    }
+
+    #[inline]
+    pub fn set_user<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.user.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn comment(&self) -> &str {
+        self.comment.value_str()
+    }
+
+    #[inline]
+    pub fn set_comment<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.comment.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn input_cells(&self) -> &[InputCells] {
+        &self.input_cells
+    }
+
+    #[inline]
+    pub fn input_cells_mut(&mut self) -> &mut Vec<InputCells> {
+        &mut self.input_cells
+    }
+
+    /// Add a changing cell with its value.
+    /// # Arguments
+    /// * `reference` - ex) "B1"
+    /// * `value` - ex) "100"
+    #[inline]
+    pub fn add_input_cell<S: Into<String>, T: Into<String>>(
+        &mut self,
+        reference: S,
+        value: T,
+    ) -> &mut Self {
+        let mut obj = InputCells::default();
+        obj.set_reference(reference).set_value(value);
+        self.input_cells.push(obj);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, name, "name");
+        set_string_from_xml!(self, e, locked, "locked");
+        set_string_from_xml!(self, e, hidden, "hidden");
+        set_string_from_xml!(self, e, user, "user");
+        set_string_from_xml!(self, e, comment, "comment");
+        if empty_flag {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"inputCells" {
+                    let mut obj = InputCells::default();
+                    obj.set_attributes(e);
+                    self.input_cells.push(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"scenario" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(XlsxError::missing_end_element("scenario", reader.buffer_position()))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // scenario
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        attributes.push(("name", self.name.value_str()).into());
+        if self.locked.has_value() {
+            attributes.push(("locked", self.locked.value_string()).into());
+        }
+        if self.hidden.has_value() {
+            attributes.push(("hidden", self.hidden.value_string()).into());
+        }
+        let count = self.input_cells.len().to_string();
+        attributes.push(("count", &count).into());
+        if self.user.has_value() {
+            attributes.push(("user", self.user.value_str()).into());
+        }
+        if self.comment.has_value() {
+            attributes.push(("comment", self.comment.value_str()).into());
+        }
+        write_start_tag(writer, "scenario", attributes, false);
+
+        for obj in &self.input_cells {
+            obj.write_to(writer);
+        }
+
+        write_end_tag(writer, "scenario");
+    }
+}
@@ -0,0 +1,171 @@
+// scenarios
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use super::Scenario;
+use crate::{
+    XlsxError,
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+        xml_read_loop,
+    },
+    structs::{
+        StringValue,
+        UInt32Value,
+    },
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+    },
+};
+
+/// The scenarios of a sheet, as kept by the Scenario Manager.
+#[derive(Clone, Default, Debug)]
+pub struct Scenarios {
+    current:       UInt32Value,
+    show:          UInt32Value,
+    sqref:         StringValue,
+    scenario_list: Vec<Scenario>,
+}
+
+impl Scenarios {
+    /// Get the index of the scenario last applied.
+    #[inline]
+    #[must_use]
+    pub fn current(&self) -> Option<u32> {
+        self.current.has_value().then(|| self.current.value())
+    }
+
+    #[inline]
+    pub fn set_current(&mut self, value: u32) -> &mut Self {
+        self.current.set_value(value);
+        self
+    }
+
+    /// Get the index of the scenario selected in the Scenario Manager.
+    #[inline]
+    #[must_use]
+    pub fn show(&self) -> Option<u32> {
+        self.show.has_value().then(|| self.show.value())
+    }
+
+    #[inline]
+    pub fn set_show(&mut self, value: u32) -> &mut Self {
+        self.show.set_value(value);
+        self
+    }
+
+    /// Get the result cells of the scenario summary. ex) "B5 C7"
+    #[inline]
+    #[must_use]
+    pub fn sqref(&self) -> &str {
+        self.sqref.value_str()
+    }
+
+    #[inline]
+    pub fn set_sqref<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.sqref.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn scenario_list(&self) -> &[Scenario] {
+        &self.scenario_list
+    }
+
+    #[inline]
+    pub fn scenario_list_mut(&mut self) -> &mut Vec<Scenario> {
+        &mut self.scenario_list
+    }
+
+    #[inline]
+    pub fn add_scenario(&mut self, value: Scenario) -> &mut Self {
+        self.scenario_list.push(value);
+        self
+    }
+
+    /// Get a scenario by name. Names compare case-insensitively, as in
+    /// Excel.
+    #[inline]
+    #[must_use]
+    pub fn scenario(&self, name: &str) -> Option<&Scenario> {
+        self.scenario_list
+            .iter()
+            .find(|x| x.name().eq_ignore_ascii_case(name))
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        empty_flag: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, current, "current");
+        set_string_from_xml!(self, e, show, "show");
+        set_string_from_xml!(self, e, sqref, "sqref");
+        if empty_flag {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"scenario" {
+                    let mut obj = Scenario::default();
+                    obj.set_attributes(reader, e, true)?;
+                    self.add_scenario(obj);
+                }
+            },
+            Event::Start(ref e) => {
+                if e.name().into_inner() == b"scenario" {
+                    let mut obj = Scenario::default();
+                    obj.set_attributes(reader, e, false)?;
+                    self.add_scenario(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"scenarios" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(XlsxError::missing_end_element("scenarios", reader.buffer_position()))
+        );
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        if self.scenario_list.is_empty() {
+            return;
+        }
+
+        // scenarios
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        let current = self.current.value_string();
+        if self.current.has_value() {
+            attributes.push(("current", &current).into());
+        }
+        let show = self.show.value_string();
+        if self.show.has_value() {
+            attributes.push(("show", &show).into());
+        }
+        if self.sqref.has_value() {
+            attributes.push(("sqref", self.sqref.value_str()).into());
+        }
+        write_start_tag(writer, "scenarios", attributes, false);
+
+        for obj in &self.scenario_list {
+            obj.write_to(writer);
+        }
+
+        write_end_tag(writer, "scenarios");
+    }
+}
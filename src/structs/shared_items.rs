@@ -16,9 +16,11 @@ use quick_xml::{
 
 use crate::{
     CellErrorType,
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        try_set_string_from_xml,
     },
     structs::{
         BooleanValue,
@@ -203,16 +205,16 @@ impl SharedItems {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flg: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, contains_semi_mixed_types, "containsSemiMixedTypes");
         set_string_from_xml!(self, e, contains_string, "containsString");
         set_string_from_xml!(self, e, contains_number, "containsNumber");
         set_string_from_xml!(self, e, contains_integer, "containsInteger");
-        set_string_from_xml!(self, e, min_value, "minValue");
-        set_string_from_xml!(self, e, max_value, "maxValue");
+        try_set_string_from_xml!(self, reader, e, min_value, "minValue");
+        try_set_string_from_xml!(self, reader, e, max_value, "maxValue");
 
         if empty_flg {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -262,10 +264,10 @@ impl SharedItems {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"sharedItems" {
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "sharedItems")
+            Event::Eof => return Err(XlsxError::missing_end_element("sharedItems", reader.buffer_position()))
         );
     }
 
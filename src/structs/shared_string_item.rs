@@ -23,6 +23,7 @@ use super::{
     TextElement,
 };
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -131,7 +132,7 @@ impl SharedStringItem {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut vec_text_element: Vec<TextElement> = Vec::new();
 
         xml_read_loop!(
@@ -140,16 +141,16 @@ impl SharedStringItem {
                 match e.name().into_inner() {
                     b"t" => {
                         let mut obj = Text::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_text(obj);
                     }
                     b"r" => {
                         let mut obj = TextElement::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         vec_text_element.push(obj);
                     }
                     b"rPh" => {
-                        PhoneticRun::set_attributes(reader, e);
+                        PhoneticRun::set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
@@ -161,10 +162,10 @@ impl SharedStringItem {
                         obj.set_rich_text_elements(vec_text_element);
                         self.set_rich_text(obj);
                     }
-                    return;
+                    return Ok(());
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "si")
+            Event::Eof => return Err(XlsxError::missing_end_element("si", reader.buffer_position()))
         );
     }
 
@@ -15,10 +15,15 @@ use quick_xml::{
 
 use super::{
     CellValue,
+    ReadLimit,
     SharedStringItem,
 };
 use crate::{
-    helper::const_str::SHEET_MAIN_NS,
+    XlsxError,
+    helper::const_str::{
+        PKG_SHARED_STRINGS,
+        SHEET_MAIN_NS,
+    },
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -97,14 +102,21 @@ impl SharedStringTable {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+        max_count: Option<usize>,
+    ) -> Result<(), XlsxError> {
         let mut n: usize = 0;
         xml_read_loop!(
             reader,
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"si" {
+                    if max_count.is_some_and(|max| n >= max) {
+                        return Err(XlsxError::LimitExceeded(
+                            ReadLimit::SharedStrings,
+                            PKG_SHARED_STRINGS.to_string(),
+                        ));
+                    }
                     let mut shared_string_item = SharedStringItem::default();
-                    shared_string_item.set_attributes(reader, e);
+                    shared_string_item.set_attributes(reader, e)?;
 
                     let hash_code = shared_string_item.hash_u64();
                     self.map.insert(hash_code, n);
@@ -115,10 +127,10 @@ impl SharedStringTable {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"sst" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "sst")
+            Event::Eof => return Err(XlsxError::missing_end_element("sst", reader.buffer_position()))
         );
     }
 
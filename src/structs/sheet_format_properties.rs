@@ -14,9 +14,11 @@ use super::{
     UInt32Value,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
+        try_set_string_from_xml,
     },
     writer::driver::write_start_tag,
 };
@@ -215,18 +217,19 @@ impl SheetFormatProperties {
 
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
-        _reader: &mut Reader<R>,
+        reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, base_column_width, "baseColWidth");
         set_string_from_xml!(self, e, custom_height, "customHeight");
-        set_string_from_xml!(self, e, default_column_width, "defaultColWidth");
-        set_string_from_xml!(self, e, default_row_height, "defaultRowHeight");
-        set_string_from_xml!(self, e, dy_descent, "x14ac:dyDescent");
+        try_set_string_from_xml!(self, reader, e, default_column_width, "defaultColWidth");
+        try_set_string_from_xml!(self, reader, e, default_row_height, "defaultRowHeight");
+        try_set_string_from_xml!(self, reader, e, dy_descent, "x14ac:dyDescent");
         set_string_from_xml!(self, e, outline_level_column, "outlineLevelCol");
         set_string_from_xml!(self, e, outline_level_row, "outlineLevelRow");
         set_string_from_xml!(self, e, thick_bottom, "thickBottom");
         set_string_from_xml!(self, e, thick_top, "thickTop");
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
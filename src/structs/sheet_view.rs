@@ -20,6 +20,7 @@ use super::{
     UInt32Value,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -283,7 +284,7 @@ impl SheetView {
         reader: &mut Reader<R>,
         e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, show_grid_lines, "showGridLines");
         set_string_from_xml!(self, e, tab_selected, "tabSelected");
         set_string_from_xml!(self, e, workbook_view_id, "workbookViewId");
@@ -305,7 +306,7 @@ impl SheetView {
         set_string_from_xml!(self, e, top_left_cell, "topLeftCell");
 
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -314,7 +315,7 @@ impl SheetView {
                 match e.name().into_inner() {
                     b"pane" => {
                         let mut obj = Pane::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_pane(obj);
                     }
                     b"selection" => {
@@ -327,10 +328,10 @@ impl SheetView {
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"sheetView" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "sheetView")
+            Event::Eof => return Err(XlsxError::missing_end_element("sheetView", reader.buffer_position()))
         );
     }
 
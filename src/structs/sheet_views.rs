@@ -12,6 +12,7 @@ use quick_xml::{
 
 use super::SheetView;
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -59,29 +60,29 @@ impl SheetViews {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Empty(ref e) => {
                 if e.name().into_inner() == b"sheetView" {
                     let mut obj = SheetView::default();
-                    obj.set_attributes(reader, e, true);
+                    obj.set_attributes(reader, e, true)?;
                     self.add_sheet_view_list_mut(obj);
                 }
             },
             Event::Start(ref e) => {
                 if e.name().into_inner() == b"sheetView" {
                     let mut obj = SheetView::default();
-                    obj.set_attributes(reader, e, false);
+                    obj.set_attributes(reader, e, false)?;
                     self.add_sheet_view_list_mut(obj);
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"sheetViews" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "sheetViews")
+            Event::Eof => return Err(XlsxError::missing_end_element("sheetViews", reader.buffer_position()))
         );
     }
 
@@ -0,0 +1,197 @@
+// sortCondition
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::BytesStart,
+};
+
+use super::{
+    BooleanValue,
+    DifferentialFormats,
+    EnumValue,
+    Range,
+    SortByValues,
+    StringValue,
+    Style,
+};
+use crate::{
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+    },
+    traits::AdjustmentCoordinate,
+    writer::driver::write_start_tag,
+};
+
+#[derive(Clone, Default, Debug)]
+pub struct SortCondition {
+    descending:     BooleanValue,
+    sort_by:        EnumValue<SortByValues>,
+    sort_reference: Range,
+    custom_list:    StringValue,
+    style:          Option<Box<Style>>,
+}
+
+impl SortCondition {
+    /// Create a condition sorting the given column range.
+    /// # Arguments
+    /// * `range` - ex) "B2:B10"
+    /// * `descending` - Sort in descending order.
+    #[inline]
+    #[must_use]
+    pub fn new<S: Into<String>>(range: S, descending: bool) -> Self {
+        let mut obj = Self::default();
+        obj.set_sort_reference(range);
+        if descending {
+            obj.set_descending(true);
+        }
+        obj
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn descending(&self) -> bool {
+        self.descending.value()
+    }
+
+    #[inline]
+    pub fn set_descending(&mut self, value: bool) -> &mut Self {
+        self.descending.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn sort_by(&self) -> &SortByValues {
+        self.sort_by.value()
+    }
+
+    #[inline]
+    pub fn set_sort_by(&mut self, value: SortByValues) -> &mut Self {
+        self.sort_by.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn sort_reference(&self) -> &Range {
+        &self.sort_reference
+    }
+
+    #[inline]
+    pub fn sort_reference_mut(&mut self) -> &mut Range {
+        &mut self.sort_reference
+    }
+
+    #[inline]
+    pub fn set_sort_reference<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.sort_reference.set_range(value);
+        self
+    }
+
+    /// Get the custom list order. ex) "Low,Medium,High"
+    #[inline]
+    #[must_use]
+    pub fn custom_list(&self) -> &str {
+        self.custom_list.value_str()
+    }
+
+    #[inline]
+    pub fn set_custom_list<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.custom_list.set_value(value);
+        self
+    }
+
+    /// Get the format holding the sorted color, when sorting by color.
+    #[inline]
+    #[must_use]
+    pub fn style(&self) -> Option<&Style> {
+        self.style.as_deref()
+    }
+
+    #[inline]
+    pub fn set_style(&mut self, value: Style) -> &mut Self {
+        self.style = Some(Box::new(value));
+        self
+    }
+
+    #[inline]
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        _reader: &mut Reader<R>,
+        e: &BytesStart,
+        differential_formats: &DifferentialFormats,
+    ) {
+        set_string_from_xml!(self, e, descending, "descending");
+        set_string_from_xml!(self, e, sort_by, "sortBy");
+        if let Some(v) = get_attribute(e, b"ref") {
+            self.sort_reference.set_range(v);
+        }
+        set_string_from_xml!(self, e, custom_list, "customList");
+        if let Some(v) = get_attribute(e, b"dxfId") {
+            if let Ok(dxf_id) = v.parse::<usize>() {
+                self.set_style(differential_formats.style(dxf_id));
+            }
+        }
+    }
+
+    pub(crate) fn write_to(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        differential_formats: &mut DifferentialFormats,
+    ) {
+        // sortCondition
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        if self.descending.has_value() {
+            attributes.push(("descending", self.descending.value_string()).into());
+        }
+        if self.sort_by.has_value() {
+            attributes.push(("sortBy", self.sort_by.value_string()).into());
+        }
+        attributes.push(("ref", self.sort_reference.range()).into());
+        if self.custom_list.has_value() {
+            attributes.push(("customList", self.custom_list.value_str()).into());
+        }
+        let dxf_id_str: String;
+        if let Some(v) = &self.style {
+            dxf_id_str = differential_formats.set_style(v).to_string();
+            attributes.push(("dxfId", &dxf_id_str).into());
+        }
+        write_start_tag(writer, "sortCondition", attributes, true);
+    }
+}
+impl AdjustmentCoordinate for SortCondition {
+    #[inline]
+    fn adjustment_insert_coordinate(
+        &mut self,
+        root_col_num: u32,
+        offset_col_num: u32,
+        root_row_num: u32,
+        offset_row_num: u32,
+    ) {
+        self.sort_reference.adjustment_insert_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+    }
+
+    #[inline]
+    fn adjustment_remove_coordinate(
+        &mut self,
+        root_col_num: u32,
+        offset_col_num: u32,
+        root_row_num: u32,
+        offset_row_num: u32,
+    ) {
+        self.sort_reference.adjustment_remove_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+    }
+}
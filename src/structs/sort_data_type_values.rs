@@ -0,0 +1,38 @@
+use std::str::FromStr;
+
+use super::EnumTrait;
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum SortDataTypeValues {
+    Auto,
+    Number,
+    Text,
+}
+impl Default for SortDataTypeValues {
+    #[inline]
+    fn default() -> Self {
+        Self::Auto
+    }
+}
+impl EnumTrait for SortDataTypeValues {
+    #[inline]
+    fn value_string(&self) -> &str {
+        match &self {
+            Self::Auto => "auto",
+            Self::Number => "number",
+            Self::Text => "text",
+        }
+    }
+}
+impl FromStr for SortDataTypeValues {
+    type Err = ();
+
+    #[inline]
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "auto" => Ok(Self::Auto),
+            "number" => Ok(Self::Number),
+            "text" => Ok(Self::Text),
+            _ => Err(()),
+        }
+    }
+}
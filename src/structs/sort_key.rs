@@ -0,0 +1,244 @@
+use std::cmp::Ordering;
+
+use super::{
+    CellRawValue,
+    SortDataTypeValues,
+};
+use crate::helper::coordinate::column_index_from_string;
+
+/// A key used by `Worksheet::sort_range`.
+///
+/// Blank cells are always placed last, regardless of the sort direction.
+/// In ascending order numbers come first, followed by text, logical values
+/// and errors, as in Excel.
+#[derive(Clone, Default, Debug)]
+pub struct SortKey {
+    column_index:   u32,
+    descending:     bool,
+    data_type:      SortDataTypeValues,
+    case_sensitive: bool,
+    custom_list:    Vec<String>,
+}
+
+#[derive(Debug)]
+enum SortValue {
+    Number(f64),
+    Text(String),
+    Bool(bool),
+    Error(String),
+    Blank,
+}
+
+impl SortValue {
+    #[inline]
+    fn rank(&self) -> u8 {
+        match self {
+            Self::Number(_) => 0,
+            Self::Text(_) => 1,
+            Self::Bool(_) => 2,
+            Self::Error(_) => 3,
+            Self::Blank => 4,
+        }
+    }
+
+    #[inline]
+    fn text(&self) -> String {
+        match self {
+            Self::Number(v) => v.to_string(),
+            Self::Text(v) | Self::Error(v) => v.clone(),
+            Self::Bool(v) => if *v { "TRUE" } else { "FALSE" }.to_string(),
+            Self::Blank => String::new(),
+        }
+    }
+}
+
+impl SortKey {
+    /// Create a key sorting by the given column.
+    /// # Arguments
+    /// * `column` - ex) "B"
+    #[inline]
+    #[must_use]
+    pub fn new(column: &str) -> Self {
+        Self::new_by_index(column_index_from_string(column))
+    }
+
+    /// Create a key sorting by the given column index.
+    /// # Arguments
+    /// * `column_index` - ex) 2
+    #[inline]
+    #[must_use]
+    pub fn new_by_index(column_index: u32) -> Self {
+        Self {
+            column_index,
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn column_index(&self) -> u32 {
+        self.column_index
+    }
+
+    #[inline]
+    pub fn set_column_index(&mut self, value: u32) -> &mut Self {
+        self.column_index = value;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn descending(&self) -> bool {
+        self.descending
+    }
+
+    #[inline]
+    pub fn set_descending(&mut self, value: bool) -> &mut Self {
+        self.descending = value;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn data_type(&self) -> SortDataTypeValues {
+        self.data_type
+    }
+
+    /// Set how values are compared.
+    /// `Number` treats numeric text as numbers, `Text` compares numbers by
+    /// their text representation.
+    #[inline]
+    pub fn set_data_type(&mut self, value: SortDataTypeValues) -> &mut Self {
+        self.data_type = value;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn case_sensitive(&self) -> bool {
+        self.case_sensitive
+    }
+
+    /// Set case sensitivity of text comparison.
+    /// When enabled, lowercase text is placed before uppercase text.
+    #[inline]
+    pub fn set_case_sensitive(&mut self, value: bool) -> &mut Self {
+        self.case_sensitive = value;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn custom_list(&self) -> &[String] {
+        &self.custom_list
+    }
+
+    /// Set a custom sort order.
+    /// Values found in the list are sorted by their position in it,
+    /// other values follow in the normal order.
+    /// # Arguments
+    /// * `value` - ex) `["Low", "Medium", "High"]`
+    #[inline]
+    pub fn set_custom_list<I, S>(&mut self, value: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.custom_list = value.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Compare two cell values with this key.
+    pub(crate) fn compare(&self, a: Option<&CellRawValue>, b: Option<&CellRawValue>) -> Ordering {
+        let a = self.sort_value(a);
+        let b = self.sort_value(b);
+        match (&a, &b) {
+            (SortValue::Blank, SortValue::Blank) => Ordering::Equal,
+            (SortValue::Blank, _) => Ordering::Greater,
+            (_, SortValue::Blank) => Ordering::Less,
+            _ => {
+                let ordering = self.compare_values(&a, &b);
+                if self.descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            }
+        }
+    }
+
+    fn compare_values(&self, a: &SortValue, b: &SortValue) -> Ordering {
+        if !self.custom_list.is_empty() {
+            match (self.custom_list_position(a), self.custom_list_position(b)) {
+                (Some(x), Some(y)) => return x.cmp(&y),
+                (Some(_), None) => return Ordering::Less,
+                (None, Some(_)) => return Ordering::Greater,
+                (None, None) => {}
+            }
+        }
+        match (a, b) {
+            (SortValue::Number(x), SortValue::Number(y)) => {
+                x.partial_cmp(y).unwrap_or(Ordering::Equal)
+            }
+            (SortValue::Text(x), SortValue::Text(y)) => self.compare_text(x, y),
+            (SortValue::Bool(x), SortValue::Bool(y)) => x.cmp(y),
+            (SortValue::Error(x), SortValue::Error(y)) => x.cmp(y),
+            _ => a.rank().cmp(&b.rank()),
+        }
+    }
+
+    fn compare_text(&self, a: &str, b: &str) -> Ordering {
+        let ordering = a.to_lowercase().cmp(&b.to_lowercase());
+        if ordering == Ordering::Equal && self.case_sensitive {
+            return swap_case(a).cmp(&swap_case(b));
+        }
+        ordering
+    }
+
+    fn custom_list_position(&self, value: &SortValue) -> Option<usize> {
+        let text = value.text().to_lowercase();
+        self.custom_list
+            .iter()
+            .position(|v| v.to_lowercase() == text)
+    }
+
+    fn sort_value(&self, value: Option<&CellRawValue>) -> SortValue {
+        let value = match value {
+            Some(CellRawValue::Numeric(v)) => SortValue::Number(*v),
+            Some(v @ (CellRawValue::String(_) | CellRawValue::RichText(_))) => {
+                SortValue::Text(v.to_string())
+            }
+            Some(CellRawValue::Lazy(v)) => match v.parse::<f64>() {
+                Ok(n) => SortValue::Number(n),
+                Err(_) => SortValue::Text(v.to_string()),
+            },
+            Some(CellRawValue::Bool(v)) => SortValue::Bool(*v),
+            Some(CellRawValue::Error(v)) => SortValue::Error(v.to_string()),
+            Some(CellRawValue::Empty) | None => SortValue::Blank,
+        };
+        match (self.data_type, value) {
+            (_, SortValue::Text(v)) if v.is_empty() => SortValue::Blank,
+            (SortDataTypeValues::Number, SortValue::Text(v)) => match v.trim().parse::<f64>() {
+                Ok(n) => SortValue::Number(n),
+                Err(_) => SortValue::Text(v),
+            },
+            (SortDataTypeValues::Text, v @ (SortValue::Number(_) | SortValue::Bool(_))) => {
+                SortValue::Text(v.text())
+            }
+            (_, v) => v,
+        }
+    }
+}
+
+fn swap_case(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| {
+            if c.is_lowercase() {
+                c.to_uppercase().next().unwrap_or(c)
+            } else {
+                c.to_lowercase().next().unwrap_or(c)
+            }
+        })
+        .collect()
+}
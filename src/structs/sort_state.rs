@@ -0,0 +1,218 @@
+// sortState
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::{
+        BytesStart,
+        Event,
+    },
+};
+
+use super::{
+    BooleanValue,
+    DifferentialFormats,
+    Range,
+    SortCondition,
+};
+use crate::{
+    XlsxError,
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+        xml_read_loop,
+    },
+    traits::AdjustmentCoordinate,
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+    },
+};
+
+#[derive(Clone, Default, Debug)]
+pub struct SortState {
+    column_sort:         BooleanValue,
+    case_sensitive:      BooleanValue,
+    sort_reference:      Range,
+    sort_condition_list: Vec<SortCondition>,
+}
+
+impl SortState {
+    /// Create a sort state for the given range, header row excluded.
+    /// # Arguments
+    /// * `range` - ex) "A2:C10"
+    #[inline]
+    #[must_use]
+    pub fn new<S: Into<String>>(range: S) -> Self {
+        let mut obj = Self::default();
+        obj.set_sort_reference(range);
+        obj
+    }
+
+    /// Whether columns are sorted (left to right) instead of rows.
+    #[inline]
+    #[must_use]
+    pub fn column_sort(&self) -> bool {
+        self.column_sort.value()
+    }
+
+    #[inline]
+    pub fn set_column_sort(&mut self, value: bool) -> &mut Self {
+        self.column_sort.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn case_sensitive(&self) -> bool {
+        self.case_sensitive.value()
+    }
+
+    #[inline]
+    pub fn set_case_sensitive(&mut self, value: bool) -> &mut Self {
+        self.case_sensitive.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn sort_reference(&self) -> &Range {
+        &self.sort_reference
+    }
+
+    #[inline]
+    pub fn sort_reference_mut(&mut self) -> &mut Range {
+        &mut self.sort_reference
+    }
+
+    #[inline]
+    pub fn set_sort_reference<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.sort_reference.set_range(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn sort_condition_list(&self) -> &[SortCondition] {
+        &self.sort_condition_list
+    }
+
+    #[inline]
+    pub fn sort_condition_list_mut(&mut self) -> &mut Vec<SortCondition> {
+        &mut self.sort_condition_list
+    }
+
+    #[inline]
+    pub fn add_sort_condition(&mut self, value: SortCondition) -> &mut Self {
+        self.sort_condition_list.push(value);
+        self
+    }
+
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+        differential_formats: &DifferentialFormats,
+        empty_flg: bool,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, column_sort, "columnSort");
+        set_string_from_xml!(self, e, case_sensitive, "caseSensitive");
+        if let Some(v) = get_attribute(e, b"ref") {
+            self.sort_reference.set_range(v);
+        }
+
+        if empty_flg {
+            return Ok(());
+        }
+
+        xml_read_loop!(
+            reader,
+            Event::Empty(ref e) => {
+                if e.name().into_inner() == b"sortCondition" {
+                    let mut obj = SortCondition::default();
+                    obj.set_attributes(reader, e, differential_formats);
+                    self.sort_condition_list.push(obj);
+                }
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == b"sortState" {
+                    return Ok(())
+                }
+            },
+            Event::Eof => return Err(XlsxError::missing_end_element("sortState", reader.buffer_position()))
+        );
+    }
+
+    pub(crate) fn write_to(
+        &self,
+        writer: &mut Writer<Cursor<Vec<u8>>>,
+        differential_formats: &mut DifferentialFormats,
+    ) {
+        // sortState
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        if self.column_sort.has_value() {
+            attributes.push(("columnSort", self.column_sort.value_string()).into());
+        }
+        if self.case_sensitive.has_value() {
+            attributes.push(("caseSensitive", self.case_sensitive.value_string()).into());
+        }
+        attributes.push(("ref", self.sort_reference.range()).into());
+        let is_empty = self.sort_condition_list.is_empty();
+        write_start_tag(writer, "sortState", attributes, is_empty);
+        if is_empty {
+            return;
+        }
+        for sort_condition in &self.sort_condition_list {
+            sort_condition.write_to(writer, differential_formats);
+        }
+        write_end_tag(writer, "sortState");
+    }
+}
+impl AdjustmentCoordinate for SortState {
+    fn adjustment_insert_coordinate(
+        &mut self,
+        root_col_num: u32,
+        offset_col_num: u32,
+        root_row_num: u32,
+        offset_row_num: u32,
+    ) {
+        self.sort_reference.adjustment_insert_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+        for sort_condition in &mut self.sort_condition_list {
+            sort_condition.adjustment_insert_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    fn adjustment_remove_coordinate(
+        &mut self,
+        root_col_num: u32,
+        offset_col_num: u32,
+        root_row_num: u32,
+        offset_row_num: u32,
+    ) {
+        self.sort_reference.adjustment_remove_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+        for sort_condition in &mut self.sort_condition_list {
+            sort_condition.adjustment_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+}
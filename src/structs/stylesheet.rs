@@ -24,6 +24,7 @@ use super::{
     Style,
 };
 use crate::{
+    XlsxError,
     helper::const_str::{
         MC_NS,
         SHEET_MAIN_NS,
@@ -523,7 +524,7 @@ impl Stylesheet {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         self.numbering_formats.build_in_formats();
 
         xml_read_loop!(
@@ -531,41 +532,41 @@ impl Stylesheet {
             Event::Start(ref e) => {
                 match e.name().into_inner() {
                     b"numFmts" => {
-                        self.numbering_formats.set_attributes(reader, e);
+                        self.numbering_formats.set_attributes(reader, e)?;
                     }
                     b"fonts" => {
-                        self.fonts.set_attributes(reader, e);
+                        self.fonts.set_attributes(reader, e)?;
                     }
                     b"fills" => {
-                        self.fills.set_attributes(reader, e);
+                        self.fills.set_attributes(reader, e)?;
                     }
                     b"borders" => {
-                        self.borders.set_attributes(reader, e);
+                        self.borders.set_attributes(reader, e)?;
                     }
                     b"cellStyleXfs" => {
-                        self.cell_style_formats.set_attributes(reader, e);
+                        self.cell_style_formats.set_attributes(reader, e)?;
                     }
                     b"cellXfs" => {
-                        self.cell_formats.set_attributes(reader, e);
+                        self.cell_formats.set_attributes(reader, e)?;
                     }
                     b"cellStyles" => {
-                        self.cell_styles.set_attributes(reader, e);
+                        self.cell_styles.set_attributes(reader, e)?;
                     }
                     b"dxfs" => {
-                        self.differential_formats.set_attributes(reader, e);
+                        self.differential_formats.set_attributes(reader, e)?;
                     }
                     b"colors" => {
-                        self.colors.set_attributes(reader, e);
+                        self.colors.set_attributes(reader, e)?;
                     }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"styleSheet" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "styleSheet")
+            Event::Eof => return Err(XlsxError::missing_end_element("styleSheet", reader.buffer_position()))
         );
     }
 
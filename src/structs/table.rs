@@ -1,24 +1,36 @@
 use super::{
     BooleanValue,
     EnumValue,
+    FilterColumn,
+    SortState,
     StringValue,
     TotalsRowFunctionValues,
     UInt32Value,
     coordinate::Coordinate,
 };
-use crate::helper::coordinate::CellCoordinates;
+use crate::{
+    helper::coordinate::{
+        CellCoordinates,
+        adjustment_remove_coordinate,
+        coordinate_from_index,
+        is_remove_coordinate,
+    },
+    traits::AdjustmentCoordinate,
+};
 
 // use reader::driver::*;
 
 #[derive(Clone, Default, Debug)]
 pub struct Table {
-    name:             Box<str>,
-    area:             (Coordinate, Coordinate),
-    display_name:     Box<str>,
-    columns:          Vec<TableColumn>,
-    style_info:       Option<Box<TableStyleInfo>>,
-    totals_row_shown: BooleanValue,
-    totals_row_count: UInt32Value,
+    name:               Box<str>,
+    area:               (Coordinate, Coordinate),
+    display_name:       Box<str>,
+    columns:            Vec<TableColumn>,
+    style_info:         Option<Box<TableStyleInfo>>,
+    totals_row_shown:   BooleanValue,
+    totals_row_count:   UInt32Value,
+    filter_column_list: Vec<FilterColumn>,
+    sort_state:         Option<SortState>,
 }
 impl Table {
     #[inline]
@@ -30,13 +42,15 @@ impl Table {
         let coord_end = Self::cell_coord_to_coord(area.1);
         let name: Box<str> = name.into();
         Self {
-            area:             (coord_beg, coord_end),
-            name:             name.clone(),
-            display_name:     name,
-            columns:          Vec::<TableColumn>::default(),
-            style_info:       None,
-            totals_row_shown: BooleanValue::default(),
-            totals_row_count: UInt32Value::default(),
+            area:               (coord_beg, coord_end),
+            name:               name.clone(),
+            display_name:       name,
+            columns:            Vec::<TableColumn>::default(),
+            style_info:         None,
+            totals_row_shown:   BooleanValue::default(),
+            totals_row_count:   UInt32Value::default(),
+            filter_column_list: Vec::new(),
+            sort_state:         None,
         }
     }
 
@@ -240,6 +254,224 @@ impl Table {
         self.totals_row_count.set_value_string(value);
     }
 
+    #[inline]
+    #[must_use]
+    pub fn filter_column_list(&self) -> &[FilterColumn] {
+        &self.filter_column_list
+    }
+
+    #[inline]
+    pub fn filter_column_list_mut(&mut self) -> &mut Vec<FilterColumn> {
+        &mut self.filter_column_list
+    }
+
+    /// Add a filter column, replacing the filter of the same column.
+    #[inline]
+    pub fn add_filter_column(&mut self, value: FilterColumn) {
+        self.filter_column_list
+            .retain(|x| x.column_id() != value.column_id());
+        self.filter_column_list.push(value);
+        self.filter_column_list.sort_by_key(FilterColumn::column_id);
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn sort_state(&self) -> Option<&SortState> {
+        self.sort_state.as_ref()
+    }
+
+    #[inline]
+    pub fn sort_state_mut(&mut self) -> Option<&mut SortState> {
+        self.sort_state.as_mut()
+    }
+
+    #[inline]
+    pub fn set_sort_state(&mut self, value: SortState) {
+        self.sort_state = Some(value);
+    }
+
+    #[inline]
+    pub fn remove_sort_state(&mut self) {
+        self.sort_state = None;
+    }
+
+    /// Get the range covered by the auto filter of the table.
+    /// The totals row is not included.
+    #[inline]
+    #[must_use]
+    pub fn auto_filter_range(&self) -> String {
+        format!(
+            "{}:{}",
+            coordinate_from_index(self.area.0.col_num(), self.area.0.row_num()),
+            coordinate_from_index(self.area.1.col_num(), self.data_row_range().1),
+        )
+    }
+
+    /// Get the row number of the header row.
+    #[inline]
+    #[must_use]
+    pub fn header_row_num(&self) -> u32 {
+        self.area.0.row_num()
+    }
+
+    /// Get the first and last row numbers of the data body.
+    /// The totals row is not included.
+    /// When the table has no data rows, the first number is greater than the last.
+    #[inline]
+    #[must_use]
+    pub fn data_row_range(&self) -> (u32, u32) {
+        (
+            self.area.0.row_num() + 1,
+            self.area
+                .1
+                .row_num()
+                .saturating_sub(self.totals_row_count()),
+        )
+    }
+
+    /// Get the index of the column with the given name.
+    /// Column names are compared case-insensitively, like Excel does.
+    #[inline]
+    #[must_use]
+    pub fn column_index(&self, name: &str) -> Option<usize> {
+        self.columns
+            .iter()
+            .position(|x| x.name().eq_ignore_ascii_case(name))
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn column_by_name(&self, name: &str) -> Option<&TableColumn> {
+        self.column_index(name).map(|i| &self.columns[i])
+    }
+
+    #[inline]
+    pub fn column_by_name_mut(&mut self, name: &str) -> Option<&mut TableColumn> {
+        self.column_index(name).map(|i| &mut self.columns[i])
+    }
+
+    #[inline]
+    pub(crate) fn truncate_columns(&mut self, len: usize) {
+        self.columns.truncate(len);
+    }
+
+    #[inline]
+    pub(crate) fn contains(&self, col_num: u32, row_num: u32) -> bool {
+        col_num >= self.area.0.col_num()
+            && col_num <= self.area.1.col_num()
+            && row_num >= self.area.0.row_num()
+            && row_num <= self.area.1.row_num()
+    }
+
+    /// Resolve the bracketed part of a structured reference to an A1 range.
+    /// # Arguments
+    /// * `specifier` - The part following the table name. ex) `[Amount]`,
+    ///   `[#Totals]`, `[@Qty]`, `[[#This Row],[Qty]]`, `[[Qty]:[Price]]`
+    /// * `current_row` - Row of the cell holding the reference. Required for
+    ///   `#This Row` and `@`.
+    /// # Return value
+    /// * `Option<String>` - ex) `C2:C10`. `None` if the reference cannot be
+    ///   resolved (unknown column, missing totals row, ...).
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    /// let mut table = Table::new("Sales", ("A1", "C5"));
+    /// table.add_column(TableColumn::new("Item"));
+    /// table.add_column(TableColumn::new("Qty"));
+    /// table.add_column(TableColumn::new("Amount"));
+    /// assert_eq!(table.resolve_structured_reference("[Amount]", None), Some("C2:C5".to_string()));
+    /// assert_eq!(table.resolve_structured_reference("[@Qty]", Some(3)), Some("B3".to_string()));
+    /// ```
+    #[must_use]
+    pub fn resolve_structured_reference(
+        &self,
+        specifier: &str,
+        current_row: Option<u32>,
+    ) -> Option<String> {
+        let (specials, columns) = parse_structured_specifier(specifier)?;
+
+        let header_row = self.header_row_num();
+        let (data_beg, data_end) = self.data_row_range();
+        let mut rows: Option<(u32, u32)> = None;
+        let specials = if specials.is_empty() {
+            vec!["#Data".to_string()]
+        } else {
+            specials
+        };
+        for special in &specials {
+            let (beg, end) = match special.to_ascii_lowercase().as_str() {
+                "#all" => (header_row, self.area.1.row_num()),
+                "#data" => (data_beg, data_end),
+                "#headers" => (header_row, header_row),
+                "#totals" => {
+                    if self.totals_row_count() == 0 {
+                        return None;
+                    }
+                    (data_end + 1, self.area.1.row_num())
+                }
+                "#this row" => {
+                    let row = current_row?;
+                    if row < data_beg || row > data_end {
+                        return None;
+                    }
+                    (row, row)
+                }
+                _ => return None,
+            };
+            rows = Some(match rows {
+                Some((b, e)) => (b.min(beg), e.max(end)),
+                None => (beg, end),
+            });
+        }
+        let (row_beg, row_end) = rows?;
+        if row_beg > row_end {
+            return None;
+        }
+
+        let (col_beg, col_end) = if columns.is_empty() {
+            (self.area.0.col_num(), self.area.1.col_num())
+        } else {
+            let mut indexes = Vec::new();
+            for column in &columns {
+                indexes.push(self.column_index(column)?);
+            }
+            let offset = self.area.0.col_num();
+            (
+                offset + u32::try_from(*indexes.iter().min()?).ok()?,
+                offset + u32::try_from(*indexes.iter().max()?).ok()?,
+            )
+        };
+
+        if col_beg == col_end && row_beg == row_end {
+            return Some(coordinate_from_index(col_beg, row_beg));
+        }
+        Some(format!(
+            "{}:{}",
+            coordinate_from_index(col_beg, row_beg),
+            coordinate_from_index(col_end, row_end)
+        ))
+    }
+
+    /// Rewrite `[@Col]` style references of this table to the
+    /// `Table[[#This Row],[Col]]` form stored in the file.
+    pub(crate) fn normalize_structured_reference(&self, operand: &str) -> Option<String> {
+        let (name, specifier) = split_structured_reference(operand)?;
+        if !name.is_empty() && !name.eq_ignore_ascii_case(&self.name) {
+            return None;
+        }
+        let inner = &specifier[1..specifier.len() - 1];
+        let result = match inner.strip_prefix('@') {
+            Some("") => format!("{}[#This Row]", self.name),
+            Some(rest) if rest.starts_with('[') => {
+                format!("{}[[#This Row],{}]", self.name, rest)
+            }
+            Some(rest) => format!("{}[[#This Row],[{}]]", self.name, rest),
+            None if name.is_empty() => format!("{}{}", self.name, specifier),
+            None => return None,
+        };
+        Some(result)
+    }
+
     #[inline]
     fn cell_coord_to_coord<T>(cc: T) -> Coordinate
     where
@@ -285,6 +517,8 @@ impl TableColumn {
         self.name()
     }
 
+    /// Set the column name.
+    /// Use `Worksheet::rename_table_column` to keep the header cell in sync.
     #[inline]
     pub fn set_name(&mut self, name: String) {
         self.name = name;
@@ -383,10 +617,216 @@ impl TableColumn {
         self.calculated_column_formula()
     }
 
+    /// Set the formula of a calculated column.
+    /// Use `Worksheet::fill_table_calculated_columns` to write it down the column.
+    #[inline]
+    pub fn set_calculated_column_formula<S: Into<String>>(&mut self, value: S) {
+        self.calculated_column_formula = Some(value.into());
+    }
+
     #[inline]
-    pub(crate) fn set_calculated_column_formula(&mut self, value: String) {
-        self.calculated_column_formula = Some(value);
+    pub fn remove_calculated_column_formula(&mut self) {
+        self.calculated_column_formula = None;
+    }
+}
+
+impl AdjustmentCoordinate for Table {
+    fn adjustment_insert_coordinate(
+        &mut self,
+        root_col_num: u32,
+        offset_col_num: u32,
+        root_row_num: u32,
+        offset_row_num: u32,
+    ) {
+        self.area.0.adjustment_insert_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+        self.area.1.adjustment_insert_coordinate(
+            root_col_num,
+            offset_col_num,
+            root_row_num,
+            offset_row_num,
+        );
+        if let Some(v) = &mut self.sort_state {
+            v.adjustment_insert_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    fn adjustment_remove_coordinate(
+        &mut self,
+        root_col_num: u32,
+        offset_col_num: u32,
+        root_row_num: u32,
+        offset_row_num: u32,
+    ) {
+        // A removed band overlapping one edge of the table shrinks it.
+        let beg_col = adjustment_remove_start(self.area.0.col_num(), root_col_num, offset_col_num);
+        let beg_row = adjustment_remove_start(self.area.0.row_num(), root_row_num, offset_row_num);
+        let end_col = adjustment_remove_end(self.area.1.col_num(), root_col_num, offset_col_num);
+        let end_row = adjustment_remove_end(self.area.1.row_num(), root_row_num, offset_row_num);
+        self.area.0.set_col_num(beg_col).set_row_num(beg_row);
+        self.area.1.set_col_num(end_col).set_row_num(end_row);
+        if let Some(v) = &mut self.sort_state {
+            v.adjustment_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+    }
+
+    #[inline]
+    fn is_remove_coordinate(
+        &self,
+        root_col_num: u32,
+        offset_col_num: u32,
+        root_row_num: u32,
+        offset_row_num: u32,
+    ) -> bool {
+        let (beg, end) = &self.area;
+        (is_remove_coordinate(beg.col_num(), root_col_num, offset_col_num)
+            && is_remove_coordinate(end.col_num(), root_col_num, offset_col_num))
+            || (is_remove_coordinate(beg.row_num(), root_row_num, offset_row_num)
+                && is_remove_coordinate(end.row_num(), root_row_num, offset_row_num))
+    }
+}
+
+#[inline]
+fn adjustment_remove_start(num: u32, root_num: u32, offset_num: u32) -> u32 {
+    if is_remove_coordinate(num, root_num, offset_num) {
+        root_num
+    } else {
+        adjustment_remove_coordinate(num, root_num, offset_num)
+    }
+}
+
+#[inline]
+fn adjustment_remove_end(num: u32, root_num: u32, offset_num: u32) -> u32 {
+    if is_remove_coordinate(num, root_num, offset_num) {
+        root_num - 1
+    } else {
+        adjustment_remove_coordinate(num, root_num, offset_num)
+    }
+}
+
+/// Split a structured reference into the table name and the bracketed specifier.
+/// The table name is empty for references like `[@Qty]`.
+pub(crate) fn split_structured_reference(reference: &str) -> Option<(&str, &str)> {
+    let pos = reference.find('[')?;
+    let (name, specifier) = reference.split_at(pos);
+    if !specifier.ends_with(']') {
+        return None;
+    }
+    Some((name, specifier))
+}
+
+/// Escape a column name for use inside structured reference brackets.
+pub(crate) fn escape_structured_column(name: &str) -> String {
+    let mut result = String::with_capacity(name.len());
+    for c in name.chars() {
+        if matches!(c, '[' | ']' | '#' | '\'') {
+            result.push('\'');
+        }
+        result.push(c);
+    }
+    result
+}
+
+fn unescape_structured_column(name: &str) -> String {
+    let mut result = String::with_capacity(name.len());
+    let mut chars = name.chars();
+    while let Some(c) = chars.next() {
+        if c == '\'' {
+            if let Some(next) = chars.next() {
+                result.push(next);
+            }
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// Parse a bracketed specifier into its special items (`#Data`, `#This Row`, ...)
+/// and column names.
+fn parse_structured_specifier(specifier: &str) -> Option<(Vec<String>, Vec<String>)> {
+    let inner = specifier.strip_prefix('[')?.strip_suffix(']')?;
+    let mut specials = Vec::new();
+    let mut columns = Vec::new();
+
+    let items = if let Some(rest) = inner.strip_prefix('@') {
+        specials.push("#This Row".to_string());
+        if rest.starts_with('[') {
+            split_structured_items(rest)?
+        } else if rest.is_empty() {
+            Vec::new()
+        } else {
+            vec![rest.to_string()]
+        }
+    } else if inner.starts_with('[') {
+        split_structured_items(inner)?
+    } else if inner.is_empty() {
+        Vec::new()
+    } else {
+        vec![inner.to_string()]
+    };
+
+    for item in items {
+        if item.starts_with('#') {
+            specials.push(item);
+        } else {
+            columns.push(unescape_structured_column(&item));
+        }
+    }
+    if columns.len() > 2 {
+        return None;
+    }
+    Some((specials, columns))
+}
+
+/// Split `[a],[b]:[c]` into `a`, `b` and `c`.
+fn split_structured_items(value: &str) -> Option<Vec<String>> {
+    let mut items = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0;
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' if depth > 0 => {
+                current.push(c);
+                current.push(chars.next()?);
+            }
+            '[' => {
+                if depth > 0 {
+                    return None;
+                }
+                depth += 1;
+            }
+            ']' => {
+                if depth == 0 {
+                    return None;
+                }
+                depth -= 1;
+                items.push(std::mem::take(&mut current));
+            }
+            ',' | ':' | ' ' if depth == 0 => {}
+            _ if depth == 0 => return None,
+            _ => current.push(c),
+        }
+    }
+    if depth != 0 {
+        return None;
     }
+    Some(items)
 }
 
 #[derive(Clone, Debug)]
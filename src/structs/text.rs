@@ -11,6 +11,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     writer::driver::{
         write_end_tag,
@@ -57,7 +58,7 @@ impl Text {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Text(e) => {
@@ -65,10 +66,10 @@ impl Text {
             },
             Event::End(ref e) => {
                 if e.name().0 == b"t" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "t")
+            Event::Eof => return Err(XlsxError::missing_end_element("t", reader.buffer_position()))
         );
     }
 
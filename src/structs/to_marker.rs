@@ -10,10 +10,14 @@ use quick_xml::{
     },
 };
 
-use crate::writer::driver::{
-    write_end_tag,
-    write_start_tag,
-    write_text_node,
+use crate::{
+    XlsxError,
+    reader::driver::parse_value,
+    writer::driver::{
+        write_end_tag,
+        write_start_tag,
+        write_text_node,
+    },
 };
 
 #[derive(Clone, Default, Debug)]
@@ -124,7 +128,7 @@ impl ToMarker {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         let mut string_value: String = String::new();
         let mut buf = Vec::new();
         loop {
@@ -132,22 +136,22 @@ impl ToMarker {
                 Ok(Event::Text(e)) => string_value = crate::helper::utils::unescape_xml_text(&e),
                 Ok(Event::End(ref e)) => match e.name().0 {
                     b"xdr:col" => {
-                        self.col = string_value.parse::<usize>().unwrap();
+                        self.col = parse_value(reader, &string_value)?;
                     }
                     b"xdr:colOff" => {
-                        self.col_off = string_value.parse::<usize>().unwrap();
+                        self.col_off = parse_value(reader, &string_value)?;
                     }
                     b"xdr:row" => {
-                        self.row = string_value.parse::<usize>().unwrap();
+                        self.row = parse_value(reader, &string_value)?;
                     }
                     b"xdr:rowOff" => {
-                        self.row_off = string_value.parse::<usize>().unwrap();
+                        self.row_off = parse_value(reader, &string_value)?;
                     }
-                    b"to" => return,
+                    b"to" => return Ok(()),
                     _ => (),
                 },
-                Ok(Event::Eof) => panic!("Error: Could not find {} end element", "to"),
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Ok(Event::Eof) => return Err(XlsxError::missing_end_element("to", reader.buffer_position())),
+                Err(e) => return Err(XlsxError::parse(reader.buffer_position(), e.to_string())),
                 _ => (),
             }
             buf.clear();
@@ -0,0 +1,148 @@
+// top10
+use std::io::Cursor;
+
+use quick_xml::{
+    Reader,
+    Writer,
+    events::BytesStart,
+};
+
+use super::{
+    BooleanValue,
+    DoubleValue,
+};
+use crate::{
+    XlsxError,
+    reader::driver::{
+        get_attribute,
+        set_string_from_xml,
+        try_set_string_from_xml,
+    },
+    writer::driver::write_start_tag,
+};
+
+#[derive(Clone, Default, Debug)]
+pub struct Top10 {
+    top:        BooleanValue,
+    percent:    BooleanValue,
+    val:        DoubleValue,
+    filter_val: DoubleValue,
+}
+
+impl Top10 {
+    /// Whether the top items are shown. `false` shows the bottom items.
+    #[inline]
+    #[must_use]
+    pub fn top(&self) -> bool {
+        !self.top.has_value() || self.top.value()
+    }
+
+    #[inline]
+    pub fn set_top(&mut self, value: bool) -> &mut Self {
+        self.top.set_value(value);
+        self
+    }
+
+    /// Whether `val` is a percentage of the items.
+    #[inline]
+    #[must_use]
+    pub fn percent(&self) -> bool {
+        self.percent.value()
+    }
+
+    #[inline]
+    pub fn set_percent(&mut self, value: bool) -> &mut Self {
+        self.percent.set_value(value);
+        self
+    }
+
+    /// Get the number (or percentage) of items to show.
+    #[inline]
+    #[must_use]
+    pub fn val(&self) -> f64 {
+        self.val.value()
+    }
+
+    #[inline]
+    pub fn set_val(&mut self, value: f64) -> &mut Self {
+        self.val.set_value(value);
+        self
+    }
+
+    /// Get the threshold value computed when the filter was applied.
+    #[inline]
+    #[must_use]
+    pub fn filter_val(&self) -> f64 {
+        self.filter_val.value()
+    }
+
+    #[inline]
+    pub fn set_filter_val(&mut self, value: f64) -> &mut Self {
+        self.filter_val.set_value(value);
+        self
+    }
+
+    /// Compute the threshold for the given column values.
+    pub(crate) fn threshold(&self, numbers: &[f64]) -> Option<f64> {
+        if numbers.is_empty() {
+            return None;
+        }
+        let mut sorted = numbers.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        if self.top() {
+            sorted.reverse();
+        }
+        let count = if self.percent() {
+            (num_traits::cast::<_, f64>(sorted.len()).unwrap() * self.val() / 100.0).ceil()
+        } else {
+            self.val().floor()
+        };
+        let count = num_traits::cast::<_, usize>(count)
+            .unwrap_or(0)
+            .clamp(1, sorted.len());
+        Some(sorted[count - 1])
+    }
+
+    #[inline]
+    pub(crate) fn is_match(&self, number: Option<f64>, threshold: Option<f64>) -> bool {
+        match (number, threshold) {
+            (Some(number), Some(threshold)) => {
+                if self.top() {
+                    number >= threshold
+                } else {
+                    number <= threshold
+                }
+            }
+            _ => false,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn set_attributes<R: std::io::BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        e: &BytesStart,
+    ) -> Result<(), XlsxError> {
+        set_string_from_xml!(self, e, top, "top");
+        set_string_from_xml!(self, e, percent, "percent");
+        try_set_string_from_xml!(self, reader, e, val, "val");
+        try_set_string_from_xml!(self, reader, e, filter_val, "filterVal");
+        Ok(())
+    }
+
+    pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
+        // top10
+        let mut attributes: crate::structs::AttrCollection = Vec::new();
+        if self.top.has_value() {
+            attributes.push(("top", self.top.value_string()).into());
+        }
+        if self.percent.has_value() {
+            attributes.push(("percent", self.percent.value_string()).into());
+        }
+        attributes.push(("val", self.val.value_string()).into());
+        if self.filter_val.has_value() {
+            attributes.push(("filterVal", self.filter_val.value_string()).into());
+        }
+        write_start_tag(writer, "top10", attributes, true);
+    }
+}
@@ -1,3 +1,5 @@
+use crate::XlsxError;
+
 #[derive(Clone, Default, Debug)]
 pub struct UInt16Value {
     value: Option<u16>,
@@ -31,9 +33,18 @@ impl UInt16Value {
         self
     }
 
+    /// The text of an attribute. A text that is not a number is a parse
+    /// error, which the reader moves to its position.
     #[inline]
-    pub(crate) fn set_value_string<S: Into<String>>(&mut self, value: S) -> &mut UInt16Value {
-        self.set_value(value.into().parse::<u16>().unwrap())
+    pub(crate) fn set_value_string<S: Into<String>>(
+        &mut self,
+        value: S,
+    ) -> Result<&mut UInt16Value, XlsxError> {
+        let value = value.into();
+        match value.parse::<u16>() {
+            Ok(v) => Ok(self.set_value(v)),
+            Err(_) => Err(XlsxError::parse(0, format!("\"{value}\" is not an unsigned short"))),
+        }
     }
 
     #[inline]
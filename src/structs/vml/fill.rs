@@ -7,6 +7,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -139,10 +140,10 @@ impl Fill {
     #[inline]
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
-        _reader: &mut Reader<R>,
+        reader: &mut Reader<R>,
         e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, color, "color");
         set_string_from_xml!(self, e, color_2, "color2");
         set_string_from_xml!(self, e, on, "on");
@@ -150,14 +151,17 @@ impl Fill {
 
         if let Some(relid) = get_attribute(e, b"o:relid") {
             if let Some(rel) = drawing_relationships {
-                let relationship = rel.relationship_by_rid(&relid);
+                let relationship = rel
+                    .relationship_by_rid(&relid)
+                    .ok_or_else(|| XlsxError::missing_relationship(&relid, reader.buffer_position()))?;
                 let mut obj = MediaObject::default();
-                obj.set_image_title(get_attribute(e, b"o:title").unwrap());
+                obj.set_image_title(get_attribute(e, b"o:title").unwrap_or_default());
                 obj.set_image_name(relationship.raw_file().file_name());
                 obj.set_image_data(relationship.raw_file().file_data());
                 self.set_image(obj);
             }
         }
+        Ok(())
     }
 
     /// We allow the `unused_assignments` lint here, because the compiler is not
@@ -7,6 +7,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -74,13 +75,15 @@ impl ImageData {
 
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
-        _reader: &mut Reader<R>,
+        reader: &mut Reader<R>,
         e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         if let Some(relid) = get_attribute(e, b"o:relid") {
             if let Some(rel) = drawing_relationships {
-                let relationship = rel.relationship_by_rid(&relid);
+                let relationship = rel
+                    .relationship_by_rid(&relid)
+                    .ok_or_else(|| XlsxError::missing_relationship(&relid, reader.buffer_position()))?;
                 let mut obj = MediaObject::default();
                 obj.set_image_name(relationship.raw_file().file_name());
                 obj.set_image_data(relationship.raw_file().file_data());
@@ -89,6 +92,7 @@ impl ImageData {
         }
 
         set_string_from_xml!(self, e, title, "o:title");
+        Ok(())
     }
 
     pub(crate) fn write_to(
@@ -20,6 +20,7 @@ use super::{
     spreadsheet::ClientData,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -395,7 +396,7 @@ impl Shape {
         reader: &mut Reader<R>,
         e: &BytesStart,
         drawing_relationships: Option<&RawRelationships>,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, r_type, "type");
         set_string_from_xml!(self, e, style, "style");
         set_string_from_xml!(self, e, filled, "filled");
@@ -413,7 +414,7 @@ impl Shape {
                 match e.name().into_inner() {
                 b"v:fill" => {
                     let mut obj = Fill::default();
-                    obj.set_attributes(reader, e, drawing_relationships);
+                    obj.set_attributes(reader, e, drawing_relationships)?;
                     self.set_fill(obj);
                 }
                 b"v:shadow" => {
@@ -433,7 +434,7 @@ impl Shape {
                 }
                 b"v:imagedata" => {
                     let mut obj = ImageData::default();
-                    obj.set_attributes(reader, e, drawing_relationships);
+                    obj.set_attributes(reader, e, drawing_relationships)?;
                     self.set_image_data(obj);
                 }
                 _ => (),
@@ -443,12 +444,12 @@ impl Shape {
                 match e.name().into_inner() {
                 b"v:textbox" => {
                     let mut obj = TextBox::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_text_box(obj);
                 }
                 b"x:ClientData" => {
                     let mut obj = ClientData::default();
-                    obj.set_attributes(reader, e);
+                    obj.set_attributes(reader, e)?;
                     self.set_client_data(obj);
                 }
                 _ => (),
@@ -456,10 +457,10 @@ impl Shape {
             },
             Event::End(ref e) => {
                 if  e.name().into_inner() == b"v:shape" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "v:shape")
+            Event::Eof => return Err(XlsxError::missing_end_element("v:shape", reader.buffer_position()))
         );
     }
 
@@ -10,6 +10,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     helper::coordinate::{
         adjustment_insert_coordinate,
         adjustment_remove_coordinate,
@@ -252,7 +253,7 @@ impl Anchor {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Text(e) => {
@@ -269,10 +270,10 @@ impl Anchor {
             },
             Event::End(ref e) => {
                 if e.name().0 == b"x:Anchor" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "x:Anchor")
+            Event::Eof => return Err(XlsxError::missing_end_element("x:Anchor", reader.buffer_position()))
         );
     }
 
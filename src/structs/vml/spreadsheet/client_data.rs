@@ -22,12 +22,19 @@ use super::{
     Visible,
 };
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
         xml_read_loop,
     },
-    structs::EnumValue,
+    structs::{
+        BooleanValue,
+        EnumValue,
+        Int32Value,
+        StringValue,
+        UInt32Value,
+    },
     traits::{
         AdjustmentCoordinate,
         AdjustmentValue,
@@ -35,6 +42,7 @@ use crate::{
     writer::driver::{
         write_end_tag,
         write_start_tag,
+        write_text_node,
     },
 };
 
@@ -50,6 +58,20 @@ pub struct ClientData {
     visible:               Option<Visible>,
     clipboard_format:      Option<ClipboardFormat>,
     auto_size_picture:     Option<AutoSizePicture>,
+    formula_macro:         StringValue,
+    formula_link:          StringValue,
+    formula_range:         StringValue,
+    checked:               UInt32Value,
+    current_value:         Int32Value,
+    minimum:               Int32Value,
+    maximum:               Int32Value,
+    increment:             Int32Value,
+    page:                  Int32Value,
+    selection:             UInt32Value,
+    drop_style:            StringValue,
+    drop_lines:            UInt32Value,
+    no_three_d:            BooleanValue,
+    first_button:          BooleanValue,
 }
 
 impl ClientData {
@@ -342,12 +364,264 @@ impl ClientData {
         self
     }
 
+    /// Macro assigned to the control (`x:FmlaMacro`).
+    #[inline]
+    #[must_use]
+    pub fn formula_macro(&self) -> &str {
+        self.formula_macro.value_str()
+    }
+
+    #[inline]
+    pub fn set_formula_macro<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.formula_macro.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_formula_macro(&mut self) -> &mut Self {
+        self.formula_macro.remove_value();
+        self
+    }
+
+    /// Cell linked to the control value (`x:FmlaLink`).
+    #[inline]
+    #[must_use]
+    pub fn formula_link(&self) -> &str {
+        self.formula_link.value_str()
+    }
+
+    #[inline]
+    pub fn set_formula_link<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.formula_link.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_formula_link(&mut self) -> &mut Self {
+        self.formula_link.remove_value();
+        self
+    }
+
+    /// Range providing list items (`x:FmlaRange`).
+    #[inline]
+    #[must_use]
+    pub fn formula_range(&self) -> &str {
+        self.formula_range.value_str()
+    }
+
+    #[inline]
+    pub fn set_formula_range<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.formula_range.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_formula_range(&mut self) -> &mut Self {
+        self.formula_range.remove_value();
+        self
+    }
+
+    /// Check state (`x:Checked`): 0 unchecked, 1 checked, 2 mixed.
+    #[inline]
+    #[must_use]
+    pub fn checked(&self) -> u32 {
+        self.checked.value()
+    }
+
+    #[inline]
+    pub fn set_checked(&mut self, value: u32) -> &mut Self {
+        self.checked.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn current_value(&self) -> i32 {
+        self.current_value.value()
+    }
+
+    #[inline]
+    pub fn set_current_value(&mut self, value: i32) -> &mut Self {
+        self.current_value.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn minimum(&self) -> i32 {
+        self.minimum.value()
+    }
+
+    #[inline]
+    pub fn set_minimum(&mut self, value: i32) -> &mut Self {
+        self.minimum.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn maximum(&self) -> i32 {
+        self.maximum.value()
+    }
+
+    #[inline]
+    pub fn set_maximum(&mut self, value: i32) -> &mut Self {
+        self.maximum.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn increment(&self) -> i32 {
+        self.increment.value()
+    }
+
+    #[inline]
+    pub fn set_increment(&mut self, value: i32) -> &mut Self {
+        self.increment.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn page(&self) -> i32 {
+        self.page.value()
+    }
+
+    #[inline]
+    pub fn set_page(&mut self, value: i32) -> &mut Self {
+        self.page.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn selection(&self) -> u32 {
+        self.selection.value()
+    }
+
+    #[inline]
+    pub fn set_selection(&mut self, value: u32) -> &mut Self {
+        self.selection.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn drop_style(&self) -> &str {
+        self.drop_style.value_str()
+    }
+
+    #[inline]
+    pub fn set_drop_style<S: Into<String>>(&mut self, value: S) -> &mut Self {
+        self.drop_style.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn drop_lines(&self) -> u32 {
+        self.drop_lines.value()
+    }
+
+    #[inline]
+    pub fn set_drop_lines(&mut self, value: u32) -> &mut Self {
+        self.drop_lines.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn no_three_d(&self) -> bool {
+        self.no_three_d.value()
+    }
+
+    #[inline]
+    pub fn set_no_three_d(&mut self, value: bool) -> &mut Self {
+        self.no_three_d.set_value(value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn first_button(&self) -> bool {
+        self.first_button.value()
+    }
+
+    #[inline]
+    pub fn set_first_button(&mut self, value: bool) -> &mut Self {
+        self.first_button.set_value(value);
+        self
+    }
+
+    #[inline]
+    pub(crate) fn has_checked(&self) -> bool {
+        self.checked.has_value()
+    }
+
+    #[inline]
+    pub(crate) fn has_current_value(&self) -> bool {
+        self.current_value.has_value()
+    }
+
+    #[inline]
+    pub(crate) fn has_minimum(&self) -> bool {
+        self.minimum.has_value()
+    }
+
+    #[inline]
+    pub(crate) fn has_maximum(&self) -> bool {
+        self.maximum.has_value()
+    }
+
+    #[inline]
+    pub(crate) fn has_increment(&self) -> bool {
+        self.increment.has_value()
+    }
+
+    #[inline]
+    pub(crate) fn has_page(&self) -> bool {
+        self.page.has_value()
+    }
+
+    #[inline]
+    pub(crate) fn has_selection(&self) -> bool {
+        self.selection.has_value()
+    }
+
+    #[inline]
+    pub(crate) fn has_drop_lines(&self) -> bool {
+        self.drop_lines.has_value()
+    }
+
+    fn read_text_node<R: std::io::BufRead>(
+        reader: &mut Reader<R>,
+        tag: &[u8],
+    ) -> Result<String, XlsxError> {
+        let mut value = String::new();
+        xml_read_loop!(
+            reader,
+            Event::Text(e) => {
+                value = crate::helper::utils::unescape_xml_text(&e);
+            },
+            Event::End(ref e) => {
+                if e.name().into_inner() == tag {
+                    return Ok(value)
+                }
+            },
+            Event::Eof => return Err(XlsxError::missing_end_element(
+                &String::from_utf8_lossy(tag),
+                reader.buffer_position(),
+            ))
+        );
+    }
+
     #[inline]
     pub(crate) fn set_attributes<R: std::io::BufRead>(
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, object_type, "ObjectType");
 
         xml_read_loop!(
@@ -356,29 +630,35 @@ impl ClientData {
                 match e.name().into_inner() {
                     b"x:MoveWithCells" => {
                         let mut obj = MoveWithCells::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_move_with_cells(obj);
                     }
                     b"x:SizeWithCells" => {
                         let mut obj = ResizeWithCells::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_resize_with_cells(obj);
                     }
                     b"x:AutoFill" => {
                         let mut obj = AutoFill::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_auto_fill(obj);
                     }
                     b"x:Visible" => {
                         let mut obj = Visible::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_visible(obj);
                     }
                     b"x:AutoPict" => {
                         let mut obj = AutoSizePicture::default();
-                        obj.set_attributes(reader, e, true);
+                        obj.set_attributes(reader, e, true)?;
                         self.set_auto_size_picture(obj);
                     }
+                    b"x:NoThreeD" => {
+                        self.no_three_d.set_value(true);
+                    }
+                    b"x:FirstButton" => {
+                        self.first_button.set_value(true);
+                    }
                     _ => (),
                 }
             },
@@ -386,58 +666,102 @@ impl ClientData {
                 match e.name().into_inner() {
                     b"x:Anchor" => {
                         let mut obj = Anchor::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_anchor(obj);
                     }
                     b"x:MoveWithCells" => {
                         let mut obj = MoveWithCells::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_move_with_cells(obj);
                     }
                     b"x:SizeWithCells" => {
                         let mut obj = ResizeWithCells::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_resize_with_cells(obj);
                     }
                     b"x:AutoFill" => {
                         let mut obj = AutoFill::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_auto_fill(obj);
                     }
                     b"x:Row" => {
                         let mut obj = CommentRowTarget::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_comment_row_target(obj);
                     }
                     b"x:Column" => {
                         let mut obj = CommentColumnTarget::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_comment_column_target(obj);
                     }
                     b"x:CF" => {
                         let mut obj = ClipboardFormat::default();
-                        obj.set_attributes(reader, e);
+                        obj.set_attributes(reader, e)?;
                         self.set_clipboard_format(obj);
                     }
                     b"x:Visible" => {
                         let mut obj = Visible::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_visible(obj);
                     }
                     b"x:AutoPict" => {
                         let mut obj = AutoSizePicture::default();
-                        obj.set_attributes(reader, e, false);
+                        obj.set_attributes(reader, e, false)?;
                         self.set_auto_size_picture(obj);
                     }
+                    b"x:FmlaMacro" => {
+                        self.formula_macro.set_value(Self::read_text_node(reader, b"x:FmlaMacro")?);
+                    }
+                    b"x:FmlaLink" => {
+                        self.formula_link.set_value(Self::read_text_node(reader, b"x:FmlaLink")?);
+                    }
+                    b"x:FmlaRange" => {
+                        self.formula_range.set_value(Self::read_text_node(reader, b"x:FmlaRange")?);
+                    }
+                    b"x:Checked" => {
+                        self.checked.set_value_string(Self::read_text_node(reader, b"x:Checked")?);
+                    }
+                    b"x:Val" => {
+                        self.current_value.set_value_string(Self::read_text_node(reader, b"x:Val")?);
+                    }
+                    b"x:Min" => {
+                        self.minimum.set_value_string(Self::read_text_node(reader, b"x:Min")?);
+                    }
+                    b"x:Max" => {
+                        self.maximum.set_value_string(Self::read_text_node(reader, b"x:Max")?);
+                    }
+                    b"x:Inc" => {
+                        self.increment.set_value_string(Self::read_text_node(reader, b"x:Inc")?);
+                    }
+                    b"x:Page" => {
+                        self.page.set_value_string(Self::read_text_node(reader, b"x:Page")?);
+                    }
+                    b"x:Sel" => {
+                        self.selection.set_value_string(Self::read_text_node(reader, b"x:Sel")?);
+                    }
+                    b"x:DropStyle" => {
+                        self.drop_style.set_value(Self::read_text_node(reader, b"x:DropStyle")?);
+                    }
+                    b"x:DropLines" => {
+                        self.drop_lines.set_value_string(Self::read_text_node(reader, b"x:DropLines")?);
+                    }
+                    b"x:NoThreeD" => {
+                        self.no_three_d.set_value(true);
+                        Self::read_text_node(reader, b"x:NoThreeD")?;
+                    }
+                    b"x:FirstButton" => {
+                        self.first_button.set_value(true);
+                        Self::read_text_node(reader, b"x:FirstButton")?;
+                    }
                     _ => (),
                 }
             },
             Event::End(ref e) => {
                 if e.name().into_inner() == b"x:ClientData" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "x:ClientData")
+            Event::Eof => return Err(XlsxError::missing_end_element("x:ClientData", reader.buffer_position()))
         );
     }
 
@@ -494,8 +818,79 @@ impl ClientData {
             v.write_to(writer);
         }
 
+        // x:FmlaMacro
+        Self::write_text_element(writer, "x:FmlaMacro", self.formula_macro.value());
+
+        // x:FirstButton
+        if self.first_button.value() {
+            write_start_tag(writer, "x:FirstButton", vec![], true);
+        }
+
+        // x:Checked
+        if self.checked.has_value() {
+            Self::write_text_element(writer, "x:Checked", Some(&self.checked.value_string()));
+        }
+
+        // x:Val
+        if self.current_value.has_value() {
+            Self::write_text_element(writer, "x:Val", Some(&self.current_value.value_string()));
+        }
+
+        // x:Min
+        if self.minimum.has_value() {
+            Self::write_text_element(writer, "x:Min", Some(&self.minimum.value_string()));
+        }
+
+        // x:Max
+        if self.maximum.has_value() {
+            Self::write_text_element(writer, "x:Max", Some(&self.maximum.value_string()));
+        }
+
+        // x:Inc
+        if self.increment.has_value() {
+            Self::write_text_element(writer, "x:Inc", Some(&self.increment.value_string()));
+        }
+
+        // x:Page
+        if self.page.has_value() {
+            Self::write_text_element(writer, "x:Page", Some(&self.page.value_string()));
+        }
+
+        // x:FmlaLink
+        Self::write_text_element(writer, "x:FmlaLink", self.formula_link.value());
+
+        // x:FmlaRange
+        Self::write_text_element(writer, "x:FmlaRange", self.formula_range.value());
+
+        // x:Sel
+        if self.selection.has_value() {
+            Self::write_text_element(writer, "x:Sel", Some(&self.selection.value_string()));
+        }
+
+        // x:NoThreeD
+        if self.no_three_d.value() {
+            write_start_tag(writer, "x:NoThreeD", vec![], true);
+        }
+
+        // x:DropStyle
+        Self::write_text_element(writer, "x:DropStyle", self.drop_style.value());
+
+        // x:DropLines
+        if self.drop_lines.has_value() {
+            Self::write_text_element(writer, "x:DropLines", Some(&self.drop_lines.value_string()));
+        }
+
         write_end_tag(writer, "x:ClientData");
     }
+
+    #[inline]
+    fn write_text_element(writer: &mut Writer<Cursor<Vec<u8>>>, tag: &str, value: Option<&str>) {
+        if let Some(v) = value {
+            write_start_tag(writer, tag, vec![], false);
+            write_text_node(writer, v);
+            write_end_tag(writer, tag);
+        }
+    }
 }
 impl AdjustmentCoordinate for ClientData {
     #[inline]
@@ -11,6 +11,7 @@ use quick_xml::{
 
 use super::ClipboardFormatValues;
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     structs::EnumValue,
     writer::driver::{
@@ -50,7 +51,7 @@ impl ClipboardFormat {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Text(e) => {
@@ -58,10 +59,10 @@ impl ClipboardFormat {
             },
             Event::End(ref e) => {
                 if e.name().0 == b"x:CF" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "x:CF")
+            Event::Eof => return Err(XlsxError::missing_end_element("x:CF", reader.buffer_position()))
         );
     }
 
@@ -10,6 +10,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     helper::coordinate::{
         adjustment_insert_coordinate,
         adjustment_remove_coordinate,
@@ -55,7 +56,7 @@ impl CommentColumnTarget {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Text(e) => {
@@ -63,10 +64,10 @@ impl CommentColumnTarget {
             },
             Event::End(ref e) => {
                 if e.name().0 == b"x:Column" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "x:Column")
+            Event::Eof => return Err(XlsxError::missing_end_element("x:Column", reader.buffer_position()))
         );
     }
 
@@ -10,6 +10,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     helper::coordinate::{
         adjustment_insert_coordinate,
         adjustment_remove_coordinate,
@@ -73,7 +74,7 @@ impl CommentRowTarget {
         &mut self,
         reader: &mut Reader<R>,
         _e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         xml_read_loop!(
             reader,
             Event::Text(e) => {
@@ -81,10 +82,10 @@ impl CommentRowTarget {
             },
             Event::End(ref e) => {
                 if e.name().0 == b"x:Row" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "x:Row")
+            Event::Eof => return Err(XlsxError::missing_end_element("x:Row", reader.buffer_position()))
         );
     }
 
@@ -10,6 +10,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::xml_read_loop,
     structs::TrueFalseBlankValue,
     writer::driver::{
@@ -50,9 +51,9 @@ impl ResizeWithCells {
         reader: &mut Reader<R>,
         _e: &BytesStart,
         empty_flag: bool,
-    ) {
+    ) -> Result<(), XlsxError> {
         if empty_flag {
-            return;
+            return Ok(());
         }
 
         xml_read_loop!(
@@ -62,10 +63,10 @@ impl ResizeWithCells {
             },
             Event::End(ref e) => {
                 if e.name().0 == b"x:SizeWithCells" {
-                    return
+                    return Ok(())
                 }
             },
-            Event::Eof => panic!("Error: Could not find {} end element", "x:SizeWithCells")
+            Event::Eof => return Err(XlsxError::missing_end_element("x:SizeWithCells", reader.buffer_position()))
         );
     }
 
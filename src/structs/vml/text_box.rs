@@ -10,6 +10,7 @@ use quick_xml::{
 };
 
 use crate::{
+    XlsxError,
     reader::driver::{
         get_attribute,
         set_string_from_xml,
@@ -75,7 +76,7 @@ impl TextBox {
         &mut self,
         reader: &mut Reader<R>,
         e: &BytesStart,
-    ) {
+    ) -> Result<(), XlsxError> {
         set_string_from_xml!(self, e, style, "style");
 
         let mut buf = Vec::new();
@@ -84,9 +85,7 @@ impl TextBox {
         loop {
             match reader.read_event_into(&mut buf) {
                 Ok(Event::Empty(ref e)) => {
-                    let mut tag = std::str::from_utf8(e.name().into_inner())
-                        .unwrap()
-                        .to_string();
+                    let mut tag = String::from_utf8_lossy(e.name().into_inner()).to_string();
                     let mut attrs = vec![];
                     e.attributes().for_each(|a| {
                         if let Ok(attribute) = a {
@@ -104,9 +103,7 @@ impl TextBox {
                     inner_text = format!("{inner_text}<{tag}/>");
                 }
                 Ok(Event::Start(ref e)) => {
-                    let mut tag = std::str::from_utf8(e.name().into_inner())
-                        .unwrap()
-                        .to_string();
+                    let mut tag = String::from_utf8_lossy(e.name().into_inner()).to_string();
                     let mut attrs = vec![];
                     e.attributes().for_each(|a| {
                         if let Ok(attribute) = a {
@@ -127,21 +124,26 @@ impl TextBox {
                     let s = crate::helper::utils::unescape_xml_text(e);
                     inner_text = format!("{inner_text}{s}");
                 }
+                Ok(Event::GeneralRef(ref e)) => {
+                    let s = String::from_utf8_lossy(e.as_ref());
+                    inner_text = format!("{inner_text}&{s};");
+                }
                 Ok(Event::End(ref e)) => {
                     if e.name().into_inner() == b"v:textbox" {
                         break;
                     }
-                    let s = std::str::from_utf8(e.name().into_inner()).unwrap();
+                    let s = String::from_utf8_lossy(e.name().into_inner());
                     inner_text = format!("{inner_text}</{s}>");
                 }
                 Ok(Event::Eof) => break,
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Err(e) => return Err(XlsxError::parse(reader.buffer_position(), e.to_string())),
                 _ => (),
             }
             buf.clear();
         }
         // reader.check_end_names(true);
         self.set_innder(inner_text);
+        Ok(())
     }
 
     pub(crate) fn write_to(&self, writer: &mut Writer<Cursor<Vec<u8>>>) {
@@ -5,20 +5,66 @@ use std::sync::{
 
 use crate::{
     StringValue, XlsxError, helper::{
-        address::split_address,
-        coordinate::column_index_from_string,
+        address::{
+            join_3d_address,
+            join_address,
+            split_address,
+        },
+        calculation::{
+            Calculator,
+            Value,
+        },
+        coordinate::{
+            CellCoordinates,
+            column_index_from_string,
+            coordinate_from_index,
+            index_from_coordinate,
+        },
+        formula::{
+            check_formula,
+            parse_to_tokens,
+            replace_range_operands,
+        },
+        reference::{
+            MAX_AREA_CELLS,
+            NameValue,
+            find_defined_name,
+            join_external_book,
+            resolve_defined_name,
+            split_external_book,
+            split_reference,
+        },
     }, reader::xlsx::raw_to_deserialize_by_worksheet, structs::{
+        ARGB8,
         Address,
+        CellErrorType,
+        CellFormulaValues,
+        CellRawValue,
         CellValue,
+        Color,
         Cells,
+        Coordinate,
         DefinedName,
+        DefinedNames,
+        DependencyGraph,
+        ExternalLink,
+        Metadata,
         Properties,
+        ReadLimits,
         SharedStringTable,
+        SheetStateValues,
         Stylesheet,
         WorkbookProtection,
         WorkbookView,
         Worksheet,
         drawing::Theme,
+        office2019::{
+            mention::Mention,
+            person::Person,
+        },
+        raw::RawUnknown,
+        table::split_structured_reference,
+        worksheet::cell_range_bounds,
     }, traits::{
         AdjustmentCoordinate,
         AdjustmentCoordinateWithSheet,
@@ -40,9 +86,16 @@ pub struct Workbook {
     shared_string_table:   Arc<RwLock<SharedStringTable>>,
     workbook_view:         WorkbookView,
     backup_context_types:  Vec<(Box<str>, Box<str>)>,
+    backup_default_types:  Vec<(Box<str>, Box<str>)>,
     pivot_caches:          Vec<(Box<str>, Box<str>, Box<str>)>,
     workbook_protection:   Option<Box<WorkbookProtection>>,
     defined_names:         Vec<DefinedName>,
+    person_list:           Vec<Person>,
+    metadata:              Metadata,
+    external_links:        Vec<ExternalLink>,
+    raw_unknown:           RawUnknown,
+    raw_package_unknown:   RawUnknown,
+    read_limits:           ReadLimits,
 }
 
 impl Workbook {
@@ -54,14 +107,24 @@ impl Workbook {
     /// * `sheet_name` - Specify the sheet name. ex) "Sheet1"
     /// * `row_index` - Specify point of insert. ex) 1
     /// * `num_rows` - Specify number to insert. ex) 2
+    /// # Errors
+    /// The first error of a sheet that can not be read, as
+    /// `read_sheet_collection` returns it. Nothing is changed then.
     /// # Examples
     /// ```
     /// let mut book = umya_spreadsheet::new_file();
-    /// book.insert_new_row("Sheet1", 2, 3);
+    /// book.insert_new_row("Sheet1", 2, 3).unwrap();
     /// ```
     #[inline]
-    pub fn insert_new_row(&mut self, sheet_name: &str, row_index: u32, num_rows: u32) {
+    pub fn insert_new_row(
+        &mut self,
+        sheet_name: &str,
+        row_index: u32,
+        num_rows: u32,
+    ) -> Result<(), XlsxError> {
+        self.read_sheet_collection()?;
         self.adjustment_insert_coordinate_with_sheet(sheet_name, 0, 0, row_index, num_rows);
+        Ok(())
     }
 
     /// Insert new columns.
@@ -69,14 +132,22 @@ impl Workbook {
     /// * `sheet_name` - Specify the sheet name. ex) "Sheet1"
     /// * `column` - Specify point of insert. ex) "B"
     /// * `num_columns` - Specify number to insert. ex) 3
+    /// # Errors
+    /// The first error of a sheet that can not be read, as
+    /// `read_sheet_collection` returns it. Nothing is changed then.
     /// # Examples
     /// ```
     /// let mut book = umya_spreadsheet::new_file();
-    /// book.insert_new_column("Sheet1", "B", 3);
+    /// book.insert_new_column("Sheet1", "B", 3).unwrap();
     /// ```
     #[inline]
-    pub fn insert_new_column(&mut self, sheet_name: &str, column: &str, num_columns: u32) {
-        self.insert_new_column_by_index(sheet_name, column_index_from_string(column), num_columns);
+    pub fn insert_new_column(
+        &mut self,
+        sheet_name: &str,
+        column: &str,
+        num_columns: u32,
+    ) -> Result<(), XlsxError> {
+        self.insert_new_column_by_index(sheet_name, column_index_from_string(column), num_columns)
     }
 
     /// Insert new columns.
@@ -84,10 +155,13 @@ impl Workbook {
     /// * `sheet_name` - Specify the sheet name. ex) "Sheet1"
     /// * `column_index` - Specify point of insert. ex) 2
     /// * `num_columns` - Specify number to insert. ex) 3
+    /// # Errors
+    /// The first error of a sheet that can not be read, as
+    /// `read_sheet_collection` returns it. Nothing is changed then.
     /// # Examples
     /// ```rust
     /// let mut book = umya_spreadsheet::new_file();
-    /// book.insert_new_column_by_index("Sheet1", 2, 3);
+    /// book.insert_new_column_by_index("Sheet1", 2, 3).unwrap();
     /// ```
     #[inline]
     pub fn insert_new_column_by_index(
@@ -95,8 +169,10 @@ impl Workbook {
         sheet_name: &str,
         column_index: u32,
         num_columns: u32,
-    ) {
+    ) -> Result<(), XlsxError> {
+        self.read_sheet_collection()?;
         self.adjustment_insert_coordinate_with_sheet(sheet_name, column_index, num_columns, 0, 0);
+        Ok(())
     }
 
     /// Remove rows.
@@ -104,14 +180,24 @@ impl Workbook {
     /// * `sheet_name` - Specify the sheet name. ex) "Sheet1"
     /// * `row_index` - Specify point of remove. ex) 1
     /// * `num_rows` - Specify number to remove. ex) &2
+    /// # Errors
+    /// The first error of a sheet that can not be read, as
+    /// `read_sheet_collection` returns it. Nothing is changed then.
     /// # Examples
     /// ```rust
     /// let mut book = umya_spreadsheet::new_file();
-    /// book.remove_row("Sheet1", 2, 3);
+    /// book.remove_row("Sheet1", 2, 3).unwrap();
     /// ```
     #[inline]
-    pub fn remove_row(&mut self, sheet_name: &str, row_index: u32, num_rows: u32) {
+    pub fn remove_row(
+        &mut self,
+        sheet_name: &str,
+        row_index: u32,
+        num_rows: u32,
+    ) -> Result<(), XlsxError> {
+        self.read_sheet_collection()?;
         self.adjustment_remove_coordinate_with_sheet(sheet_name, 0, 0, row_index, num_rows);
+        Ok(())
     }
 
     /// Remove columns.
@@ -119,14 +205,22 @@ impl Workbook {
     /// * `sheet_name` - Specify the sheet name. ex) "Sheet1"
     /// * `column` - Specify point of remove. ex) "B"
     /// * `num_columns` - Specify number to remove. ex) 3
+    /// # Errors
+    /// The first error of a sheet that can not be read, as
+    /// `read_sheet_collection` returns it. Nothing is changed then.
     /// # Examples
     /// ```rust
     /// let mut book = umya_spreadsheet::new_file();
-    /// book.remove_column("Sheet1", "B", 3);
+    /// book.remove_column("Sheet1", "B", 3).unwrap();
     /// ```
     #[inline]
-    pub fn remove_column(&mut self, sheet_name: &str, column: &str, num_columns: u32) {
-        self.remove_column_by_index(sheet_name, column_index_from_string(column), num_columns);
+    pub fn remove_column(
+        &mut self,
+        sheet_name: &str,
+        column: &str,
+        num_columns: u32,
+    ) -> Result<(), XlsxError> {
+        self.remove_column_by_index(sheet_name, column_index_from_string(column), num_columns)
     }
 
     /// Remove columns.
@@ -134,10 +228,13 @@ impl Workbook {
     /// * `sheet_name` - Specify the sheet name. ex) "Sheet1"
     /// * `column_index` - Specify point of remove. ex) 2
     /// * `num_columns` - Specify number to remove. ex) 3
+    /// # Errors
+    /// The first error of a sheet that can not be read, as
+    /// `read_sheet_collection` returns it. Nothing is changed then.
     /// # Examples
     /// ```rust
     /// let mut book = umya_spreadsheet::new_file();
-    /// book.remove_column_by_index("Sheet1", 2, 3);
+    /// book.remove_column_by_index("Sheet1", 2, 3).unwrap();
     /// ```
     #[inline]
     pub fn remove_column_by_index(
@@ -145,8 +242,10 @@ impl Workbook {
         sheet_name: &str,
         column_index: u32,
         num_columns: u32,
-    ) {
+    ) -> Result<(), XlsxError> {
+        self.read_sheet_collection()?;
         self.adjustment_remove_coordinate_with_sheet(sheet_name, column_index, num_columns, 0, 0);
+        Ok(())
     }
 
     /// Gets the cell value by specifying an address.
@@ -229,6 +328,23 @@ impl Workbook {
         self
     }
 
+    /// Resolve a color to a concrete ARGB value using the theme of the
+    /// workbook. Theme colors are looked up in the color scheme and the tint
+    /// is applied.
+    /// # Examples
+    /// ```
+    /// let book = umya_spreadsheet::new_file();
+    /// let mut color = umya_spreadsheet::Color::default();
+    /// color.set_theme_index(4).set_tint(0.5);
+    /// let argb = book.resolve_color(&color);
+    /// assert_eq!(umya_spreadsheet::Color::argb8_to_hex(argb), "FFA2B9E2");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn resolve_color(&self, color: &Color) -> ARGB8 {
+        color.resolve(&self.theme)
+    }
+
     /// Get Properties.
     #[inline]
     #[must_use]
@@ -480,16 +596,27 @@ impl Workbook {
         self.sheet_collection_no_check()
     }
 
+    /// (This method is crate only.)
     /// Get Work Sheet List in mutable.
+    /// No check deserialized.
     #[inline]
-    pub fn sheet_collection_mut(&mut self) -> &mut [Worksheet] {
-        self.read_sheet_collection();
+    pub(crate) fn sheet_collection_no_check_mut(&mut self) -> &mut [Worksheet] {
         &mut self.work_sheet_collection
     }
 
+    /// Get Work Sheet List in mutable.
+    /// # Errors
+    /// The first error of a sheet that can not be read, as
+    /// `read_sheet_collection` returns it.
+    #[inline]
+    pub fn sheet_collection_mut(&mut self) -> Result<&mut [Worksheet], XlsxError> {
+        self.read_sheet_collection()?;
+        Ok(&mut self.work_sheet_collection)
+    }
+
     #[inline]
     #[deprecated(since = "3.0.0", note = "Use sheet_collection_mut()")]
-    pub fn get_sheet_collection_mut(&mut self) -> &mut [Worksheet] {
+    pub fn get_sheet_collection_mut(&mut self) -> Result<&mut [Worksheet], XlsxError> {
         self.sheet_collection_mut()
     }
 
@@ -510,30 +637,57 @@ impl Workbook {
     }
 
     /// deserialize by all worksheet.
-    #[inline]
-    pub fn read_sheet_collection(&mut self) -> &mut Self {
+    /// # Errors
+    /// Returns the first parse error. Worksheets that fail to deserialize
+    /// are left as they are.
+    pub fn read_sheet_collection(&mut self) -> Result<&mut Self, XlsxError> {
         let shared_string_table = self.shared_string_table();
         let stylesheet = self.stylesheet().clone();
+        let mut result = Ok(());
         for worksheet in &mut self.work_sheet_collection {
-            raw_to_deserialize_by_worksheet(worksheet, &shared_string_table, &stylesheet);
+            let read = raw_to_deserialize_by_worksheet(
+                worksheet,
+                &shared_string_table,
+                &stylesheet,
+                &self.metadata,
+                &self.read_limits,
+            );
+            if result.is_ok() {
+                result = read;
+            }
         }
-        self
+        result.map(|()| self)
     }
 
     /// deserialize a worksheet.
+    /// # Errors
+    /// Returns `NotFound` for an invalid index and a parse error for a
+    /// malformed worksheet.
     #[inline]
-    pub fn read_sheet(&mut self, index: usize) -> &mut Self {
+    pub fn read_sheet(&mut self, index: usize) -> Result<&mut Self, XlsxError> {
         let shared_string_table = self.shared_string_table();
         let stylesheet = self.stylesheet().clone();
-        let worksheet = self.work_sheet_collection.get_mut(index).unwrap();
-        raw_to_deserialize_by_worksheet(worksheet, &shared_string_table, &stylesheet);
-        self
+        let worksheet = self
+            .work_sheet_collection
+            .get_mut(index)
+            .ok_or(XlsxError::NotFound())?;
+        raw_to_deserialize_by_worksheet(
+            worksheet,
+            &shared_string_table,
+            &stylesheet,
+            &self.metadata,
+            &self.read_limits,
+        )?;
+        Ok(self)
     }
 
     /// deserialize a worksheet.
+    /// # Errors
+    /// Returns `NotFound` for an unknown sheet name and a parse error for a
+    /// malformed worksheet.
     #[inline]
-    pub fn read_sheet_by_name(&mut self, sheet_name: &str) -> &mut Self {
-        let index = self.find_sheet_index_by_name(sheet_name).unwrap();
+    pub fn read_sheet_by_name(&mut self, sheet_name: &str) -> Result<&mut Self, XlsxError> {
+        let index = self.find_sheet_index_by_name(sheet_name)?;
         self.read_sheet(index)
     }
 
@@ -589,10 +743,12 @@ impl Workbook {
 
     pub fn lazy_read_sheet_cells(&self, index: usize) -> Result<Cells, XlsxError> {
         let shared_string_table = self.shared_string_table();
-        self.work_sheet_collection
+        let worksheet = self
+            .work_sheet_collection
             .get(index)
-            .map(|v| v.cells_stream(&shared_string_table.read().unwrap(), self.stylesheet()))
-            .ok_or(XlsxError::NotFound())
+            .ok_or(XlsxError::NotFound())?;
+        let cells = worksheet.cells_stream(&shared_string_table.read().unwrap(), self.stylesheet());
+        cells
     }
 
     #[deprecated(since = "3.0.0", note = "Use lazy_read_sheet_cells()")]
@@ -609,13 +765,20 @@ impl Workbook {
     pub fn sheet_mut(&mut self, index: usize) -> Result<&mut Worksheet, XlsxError> {
         let shared_string_table = self.shared_string_table();
         let stylesheet = self.stylesheet().clone();
-        self.work_sheet_collection
+        let person_list: Vec<Person> = self.merged_person_list().into_iter().cloned().collect();
+        let worksheet = self
+            .work_sheet_collection
             .get_mut(index)
-            .map(|v| {
-                raw_to_deserialize_by_worksheet(v, &shared_string_table, &stylesheet);
-                v
-            })
-            .ok_or(XlsxError::NotFound())
+            .ok_or(XlsxError::NotFound())?;
+        worksheet.set_workbook_person_list(person_list);
+        raw_to_deserialize_by_worksheet(
+            worksheet,
+            &shared_string_table,
+            &stylesheet,
+            &self.metadata,
+            &self.read_limits,
+        )?;
+        Ok(worksheet)
     }
 
     #[deprecated(since = "3.0.0", note = "Use sheet_mut()")]
@@ -668,16 +831,18 @@ impl Workbook {
 
     /// Get Active Work Sheet in mutable.
     /// # Return value
-    /// * `&mut Worksheet` - Work sheet.
+    /// * `Result<&mut Worksheet, XlsxError>` - Work sheet.
+    /// # Errors
+    /// Same as `sheet_mut`, `NotFound` when the active tab is not a sheet.
     #[inline]
-    pub fn active_sheet_mut(&mut self) -> &mut Worksheet {
+    pub fn active_sheet_mut(&mut self) -> Result<&mut Worksheet, XlsxError> {
         let index = self.workbook_view().active_tab();
-        self.sheet_mut(index as usize).unwrap()
+        self.sheet_mut(index as usize)
     }
 
     #[inline]
     #[deprecated(since = "3.0.0", note = "Use active_sheet_mut()")]
-    pub fn get_active_sheet_mut(&mut self) -> &mut Worksheet {
+    pub fn get_active_sheet_mut(&mut self) -> Result<&mut Worksheet, XlsxError> {
         self.active_sheet_mut()
     }
 
@@ -695,6 +860,350 @@ impl Workbook {
         Ok(self.work_sheet_collection.last_mut().unwrap())
     }
 
+    /// Import a worksheet of another workbook.
+    /// The worksheet is added at the end with its cells, styles, drawings,
+    /// images, charts, comments, tables, pivot tables and defined names.
+    /// * The sheet is renamed "Name (2)" when the name is taken, and the
+    ///   references to it are rewritten.
+    /// * Tables and workbook names taken in this workbook are renamed
+    ///   `Name_2`, and the formulas of the sheet are rewritten.
+    /// * Workbook names of `source` used by the formulas of the sheet are
+    ///   imported too.
+    /// * Links to other workbooks used by the formulas are imported too, or
+    ///   share the link of this workbook to the same file, and the formulas
+    ///   are renumbered.
+    /// # Arguments
+    /// * `source` - Workbook to copy from.
+    /// * `sheet_name` - Sheet name in `source`. ex) "Sheet1"
+    /// # Return value
+    /// * `Result<&mut Worksheet, XlsxError>` - The imported worksheet.
+    /// # Errors
+    /// * `NotFound` - the sheet does not exist in `source`.
+    /// * `NotDeserialized` - the sheet has not been read.
+    /// # Examples
+    /// ```
+    /// let mut source = umya_spreadsheet::new_file();
+    /// source.sheet_mut(0).unwrap().cell_mut("A1").set_value("Hello");
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let sheet = book.import_sheet(&source, "Sheet1").unwrap();
+    /// assert_eq!(sheet.name(), "Sheet1 (2)");
+    /// assert_eq!(sheet.value("A1"), "Hello");
+    /// ```
+    pub fn import_sheet(
+        &mut self,
+        source: &Workbook,
+        sheet_name: &str,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let mut worksheet = source.sheet_by_name(sheet_name)?.clone();
+        let sheet_index = self.work_sheet_collection.len();
+        let new_sheet_name = self.free_sheet_name(sheet_name);
+        worksheet.set_name(new_sheet_name.clone());
+        worksheet.set_sheet_id((sheet_index + 1).to_string());
+
+        let table_renames = self.rename_tables(&mut worksheet);
+
+        // defined names
+        let mut operands: Vec<String> = Vec::new();
+        for cell in worksheet.cells() {
+            replace_range_operands(cell.formula(), |operand| {
+                operands.push(operand.to_string());
+                None
+            });
+        }
+        let mut imported_names: Vec<DefinedName> = source
+            .defined_names()
+            .iter()
+            .chain(
+                source
+                    .sheet_collection_no_check()
+                    .iter()
+                    .filter(|sheet| sheet.name() != sheet_name)
+                    .flat_map(Worksheet::defined_names),
+            )
+            .filter(|defined_name| {
+                !defined_name.has_local_sheet_id()
+                    && operands
+                        .iter()
+                        .any(|x| x.eq_ignore_ascii_case(defined_name.name()))
+            })
+            .cloned()
+            .collect();
+        let local_names: Vec<String> = worksheet
+            .defined_names()
+            .iter()
+            .filter(|defined_name| defined_name.has_local_sheet_id())
+            .map(|defined_name| defined_name.name().to_string())
+            .collect();
+        let mut name_renames: Vec<(String, String)> = Vec::new();
+        let mut used_names: Vec<String> = Vec::new();
+        let mut dedup_names = |list: &mut Vec<DefinedName>| {
+            list.retain_mut(|defined_name| {
+                if defined_name.has_local_sheet_id() {
+                    defined_name.set_local_sheet_id(num_traits::cast(sheet_index).unwrap());
+                    return true;
+                }
+                let name = defined_name.name().to_string();
+                // The same name with the same reference is already defined.
+                if find_defined_name(self, &name, None)
+                    .is_some_and(|x| x.address() == defined_name.address())
+                {
+                    return false;
+                }
+                let new_name = free_name(&name, |x| {
+                    find_defined_name(self, x, None).is_some()
+                        || used_names.iter().any(|v| v.eq_ignore_ascii_case(x))
+                });
+                if new_name != name {
+                    defined_name.set_name(new_name.clone());
+                    name_renames.push((name, new_name.clone()));
+                }
+                used_names.push(new_name);
+                true
+            });
+        };
+        dedup_names(worksheet.defined_names_mut());
+        dedup_names(&mut imported_names);
+
+        // links to other workbooks
+        let mut link_numbers: Vec<usize> = Vec::new();
+        let mut find_links = |operand: &str| -> Option<String> {
+            if let Some((number, _)) = split_external_book(operand) {
+                if !link_numbers.contains(&number) {
+                    link_numbers.push(number);
+                }
+            }
+            None
+        };
+        worksheet.replace_operands(&mut find_links);
+        for defined_name in &mut imported_names {
+            defined_name.replace_operands(&mut find_links);
+        }
+        // Cells are not visited in order: keep the order of the source.
+        link_numbers.sort_unstable();
+        let mut link_renumbers: Vec<(usize, usize)> = Vec::new();
+        for number in link_numbers {
+            let Some(external_link) = number
+                .checked_sub(1)
+                .and_then(|index| source.external_links.get(index))
+            else {
+                continue;
+            };
+            let index = self
+                .external_links
+                .iter()
+                .position(|v| v.target() == external_link.target())
+                .unwrap_or_else(|| {
+                    self.external_links.push(external_link.clone());
+                    self.external_links.len() - 1
+                });
+            link_renumbers.push((number, index + 1));
+        }
+
+        let replacer = |operand: &str| -> Option<String> {
+            if let Some((number, local)) = split_external_book(operand) {
+                return link_renumbers
+                    .iter()
+                    .find(|(old, new)| *old == number && old != new)
+                    .map(|(_, new)| join_external_book(*new, &local));
+            }
+            let (sheet, local) = split_reference(operand);
+            if let Some(sheet) = sheet {
+                return (sheet == sheet_name && new_sheet_name != sheet_name)
+                    .then(|| join_address(&new_sheet_name, local));
+            }
+            if split_structured_reference(operand).is_some() {
+                return rename_table_reference(operand, &table_renames);
+            }
+            if local_names.iter().any(|x| x.eq_ignore_ascii_case(operand)) {
+                return None;
+            }
+            name_renames
+                .iter()
+                .find(|(old, _)| old.eq_ignore_ascii_case(operand))
+                .map(|(_, new)| new.clone())
+        };
+        worksheet.replace_operands(replacer);
+        for defined_name in &mut imported_names {
+            defined_name.replace_operands(replacer);
+        }
+        self.defined_names.extend(imported_names);
+
+        // persons of threaded comments
+        let persons = source.merged_person_list();
+        for comment in worksheet.threaded_comments() {
+            let person_ids = std::iter::once(comment.person_id())
+                .chain(comment.mentions().iter().map(Mention::mention_person_id));
+            for person_id in person_ids {
+                if let Some(person) = persons.iter().find(|x| x.id() == person_id) {
+                    self.add_person((*person).clone());
+                }
+            }
+        }
+
+        // pivot caches
+        let mut cache_ids: Vec<(u32, u32)> = Vec::new();
+        for pivot_table in worksheet.pivot_tables_mut() {
+            let definition = pivot_table.pivot_table_definition_mut();
+            let cache_id = definition.cache_id();
+            if let Some((_, new_id)) = cache_ids.iter().find(|(old, _)| *old == cache_id) {
+                definition.set_cache_id(*new_id);
+                continue;
+            }
+            let Some((_, _, path)) = source
+                .pivot_caches
+                .iter()
+                .find(|(_, id, _)| **id == *cache_id.to_string())
+            else {
+                continue;
+            };
+            let is_used = |id: u32| self.pivot_caches.iter().any(|(_, v, _)| **v == *id.to_string());
+            let new_id = if is_used(cache_id) {
+                (0..=u32::MAX).find(|id| !is_used(*id)).unwrap()
+            } else {
+                cache_id
+            };
+            let path = if self.pivot_caches.iter().any(|(_, _, v)| v == path) {
+                (1..=usize::MAX)
+                    .map(|no| format!("pivotCache/pivotCacheDefinition{no}.xml"))
+                    .find(|x| !self.pivot_caches.iter().any(|(_, _, v)| **v == **x))
+                    .unwrap()
+            } else {
+                path.to_string()
+            };
+            let r_id = (1..=usize::MAX)
+                .map(|no| format!("rId{no}"))
+                .find(|x| !self.pivot_caches.iter().any(|(v, _, _)| **v == **x))
+                .unwrap();
+            definition.set_cache_id(new_id);
+            cache_ids.push((cache_id, new_id));
+            self.add_pivot_caches((r_id, new_id.to_string(), path));
+        }
+
+        self.work_sheet_collection.push(worksheet);
+        Ok(self.work_sheet_collection.last_mut().unwrap())
+    }
+
+    /// Duplicate a worksheet, as "Move or Copy > Create a copy" in Excel.
+    /// The copy has the cells, styles, merged cells, column and row
+    /// dimensions, drawings, charts, tables, data validations, conditional
+    /// formats, sheet scoped names and print settings of the sheet.
+    /// * Tables are renamed `Name_2`, `Name_3`, ...
+    /// * References to the sheet in the formulas, names and charts of the
+    ///   copy point at the copy.
+    /// # Arguments
+    /// * `sheet_name` - Sheet to copy. ex) "Sheet1"
+    /// * `new_name` - Name of the copy. ex) "Sheet1 (2)"
+    /// * `position` - Sheet index of the copy. ex) 1
+    /// # Return value
+    /// * `Result<&mut Worksheet, XlsxError>` - The copy.
+    /// # Errors
+    /// * `NotFound` - the sheet does not exist.
+    /// * `NameDuplicate` - `new_name` is already used.
+    /// * `InvalidArgument` - `position` is past the end.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// book.sheet_mut(0).unwrap().cell_mut("A1").set_value("Hello");
+    /// let sheet = book.duplicate_sheet("Sheet1", "Copy", 0).unwrap();
+    /// assert_eq!(sheet.value("A1"), "Hello");
+    /// assert_eq!(book.sheet(1).unwrap().name(), "Sheet1");
+    /// ```
+    pub fn duplicate_sheet(
+        &mut self,
+        sheet_name: &str,
+        new_name: &str,
+        position: usize,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let index = self.find_sheet_index_by_name(sheet_name)?;
+        self.check_sheet_name(new_name)?;
+        if position > self.work_sheet_collection.len() {
+            return Err(XlsxError::InvalidArgument(format!(
+                "sheet position {position} is out of range"
+            )));
+        }
+        self.read_sheet(index)?;
+        let mut worksheet = self.work_sheet_collection[index].clone();
+        worksheet.set_name(new_name);
+        worksheet.set_sheet_id((self.work_sheet_collection.len() + 1).to_string());
+        let table_renames = self.rename_tables(&mut worksheet);
+
+        // Workbook names kept on the sheet stay with the original.
+        let local_sheet_id: u32 = num_traits::cast(position).unwrap();
+        worksheet.defined_names_mut().retain_mut(|defined_name| {
+            if !defined_name.has_local_sheet_id() {
+                return false;
+            }
+            defined_name.set_local_sheet_id(local_sheet_id);
+            true
+        });
+        worksheet.replace_operands(|operand| {
+            let (sheet, local) = split_reference(operand);
+            match sheet {
+                Some(sheet) => (sheet == sheet_name).then(|| join_address(new_name, local)),
+                None => rename_table_reference(operand, &table_renames),
+            }
+        });
+
+        // Sheets from `position` on move one to the right.
+        let shift = |defined_name: &mut DefinedName| {
+            if defined_name.has_local_sheet_id() && defined_name.local_sheet_id() >= local_sheet_id {
+                defined_name.set_local_sheet_id(defined_name.local_sheet_id() + 1);
+            }
+        };
+        self.defined_names.iter_mut().for_each(shift);
+        for sheet in &mut self.work_sheet_collection {
+            sheet.defined_names_mut().iter_mut().for_each(shift);
+        }
+        let active_tab = self.workbook_view.active_tab();
+        if active_tab >= local_sheet_id {
+            self.workbook_view.set_active_tab(active_tab + 1);
+        }
+
+        self.work_sheet_collection.insert(position, worksheet);
+        Ok(&mut self.work_sheet_collection[position])
+    }
+
+    /// Rename the tables of `worksheet` whose names are used in this
+    /// workbook, and return the old and the new names.
+    fn rename_tables(&self, worksheet: &mut Worksheet) -> Vec<(String, String)> {
+        let mut renames: Vec<(String, String)> = Vec::new();
+        let mut used_names: Vec<String> = self
+            .work_sheet_collection
+            .iter()
+            .flat_map(Worksheet::tables)
+            .map(|table| table.name().to_string())
+            .collect();
+        for table in worksheet.tables_mut() {
+            let name = table.name().to_string();
+            let new_name = free_name(&name, |x| {
+                used_names.iter().any(|v| v.eq_ignore_ascii_case(x))
+            });
+            if new_name != name {
+                table.set_name(&new_name);
+                table.set_display_name(&new_name);
+                renames.push((name, new_name.clone()));
+            }
+            used_names.push(new_name);
+        }
+        renames
+    }
+
+    /// A sheet name not used in this workbook, as "Name (2)" when `name` is
+    /// taken. Sheet names are at most 31 characters.
+    fn free_sheet_name(&self, name: &str) -> String {
+        if self.check_sheet_name(name).is_ok() {
+            return name.to_string();
+        }
+        (2..=usize::MAX)
+            .map(|no| {
+                let suffix = format!(" ({no})");
+                let stem: String = name.chars().take(31 - suffix.len()).collect();
+                format!("{stem}{suffix}")
+            })
+            .find(|x| self.check_sheet_name(x).is_ok())
+            .unwrap()
+    }
+
     /// Remove Work Sheet.
     /// # Arguments
     /// * `index` - sheet index
@@ -725,6 +1234,161 @@ impl Workbook {
         Ok(())
     }
 
+    /// Move a worksheet to another position.
+    /// The active sheet, the scope of sheet scoped names and 3-D references
+    /// such as `Sheet1:Sheet3!A1` follow the sheets.
+    /// # Arguments
+    /// * `from` - Sheet index to move. ex) 0
+    /// * `to` - New sheet index. ex) 2
+    /// # Errors
+    /// * `NotFound` - an index is past the end.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// book.new_sheet("Sheet2").unwrap();
+    /// book.move_sheet(0, 1).unwrap();
+    /// assert_eq!(book.sheet_index("Sheet1"), Some(1));
+    /// ```
+    pub fn move_sheet(&mut self, from: usize, to: usize) -> Result<(), XlsxError> {
+        let len = self.work_sheet_collection.len();
+        if from >= len || to >= len {
+            return Err(XlsxError::NotFound());
+        }
+        if from == to {
+            return Ok(());
+        }
+        // new index of each sheet, by old index
+        let mut order: Vec<usize> = (0..len).collect();
+        let moved = order.remove(from);
+        order.insert(to, moved);
+        let mut new_index = vec![0; len];
+        for (index, old_index) in order.iter().enumerate() {
+            new_index[*old_index] = index;
+        }
+
+        let sheet = self.work_sheet_collection.remove(from);
+        self.work_sheet_collection.insert(to, sheet);
+
+        let reindex = |defined_name: &mut DefinedName| {
+            if defined_name.has_local_sheet_id() {
+                if let Some(index) = new_index.get(defined_name.local_sheet_id() as usize) {
+                    defined_name.set_local_sheet_id(num_traits::cast(*index).unwrap());
+                }
+            }
+        };
+        self.defined_names.iter_mut().for_each(reindex);
+        for sheet in &mut self.work_sheet_collection {
+            sheet.defined_names_mut().iter_mut().for_each(reindex);
+        }
+        let active_tab = self.workbook_view.active_tab() as usize;
+        if let Some(index) = new_index.get(active_tab) {
+            self.workbook_view
+                .set_active_tab(num_traits::cast(*index).unwrap());
+        }
+
+        // 3-D references whose first sheet now comes after the last one
+        let sheet_names: Vec<String> = self
+            .work_sheet_collection
+            .iter()
+            .map(|sheet| sheet.name().to_string())
+            .collect();
+        let replacer = |operand: &str| -> Option<String> {
+            let (Some(sheet), local) = split_reference(operand) else {
+                return None;
+            };
+            let (first, last) = sheet.split_once(':')?;
+            let position = |name: &str| sheet_names.iter().position(|x| x == name);
+            (position(first)? > position(last)?).then(|| join_3d_address(last, first, local))
+        };
+        for sheet in &mut self.work_sheet_collection {
+            sheet.replace_operands(replacer);
+        }
+        for defined_name in &mut self.defined_names {
+            defined_name.replace_operands(replacer);
+        }
+        Ok(())
+    }
+
+    /// Get the index of a worksheet.
+    /// # Arguments
+    /// * `sheet_name` - Sheet name. ex) "Sheet1"
+    #[inline]
+    #[must_use]
+    pub fn sheet_index(&self, sheet_name: &str) -> Option<usize> {
+        self.find_sheet_index_by_name(sheet_name).ok()
+    }
+
+    /// Hide a worksheet. It can be shown again from Excel.
+    /// When the sheet is active, the next visible sheet becomes active.
+    /// # Arguments
+    /// * `sheet_name` - Sheet name. ex) "Sheet1"
+    /// # Errors
+    /// * `NotFound` - the sheet does not exist.
+    /// * `InvalidArgument` - the sheet is the last visible sheet.
+    #[inline]
+    pub fn hide_sheet(&mut self, sheet_name: &str) -> Result<(), XlsxError> {
+        self.set_sheet_visibility(sheet_name, SheetStateValues::Hidden)
+    }
+
+    /// Hide a worksheet so that it can be shown again only by a macro or a
+    /// program. When the sheet is active, the next visible sheet becomes
+    /// active.
+    /// # Arguments
+    /// * `sheet_name` - Sheet name. ex) "Sheet1"
+    /// # Errors
+    /// * `NotFound` - the sheet does not exist.
+    /// * `InvalidArgument` - the sheet is the last visible sheet.
+    #[inline]
+    pub fn very_hide_sheet(&mut self, sheet_name: &str) -> Result<(), XlsxError> {
+        self.set_sheet_visibility(sheet_name, SheetStateValues::VeryHidden)
+    }
+
+    /// Show a hidden worksheet.
+    /// # Arguments
+    /// * `sheet_name` - Sheet name. ex) "Sheet1"
+    /// # Errors
+    /// * `NotFound` - the sheet does not exist.
+    #[inline]
+    pub fn unhide_sheet(&mut self, sheet_name: &str) -> Result<(), XlsxError> {
+        self.set_sheet_visibility(sheet_name, SheetStateValues::Visible)
+    }
+
+    fn set_sheet_visibility(
+        &mut self,
+        sheet_name: &str,
+        state: SheetStateValues,
+    ) -> Result<(), XlsxError> {
+        let index = self.find_sheet_index_by_name(sheet_name)?;
+        let is_visible = |sheet: &Worksheet| matches!(sheet.state(), SheetStateValues::Visible);
+        if matches!(state, SheetStateValues::Visible) {
+            self.work_sheet_collection[index].set_state(state);
+            return Ok(());
+        }
+        let visible: Vec<usize> = self
+            .work_sheet_collection
+            .iter()
+            .enumerate()
+            .filter(|(i, sheet)| *i != index && is_visible(sheet))
+            .map(|(i, _)| i)
+            .collect();
+        if visible.is_empty() {
+            return Err(XlsxError::InvalidArgument(format!(
+                "\"{sheet_name}\" is the last visible sheet"
+            )));
+        }
+        self.work_sheet_collection[index].set_state(state);
+        if self.workbook_view.active_tab() as usize == index {
+            let active = visible
+                .iter()
+                .find(|i| **i > index)
+                .or_else(|| visible.last())
+                .unwrap();
+            self.workbook_view
+                .set_active_tab(num_traits::cast(*active).unwrap());
+        }
+        Ok(())
+    }
+
     /// Add New Work Sheet.
     /// # Arguments
     /// * `sheet_title` - sheet title
@@ -763,6 +1427,7 @@ impl Workbook {
     }
 
     /// Set Sheet Name.
+    /// References to the sheet are rewritten as in [`Self::rename_sheet`].
     /// # Arguments
     /// * `index` - target sheet index
     /// * `sheet_name` - sheet name
@@ -773,14 +1438,81 @@ impl Workbook {
         index: usize,
         sheet_name: S,
     ) -> Result<(), XlsxError> {
-        let sheet_name_str = sheet_name.into();
-        Workbook::check_sheet_name(self, sheet_name_str.as_ref())?;
-        self.work_sheet_collection
-            .get_mut(index)
-            .map(|sheet| {
-                sheet.set_name(sheet_name_str);
-            })
-            .ok_or(XlsxError::NotFound())
+        let old_name = self
+            .work_sheet_collection
+            .get(index)
+            .ok_or(XlsxError::NotFound())?
+            .name()
+            .to_string();
+        self.rename_sheet(&old_name, &sheet_name.into())
+    }
+
+    /// Rename a worksheet and rewrite the references to it.
+    /// Cell formulas, chart series, data validations, conditional formats,
+    /// defined names, internal hyperlinks and pivot table sources of every
+    /// sheet are updated, quoting the new name where a formula needs it.
+    /// # Arguments
+    /// * `sheet_name` - Current sheet name. ex) "Sheet1"
+    /// * `new_name` - New sheet name. ex) "Sales 2024"
+    /// # Errors
+    /// * `NotFound` - there is no sheet named `sheet_name`.
+    /// * `NameDuplicate` - another sheet is already named `new_name`.
+    /// * `InvalidArgument` - `new_name` is not a valid sheet name.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// book.new_sheet("Sheet2").unwrap();
+    /// book.sheet_by_name_mut("Sheet2")
+    ///     .unwrap()
+    ///     .cell_mut("A1")
+    ///     .set_formula("Sheet1!B2*2");
+    /// book.rename_sheet("Sheet1", "Bob's Data").unwrap();
+    /// let formula = book.sheet_by_name("Sheet2").unwrap().cell("A1").unwrap().formula();
+    /// assert_eq!(formula, "'Bob''s Data'!B2*2");
+    /// ```
+    pub fn rename_sheet(&mut self, sheet_name: &str, new_name: &str) -> Result<(), XlsxError> {
+        let index = self.find_sheet_index_by_name(sheet_name)?;
+        if sheet_name == new_name {
+            return Ok(());
+        }
+        validate_sheet_name(new_name)?;
+        if self
+            .work_sheet_collection
+            .iter()
+            .enumerate()
+            .any(|(i, sheet)| i != index && sheet.name().to_lowercase() == new_name.to_lowercase())
+        {
+            return Err(XlsxError::NameDuplicate());
+        }
+        self.read_sheet_collection()?;
+        self.work_sheet_collection[index].set_name(new_name);
+
+        let old_name = sheet_name.to_lowercase();
+        let rename = |name: &str| -> String {
+            if name.to_lowercase() == old_name {
+                new_name.to_string()
+            } else {
+                name.to_string()
+            }
+        };
+        let replacer = |operand: &str| -> Option<String> {
+            let (Some(sheet), local) = split_reference(operand) else {
+                return None;
+            };
+            if let Some((first, last)) = sheet.split_once(':') {
+                let (new_first, new_last) = (rename(first), rename(last));
+                return (new_first != first || new_last != last)
+                    .then(|| join_3d_address(&new_first, &new_last, local));
+            }
+            (sheet.to_lowercase() == old_name).then(|| join_address(new_name, local))
+        };
+        for sheet in &mut self.work_sheet_collection {
+            sheet.replace_operands(replacer);
+        }
+        for defined_name in &mut self.defined_names {
+            defined_name.replace_operands(replacer);
+        }
+        Ok(())
     }
 
     /// (This method is crate only.)
@@ -875,6 +1607,64 @@ impl Workbook {
         self
     }
 
+    /// (This method is crate only.)
+    /// Content types registered by file extension in the file that was read.
+    #[inline]
+    pub(crate) fn backup_default_types(&self) -> &[(Box<str>, Box<str>)] {
+        &self.backup_default_types
+    }
+
+    #[inline]
+    pub(crate) fn set_backup_default_types(
+        &mut self,
+        value: impl Into<Vec<(String, String)>>,
+    ) -> &mut Self {
+        self.backup_default_types = value
+            .into()
+            .into_iter()
+            .map(|(a, b)| (a.into_boxed_str(), b.into_boxed_str()))
+            .collect();
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Workbook XML and workbook parts that are not modeled.
+    #[inline]
+    pub(crate) fn raw_unknown(&self) -> &RawUnknown {
+        &self.raw_unknown
+    }
+
+    #[inline]
+    pub(crate) fn raw_unknown_mut(&mut self) -> &mut RawUnknown {
+        &mut self.raw_unknown
+    }
+
+    /// (This method is crate only.)
+    /// Package parts related from the package root that are not modeled.
+    #[inline]
+    pub(crate) fn raw_package_unknown(&self) -> &RawUnknown {
+        &self.raw_package_unknown
+    }
+
+    #[inline]
+    pub(crate) fn raw_package_unknown_mut(&mut self) -> &mut RawUnknown {
+        &mut self.raw_package_unknown
+    }
+
+    /// (This method is crate only.)
+    /// Limits the workbook was read with. Also applied to worksheets
+    /// deserialized after reading.
+    #[inline]
+    pub(crate) fn read_limits(&self) -> &ReadLimits {
+        &self.read_limits
+    }
+
+    #[inline]
+    pub(crate) fn set_read_limits(&mut self, value: ReadLimits) -> &mut Self {
+        self.read_limits = value;
+        self
+    }
+
     #[must_use]
     pub fn pivot_caches(&self) -> Vec<(String, String, String)> {
         let mut result: Vec<(String, String, String)> = Vec::new();
@@ -1011,6 +1801,14 @@ impl Workbook {
         self.defined_names = value.into();
     }
 
+    /// Get the manager of the defined names of the workbook and its
+    /// worksheets, with lookup by name and scope, validation, renaming and
+    /// the print area and print titles.
+    #[inline]
+    pub fn defined_names_manager(&mut self) -> DefinedNames<'_> {
+        DefinedNames::new(self)
+    }
+
     /// Add Defined Name.
     /// # Arguments
     /// * `value` - `DefinedName`.
@@ -1019,6 +1817,680 @@ impl Workbook {
         self.defined_names.push(value);
     }
 
+    /// Get the cells a defined name refers to.
+    /// Multi-area names, names referring to other names and dynamic ranges
+    /// built with `OFFSET` or `INDEX` are resolved.
+    /// Whole columns and whole rows stop at the last used row and column.
+    /// # Arguments
+    /// * `name` - Name. ex) "Products", "'My Sheet'!Products"
+    /// * `scope` - Sheet name the name is seen from, `None` for the workbook.
+    ///   A name of this sheet hides a workbook name of the same name.
+    /// # Return value
+    /// * `Result<Vec<(String, Coordinate)>, XlsxError>` - Sheet names and
+    ///   coordinates of the cells.
+    /// # Errors
+    /// * `NotFound` - the name or a sheet does not exist.
+    /// * `NotDeserialized` - a sheet has not been read.
+    /// * `InvalidArgument` - the name is not a reference, can not be
+    ///   evaluated or refers to more than 1,048,576 cells, the cells of a
+    ///   whole column.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// book.defined_names_manager()
+    ///     .add("Products", "OFFSET(Sheet1!$A$1,0,0,COUNTA(Sheet1!$A:$A),1)", None)
+    ///     .unwrap();
+    /// book.sheet_mut(0).unwrap().cell_mut("A1").set_value("Apple");
+    /// book.sheet_mut(0).unwrap().cell_mut("A2").set_value("Pear");
+    /// let cells = book.resolve_name("Products", None).unwrap();
+    /// assert_eq!(cells.len(), 2);
+    /// assert_eq!(cells[1].0, "Sheet1");
+    /// assert_eq!(cells[1].1.to_string(), "A2");
+    /// ```
+    pub fn resolve_name(
+        &self,
+        name: &str,
+        scope: Option<&str>,
+    ) -> Result<Vec<(String, Coordinate)>, XlsxError> {
+        let scope = scope
+            .map(|sheet_name| self.find_sheet_index_by_name(sheet_name))
+            .transpose()?;
+        let NameValue::Areas(areas) = resolve_defined_name(self, name, scope)? else {
+            return Err(XlsxError::InvalidArgument(format!(
+                "\"{name}\" does not refer to cells"
+            )));
+        };
+        let mut count = 0;
+        for area in &areas {
+            count += area.cell_count(self.sheet_by_name(&area.sheet_name)?);
+        }
+        if count > MAX_AREA_CELLS {
+            return Err(XlsxError::InvalidArgument(format!(
+                "\"{name}\" refers to more than {MAX_AREA_CELLS} cells"
+            )));
+        }
+        let mut result = Vec::new();
+        for area in areas {
+            let worksheet = self.sheet_by_name(&area.sheet_name)?;
+            for (col, row) in area.coordinates(worksheet) {
+                let mut coordinate = Coordinate::default();
+                coordinate.set_col_num(col).set_row_num(row);
+                result.push((area.sheet_name.clone(), coordinate));
+            }
+        }
+        Ok(result)
+    }
+
+    /// Get the values of a defined name.
+    /// The values of the cells the name refers to, in the order of
+    /// `resolve_name`, or the constant of a name such as `0.2`.
+    /// # Arguments
+    /// * `name` - Name. ex) "`TaxRate`", "'My Sheet'!`TaxRate`"
+    /// # Errors
+    /// Same as `resolve_name`.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// book.defined_names_manager().add("TaxRate", "0.2", None).unwrap();
+    /// let values = book.name_value("TaxRate").unwrap();
+    /// assert_eq!(values[0].value_number(), Some(0.2));
+    /// ```
+    pub fn name_value(&self, name: &str) -> Result<Vec<CellValue>, XlsxError> {
+        if let Some(value) = resolve_defined_name(self, name, None)?.constant() {
+            return Ok(vec![value]);
+        }
+        Ok(self
+            .resolve_name(name, None)?
+            .into_iter()
+            .map(|(sheet_name, coordinate)| {
+                self.sheet_by_name(&sheet_name)
+                    .unwrap()
+                    .cell_value((coordinate.col_num(), coordinate.row_num()))
+                    .clone()
+            })
+            .collect())
+    }
+
+    /// Get the graph of the references between the formulas of the cells and
+    /// defined names, to find what feeds a cell and what it feeds.
+    /// # Return value
+    /// * `Result<DependencyGraph, XlsxError>`
+    /// # Errors
+    /// * `NotDeserialized` - a sheet has not been read.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::DependencyNode;
+    ///
+    /// let mut book = umya_spreadsheet::new_file();
+    /// book.sheet_mut(0).unwrap().cell_mut("B1").set_formula("A1+1");
+    /// let graph = book.dependency_graph().unwrap();
+    /// let precedents = graph.precedents(&DependencyNode::cell("Sheet1", "B1"));
+    /// assert_eq!(precedents[0].to_string(), "Sheet1!A1");
+    /// ```
+    #[inline]
+    pub fn dependency_graph(&self) -> Result<DependencyGraph, XlsxError> {
+        DependencyGraph::new(self)
+    }
+
+    /// Find the value of a cell that makes a formula reach a target value, as
+    /// Goal Seek does in Excel. The value found is set to `changing_cell`
+    /// and the result of the formula to `set_cell`.
+    ///
+    /// The formulas are calculated by the crate: arithmetic, comparison and
+    /// text operators, references, defined names and common functions such
+    /// as `SUM`, `IF`, `ROUND` and `PMT`.
+    /// # Arguments
+    /// * `set_cell` - The cell with the formula. ex) "B4", "Sheet1!B4".
+    ///   Without a sheet name, the cell is on the active sheet.
+    /// * `target_value` - The value the formula must reach.
+    /// * `changing_cell` - The cell with the value to change. ex) "B3"
+    /// # Return value
+    /// * `Result<f64, XlsxError>` - The value set to `changing_cell`.
+    /// # Errors
+    /// * `NotFound` - a sheet does not exist.
+    /// * `NotDeserialized` - a sheet has not been read.
+    /// * `InvalidArgument` - `set_cell` has no formula, `changing_cell` does
+    ///   not hold a number, a formula can not be calculated or no value
+    ///   reaches the target.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let sheet = book.sheet_mut(0).unwrap();
+    /// sheet.cell_mut("B1").set_value_number(10);
+    /// sheet.cell_mut("B2").set_formula("B1*B1+1");
+    /// let value = book.goal_seek("B2", 50.0, "B1").unwrap();
+    /// assert!((value - 7.0).abs() < 1e-6);
+    /// ```
+    pub fn goal_seek(
+        &mut self,
+        set_cell: &str,
+        target_value: f64,
+        changing_cell: &str,
+    ) -> Result<f64, XlsxError> {
+        const MAX_ITERATIONS: usize = 100;
+
+        if self
+            .work_sheet_collection
+            .iter()
+            .any(|sheet| !sheet.is_deserialized())
+        {
+            return Err(XlsxError::NotDeserialized());
+        }
+        let (set_sheet, set_col, set_row) = self.goal_seek_cell(set_cell)?;
+        let (sheet_index, col, row) = self.goal_seek_cell(changing_cell)?;
+        let worksheet = &self.work_sheet_collection[set_sheet];
+        if worksheet
+            .cell((set_col, set_row))
+            .is_none_or(|cell| cell.formula().is_empty())
+        {
+            return Err(XlsxError::InvalidArgument(format!(
+                "{set_cell} does not contain a formula"
+            )));
+        }
+        let start = match self.work_sheet_collection[sheet_index].cell((col, row)) {
+            None => 0.0,
+            Some(cell) if cell.formula().is_empty() && cell.cell_value().is_empty() => 0.0,
+            Some(cell) if cell.formula().is_empty() => {
+                cell.cell_value().value_number().ok_or_else(|| {
+                    XlsxError::InvalidArgument(format!("{changing_cell} does not hold a number"))
+                })?
+            }
+            Some(_) => {
+                return Err(XlsxError::InvalidArgument(format!(
+                    "{changing_cell} must hold a value, not a formula"
+                )));
+            }
+        };
+
+        let tolerance = 1e-9 * target_value.abs().max(1.0);
+        let mut calculator = Calculator::new(self);
+        let mut difference = |value: f64| -> Result<Option<f64>, XlsxError> {
+            calculator.set_input(sheet_index, col, row, value);
+            Ok(calculator
+                .cell(set_sheet, set_col, set_row)?
+                .number()
+                .ok()
+                .map(|result| result - target_value))
+        };
+
+        // Secant method, halving the step back when the formula fails.
+        let mut x0 = start;
+        let Some(mut f0) = difference(x0)? else {
+            return Err(XlsxError::InvalidArgument(format!(
+                "{set_cell} does not calculate to a number"
+            )));
+        };
+        let mut found = (f0.abs() <= tolerance).then_some(x0);
+        let mut x1 = if x0 == 0.0 { 0.01 } else { x0 * 1.01 };
+        for _ in 0..MAX_ITERATIONS {
+            if found.is_some() {
+                break;
+            }
+            let Some(f1) = difference(x1)? else {
+                x1 = f64::midpoint(x0, x1);
+                continue;
+            };
+            if f1.abs() <= tolerance {
+                found = Some(x1);
+                break;
+            }
+            let slope = (f1 - f0) / (x1 - x0);
+            if slope == 0.0 || !slope.is_finite() {
+                break;
+            }
+            (x0, f0, x1) = (x1, f1, x1 - f1 / slope);
+            if !x1.is_finite() {
+                break;
+            }
+        }
+        let value = found.ok_or_else(|| {
+            XlsxError::InvalidArgument(format!(
+                "no value of {changing_cell} makes {set_cell} reach {target_value}"
+            ))
+        })?;
+
+        let result = difference(value)?.unwrap_or_default() + target_value;
+        self.work_sheet_collection[sheet_index]
+            .cell_mut((col, row))
+            .set_value_number(value);
+        self.work_sheet_collection[set_sheet]
+            .cell_mut((set_col, set_row))
+            .cell_value_mut()
+            .set_formula_result_number(result);
+        Ok(value)
+    }
+
+    /// Sheet index, column and row of a cell given to `goal_seek`.
+    fn goal_seek_cell(&self, reference: &str) -> Result<(usize, u32, u32), XlsxError> {
+        let (sheet_name, local) = split_reference(reference);
+        let sheet_index = match sheet_name {
+            Some(sheet_name) => self.find_sheet_index_by_name(&sheet_name)?,
+            None => self.workbook_view().active_tab() as usize,
+        };
+        if sheet_index >= self.work_sheet_collection.len() {
+            return Err(XlsxError::NotFound());
+        }
+        match index_from_coordinate(local.to_uppercase()) {
+            (Some(col), Some(row), ..) => Ok((sheet_index, col, row)),
+            _ => Err(XlsxError::InvalidArgument(format!(
+                "{reference} is not a cell"
+            ))),
+        }
+    }
+
+    /// Calculate the results of a data table added with
+    /// `Worksheet::add_data_table`, as Excel does when it opens the file.
+    ///
+    /// The formulas of the table are calculated with each input value
+    /// substituted for the input cells, and the results are stored in the
+    /// result cells. Only the formulas goal seek can calculate are supported.
+    /// # Arguments
+    /// * `range` - The whole table, as given to `add_data_table`. The active
+    ///   sheet is used when no sheet is given. ex) "A3:B6", "Sheet1!A3:B6"
+    /// # Errors
+    /// * `NotFound` - the sheet does not exist.
+    /// * `NotDeserialized` - a sheet has not been read.
+    /// * `InvalidArgument` - `range` is not a data table, an input value is
+    ///   not a number, or a formula can not be calculated.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let sheet = book.sheet_mut(0).unwrap();
+    /// sheet.cell_mut("B1").set_value_number(0.05);
+    /// sheet.cell_mut("B3").set_formula("1000*B1");
+    /// sheet.cell_mut("A4").set_value_number(0.04);
+    /// sheet.cell_mut("A5").set_value_number(0.06);
+    /// sheet.add_data_table("A3:B5", None, Some("B1")).unwrap();
+    /// book.calculate_data_table("A3:B5").unwrap();
+    /// let sheet = book.sheet(0).unwrap();
+    /// assert_eq!(sheet.value("B4"), "40");
+    /// assert_eq!(sheet.value("B5"), "60");
+    /// ```
+    pub fn calculate_data_table(&mut self, range: &str) -> Result<(), XlsxError> {
+        if self
+            .work_sheet_collection
+            .iter()
+            .any(|sheet| !sheet.is_deserialized())
+        {
+            return Err(XlsxError::NotDeserialized());
+        }
+        let (sheet_name, local) = split_reference(range);
+        let sheet_index = match sheet_name {
+            Some(sheet_name) => self.find_sheet_index_by_name(&sheet_name)?,
+            None => self.workbook_view().active_tab() as usize,
+        };
+        let worksheet = self
+            .work_sheet_collection
+            .get(sheet_index)
+            .ok_or(XlsxError::NotFound())?;
+        let not_a_table = || XlsxError::InvalidArgument(format!("{range} is not a data table"));
+        let (row_start, row_end, col_start, col_end) =
+            cell_range_bounds(local).ok_or_else(not_a_table)?;
+        let results = (row_start + 1, row_end, col_start + 1, col_end);
+        let formula = worksheet
+            .cell((results.2, results.0))
+            .and_then(|cell| cell.cell_value().formula_obj())
+            .filter(|formula| {
+                formula.formula_type() == &CellFormulaValues::DataTable
+                    && cell_range_bounds(formula.reference()) == Some(results)
+            })
+            .ok_or_else(not_a_table)?;
+        let input_cell = |coordinate: &str| match index_from_coordinate(coordinate) {
+            (Some(col), Some(row), ..) => Ok((col, row)),
+            _ => Err(not_a_table()),
+        };
+        let (row_input, col_input) = match (formula.data_table_2d(), formula.data_table_row()) {
+            (true, _) => (Some(input_cell(formula.r1())?), Some(input_cell(formula.r2())?)),
+            (false, true) => (Some(input_cell(formula.r1())?), None),
+            (false, false) => (None, Some(input_cell(formula.r1())?)),
+        };
+
+        let mut calculator = Calculator::new(self);
+        let mut input_value = |col: u32, row: u32| {
+            calculator.cell(sheet_index, col, row)?.number().map_err(|_| {
+                XlsxError::InvalidArgument(format!(
+                    "{} does not hold a number",
+                    coordinate_from_index(col, row)
+                ))
+            })
+        };
+        let row_values = (results.2..=results.3)
+            .map(|col| input_value(col, row_start))
+            .collect::<Result<Vec<f64>, XlsxError>>()?;
+        let col_values = (results.0..=results.1)
+            .map(|row| input_value(col_start, row))
+            .collect::<Result<Vec<f64>, XlsxError>>()?;
+
+        let mut values = Vec::new();
+        for (row, col_value) in (results.0..=results.1).zip(&col_values) {
+            for (col, row_value) in (results.2..=results.3).zip(&row_values) {
+                // A two-variable table has its formula in the corner, a
+                // one-variable table one formula per row or column.
+                let formula_cell = match (row_input, col_input) {
+                    (Some(_), Some(_)) => (col_start, row_start),
+                    (Some(_), None) => (col_start, row),
+                    _ => (col, row_start),
+                };
+                if let Some((input_col, input_row)) = row_input {
+                    calculator.set_input(sheet_index, input_col, input_row, *row_value);
+                }
+                if let Some((input_col, input_row)) = col_input {
+                    calculator.set_input(sheet_index, input_col, input_row, *col_value);
+                }
+                let value = calculator.cell(sheet_index, formula_cell.0, formula_cell.1)?;
+                values.push((col, row, value.scalar()));
+            }
+        }
+
+        let worksheet = &mut self.work_sheet_collection[sheet_index];
+        for (col, row, value) in values {
+            let cell_value = worksheet.cell_mut((col, row)).cell_value_mut();
+            match value {
+                Value::Text(v) => cell_value.set_formula_result_string(v),
+                Value::Boolean(v) => cell_value.set_formula_result_bool(v),
+                Value::Error(v) => cell_value.set_formula_result_error(
+                    v.parse().unwrap_or(CellErrorType::Value),
+                ),
+                v => cell_value.set_formula_result_number(v.number().unwrap_or_default()),
+            };
+        }
+        Ok(())
+    }
+
+    /// Set a formula to a cell after checking it, where `set_formula`
+    /// accepts any text.
+    ///
+    /// The formula must have balanced parentheses, quotes and operators, call
+    /// functions Excel has with a number of arguments they accept, and refer
+    /// to existing sheets and cells or to defined names. Functions newer than
+    /// the file format, such as `XLOOKUP`, `IFS` or `CONCAT`, get the
+    /// `_xlfn.` prefix Excel stores them with, the parameters of `LET` and
+    /// `LAMBDA` get `_xlpm.`, and `@` and `A1#` are stored as `_xlfn.SINGLE`
+    /// and `_xlfn.ANCHORARRAY`.
+    /// # Arguments
+    /// * `sheet_name` - The sheet of the cell.
+    /// * `coordinate` - The cell. ex) "B4", (2, 4)
+    /// * `formula` - ex) "SUM(A1:A3)", "=XLOOKUP(A1,Sheet2!A:A,Sheet2!B:B)"
+    /// # Errors
+    /// * `NotFound` - the sheet does not exist.
+    /// * `InvalidArgument` - the formula is not valid, with the reason.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// book.set_formula_checked("Sheet1", "B1", "IFS(A1>0,1,TRUE,0)")
+    ///     .unwrap();
+    /// let sheet = book.sheet_by_name("Sheet1").unwrap();
+    /// assert_eq!(sheet.cell("B1").unwrap().formula(), "_xlfn.IFS(A1>0,1,TRUE,0)");
+    ///
+    /// book.set_formula_checked("Sheet1", "B2", "LET(x,A1,x*2)").unwrap();
+    /// let sheet = book.sheet_by_name("Sheet1").unwrap();
+    /// assert_eq!(sheet.cell("B2").unwrap().formula(), "_xlfn.LET(_xlpm.x,A1,_xlpm.x*2)");
+    ///
+    /// assert!(book.set_formula_checked("Sheet1", "B2", "SUM(A1:A3").is_err());
+    /// assert!(book.set_formula_checked("Sheet1", "B2", "SUMM(A1:A3)").is_err());
+    /// assert!(book.set_formula_checked("Sheet1", "B2", "ROUND(A1)").is_err());
+    /// assert!(book.set_formula_checked("Sheet1", "B2", "Sheet9!A1").is_err());
+    /// ```
+    pub fn set_formula_checked<T>(
+        &mut self,
+        sheet_name: &str,
+        coordinate: T,
+        formula: &str,
+    ) -> Result<(), XlsxError>
+    where
+        T: Into<CellCoordinates>,
+    {
+        let sheet_index = self.find_sheet_index_by_name(sheet_name)?;
+        let find_sheet = |name: &str| {
+            self.work_sheet_collection
+                .iter()
+                .position(|sheet| sheet.name().eq_ignore_ascii_case(name))
+        };
+        let formula = check_formula(
+            formula,
+            |name| find_sheet(name).is_some(),
+            |sheet_name, name| {
+                let scope = match sheet_name {
+                    Some(sheet_name) => find_sheet(sheet_name),
+                    None => Some(sheet_index),
+                };
+                find_defined_name(self, name, scope).is_some()
+            },
+        )?;
+        self.sheet_mut(sheet_index)?
+            .cell_mut(coordinate)
+            .set_formula(formula);
+        Ok(())
+    }
+
+    /// Get the cell metadata of the workbook, which marks dynamic array
+    /// formulas.
+    #[inline]
+    #[must_use]
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    #[inline]
+    pub fn metadata_mut(&mut self) -> &mut Metadata {
+        &mut self.metadata
+    }
+
+    #[inline]
+    pub fn set_metadata(&mut self, value: Metadata) -> &mut Self {
+        self.metadata = value;
+        self
+    }
+
+    /// Get the links to other workbooks.
+    /// Formulas refer to the n-th link as `[n]`. ex) `[1]Sheet1!A1`
+    #[inline]
+    #[must_use]
+    pub fn external_links(&self) -> &[ExternalLink] {
+        &self.external_links
+    }
+
+    #[inline]
+    pub fn external_links_mut(&mut self) -> &mut Vec<ExternalLink> {
+        &mut self.external_links
+    }
+
+    /// Add a link to another workbook.
+    /// Formulas refer to it with the number of links the workbook has after
+    /// adding it. ex) `[1]Sheet1!A1` for the first link
+    #[inline]
+    pub fn add_external_link(&mut self, value: ExternalLink) -> &mut Self {
+        self.external_links.push(value);
+        self
+    }
+
+    /// Point the links to a workbook to another file.
+    /// # Arguments
+    /// * `target` - Current path of the linked workbook. ex) "Budget.xlsx"
+    /// * `new_target` - New path of it. ex) "Budget 2025.xlsx"
+    /// # Return value
+    /// * `Result<(), XlsxError>` - `NotFound` when no link has `target`.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::ExternalLink;
+    ///
+    /// let mut book = umya_spreadsheet::new_file();
+    /// book.add_external_link(ExternalLink::new("Budget.xlsx"));
+    /// book.retarget_external_link("Budget.xlsx", "Budget 2025.xlsx").unwrap();
+    /// assert_eq!(book.external_links()[0].target(), "Budget 2025.xlsx");
+    /// ```
+    pub fn retarget_external_link(
+        &mut self,
+        target: &str,
+        new_target: &str,
+    ) -> Result<(), XlsxError> {
+        let mut is_found = false;
+        for external_link in &mut self.external_links {
+            if external_link.target() == target {
+                external_link.set_target(new_target);
+                is_found = true;
+            }
+        }
+        if is_found {
+            Ok(())
+        } else {
+            Err(XlsxError::NotFound())
+        }
+    }
+
+    /// Remove the link to a workbook, as Excel's Break Link does.
+    /// Cell formulas using the link are replaced with their values. A formula
+    /// that is only a reference to a cell of the linked workbook without a
+    /// value of its own gets the value cached with the link.
+    /// Defined names and the other formulas of the sheets using the link,
+    /// such as those of data validations, conditional formats, charts and
+    /// table columns, refer to the cached value instead, or to `#REF!` when
+    /// there is none.
+    /// # Arguments
+    /// * `target` - Path of the linked workbook. ex) "Budget.xlsx"
+    /// # Return value
+    /// * `Result<(), XlsxError>` - `NotFound` when no link has `target`.
+    pub fn break_external_link(&mut self, target: &str) -> Result<(), XlsxError> {
+        let index = self
+            .external_links
+            .iter()
+            .position(|external_link| external_link.target() == target)
+            .ok_or(XlsxError::NotFound())?;
+        self.read_sheet_collection()?;
+        let external_link = self.external_links.remove(index);
+        let number = index + 1;
+
+        let is_link_operand = |operand: &str| {
+            split_external_book(operand).is_some_and(|(book_number, _)| book_number == number)
+        };
+        let uses_link = |formula: &str| {
+            let mut result = false;
+            replace_range_operands(formula, |operand| {
+                result |= is_link_operand(operand);
+                None
+            });
+            result
+        };
+        let cached_value = |operand: &str| -> Option<CellRawValue> {
+            let (_, local) = split_external_book(operand)?;
+            let (Some(sheet_name), coordinate) = split_reference(&local) else {
+                return None;
+            };
+            external_link
+                .cached_value(&sheet_name, coordinate)
+                .cloned()
+        };
+
+        for worksheet in &mut self.work_sheet_collection {
+            for cell in worksheet.cells_mut() {
+                if !uses_link(cell.formula()) {
+                    continue;
+                }
+                let value = if cell.cell_value().is_value_empty() {
+                    let operand = cell.formula().trim().to_string();
+                    let tokens = parse_to_tokens(format!("={operand}"));
+                    if tokens.len() == 1 { cached_value(&operand) } else { None }
+                } else {
+                    None
+                };
+                let cell_value = cell.cell_value_mut();
+                cell_value.remove_formula();
+                if let Some(v) = value {
+                    cell_value.raw_value = v;
+                }
+            }
+        }
+
+        let constant = |operand: &str| -> Option<String> {
+            if !is_link_operand(operand) {
+                return None;
+            }
+            let value = match cached_value(operand) {
+                Some(CellRawValue::Numeric(v)) => v.to_string(),
+                Some(CellRawValue::Bool(v)) => if v { "TRUE" } else { "FALSE" }.to_string(),
+                Some(CellRawValue::Error(v)) => v.to_string(),
+                Some(CellRawValue::String(v)) => format!("\"{}\"", v.replace('"', "\"\"")),
+                _ => "#REF!".to_string(),
+            };
+            Some(value)
+        };
+        let defined_names = self.defined_names.iter_mut().chain(
+            self.work_sheet_collection
+                .iter_mut()
+                .flat_map(|worksheet| worksheet.defined_names_mut().iter_mut()),
+        );
+        for defined_name in defined_names {
+            let address = defined_name.address();
+            if uses_link(&address) {
+                defined_name.set_string_value(replace_range_operands(&address, constant));
+            }
+        }
+
+        // The other formulas of the sheets get the cached value too, and the
+        // links after the removed one are renumbered.
+        let renumber = |operand: &str| -> Option<String> {
+            let (book_number, local) = split_external_book(operand)?;
+            (book_number > number).then(|| join_external_book(book_number - 1, &local))
+        };
+        for worksheet in &mut self.work_sheet_collection {
+            worksheet.replace_operands(|operand| constant(operand).or_else(|| renumber(operand)));
+        }
+        for defined_name in &mut self.defined_names {
+            defined_name.replace_operands(renumber);
+        }
+        Ok(())
+    }
+
+    /// Get the persons of threaded comments.
+    /// Authors added with `Worksheet::add_threaded_comment` are merged into
+    /// this list when the workbook is written.
+    #[inline]
+    #[must_use]
+    pub fn person_list(&self) -> &[Person] {
+        &self.person_list
+    }
+
+    #[inline]
+    pub fn person_list_mut(&mut self) -> &mut Vec<Person> {
+        &mut self.person_list
+    }
+
+    /// Add a `Person`.
+    /// A person with the same id is not added twice.
+    /// # Arguments
+    /// * `value` - `Person`.
+    #[inline]
+    pub fn add_person(&mut self, value: Person) {
+        if !self.person_list.iter().any(|v| v.id() == value.id()) {
+            self.person_list.push(value);
+        }
+    }
+
+    /// Find a `Person` by display name.
+    #[inline]
+    #[must_use]
+    pub fn person_by_name(&self, display_name: &str) -> Option<&Person> {
+        self.person_list
+            .iter()
+            .chain(self.work_sheet_collection.iter().flat_map(Worksheet::person_list))
+            .find(|v| v.display_name() == display_name)
+    }
+
+    // Persons of the workbook together with the ones registered by the sheets.
+    pub(crate) fn merged_person_list(&self) -> Vec<&Person> {
+        let mut result: Vec<&Person> = Vec::new();
+        let sheet_persons = self
+            .work_sheet_collection
+            .iter()
+            .flat_map(Worksheet::person_list);
+        for person in self.person_list.iter().chain(sheet_persons) {
+            if !result.iter().any(|v| v.id() == person.id()) {
+                result.push(person);
+            }
+        }
+        result
+    }
+
     /// Has `ThreadedComments`.
     #[inline]
     #[must_use]
@@ -1040,7 +2512,6 @@ impl AdjustmentCoordinateWithSheet for Workbook {
         root_row_num: u32,
         offset_row_num: u32,
     ) {
-        self.read_sheet_collection();
         for worksheet in &mut self.work_sheet_collection {
             worksheet.adjustment_insert_coordinate(
                 root_col_num,
@@ -1066,7 +2537,6 @@ impl AdjustmentCoordinateWithSheet for Workbook {
         root_row_num: u32,
         offset_row_num: u32,
     ) {
-        self.read_sheet_collection();
         for worksheet in &mut self.work_sheet_collection {
             worksheet.adjustment_remove_coordinate(
                 root_col_num,
@@ -1084,3 +2554,46 @@ impl AdjustmentCoordinateWithSheet for Workbook {
         }
     }
 }
+
+/// Check the characters and the length Excel allows in a sheet name.
+fn validate_sheet_name(name: &str) -> Result<(), XlsxError> {
+    if name.is_empty() || name.chars().count() > 31 {
+        return Err(XlsxError::InvalidArgument(format!(
+            "sheet name must be 1 to 31 characters: {name}"
+        )));
+    }
+    if name.contains(['[', ']', ':', '*', '?', '/', '\\']) {
+        return Err(XlsxError::InvalidArgument(format!(
+            "sheet name must not contain []:*?/\\ : {name}"
+        )));
+    }
+    if name.starts_with('\'') || name.ends_with('\'') {
+        return Err(XlsxError::InvalidArgument(format!(
+            "sheet name must not begin or end with an apostrophe: {name}"
+        )));
+    }
+    Ok(())
+}
+
+/// `name`, or `Name_2`, `Name_3`, ... when `is_used` says `name` is taken.
+fn free_name<F>(name: &str, is_used: F) -> String
+where
+    F: Fn(&str) -> bool,
+{
+    if !is_used(name) {
+        return name.to_string();
+    }
+    (2..=usize::MAX)
+        .map(|no| format!("{name}_{no}"))
+        .find(|x| !is_used(x))
+        .unwrap()
+}
+
+/// Rename the table of a structured reference. ex) "Sales[Amount]"
+fn rename_table_reference(operand: &str, renames: &[(String, String)]) -> Option<String> {
+    let (table, specifier) = split_structured_reference(operand)?;
+    renames
+        .iter()
+        .find(|(old, _)| old.eq_ignore_ascii_case(table))
+        .map(|(_, new)| format!("{new}{specifier}"))
+}
@@ -4,14 +4,22 @@ use crate::{
     StringValue,
     helper::{
         const_str::PIVOT_CACHE_DEF_NS,
+        formula::{
+            parse_to_tokens,
+            replace_range_operands,
+            translate_formula,
+        },
+        utils::new_guid,
         coordinate::{
             CellCoordinates,
             column_index_from_string,
             coordinate_from_index,
+            index_from_coordinate,
             string_from_column_index,
         },
         range::{
             get_coordinate_list,
+            get_split_range,
             get_start_and_end_point,
         },
     },
@@ -19,6 +27,8 @@ use crate::{
     structs::{
         AutoFilter,
         Cell,
+        CellFormula,
+        CellFormulaValues,
         CellValue,
         Cells,
         Chart,
@@ -31,6 +41,7 @@ use crate::{
         DataValidations,
         DefinedName,
         EnumValue,
+        FormControl,
         HeaderFooter,
         Hyperlink,
         Image,
@@ -45,18 +56,36 @@ use crate::{
         Row,
         RowBreaks,
         Rows,
+        Scenarios,
         SharedStringTable,
         SheetFormatProperties,
         SheetProtection,
         SheetStateValues,
         SheetViews,
+        SortKey,
         Style,
         Stylesheet,
         Table,
+        TableColumn,
+        XlsxError,
+        auto_filter::evaluate_filter_columns,
         drawing::spreadsheet::WorksheetDrawing,
         office2010::excel::DataValidations as DataValidations2010,
-        office2019::threaded_comment::ThreadedComment,
-        raw::RawWorksheet,
+        office2019::{
+            mention::Mention,
+            person::Person,
+            threaded_comment::ThreadedComment,
+            threaded_comment_handle::ThreadedCommentHandle,
+        },
+        raw::{
+            RawUnknown,
+            RawWorksheet,
+        },
+        table::{
+            escape_structured_column,
+            split_structured_reference,
+        },
+        vml::spreadsheet::ObjectValues,
     },
     traits::{
         AdjustmentCoordinate,
@@ -88,10 +117,13 @@ pub struct Worksheet {
     auto_filter:                       Option<AutoFilter>,
     comments:                          Vec<Comment>,
     threaded_comments:                 Vec<ThreadedComment>,
+    person_list:                       Vec<Person>,
+    workbook_person_list:              Vec<Person>,
     active_cell:                       Box<str>,
     tab_color:                         Option<Color>,
     code_name:                         StringValue,
     ole_objects:                       OleObjects,
+    form_controls:                     Vec<FormControl>,
     defined_names:                     Vec<DefinedName>,
     print_options:                     PrintOptions,
     column_breaks:                     ColumnBreaks,
@@ -102,6 +134,8 @@ pub struct Worksheet {
     data_validations_2010:             Option<DataValidations2010>,
     sheet_format_properties:           SheetFormatProperties,
     sheet_protection:                  Option<SheetProtection>,
+    scenarios:                         Option<Scenarios>,
+    raw_unknown:                       RawUnknown,
 }
 
 impl Worksheet {
@@ -278,7 +312,7 @@ impl Worksheet {
         &self,
         shared_string_table: &SharedStringTable,
         stylesheet: &Stylesheet,
-    ) -> Cells {
+    ) -> Result<Cells, XlsxError> {
         assert!(!self.is_deserialized(), "This Worksheet is Deserialized.");
 
         read_lite(
@@ -293,7 +327,7 @@ impl Worksheet {
         &self,
         shared_string_table: &SharedStringTable,
         stylesheet: &Stylesheet,
-    ) -> Cells {
+    ) -> Result<Cells, XlsxError> {
         self.cells_stream(shared_string_table, stylesheet)
     }
 
@@ -459,9 +493,14 @@ impl Worksheet {
     }
 
     /// Remove Cell
+    /// A cell that is a part of an array formula over several cells is not
+    /// removed, as Excel does not allow changing a part of an array: use
+    /// `remove_array_formula` to remove the whole array.
     /// # Arguments
     /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)` or `(1,
     ///   1)`
+    /// # Return value
+    /// * `bool` - Whether the cell was removed.
     /// # Examples
     /// ```rust
     /// let mut book = umya_spreadsheet::new_file();
@@ -470,12 +509,14 @@ impl Worksheet {
     /// // or pass in a tuple `(col, row)`, both col and row starting at `1`
     /// worksheet.remove_cell((1, 1));
     /// ```
-    #[inline]
     pub fn remove_cell<T>(&mut self, coordinate: T) -> bool
     where
         T: Into<CellCoordinates>,
     {
         let CellCoordinates { col, row } = coordinate.into();
+        if self.cells.get((col, row)).is_none() || self.is_in_legacy_array_formula(col, row) {
+            return false;
+        }
         self.cells.remove(col, row)
     }
 
@@ -709,6 +750,454 @@ impl Worksheet {
         self
     }
 
+    /// Fill a range with a formula, as filling it down from its top-left cell
+    /// does: relative references are moved along with each cell and absolute
+    /// ones are kept.
+    /// The range is written as a single shared formula.
+    /// # Arguments
+    /// * `range` - Specify the range. ex) "C2:C1000"
+    /// * `formula` - Formula of the top-left cell, without the leading `=`.
+    ///   ex) "A2*$B$1"
+    /// # Return value
+    /// * `Result<(), XlsxError>` - `InvalidArgument` when `range` is not a
+    ///   range of cells, e.g. whole columns.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.sheet_mut(0).unwrap();
+    /// worksheet.fill_formula_down("C2:C4", "A2*$B$1").unwrap();
+    /// assert_eq!(worksheet.cell("C4").unwrap().formula(), "A4*$B$1");
+    /// ```
+    pub fn fill_formula_down(&mut self, range: &str, formula: &str) -> Result<(), XlsxError> {
+        let Some((row_start, row_end, col_start, col_end)) = cell_range_bounds(range) else {
+            return Err(XlsxError::InvalidArgument(format!(
+                "Not a range of cells: {range}"
+            )));
+        };
+        let shared_index = self
+            .cells
+            .iter_collection()
+            .filter_map(Cell::formula_shared_index)
+            .max()
+            .map_or(0, |v| v + 1);
+        let token_list = parse_to_tokens(format!("={formula}"));
+
+        for row_num in row_start..=row_end {
+            for col_num in col_start..=col_end {
+                let mut cell_formula = CellFormula::default();
+                cell_formula.set_formula_type(CellFormulaValues::Shared);
+                cell_formula.set_shared_index(shared_index);
+                if row_num == row_start && col_num == col_start {
+                    cell_formula.set_text(formula);
+                } else {
+                    let offset_col_num = num_traits::cast::<_, i32>(col_num - col_start).unwrap();
+                    let offset_row_num = num_traits::cast::<_, i32>(row_num - row_start).unwrap();
+                    cell_formula.set_text_view(translate_formula(
+                        &token_list,
+                        offset_col_num,
+                        offset_row_num,
+                    ));
+                }
+                self.cell_mut((col_num, row_num))
+                    .cell_value_mut()
+                    .set_formula_obj(cell_formula);
+            }
+        }
+        Ok(())
+    }
+
+    /// Set a dynamic array formula along with the result it spills.
+    /// The first value is the cached result of the formula cell, the others
+    /// are set to the cells it spills over.
+    /// # Arguments
+    /// * `coordinate` - Cell the formula is in. ex) "B1"
+    /// * `formula` - ex) "SORT(A1:A3)"
+    /// * `values` - Spilled result, row by row.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.sheet_mut(0).unwrap();
+    /// worksheet.set_dynamic_array_formula("B1", "SEQUENCE(3)", &[vec!["1"], vec!["2"], vec!["3"]]);
+    /// assert_eq!(worksheet.cell("B1").unwrap().formula_obj().unwrap().reference(), "B1:B3");
+    /// assert_eq!(worksheet.value("B3"), "3");
+    /// ```
+    pub fn set_dynamic_array_formula<T, S>(
+        &mut self,
+        coordinate: T,
+        formula: &str,
+        values: &[Vec<S>],
+    ) -> &mut Self
+    where
+        T: Into<CellCoordinates>,
+        S: AsRef<str>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        let row_count = num_traits::cast::<_, u32>(values.len().max(1)).unwrap();
+        let col_count =
+            num_traits::cast::<_, u32>(values.iter().map(Vec::len).max().unwrap_or(0).max(1))
+                .unwrap();
+        let spill_ref = format!(
+            "{}:{}",
+            coordinate_from_index(col, row),
+            coordinate_from_index(col + col_count - 1, row + row_count - 1)
+        );
+        self.cell_mut((col, row))
+            .cell_value_mut()
+            .set_dynamic_array_formula(formula, spill_ref);
+
+        for (row_offset, row_values) in (0u32..).zip(values) {
+            for (col_offset, value) in (0u32..).zip(row_values) {
+                let cell_value = self
+                    .cell_mut((col + col_offset, row + row_offset))
+                    .cell_value_mut();
+                if row_offset == 0 && col_offset == 0 {
+                    cell_value.set_formula_result_default(value.as_ref());
+                } else {
+                    cell_value.set_value(value.as_ref());
+                }
+            }
+        }
+        self
+    }
+
+    /// (This method is crate only.)
+    /// Has Dynamic Array Formula
+    #[inline]
+    pub(crate) fn has_dynamic_array_formula(&self) -> bool {
+        self.cells.iter_collection().any(|cell| {
+            cell.cell_value()
+                .formula_obj()
+                .is_some_and(CellFormula::dynamic_array)
+        })
+    }
+
+    /// Set an array formula over a range, as entered with Ctrl+Shift+Enter.
+    /// The formula is kept by the top-left cell of the range and formulas of
+    /// the other cells in the range are removed.
+    /// # Arguments
+    /// * `range` - Specify the range. ex) "C1:C3"
+    /// * `formula` - ex) "A1:A3*B1:B3"
+    /// # Return value
+    /// * `Result<(), XlsxError>` - `InvalidArgument` when `range` is not a
+    ///   range of cells, or covers only a part of another array formula.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.sheet_mut(0).unwrap();
+    /// worksheet.set_array_formula("C1:C3", "A1:A3*B1:B3").unwrap();
+    /// let (formula, range) = worksheet.array_formula_at("C2").unwrap();
+    /// assert_eq!(formula.text(), "A1:A3*B1:B3");
+    /// assert_eq!(range, "C1:C3");
+    /// ```
+    pub fn set_array_formula(&mut self, range: &str, formula: &str) -> Result<(), XlsxError> {
+        let Some(bounds) = cell_range_bounds(range) else {
+            return Err(XlsxError::InvalidArgument(format!(
+                "Not a range of cells: {range}"
+            )));
+        };
+        let (row_start, row_end, col_start, col_end) = bounds;
+        if let Some((cell, _)) = self.array_formula_areas().into_iter().find(|(_, area)| {
+            area_overlaps(*area, bounds)
+                && !(area_contains(bounds, area.0, area.2) && area_contains(bounds, area.1, area.3))
+        }) {
+            return Err(XlsxError::InvalidArgument(format!(
+                "{range} covers a part of the array formula in {}",
+                cell.cell_value().formula_obj().unwrap().reference()
+            )));
+        }
+
+        let formula_cells: Vec<(u32, u32)> = self
+            .cells
+            .iter_collection()
+            .filter(|cell| cell.cell_value().is_formula())
+            .map(|cell| (cell.coordinate().col_num(), cell.coordinate().row_num()))
+            .filter(|(col_num, row_num)| area_contains(bounds, *row_num, *col_num))
+            .collect();
+        for coordinate in formula_cells {
+            self.cell_mut(coordinate).cell_value_mut().remove_formula();
+        }
+
+        let reference = if row_start == row_end && col_start == col_end {
+            coordinate_from_index(col_start, row_start)
+        } else {
+            format!(
+                "{}:{}",
+                coordinate_from_index(col_start, row_start),
+                coordinate_from_index(col_end, row_end)
+            )
+        };
+        let mut cell_formula = CellFormula::default();
+        cell_formula.set_formula_type(CellFormulaValues::Array);
+        cell_formula.set_reference(reference).set_text(formula);
+        self.cell_mut((col_start, row_start))
+            .cell_value_mut()
+            .set_formula_obj(cell_formula);
+        Ok(())
+    }
+
+    /// Get the array formula a cell belongs to.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinates. ex) `"A1"` or `(1, 1)`
+    /// # Return value
+    /// * `Option<(&CellFormula, &str)>` - The formula, kept by the top-left
+    ///   cell of the array, and the range of the array.
+    #[must_use]
+    pub fn array_formula_at<T>(&self, coordinate: T) -> Option<(&CellFormula, &str)>
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        self.array_formula_areas()
+            .into_iter()
+            .find(|(_, area)| area_contains(*area, row, col))
+            .and_then(|(cell, _)| cell.cell_value().formula_obj())
+            .map(|formula| (formula, formula.reference()))
+    }
+
+    /// Remove an array formula and all the cells of its range.
+    /// # Arguments
+    /// * `range` - Specify the range of the array. ex) "C1:C3"
+    /// # Return value
+    /// * `bool` - Whether the array formula was removed. `false` when
+    ///   `range` is not the range of an array formula.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.sheet_mut(0).unwrap();
+    /// worksheet.set_array_formula("C1:C3", "A1:A3*B1:B3").unwrap();
+    /// assert!(!worksheet.remove_cell("C1"));
+    /// assert!(worksheet.remove_array_formula("C1:C3"));
+    /// assert!(worksheet.cell("C1").is_none());
+    /// ```
+    pub fn remove_array_formula(&mut self, range: &str) -> bool {
+        let Some(bounds) = cell_range_bounds(range) else {
+            return false;
+        };
+        let (row_start, row_end, col_start, col_end) = bounds;
+        let is_array = self
+            .cells
+            .get((col_start, row_start))
+            .and_then(|cell| cell.cell_value().formula_obj())
+            .is_some_and(|formula| {
+                formula.formula_type() == &CellFormulaValues::Array
+                    && cell_range_bounds(formula.reference()) == Some(bounds)
+            });
+        if !is_array {
+            return false;
+        }
+        let coordinates: Vec<(u32, u32)> = self
+            .cells
+            .iter_coordinates_by_range_sorted_by_row(row_start, row_end, col_start, col_end)
+            .collect();
+        for (col_num, row_num) in coordinates {
+            self.cells.remove(col_num, row_num);
+        }
+        true
+    }
+
+    /// Add a one- or two-variable data table (what-if analysis), as created
+    /// with Data > What-If Analysis > Data Table.
+    /// `range` covers the whole table: its first row and first column hold
+    /// the input values and the formulas, the other cells receive the
+    /// results. Formulas of the result cells are removed.
+    ///
+    /// The results are not computed here: Excel calculates them when the file
+    /// is opened, and `Workbook::calculate_data_table` stores them for the
+    /// formulas goal seek can calculate.
+    /// # Arguments
+    /// * `range` - Specify the range of the table. ex) "A3:B6"
+    /// * `row_input` - The cell the values of the first row are substituted
+    ///   for. ex) `Some("B1")`
+    /// * `col_input` - The cell the values of the first column are
+    ///   substituted for. ex) `Some("B2")`
+    /// # Return value
+    /// * `Result<(), XlsxError>` - `InvalidArgument` when `range` is not a
+    ///   range of at least two rows and two columns, when no input cell is
+    ///   given, when an input cell is not a cell outside the table, or when
+    ///   the results overlap an array formula.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.sheet_mut(0).unwrap();
+    /// worksheet.cell_mut("B1").set_value_number(0.05);
+    /// worksheet.cell_mut("B3").set_formula("1000*B1");
+    /// worksheet.cell_mut("A4").set_value_number(0.04);
+    /// worksheet.cell_mut("A5").set_value_number(0.06);
+    /// worksheet.add_data_table("A3:B5", None, Some("B1")).unwrap();
+    /// let formula = worksheet.cell("B4").unwrap().cell_value().formula_obj().unwrap();
+    /// assert_eq!(formula.reference(), "B4:B5");
+    /// assert_eq!(formula.r1(), "B1");
+    /// ```
+    pub fn add_data_table(
+        &mut self,
+        range: &str,
+        row_input: Option<&str>,
+        col_input: Option<&str>,
+    ) -> Result<(), XlsxError> {
+        let bounds = cell_range_bounds(range)
+            .filter(|(row_start, row_end, col_start, col_end)| {
+                row_start < row_end && col_start < col_end
+            })
+            .ok_or_else(|| {
+                XlsxError::InvalidArgument(format!(
+                    "A data table needs at least two rows and two columns: {range}"
+                ))
+            })?;
+        let input_cell = |input: &str| {
+            cell_range_bounds(input)
+                .filter(|area| area.0 == area.1 && area.2 == area.3)
+                .filter(|area| !area_contains(bounds, area.0, area.2))
+                .map(|area| coordinate_from_index(area.2, area.0))
+                .ok_or_else(|| {
+                    XlsxError::InvalidArgument(format!(
+                        "Not a cell outside the data table: {input}"
+                    ))
+                })
+        };
+        let row_input = row_input.map(input_cell).transpose()?;
+        let col_input = col_input.map(input_cell).transpose()?;
+
+        let (row_start, row_end, col_start, col_end) = bounds;
+        let results = (row_start + 1, row_end, col_start + 1, col_end);
+        if let Some((cell, _)) = self
+            .array_formula_areas()
+            .into_iter()
+            .find(|(_, area)| area_overlaps(*area, results))
+        {
+            return Err(XlsxError::InvalidArgument(format!(
+                "{range} overlaps the array formula in {}",
+                cell.cell_value().formula_obj().unwrap().reference()
+            )));
+        }
+
+        let mut cell_formula = CellFormula::default();
+        cell_formula.set_formula_type(CellFormulaValues::DataTable);
+        match (row_input, col_input) {
+            (Some(row_input), Some(col_input)) => {
+                cell_formula
+                    .set_data_table_2d(true)
+                    .set_data_table_row(true)
+                    .set_r1(row_input)
+                    .set_r2(col_input);
+            }
+            (Some(row_input), None) => {
+                cell_formula
+                    .set_data_table_2d(false)
+                    .set_data_table_row(true)
+                    .set_r1(row_input);
+            }
+            (None, Some(col_input)) => {
+                cell_formula
+                    .set_data_table_2d(false)
+                    .set_data_table_row(false)
+                    .set_r1(col_input);
+            }
+            (None, None) => {
+                return Err(XlsxError::InvalidArgument(
+                    "A data table needs a row input cell or a column input cell".into(),
+                ));
+            }
+        }
+        cell_formula.set_reference(format!(
+            "{}:{}",
+            coordinate_from_index(results.2, results.0),
+            coordinate_from_index(results.3, results.1)
+        ));
+
+        let formula_cells: Vec<(u32, u32)> = self
+            .cells
+            .iter_collection()
+            .filter(|cell| cell.cell_value().is_formula())
+            .map(|cell| (cell.coordinate().col_num(), cell.coordinate().row_num()))
+            .filter(|(col_num, row_num)| area_contains(results, *row_num, *col_num))
+            .collect();
+        for coordinate in formula_cells {
+            self.cell_mut(coordinate).cell_value_mut().remove_formula();
+        }
+        self.cell_mut((results.2, results.0))
+            .cell_value_mut()
+            .set_formula_obj(cell_formula);
+        Ok(())
+    }
+
+    /// (This method is crate only.)
+    /// Check that no cell inside an array formula has a formula of its own,
+    /// which Excel refuses to open.
+    pub(crate) fn check_array_formulas(&self) -> Result<(), XlsxError> {
+        let areas = self.legacy_array_formula_areas();
+        if areas.is_empty() {
+            return Ok(());
+        }
+        for cell in self.cells.iter_collection() {
+            if !cell.cell_value().is_formula() {
+                continue;
+            }
+            let col_num = cell.coordinate().col_num();
+            let row_num = cell.coordinate().row_num();
+            if let Some((master, _)) = areas.iter().find(|(master, area)| {
+                area_contains(*area, row_num, col_num) && !std::ptr::eq(*master, cell)
+            }) {
+                return Err(XlsxError::InvalidArgument(format!(
+                    "{} on sheet {} is a part of the array formula in {}",
+                    cell.coordinate(),
+                    self.name(),
+                    master.cell_value().formula_obj().unwrap().reference()
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Cells with an array formula, with the range of the array.
+    fn array_formula_areas(&self) -> Vec<(&Cell, CellArea)> {
+        self.cells
+            .iter_collection()
+            .filter_map(|cell| {
+                let formula = cell.cell_value().formula_obj()?;
+                if formula.formula_type() != &CellFormulaValues::Array {
+                    return None;
+                }
+                let area = if formula.reference().is_empty() {
+                    let coordinate = cell.coordinate();
+                    let (col_num, row_num) = (coordinate.col_num(), coordinate.row_num());
+                    (row_num, row_num, col_num, col_num)
+                } else {
+                    cell_range_bounds(formula.reference())?
+                };
+                Some((cell, area))
+            })
+            .collect()
+    }
+
+    /// Whether a cell is inside an array formula entered over more than one
+    /// cell. Only the cells above and to the left can hold such a formula.
+    fn is_in_legacy_array_formula(&self, col_num: u32, row_num: u32) -> bool {
+        self.cells
+            .iter_cells_by_range_sorted_by_row(1, row_num, 1, col_num)
+            .filter_map(|cell| {
+                let formula = cell.cell_value().formula_obj()?;
+                if formula.formula_type() != &CellFormulaValues::Array || formula.dynamic_array() {
+                    return None;
+                }
+                cell_range_bounds(formula.reference())
+            })
+            .any(|area| {
+                (area.0 != area.1 || area.2 != area.3) && area_contains(area, row_num, col_num)
+            })
+    }
+
+    /// Array formulas entered over more than one cell, without the dynamic
+    /// array formulas whose results only spill over their range.
+    fn legacy_array_formula_areas(&self) -> Vec<(&Cell, CellArea)> {
+        self.array_formula_areas()
+            .into_iter()
+            .filter(|(cell, (row_start, row_end, col_start, col_end))| {
+                (row_start != row_end || col_start != col_end)
+                    && !cell.cell_value().formula_obj().unwrap().dynamic_array()
+            })
+            .collect()
+    }
+
     // ************************
     // Comment
     // ************************
@@ -779,6 +1268,72 @@ impl Worksheet {
         !self.comments.is_empty()
     }
 
+    // ************************
+    // FormControl
+    // ************************
+    /// Get Form Controls.
+    #[inline]
+    #[must_use]
+    pub fn form_controls(&self) -> &[FormControl] {
+        &self.form_controls
+    }
+
+    /// Get Form Controls in mutable.
+    #[inline]
+    pub fn form_controls_mut(&mut self) -> &mut Vec<FormControl> {
+        &mut self.form_controls
+    }
+
+    /// Get Form Control by name.
+    /// # Arguments
+    /// * `name` - Name of the control. ex) `"Check Box 1"`
+    #[inline]
+    #[must_use]
+    pub fn form_control_by_name(&self, name: &str) -> Option<&FormControl> {
+        self.form_controls.iter().find(|v| v.name() == name)
+    }
+
+    /// Get Form Control by name in mutable.
+    /// # Arguments
+    /// * `name` - Name of the control. ex) `"Check Box 1"`
+    #[inline]
+    pub fn form_control_by_name_mut(&mut self, name: &str) -> Option<&mut FormControl> {
+        self.form_controls.iter_mut().find(|v| v.name() == name)
+    }
+
+    /// Add Form Control.
+    /// When the control has no name, a name such as `"Check Box 1"` is
+    /// assigned.
+    /// # Arguments
+    /// * `value` - `FormControl`
+    pub fn add_form_control(&mut self, mut value: FormControl) -> &mut Self {
+        if value.name().is_empty() {
+            let prefix = match value.object_type() {
+                ObjectValues::Button => "Button",
+                ObjectValues::Checkbox => "Check Box",
+                ObjectValues::Drop => "Drop Down",
+                ObjectValues::GroupBox => "Group Box",
+                ObjectValues::Label => "Label",
+                ObjectValues::List => "List Box",
+                ObjectValues::Radio => "Option Button",
+                ObjectValues::Scroll => "Scroll Bar",
+                ObjectValues::Spin => "Spinner",
+                _ => "Control",
+            };
+            let no = self.form_controls.len() + 1;
+            value.set_name(format!("{prefix} {no}"));
+        }
+        self.form_controls.push(value);
+        self
+    }
+
+    /// Has Form Controls.
+    #[inline]
+    #[must_use]
+    pub fn has_form_controls(&self) -> bool {
+        !self.form_controls.is_empty()
+    }
+
     // ************************
     // ThreadedComment
     // ************************
@@ -852,6 +1407,228 @@ impl Worksheet {
         !self.threaded_comments.is_empty()
     }
 
+    /// Start a threaded comment conversation on a cell.
+    /// When the cell already has a thread, the comment is added to it as a
+    /// reply. The author is registered as a `Person` of the workbook, or
+    /// keeps the id of the person of the same display name, and a legacy
+    /// note is generated for older clients.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinate. ex) `"A1"` or `(1, 1)`
+    /// * `author` - Display name of the author. ex) "John Doe"
+    /// * `text` - Comment text.
+    /// # Examples
+    /// ```
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let sheet = book.sheet_mut(0).unwrap();
+    /// sheet
+    ///     .add_threaded_comment("B2", "John Doe", "Please check.")
+    ///     .reply("Jane Doe", "Done.")
+    ///     .resolve();
+    /// assert_eq!(sheet.threaded_comments().len(), 2);
+    /// ```
+    pub fn add_threaded_comment<T>(
+        &mut self,
+        coordinate: T,
+        author: &str,
+        text: &str,
+    ) -> ThreadedCommentHandle<'_>
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        let root_id = self
+            .threaded_comments
+            .iter()
+            .find(|v| {
+                v.parent_id().is_empty()
+                    && v.coordinate().col_num() == col
+                    && v.coordinate().row_num() == row
+            })
+            .map(|v| v.id().to_string());
+        let id = if let Some(root_id) = root_id {
+            self.reply_threaded_comment_crate(&root_id, author, text);
+            root_id
+        } else {
+            let id = self.push_threaded_comment(col, row, None, author, text);
+            self.sync_threaded_comment_note(&id);
+            id
+        };
+        ThreadedCommentHandle::new(self, id)
+    }
+
+    /// Get a handle to the threaded comment conversation of a cell.
+    /// # Arguments
+    /// * `coordinate` - Specify the coordinate. ex) `"A1"` or `(1, 1)`
+    pub fn threaded_comment_thread_mut<T>(
+        &mut self,
+        coordinate: T,
+    ) -> Option<ThreadedCommentHandle<'_>>
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        let id = self
+            .threaded_comments
+            .iter()
+            .find(|v| {
+                v.parent_id().is_empty()
+                    && v.coordinate().col_num() == col
+                    && v.coordinate().row_num() == row
+            })?
+            .id()
+            .to_string();
+        Some(ThreadedCommentHandle::new(self, id))
+    }
+
+    /// Get the persons registered by threaded comments of this sheet.
+    /// They are merged into the person list of the workbook when writing.
+    #[inline]
+    #[must_use]
+    pub fn person_list(&self) -> &[Person] {
+        &self.person_list
+    }
+
+    // Persons already known to the workbook, reused by display name.
+    #[inline]
+    pub(crate) fn set_workbook_person_list(&mut self, value: Vec<Person>) {
+        self.workbook_person_list = value;
+    }
+
+    pub(crate) fn reply_threaded_comment_crate(&mut self, root_id: &str, author: &str, text: &str) {
+        let Some(root) = self.threaded_comments.iter().find(|v| v.id() == root_id) else {
+            return;
+        };
+        let col = root.coordinate().col_num();
+        let row = root.coordinate().row_num();
+        self.push_threaded_comment(col, row, Some(root_id), author, text);
+        self.sync_threaded_comment_note(root_id);
+    }
+
+    pub(crate) fn resolve_threaded_comment_crate(&mut self, root_id: &str, value: bool) {
+        if let Some(root) = self.threaded_comments.iter_mut().find(|v| v.id() == root_id) {
+            root.set_done(value);
+        }
+    }
+
+    pub(crate) fn mention_threaded_comment_crate(&mut self, root_id: &str, person: &str) {
+        let mention_person_id = self.register_person(person);
+        let Some(comment) = self
+            .threaded_comments
+            .iter_mut()
+            .rev()
+            .find(|v| v.id() == root_id || v.parent_id() == root_id)
+        else {
+            return;
+        };
+        let mut text = comment.text().to_string();
+        if !text.is_empty() && !text.ends_with(char::is_whitespace) {
+            text.push(' ');
+        }
+        let start_index = text.chars().count();
+        text.push('@');
+        text.push_str(person);
+
+        let mut mention = Mention::default();
+        mention
+            .set_mention_person_id(mention_person_id)
+            .set_mention_id(new_guid())
+            .set_start_index(num_traits::cast(start_index).unwrap())
+            .set_length(num_traits::cast(person.chars().count() + 1).unwrap());
+        comment.set_text(text).add_mention(mention);
+        self.sync_threaded_comment_note(root_id);
+    }
+
+    // Add a threaded comment and return its id.
+    fn push_threaded_comment(
+        &mut self,
+        col: u32,
+        row: u32,
+        parent_id: Option<&str>,
+        author: &str,
+        text: &str,
+    ) -> String {
+        let person_id = self.register_person(author);
+        let id = new_guid();
+        let mut threaded_comment = ThreadedComment::default();
+        threaded_comment
+            .set_d_t(
+                chrono::Local::now()
+                    .naive_local()
+                    .format("%Y-%m-%dT%H:%M:%S%.3f")
+                    .to_string(),
+            )
+            .set_person_id(person_id)
+            .set_id(id.clone())
+            .set_text(text);
+        threaded_comment
+            .coordinate_mut()
+            .set_col_num(col)
+            .set_row_num(row);
+        if let Some(parent_id) = parent_id {
+            threaded_comment.set_parent_id(parent_id);
+        }
+        self.threaded_comments.push(threaded_comment);
+        id
+    }
+
+    // Register a person and return its id.
+    // A person of the same display name keeps the id it already has.
+    fn register_person(&mut self, display_name: &str) -> String {
+        if let Some(person) = self
+            .workbook_person_list
+            .iter()
+            .chain(&self.person_list)
+            .find(|v| v.display_name() == display_name)
+        {
+            return person.id().to_string();
+        }
+        let person = Person::new(display_name);
+        let id = person.id().to_string();
+        if !self.person_list.iter().any(|v| v.id() == id) {
+            self.person_list.push(person);
+        }
+        id
+    }
+
+    // Rebuild the legacy note shown by clients without threaded comments.
+    fn sync_threaded_comment_note(&mut self, root_id: &str) {
+        let mut thread = self
+            .threaded_comments
+            .iter()
+            .filter(|v| v.id() == root_id || v.parent_id() == root_id);
+        let Some(root) = thread.next() else {
+            return;
+        };
+        let col = root.coordinate().col_num();
+        let row = root.coordinate().row_num();
+        let mut text = format!(
+            "[Threaded comment]\n\nYour version of Excel allows you to read this threaded \
+             comment; however, any edits to it will get removed if the file is opened in a \
+             newer version of Excel. Learn more: https://go.microsoft.com/fwlink/?linkid=870924\n\n\
+             Comment:\n    {}",
+            root.text()
+        );
+        for reply in thread {
+            text.push_str("\nReply:\n    ");
+            text.push_str(reply.text());
+        }
+
+        let index = self.comments.iter().position(|v| {
+            v.coordinate().col_num() == col && v.coordinate().row_num() == row
+        });
+        let comment = if let Some(index) = index {
+            &mut self.comments[index]
+        } else {
+            let mut comment = Comment::default();
+            comment.new_comment((col, row));
+            self.comments.push(comment);
+            self.comments.last_mut().unwrap()
+        };
+        comment
+            .set_author(format!("tc={root_id}"))
+            .set_text_string(text);
+    }
+
     // ************************
     // Conditional
     // ************************
@@ -1034,12 +1811,83 @@ impl Worksheet {
         self.auto_filter = Some(auto_filter);
     }
 
+    /// Set Auto Filter.
+    /// # Arguments
+    /// * `value` - `AutoFilter` with its filter columns and sort state.
+    #[inline]
+    pub fn set_auto_filter_obj(&mut self, value: AutoFilter) {
+        self.auto_filter = Some(value);
+    }
+
     // Remove Auto Filter.
     #[inline]
     pub fn remove_auto_filter(&mut self) {
         self.auto_filter = None;
     }
 
+    /// Evaluate the filter criteria of the auto filter and of the tables,
+    /// and set the hidden flag of the filtered rows, as Excel does when saving.
+    /// Computed values such as the top 10 threshold or the average of a
+    /// dynamic filter are stored in the criteria.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    /// let mut book = new_file();
+    /// let worksheet = book.sheet_mut(0).unwrap();
+    /// worksheet.cell_mut("A1").set_value("Fruit");
+    /// worksheet.cell_mut("A2").set_value("Apple");
+    /// worksheet.cell_mut("A3").set_value("Orange");
+    /// worksheet.set_auto_filter("A1:A3");
+    /// let mut filters = Filters::default();
+    /// filters.add_filter("Apple");
+    /// let mut filter_column = FilterColumn::new(0);
+    /// filter_column.set_filters(filters);
+    /// worksheet.auto_filter_mut().unwrap().add_filter_column(filter_column);
+    /// worksheet.apply_auto_filter();
+    /// assert!(worksheet.row_dimension(3).unwrap().hidden());
+    /// ```
+    pub fn apply_auto_filter(&mut self) {
+        let today = chrono::Local::now().date_naive();
+        let mut rows: Vec<(u32, bool)> = Vec::new();
+
+        if let Some(auto_filter) = &mut self.auto_filter {
+            let (row_beg, row_end, col_beg, _) =
+                get_start_and_end_point(&auto_filter.range().range());
+            rows.extend(evaluate_filter_columns(
+                auto_filter.filter_column_list_mut(),
+                &self.cells,
+                col_beg,
+                row_beg + 1,
+                row_end,
+                today,
+            ));
+        }
+        for table in &mut self.tables {
+            let col_beg = table.area().0.col_num();
+            let (row_beg, row_end) = table.data_row_range();
+            rows.extend(evaluate_filter_columns(
+                table.filter_column_list_mut(),
+                &self.cells,
+                col_beg,
+                row_beg,
+                row_end,
+                today,
+            ));
+        }
+
+        let mut shown: HashMap<u32, bool> = HashMap::new();
+        for (row_num, is_shown) in rows {
+            *shown.entry(row_num).or_insert(true) &= is_shown;
+        }
+        for (row_num, is_shown) in shown {
+            if !is_shown {
+                self.row_dimension_mut(row_num).set_hidden(true);
+            } else if self.row_dimension(row_num).is_some_and(Row::hidden) {
+                self.row_dimension_mut(row_num).set_hidden(false);
+            }
+        }
+    }
+
     // ************************
     // Column Dimensions
     // ************************
@@ -2168,6 +3016,434 @@ impl Worksheet {
         self.tables_mut()
     }
 
+    /// Get a table by name.
+    /// Table names are compared case-insensitively.
+    #[inline]
+    #[must_use]
+    pub fn table_by_name(&self, name: &str) -> Option<&Table> {
+        self.tables
+            .iter()
+            .find(|x| x.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Get a table by name.
+    #[inline]
+    pub fn table_by_name_mut(&mut self, name: &str) -> Option<&mut Table> {
+        self.tables
+            .iter_mut()
+            .find(|x| x.name().eq_ignore_ascii_case(name))
+    }
+
+    #[inline]
+    fn table_index(&self, name: &str) -> Result<usize, XlsxError> {
+        self.tables
+            .iter()
+            .position(|x| x.name().eq_ignore_ascii_case(name))
+            .ok_or(XlsxError::NotFound())
+    }
+
+    /// Append a data row to the table.
+    /// Rows below the table are shifted down with `insert_new_row`.
+    /// The new row takes the style of the last data row, and calculated
+    /// columns are filled with their formula.
+    /// # Arguments
+    /// * `table_name` - Name of the table. ex) "Sales"
+    /// * `values` - Values written from the first column of the table.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    /// let mut book = new_file();
+    /// let worksheet = book.sheet_mut(0).unwrap();
+    /// let mut table = Table::new("Sales", ("A1", "B2"));
+    /// table.add_column(TableColumn::new("Item"));
+    /// table.add_column(TableColumn::new("Qty"));
+    /// worksheet.add_table(table);
+    /// worksheet.append_table_row("Sales", ["Apple", "3"]).unwrap();
+    /// assert_eq!(worksheet.value("A3"), "Apple");
+    /// ```
+    pub fn append_table_row<I, S>(&mut self, table_name: &str, values: I) -> Result<(), XlsxError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let index = self.table_index(table_name)?;
+        let (_, data_end) = self.tables[index].data_row_range();
+        let row_num = data_end + 1;
+        self.insert_table_rows(index, row_num, 1);
+
+        let (beg, end) = self.tables[index].area().clone();
+        for (col_num, value) in (beg.col_num()..=end.col_num()).zip(values) {
+            self.cell_mut((col_num, row_num)).set_value(value);
+        }
+        self.fill_table_calculated_columns_by_index(index);
+        Ok(())
+    }
+
+    /// Resize the table, keeping its header row in place.
+    /// Additional data rows are inserted with `insert_new_row`, so cells
+    /// below the table are shifted down. Added columns get a header taken
+    /// from the existing header cell, or a generated `ColumnN` name.
+    /// # Arguments
+    /// * `table_name` - Name of the table. ex) "Sales"
+    /// * `end` - New bottom-right cell of the table. ex) "D20"
+    pub fn resize_table<T>(&mut self, table_name: &str, end: T) -> Result<(), XlsxError>
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates {
+            col: end_col,
+            row: end_row,
+        } = end.into();
+        let index = self.table_index(table_name)?;
+        let (beg, old_end) = self.tables[index].area().clone();
+        let totals_row_count = self.tables[index].totals_row_count();
+        if end_col < beg.col_num() || end_row < beg.row_num() + totals_row_count {
+            return Err(XlsxError::InvalidArgument(format!(
+                "{} cannot end at {}",
+                table_name,
+                coordinate_from_index(end_col, end_row)
+            )));
+        }
+
+        // rows
+        if end_row > old_end.row_num() {
+            let (_, data_end) = self.tables[index].data_row_range();
+            self.insert_table_rows(index, data_end + 1, end_row - old_end.row_num());
+        }
+        self.tables[index].set_area((
+            (beg.col_num(), beg.row_num()),
+            (end_col, end_row),
+        ));
+
+        // columns
+        let header_row = beg.row_num();
+        let col_count = (end_col - beg.col_num() + 1) as usize;
+        let mut suffix = self.tables[index].columns().len();
+        while self.tables[index].columns().len() < col_count {
+            let col_num = beg.col_num()
+                + num_traits::cast::<_, u32>(self.tables[index].columns().len()).unwrap();
+            let mut name = self.value((col_num, header_row));
+            while name.is_empty() || self.tables[index].column_index(&name).is_some() {
+                suffix += 1;
+                name = format!("Column{suffix}");
+            }
+            self.cell_mut((col_num, header_row)).set_value_string(&name);
+            self.tables[index].add_column(TableColumn::new(&name));
+        }
+        self.tables[index].truncate_columns(col_count);
+
+        self.fill_table_calculated_columns_by_index(index);
+        Ok(())
+    }
+
+    /// Rename a table column.
+    /// The header cell and structured references to the column in
+    /// calculated columns and cell formulas of this worksheet follow the new name.
+    /// # Arguments
+    /// * `table_name` - Name of the table. ex) "Sales"
+    /// * `old_name` - Current column name. ex) "Amount"
+    /// * `new_name` - New column name. ex) "Total"
+    pub fn rename_table_column(
+        &mut self,
+        table_name: &str,
+        old_name: &str,
+        new_name: &str,
+    ) -> Result<(), XlsxError> {
+        let index = self.table_index(table_name)?;
+        let table = &self.tables[index];
+        let col_index = table.column_index(old_name).ok_or(XlsxError::NotFound())?;
+        if table
+            .column_index(new_name)
+            .is_some_and(|x| x != col_index)
+        {
+            return Err(XlsxError::NameDuplicate());
+        }
+        let old_name = table.columns()[col_index].name().to_string();
+        let header = (
+            table.area().0.col_num() + num_traits::cast::<_, u32>(col_index).unwrap(),
+            table.header_row_num(),
+        );
+        let table_name = table.name().to_string();
+
+        self.tables[index].columns_mut()[col_index].set_name(new_name.to_string());
+        self.cell_mut(header).set_value_string(new_name);
+
+        let old_escaped = escape_structured_column(&old_name);
+        let new_escaped = escape_structured_column(new_name);
+        let rename = |operand: &str, is_in_table: bool| -> Option<String> {
+            let (name, specifier) = split_structured_reference(operand)?;
+            if !(name.eq_ignore_ascii_case(&table_name) || (name.is_empty() && is_in_table)) {
+                return None;
+            }
+            let result = specifier
+                .replace(&format!("[{old_escaped}]"), &format!("[{new_escaped}]"))
+                .replace(&format!("[@{old_escaped}]"), &format!("[@{new_escaped}]"));
+            (result != specifier).then(|| format!("{name}{result}"))
+        };
+
+        for column in self.tables[index].columns_mut() {
+            if let Some(formula) = column.calculated_column_formula() {
+                let formula = replace_range_operands(formula, |x| rename(x, true));
+                column.set_calculated_column_formula(formula);
+            }
+        }
+        let table = self.tables[index].clone();
+        for cell in self.cells.collection_mut() {
+            if cell.formula().is_empty() {
+                continue;
+            }
+            let coordinate = cell.coordinate();
+            let is_in_table = table.contains(coordinate.col_num(), coordinate.row_num());
+            let formula = replace_range_operands(cell.formula(), |x| rename(x, is_in_table));
+            if formula != cell.formula() {
+                Self::replace_formula_text(cell, formula);
+            }
+        }
+        Ok(())
+    }
+
+    /// Write the calculated column formulas of the table to every data row.
+    /// `[@Col]` references are stored in the `Table[[#This Row],[Col]]` form.
+    /// # Arguments
+    /// * `table_name` - Name of the table. ex) "Sales"
+    pub fn fill_table_calculated_columns(&mut self, table_name: &str) -> Result<(), XlsxError> {
+        let index = self.table_index(table_name)?;
+        self.fill_table_calculated_columns_by_index(index);
+        Ok(())
+    }
+
+    fn fill_table_calculated_columns_by_index(&mut self, index: usize) {
+        let table = self.tables[index].clone();
+        let (data_beg, data_end) = table.data_row_range();
+        for (i, column) in table.columns().iter().enumerate() {
+            let Some(formula) = column.calculated_column_formula() else {
+                continue;
+            };
+            let formula =
+                replace_range_operands(formula, |x| table.normalize_structured_reference(x));
+            let col_num = table.area().0.col_num() + num_traits::cast::<_, u32>(i).unwrap();
+            for row_num in data_beg..=data_end {
+                self.cell_mut((col_num, row_num)).set_formula(formula.as_str());
+            }
+            self.tables[index].columns_mut()[i].set_calculated_column_formula(formula);
+        }
+    }
+
+    /// Insert rows into a table and extend its area over them.
+    fn insert_table_rows(&mut self, index: usize, row_num: u32, num_rows: u32) {
+        self.insert_new_row(row_num, num_rows);
+
+        let (beg, mut end) = self.tables[index].area().clone();
+        if end.row_num() < row_num {
+            end.set_row_num(end.row_num() + num_rows);
+        }
+        self.tables[index].set_area((
+            (beg.col_num(), beg.row_num()),
+            (end.col_num(), end.row_num()),
+        ));
+
+        if row_num > beg.row_num() + 1 {
+            for target in row_num..row_num + num_rows {
+                self.copy_row_styling(
+                    row_num - 1,
+                    target,
+                    Some(beg.col_num()),
+                    Some(end.col_num()),
+                );
+            }
+        }
+    }
+
+    /// Resolve a structured reference such as `Sales[Amount]` or
+    /// `Sales[#Totals]` to an A1 range.
+    /// # Arguments
+    /// * `reference` - Structured reference with a table name.
+    /// # Return value
+    /// * `Option<String>` - ex) `C2:C10`
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::*;
+    /// let mut book = new_file();
+    /// let worksheet = book.sheet_mut(0).unwrap();
+    /// let mut table = Table::new("Sales", ("A1", "B5"));
+    /// table.add_column(TableColumn::new("Item"));
+    /// table.add_column(TableColumn::new("Amount"));
+    /// worksheet.add_table(table);
+    /// assert_eq!(worksheet.resolve_structured_reference("Sales[Amount]"), Some("B2:B5".to_string()));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn resolve_structured_reference(&self, reference: &str) -> Option<String> {
+        self.resolve_structured_reference_crate(reference, None)
+    }
+
+    /// Resolve a structured reference written in the given cell.
+    /// References without a table name such as `[@Qty]` use the table
+    /// containing the cell, and `@`/`#This Row` use the row of the cell.
+    /// # Arguments
+    /// * `reference` - Structured reference. ex) `[@Qty]`
+    /// * `coordinate` - Cell holding the reference. ex) "D3"
+    #[inline]
+    pub fn resolve_structured_reference_at<T>(&self, reference: &str, coordinate: T) -> Option<String>
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        self.resolve_structured_reference_crate(reference, Some((col, row)))
+    }
+
+    /// Replace the structured references of a formula with A1 ranges.
+    /// References that cannot be resolved become `#REF!`.
+    /// # Arguments
+    /// * `formula` - Formula without the leading `=`. ex) `SUM(Sales[Amount])`
+    /// * `coordinate` - Cell holding the formula. ex) "D3"
+    /// # Return value
+    /// * `String` - ex) `SUM(B2:B5)`
+    pub fn structured_references_to_a1<T>(&self, formula: &str, coordinate: T) -> String
+    where
+        T: Into<CellCoordinates>,
+    {
+        let CellCoordinates { col, row } = coordinate.into();
+        replace_range_operands(formula, |operand| {
+            split_structured_reference(operand)?;
+            Some(
+                self.resolve_structured_reference_crate(operand, Some((col, row)))
+                    .unwrap_or_else(|| "#REF!".to_string()),
+            )
+        })
+    }
+
+    fn resolve_structured_reference_crate(
+        &self,
+        reference: &str,
+        coordinate: Option<(u32, u32)>,
+    ) -> Option<String> {
+        let (name, specifier) = split_structured_reference(reference)?;
+        let table = if name.is_empty() {
+            let (col, row) = coordinate?;
+            self.tables.iter().find(|x| x.contains(col, row))?
+        } else {
+            self.table_by_name(name)?
+        };
+        table.resolve_structured_reference(specifier, coordinate.map(|x| x.1))
+    }
+
+    /// (This method is crate only.)
+    /// Rewrite the formula of every cell with `replacer`.
+    pub(crate) fn replace_formulas<F>(&mut self, mut replacer: F)
+    where
+        F: FnMut(&str) -> String,
+    {
+        for cell in self.cells.collection_mut() {
+            if cell.formula().is_empty() {
+                continue;
+            }
+            let formula = replacer(cell.formula());
+            if formula != cell.formula() {
+                Self::replace_formula_text(cell, formula);
+            }
+        }
+    }
+
+    /// (This method is crate only.)
+    /// Rewrite the range operands of the cell formulas, the calculated
+    /// columns of the tables, the defined names, the chart series, the data
+    /// validations, the conditional formats, the internal hyperlinks and the
+    /// pivot table sources with `replacer`.
+    pub(crate) fn replace_operands<F>(&mut self, mut replacer: F)
+    where
+        F: FnMut(&str) -> Option<String>,
+    {
+        self.replace_formulas(|formula| replace_range_operands(formula, &mut replacer));
+        for table in &mut self.tables {
+            for column in table.columns_mut() {
+                if let Some(formula) = column.calculated_column_formula() {
+                    let replaced = replace_range_operands(formula, &mut replacer);
+                    if replaced != *formula {
+                        column.set_calculated_column_formula(replaced);
+                    }
+                }
+            }
+        }
+        for defined_name in &mut self.defined_names {
+            defined_name.replace_operands(&mut replacer);
+        }
+        for formula in self.worksheet_drawing.chart_formula_mut() {
+            if formula.has_string_value() {
+                // Formulas that are not a plain address, such as references
+                // to other workbooks.
+                let text = formula.address_str();
+                let replaced = replace_range_operands(&text, &mut replacer);
+                if replaced != text {
+                    formula.set_string_value(replaced);
+                }
+                continue;
+            }
+            formula.address_mut().replace_operand(&mut replacer);
+        }
+        if let Some(data_validations) = &mut self.data_validations {
+            for data_validation in data_validations.data_validation_list_mut() {
+                // Only changed formulas are set again: setting an empty
+                // formula would write an empty element.
+                let formula1 = replace_range_operands(data_validation.formula1(), &mut replacer);
+                if !formula1.is_empty() && formula1 != data_validation.formula1() {
+                    data_validation.set_formula1(formula1);
+                }
+                let formula2 = replace_range_operands(data_validation.formula2(), &mut replacer);
+                if !formula2.is_empty() && formula2 != data_validation.formula2() {
+                    data_validation.set_formula2(formula2);
+                }
+            }
+        }
+        if let Some(data_validations) = &mut self.data_validations_2010 {
+            for data_validation in data_validations.data_validation_list_mut() {
+                if let Some(formula) = data_validation.formula1_mut() {
+                    formula.value_mut().value_mut().replace_operand(&mut replacer);
+                }
+                if let Some(formula) = data_validation.formula2_mut() {
+                    formula.value_mut().value_mut().replace_operand(&mut replacer);
+                }
+            }
+        }
+        for conditional_formatting in &mut self.conditional_formatting_collection {
+            for rule in conditional_formatting.conditional_collection_mut() {
+                if let Some(formula) = rule.formula() {
+                    let mut formula = formula.clone();
+                    formula.replace_operands(&mut replacer);
+                    rule.set_formula(formula);
+                }
+            }
+        }
+        for cell in self.cells.collection_mut() {
+            if cell.hyperlink().is_some_and(Hyperlink::location) {
+                let hyperlink = cell.hyperlink_mut();
+                if let Some(url) = replacer(hyperlink.url()) {
+                    hyperlink.set_url(url);
+                }
+            }
+        }
+        for pivot_table in &mut self.pivot_tables {
+            if let Some(source) = pivot_table
+                .pivot_cache_definition_mut()
+                .cache_source_mut()
+                .worksheet_source_mut()
+            {
+                source.address_mut().replace_operand(&mut replacer);
+            }
+        }
+    }
+
+    #[inline]
+    fn replace_formula_text(cell: &mut Cell, text: String) {
+        let mut formula = cell
+            .cell_value()
+            .formula_obj()
+            .cloned()
+            .unwrap_or_default();
+        formula.replace_text(text);
+        cell.cell_value_mut().set_formula_obj(formula);
+    }
+
     #[inline]
     #[must_use]
     pub fn has_pivot_table(&self) -> bool {
@@ -2652,6 +3928,18 @@ impl Worksheet {
         self
     }
 
+    /// (This method is crate only.)
+    /// Worksheet XML and related parts that are not modeled.
+    #[inline]
+    pub(crate) fn raw_unknown(&self) -> &RawUnknown {
+        &self.raw_unknown
+    }
+
+    #[inline]
+    pub(crate) fn raw_unknown_mut(&mut self) -> &mut RawUnknown {
+        &mut self.raw_unknown
+    }
+
     #[inline]
     #[must_use]
     pub fn sheet_protection(&self) -> Option<&SheetProtection> {
@@ -2689,6 +3977,69 @@ impl Worksheet {
         self
     }
 
+    /// Get the scenarios of the sheet, as kept by the Scenario Manager.
+    #[inline]
+    #[must_use]
+    pub fn scenarios(&self) -> Option<&Scenarios> {
+        self.scenarios.as_ref()
+    }
+
+    #[inline]
+    pub fn scenarios_mut(&mut self) -> &mut Scenarios {
+        self.scenarios.get_or_insert(Scenarios::default())
+    }
+
+    #[inline]
+    pub fn set_scenarios(&mut self, value: Scenarios) -> &mut Self {
+        self.scenarios = Some(value);
+        self
+    }
+
+    #[inline]
+    pub fn remove_scenarios(&mut self) -> &mut Self {
+        self.scenarios = None;
+        self
+    }
+
+    /// Apply a scenario: set its changing cells to its values, as Show in
+    /// the Scenario Manager does.
+    /// # Arguments
+    /// * `name` - Name of the scenario. Names compare case-insensitively.
+    /// # Return value
+    /// * `Result<(), XlsxError>` - `NotFound` when the sheet has no scenario
+    ///   of this name.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::Scenario;
+    ///
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let worksheet = book.sheet_mut(0).unwrap();
+    /// let mut scenario = Scenario::default();
+    /// scenario.set_name("Best case").add_input_cell("B1", "120");
+    /// worksheet.scenarios_mut().add_scenario(scenario);
+    /// worksheet.apply_scenario("best case").unwrap();
+    /// assert_eq!(worksheet.value("B1"), "120");
+    /// ```
+    pub fn apply_scenario(&mut self, name: &str) -> Result<(), XlsxError> {
+        let scenarios = self.scenarios.as_ref().ok_or(XlsxError::NotFound())?;
+        let index = scenarios
+            .scenario_list()
+            .iter()
+            .position(|x| x.name().eq_ignore_ascii_case(name))
+            .ok_or(XlsxError::NotFound())?;
+        let input_cells: Vec<(String, String)> = scenarios.scenario_list()[index]
+            .input_cells()
+            .iter()
+            .filter(|x| !x.deleted())
+            .map(|x| (x.reference().to_string(), x.value().to_string()))
+            .collect();
+        for (reference, value) in input_cells {
+            self.cell_mut(reference.as_str()).set_value(value);
+        }
+        self.scenarios_mut().set_current(num_traits::cast(index).unwrap());
+        Ok(())
+    }
+
     /// (This method is crate only.)
     /// Has Ole Objects.
     #[inline]
@@ -2700,7 +4051,7 @@ impl Worksheet {
     /// Has Legacy Drawing.
     #[inline]
     pub(crate) fn has_legacy_drawing(&self) -> bool {
-        self.has_comments() || self.has_ole_objects()
+        self.has_comments() || self.has_ole_objects() || self.has_form_controls()
     }
 
     /// Moving a section of the sheet
@@ -2774,6 +4125,111 @@ impl Worksheet {
         self
     }
 
+    /// Sort the rows of a section of the sheet.
+    /// Cells are moved together with their styles and hyperlinks.
+    /// Relative references in formulas inside the range are adjusted to the
+    /// new row, and comments attached to the moved cells follow them.
+    /// # Arguments
+    /// * `range` - Specify like "A2:D10" (without the header row)
+    /// * `keys` - Sort keys, evaluated in order. Each column must lie inside
+    ///   the range.
+    /// # Errors
+    /// Returns `XlsxError::InvalidArgument` when no key is given or a key
+    /// column is outside the range.
+    /// # Examples
+    /// ```
+    /// use umya_spreadsheet::SortKey;
+    ///
+    /// let mut book = umya_spreadsheet::new_file();
+    /// let sheet = book.sheet_mut(0).unwrap();
+    /// sheet.cell_mut("A1").set_value_number(3);
+    /// sheet.cell_mut("A2").set_value_number(1);
+    /// sheet.cell_mut("A3").set_value_number(2);
+    /// let mut key = SortKey::new("A");
+    /// key.set_descending(true);
+    /// sheet.sort_range("A1:A3", &[key]).unwrap();
+    /// assert_eq!(sheet.value("A1"), "3");
+    /// assert_eq!(sheet.value("A3"), "1");
+    /// ```
+    pub fn sort_range(&mut self, range: &str, keys: &[SortKey]) -> Result<(), XlsxError> {
+        if keys.is_empty() {
+            return Err(XlsxError::InvalidArgument(
+                "at least one sort key is required".to_string(),
+            ));
+        }
+        let (row_start, row_end, col_start, col_end) = get_start_and_end_point(range);
+        if let Some(key) = keys
+            .iter()
+            .find(|key| key.column_index() < col_start || key.column_index() > col_end)
+        {
+            return Err(XlsxError::InvalidArgument(format!(
+                "sort column {} is outside of {range}",
+                string_from_column_index(key.column_index())
+            )));
+        }
+
+        // Sort the row numbers by the key values.
+        let mut rows: Vec<u32> = (row_start..=row_end).collect();
+        rows.sort_by(|a, b| {
+            keys.iter()
+                .map(|key| {
+                    key.compare(
+                        self.cells.get((key.column_index(), *a)).map(Cell::raw_value),
+                        self.cells.get((key.column_index(), *b)).map(Cell::raw_value),
+                    )
+                })
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let new_rows: HashMap<u32, u32> = rows
+            .iter()
+            .zip(row_start..=row_end)
+            .filter(|(from, to)| *from != to)
+            .map(|(from, to)| (*from, to))
+            .collect();
+        if new_rows.is_empty() {
+            return Ok(());
+        }
+
+        // Move the cells.
+        let cells: Vec<Cell> = self
+            .cells
+            .iter_all_cells_by_range_sorted_by_row(range)
+            .flatten()
+            .filter(|cell| new_rows.contains_key(&cell.coordinate().row_num()))
+            .cloned()
+            .collect();
+        for cell in &cells {
+            self.cells.remove(cell.coordinate().col_num(), cell.coordinate().row_num());
+        }
+        for mut cell in cells {
+            let col_num = cell.coordinate().col_num();
+            let row_num = new_rows[&cell.coordinate().row_num()];
+            cell.set_coordinate((col_num, row_num));
+            self.set_cell(cell);
+        }
+
+        // Move the comments.
+        let is_target = |col_num: u32, row_num: u32| {
+            col_num >= col_start && col_num <= col_end && new_rows.contains_key(&row_num)
+        };
+        for comment in &mut self.comments {
+            let coordinate = comment.coordinate();
+            if is_target(coordinate.col_num(), coordinate.row_num()) {
+                let row_num = coordinate.row_num();
+                move_row_of(comment, row_num, new_rows[&row_num]);
+            }
+        }
+        for threaded_comment in &mut self.threaded_comments {
+            let coordinate = threaded_comment.coordinate();
+            if is_target(coordinate.col_num(), coordinate.row_num()) {
+                let row_num = coordinate.row_num();
+                move_row_of(threaded_comment, row_num, new_rows[&row_num]);
+            }
+        }
+        Ok(())
+    }
+
     /// Remove invisible garbage data.
     /// Doing so may reduce file size.
     /// Processing may take some time.
@@ -2879,6 +4335,38 @@ impl Worksheet {
         }
     }
 }
+// Shift an object from one row to another.
+fn move_row_of<T: AdjustmentCoordinate>(object: &mut T, from: u32, to: u32) {
+    if to > from {
+        object.adjustment_insert_coordinate(0, 0, 0, to - from);
+    } else {
+        object.adjustment_remove_coordinate(0, 0, 0, from - to);
+    }
+}
+
+// Bounds of a range of cells: (row start, row end, col start, col end).
+type CellArea = (u32, u32, u32, u32);
+
+// Get the bounds of a range of cells, or `None` when it is not a range of
+// cells, e.g. whole columns.
+pub(crate) fn cell_range_bounds(range: &str) -> Option<CellArea> {
+    let split_range = get_split_range(range);
+    let is_cell_range = matches!(split_range.len(), 1 | 2)
+        && split_range
+            .into_iter()
+            .all(|v| matches!(index_from_coordinate(v), (Some(_), Some(_), ..)));
+    is_cell_range.then(|| get_start_and_end_point(range))
+}
+
+fn area_contains(area: CellArea, row_num: u32, col_num: u32) -> bool {
+    let (row_start, row_end, col_start, col_end) = area;
+    (row_start..=row_end).contains(&row_num) && (col_start..=col_end).contains(&col_num)
+}
+
+fn area_overlaps(a: CellArea, b: CellArea) -> bool {
+    a.0 <= b.1 && b.0 <= a.1 && a.2 <= b.3 && b.2 <= a.3
+}
+
 impl AdjustmentCoordinate for Worksheet {
     fn adjustment_insert_coordinate(
         &mut self,
@@ -2939,6 +4427,16 @@ impl AdjustmentCoordinate for Worksheet {
             );
         }
 
+        // form controls
+        for form_control in &mut self.form_controls {
+            form_control.adjustment_insert_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+
         // conditional styles
         for conditional_styles in &mut self.conditional_formatting_collection {
             conditional_styles.adjustment_insert_coordinate(
@@ -2959,6 +4457,16 @@ impl AdjustmentCoordinate for Worksheet {
             );
         }
 
+        // tables
+        for table in &mut self.tables {
+            table.adjustment_insert_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+
         // auto filter
         if let Some(v) = self.auto_filter_mut() {
             v.adjustment_insert_coordinate(
@@ -3043,6 +4551,16 @@ impl AdjustmentCoordinate for Worksheet {
             );
         }
 
+        // form controls
+        for form_control in &mut self.form_controls {
+            form_control.adjustment_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+
         // conditional styles
         self.conditional_formatting_collection.retain(|x| {
             !x.is_remove_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num)
@@ -3069,6 +4587,19 @@ impl AdjustmentCoordinate for Worksheet {
             );
         }
 
+        // tables
+        self.tables.retain(|x| {
+            !x.is_remove_coordinate(root_col_num, offset_col_num, root_row_num, offset_row_num)
+        });
+        for table in &mut self.tables {
+            table.adjustment_remove_coordinate(
+                root_col_num,
+                offset_col_num,
+                root_row_num,
+                offset_row_num,
+            );
+        }
+
         // auto filter
         let is_remove = match self.auto_filter() {
             Some(v) => v.range().is_remove_coordinate(
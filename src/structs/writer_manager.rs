@@ -12,21 +12,27 @@ use crate::{
         CHART_TYPE,
         COMMENTS_TYPE,
         CORE_PROPS_TYPE,
+        CTRL_PROP_TYPE,
         CUSTOM_PROPS_TYPE,
         DRAWING_TYPE,
+        EXTERNAL_LINK_TYPE,
         OLE_OBJECT_TYPE,
+        PERSON_TYPE,
         PKG_CHARTS,
+        PKG_CTRL_PROPS,
         PKG_DRAWINGS,
         PKG_EMBEDDINGS,
         PKG_PRNTR_SETTINGS,
         PKG_TABLES,
         SHARED_STRINGS_TYPE,
+        SHEET_METADATA_TYPE,
         SHEET_TYPE,
         STYLES_TYPE,
         TABLE_TYPE,
         PIVOT_TABLE_TYPE,
         PIVOT_CACHE_DEF_TYPE,
         THEME_TYPE,
+        THREADED_COMMENTS_TYPE,
         VBA_TYPE,
         WORKBOOK_MACRO_TYPE,
         WORKBOOK_TYPE,
@@ -235,6 +241,21 @@ impl<'a, W: io::Seek + io::Write> WriterManager<'a, W> {
         }
     }
 
+    pub(crate) fn add_file_at_ctrl_prop(
+        &mut self,
+        writer: Writer<Cursor<Vec<u8>>>,
+    ) -> Result<i32, XlsxError> {
+        let mut index = 0;
+        loop {
+            index += 1;
+            let file_path = format!("{PKG_CTRL_PROPS}/ctrlProp{index}.xml");
+            if !self.check_file_exist(&file_path) {
+                self.add_writer(&file_path, writer)?;
+                return Ok(index);
+            }
+        }
+    }
+
     pub(crate) fn add_file_at_ole_object(&mut self, writer: &[u8]) -> Result<i32, XlsxError> {
         let mut index = 0;
         loop {
@@ -352,6 +373,26 @@ impl<'a, W: io::Seek + io::Write> WriterManager<'a, W> {
                 content_type = COMMENTS_TYPE;
             }
 
+            // Override threadedComments
+            if file.starts_with("/xl/threadedComments/threadedComment") {
+                content_type = THREADED_COMMENTS_TYPE;
+            }
+
+            // Override persons
+            if file.starts_with("/xl/persons/person") {
+                content_type = PERSON_TYPE;
+            }
+
+            // Override externalLinks
+            if file.starts_with("/xl/externalLinks/externalLink") {
+                content_type = EXTERNAL_LINK_TYPE;
+            }
+
+            // Override metadata
+            if file.starts_with("/xl/metadata.xml") {
+                content_type = SHEET_METADATA_TYPE;
+            }
+
             // Override theme
             if file.starts_with("/xl/theme/theme") {
                 content_type = THEME_TYPE;
@@ -377,6 +418,11 @@ impl<'a, W: io::Seek + io::Write> WriterManager<'a, W> {
                 content_type = CHART_TYPE;
             }
 
+            // Override ctrlProps
+            if file.starts_with("/xl/ctrlProps/ctrlProp") {
+                content_type = CTRL_PROP_TYPE;
+            }
+
             // Override embeddings
             if file.starts_with("/xl/embeddings/oleObject") {
                 content_type = OLE_OBJECT_TYPE;
@@ -12,6 +12,7 @@ use crate::{
     helper::crypt::encrypt,
     structs::{
         Workbook,
+        Worksheet,
         WriterManager,
     },
 };
@@ -19,14 +20,17 @@ use crate::{
 mod chart;
 mod comment;
 mod content_types;
+mod ctrl_prop;
 mod doc_props_app;
 mod doc_props_core;
 mod doc_props_custom;
 mod drawing;
 mod drawing_rels;
 mod embeddings;
+mod external_link;
 mod jsa_project_bin;
 mod media;
+mod metadata;
 mod person;
 mod pivot_cache;
 mod pivot_table;
@@ -67,6 +71,14 @@ fn write_zip_to_writer<W: io::Write + io::Seek>(
 
         let shared_string_table = wb.shared_string_table();
         let mut stylesheet = wb.stylesheet().clone();
+        let mut metadata = wb.metadata().clone();
+        if wb
+            .sheet_collection_no_check()
+            .iter()
+            .any(Worksheet::has_dynamic_array_formula)
+        {
+            metadata.add_dynamic_array();
+        }
 
         // Process each worksheet
         wb.sheet_collection_no_check()
@@ -80,6 +92,7 @@ fn write_zip_to_writer<W: io::Write + io::Seek>(
                         worksheet,
                         &shared_string_table,
                         &mut stylesheet,
+                        &metadata,
                         wb.has_macros(),
                         &mut writer_manager,
                     )
@@ -136,6 +149,9 @@ fn write_zip_to_writer<W: io::Write + io::Seek>(
                 // Add threaded_comment
                 let threaded_comment_no = threaded_comment::write(worksheet, &mut writer_manager)?;
 
+                // Add form control properties
+                let ctrl_prop_no_list = ctrl_prop::write(worksheet, &mut writer_manager)?;
+
                 // Add ole_object and excel
                 let (ole_object_no_list, excel_no_list) =
                     embeddings::write(worksheet, &mut writer_manager)?;
@@ -152,7 +168,7 @@ fn write_zip_to_writer<W: io::Write + io::Seek>(
                     });
 
                 // Add tables
-                let table_no_list = table::write(worksheet, &mut writer_manager)?;
+                let table_no_list = table::write(worksheet, &mut stylesheet, &mut writer_manager)?;
 
                 // Add pivot tables and caches
                 let pivot_table_no_list = pivot_table::write(worksheet, &mut writer_manager)?;
@@ -172,6 +188,7 @@ fn write_zip_to_writer<W: io::Write + io::Seek>(
                     &table_no_list,
                     &pivot_table_no_list,
                     &pivot_cache_no_list,
+                    &ctrl_prop_no_list,
                     &mut writer_manager,
                 )
             })?;
@@ -180,10 +197,17 @@ fn write_zip_to_writer<W: io::Write + io::Seek>(
         writer_manager.file_list_sort();
         shared_strings::write(&shared_string_table, &mut writer_manager)?;
         styles::write(&stylesheet, &mut writer_manager)?;
+        metadata::write(&metadata, &mut writer_manager)?;
+        external_link::write(wb, &mut writer_manager)?;
         workbook::write(wb, &mut writer_manager)?;
 
         let has_shared_string_table = shared_string_table.read().unwrap().has_value();
-        workbook_rels::write(wb, has_shared_string_table, &mut writer_manager)?;
+        workbook_rels::write(
+            wb,
+            has_shared_string_table,
+            !metadata.is_empty(),
+            &mut writer_manager,
+        )?;
         content_types::write(wb, &mut writer_manager)?;
     }
 
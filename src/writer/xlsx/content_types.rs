@@ -97,6 +97,26 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         }
     }
 
+    // Write content types of extensions only used by parts that are not modeled
+    for (extension, content_type) in wb.backup_default_types() {
+        let extension = extension.to_lowercase();
+        let is_written = default_content_types
+            .iter()
+            .chain(optional_extensions.iter())
+            .any(|(v, _)| *v == extension);
+        if !is_written && writer_mng.has_extension(&extension) {
+            write_start_tag(
+                &mut writer,
+                "Default",
+                vec![
+                    ("Extension", extension.as_str()).into(),
+                    ("ContentType", &**content_type).into(),
+                ],
+                true,
+            );
+        }
+    }
+
     // Override
     for (part_name, content_type) in writer_mng.make_context_type_override(wb) {
         write_start_tag(
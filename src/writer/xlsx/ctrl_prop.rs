@@ -0,0 +1,45 @@
+use std::io;
+
+use quick_xml::{
+    Writer,
+    events::{
+        BytesDecl,
+        Event,
+    },
+};
+
+use super::{
+    XlsxError,
+    driver::write_new_line,
+};
+use crate::structs::{
+    Worksheet,
+    WriterManager,
+};
+
+pub(crate) fn write<W: io::Seek + io::Write>(
+    worksheet: &Worksheet,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<Vec<String>, XlsxError> {
+    let mut ctrl_prop_no_list = Vec::<String>::new();
+    for form_control in worksheet.form_controls() {
+        let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+
+        // XML header
+        writer
+            .write_event(Event::Decl(BytesDecl::new(
+                "1.0",
+                Some("UTF-8"),
+                Some("yes"),
+            )))
+            .unwrap();
+        write_new_line(&mut writer);
+
+        // formControlPr
+        form_control.write_ctrl_prop_to(&mut writer);
+
+        let ctrl_prop_no = writer_mng.add_file_at_ctrl_prop(writer)?;
+        ctrl_prop_no_list.push(ctrl_prop_no.to_string());
+    }
+    Ok(ctrl_prop_no_list)
+}
@@ -0,0 +1,119 @@
+use std::io;
+
+use quick_xml::{
+    Writer,
+    events::{
+        BytesDecl,
+        Event,
+    },
+};
+
+use super::{
+    XlsxError,
+    driver::{
+        write_end_tag,
+        write_new_line,
+        write_start_tag,
+    },
+};
+use crate::{
+    helper::const_str::{
+        PKG_EXTERNAL_LINKS,
+        REL_NS,
+    },
+    structs::{
+        ExternalLink,
+        Workbook,
+        WriterManager,
+    },
+};
+
+pub(crate) fn write<W: io::Seek + io::Write>(
+    wb: &Workbook,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<(), XlsxError> {
+    for (index, external_link) in wb.external_links().iter().enumerate() {
+        let file_name = format!("externalLink{}.xml", index + 1);
+        write_part(external_link, &file_name, writer_mng)?;
+        write_rels(external_link, &file_name, writer_mng)?;
+    }
+    Ok(())
+}
+
+fn write_part<W: io::Seek + io::Write>(
+    external_link: &ExternalLink,
+    file_name: &str,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<(), XlsxError> {
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    // XML header
+    writer
+        .write_event(Event::Decl(BytesDecl::new(
+            "1.0",
+            Some("UTF-8"),
+            Some("yes"),
+        )))
+        .unwrap();
+    write_new_line(&mut writer);
+
+    // externalLink
+    external_link.write_to(&mut writer, "rId1");
+
+    let target = format!("{PKG_EXTERNAL_LINKS}/{file_name}");
+    writer_mng.add_writer(&target, writer)
+}
+
+fn write_rels<W: io::Seek + io::Write>(
+    external_link: &ExternalLink,
+    file_name: &str,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<(), XlsxError> {
+    let has_target = external_link.has_external_book() && !external_link.target().is_empty();
+    if !has_target && !external_link.raw_unknown().has_parts() {
+        return Ok(());
+    }
+
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    // XML header
+    writer
+        .write_event(Event::Decl(BytesDecl::new(
+            "1.0",
+            Some("UTF-8"),
+            Some("yes"),
+        )))
+        .unwrap();
+    write_new_line(&mut writer);
+
+    // relationships
+    write_start_tag(
+        &mut writer,
+        "Relationships",
+        vec![("xmlns", REL_NS).into()],
+        false,
+    );
+
+    // relationship of the linked workbook
+    if has_target {
+        write_start_tag(
+            &mut writer,
+            "Relationship",
+            vec![
+                ("Id", "rId1").into(),
+                ("Type", external_link.relationship_type()).into(),
+                ("Target", external_link.target()).into(),
+                ("TargetMode", "External").into(),
+            ],
+            true,
+        );
+    }
+
+    // relationships of parts that are not modeled
+    external_link
+        .raw_unknown()
+        .write_parts(&mut writer, writer_mng)?;
+
+    write_end_tag(&mut writer, "Relationships");
+
+    let target = format!("{PKG_EXTERNAL_LINKS}/_rels/{file_name}.rels");
+    writer_mng.add_writer(&target, writer)
+}
@@ -0,0 +1,46 @@
+use std::io;
+
+use quick_xml::{
+    Writer,
+    events::{
+        BytesDecl,
+        Event,
+    },
+};
+
+use super::{
+    XlsxError,
+    driver::write_new_line,
+};
+use crate::{
+    helper::const_str::PKG_METADATA,
+    structs::{
+        Metadata,
+        WriterManager,
+    },
+};
+
+pub(crate) fn write<W: io::Seek + io::Write>(
+    metadata: &Metadata,
+    writer_mng: &mut WriterManager<W>,
+) -> Result<(), XlsxError> {
+    if metadata.is_empty() {
+        return Ok(());
+    }
+
+    let mut writer = Writer::new(io::Cursor::new(Vec::new()));
+    // XML header
+    writer
+        .write_event(Event::Decl(BytesDecl::new(
+            "1.0",
+            Some("UTF-8"),
+            Some("yes"),
+        )))
+        .unwrap();
+    write_new_line(&mut writer);
+
+    // metadata
+    metadata.write_to(&mut writer);
+
+    writer_mng.add_writer(PKG_METADATA, writer)
+}
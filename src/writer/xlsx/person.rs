@@ -47,7 +47,7 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         .unwrap();
     write_new_line(&mut writer);
 
-    // comments
+    // personList
     write_start_tag(
         &mut writer,
         "personList",
@@ -58,6 +58,10 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         false,
     );
 
+    for person in wb.merged_person_list() {
+        person.write_to(&mut writer);
+    }
+
     write_end_tag(&mut writer, "personList");
     writer_mng.add_writer(PKG_PERSON, writer)
 }
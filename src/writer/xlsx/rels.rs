@@ -25,6 +25,7 @@ use crate::{
         CUSTOM_PROPS_REL,
         CUSTOMUI_NS,
         OFCDOC_NS,
+        PKG_RELS,
         PKG_WORKBOOK,
         REL_NS,
         XPROPS_REL,
@@ -88,10 +89,13 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         );
     }
 
+    // relationships of parts that are not modeled
+    wb.raw_package_unknown()
+        .write_parts(&mut writer, writer_mng)?;
+
     write_end_tag(&mut writer, "Relationships");
 
-    let target = "_rels/.rels";
-    writer_mng.add_writer(target, writer)
+    writer_mng.add_writer(PKG_RELS, writer)
 }
 
 fn write_relationship(
@@ -20,6 +20,7 @@ use super::{
 use crate::{
     helper::const_str::SHEET_MAIN_NS,
     structs::{
+        Stylesheet,
         Worksheet,
         WriterManager,
     },
@@ -27,6 +28,7 @@ use crate::{
 
 pub(crate) fn write<W: io::Seek + io::Write>(
     worksheet: &Worksheet,
+    stylesheet: &mut Stylesheet,
     writer_mng: &mut WriterManager<W>,
 ) -> Result<Vec<String>, XlsxError> {
     let mut table_no_list = Vec::<String>::new();
@@ -68,7 +70,24 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         write_start_tag(&mut writer, "table", attributes, false);
 
         // autoFilter
-        write_start_tag(&mut writer, "autoFilter", vec![("ref", &area).into()], true);
+        let filter_column_list = table.filter_column_list();
+        write_start_tag(
+            &mut writer,
+            "autoFilter",
+            vec![("ref", &table.auto_filter_range()).into()],
+            filter_column_list.is_empty(),
+        );
+        if !filter_column_list.is_empty() {
+            for filter_column in filter_column_list {
+                filter_column.write_to(&mut writer, stylesheet.differential_formats_mut());
+            }
+            write_end_tag(&mut writer, "autoFilter");
+        }
+
+        // sortState
+        if let Some(v) = table.sort_state() {
+            v.write_to(&mut writer, stylesheet.differential_formats_mut());
+        }
 
         // tableColumns
         let cols = table.columns();
@@ -222,6 +222,60 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         }
     }
 
+    // form control
+    if worksheet.has_form_controls() {
+        // v:shapetype
+        write_start_tag(
+            &mut writer,
+            "v:shapetype",
+            vec![
+                ("id", "_x0000_t201").into(),
+                ("coordsize", "21600,21600").into(),
+                ("o:spt", "201").into(),
+                ("path", "m,l,21600r21600,l21600,xe").into(),
+            ],
+            false,
+        );
+
+        // v:stroke
+        write_start_tag(
+            &mut writer,
+            "v:stroke",
+            vec![("joinstyle", "miter").into()],
+            true,
+        );
+
+        // v:path
+        write_start_tag(
+            &mut writer,
+            "v:path",
+            vec![
+                ("shadowok", "f").into(),
+                ("o:extrusionok", "f").into(),
+                ("strokeok", "f").into(),
+                ("fillok", "f").into(),
+                ("o:connecttype", "rect").into(),
+            ],
+            true,
+        );
+
+        // o:lock
+        write_start_tag(
+            &mut writer,
+            "o:lock",
+            vec![("v:ext", "edit").into(), ("shapetype", "t").into()],
+            true,
+        );
+
+        write_end_tag(&mut writer, "v:shapetype");
+
+        for form_control in worksheet.form_controls() {
+            // v:shape
+            form_control.shape().write_to(&mut writer, id, &mut rel_list);
+            id += 1;
+        }
+    }
+
     write_end_tag(&mut writer, "xml");
 
     let file_no = writer_mng.add_file_at_vml_drawing(writer)?;
@@ -25,6 +25,7 @@ use crate::{
     structs::{
         Workbook,
         WriterManager,
+        raw::WORKBOOK_ELEMENTS,
     },
 };
 
@@ -44,15 +45,13 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     write_new_line(&mut writer);
 
     // workbook
-    write_start_tag(
-        &mut writer,
-        "workbook",
-        vec![
-            ("xmlns", SHEET_MAIN_NS).into(),
-            ("xmlns:r", REL_OFC_NS).into(),
-        ],
-        false,
-    );
+    let raw_unknown = wb.raw_unknown();
+    let mut attributes: crate::structs::AttrCollection = vec![
+        ("xmlns", SHEET_MAIN_NS).into(),
+        ("xmlns:r", REL_OFC_NS).into(),
+    ];
+    raw_unknown.extend_root_attributes(&mut attributes);
+    write_start_tag(&mut writer, "workbook", attributes, false);
 
     // fileVersion
     write_start_tag(
@@ -66,6 +65,7 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         ],
         true,
     );
+    raw_unknown.write_elements(&mut writer, WORKBOOK_ELEMENTS, "fileVersion", "workbookPr");
 
     // workbookPr
     let mut attributes: crate::structs::AttrCollection = Vec::new();
@@ -75,6 +75,12 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         attributes.push(("codeName", wb.code_name().unwrap_or("ThisWorkbook")).into());
     }
     write_start_tag(&mut writer, "workbookPr", attributes, true);
+    raw_unknown.write_elements(
+        &mut writer,
+        WORKBOOK_ELEMENTS,
+        "workbookPr",
+        "workbookProtection",
+    );
 
     // workbookProtection
     if let Some(v) = wb.workbook_protection() {
@@ -110,6 +116,34 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         index += 1;
     }
     write_end_tag(&mut writer, "sheets");
+    raw_unknown.write_elements(
+        &mut writer,
+        WORKBOOK_ELEMENTS,
+        "workbookProtection",
+        "externalReferences",
+    );
+
+    // externalReferences
+    if !wb.external_links().is_empty() {
+        write_start_tag(&mut writer, "externalReferences", vec![], false);
+        let first_index = index + wb.pivot_caches().len();
+        for external_link_index in first_index..first_index + wb.external_links().len() {
+            let r_id = format!("rId{external_link_index}");
+            write_start_tag(
+                &mut writer,
+                "externalReference",
+                vec![("r:id", &r_id).into()],
+                true,
+            );
+        }
+        write_end_tag(&mut writer, "externalReferences");
+    }
+    raw_unknown.write_elements(
+        &mut writer,
+        WORKBOOK_ELEMENTS,
+        "externalReferences",
+        "definedNames",
+    );
 
     // definedNames
     if wb.has_defined_names() {
@@ -141,6 +175,7 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         ],
         true,
     );
+    raw_unknown.write_elements(&mut writer, WORKBOOK_ELEMENTS, "definedNames", "pivotCaches");
 
     // pivotCaches
     let pivot_cache_definition_collection = wb.pivot_caches();
@@ -158,6 +193,11 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         }
         write_end_tag(&mut writer, "pivotCaches");
     }
+    raw_unknown.write_elements(&mut writer, WORKBOOK_ELEMENTS, "pivotCaches", "extLst");
+
+    // extLst
+    raw_unknown.write_ext_lst(&mut writer);
+    raw_unknown.write_elements(&mut writer, WORKBOOK_ELEMENTS, "extLst", "");
 
     write_end_tag(&mut writer, "workbook");
 
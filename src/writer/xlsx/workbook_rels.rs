@@ -19,12 +19,14 @@ use super::{
 };
 use crate::{
     helper::const_str::{
+        EXTERNAL_LINK_NS,
         JSA_PROJECT_NS,
         PERSION_NS,
         PIVOT_CACHE_DEF_NS,
         PKG_WORKBOOK_RELS,
         REL_NS,
         SHARED_STRINGS_NS,
+        SHEET_METADATA_NS,
         STYLES_NS,
         THEME_NS,
         VBA_PROJECT_NS,
@@ -39,6 +41,7 @@ use crate::{
 pub(crate) fn write<W: io::Seek + io::Write>(
     wb: &Workbook,
     has_shared_string_table: bool,
+    has_metadata: bool,
     writer_mng: &mut WriterManager<W>,
 ) -> Result<(), XlsxError> {
     let is_light = writer_mng.get_is_light();
@@ -79,6 +82,18 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         index += 1;
     }
 
+    // relationships external_link
+    for number in 1..=wb.external_links().len() {
+        write_relationship(
+            &mut writer,
+            &index.to_string(),
+            EXTERNAL_LINK_NS,
+            &format!("externalLinks/externalLink{number}.xml"),
+            "",
+        );
+        index += 1;
+    }
+
     // relationship styles.xml
     write_relationship(&mut writer, &index.to_string(), STYLES_NS, "styles.xml", "");
     index += 1;
@@ -138,8 +153,23 @@ pub(crate) fn write<W: io::Seek + io::Write>(
             "persons/person.xml",
             "",
         );
+        index += 1;
     }
 
+    // relationship metadata.xml
+    if has_metadata {
+        write_relationship(
+            &mut writer,
+            &index.to_string(),
+            SHEET_METADATA_NS,
+            "metadata.xml",
+            "",
+        );
+    }
+
+    // relationships of parts that are not modeled
+    wb.raw_unknown().write_parts(&mut writer, writer_mng)?;
+
     write_end_tag(&mut writer, root_tag_name);
     make_file_from_writer(
         PKG_WORKBOOK_RELS,
@@ -1,5 +1,8 @@
 use std::{
-    collections::HashMap,
+    collections::{
+        HashMap,
+        HashSet,
+    },
     io,
     sync::RwLock,
 };
@@ -19,24 +22,36 @@ use super::{
         write_new_line,
         write_start_tag,
     },
+    worksheet_rels::RelationshipIds,
 };
 use crate::{
     Row,
-    helper::const_str::{
-        MC_NS,
-        PKG_SHEET,
-        REL_OFC_NS,
-        SHEET_DRAWING_NS,
-        SHEET_MAIN_NS,
-        SHEET_MS_MAIN_NS,
-        SHEETML_AC_NS,
+    helper::{
+        const_str::{
+            MC_NS,
+            PKG_SHEET,
+            REL_OFC_NS,
+            SHEET_DRAWING_NS,
+            SHEET_MAIN_NS,
+            SHEET_MS_MAIN_NS,
+            SHEETML_AC_NS,
+        },
+        coordinate::coordinate_from_index,
+        formula::{
+            parse_to_tokens,
+            translate_formula,
+        },
     },
     structs::{
         Cell,
+        CellFormula,
+        Metadata,
+        SharedFormulaGroup,
         SharedStringTable,
         Stylesheet,
         Worksheet,
         WriterManager,
+        raw::WORKSHEET_ELEMENTS,
     },
 };
 
@@ -50,6 +65,7 @@ type InternalWriter = Writer<io::Cursor<Vec<u8>>>;
 /// * `worksheet` - The worksheet to write
 /// * `shared_string_table` - Table containing shared strings
 /// * `stylesheet` - The workbook's stylesheet
+/// * `metadata` - The workbook's cell metadata
 /// * `has_macros` - Whether the workbook contains macros
 /// * `writer_mng` - The writer manager handling file output
 ///
@@ -61,15 +77,24 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     worksheet: &Worksheet,
     shared_string_table: &RwLock<SharedStringTable>,
     stylesheet: &mut Stylesheet,
+    metadata: &Metadata,
     has_macros: bool,
     writer_mng: &mut WriterManager<W>,
 ) -> Result<(), XlsxError> {
+    worksheet.check_array_formulas()?;
+
     let mut writer = Writer::new(io::Cursor::new(Vec::new()));
 
-    write_worksheet_header(&mut writer);
+    write_worksheet_header(&mut writer, worksheet);
     write_worksheet_properties(&mut writer, worksheet, has_macros);
     write_dimension_and_views(&mut writer, worksheet);
-    write_columns_and_rows(&mut writer, worksheet, shared_string_table, stylesheet);
+    write_columns_and_rows(
+        &mut writer,
+        worksheet,
+        shared_string_table,
+        stylesheet,
+        metadata,
+    );
     write_worksheet_features(&mut writer, worksheet, stylesheet);
     write_worksheet_extensions(&mut writer, worksheet);
 
@@ -85,7 +110,8 @@ pub(crate) fn write<W: io::Seek + io::Write>(
 /// # Arguments
 ///
 /// * `writer` - The XML writer to write to
-fn write_worksheet_header(writer: &mut InternalWriter) {
+/// * `worksheet` - The worksheet whose original namespaces are kept
+fn write_worksheet_header(writer: &mut InternalWriter, worksheet: &Worksheet) {
     writer
         .write_event(Event::Decl(BytesDecl::new(
             "1.0",
@@ -95,20 +121,19 @@ fn write_worksheet_header(writer: &mut InternalWriter) {
         .unwrap();
     write_new_line(writer);
 
-    write_start_tag(
-        writer,
-        "worksheet",
-        vec![
-            ("xmlns", SHEET_MAIN_NS).into(),
-            ("xmlns:r", REL_OFC_NS).into(),
-            ("xmlns:xdr", SHEET_DRAWING_NS).into(),
-            ("xmlns:x14", SHEET_MS_MAIN_NS).into(),
-            ("xmlns:mc", MC_NS).into(),
-            ("mc:Ignorable", "x14ac").into(),
-            ("xmlns:x14ac", SHEETML_AC_NS).into(),
-        ],
-        false,
-    );
+    let mut attributes: crate::structs::AttrCollection = vec![
+        ("xmlns", SHEET_MAIN_NS).into(),
+        ("xmlns:r", REL_OFC_NS).into(),
+        ("xmlns:xdr", SHEET_DRAWING_NS).into(),
+        ("xmlns:x14", SHEET_MS_MAIN_NS).into(),
+        ("xmlns:mc", MC_NS).into(),
+        ("mc:Ignorable", "x14ac").into(),
+        ("xmlns:x14ac", SHEETML_AC_NS).into(),
+    ];
+    worksheet
+        .raw_unknown()
+        .extend_root_attributes(&mut attributes);
+    write_start_tag(writer, "worksheet", attributes, false);
 }
 
 /// Writes worksheet properties including sheet protection and tab color
@@ -174,18 +199,27 @@ fn write_dimension_and_views(writer: &mut InternalWriter, worksheet: &Worksheet)
 /// * `worksheet` - The worksheet containing the data
 /// * `shared_string_table` - Table containing shared string values
 /// * `stylesheet` - The workbook's stylesheet for formatting
+/// * `metadata` - The workbook's cell metadata
 fn write_columns_and_rows(
     writer: &mut InternalWriter,
     worksheet: &Worksheet,
     shared_string_table: &RwLock<SharedStringTable>,
     stylesheet: &mut Stylesheet,
+    metadata: &Metadata,
 ) {
     let mut column_dimensions = worksheet.column_dimensions_crate().clone();
     column_dimensions
         .calculation_auto_width(worksheet.cells_crate(), worksheet.merge_cells_crate());
     column_dimensions.write_to(writer, stylesheet);
 
-    write_sheet_data(writer, worksheet, shared_string_table, stylesheet);
+    write_sheet_data(
+        writer,
+        worksheet,
+        shared_string_table,
+        stylesheet,
+        metadata,
+    );
+    write_unknown_elements(writer, worksheet, "sheetPr", "sheetProtection");
 }
 
 /// Writes the sheet data section containing rows and cells.
@@ -196,11 +230,13 @@ fn write_columns_and_rows(
 /// * `worksheet` - The worksheet containing the data
 /// * `shared_string_table` - Table containing shared string values
 /// * `stylesheet` - The workbook's stylesheet for formatting
+/// * `metadata` - The workbook's cell metadata
 fn write_sheet_data(
     writer: &mut InternalWriter,
     worksheet: &Worksheet,
     shared_string_table: &RwLock<SharedStringTable>,
     stylesheet: &mut Stylesheet,
+    metadata: &Metadata,
 ) {
     let has_sheet_data = worksheet.has_sheet_data();
     write_start_tag(writer, "sheetData", vec![], !has_sheet_data);
@@ -216,6 +252,7 @@ fn write_sheet_data(
         shared_string_table,
         stylesheet,
         &formula_shared_list,
+        metadata,
     );
 
     if has_sheet_data {
@@ -223,32 +260,82 @@ fn write_sheet_data(
     }
 }
 
-/// Creates a map of shared formulas indexed by their shared formula ID.
+/// Creates a map of shared formula groups indexed by their shared formula ID.
+///
+/// The master of a group is its first member holding the formula text. A
+/// member only stays in the group while its formula is empty or still the
+/// master's translated to its position, and the group's `ref` is narrowed to
+/// the master and those members.
 ///
 /// # Arguments
 ///
-/// * `cells` - Slice of cell references to process
+/// * `cells` - Slice of cell references to process, sorted by position
 ///
 /// # Returns
 ///
-/// A `HashMap` mapping formula IDs to tuples of (`start_cell_ref`,
-/// `end_cell_ref`)
-fn build_formula_shared_list(cells: &[&Cell]) -> HashMap<u32, (String, Option<String>)> {
-    let mut formula_shared_list: HashMap<u32, (String, Option<String>)> = HashMap::new();
+/// A `HashMap` mapping formula IDs to their `SharedFormulaGroup`
+fn build_formula_shared_list(cells: &[&Cell]) -> HashMap<u32, SharedFormulaGroup> {
+    let mut members: HashMap<u32, Vec<&Cell>> = HashMap::new();
     for cell in cells {
         if let Some(si) = cell.formula_shared_index() {
-            match formula_shared_list.get(&si) {
-                Some((start_cell, _)) => {
-                    formula_shared_list.insert(
-                        si,
-                        (start_cell.clone(), Some(cell.coordinate().get_coordinate())),
-                    );
-                }
-                None => {
-                    formula_shared_list.insert(si, (cell.coordinate().get_coordinate(), None));
-                }
+            members.entry(si).or_default().push(cell);
+        }
+    }
+
+    let mut formula_shared_list: HashMap<u32, SharedFormulaGroup> = HashMap::new();
+    for (si, cells) in members {
+        let Some(master) = cells
+            .iter()
+            .find(|cell| cell.formula_obj().is_some_and(CellFormula::is_shared_master))
+        else {
+            continue;
+        };
+        let master_col_num = master.coordinate().col_num();
+        let master_row_num = master.coordinate().row_num();
+        let token_list = parse_to_tokens(format!("={}", master.formula()));
+
+        let mut end_col_num = master_col_num;
+        let mut end_row_num = master_row_num;
+        let mut dependents = HashSet::new();
+        for cell in &cells {
+            let col_num = cell.coordinate().col_num();
+            let row_num = cell.coordinate().row_num();
+            if col_num < master_col_num
+                || row_num < master_row_num
+                || (col_num == master_col_num && row_num == master_row_num)
+            {
+                continue;
+            }
+            let offset_col_num = num_traits::cast::<_, i32>(col_num - master_col_num).unwrap();
+            let offset_row_num = num_traits::cast::<_, i32>(row_num - master_row_num).unwrap();
+            // A member without its own text takes the master's as is.
+            if !cell.formula().is_empty()
+                && translate_formula(&token_list, offset_col_num, offset_row_num) != cell.formula()
+            {
+                continue;
             }
+            end_col_num = end_col_num.max(col_num);
+            end_row_num = end_row_num.max(row_num);
+            dependents.insert(cell.coordinate().get_coordinate());
         }
+
+        let master = master.coordinate().get_coordinate();
+        let reference = if dependents.is_empty() {
+            master.clone()
+        } else {
+            format!(
+                "{master}:{}",
+                coordinate_from_index(end_col_num, end_row_num)
+            )
+        };
+        formula_shared_list.insert(
+            si,
+            SharedFormulaGroup {
+                master,
+                reference,
+                dependents,
+            },
+        );
     }
     formula_shared_list
 }
@@ -269,15 +356,17 @@ fn write_worksheet_features(
     if let Some(v) = worksheet.sheet_protection() {
         v.write_to(writer);
     }
+    write_unknown_elements(writer, worksheet, "sheetProtection", "scenarios");
+
+    if let Some(v) = worksheet.scenarios() {
+        v.write_to(writer);
+    }
+    write_unknown_elements(writer, worksheet, "scenarios", "autoFilter");
 
     if let Some(v) = worksheet.auto_filter() {
-        write_start_tag(
-            writer,
-            "autoFilter",
-            vec![("ref", &v.range().range()).into()],
-            true,
-        );
+        v.write_to(writer, stylesheet.differential_formats_mut());
     }
+    write_unknown_elements(writer, worksheet, "autoFilter", "mergeCells");
 
     worksheet.merge_cells_crate().write_to(writer);
     write_start_tag(writer, "phoneticPr", vec![("fontId", "1").into()], true);
@@ -299,18 +388,46 @@ fn write_worksheet_features(
 /// * `writer` - The XML writer to write to
 /// * `worksheet` - The worksheet containing the extensions
 fn write_worksheet_extensions(writer: &mut InternalWriter, worksheet: &Worksheet) {
-    let mut r_id = write_hyperlinks(writer, worksheet);
-    r_id = write_print_settings(writer, worksheet, r_id);
-    r_id = write_drawings(writer, worksheet, r_id);
-    write_tables_and_objects(writer, worksheet, r_id);
-
-    if worksheet.data_validations_2010().is_some() {
+    let r_ids = RelationshipIds::new(worksheet);
+    write_hyperlinks(writer, worksheet, r_ids.hyperlink);
+    write_print_settings(writer, worksheet, r_ids.printer_settings);
+    write_unknown_elements(writer, worksheet, "mergeCells", "drawing");
+    write_drawings(writer, worksheet, &r_ids);
+    write_unknown_elements(writer, worksheet, "drawing", "oleObjects");
+    write_ole_objects_and_controls(writer, worksheet, &r_ids);
+    write_unknown_elements(writer, worksheet, "oleObjects", "tableParts");
+    write_table_parts(writer, worksheet, r_ids.table);
+    write_unknown_elements(writer, worksheet, "tableParts", "extLst");
+
+    if worksheet.data_validations_2010().is_some() || worksheet.raw_unknown().has_extensions() {
         write_start_tag(writer, "extLst", vec![], false);
         if let Some(v) = worksheet.data_validations_2010() {
             v.write_to(writer);
         }
+        worksheet.raw_unknown().write_extensions(writer);
         write_end_tag(writer, "extLst");
     }
+    write_unknown_elements(writer, worksheet, "extLst", "");
+}
+
+/// Writes the worksheet elements that are not modeled and were read between
+/// the schema elements `after` and `before`.
+///
+/// # Arguments
+///
+/// * `writer` - The XML writer to write to
+/// * `worksheet` - The worksheet containing the elements
+/// * `after` - The schema element the elements follow
+/// * `before` - The schema element the elements precede, empty for the end
+fn write_unknown_elements(
+    writer: &mut InternalWriter,
+    worksheet: &Worksheet,
+    after: &str,
+    before: &str,
+) {
+    worksheet
+        .raw_unknown()
+        .write_elements(writer, WORKSHEET_ELEMENTS, after, before);
 }
 
 /// Writes rows and their contained cells to the worksheet.
@@ -323,13 +440,15 @@ fn write_worksheet_extensions(writer: &mut InternalWriter, worksheet: &Worksheet
 /// * `shared_string_table` - Table containing shared string values
 /// * `stylesheet` - The workbook's stylesheet for formatting
 /// * `formula_shared_list` - Map of shared formula definitions
+/// * `metadata` - The workbook's cell metadata
 fn write_rows_and_cells(
     writer: &mut InternalWriter,
     row_dimensions: &[&Row],
     cells: &[&Cell],
     shared_string_table: &RwLock<SharedStringTable>,
     stylesheet: &mut Stylesheet,
-    formula_shared_list: &HashMap<u32, (String, Option<String>)>,
+    formula_shared_list: &HashMap<u32, SharedFormulaGroup>,
+    metadata: &Metadata,
 ) {
     let mut cells_iter = cells.iter().peekable();
 
@@ -350,6 +469,7 @@ fn write_rows_and_cells(
             shared_string_table,
             stylesheet,
             formula_shared_list,
+            metadata,
         );
     }
 }
@@ -364,13 +484,15 @@ fn write_rows_and_cells(
 /// * `shared_string_table` - Table containing shared string values
 /// * `stylesheet` - The workbook's stylesheet for formatting
 /// * `formula_shared_list` - Map of shared formula definitions
+/// * `metadata` - The workbook's cell metadata
 fn write_row_with_cells(
     writer: &mut InternalWriter,
     row: &Row,
     cells_in_row: &[&Cell],
     shared_string_table: &RwLock<SharedStringTable>,
     stylesheet: &mut Stylesheet,
-    formula_shared_list: &HashMap<u32, (String, Option<String>)>,
+    formula_shared_list: &HashMap<u32, SharedFormulaGroup>,
+    metadata: &Metadata,
 ) {
     if cells_in_row.is_empty() {
         let spans = "0:0";
@@ -385,26 +507,27 @@ fn write_row_with_cells(
         row.write_to(writer, stylesheet, &spans, false);
 
         for cell in cells_in_row {
-            cell.write_to(writer, shared_string_table, stylesheet, formula_shared_list);
+            cell.write_to(
+                writer,
+                shared_string_table,
+                stylesheet,
+                formula_shared_list,
+                metadata,
+            );
         }
 
         write_end_tag(writer, "row");
     }
 }
 
-/// Writes hyperlinks and returns the next available relationship ID.
+/// Writes hyperlinks.
 ///
 /// # Arguments
 ///
 /// * `writer` - The XML writer to write to
 /// * `worksheet` - The worksheet containing hyperlinks
-///
-/// # Returns
-///
-/// The next available relationship ID
-fn write_hyperlinks(writer: &mut InternalWriter, worksheet: &Worksheet) -> i32 {
-    let mut r_id = 1;
-
+/// * `r_id` - The relationship ID of the first external hyperlink
+fn write_hyperlinks(writer: &mut InternalWriter, worksheet: &Worksheet, mut r_id: i32) {
     if worksheet.has_hyperlink() {
         write_start_tag(writer, "hyperlinks", vec![], false);
 
@@ -426,23 +549,16 @@ fn write_hyperlinks(writer: &mut InternalWriter, worksheet: &Worksheet) -> i32 {
 
         write_end_tag(writer, "hyperlinks");
     }
-
-    r_id
 }
 
-/// Writes print settings and returns the next available relationship ID.
+/// Writes print settings.
 ///
 /// # Arguments
 ///
 /// * `writer` - The XML writer to write to
 /// * `worksheet` - The worksheet containing print settings
-/// * `r_id` - The current relationship ID
-///
-/// # Returns
-///
-/// The next available relationship ID
-fn write_print_settings(writer: &mut InternalWriter, worksheet: &Worksheet, r_id: i32) -> i32 {
-    let mut r_id = r_id;
+/// * `r_id` - The relationship ID of the printer settings
+fn write_print_settings(writer: &mut InternalWriter, worksheet: &Worksheet, r_id: i32) {
     worksheet.print_options().write_to(writer);
     worksheet.page_margins().write_to(writer);
 
@@ -450,56 +566,65 @@ fn write_print_settings(writer: &mut InternalWriter, worksheet: &Worksheet, r_id
         worksheet
             .page_setup()
             .write_to(writer, &mut num_traits::cast(r_id).unwrap());
-        r_id += 1;
     }
 
     worksheet.header_footer().write_to(writer);
     worksheet.row_breaks().write_to(writer);
     worksheet.column_breaks().write_to(writer);
-
-    r_id
 }
 
-/// Writes drawing objects and returns the next available relationship ID.
+/// Writes drawing objects.
 ///
 /// # Arguments
 ///
 /// * `writer` - The XML writer to write to
 /// * `worksheet` - The worksheet containing drawings
-/// * `r_id` - The current relationship ID
-///
-/// # Returns
-///
-/// The next available relationship ID
-fn write_drawings(writer: &mut InternalWriter, worksheet: &Worksheet, mut r_id: i32) -> i32 {
+/// * `r_ids` - The relationship IDs of the worksheet
+fn write_drawings(writer: &mut InternalWriter, worksheet: &Worksheet, r_ids: &RelationshipIds) {
     if worksheet.has_drawing_object() {
-        let r_id_str = format!("rId{r_id}");
+        let r_id_str = format!("rId{}", r_ids.drawing);
         write_start_tag(writer, "drawing", vec![("r:id", &r_id_str).into()], true);
-        r_id += 1;
     }
 
     if worksheet.has_legacy_drawing() {
-        let r_id_str = format!("rId{r_id}");
+        let r_id_str = format!("rId{}", r_ids.legacy_drawing);
         write_start_tag(
             writer,
             "legacyDrawing",
             vec![("r:id", &r_id_str).into()],
             true,
         );
-        r_id += 1;
     }
+}
+
+/// Writes OLE objects and form controls.
+///
+/// # Arguments
+///
+/// * `writer` - The XML writer to write to
+/// * `worksheet` - The worksheet containing the objects
+/// * `r_ids` - The relationship IDs of the worksheet
+fn write_ole_objects_and_controls(
+    writer: &mut InternalWriter,
+    worksheet: &Worksheet,
+    r_ids: &RelationshipIds,
+) {
+    let ole_id = 1000 + 25;
+    worksheet
+        .ole_objects()
+        .write_to(writer, num_traits::cast(r_ids.ole_object).unwrap(), ole_id);
 
-    r_id
+    write_form_controls(writer, worksheet, r_ids.ctrl_prop);
 }
 
-/// Writes tables and OLE objects.
+/// Writes the parts of the tables.
 ///
 /// # Arguments
 ///
 /// * `writer` - The XML writer to write to
-/// * `worksheet` - The worksheet containing tables and objects
-/// * `r_id` - The current relationship ID
-fn write_tables_and_objects(writer: &mut InternalWriter, worksheet: &Worksheet, mut r_id: i32) {
+/// * `worksheet` - The worksheet containing tables
+/// * `r_id` - The relationship ID of the first table
+fn write_table_parts(writer: &mut InternalWriter, worksheet: &Worksheet, mut r_id: i32) {
     if worksheet.has_table() {
         let tables = worksheet.tables();
         write_start_tag(
@@ -515,11 +640,42 @@ fn write_tables_and_objects(writer: &mut InternalWriter, worksheet: &Worksheet,
         }
         write_end_tag(writer, "tableParts");
     }
+}
 
-    let ole_id = 1000 + 25;
-    worksheet
-        .ole_objects()
-        .write_to(writer, num_traits::cast(r_id).unwrap(), ole_id);
+/// Writes form controls.
+///
+/// # Arguments
+///
+/// * `writer` - The XML writer to write to
+/// * `worksheet` - The worksheet containing the form controls
+/// * `r_id` - The current relationship ID
+fn write_form_controls(writer: &mut InternalWriter, worksheet: &Worksheet, r_id: i32) {
+    if !worksheet.has_form_controls() {
+        return;
+    }
+
+    // mc:AlternateContent
+    write_start_tag(writer, "mc:AlternateContent", vec![], false);
+
+    // mc:Choice
+    write_start_tag(writer, "mc:Choice", vec![("Requires", "x14").into()], false);
+
+    // controls
+    write_start_tag(writer, "controls", vec![], false);
+
+    // Shape ids follow the ole objects and the comments in the vml drawing.
+    let shape_id =
+        1000 + 25 + worksheet.ole_objects().ole_object().len() + worksheet.comments().len();
+    let r_id: usize = num_traits::cast(r_id).unwrap();
+    for (i, form_control) in worksheet.form_controls().iter().enumerate() {
+        form_control.write_to(writer, r_id + i, shape_id + i);
+    }
+
+    write_end_tag(writer, "controls");
+
+    write_end_tag(writer, "mc:Choice");
+
+    write_end_tag(writer, "mc:AlternateContent");
 }
 
 #[cfg(test)]
@@ -549,7 +705,8 @@ mod tests {
     #[test]
     fn test_write_worksheet_header() {
         let mut writer = setup_test_writer();
-        write_worksheet_header(&mut writer);
+        let worksheet = setup_test_worksheet();
+        write_worksheet_header(&mut writer, &worksheet);
 
         let result = String::from_utf8(writer.into_inner().into_inner()).unwrap();
         assert!(result.contains("<?xml"));
@@ -602,6 +759,7 @@ mod tests {
             &shared_string_table,
             &mut stylesheet,
             &formula_shared_list,
+            &Metadata::default(),
         );
 
         let result = String::from_utf8(writer.into_inner().into_inner()).unwrap();
@@ -626,6 +784,7 @@ mod tests {
             &shared_string_table,
             &mut stylesheet,
             &formula_shared_list,
+            &Metadata::default(),
         );
 
         let result = String::from_utf8(writer.into_inner().into_inner()).unwrap();
@@ -651,11 +810,11 @@ mod tests {
         let mut writer = setup_test_writer();
         let worksheet = setup_test_worksheet();
 
-        let r_id = write_print_settings(&mut writer, &worksheet, 1);
+        write_print_settings(&mut writer, &worksheet, 1);
 
         let result = String::from_utf8(writer.into_inner().into_inner()).unwrap();
         assert!(result.contains("pageMargins"));
-        assert_eq!(r_id, 1);
+        assert!(!result.contains("r:id"));
     }
 
     #[test]
@@ -663,10 +822,11 @@ mod tests {
         let mut writer = setup_test_writer();
         let worksheet = setup_test_worksheet();
 
-        let r_id = write_drawings(&mut writer, &worksheet, 1);
+        write_drawings(&mut writer, &worksheet, &RelationshipIds::new(&worksheet));
 
-        // Should return same r_id if no drawings
-        assert_eq!(r_id, 1);
+        // Should be empty if no drawings
+        let result = String::from_utf8(writer.into_inner().into_inner()).unwrap();
+        assert!(result.is_empty());
     }
 
     #[test]
@@ -674,7 +834,8 @@ mod tests {
         let mut writer = setup_test_writer();
         let worksheet = setup_test_worksheet();
 
-        write_tables_and_objects(&mut writer, &worksheet, 1);
+        write_ole_objects_and_controls(&mut writer, &worksheet, &RelationshipIds::new(&worksheet));
+        write_table_parts(&mut writer, &worksheet, 1);
 
         let result = String::from_utf8(writer.into_inner().into_inner()).unwrap();
         // Should be empty if no tables/objects
@@ -695,6 +856,7 @@ mod tests {
             &worksheet,
             &shared_string_table,
             &mut stylesheet,
+            &Metadata::default(),
             false,
             &mut writer_manager,
         );
@@ -19,6 +19,7 @@ use super::{
 use crate::{
     helper::const_str::{
         COMMENTS_NS,
+        CTRL_PROP_NS,
         DRAWINGS_NS,
         HYPERLINK_NS,
         IMAGE_NS,
@@ -38,6 +39,57 @@ use crate::{
     },
 };
 
+/// The first relationship id of each kind of part a worksheet refers to.
+/// `write` numbers the relationships in this order, and the worksheet takes
+/// the ids it refers to from here.
+pub(crate) struct RelationshipIds {
+    pub(crate) hyperlink:        i32,
+    pub(crate) printer_settings: i32,
+    pub(crate) drawing:          i32,
+    pub(crate) legacy_drawing:   i32,
+    pub(crate) table:            i32,
+    pub(crate) pivot_table:      i32,
+    pub(crate) ole_object:       i32,
+    pub(crate) ctrl_prop:        i32,
+    pub(crate) comments:         i32,
+    pub(crate) threaded_comment: i32,
+}
+
+impl RelationshipIds {
+    pub(crate) fn new(worksheet: &Worksheet) -> Self {
+        let mut next = 1;
+        let mut take = |count: usize| {
+            let id = next;
+            next += num_traits::cast::<_, i32>(count).unwrap();
+            id
+        };
+        let hyperlinks = worksheet
+            .hyperlink_collection()
+            .iter()
+            .filter(|(_, hyperlink)| !hyperlink.location())
+            .count();
+        // An embedded workbook or binary, and its image.
+        let ole_objects = worksheet
+            .ole_objects()
+            .ole_object()
+            .iter()
+            .map(|v| usize::from(v.is_xlsx()) + usize::from(v.is_bin()) + 1)
+            .sum();
+        Self {
+            hyperlink:        take(hyperlinks),
+            printer_settings: take(usize::from(worksheet.page_setup().object_data().is_some())),
+            drawing:          take(usize::from(worksheet.has_drawing_object())),
+            legacy_drawing:   take(usize::from(worksheet.has_legacy_drawing())),
+            table:            take(worksheet.tables().len()),
+            pivot_table:      take(worksheet.pivot_tables().len()),
+            ole_object:       take(ole_objects),
+            ctrl_prop:        take(worksheet.form_controls().len()),
+            comments:         take(usize::from(worksheet.has_comments())),
+            threaded_comment: take(usize::from(worksheet.has_threaded_comments())),
+        }
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn write<W: io::Seek + io::Write>(
     worksheet: &Worksheet,
@@ -52,6 +104,7 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     table_no_list: &[String],
     pivot_table_no_list: &[String],
     pivot_cache_no_list: &[String],
+    ctrl_prop_no_list: &[String],
     writer_mng: &mut WriterManager<W>,
 ) -> Result<(), XlsxError> {
     let mut is_write = false;
@@ -75,9 +128,10 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         false,
     );
 
-    let mut r_id: i32 = 1;
+    let ids = RelationshipIds::new(worksheet);
 
     // Write hyperlink relationships
+    let mut r_id = ids.hyperlink;
     for (_, hyperlink) in worksheet.hyperlink_collection() {
         if !hyperlink.location() {
             is_write = write_relationship(
@@ -96,40 +150,37 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         let object_name = format!("printerSettings{printer_settings_no}.bin");
         is_write = write_relationship(
             &mut writer,
-            &r_id.to_string(),
+            &ids.printer_settings.to_string(),
             PRINTER_SETTINGS_NS,
             format!("../printerSettings/{object_name}").as_str(),
             "",
         );
-        r_id += 1;
     }
 
     // write drawing relationships
     if worksheet.has_drawing_object() {
         is_write = write_relationship(
             &mut writer,
-            &r_id.to_string(),
+            &ids.drawing.to_string(),
             DRAWINGS_NS,
             format!("../drawings/drawing{drawing_no}.xml").as_str(),
             "",
         );
-        r_id += 1;
     }
 
     // Write vmlDrawing relationship
     if worksheet.has_legacy_drawing() {
         is_write = write_relationship(
             &mut writer,
-            &r_id.to_string(),
+            &ids.legacy_drawing.to_string(),
             VML_DRAWING_NS,
             format!("../drawings/vmlDrawing{vml_drawing_no}.vml").as_str(),
             "",
         );
-        r_id += 1;
     }
 
     // write table relationships
-    for table_no in table_no_list {
+    for (r_id, table_no) in (ids.table..).zip(table_no_list) {
         is_write = write_relationship(
             &mut writer,
             &r_id.to_string(),
@@ -137,11 +188,10 @@ pub(crate) fn write<W: io::Seek + io::Write>(
             format!("../tables/table{table_no}.xml").as_str(),
             "",
         );
-        r_id += 1;
     }
 
     // write pivot table relationships
-    for pivot_table_no in pivot_table_no_list {
+    for (r_id, pivot_table_no) in (ids.pivot_table..).zip(pivot_table_no_list) {
         is_write = write_relationship(
             &mut writer,
             &r_id.to_string(),
@@ -149,7 +199,6 @@ pub(crate) fn write<W: io::Seek + io::Write>(
             format!("../pivotTables/pivotTable{pivot_table_no}.xml").as_str(),
             "",
         );
-        r_id += 1;
     }
 
     // write pivot cache relationships (handled at workbook level, not worksheet
@@ -158,6 +207,7 @@ pub(crate) fn write<W: io::Seek + io::Write>(
     let _ = pivot_cache_no_list; // Suppress unused warning
 
     // Write ole_objects
+    let mut r_id = ids.ole_object;
     let mut excel_no_list = excel_no_list.iter();
     let mut ole_object_no_list = ole_object_no_list.iter();
     for ole_object in worksheet.ole_objects().ole_object() {
@@ -197,29 +247,45 @@ pub(crate) fn write<W: io::Seek + io::Write>(
         r_id += 1;
     }
 
+    // Write ctrlProp relationships
+    for (r_id, ctrl_prop_no) in (ids.ctrl_prop..).zip(ctrl_prop_no_list) {
+        is_write = write_relationship(
+            &mut writer,
+            &r_id.to_string(),
+            CTRL_PROP_NS,
+            format!("../ctrlProps/ctrlProp{ctrl_prop_no}.xml").as_str(),
+            "",
+        );
+    }
+
     // Write comments relationship
     if worksheet.has_comments() {
         is_write = write_relationship(
             &mut writer,
-            &r_id.to_string(),
+            &ids.comments.to_string(),
             COMMENTS_NS,
             format!("../comments{comment_no}.xml").as_str(),
             "",
         );
-        r_id += 1;
     }
 
     // Write threadedComment relationship
     if worksheet.has_threaded_comments() {
         is_write = write_relationship(
             &mut writer,
-            &r_id.to_string(),
+            &ids.threaded_comment.to_string(),
             THREADED_COMMENT_NS,
             format!("../threadedComments/threadedComment{threaded_comment_no}.xml").as_str(),
             "",
         );
     }
 
+    // Write relationships of parts that are not modeled
+    if worksheet.raw_unknown().has_parts() {
+        worksheet.raw_unknown().write_parts(&mut writer, writer_mng)?;
+        is_write = true;
+    }
+
     write_end_tag(&mut writer, "Relationships");
 
     if is_write {
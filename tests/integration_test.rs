@@ -276,7 +276,7 @@ fn profile_large_string_memory() {
 
     if env::var_os("UMYA_PROFILE_DESERIALIZE").is_some() {
         let sheet_name = env::var("UMYA_PROFILE_SHEET").unwrap_or_else(|_| "Sheet1".to_string());
-        book.read_sheet_by_name(&sheet_name);
+        book.read_sheet_by_name(&sheet_name).unwrap();
         print_large_file_checkpoint("deserialize_sheet", total);
     }
 
@@ -870,12 +870,12 @@ fn insert_and_remove_cells() {
     let path = std::path::Path::new("./tests/test_files/aaa_insertCell.xlsx");
     let mut book = reader::xlsx::read(path).unwrap();
 
-    book.insert_new_row("Sheet1", 2, 3);
-    book.insert_new_column("Sheet1", "B", 3);
-    book.insert_new_column_by_index("Sheet1", 2, 3);
+    book.insert_new_row("Sheet1", 2, 3).unwrap();
+    book.insert_new_column("Sheet1", "B", 3).unwrap();
+    book.insert_new_column_by_index("Sheet1", 2, 3).unwrap();
 
-    book.remove_row("Sheet1", 6, 2);
-    book.remove_column_by_index("Sheet1", 6, 2);
+    book.remove_row("Sheet1", 6, 2).unwrap();
+    book.remove_column_by_index("Sheet1", 6, 2).unwrap();
 
     // writer
     let path = std::path::Path::new("./tests/result_files/bbb_insertCell.xlsx");
@@ -2020,7 +2020,7 @@ fn issue_225() {
 fn issue_227() {
     let path = std::path::Path::new("./tests/test_files/aaa.xlsx");
     let mut book = reader::xlsx::lazy_read(path).unwrap();
-    book.read_sheet_by_name("Sheet2");
+    book.read_sheet_by_name("Sheet2").unwrap();
     let sheet = book.sheet_by_name("Sheet2").unwrap();
     assert_eq!("3", sheet.cell("B6").unwrap().value());
 }
@@ -2904,7 +2904,7 @@ fn set_legend_present_false_suppresses_legend() {
     std::fs::create_dir_all(out_dir).unwrap();
 
     let mut book = reader::xlsx::read(src).expect("read aaa.xlsx");
-    for sheet in book.sheet_collection_mut().iter_mut() {
+    for sheet in book.sheet_collection_mut().unwrap().iter_mut() {
         for chart in sheet.chart_collection_mut().iter_mut() {
             chart
                 .chart_space_mut()
@@ -2936,3 +2936,2015 @@ fn set_legend_present_false_suppresses_legend() {
         "set_legend_present(false) should suppress all legends; {with_legend}/{charts} remain"
     );
 }
+
+#[test]
+fn form_controls_roundtrip() {
+    use umya_spreadsheet::structs::vml::spreadsheet::ObjectValues;
+
+    let mut book = new_file();
+    let sheet = book.sheet_mut(0).unwrap();
+    sheet.cell_mut("D1").set_value("Red");
+    sheet.cell_mut("D2").set_value("Green");
+    sheet.cell_mut("D3").set_value("Blue");
+    sheet.add_comments({
+        let mut comment = Comment::default();
+        comment.new_comment("F1").set_author("Foo").set_text_string("note");
+        comment
+    });
+
+    let mut checkbox = FormControl::default();
+    checkbox
+        .new_form_control(ObjectValues::Checkbox, "B2", "C2")
+        .set_text("Task done")
+        .set_linked_cell("$A$2")
+        .set_checked(CheckedValues::Checked);
+    sheet.add_form_control(checkbox);
+
+    let mut dropdown = FormControl::default();
+    dropdown
+        .new_form_control(ObjectValues::Drop, "B4", "C4")
+        .set_list_fill_range("$D$1:$D$3")
+        .set_linked_cell("$A$4")
+        .set_selected(2)
+        .set_drop_lines(3);
+    sheet.add_form_control(dropdown);
+
+    let mut spinner = FormControl::default();
+    spinner
+        .new_form_control(ObjectValues::Spin, "B6", "B7")
+        .set_linked_cell("$A$6")
+        .set_minimum(1)
+        .set_maximum(10)
+        .set_increment(2)
+        .set_current_value(5)
+        .set_macro_name("[0]!Spin");
+    sheet.add_form_control(spinner);
+
+    sheet.cell_mut("H1").set_value("Name");
+    sheet.cell_mut("H2").set_value("x");
+    let mut table = Table::new("Names", ("H1", "H2"));
+    table.add_column(TableColumn::new("Name"));
+    sheet.add_table(table);
+
+    let path = std::path::Path::new("./tests/result_files/form_controls.xlsx");
+    writer::xlsx::write(&book, path).unwrap();
+
+    let book = reader::xlsx::read(path).unwrap();
+    let sheet = book.sheet(0).unwrap();
+    assert_eq!(sheet.comments().len(), 1);
+    let controls = sheet.form_controls();
+    assert_eq!(controls.len(), 3);
+
+    let checkbox = sheet.form_control_by_name("Check Box 1").unwrap();
+    assert!(matches!(checkbox.object_type(), ObjectValues::Checkbox));
+    assert_eq!(checkbox.text(), "Task done");
+    assert_eq!(checkbox.linked_cell(), "$A$2");
+    assert_eq!(checkbox.checked(), CheckedValues::Checked);
+    assert_eq!(checkbox.anchor().left_column(), 1);
+    assert_eq!(checkbox.anchor().top_row(), 1);
+
+    let dropdown = sheet.form_control_by_name("Drop Down 2").unwrap();
+    assert!(matches!(dropdown.object_type(), ObjectValues::Drop));
+    assert_eq!(dropdown.list_fill_range(), "$D$1:$D$3");
+    assert_eq!(dropdown.selected(), 2);
+    assert_eq!(dropdown.drop_lines(), 3);
+
+    let spinner = sheet.form_control_by_name("Spinner 3").unwrap();
+    assert_eq!(spinner.minimum(), 1);
+    assert_eq!(spinner.maximum(), 10);
+    assert_eq!(spinner.increment(), 2);
+    assert_eq!(spinner.current_value(), 5);
+    assert_eq!(spinner.macro_name(), "[0]!Spin");
+
+    let mut zip = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+    let mut ctrl_prop = String::new();
+    std::io::Read::read_to_string(
+        &mut zip.by_name("xl/ctrlProps/ctrlProp1.xml").unwrap(),
+        &mut ctrl_prop,
+    )
+    .unwrap();
+    assert!(ctrl_prop.contains(r#"objectType="CheckBox""#));
+    assert!(ctrl_prop.contains(r#"checked="Checked""#));
+
+    // controls come before tableParts, and the ids point at the right parts
+    let xlsx = std::fs::read(path).unwrap();
+    let sheet_xml = zip_entry_to_string(&xlsx, "xl/worksheets/sheet1.xml");
+    let rels_xml = zip_entry_to_string(&xlsx, "xl/worksheets/_rels/sheet1.xml.rels");
+    assert!(sheet_xml.find("<controls>").unwrap() < sheet_xml.find("<tableParts").unwrap());
+    let target_of = |r_id: &str| {
+        let start = rels_xml.find(&format!("Id=\"{r_id}\"")).unwrap();
+        let rest = &rels_xml[start..];
+        let target = &rest[rest.find("Target=\"").unwrap() + 8..];
+        target[..target.find('"').unwrap()].to_string()
+    };
+    let r_id_after = |tag: &str| {
+        let rest = &sheet_xml[sheet_xml.find(tag).unwrap()..];
+        let r_id = &rest[rest.find("r:id=\"").unwrap() + 6..];
+        r_id[..r_id.find('"').unwrap()].to_string()
+    };
+    assert_eq!(target_of(&r_id_after("<control ")), "../ctrlProps/ctrlProp1.xml");
+    assert_eq!(target_of(&r_id_after("<tablePart ")), "../tables/table1.xml");
+    assert_eq!(target_of(&r_id_after("<legacyDrawing ")), "../drawings/vmlDrawing1.vml");
+}
+
+#[test]
+fn table_operations() {
+    let mut book = new_file();
+    let sheet = book.sheet_mut(0).unwrap();
+    sheet.cell_mut("A1").set_value("Item");
+    sheet.cell_mut("B1").set_value("Qty");
+    sheet.cell_mut("C1").set_value("Amount");
+    sheet.cell_mut("A2").set_value("Apple");
+    sheet.cell_mut("B2").set_value_number(3);
+    sheet.cell_mut("A3").set_value("Orange");
+    sheet.cell_mut("B3").set_value_number(5);
+    sheet.cell_mut("A5").set_value("below");
+    sheet.cell_mut("E1").set_formula("SUM(Sales[Qty])");
+
+    let mut table = Table::new("Sales", ("A1", "C3"));
+    table.add_column(TableColumn::new("Item"));
+    table.add_column(TableColumn::new("Qty"));
+    let mut amount = TableColumn::new("Amount");
+    amount.set_calculated_column_formula("[@Qty]*2");
+    table.add_column(amount);
+    sheet.add_table(table);
+
+    sheet.fill_table_calculated_columns("Sales").unwrap();
+    assert_eq!(sheet.cell("C2").unwrap().formula(), "Sales[[#This Row],[Qty]]*2");
+
+    sheet.append_table_row("Sales", ["Lemon", "7"]).unwrap();
+    assert_eq!(sheet.value("A4"), "Lemon");
+    assert_eq!(sheet.value("A6"), "below");
+    assert_eq!(sheet.cell("C4").unwrap().formula(), "Sales[[#This Row],[Qty]]*2");
+    let area = sheet.table_by_name("sales").unwrap().area();
+    assert_eq!((area.1.col_num(), area.1.row_num()), (3, 4));
+
+    assert_eq!(sheet.resolve_structured_reference("Sales[Amount]"), Some("C2:C4".to_string()));
+    assert_eq!(sheet.resolve_structured_reference("Sales[#Headers]"), Some("A1:C1".to_string()));
+    assert_eq!(sheet.resolve_structured_reference("Sales[#Totals]"), None);
+    assert_eq!(sheet.resolve_structured_reference_at("[@Qty]", "C3"), Some("B3".to_string()));
+    assert_eq!(
+        sheet.resolve_structured_reference("Sales[[#All],[Qty]:[Amount]]"),
+        Some("B1:C4".to_string())
+    );
+    assert_eq!(sheet.structured_references_to_a1("SUM(Sales[Qty])", "E1"), "SUM(B2:B4)");
+
+    sheet.rename_table_column("Sales", "Qty", "Units").unwrap();
+    assert_eq!(sheet.value("B1"), "Units");
+    assert_eq!(sheet.cell("C3").unwrap().formula(), "Sales[[#This Row],[Units]]*2");
+    assert_eq!(sheet.cell("E1").unwrap().formula(), "SUM(Sales[Units])");
+    assert!(sheet.rename_table_column("Sales", "Units", "Item").is_err());
+
+    sheet.resize_table("Sales", "D6").unwrap();
+    let table = sheet.table_by_name("Sales").unwrap();
+    assert_eq!(table.columns().len(), 4);
+    assert_eq!(table.columns()[3].name(), "Column4");
+    assert_eq!(sheet.value("D1"), "Column4");
+    assert_eq!(sheet.value("A8"), "below");
+    assert_eq!(sheet.cell("C6").unwrap().formula(), "Sales[[#This Row],[Units]]*2");
+
+    sheet.insert_new_row(1, 2);
+    let area = sheet.table_by_name("Sales").unwrap().area();
+    assert_eq!((area.0.row_num(), area.1.row_num()), (3, 8));
+
+    let path = std::path::Path::new("./tests/result_files/table_operations.xlsx");
+    writer::xlsx::write(&book, path).unwrap();
+}
+
+#[test]
+fn dynamic_filter_ignores_numbers_out_of_the_dates() {
+    let mut book = new_file();
+    let sheet = book.sheet_mut(0).unwrap();
+    sheet.cell_mut("A1").set_value("Date");
+    // 2023-02-15, then numbers that are not dates of Excel
+    for (i, value) in [44972.0, 1e20, f64::INFINITY, -1e10, 44972.5].iter().enumerate() {
+        sheet.cell_mut((1, i as u32 + 2)).set_value_number(*value);
+    }
+    for (filter_type, hidden) in [
+        (DynamicFilterValues::Month2, vec![false, true, true, true, false]),
+        (DynamicFilterValues::Quarter1, vec![false, true, true, true, false]),
+        (DynamicFilterValues::Quarter2, vec![true, true, true, true, true]),
+    ] {
+        let mut dynamic_filter = DynamicFilter::default();
+        dynamic_filter.set_type(filter_type);
+        let mut column = FilterColumn::new(0);
+        column.set_dynamic_filter(dynamic_filter);
+        sheet.set_auto_filter("A1:A6");
+        sheet.auto_filter_mut().unwrap().add_filter_column(column);
+        sheet.apply_auto_filter();
+        let result: Vec<bool> = (2..=6)
+            .map(|row| sheet.row_dimension(row).is_some_and(Row::hidden))
+            .collect();
+        assert_eq!(result, hidden);
+    }
+
+    let mut date_group_item = DateGroupItem::default();
+    date_group_item
+        .set_year(2023)
+        .set_date_time_grouping(DateTimeGroupingValues::Year);
+    let mut filters = Filters::default();
+    filters.add_date_group_item(date_group_item);
+    let mut column = FilterColumn::new(0);
+    column.set_filters(filters);
+    sheet.set_auto_filter("A1:A6");
+    sheet.auto_filter_mut().unwrap().add_filter_column(column);
+    sheet.apply_auto_filter();
+    let result: Vec<bool> = (2..=6)
+        .map(|row| sheet.row_dimension(row).is_some_and(Row::hidden))
+        .collect();
+    assert_eq!(result, [false, true, true, true, false]);
+}
+
+#[test]
+fn auto_filter_criteria_and_sort_state() {
+    let mut book = new_file();
+    let sheet = book.sheet_mut(0).unwrap();
+    sheet.cell_mut("A1").set_value("Fruit");
+    sheet.cell_mut("B1").set_value("Qty");
+    let data = [("Apple", 5), ("Orange", 20), ("apple", 30), ("Lemon", 40), ("Melon", 8)];
+    for (i, (fruit, qty)) in data.iter().enumerate() {
+        let row = i as u32 + 2;
+        sheet.cell_mut((1, row)).set_value(*fruit);
+        sheet.cell_mut((2, row)).set_value_number(*qty);
+    }
+    sheet.set_auto_filter("A1:B6");
+
+    let mut filters = Filters::default();
+    filters.add_filter("Apple").add_filter("Lemon");
+    let mut fruit = FilterColumn::new(0);
+    fruit.set_filters(filters);
+
+    let mut greater = CustomFilter::default();
+    greater
+        .set_operator(FilterOperatorValues::GreaterThan)
+        .set_val("10");
+    let mut custom_filters = CustomFilters::default();
+    custom_filters.add_custom_filter(greater);
+    let mut qty = FilterColumn::new(1);
+    qty.set_custom_filters(custom_filters);
+
+    let mut sort_state = SortState::new("A2:B6");
+    sort_state.add_sort_condition(SortCondition::new("B2:B6", true));
+
+    let auto_filter = sheet.auto_filter_mut().unwrap();
+    auto_filter.add_filter_column(qty).add_filter_column(fruit);
+    auto_filter.set_sort_state(sort_state);
+    sheet.apply_auto_filter();
+
+    let hidden: Vec<bool> = (2..=6)
+        .map(|row| sheet.row_dimension(row).is_some_and(Row::hidden))
+        .collect();
+    assert_eq!(hidden, vec![true, true, false, false, true]);
+
+    // table with a top 10 filter
+    sheet.cell_mut("D1").set_value("Score");
+    for (i, score) in [10, 50, 30, 40, 20].iter().enumerate() {
+        sheet.cell_mut((4, i as u32 + 2)).set_value_number(*score);
+    }
+    let mut table = Table::new("Scores", ("D1", "D6"));
+    table.add_column(TableColumn::new("Score"));
+    let mut top10 = Top10::default();
+    top10.set_val(2.0);
+    let mut score = FilterColumn::new(0);
+    score.set_top10(top10);
+    table.add_filter_column(score);
+    table.set_sort_state(SortState::new("D2:D6"));
+    sheet.add_table(table);
+    sheet.apply_auto_filter();
+    assert_eq!(
+        sheet.table_by_name("Scores").unwrap().filter_column_list()[0]
+            .top10()
+            .unwrap()
+            .filter_val(),
+        40.0
+    );
+    assert!(sheet.row_dimension(2).unwrap().hidden());
+
+    let path = std::path::Path::new("./tests/result_files/auto_filter_criteria.xlsx");
+    writer::xlsx::write(&book, path).unwrap();
+
+    let mut book = reader::xlsx::read(path).unwrap();
+    let sheet = book.sheet_mut(0).unwrap();
+    let auto_filter = sheet.auto_filter().unwrap();
+    assert_eq!(auto_filter.range().range(), "A1:B6");
+    let fruit = auto_filter.filter_column(0).unwrap();
+    assert_eq!(fruit.filters().unwrap().filter_list(), ["Apple", "Lemon"]);
+    let qty = auto_filter.filter_column(1).unwrap();
+    let condition = &qty.custom_filters().unwrap().custom_filter_list()[0];
+    assert_eq!(condition.operator(), &FilterOperatorValues::GreaterThan);
+    assert_eq!(condition.val(), "10");
+    let sort_state = auto_filter.sort_state().unwrap();
+    assert_eq!(sort_state.sort_reference().range(), "A2:B6");
+    assert!(sort_state.sort_condition_list()[0].descending());
+
+    let table = sheet.table_by_name("Scores").unwrap();
+    assert_eq!(table.filter_column_list()[0].top10().unwrap().val(), 2.0);
+    assert_eq!(table.sort_state().unwrap().sort_reference().range(), "D2:D6");
+
+    sheet.insert_new_row(3, 1);
+    assert_eq!(
+        sheet.auto_filter().unwrap().sort_state().unwrap().sort_reference().range(),
+        "A2:B7"
+    );
+}
+
+#[test]
+fn sort_range() {
+    use umya_spreadsheet::{
+        SortDataTypeValues,
+        SortKey,
+    };
+
+    let mut book = new_file();
+    let sheet = book.sheet_mut(0).unwrap();
+    sheet.cell_mut("A1").set_value("Name");
+    sheet.cell_mut("B1").set_value("Priority");
+    sheet.cell_mut("C1").set_value("Qty");
+    let rows = [
+        ("pear", "Low", "10"),
+        ("Apple", "High", "9"),
+        ("apple", "High", "100"),
+        ("fig", "Medium", ""),
+        ("kiwi", "Low", "10"),
+    ];
+    for (i, (name, priority, qty)) in rows.iter().enumerate() {
+        let row = num_traits::cast::<_, u32>(i).unwrap() + 2;
+        sheet.cell_mut((1, row)).set_value(*name);
+        sheet.cell_mut((2, row)).set_value(*priority);
+        sheet.cell_mut((3, row)).set_value_string(*qty);
+        sheet
+            .cell_mut((4, row))
+            .set_formula(format!("C{row}*2"));
+    }
+    sheet.style_mut("A3").font_mut().set_bold(true);
+    sheet.cell_mut("A5").hyperlink_mut().set_url("https://example.com");
+    let mut comment = Comment::default();
+    comment.new_comment("A2").set_text_string("first");
+    sheet.add_comments(comment);
+
+    // custom list, then numeric text descending, then case sensitive name.
+    let mut priority = SortKey::new("B");
+    priority.set_custom_list(["High", "Medium", "Low"]);
+    let mut qty = SortKey::new("C");
+    qty.set_descending(true).set_data_type(SortDataTypeValues::Number);
+    let mut name = SortKey::new("A");
+    name.set_case_sensitive(true);
+    sheet.sort_range("A2:D6", &[priority, qty, name]).unwrap();
+
+    let names: Vec<String> = (2..=6).map(|row| sheet.value((1, row))).collect();
+    assert_eq!(names, ["apple", "Apple", "fig", "kiwi", "pear"]);
+    assert_eq!(sheet.value("A1"), "Name");
+    assert!(sheet.style("A3").font().unwrap().bold());
+    assert_eq!(sheet.cell("A4").unwrap().hyperlink().unwrap().url(), "https://example.com");
+    assert_eq!(sheet.cell("D2").unwrap().formula(), "C2*2");
+    assert_eq!(sheet.cell("C2").unwrap().value(), "100");
+    assert_eq!(sheet.comments()[0].coordinate().to_string(), "A6");
+
+    assert!(sheet.sort_range("A2:D6", &[SortKey::new("F")]).is_err());
+}
+
+#[test]
+fn threaded_comment_authoring() {
+    let mut book = new_file();
+    let sheet = book.sheet_mut(0).unwrap();
+    let root_id = sheet
+        .add_threaded_comment("B2", "John Doe", "Please check")
+        .reply("Jane Doe", "Looks fine")
+        .mention("John Doe")
+        .resolve()
+        .id()
+        .to_string();
+    assert_eq!(sheet.threaded_comments().len(), 2);
+    assert_eq!(sheet.person_list().len(), 2);
+    let reply = &sheet.threaded_comments()[1];
+    assert_eq!(reply.parent_id(), root_id);
+    assert_eq!(reply.text(), "Looks fine @John Doe");
+    assert_eq!(reply.mentions()[0].start_index(), 11);
+    assert_eq!(reply.mentions()[0].length(), 9);
+    let note = &sheet.comments()[0];
+    assert_eq!(note.author(), format!("tc={root_id}"));
+    assert!(note.text().text().unwrap().value().contains("Reply:\n    Looks fine @John Doe"));
+
+    let mut person = structs::office2019::person::Person::default();
+    person
+        .set_display_name("Ann Lee")
+        .set_id("{8A1E0C3B-5D41-4A2B-9C7E-1F2D3E4A5B6C}")
+        .set_user_id("ann@example.com")
+        .set_provider_id("AD");
+    book.add_person(person);
+
+    let path = std::path::Path::new("./tests/result_files/threaded_comment_authoring.xlsx");
+    writer::xlsx::write(&book, path).unwrap();
+
+    let mut book = reader::xlsx::read(path).unwrap();
+    assert_eq!(book.person_list().len(), 3);
+    let john = book.person_by_name("John Doe").unwrap();
+    let sheet = book.sheet(0).unwrap();
+    let root = &sheet.threaded_comments()[0];
+    assert_eq!(root.id(), root_id);
+    assert_eq!(root.person_id(), john.id());
+    assert!(root.done());
+    assert_eq!(root.coordinate().to_string(), "B2");
+    let reply = &sheet.threaded_comments()[1];
+    assert_eq!(reply.parent_id(), root_id);
+    assert_eq!(reply.mentions()[0].mention_person_id(), john.id());
+    assert_eq!(sheet.comments().len(), 1);
+
+    // Authors already in the workbook keep their id.
+    let john_id = john.id().to_string();
+    let sheet = book.sheet_mut(0).unwrap();
+    sheet.add_threaded_comment("C3", "Ann Lee", "New figures");
+    sheet.add_threaded_comment("C4", "John Doe", "Updated");
+    assert!(sheet.person_list().is_empty());
+    assert_eq!(
+        sheet.threaded_comments()[2].person_id(),
+        "{8A1E0C3B-5D41-4A2B-9C7E-1F2D3E4A5B6C}"
+    );
+    assert_eq!(sheet.threaded_comments()[3].person_id(), john_id);
+    let xlsx = workbook_to_xlsx_bytes(&book);
+    let person_xml = zip_entry_to_string(&xlsx, "xl/persons/person.xml");
+    assert_eq!(person_xml.matches("<person ").count(), 3);
+}
+
+#[test]
+fn malformed_part_returns_parse_error() {
+    use std::io::{
+        Read,
+        Write,
+    };
+
+    let mut book = new_file();
+    book.sheet_mut(0).unwrap().cell_mut("A1").set_value("x");
+    let mut source = std::io::Cursor::new(Vec::new());
+    writer::xlsx::write_writer(&book, &mut source).unwrap();
+
+    let source = source.into_inner();
+    let edit_sheet = |edit: &dyn Fn(String) -> String| {
+        let mut zin = zip::ZipArchive::new(std::io::Cursor::new(&source)).unwrap();
+        let mut zout = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        for i in 0..zin.len() {
+            let mut entry = zin.by_index(i).unwrap();
+            let name = entry.name().to_string();
+            let mut buf = Vec::new();
+            entry.read_to_end(&mut buf).unwrap();
+            if name == "xl/worksheets/sheet1.xml" {
+                buf = edit(String::from_utf8(buf).unwrap()).into_bytes();
+            }
+            zout.start_file(name, zip::write::SimpleFileOptions::default())
+                .unwrap();
+            zout.write_all(&buf).unwrap();
+        }
+        zout.finish().unwrap().into_inner()
+    };
+    let data = edit_sheet(&|xml| xml.replace("</row>", "</rows>"));
+
+    let Err(XlsxError::Parse(error)) = reader::xlsx::read_reader(std::io::Cursor::new(&data), true)
+    else {
+        panic!("expected a parse error");
+    };
+    assert_eq!(error.part(), "xl/worksheets/sheet1.xml");
+    assert_eq!(error.path(), "worksheet/sheetData/row");
+    assert!(error.offset() > 0);
+    assert!(error.to_string().contains("xl/worksheets/sheet1.xml"));
+
+    // lazy reading keeps the worksheet raw on failure
+    let mut book = reader::xlsx::read_reader(std::io::Cursor::new(&data), false).unwrap();
+    assert!(matches!(book.read_sheet(0), Err(XlsxError::Parse(_))));
+    assert!(matches!(book.sheet_mut(0), Err(XlsxError::Parse(_))));
+    assert!(matches!(book.read_sheet(5), Err(XlsxError::NotFound())));
+    assert!(matches!(book.active_sheet_mut(), Err(XlsxError::Parse(_))));
+    assert!(matches!(book.sheet_collection_mut(), Err(XlsxError::Parse(_))));
+    assert!(matches!(
+        book.insert_new_row("Sheet1", 1, 1),
+        Err(XlsxError::Parse(_))
+    ));
+
+    // numbers that can not be parsed are errors instead of zero
+    let data = edit_sheet(&|xml| xml.replace("<row r=\"1\"", "<row r=\"1\" ht=\"tall\""));
+    let Err(XlsxError::Parse(error)) = reader::xlsx::read_reader(std::io::Cursor::new(&data), true)
+    else {
+        panic!("expected a parse error");
+    };
+    assert_eq!(error.part(), "xl/worksheets/sheet1.xml");
+    assert!(error.reason().contains("tall"));
+    assert!(error.offset() > 0);
+}
+
+#[test]
+fn unknown_xml_and_parts_survive_round_trip() {
+    use std::io::{
+        Read,
+        Write,
+    };
+
+    fn read_entries(data: Vec<u8>) -> Vec<(String, Vec<u8>)> {
+        let mut zin = zip::ZipArchive::new(std::io::Cursor::new(data)).unwrap();
+        (0..zin.len())
+            .map(|i| {
+                let mut entry = zin.by_index(i).unwrap();
+                let mut buf = Vec::new();
+                entry.read_to_end(&mut buf).unwrap();
+                (entry.name().to_string(), buf)
+            })
+            .collect()
+    }
+    fn entry<'a>(entries: &'a [(String, Vec<u8>)], name: &str) -> &'a str {
+        let (_, data) = entries
+            .iter()
+            .find(|(v, _)| v == name)
+            .unwrap_or_else(|| panic!("{name} is missing"));
+        std::str::from_utf8(data).unwrap()
+    }
+
+    let mut book = new_file();
+    book.sheet_mut(0).unwrap().cell_mut("A1").set_value("1");
+    let mut source = std::io::Cursor::new(Vec::new());
+    writer::xlsx::write_writer(&book, &mut source).unwrap();
+
+    let extra_files = [
+        (
+            "xl/worksheets/_rels/sheet1.xml.rels",
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId9" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/vmlDrawing" Target="../drawings/vmlDrawing9.vml"/></Relationships>"#,
+        ),
+        ("xl/drawings/vmlDrawing9.vml", "<xml>header footer</xml>"),
+        (
+            "xl/connections.xml",
+            r#"<connections xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><connection id="1" name="Query" type="5" refreshedVersion="6"/></connections>"#,
+        ),
+        ("customXml/item1.xml", "<root>custom</root>"),
+        ("customXml/itemProps1.xml", "<ds:datastoreItem/>"),
+        (
+            "customXml/_rels/item1.xml.rels",
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/customXmlProps" Target="itemProps1.xml"/></Relationships>"#,
+        ),
+        ("docProps/thumbnail.wmf", "thumbnail"),
+    ];
+
+    let mut zout = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    for (name, buf) in read_entries(source.into_inner()) {
+        let mut text = String::from_utf8(buf).unwrap();
+        match name.as_str() {
+            "xl/worksheets/sheet1.xml" => {
+                text = text
+                    .replace(
+                        r#"mc:Ignorable="x14ac""#,
+                        r#"mc:Ignorable="x14ac xr" xmlns:xr="http://schemas.microsoft.com/office/spreadsheetml/2014/revision""#,
+                    )
+                    .replace(
+                        "</sheetData>",
+                        r#"</sheetData><protectedRanges><protectedRange sqref="A1" name="Range1"/></protectedRanges>"#,
+                    )
+                    .replace(
+                        "</worksheet>",
+                        r#"<ignoredErrors><ignoredError sqref="A1" numberStoredAsText="1"/></ignoredErrors><legacyDrawingHF r:id="rId9"/><extLst><ext uri="{78C0D931-6437-407d-A8EE-F0AAD7539E65}" xmlns:x14="http://schemas.microsoft.com/office/spreadsheetml/2009/9/main"><x14:conditionalFormattings/></ext></extLst></worksheet>"#,
+                    );
+            }
+            "xl/workbook.xml" => {
+                text = text.replace("</workbook>", r#"<fileRecoveryPr repairLoad="1"/></workbook>"#);
+            }
+            "xl/_rels/workbook.xml.rels" => {
+                text = text.replace(
+                    "</Relationships>",
+                    r#"<Relationship Id="rId90" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/connections" Target="connections.xml"/><Relationship Id="rId91" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/customXml" Target="../customXml/item1.xml"/></Relationships>"#,
+                );
+            }
+            "_rels/.rels" => {
+                text = text.replace(
+                    "</Relationships>",
+                    r#"<Relationship Id="rId9" Type="http://schemas.openxmlformats.org/package/2006/relationships/metadata/thumbnail" Target="docProps/thumbnail.wmf"/></Relationships>"#,
+                );
+            }
+            "[Content_Types].xml" => {
+                text = text.replace(
+                    "</Types>",
+                    r#"<Default Extension="wmf" ContentType="image/x-wmf"/><Override PartName="/xl/connections.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.connections+xml"/><Override PartName="/customXml/itemProps1.xml" ContentType="application/vnd.openxmlformats-officedocument.customXmlProperties+xml"/></Types>"#,
+                );
+            }
+            _ => {}
+        }
+        zout.start_file(name, zip::write::SimpleFileOptions::default())
+            .unwrap();
+        zout.write_all(text.as_bytes()).unwrap();
+    }
+    for (name, text) in extra_files {
+        zout.start_file(name, zip::write::SimpleFileOptions::default())
+            .unwrap();
+        zout.write_all(text.as_bytes()).unwrap();
+    }
+    let data = zout.finish().unwrap().into_inner();
+
+    for lazy in [false, true] {
+        let mut book = reader::xlsx::read_reader(std::io::Cursor::new(&data), !lazy).unwrap();
+        book.sheet_mut(0).unwrap().cell_mut("B1").set_value("2");
+        let mut output = std::io::Cursor::new(Vec::new());
+        writer::xlsx::write_writer(&book, &mut output).unwrap();
+        let entries = read_entries(output.into_inner());
+
+        let sheet = entry(&entries, "xl/worksheets/sheet1.xml");
+        assert!(sheet.contains(r#"xmlns:xr="http://schemas.microsoft.com/office/spreadsheetml/2014/revision""#));
+        assert!(sheet.contains(r#"mc:Ignorable="x14ac xr""#));
+        let positions: Vec<usize> = [
+            "</sheetData>",
+            r#"<protectedRanges><protectedRange sqref="A1" name="Range1"/></protectedRanges>"#,
+            "<phoneticPr",
+            "<pageMargins",
+            r#"<ignoredErrors><ignoredError sqref="A1" numberStoredAsText="1"/></ignoredErrors>"#,
+            r#"<legacyDrawingHF r:id="rIdRaw1"/>"#,
+            r#"<x14:conditionalFormattings/></ext></extLst>"#,
+        ]
+        .iter()
+        .map(|v| sheet.find(v).unwrap_or_else(|| panic!("{v} is missing")))
+        .collect();
+        assert!(positions.windows(2).all(|v| v[0] < v[1]));
+
+        let sheet_rels = entry(&entries, "xl/worksheets/_rels/sheet1.xml.rels");
+        assert!(sheet_rels.contains(r#"Id="rIdRaw1""#));
+        assert!(sheet_rels.contains(r#"Target="../drawings/vmlDrawing9.vml""#));
+        assert_eq!(
+            entry(&entries, "xl/drawings/vmlDrawing9.vml"),
+            "<xml>header footer</xml>"
+        );
+
+        let workbook = entry(&entries, "xl/workbook.xml");
+        assert!(workbook.find("<calcPr").unwrap() < workbook.find("<fileRecoveryPr").unwrap());
+        let workbook_rels = entry(&entries, "xl/_rels/workbook.xml.rels");
+        assert!(workbook_rels.contains(r#"Target="connections.xml""#));
+        assert!(workbook_rels.contains(r#"Target="../customXml/item1.xml""#));
+        assert!(entry(&entries, "xl/connections.xml").contains("Query"));
+        assert_eq!(entry(&entries, "customXml/item1.xml"), "<root>custom</root>");
+        assert!(entry(&entries, "customXml/_rels/item1.xml.rels").contains("itemProps1.xml"));
+        assert_eq!(entry(&entries, "customXml/itemProps1.xml"), "<ds:datastoreItem/>");
+        assert!(entry(&entries, "_rels/.rels").contains(r#"Target="docProps/thumbnail.wmf""#));
+        assert_eq!(entry(&entries, "docProps/thumbnail.wmf"), "thumbnail");
+
+        let content_types = entry(&entries, "[Content_Types].xml");
+        assert!(content_types.contains(r#"<Default Extension="wmf" ContentType="image/x-wmf"/>"#));
+        assert!(content_types.contains(r#"PartName="/xl/connections.xml""#));
+        assert!(content_types.contains(r#"PartName="/customXml/itemProps1.xml""#));
+    }
+}
+
+#[test]
+fn unknown_xml_before_table_parts_keeps_schema_order() {
+    let mut book = new_file();
+    let sheet = book.sheet_mut(0).unwrap();
+    sheet.cell_mut("A1").set_value("Name");
+    sheet.cell_mut("A2").set_value("x");
+    let mut table = Table::new("Names", ("A1", "A2"));
+    table.add_column(TableColumn::new("Name"));
+    sheet.add_table(table);
+    let xlsx = workbook_to_xlsx_bytes(&book);
+
+    let web_publish_items = r#"<webPublishItems count="1"><webPublishItem id="1" divId="Book1_1" sourceType="sheet" destinationFile="page.htm"/></webPublishItems>"#;
+    let mut zin = zip::ZipArchive::new(std::io::Cursor::new(xlsx)).unwrap();
+    let mut zout = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    for i in 0..zin.len() {
+        let mut entry = zin.by_index(i).unwrap();
+        let name = entry.name().to_string();
+        let mut text = String::new();
+        std::io::Read::read_to_string(&mut entry, &mut text).unwrap();
+        if name == "xl/worksheets/sheet1.xml" {
+            text = text.replace("<tableParts", &format!("{web_publish_items}<tableParts"));
+        }
+        zout.start_file(name, zip::write::SimpleFileOptions::default())
+            .unwrap();
+        std::io::Write::write_all(&mut zout, text.as_bytes()).unwrap();
+    }
+    let data = zout.finish().unwrap().into_inner();
+
+    let book = reader::xlsx::read_reader(std::io::Cursor::new(data), true).unwrap();
+    let xlsx = workbook_to_xlsx_bytes(&book);
+    let sheet_xml = zip_entry_to_string(&xlsx, "xl/worksheets/sheet1.xml");
+    let position = sheet_xml.find(web_publish_items).unwrap();
+    assert!(position < sheet_xml.find("<tableParts").unwrap());
+}
+
+#[test]
+fn read_limits_reject_oversized_files() {
+    use std::io::Write;
+
+    let mut book = new_file();
+    book.new_sheet("Sheet2").unwrap();
+    let sheet = book.sheet_mut(0).unwrap();
+    for row in 1..=10 {
+        sheet.cell_mut((1, row)).set_value(format!("text {row}"));
+    }
+    let mut source = std::io::Cursor::new(Vec::new());
+    writer::xlsx::write_writer(&book, &mut source).unwrap();
+    let data = source.into_inner();
+
+    let read = |limits: &ReadLimits| {
+        reader::xlsx::read_reader_with_limits(std::io::Cursor::new(&data), true, limits)
+    };
+    let limit_of = |result: Result<Workbook, XlsxError>| match result {
+        Err(XlsxError::LimitExceeded(limit, _)) => Some(limit),
+        _ => None,
+    };
+
+    assert!(read(&ReadLimits::default()).is_ok());
+    let mut limits = ReadLimits::default();
+    limits
+        .set_max_part_size(1024 * 1024)
+        .set_max_total_size(16 * 1024 * 1024)
+        .set_max_cells(10)
+        .set_max_shared_strings(10)
+        .set_max_sheets(2)
+        .set_max_xml_depth(32);
+    assert!(read(&limits).is_ok());
+
+    let mut limits = ReadLimits::default();
+    limits.set_max_sheets(1);
+    assert_eq!(limit_of(read(&limits)), Some(ReadLimit::Sheets));
+
+    let mut limits = ReadLimits::default();
+    limits.set_max_shared_strings(9);
+    assert_eq!(limit_of(read(&limits)), Some(ReadLimit::SharedStrings));
+
+    let mut limits = ReadLimits::default();
+    limits.set_max_cells(9);
+    assert_eq!(limit_of(read(&limits)), Some(ReadLimit::Cells));
+    // worksheets deserialized later are held to the same limits
+    let mut book =
+        reader::xlsx::read_reader_with_limits(std::io::Cursor::new(&data), false, &limits).unwrap();
+    assert!(matches!(
+        book.read_sheet(0),
+        Err(XlsxError::LimitExceeded(ReadLimit::Cells, _))
+    ));
+    assert!(book.read_sheet(1).is_ok());
+
+    let mut limits = ReadLimits::default();
+    limits.set_max_total_size(1024);
+    assert_eq!(limit_of(read(&limits)), Some(ReadLimit::TotalSize));
+
+    let mut limits = ReadLimits::default();
+    limits.set_max_xml_depth(2);
+    let Err(XlsxError::LimitExceeded(ReadLimit::XmlDepth, part)) = read(&limits) else {
+        panic!("expected the XML depth limit");
+    };
+    assert!(!part.is_empty());
+
+    // a highly compressed part is refused without being inflated in full
+    let mut zin = zip::ZipArchive::new(std::io::Cursor::new(&data)).unwrap();
+    let mut zout = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    for i in 0..zin.len() {
+        let entry = zin.by_index(i).unwrap();
+        zout.raw_copy_file(entry).unwrap();
+    }
+    zout.start_file("xl/media/bomb.bin", zip::write::SimpleFileOptions::default())
+        .unwrap();
+    let zeros = vec![0u8; 1024 * 1024];
+    for _ in 0..16 {
+        zout.write_all(&zeros).unwrap();
+    }
+    let bomb = zout.finish().unwrap().into_inner();
+    assert!(bomb.len() < 1024 * 1024);
+    let mut limits = ReadLimits::default();
+    limits.set_max_part_size(1024 * 1024);
+    let result = reader::xlsx::read_reader_with_limits(std::io::Cursor::new(&bomb), true, &limits);
+    let Err(XlsxError::LimitExceeded(ReadLimit::PartSize, part)) = result else {
+        panic!("expected the part size limit");
+    };
+    assert_eq!(part, "xl/media/bomb.bin");
+}
+
+#[test]
+fn read_limits_count_cells_while_reading_a_row() {
+    use std::io::Write;
+
+    let mut source = std::io::Cursor::new(Vec::new());
+    writer::xlsx::write_writer(&new_file(), &mut source).unwrap();
+    let data = source.into_inner();
+    let with_sheet = |sheet_data: &str| {
+        let mut zin = zip::ZipArchive::new(std::io::Cursor::new(&data)).unwrap();
+        let mut zout = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        for i in 0..zin.len() {
+            let entry = zin.by_index(i).unwrap();
+            if entry.name() != "xl/worksheets/sheet1.xml" {
+                zout.raw_copy_file(entry).unwrap();
+            }
+        }
+        zout.start_file("xl/worksheets/sheet1.xml", zip::write::SimpleFileOptions::default())
+            .unwrap();
+        write!(
+            zout,
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">{sheet_data}"#
+        )
+        .unwrap();
+        zout.finish().unwrap().into_inner()
+    };
+    let mut limits = ReadLimits::default();
+    limits.set_max_cells(100);
+    let read = |file: &[u8]| {
+        reader::xlsx::read_reader_with_limits(std::io::Cursor::new(file), true, &limits)
+    };
+
+    // one row carrying far more cells than allowed, cut off before its end:
+    // the limit is hit before the reader gets to the end of the row
+    let cells: String = (1..=50_000u32)
+        .map(|i| {
+            let col = helper::coordinate::string_from_column_index(i % 16_384 + 1);
+            format!(r#"<c r="{col}{i}"><v>1</v></c>"#)
+        })
+        .collect();
+    let file = with_sheet(&format!(r#"<sheetData><row r="1">{cells}"#));
+    assert!(matches!(
+        read(&file),
+        Err(XlsxError::LimitExceeded(ReadLimit::Cells, part)) if part == "xl/worksheets/sheet1.xml"
+    ));
+
+    // cells made by hyperlinks count too
+    let cells: String = (1..=60)
+        .map(|row| format!(r#"<row r="{row}"><c r="A{row}"><v>1</v></c></row>"#))
+        .collect();
+    let hyperlinks: String = (1..=60)
+        .map(|row| format!(r##"<hyperlink ref="B{row}" location="Sheet1!A1"/>"##))
+        .collect();
+    let file = with_sheet(&format!(
+        "<sheetData>{cells}</sheetData><hyperlinks>{hyperlinks}</hyperlinks></worksheet>"
+    ));
+    assert!(matches!(read(&file), Err(XlsxError::LimitExceeded(ReadLimit::Cells, _))));
+    limits.set_max_cells(120);
+    let book =
+        reader::xlsx::read_reader_with_limits(std::io::Cursor::new(&file), true, &limits).unwrap();
+    assert_eq!(book.sheet(0).unwrap().cells().len(), 120);
+}
+
+#[test]
+fn theme_palette_and_color_resolution() {
+    use umya_spreadsheet::drawing::SchemeColorValues;
+
+    let mut book = new_file();
+    let mut color = Color::default();
+    color.set_theme_index(0).set_tint(-0.5);
+    assert_eq!(Color::argb8_to_hex(book.resolve_color(&color)), "FF808080");
+    let mut color = Color::default();
+    color.set_argb_str("FF000000").set_tint(0.5);
+    assert_eq!(Color::argb8_to_hex(book.resolve_color(&color)), "FF808080");
+    let mut color = Color::default();
+    color.set_indexed(2);
+    assert_eq!(Color::argb8_to_hex(book.resolve_color(&color)), "FFFF0000");
+
+    book.theme_mut()
+        .set_scheme_color(&SchemeColorValues::Accent1, "1F4E79")
+        .unwrap()
+        .set_scheme_color(&SchemeColorValues::Text1, "#202020")
+        .unwrap()
+        .set_scheme_color(&SchemeColorValues::Hyperlink, "0563c1")
+        .unwrap()
+        .set_major_font("Georgia")
+        .set_minor_font("Verdana");
+    for invalid in ["FF0563C1", "#12345", "GGGGGG", "ab€d", ""] {
+        assert!(matches!(
+            book.theme_mut().set_scheme_color(&SchemeColorValues::Hyperlink, invalid),
+            Err(XlsxError::InvalidArgument(_))
+        ));
+    }
+    let mut accent = Color::default();
+    accent.set_theme_index(4);
+    assert_eq!(Color::argb8_to_hex(book.resolve_color(&accent)), "FF1F4E79");
+    let mut text = Color::default();
+    text.set_theme_index(1);
+    assert_eq!(Color::argb8_to_hex(book.resolve_color(&text)), "FF202020");
+
+    let mut data = std::io::Cursor::new(Vec::new());
+    writer::xlsx::write_writer(&book, &mut data).unwrap();
+    data.set_position(0);
+    let book = reader::xlsx::read_reader(data, true).unwrap();
+    let elements = book.theme().theme_elements();
+    assert_eq!(elements.color_scheme().accent1().val(), "1F4E79");
+    assert_eq!(elements.color_scheme().dk1().val(), "202020");
+    assert_eq!(elements.color_scheme().hlink().val(), "0563C1");
+    assert_eq!(elements.font_scheme().major_font().latin_font().typeface(), "Georgia");
+    assert_eq!(elements.font_scheme().minor_font().latin_font().typeface(), "Verdana");
+    assert_eq!(Color::argb8_to_hex(book.resolve_color(&accent)), "FF1F4E79");
+}
+
+#[test]
+fn defined_names_manager_scopes_renames_and_print_settings() {
+    let mut book = new_file();
+    book.new_sheet("Data Sheet").unwrap();
+    book.sheet_mut(0).unwrap().cell_mut("A1").set_formula("Rate*2");
+    book.sheet_mut(0).unwrap().cell_mut("A2").set_formula("SUM(Rate,'Data Sheet'!Rate)");
+    book.sheet_mut(1).unwrap().cell_mut("A1").set_formula("Rate+1");
+
+    let mut names = book.defined_names_manager();
+    names.add("Rate", "Sheet1!$B$1", None).unwrap();
+    names.add("Rate", "'Data Sheet'!$B$1", Some("Data Sheet")).unwrap();
+    assert!(matches!(
+        names.add("rate", "Sheet1!$C$1", None),
+        Err(XlsxError::NameDuplicate())
+    ));
+    for invalid in ["Tax Rate", "A1", "xfd1048576", "R1C1", "r", "1st", ""] {
+        assert!(matches!(
+            names.add(invalid, "Sheet1!$C$1", None),
+            Err(XlsxError::InvalidArgument(_))
+        ));
+    }
+    assert!(names.add("ABCD1", "Sheet1!$C$1", None).is_ok());
+    assert!(matches!(
+        names.add("Other", "Sheet1!$C$1", Some("Missing")),
+        Err(XlsxError::NotFound())
+    ));
+    assert_eq!(names.get("RATE", None).unwrap().address(), "Sheet1!$B$1");
+    assert_eq!(
+        names.get("Rate", Some("Data Sheet")).unwrap().address(),
+        "'Data Sheet'!$B$1"
+    );
+    assert!(names.get("Rate", Some("Sheet1")).is_none());
+
+    // the workbook name is shadowed by the local name on "Data Sheet"
+    names.rename("Rate", "TaxRate", None).unwrap();
+    assert!(names.get("Rate", None).is_none());
+    assert!(names.get("TaxRate", None).is_some());
+    names.rename("Rate", "LocalRate", Some("Data Sheet")).unwrap();
+    assert!(matches!(
+        names.rename("Missing", "Other", None),
+        Err(XlsxError::NotFound())
+    ));
+    assert!(matches!(
+        names.rename("TaxRate", "ABCD1", None),
+        Err(XlsxError::NameDuplicate())
+    ));
+    names.rename("ABCD1", "aéééé", None).unwrap();
+    assert!(matches!(
+        names.rename("aéééé", "_xlnm.Print_Area", None),
+        Err(XlsxError::InvalidArgument(_))
+    ));
+    names.rename("aéééé", "ABCD1", None).unwrap();
+
+    names.set_print_area("Data Sheet", "a1:d20").unwrap();
+    names.set_print_titles("Sheet1", Some("1:2"), Some("A:A")).unwrap();
+    assert!(matches!(
+        names.set_print_area("Sheet1", "nope"),
+        Err(XlsxError::InvalidArgument(_))
+    ));
+    assert_eq!(names.print_area("Data Sheet").unwrap(), "'Data Sheet'!$A$1:$D$20");
+    assert_eq!(names.print_titles("Sheet1").unwrap(), "Sheet1!$1:$2,Sheet1!$A:$A");
+    assert!(names.remove("ABCD1", None).is_some());
+    assert!(names.remove("ABCD1", None).is_none());
+
+    assert_eq!(book.sheet(0).unwrap().cell("A1").unwrap().formula(), "TaxRate*2");
+    assert_eq!(
+        book.sheet(0).unwrap().cell("A2").unwrap().formula(),
+        "SUM(TaxRate,'Data Sheet'!LocalRate)"
+    );
+    assert_eq!(book.sheet(1).unwrap().cell("A1").unwrap().formula(), "LocalRate+1");
+
+    let mut data = std::io::Cursor::new(Vec::new());
+    writer::xlsx::write_writer(&book, &mut data).unwrap();
+    data.set_position(0);
+    let mut book = reader::xlsx::read_reader(data, true).unwrap();
+    let names = book.defined_names_manager();
+    assert_eq!(names.get("TaxRate", None).unwrap().address(), "Sheet1!$B$1");
+    assert!(names.get("LocalRate", Some("Data Sheet")).is_some());
+    assert_eq!(names.print_area("Data Sheet").unwrap(), "'Data Sheet'!$A$1:$D$20");
+    assert_eq!(names.print_titles("Sheet1").unwrap(), "Sheet1!$1:$2,Sheet1!$A:$A");
+}
+
+#[test]
+fn resolve_name_and_name_value() {
+    let mut book = new_file();
+    book.new_sheet("Price's List").unwrap();
+    {
+        let sheet = book.sheet_mut(1).unwrap();
+        sheet.cell_mut("A1").set_value("Product");
+        sheet.cell_mut("A2").set_value("Apple");
+        sheet.cell_mut("A3").set_value("Pear");
+        sheet.cell_mut("A4").set_value("Plum");
+        sheet.cell_mut("B2").set_value_number(3);
+    }
+    book.sheet_mut(0).unwrap().cell_mut("B1").set_value_number(0.1);
+
+    let mut names = book.defined_names_manager();
+    names.add("TaxRate", "Sheet1!$B$1", None).unwrap();
+    names.add("TaxRate", "0.2", Some("Price's List")).unwrap();
+    names
+        .add(
+            "Products",
+            "OFFSET('Price''s List'!$A$2,0,0,COUNTA('Price''s List'!$A:$A)-1,1)",
+            None,
+        )
+        .unwrap();
+    names
+        .add("Mixed", "Sheet1!$B$1,'Price''s List'!$A$2:$B$2", None)
+        .unwrap();
+    names.add("AllProducts", "Products", None).unwrap();
+    names.add("Loop", "Loop", None).unwrap();
+
+    let cells = book.resolve_name("Products", None).unwrap();
+    let cells: Vec<String> = cells
+        .iter()
+        .map(|(sheet_name, coordinate)| format!("{sheet_name}!{coordinate}"))
+        .collect();
+    assert_eq!(
+        cells,
+        ["Price's List!A2", "Price's List!A3", "Price's List!A4"]
+    );
+    let values: Vec<String> = book
+        .name_value("AllProducts")
+        .unwrap()
+        .iter()
+        .map(|v| v.value().to_string())
+        .collect();
+    assert_eq!(values, ["Apple", "Pear", "Plum"]);
+
+    let mixed = book.resolve_name("Mixed", None).unwrap();
+    assert_eq!(mixed.len(), 3);
+    assert_eq!(mixed[0].0, "Sheet1");
+    assert_eq!(mixed[2].1.to_string(), "B2");
+
+    // the sheet scoped name hides the workbook name
+    assert_eq!(book.name_value("TaxRate").unwrap()[0].value_number(), Some(0.1));
+    assert_eq!(
+        book.name_value("'Price''s List'!TaxRate").unwrap()[0].value_number(),
+        Some(0.2)
+    );
+    assert_eq!(book.resolve_name("TaxRate", Some("Sheet1")).unwrap().len(), 1);
+    assert!(matches!(
+        book.resolve_name("TaxRate", Some("Price's List")),
+        Err(XlsxError::InvalidArgument(_))
+    ));
+    assert!(matches!(
+        book.resolve_name("Missing", None),
+        Err(XlsxError::NotFound())
+    ));
+    assert!(matches!(
+        book.resolve_name("Loop", None),
+        Err(XlsxError::InvalidArgument(_))
+    ));
+
+    // huge areas are refused instead of listing billions of cells
+    let mut names = book.defined_names_manager();
+    names.add("Huge", "Sheet1!$A$1:$XFD$1048575", None).unwrap();
+    names
+        .add("HugeOffset", "OFFSET(Sheet1!$A$1,0,0,1048575,16384)", None)
+        .unwrap();
+    names.add("Column", "Sheet1!$B$1:$B$1048576", None).unwrap();
+    for name in ["Huge", "HugeOffset"] {
+        assert!(matches!(
+            book.resolve_name(name, None),
+            Err(XlsxError::InvalidArgument(_))
+        ));
+        assert!(book.name_value(name).is_err());
+    }
+    assert_eq!(book.resolve_name("Column", None).unwrap().len(), 1);
+}
+
+#[test]
+fn import_sheet_from_another_workbook() {
+    let mut source = new_file();
+    source.set_sheet_name(0, "Data").unwrap();
+    {
+        let sheet = source.sheet_by_name_mut("Data").unwrap();
+        sheet.cell_mut("A1").set_value("Item");
+        sheet.cell_mut("B1").set_value("Amount");
+        sheet.cell_mut("A2").set_value("Apple");
+        sheet.cell_mut("B2").set_value_number(10);
+        sheet.cell_mut("A3").set_value("Pear");
+        sheet.cell_mut("B3").set_value_number(20);
+        sheet.cell_mut("D1").set_value_number(0.5);
+        sheet.cell_mut("E1").set_formula("SUM(Sales[Amount])*Rate*Vat");
+        sheet.cell_mut("E2").set_formula("Data!B2+Local");
+        sheet.style_mut("B2").font_mut().set_bold(true);
+        let mut table = Table::new("Sales", ("A1", "B3"));
+        table.add_column(TableColumn::new("Item"));
+        table.add_column(TableColumn::new("Amount"));
+        sheet.add_table(table);
+        let mut marker = drawing::spreadsheet::MarkerType::default();
+        marker.set_coordinate("G3");
+        let mut image = Image::default();
+        image.new_image("./images/sample1.png", marker);
+        sheet.add_image(image);
+    }
+    let mut names = source.defined_names_manager();
+    names.add("Rate", "Data!$D$1", None).unwrap();
+    names.add("Vat", "0.2", None).unwrap();
+    names.add("Unused", "0.3", None).unwrap();
+    names.add("Local", "Data!$B$3", Some("Data")).unwrap();
+
+    let mut book = new_file();
+    book.set_sheet_name(0, "Data").unwrap();
+    book.sheet_mut(0)
+        .unwrap()
+        .add_table(Table::new("Sales", ("A1", "A2")));
+    book.defined_names_manager()
+        .add("Rate", "Data!$Z$1", None)
+        .unwrap();
+
+    let sheet = book.import_sheet(&source, "Data").unwrap();
+    assert_eq!(sheet.name(), "Data (2)");
+    assert_eq!(sheet.tables()[0].name(), "Sales_2");
+    assert_eq!(
+        sheet.cell("E1").unwrap().formula(),
+        "SUM(Sales_2[Amount])*Rate_2*Vat"
+    );
+    assert_eq!(sheet.cell("E2").unwrap().formula(), "'Data (2)'!B2+Local");
+    assert!(sheet.style("B2").font().unwrap().bold());
+    assert_eq!(sheet.image_collection().len(), 1);
+    assert!(matches!(
+        book.import_sheet(&source, "Missing"),
+        Err(XlsxError::NotFound())
+    ));
+
+    let cells = book.resolve_name("Rate_2", None).unwrap();
+    assert_eq!(cells[0].0, "Data (2)");
+    assert_eq!(book.name_value("Vat").unwrap()[0].value_number(), Some(0.2));
+    assert!(book.resolve_name("Unused", None).is_err());
+    let local = book.resolve_name("Local", Some("Data (2)")).unwrap();
+    assert_eq!(local[0].0, "Data (2)");
+    assert_eq!(local[0].1.to_string(), "B3");
+
+    let xlsx = workbook_to_xlsx_bytes(&book);
+    let book = reader::xlsx::read_reader(std::io::Cursor::new(xlsx), true).unwrap();
+    let sheet = book.sheet_by_name("Data (2)").unwrap();
+    assert_eq!(sheet.value("A3"), "Pear");
+    assert_eq!(sheet.tables()[0].name(), "Sales_2");
+    assert_eq!(sheet.image_collection().len(), 1);
+    assert_eq!(book.name_value("Rate_2").unwrap()[0].value_number(), Some(0.5));
+}
+
+#[test]
+fn import_sheet_brings_its_external_links() {
+    let mut source = new_file();
+    source.add_external_link(ExternalLink::new("a.xlsx"));
+    source.add_external_link(ExternalLink::new("b.xlsx"));
+    source.add_external_link(ExternalLink::new("c.xlsx"));
+    {
+        let sheet = source.sheet_mut(0).unwrap();
+        sheet.cell_mut("A1").set_formula("[2]Sheet1!A1*2");
+        sheet.cell_mut("A2").set_formula("[3]Sheet1!A1+[2]Sheet1!B1");
+    }
+
+    let mut book = new_file();
+    book.add_external_link(ExternalLink::new("c.xlsx"));
+    let sheet = book.import_sheet(&source, "Sheet1").unwrap();
+    assert_eq!(sheet.cell("A1").unwrap().formula(), "[2]Sheet1!A1*2");
+    assert_eq!(
+        sheet.cell("A2").unwrap().formula(),
+        "[1]Sheet1!A1+[2]Sheet1!B1"
+    );
+    let targets: Vec<&str> = book.external_links().iter().map(|v| v.target()).collect();
+    assert_eq!(targets, ["c.xlsx", "b.xlsx"]);
+
+    let mut book = new_file();
+    book.import_sheet(&source, "Sheet1").unwrap();
+    let sheet = book.import_sheet(&source, "Sheet1").unwrap();
+    assert_eq!(sheet.cell("A1").unwrap().formula(), "[1]Sheet1!A1*2");
+    assert_eq!(book.external_links().len(), 2);
+}
+
+#[test]
+fn duplicate_sheet_copies_and_rewrites_self_references() {
+    let mut book = new_file();
+    book.set_sheet_name(0, "Data").unwrap();
+    book.new_sheet("Other").unwrap();
+    {
+        let sheet = book.sheet_by_name_mut("Data").unwrap();
+        sheet.cell_mut("A1").set_value("Item");
+        sheet.cell_mut("B1").set_value("Amount");
+        sheet.cell_mut("B2").set_value_number(10);
+        sheet.cell_mut("B3").set_value_number(20);
+        sheet.cell_mut("C1").set_formula("SUM(Data!B2:B3)+SUM(Sales[Amount])+Other!A1");
+        sheet.style_mut("B2").font_mut().set_bold(true);
+        sheet.add_merge_cells("D1:E1");
+        sheet.column_dimension_mut("A").set_width(30.0);
+        let mut table = Table::new("Sales", ("A1", "B3"));
+        table.add_column(TableColumn::new("Item"));
+        table.add_column(TableColumn::new("Amount"));
+        sheet.add_table(table);
+        let mut from_marker = drawing::spreadsheet::MarkerType::default();
+        let mut to_marker = drawing::spreadsheet::MarkerType::default();
+        from_marker.set_coordinate("G1");
+        to_marker.set_coordinate("L10");
+        let mut chart = Chart::default();
+        chart.new_chart(
+            &ChartType::LineChart,
+            from_marker,
+            to_marker,
+            vec!["Data!$B$2:$B$3"],
+        );
+        sheet.add_chart(chart);
+    }
+    let mut names = book.defined_names_manager();
+    names.set_print_area("Data", "A1:E3").unwrap();
+    names.set_print_area("Other", "A1:B2").unwrap();
+    names.add("Rate", "Data!$B$2", None).unwrap();
+    book.set_active_sheet(1);
+
+    let copy = book.duplicate_sheet("Data", "Data Copy", 0).unwrap();
+    assert_eq!(copy.name(), "Data Copy");
+    assert_eq!(copy.tables()[0].name(), "Sales_2");
+    assert_eq!(
+        copy.cell("C1").unwrap().formula(),
+        "SUM('Data Copy'!B2:B3)+SUM(Sales_2[Amount])+Other!A1"
+    );
+    assert!(copy.style("B2").font().unwrap().bold());
+    assert_eq!(copy.merge_cells().len(), 1);
+    assert_eq!(copy.column_dimension("A").unwrap().width(), 30.0);
+    assert_eq!(copy.value("B3"), "20");
+    assert!(matches!(
+        book.duplicate_sheet("Data", "Other", 0),
+        Err(XlsxError::NameDuplicate())
+    ));
+    assert!(matches!(
+        book.duplicate_sheet("Missing", "New", 0),
+        Err(XlsxError::NotFound())
+    ));
+    assert!(matches!(
+        book.duplicate_sheet("Data", "New", 9),
+        Err(XlsxError::InvalidArgument(_))
+    ));
+
+    let names: Vec<&str> = book.sheet_collection().iter().map(|x| x.name()).collect();
+    assert_eq!(names, ["Data Copy", "Data", "Other"]);
+    assert_eq!(book.workbook_view().active_tab(), 2);
+    assert_eq!(book.sheet_by_name("Data").unwrap().tables()[0].name(), "Sales");
+
+    let mut names = book.defined_names_manager();
+    assert_eq!(names.print_area("Data Copy").unwrap(), "'Data Copy'!$A$1:$E$3");
+    assert_eq!(names.print_area("Data").unwrap(), "Data!$A$1:$E$3");
+    assert_eq!(names.print_area("Other").unwrap(), "Other!$A$1:$B$2");
+    assert_eq!(names.get("Rate", None).unwrap().address(), "Data!$B$2");
+    // three print areas and "Rate"
+    let name_count: usize = book
+        .sheet_collection()
+        .iter()
+        .map(|x| x.defined_names().len())
+        .sum();
+    assert_eq!(name_count + book.defined_names().len(), 4);
+
+    let xlsx = workbook_to_xlsx_bytes(&book);
+    let charts = [
+        zip_entry_to_string(&xlsx, "xl/charts/chart1.xml"),
+        zip_entry_to_string(&xlsx, "xl/charts/chart2.xml"),
+    ];
+    assert!(charts.iter().any(|x| x.contains("'Data Copy'!$B$2:$B$3")));
+    assert!(charts.iter().any(|x| x.contains("<c:f>Data!$B$2:$B$3")));
+    let book = reader::xlsx::read_reader(std::io::Cursor::new(xlsx), true).unwrap();
+    let copy = book.sheet_by_name("Data Copy").unwrap();
+    assert_eq!(copy.tables()[0].name(), "Sales_2");
+    assert_eq!(copy.value("B2"), "10");
+}
+
+#[test]
+fn move_sheet_and_hide_sheets() {
+    let mut book = new_file();
+    book.new_sheet("Sheet2").unwrap();
+    book.new_sheet("Sheet 3").unwrap();
+    book.new_sheet("Summary").unwrap();
+    {
+        let sheet = book.sheet_by_name_mut("Summary").unwrap();
+        sheet.cell_mut("A1").set_formula("SUM(Sheet1:Sheet2!A1)");
+        sheet.cell_mut("A2").set_formula("SUM('Sheet2:Sheet 3'!A1)+Sheet1!A1");
+    }
+    let mut names = book.defined_names_manager();
+    names.set_print_area("Sheet1", "A1:B2").unwrap();
+    names.set_print_area("Sheet 3", "C1:D2").unwrap();
+    names.add("Total", "SUM(Sheet1:Sheet2!B1)", None).unwrap();
+    book.set_active_sheet(2);
+
+    book.move_sheet(0, 2).unwrap();
+    let names: Vec<&str> = book.sheet_collection().iter().map(|x| x.name()).collect();
+    assert_eq!(names, ["Sheet2", "Sheet 3", "Sheet1", "Summary"]);
+    assert_eq!(book.sheet_index("Sheet1"), Some(2));
+    assert_eq!(book.sheet_index("Missing"), None);
+    assert_eq!(book.workbook_view().active_tab(), 1);
+    let summary = book.sheet_by_name("Summary").unwrap();
+    assert_eq!(summary.cell("A1").unwrap().formula(), "SUM(Sheet2:Sheet1!A1)");
+    assert_eq!(
+        summary.cell("A2").unwrap().formula(),
+        "SUM('Sheet2:Sheet 3'!A1)+Sheet1!A1"
+    );
+    let mut names = book.defined_names_manager();
+    assert_eq!(names.print_area("Sheet1").unwrap(), "Sheet1!$A$1:$B$2");
+    assert_eq!(names.print_area("Sheet 3").unwrap(), "'Sheet 3'!$C$1:$D$2");
+    assert_eq!(
+        names.get("Total", None).unwrap().address(),
+        "SUM(Sheet2:Sheet1!B1)"
+    );
+    assert!(matches!(book.move_sheet(0, 4), Err(XlsxError::NotFound())));
+
+    // the active sheet moves on to the next visible sheet
+    book.hide_sheet("Sheet 3").unwrap();
+    assert_eq!(book.workbook_view().active_tab(), 2);
+    book.very_hide_sheet("Sheet2").unwrap();
+    book.hide_sheet("Summary").unwrap();
+    assert!(matches!(
+        book.sheet_by_name("Sheet 3").unwrap().state(),
+        SheetStateValues::Hidden
+    ));
+    assert!(matches!(
+        book.sheet_by_name("Sheet2").unwrap().state(),
+        SheetStateValues::VeryHidden
+    ));
+    assert!(matches!(
+        book.hide_sheet("Sheet1"),
+        Err(XlsxError::InvalidArgument(_))
+    ));
+    book.unhide_sheet("Sheet 3").unwrap();
+    book.hide_sheet("Sheet1").unwrap();
+    assert_eq!(book.workbook_view().active_tab(), 1);
+
+    let xlsx = workbook_to_xlsx_bytes(&book);
+    let book = reader::xlsx::read_reader(std::io::Cursor::new(xlsx), true).unwrap();
+    assert!(matches!(
+        book.sheet_by_name("Sheet2").unwrap().state(),
+        SheetStateValues::VeryHidden
+    ));
+    assert_eq!(
+        book.sheet_by_name("Summary").unwrap().cell("A1").unwrap().formula(),
+        "SUM(Sheet2:Sheet1!A1)"
+    );
+}
+
+#[test]
+fn rename_sheet_rewrites_references() {
+    let mut book = new_file();
+    book.new_sheet("Report").unwrap();
+    book.new_sheet("Last").unwrap();
+    {
+        let sheet = book.sheet_by_name_mut("Report").unwrap();
+        sheet.cell_mut("A1").set_formula("SUM(Sheet1!A1:A3)+sheet1!B1");
+        sheet.cell_mut("A2").set_formula("SUM(Sheet1:Last!A1)");
+        sheet.cell_mut("A3").set_formula("Report!A1+Other!A1");
+
+        let mut data_validation = DataValidation::default();
+        data_validation.set_type(DataValidationValues::List);
+        data_validation.set_formula1("Sheet1!$A$1:$A$3");
+        let mut seq = SequenceOfReferences::default();
+        seq.set_sqref("B1");
+        data_validation.set_sequence_of_references(seq);
+        let mut data_validations = DataValidations::default();
+        data_validations.add_data_validation_list(data_validation);
+        sheet.set_data_validations(data_validations);
+
+        let mut form = Formula::default();
+        form.set_string_value("$A1>Sheet1!$B$1");
+        let mut rule = ConditionalFormattingRule::default();
+        rule.set_type(ConditionalFormatValues::Expression)
+            .set_priority(1)
+            .set_formula(form);
+        let mut seq = SequenceOfReferences::default();
+        seq.set_sqref("A1:A3");
+        let mut conditional_formatting = ConditionalFormatting::default();
+        conditional_formatting.set_sequence_of_references(seq);
+        conditional_formatting.set_conditional_collection(vec![rule]);
+        sheet.add_conditional_formatting_collection(conditional_formatting);
+
+        let mut hyperlink = Hyperlink::default();
+        hyperlink.set_location(true).set_url("Sheet1!C3");
+        sheet.cell_mut("C1").set_hyperlink(hyperlink);
+    }
+    book.defined_names_manager()
+        .add("Source", "Sheet1!$A$1:$A$3", None)
+        .unwrap();
+
+    assert!(matches!(
+        book.rename_sheet("Sheet1", "Bad:Name"),
+        Err(XlsxError::InvalidArgument(_))
+    ));
+    assert!(matches!(
+        book.rename_sheet("Sheet1", "report"),
+        Err(XlsxError::NameDuplicate())
+    ));
+    assert!(matches!(
+        book.rename_sheet("Missing", "Other"),
+        Err(XlsxError::NotFound())
+    ));
+    book.rename_sheet("Sheet1", "Bob's Data").unwrap();
+
+    let check = |book: &Workbook| {
+        let sheet = book.sheet_by_name("Report").unwrap();
+        assert_eq!(
+            sheet.cell("A1").unwrap().formula(),
+            "SUM('Bob''s Data'!A1:A3)+'Bob''s Data'!B1"
+        );
+        assert_eq!(
+            sheet.cell("A2").unwrap().formula(),
+            "SUM('Bob''s Data:Last'!A1)"
+        );
+        assert_eq!(sheet.cell("A3").unwrap().formula(), "Report!A1+Other!A1");
+        assert_eq!(
+            sheet.data_validations().unwrap().data_validation_list()[0].formula1(),
+            "'Bob''s Data'!$A$1:$A$3"
+        );
+        assert_eq!(
+            sheet.conditional_formatting_collection()[0].conditional_collection()[0]
+                .formula()
+                .unwrap()
+                .address_str(),
+            "$A1>'Bob''s Data'!$B$1"
+        );
+        assert_eq!(
+            sheet.cell("C1").unwrap().hyperlink().unwrap().url(),
+            "'Bob''s Data'!C3"
+        );
+    };
+    check(&book);
+    assert_eq!(
+        book.defined_names_manager().get("Source", None).unwrap().address(),
+        "'Bob''s Data'!$A$1:$A$3"
+    );
+
+    book.set_sheet_name(0, "Data").unwrap();
+    assert_eq!(
+        book.sheet_by_name("Report").unwrap().cell("A1").unwrap().formula(),
+        "SUM(Data!A1:A3)+Data!B1"
+    );
+    book.rename_sheet("Data", "Bob's Data").unwrap();
+
+    let xlsx = workbook_to_xlsx_bytes(&book);
+    let mut book = reader::xlsx::read_reader(std::io::Cursor::new(xlsx), true).unwrap();
+    assert_eq!(book.sheet_collection()[0].name(), "Bob's Data");
+    check(&book);
+    assert_eq!(
+        book.defined_names_manager().get("Source", None).unwrap().address(),
+        "'Bob''s Data'!$A$1:$A$3"
+    );
+}
+
+#[test]
+fn rename_sheet_keeps_data_validation_formulas() {
+    use std::io::Read;
+
+    let mut book = new_file();
+    book.new_sheet("Other").unwrap();
+    {
+        let sheet = book.sheet_mut(0).unwrap();
+        let mut list = DataValidation::default();
+        list.set_type(DataValidationValues::List)
+            .set_formula1("\"a,b\"");
+        let mut seq = SequenceOfReferences::default();
+        seq.set_sqref("A1");
+        list.set_sequence_of_references(seq);
+        let mut between = DataValidation::default();
+        between
+            .set_type(DataValidationValues::Whole)
+            .set_formula1("Other!$A$1")
+            .set_formula2("10");
+        let mut seq = SequenceOfReferences::default();
+        seq.set_sqref("B1");
+        between.set_sequence_of_references(seq);
+        let mut data_validations = DataValidations::default();
+        data_validations.add_data_validation_list(list);
+        data_validations.add_data_validation_list(between);
+        sheet.set_data_validations(data_validations);
+    }
+    book.rename_sheet("Other", "Renamed").unwrap();
+
+    let mut source = std::io::Cursor::new(Vec::new());
+    writer::xlsx::write_writer(&book, &mut source).unwrap();
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(source.into_inner())).unwrap();
+    let mut xml = String::new();
+    archive
+        .by_name("xl/worksheets/sheet1.xml")
+        .unwrap()
+        .read_to_string(&mut xml)
+        .unwrap();
+    assert!(xml.contains(r#"<formula1>"a,b"</formula1></dataValidation>"#));
+    assert!(xml.contains("<formula1>Renamed!$A$1</formula1><formula2>10</formula2>"));
+    assert!(!xml.contains("<formula2></formula2>"));
+    assert!(!xml.contains("<formula2/>"));
+}
+
+#[test]
+fn fill_formula_down_writes_shared_formula() {
+    let mut book = new_file();
+    {
+        let sheet = book.sheet_mut(0).unwrap();
+        sheet.fill_formula_down("C2:C5", "A2*$B$1").unwrap();
+        sheet.fill_formula_down("D2:E3", "A2&\"<\"&B$1").unwrap();
+        assert!(matches!(
+            sheet.fill_formula_down("C:C", "A1"),
+            Err(XlsxError::InvalidArgument(_))
+        ));
+        assert_eq!(sheet.cell("C4").unwrap().formula(), "A4*$B$1");
+        assert_eq!(sheet.cell("E3").unwrap().formula(), "B3&\"<\"&C$1");
+        sheet.cell_mut("C5").set_formula("A5+1");
+    }
+
+    let xlsx = workbook_to_xlsx_bytes(&book);
+    let sheet_xml = zip_entry_to_string(&xlsx, "xl/worksheets/sheet1.xml");
+    assert!(cell_fragment(&sheet_xml, "C2").contains("ref=\"C2:C4\""));
+    assert!(cell_fragment(&sheet_xml, "C3").contains("<f t=\"shared\" si=\"0\"></f>"));
+    assert!(cell_fragment(&sheet_xml, "D2").contains("ref=\"D2:E3\""));
+    assert!(!cell_fragment(&sheet_xml, "C5").contains("shared"));
+
+    let mut book = reader::xlsx::read_reader(std::io::Cursor::new(xlsx), true).unwrap();
+    {
+        let sheet = book.sheet(0).unwrap();
+        assert_eq!(sheet.cell("C2").unwrap().formula(), "A2*$B$1");
+        assert_eq!(sheet.cell("C4").unwrap().formula(), "A4*$B$1");
+        assert_eq!(sheet.cell("C5").unwrap().formula(), "A5+1");
+        assert_eq!(sheet.cell("E3").unwrap().formula(), "B3&\"<\"&C$1");
+    }
+
+    // Removing the master row leaves the dependents without the formula text.
+    book.sheet_mut(0).unwrap().remove_row(2, 1);
+    let xlsx = workbook_to_xlsx_bytes(&book);
+    let sheet_xml = zip_entry_to_string(&xlsx, "xl/worksheets/sheet1.xml");
+    assert!(!cell_fragment(&sheet_xml, "C2").contains("shared"));
+    let book = reader::xlsx::read_reader(std::io::Cursor::new(xlsx), true).unwrap();
+    let sheet = book.sheet(0).unwrap();
+    assert_eq!(sheet.cell("C2").unwrap().formula(), "A2*$B$1");
+    assert_eq!(sheet.cell("C3").unwrap().formula(), "A3*$B$1");
+    assert_eq!(sheet.cell("D2").unwrap().formula(), "A2&\"<\"&B$1");
+}
+
+#[test]
+fn dependency_graph_queries() {
+    let mut book = new_file();
+    book.new_sheet("Calc").unwrap();
+    {
+        let sheet = book.sheet_mut(0).unwrap();
+        sheet.cell_mut("A1").set_value_number(100);
+        sheet.cell_mut("A2").set_value_number(200);
+        sheet.cell_mut("B1").set_formula("SUM(A:A)*Rate");
+    }
+    {
+        let sheet = book.sheet_by_name_mut("Calc").unwrap();
+        sheet.cell_mut("A1").set_formula("Sheet1!B1+Sheet1:Calc!C5");
+        sheet.cell_mut("A2").set_formula("A1*2");
+        sheet.cell_mut("D1").set_formula("D2+1");
+        sheet.cell_mut("D2").set_formula("D3+1");
+        sheet.cell_mut("D3").set_formula("D1+1");
+    }
+    book.defined_names_manager()
+        .add("Rate", "Sheet1!$C$1", None)
+        .unwrap();
+
+    let graph = book.dependency_graph().unwrap();
+    let names = |nodes: Vec<&DependencyNode>| {
+        nodes.iter().map(ToString::to_string).collect::<Vec<String>>()
+    };
+    let b1 = DependencyNode::cell("Sheet1", "B1");
+    assert_eq!(
+        names(graph.precedents(&b1)),
+        ["Sheet1!A1", "Sheet1!A2", "Rate"]
+    );
+    assert_eq!(
+        names(graph.precedents(&DependencyNode::cell("Calc", "A1"))),
+        ["Sheet1!B1", "Sheet1!C5", "Calc!C5"]
+    );
+    assert_eq!(
+        names(graph.dependents(&DependencyNode::name("rate", None))),
+        ["Sheet1!B1"]
+    );
+    assert_eq!(
+        names(graph.all_dependents(&DependencyNode::cell("sheet1", "A2"))),
+        ["Sheet1!B1", "Calc!A1", "Calc!A2"]
+    );
+    assert_eq!(
+        names(graph.all_precedents(&DependencyNode::cell("Calc", "A2"))),
+        [
+            "Calc!A1",
+            "Sheet1!B1",
+            "Sheet1!C5",
+            "Calc!C5",
+            "Sheet1!A1",
+            "Sheet1!A2",
+            "Rate",
+            "Sheet1!C1"
+        ]
+    );
+
+    let cycles = graph.circular_references();
+    assert_eq!(cycles.len(), 1);
+    assert_eq!(names(cycles[0].clone()), ["Calc!D1", "Calc!D2", "Calc!D3"]);
+    assert!(matches!(
+        graph.topological_order(),
+        Err(XlsxError::InvalidArgument(message))
+            if message.ends_with("Calc!D1 -> Calc!D2 -> Calc!D3 -> Calc!D1")
+    ));
+
+    book.sheet_by_name_mut("Calc")
+        .unwrap()
+        .cell_mut("D3")
+        .set_value_number(1);
+    let graph = book.dependency_graph().unwrap();
+    assert!(graph.circular_references().is_empty());
+    let order = graph.topological_order().unwrap();
+    let position = |node: DependencyNode| order.iter().position(|x| **x == node).unwrap();
+    assert!(position(DependencyNode::cell("Sheet1", "C1")) < position(DependencyNode::name("Rate", None)));
+    assert!(position(DependencyNode::name("Rate", None)) < position(b1.clone()));
+    assert!(position(b1) < position(DependencyNode::cell("Calc", "A2")));
+    assert!(position(DependencyNode::cell("Calc", "D2")) < position(DependencyNode::cell("Calc", "D1")));
+}
+
+#[test]
+fn dynamic_array_formula_round_trip() {
+    let mut book = new_file();
+    {
+        let sheet = book.sheet_mut(0).unwrap();
+        sheet.cell_mut("A1").set_value_number(3);
+        sheet.cell_mut("A2").set_value_number(1);
+        sheet.cell_mut("A3").set_value_number(2);
+        sheet.set_dynamic_array_formula("B1", "SORT(A1:A3)", &[vec!["1"], vec!["2"], vec!["3"]]);
+        sheet
+            .cell_mut("D1")
+            .cell_value_mut()
+            .set_dynamic_array_formula("SEQUENCE(2,2)", "D1:E2");
+        sheet.cell_mut("G1").set_formula("SUM(A1:A3)");
+        assert_eq!(sheet.value("B1"), "1");
+        assert_eq!(sheet.value("B3"), "3");
+    }
+
+    let xlsx = workbook_to_xlsx_bytes(&book);
+    let sheet_xml = zip_entry_to_string(&xlsx, "xl/worksheets/sheet1.xml");
+    let b1 = cell_fragment(&sheet_xml, "B1");
+    assert!(b1.contains("cm=\"1\""));
+    assert!(b1.contains("t=\"array\""));
+    assert!(b1.contains("ref=\"B1:B3\""));
+    assert!(b1.contains("_xlfn._xlws.SORT(A1:A3)"));
+    assert!(cell_fragment(&sheet_xml, "D1").contains("cm=\"1\""));
+    assert!(!cell_fragment(&sheet_xml, "B2").contains("cm="));
+    assert!(!cell_fragment(&sheet_xml, "G1").contains("cm="));
+    let metadata_xml = zip_entry_to_string(&xlsx, "xl/metadata.xml");
+    assert!(metadata_xml.contains("name=\"XLDAPR\""));
+    assert!(metadata_xml.contains("fDynamic=\"1\""));
+    assert!(metadata_xml.contains("<cellMetadata count=\"1\">"));
+    let rels_xml = zip_entry_to_string(&xlsx, "xl/_rels/workbook.xml.rels");
+    assert!(rels_xml.contains("relationships/sheetMetadata\" Target=\"metadata.xml\""));
+    let content_types_xml = zip_entry_to_string(&xlsx, "[Content_Types].xml");
+    assert!(content_types_xml.contains("/xl/metadata.xml"));
+
+    let book = reader::xlsx::read_reader(std::io::Cursor::new(xlsx), true).unwrap();
+    assert!(!book.metadata().is_empty());
+    let sheet = book.sheet(0).unwrap();
+    let formula = sheet.cell("B1").unwrap().cell_value().formula_obj().unwrap();
+    assert!(formula.dynamic_array());
+    assert_eq!(formula.reference(), "B1:B3");
+    let formula = sheet.cell("G1").unwrap().cell_value().formula_obj().unwrap();
+    assert!(!formula.dynamic_array());
+    assert_eq!(sheet.value("B2"), "2");
+
+    // The metadata part is kept as is on another round trip.
+    let xlsx = workbook_to_xlsx_bytes(&book);
+    let metadata_xml = zip_entry_to_string(&xlsx, "xl/metadata.xml");
+    assert!(metadata_xml.contains("<cellMetadata count=\"1\">"));
+    let sheet_xml = zip_entry_to_string(&xlsx, "xl/worksheets/sheet1.xml");
+    assert!(cell_fragment(&sheet_xml, "D1").contains("cm=\"1\""));
+}
+
+#[test]
+fn array_formula_over_range() {
+    let mut book = new_file();
+    {
+        let sheet = book.sheet_mut(0).unwrap();
+        sheet.set_array_formula("C1:C3", "A1:A3*B1:B3").unwrap();
+        let (formula, range) = sheet.array_formula_at("C3").unwrap();
+        assert_eq!(formula.text(), "A1:A3*B1:B3");
+        assert_eq!(range, "C1:C3");
+        assert!(sheet.array_formula_at("D1").is_none());
+
+        assert!(matches!(
+            sheet.set_array_formula("C3:D3", "1"),
+            Err(XlsxError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            sheet.set_array_formula("A:A", "1"),
+            Err(XlsxError::InvalidArgument(_))
+        ));
+        sheet.set_array_formula("C1:C4", "A1:A4*B1:B4").unwrap();
+        assert_eq!(sheet.array_formula_at("C4").unwrap().1, "C1:C4");
+
+        sheet.cell_mut("C2").set_value_number(2);
+        assert!(!sheet.remove_cell("C2"));
+        assert!(!sheet.remove_cell("C1"));
+        assert!(sheet.cell("C2").is_some());
+        sheet.cell_mut("D1").set_value_number(1);
+        assert!(sheet.remove_cell("D1"));
+
+        sheet.set_array_formula("E1:F2", "A1:B2").unwrap();
+        sheet.cell_mut("F2").set_value_number(1);
+        assert!(!sheet.remove_array_formula("E1:E2"));
+        assert!(!sheet.remove_array_formula("F2"));
+        assert!(sheet.remove_array_formula("E1:F2"));
+        assert!(sheet.cell("E1").is_none());
+        assert!(sheet.cell("F2").is_none());
+        assert!(sheet.array_formula_at("E1").is_none());
+        assert!(!sheet.remove_array_formula("E1:F2"));
+    }
+
+    // A formula inside the array can not be written.
+    book.sheet_mut(0).unwrap().cell_mut("C3").set_formula("A3*B3");
+    let mut buffer = std::io::Cursor::new(Vec::new());
+    assert!(matches!(
+        writer::xlsx::write_writer(&book, &mut buffer),
+        Err(XlsxError::InvalidArgument(_))
+    ));
+    book.sheet_mut(0).unwrap().cell_mut("C3").cell_value_mut().remove_formula();
+
+    let xlsx = workbook_to_xlsx_bytes(&book);
+    let sheet_xml = zip_entry_to_string(&xlsx, "xl/worksheets/sheet1.xml");
+    assert!(cell_fragment(&sheet_xml, "C1").contains("<f t=\"array\" ref=\"C1:C4\">"));
+    let book = reader::xlsx::read_reader(std::io::Cursor::new(xlsx), true).unwrap();
+    let sheet = book.sheet(0).unwrap();
+    let (formula, range) = sheet.array_formula_at("C2").unwrap();
+    assert_eq!(formula.text(), "A1:A4*B1:B4");
+    assert_eq!(range, "C1:C4");
+    assert!(!formula.dynamic_array());
+}
+
+#[test]
+fn external_link_round_trip() {
+    let mut book = new_file();
+    for target in ["file:///C:/data/prices.xlsx", "rates.xlsx"] {
+        let mut sheet_data = ExternalSheetData::default();
+        let mut cell = ExternalCell::default();
+        cell.set_coordinate("A1").set_value(CellRawValue::Numeric(42.0));
+        sheet_data.add_cell(cell);
+        let mut cell = ExternalCell::default();
+        cell.set_coordinate("B1")
+            .set_value(CellRawValue::String("Tokyo".into()));
+        sheet_data.add_cell(cell);
+        let mut defined_name = ExternalDefinedName::default();
+        defined_name.set_name("Total").set_refers_to("=Sheet1!$A$1");
+        let mut link = ExternalLink::new(target);
+        link.add_sheet_name("Sheet1")
+            .add_sheet_data(sheet_data)
+            .add_defined_name(defined_name);
+        book.add_external_link(link);
+    }
+    {
+        let sheet = book.sheet_mut(0).unwrap();
+        sheet.cell_mut("A1").set_formula("[1]Sheet1!A1");
+        sheet.cell_mut("A2").set_formula("[1]Sheet1!B1");
+        sheet.cell_mut("A3").set_formula("[2]Sheet1!A1*2");
+        let mut data_validation = DataValidation::default();
+        data_validation
+            .set_type(DataValidationValues::Whole)
+            .set_formula1("[1]Sheet1!$A$1")
+            .set_formula2("[2]Sheet1!$A$1");
+        let mut seq = SequenceOfReferences::default();
+        seq.set_sqref("B1");
+        data_validation.set_sequence_of_references(seq);
+        let mut data_validations = DataValidations::default();
+        data_validations.add_data_validation_list(data_validation);
+        sheet.set_data_validations(data_validations);
+    }
+
+    let xlsx = workbook_to_xlsx_bytes(&book);
+    let link_xml = zip_entry_to_string(&xlsx, "xl/externalLinks/externalLink1.xml");
+    assert!(link_xml.contains("<sheetName val=\"Sheet1\"/>"));
+    assert!(link_xml.contains("<definedName name=\"Total\" refersTo=\"=Sheet1!$A$1\"/>"));
+    assert!(link_xml.contains("<cell r=\"B1\" t=\"str\"><v>Tokyo</v></cell>"));
+    let rels_xml = zip_entry_to_string(&xlsx, "xl/externalLinks/_rels/externalLink1.xml.rels");
+    assert!(rels_xml.contains("Target=\"file:///C:/data/prices.xlsx\" TargetMode=\"External\""));
+    let workbook_rels_xml = zip_entry_to_string(&xlsx, "xl/_rels/workbook.xml.rels");
+    assert!(workbook_rels_xml.contains("Target=\"externalLinks/externalLink2.xml\""));
+    let workbook_xml = zip_entry_to_string(&xlsx, "xl/workbook.xml");
+    assert!(workbook_xml.contains("<externalReferences><externalReference r:id=\"rId2\"/>"));
+    let content_types_xml = zip_entry_to_string(&xlsx, "[Content_Types].xml");
+    assert!(content_types_xml.contains("/xl/externalLinks/externalLink1.xml"));
+
+    let mut book = reader::xlsx::read_reader(std::io::Cursor::new(xlsx), true).unwrap();
+    assert_eq!(book.external_links().len(), 2);
+    let link = &book.external_links()[0];
+    assert_eq!(link.target(), "file:///C:/data/prices.xlsx");
+    assert_eq!(link.sheet_names(), ["Sheet1"]);
+    assert_eq!(link.defined_names()[0].name(), "Total");
+    assert_eq!(
+        link.cached_value("sheet1", "B1").unwrap().to_string(),
+        "Tokyo"
+    );
+
+    book.retarget_external_link("file:///C:/data/prices.xlsx", "prices-2024.xlsx")
+        .unwrap();
+    assert_eq!(book.external_links()[0].target(), "prices-2024.xlsx");
+    assert!(book.retarget_external_link("missing.xlsx", "x.xlsx").is_err());
+
+    book.break_external_link("prices-2024.xlsx").unwrap();
+    assert_eq!(book.external_links().len(), 1);
+    {
+        let sheet = book.sheet(0).unwrap();
+        assert!(!sheet.cell("A1").unwrap().cell_value().is_formula());
+        assert_eq!(sheet.value("A1"), "42");
+        assert_eq!(sheet.value("A2"), "Tokyo");
+        assert_eq!(sheet.cell("A3").unwrap().formula(), "[1]Sheet1!A1*2");
+    }
+
+    let xlsx = workbook_to_xlsx_bytes(&book);
+    let sheet_xml = zip_entry_to_string(&xlsx, "xl/worksheets/sheet1.xml");
+    assert!(sheet_xml.contains("<formula1>42</formula1><formula2>[1]Sheet1!$A$1</formula2>"));
+    let link_xml = zip_entry_to_string(&xlsx, "xl/externalLinks/externalLink1.xml");
+    assert!(link_xml.contains("<cell r=\"A1\"><v>42</v></cell>"));
+    let rels_xml = zip_entry_to_string(&xlsx, "xl/externalLinks/_rels/externalLink1.xml.rels");
+    assert!(rels_xml.contains("Target=\"rates.xlsx\""));
+    let archive = zip::ZipArchive::new(std::io::Cursor::new(xlsx)).unwrap();
+    assert!(archive.index_for_name("xl/externalLinks/externalLink2.xml").is_none());
+}
+
+#[test]
+fn data_table_round_trip() {
+    let mut book = new_file();
+    {
+        let sheet = book.sheet_mut(0).unwrap();
+        sheet.cell_mut("B1").set_value_number(0.05);
+        sheet.cell_mut("B2").set_value_number(10);
+        sheet.cell_mut("B4").set_formula("1000*B1");
+        sheet.cell_mut("A5").set_value_number(0.04);
+        sheet.cell_mut("A6").set_value_number(0.06);
+        sheet.cell_mut("B5").set_formula("A5");
+        sheet.add_data_table("A4:B6", None, Some("$B$1")).unwrap();
+        assert_eq!(sheet.cell("B5").unwrap().formula(), "");
+
+        sheet.cell_mut("D4").set_formula("B1*B2");
+        sheet.add_data_table("D4:F6", Some("B2"), Some("B1")).unwrap();
+
+        assert!(sheet.add_data_table("H1:H5", None, Some("B1")).is_err());
+        assert!(sheet.add_data_table("H1:I5", None, None).is_err());
+        assert!(sheet.add_data_table("H1:I5", Some("I2"), None).is_err());
+        assert!(sheet.add_data_table("H1:I5", Some("Sheet2!B1"), None).is_err());
+
+        sheet.cell_mut("E4").set_value_number(5);
+        sheet.cell_mut("F4").set_value_number(20);
+        sheet.cell_mut("D5").set_value_number(0.1);
+        sheet.cell_mut("D6").set_value_number(0.2);
+    }
+    book.calculate_data_table("A4:B6").unwrap();
+    book.calculate_data_table("Sheet1!D4:F6").unwrap();
+    assert!(book.calculate_data_table("A5:B6").is_err());
+    assert!(book.calculate_data_table("Sheet2!A4:B6").is_err());
+    {
+        let sheet = book.sheet(0).unwrap();
+        assert_eq!(sheet.value("B5"), "40");
+        assert_eq!(sheet.value("B6"), "60");
+        assert_eq!(sheet.value("E5"), "0.5");
+        assert_eq!(sheet.value("F5"), "2");
+        assert_eq!(sheet.value("E6"), "1");
+        assert_eq!(sheet.value("F6"), "4");
+        assert_eq!(sheet.value("B1"), "0.05");
+    }
+
+    let xlsx = workbook_to_xlsx_bytes(&book);
+    let sheet_xml = zip_entry_to_string(&xlsx, "xl/worksheets/sheet1.xml");
+    let b5 = cell_fragment(&sheet_xml, "B5");
+    assert!(b5.contains("t=\"dataTable\""));
+    assert!(b5.contains("ref=\"B5:B6\""));
+    assert!(b5.contains("dt2D=\"0\""));
+    assert!(b5.contains("dtr=\"0\""));
+    assert!(b5.contains("r1=\"B1\""));
+    let e5 = cell_fragment(&sheet_xml, "E5");
+    assert!(e5.contains("ref=\"E5:F6\""));
+    assert!(e5.contains("dt2D=\"1\""));
+    assert!(e5.contains("r1=\"B2\""));
+    assert!(e5.contains("r2=\"B1\""));
+    assert!(e5.contains("<v>0.5</v>"));
+
+    let book = reader::xlsx::read_reader(std::io::Cursor::new(xlsx), true).unwrap();
+    let sheet = book.sheet(0).unwrap();
+    let formula = sheet.cell("E5").unwrap().cell_value().formula_obj().unwrap();
+    assert_eq!(formula.formula_type(), &CellFormulaValues::DataTable);
+    assert!(formula.data_table_2d());
+    assert!(formula.data_table_row());
+    assert_eq!(formula.reference(), "E5:F6");
+    assert_eq!(formula.r1(), "B2");
+    assert_eq!(formula.r2(), "B1");
+    assert_eq!(sheet.cell("B4").unwrap().formula(), "1000*B1");
+}
+
+#[test]
+fn goal_seek_and_scenarios() {
+    let mut book = new_file();
+    book.new_sheet("Loan").unwrap();
+    {
+        let sheet = book.sheet_by_name_mut("Loan").unwrap();
+        sheet.cell_mut("B1").set_value_number(0.06);
+        sheet.cell_mut("B2").set_value_number(360);
+        sheet.cell_mut("B3").set_value_number(100_000);
+        sheet.cell_mut("B4").set_formula("PMT(B1/12,B2,-B3)");
+        sheet.cell_mut("C1").set_value("text");
+    }
+    book.defined_names_manager()
+        .add("Payment", "Loan!$B$4", None)
+        .unwrap();
+    {
+        let sheet = book.sheet_mut(0).unwrap();
+        sheet.cell_mut("A1").set_value_number(1);
+        sheet.cell_mut("A2").set_formula("IF(A1>0,A1^2,0)+Payment*0");
+    }
+
+    let principal = book.goal_seek("Loan!B4", 1500.0, "Loan!B3").unwrap();
+    assert!((principal - 250_187.4).abs() < 0.1);
+    let sheet = book.sheet_by_name("Loan").unwrap();
+    assert_eq!(sheet.cell("B3").unwrap().cell_value().value_number(), Some(principal));
+    let payment = sheet.cell("B4").unwrap().cell_value().value_number().unwrap();
+    assert!((payment - 1500.0).abs() < 1e-6);
+
+    let root = book.goal_seek("A2", 2.0, "A1").unwrap();
+    assert!((root - std::f64::consts::SQRT_2).abs() < 1e-6);
+
+    assert!(book.goal_seek("A1", 2.0, "A2").is_err());
+    assert!(book.goal_seek("Loan!B4", 1500.0, "Loan!C1").is_err());
+    assert!(book.goal_seek("Missing!B4", 1500.0, "B3").is_err());
+
+    {
+        let sheet = book.sheet_by_name_mut("Loan").unwrap();
+        let mut scenario = Scenario::default();
+        scenario
+            .set_name("Low rate")
+            .set_comment("Best case")
+            .add_input_cell("B1", "0.04")
+            .add_input_cell("B2", "240");
+        sheet.scenarios_mut().add_scenario(scenario);
+        let mut scenario = Scenario::default();
+        scenario.set_name("High rate").add_input_cell("B1", "0.08");
+        sheet.scenarios_mut().add_scenario(scenario);
+        sheet.scenarios_mut().set_sqref("B4");
+        sheet.apply_scenario("LOW RATE").unwrap();
+        assert_eq!(sheet.value("B1"), "0.04");
+        assert_eq!(sheet.value("B2"), "240");
+        assert!(sheet.apply_scenario("Missing").is_err());
+    }
+
+    let xlsx = workbook_to_xlsx_bytes(&book);
+    let sheet_xml = zip_entry_to_string(&xlsx, "xl/worksheets/sheet2.xml");
+    assert!(sheet_xml.contains("<scenarios current=\"0\" sqref=\"B4\">"));
+    assert!(sheet_xml.contains(
+        "<scenario name=\"Low rate\" count=\"2\" comment=\"Best case\"><inputCells r=\"B1\" val=\"0.04\"/>"
+    ));
+
+    let mut book = reader::xlsx::read_reader(std::io::Cursor::new(xlsx), true).unwrap();
+    let sheet = book.sheet_by_name_mut("Loan").unwrap();
+    let scenarios = sheet.scenarios().unwrap();
+    assert_eq!(scenarios.current(), Some(0));
+    assert_eq!(scenarios.scenario_list().len(), 2);
+    let scenario = scenarios.scenario("high rate").unwrap();
+    assert_eq!(scenario.input_cells()[0].reference(), "B1");
+    assert_eq!(scenario.input_cells()[0].value(), "0.08");
+    sheet.apply_scenario("High rate").unwrap();
+    assert_eq!(sheet.value("B1"), "0.08");
+    assert_eq!(sheet.scenarios().unwrap().current(), Some(1));
+}
+
+#[test]
+fn goal_seek_long_chain() {
+    const ROWS: u32 = 20_000;
+    let mut book = new_file();
+    let sheet = book.sheet_mut(0).unwrap();
+    sheet.cell_mut("A1").set_value_number(0);
+    for row in 2..=ROWS {
+        sheet.cell_mut((1, row)).set_formula(format!("A{}+1", row - 1));
+    }
+    sheet.cell_mut("B1").set_formula(format!("A{ROWS}*2"));
+
+    let value = book.goal_seek("B1", 50_000.0, "A1").unwrap();
+    assert!((value - 5_001.0).abs() < 1e-4);
+    let sheet = book.sheet(0).unwrap();
+    let result = sheet.cell("B1").unwrap().cell_value().value_number().unwrap();
+    assert!((result - 50_000.0).abs() < 1e-4);
+}
+
+#[test]
+fn set_formula_checked_validates() {
+    let mut book = new_file();
+    book.new_sheet("Rates 2024").unwrap();
+    book.defined_names_manager()
+        .add("Rate", "'Rates 2024'!$A$1", None)
+        .unwrap();
+
+    book.set_formula_checked("Sheet1", "A1", "=SUM('Rates 2024'!B1:B3)*Rate")
+        .unwrap();
+    book.set_formula_checked("Sheet1", "A2", "XLOOKUP(1,A:A,B:B)&CONCAT(\"(\",C1)")
+        .unwrap();
+    book.set_formula_checked("Sheet1", (1, 3), "TEXTJOIN(\",\",TRUE,B1:B3)")
+        .unwrap();
+    let sheet = book.sheet_by_name("Sheet1").unwrap();
+    assert_eq!(sheet.cell("A1").unwrap().formula(), "SUM('Rates 2024'!B1:B3)*Rate");
+    assert_eq!(
+        sheet.cell("A2").unwrap().formula(),
+        "_xlfn.XLOOKUP(1,A:A,B:B)&_xlfn.CONCAT(\"(\",C1)"
+    );
+    assert_eq!(sheet.cell("A3").unwrap().formula(), "_xlfn.TEXTJOIN(\",\",TRUE,B1:B3)");
+
+    book.set_formula_checked("Sheet1", "A4", "SUM(B1:B3 A2:C2)")
+        .unwrap();
+    book.set_formula_checked("Sheet1", "A5", "B1:B3 A2:C2+SUM((B1:B3,C1:C3))")
+        .unwrap();
+    let sheet = book.sheet_by_name("Sheet1").unwrap();
+    assert_eq!(sheet.cell("A4").unwrap().formula(), "SUM(B1:B3 A2:C2)");
+    assert_eq!(
+        sheet.cell("A5").unwrap().formula(),
+        "B1:B3 A2:C2+SUM((B1:B3,C1:C3))"
+    );
+
+    for formula in [
+        "SUM(A1:A3",
+        "IF(A1>0,1,0))",
+        "\"open",
+        "A1*",
+        "VLOKUP(1,A:A,1)",
+        "IF(A1)",
+        "'Rates 2025'!A1",
+        "Rte*2",
+        "SUM(B1:B3 'Rates 2025'!A2)",
+    ] {
+        assert!(
+            book.set_formula_checked("Sheet1", "B1", formula).is_err(),
+            "{formula}"
+        );
+    }
+    assert!(book.sheet_by_name("Sheet1").unwrap().cell("B1").is_none());
+    assert!(book.set_formula_checked("Missing", "B1", "1+1").is_err());
+}